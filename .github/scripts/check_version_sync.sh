@@ -1,11 +1,14 @@
 #!/bin/bash
-# Check that Cargo.toml, Python pyproject.toml, and ROS2 package.xml versions are synchronized
+# Check that Cargo.toml (crate and C library), Python pyproject.toml, and ROS2 package.xml versions are synchronized
 
 set -e
 
 # Extract version from Cargo.toml
 CARGO_VERSION=$(grep '^version = ' Cargo.toml | head -1 | sed 's/version = "\(.*\)"/\1/')
 
+# Extract version from the C library crate
+CAPI_VERSION=$(grep '^version = ' crates/capi/Cargo.toml | head -1 | sed 's/version = "\(.*\)"/\1/')
+
 # Extract version from Python pyproject.toml
 PYTHON_VERSION=$(grep '^version = ' crates/python/pyproject.toml | head -1 | sed 's/version = "\(.*\)"/\1/')
 
@@ -17,11 +20,12 @@ echo "Version Synchronization Check"
 echo "=================================================="
 echo ""
 echo "Cargo.toml version:              $CARGO_VERSION"
+echo "C library Cargo.toml version:    $CAPI_VERSION"
 echo "Python pyproject.toml version:   $PYTHON_VERSION"
 echo "ROS2 package.xml version:        $PACKAGE_XML_VERSION"
 echo ""
 
-if [ "$CARGO_VERSION" = "$PYTHON_VERSION" ] && [ "$CARGO_VERSION" = "$PACKAGE_XML_VERSION" ]; then
+if [ "$CARGO_VERSION" = "$CAPI_VERSION" ] && [ "$CARGO_VERSION" = "$PYTHON_VERSION" ] && [ "$CARGO_VERSION" = "$PACKAGE_XML_VERSION" ]; then
     echo "✅ All versions are synchronized!"
     echo ""
     exit 0
@@ -30,6 +34,7 @@ else
     echo ""
     echo "All versions must match across:"
     echo "  - Cargo.toml"
+    echo "  - crates/capi/Cargo.toml"
     echo "  - crates/python/pyproject.toml"
     echo "  - edgefirst_msgs/package.xml"
     echo ""
@@ -1,13 +0,0 @@
-// SPDX-FileCopyrightText: Copyright 2025 Au-Zone Technologies
-// SPDX-License-Identifier: Apache-2.0
-
-use std::env;
-
-fn main() {
-    // Set SONAME for shared library versioning on Linux
-    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
-    if target_os == "linux" {
-        let major = env::var("CARGO_PKG_VERSION_MAJOR").unwrap();
-        println!("cargo:rustc-cdylib-link-arg=-Wl,-soname,libedgefirst_schemas.so.{major}");
-    }
-}
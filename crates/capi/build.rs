@@ -0,0 +1,230 @@
+// SPDX-FileCopyrightText: Copyright 2025 Au-Zone Technologies
+// SPDX-License-Identifier: Apache-2.0
+
+use std::env;
+
+#[cfg(feature = "csharp")]
+mod csharp;
+
+fn main() {
+    // Set SONAME for shared library versioning on Linux
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_os == "linux" {
+        let major = env::var("CARGO_PKG_VERSION_MAJOR").unwrap();
+        println!("cargo:rustc-cdylib-link-arg=-Wl,-soname,libedgefirst_schemas.so.{major}");
+    }
+
+    #[cfg(feature = "header")]
+    generate_header();
+}
+
+/// Write `edgefirst_schemas.h` for every `extern "C"` function, handle type
+/// and constant in edgefirst-schemas to `$OUT_DIR`, and also to
+/// `$EDGEFIRST_SCHEMAS_HEADER` when that is set.
+#[cfg(feature = "header")]
+fn generate_header() {
+    use std::path::PathBuf;
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let crate_dir = manifest_dir.join("../..");
+    let mut config = cbindgen::Config::from_file(manifest_dir.join("cbindgen.toml"))
+        .expect("crates/capi/cbindgen.toml");
+
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!(
+        "cargo:rerun-if-changed={}",
+        crate_dir.join("src/ffi.rs").display()
+    );
+    println!("cargo:rerun-if-env-changed=EDGEFIRST_SCHEMAS_HEADER");
+
+    // Only the ffi module: the message modules' associated constants
+    // (`STATUS_OK`, `ERROR`, ...) are not part of the C API.
+    let ffi_rs = crate_dir.join("src/ffi.rs");
+    let source = std::fs::read_to_string(&ffi_rs).expect("read src/ffi.rs");
+    config.after_includes = Some(macro_declarations(&source));
+    let bindings = cbindgen::Builder::new()
+        .with_src(&ffi_rs)
+        .with_config(config)
+        .generate()
+        .expect("cbindgen failed to parse src/ffi.rs");
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("edgefirst_schemas.h");
+    bindings.write_to_file(&out);
+    if let Some(dest) = env::var_os("EDGEFIRST_SCHEMAS_HEADER") {
+        let dest = PathBuf::from(dest);
+        if let Some(dir) = dest.parent() {
+            std::fs::create_dir_all(dir).expect("create header directory");
+        }
+        bindings.write_to_file(&dest);
+    }
+
+    #[cfg(feature = "csharp")]
+    generate_csharp(&bindings);
+}
+
+/// Write `EdgeFirstSchemas.g.cs` P/Invoke bindings, translated from the
+/// generated header, to `$OUT_DIR` and to `$EDGEFIRST_SCHEMAS_CSHARP` when
+/// that is set.
+#[cfg(feature = "csharp")]
+fn generate_csharp(bindings: &cbindgen::Bindings) {
+    use std::path::PathBuf;
+
+    println!("cargo:rerun-if-changed=csharp.rs");
+    println!("cargo:rerun-if-env-changed=EDGEFIRST_SCHEMAS_CSHARP");
+
+    let mut header = Vec::new();
+    bindings.write(&mut header);
+    let source = csharp::generate(&String::from_utf8(header).expect("UTF-8 header"));
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("EdgeFirstSchemas.g.cs");
+    std::fs::write(&out, &source).expect("write EdgeFirstSchemas.g.cs");
+    if let Some(dest) = env::var_os("EDGEFIRST_SCHEMAS_CSHARP") {
+        let dest = PathBuf::from(dest);
+        if let Some(dir) = dest.parent() {
+            std::fs::create_dir_all(dir).expect("create C# output directory");
+        }
+        std::fs::write(&dest, &source).expect("write C# bindings");
+    }
+}
+
+/// cbindgen does not expand `macro_rules!`, so declare the functions and
+/// handles that src/ffi.rs generates with `impl_simple_stamped!`,
+/// `impl_as_cdr!`, `impl_clone!`, `impl_equals!`, `impl_retain!`,
+/// `impl_getter!`, `impl_fixed_codec!` and the `impl_*serialized_size!`
+/// macros from their invocations.
+#[cfg(feature = "header")]
+fn macro_declarations(source: &str) -> String {
+    let mut types = String::new();
+    let mut fns = String::new();
+    for args in macro_invocations(source, "impl_simple_stamped!") {
+        let [handle, _, from_cdr, free, sec, nanosec, frame_id] = args.as_slice() else {
+            panic!("impl_simple_stamped! takes 7 arguments: {args:?}");
+        };
+        types += &format!("typedef struct {handle} {handle};\n");
+        fns += &format!(
+            "{handle}* {from_cdr}(const uint8_t* data, size_t len);\n\
+             void {free}({handle}* view);\n\
+             int32_t {sec}(const {handle}* view);\n\
+             uint32_t {nanosec}(const {handle}* view);\n\
+             const char* {frame_id}(const {handle}* view);\n"
+        );
+    }
+    for args in macro_invocations(source, "impl_as_cdr!") {
+        let [name, handle] = args.as_slice() else {
+            panic!("impl_as_cdr! takes 2 arguments: {args:?}");
+        };
+        // cbindgen's own typedef comes later; C11 allows repeating it.
+        types += &format!("typedef struct {handle} {handle};\n");
+        fns += &format!("const uint8_t* {name}(const {handle}* view, size_t* out_len);\n");
+    }
+    for args in macro_invocations(source, "impl_clone!") {
+        let [name, handle, _, _] = args.as_slice() else {
+            panic!("impl_clone! takes 4 arguments: {args:?}");
+        };
+        types += &format!("typedef struct {handle} {handle};\n");
+        fns += &format!("{handle}* {name}(const {handle}* view);\n");
+    }
+    for args in macro_invocations(source, "impl_equals!") {
+        let [name, handle, _, _] = args.as_slice() else {
+            panic!("impl_equals! takes 4 arguments: {args:?}");
+        };
+        types += &format!("typedef struct {handle} {handle};\n");
+        fns += &format!("int32_t {name}(const {handle}* a, const {handle}* b);\n");
+    }
+    for args in macro_invocations(source, "impl_retain!") {
+        let [retain, release, handle, _] = args.as_slice() else {
+            panic!("impl_retain! takes 4 arguments: {args:?}");
+        };
+        types += &format!("typedef struct {handle} {handle};\n");
+        fns += &format!(
+            "{handle}* {retain}({handle}* view);\n\
+             void {release}({handle}* view);\n"
+        );
+    }
+    for args in macro_invocations(source, "impl_serialized_size!") {
+        let [name, handle, _] = args.as_slice() else {
+            panic!("impl_serialized_size! takes 3 arguments: {args:?}");
+        };
+        types += &format!("typedef struct {handle} {handle};\n");
+        fns += &format!("int32_t {name}(const {handle}* view, size_t* out);\n");
+    }
+    for args in macro_invocations(source, "impl_builder_serialized_size!") {
+        let [name, handle, _] = args.as_slice() else {
+            panic!("impl_builder_serialized_size! takes 3 arguments: {args:?}");
+        };
+        types += &format!("typedef struct {handle} {handle};\n");
+        fns += &format!("int32_t {name}(const {handle}* b, size_t* out);\n");
+    }
+    for args in macro_invocations(source, "impl_getter!") {
+        let [name, handle, ret, _] = args.as_slice() else {
+            panic!("impl_getter! takes 4 arguments: {args:?}");
+        };
+        types += &format!("typedef struct {handle} {handle};\n");
+        fns += &format!("{} {name}(const {handle}* view);\n", c_type(ret));
+    }
+    for args in macro_invocations(source, "impl_fixed_codec!") {
+        let [encode, decode, fields @ ..] = args.as_slice() else {
+            panic!("impl_fixed_codec! takes encode, decode, type and fields: {args:?}");
+        };
+        let fields: Vec<(&str, &str)> = fields[1..]
+            .iter()
+            .map(|f| {
+                let (name, ty) = f.split_once(':').expect("field is `name: type`");
+                (name.trim(), c_type(ty.trim()))
+            })
+            .collect();
+        let params = |ptr: &str| {
+            fields
+                .iter()
+                .map(|(name, ty)| format!(", {ty}{ptr} {name}"))
+                .collect::<String>()
+        };
+        fns += &format!(
+            "int32_t {encode}(uint8_t* buf, size_t cap, size_t* written{});\n\
+             int32_t {decode}(const uint8_t* data, size_t len{});\n",
+            params(""),
+            params("*")
+        );
+    }
+    format!(
+        "\n{types}\n#ifdef __cplusplus\nextern \"C\" {{\n#endif\n\n{fns}\n\
+         #ifdef __cplusplus\n}}\n#endif"
+    )
+}
+
+/// The C type of a scalar Rust type in a macro invocation.
+#[cfg(feature = "header")]
+fn c_type(rust: &str) -> &'static str {
+    match rust {
+        "bool" => "bool",
+        "u8" => "uint8_t",
+        "u16" => "uint16_t",
+        "u32" => "uint32_t",
+        "i8" => "int8_t",
+        "i16" => "int16_t",
+        "i32" => "int32_t",
+        "f32" => "float",
+        "f64" => "double",
+        _ => panic!("no C type for `{rust}`"),
+    }
+}
+
+/// Comma-separated arguments of every top-level `name(...)` invocation.
+#[cfg(feature = "header")]
+fn macro_invocations(source: &str, name: &str) -> Vec<Vec<String>> {
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with(name))
+        .map(|(i, _)| {
+            let rest: String = source.lines().skip(i).collect::<Vec<_>>().join(" ");
+            let open = rest.find('(').unwrap() + 1;
+            let close = rest.find(");").expect("unterminated macro invocation");
+            rest[open..close]
+                .split(',')
+                .map(|arg| arg.trim().to_string())
+                .filter(|arg| !arg.is_empty())
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,379 @@
+// SPDX-FileCopyrightText: Copyright 2026 Au-Zone Technologies
+// SPDX-License-Identifier: Apache-2.0
+
+//! C# P/Invoke bindings derived from the cbindgen header (the `csharp`
+//! feature). Names follow the C API one to one; handle types become
+//! `SafeHandle` subclasses that call the matching `_free` on dispose.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// A parsed C declaration: `[const] base *...* name`.
+struct CDecl {
+    is_const: bool,
+    base: String,
+    ptr: usize,
+    name: String,
+    fn_ptr: bool,
+}
+
+struct Function {
+    ret: CDecl,
+    params: Vec<CDecl>,
+}
+
+/// Translate the generated C header into `EdgeFirstSchemas.g.cs`.
+pub fn generate(header: &str) -> String {
+    let text = strip_comments(header);
+    let (text, structs) = take_structs(&text);
+
+    let mut consts: Vec<(String, String)> = Vec::new();
+    let mut body = String::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if let Some(define) = line.strip_prefix("#define ") {
+            if let Some((name, value)) = define.trim().split_once(char::is_whitespace) {
+                if is_const_expr(value.trim(), &consts) {
+                    consts.push((name.to_string(), value.trim().to_string()));
+                }
+            }
+        } else if !(line.starts_with('#') || line.starts_with("extern \"C\"") || line == "}") {
+            body.push_str(line);
+            body.push(' ');
+        }
+    }
+
+    let mut handles = BTreeSet::new();
+    let mut functions = BTreeMap::new();
+    let mut unbound = Vec::new();
+    for stmt in body.split(';') {
+        let stmt = stmt.split_whitespace().collect::<Vec<_>>().join(" ");
+        if stmt.is_empty() {
+            continue;
+        }
+        if let Some(rest) = stmt.strip_prefix("typedef struct ") {
+            if let Some((name, _)) = rest.split_once(' ') {
+                handles.insert(name.to_string());
+            }
+        } else if stmt.starts_with("extern ") {
+            let decl = parse_decl(stmt.trim_start_matches("extern "));
+            unbound.push(decl.name.split('[').next().unwrap_or_default().to_string());
+        } else if let Some(open) = stmt.find('(') {
+            let ret = parse_decl(&stmt[..open]);
+            let inner = &stmt[open + 1..stmt.rfind(')').expect("unbalanced declaration")];
+            let params = split_params(inner)
+                .into_iter()
+                .filter(|p| p != "void")
+                .map(|p| parse_decl(&p))
+                .collect();
+            functions
+                .entry(ret.name.clone())
+                .or_insert(Function { ret, params });
+        }
+    }
+
+    let mut out = String::new();
+    out += "// <auto-generated>\n\
+            // C# P/Invoke bindings for libedgefirst_schemas, generated by the\n\
+            // edgefirst-schemas-capi build script (`csharp` feature) from the cbindgen\n\
+            // header. Do not edit; see CAPI.md for the documented API.\n\
+            // </auto-generated>\n\n\
+            using System;\n\
+            using System.Runtime.InteropServices;\n\n\
+            namespace EdgeFirst.Schemas\n{\n";
+
+    out += "    public static unsafe partial class EdgeFirstSchemas\n    {\n";
+    out += "        public const string Library = \"edgefirst_schemas\";\n\n";
+    for (name, value) in &consts {
+        let _ = writeln!(out, "        public const int {name} = {value};");
+    }
+    if !consts.is_empty() {
+        out += "\n";
+    }
+    out += "        /// <summary>Copy a borrowed UTF-8 C string into a managed string.</summary>\n\
+         \x20       public static string Utf8(IntPtr str) => Marshal.PtrToStringUTF8(str);\n";
+    for (name, f) in &functions {
+        out += "\n";
+        out += &function(name, f, &handles);
+    }
+    for name in &unbound {
+        let _ = write!(out, "\n        // Not bound: data symbol `{name}`.\n");
+    }
+    out += "    }\n";
+
+    for (name, fields) in &structs {
+        let _ = write!(
+            out,
+            "\n    [StructLayout(LayoutKind.Sequential)]\n    public unsafe struct {name}\n    {{\n"
+        );
+        for field in fields {
+            let _ = writeln!(
+                out,
+                "        public {} {};",
+                field_type(field, &handles),
+                ident(&field.name)
+            );
+        }
+        out += "    }\n";
+    }
+
+    for handle in &handles {
+        let stem = handle.strip_suffix("_t").unwrap_or(handle);
+        let free = format!("{stem}_free");
+        let release = if functions.contains_key(&free) {
+            format!("EdgeFirstSchemas.{free}(handle); ")
+        } else {
+            String::new()
+        };
+        let _ = write!(
+            out,
+            "\n    /// <summary>Owned <c>{handle}*</c>; disposing calls <c>{free}</c>.</summary>\n\
+             \x20   public sealed class {handle} : SafeHandle\n    {{\n\
+             \x20       public {handle}() : base(IntPtr.Zero, true) {{ }}\n\n\
+             \x20       private {handle}(IntPtr ptr) : base(IntPtr.Zero, false) {{ SetHandle(ptr); }}\n\n\
+             \x20       /// <summary>Wrap a borrowed pointer without taking ownership.</summary>\n\
+             \x20       public static {handle} Borrow(IntPtr ptr) => new {handle}(ptr);\n\n\
+             \x20       public override bool IsInvalid => handle == IntPtr.Zero;\n\n\
+             \x20       protected override bool ReleaseHandle() {{ {release}return true; }}\n    }}\n"
+        );
+    }
+    out += "}\n";
+    out
+}
+
+/// One `[DllImport]` declaration. `_free`/`_release` take the raw pointer
+/// and stay internal: the handle classes call them from `ReleaseHandle`.
+fn function(name: &str, f: &Function, handles: &BTreeSet<String>) -> String {
+    let releases = (name.ends_with("_free") || name.ends_with("_release"))
+        && f.params.len() == 1
+        && f.params[0].ptr == 1
+        && handles.contains(&f.params[0].base);
+    let params = f
+        .params
+        .iter()
+        .map(|p| {
+            let ty = if releases {
+                "IntPtr".to_string()
+            } else {
+                param_type(p, handles)
+            };
+            format!("{ty} {}", ident(&p.name))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut out =
+        String::from("        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]\n");
+    let ret = &f.ret;
+    let ret_type = if ret.ptr == 0 && ret.base == "bool" {
+        out += "        [return: MarshalAs(UnmanagedType.U1)]\n";
+        "bool".to_string()
+    } else if ret.ptr == 1 && handles.contains(&ret.base) && !ret.is_const {
+        ret.base.clone()
+    } else if ret.ptr == 1 && (ret.base == "char" || handles.contains(&ret.base)) {
+        // Borrowed: a string or a view tied to its parent's lifetime.
+        "IntPtr".to_string()
+    } else {
+        plain_type(ret, handles)
+    };
+    let vis = if releases { "internal" } else { "public" };
+    let _ = writeln!(
+        out,
+        "        {vis} static extern {ret_type} {name}({params});"
+    );
+    out
+}
+
+fn param_type(p: &CDecl, handles: &BTreeSet<String>) -> String {
+    if p.fn_ptr {
+        "IntPtr".to_string()
+    } else if p.ptr == 0 && p.base == "bool" {
+        "[MarshalAs(UnmanagedType.U1)] bool".to_string()
+    } else if p.ptr == 1 && p.base == "char" && p.is_const {
+        "[MarshalAs(UnmanagedType.LPUTF8Str)] string".to_string()
+    } else if p.ptr == 1 && handles.contains(&p.base) {
+        p.base.clone()
+    } else {
+        plain_type(p, handles)
+    }
+}
+
+fn field_type(f: &CDecl, handles: &BTreeSet<String>) -> String {
+    if f.ptr == 0 && f.base == "bool" {
+        // C# `bool` has no fixed layout inside a marshalled struct.
+        "byte".to_string()
+    } else {
+        plain_type(f, handles)
+    }
+}
+
+/// Blittable mapping: scalars by width, pointers as unsafe `T*`.
+fn plain_type(d: &CDecl, handles: &BTreeSet<String>) -> String {
+    let (base, ptr) = if handles.contains(&d.base) && d.ptr > 0 {
+        ("IntPtr", d.ptr - 1)
+    } else {
+        let base = match d.base.as_str() {
+            "int8_t" => "sbyte",
+            "uint8_t" | "char" => "byte",
+            "int16_t" => "short",
+            "uint16_t" => "ushort",
+            "int32_t" | "int" => "int",
+            "uint32_t" => "uint",
+            "int64_t" => "long",
+            "uint64_t" => "ulong",
+            "size_t" => "nuint",
+            "float" | "double" | "void" | "bool" => d.base.as_str(),
+            other => other,
+        };
+        (base, d.ptr)
+    };
+    format!("{base}{}", "*".repeat(ptr))
+}
+
+fn parse_decl(decl: &str) -> CDecl {
+    if let Some(start) = decl.find("(*") {
+        let name = decl[start + 2..].split(')').next().unwrap_or_default();
+        return CDecl {
+            is_const: false,
+            base: "void".into(),
+            ptr: 1,
+            name: name.trim().to_string(),
+            fn_ptr: true,
+        };
+    }
+    let ptr = decl.matches('*').count();
+    let spaced = decl.replace('*', " ");
+    let mut tokens: Vec<&str> = spaced
+        .split_whitespace()
+        .filter(|t| *t != "struct")
+        .collect();
+    let is_const = tokens.first() == Some(&"const");
+    tokens.retain(|t| *t != "const");
+    let name = tokens.pop().unwrap_or_default().to_string();
+    CDecl {
+        is_const,
+        base: tokens.join(" "),
+        ptr,
+        name,
+        fn_ptr: false,
+    }
+}
+
+/// Split a parameter list on top-level commas (function pointer
+/// parameters carry their own parenthesised lists).
+fn split_params(inner: &str) -> Vec<String> {
+    let mut params = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in inner.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                params.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        params.push(current.trim().to_string());
+    }
+    params
+}
+
+fn strip_comments(header: &str) -> String {
+    let mut out = String::new();
+    let mut rest = header;
+    while let Some(start) = rest.find("/*") {
+        out += &rest[..start];
+        rest = rest[start..]
+            .find("*/")
+            .map_or("", |end| &rest[start + end + 2..]);
+    }
+    out += rest;
+    out.lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Remove `typedef struct X { ... } X;` definitions, returning their fields.
+fn take_structs(text: &str) -> (String, Vec<(String, Vec<CDecl>)>) {
+    let mut out = String::new();
+    let mut structs = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("typedef struct ") {
+        let Some(brace) = rest[start..].find(['{', ';']).map(|i| start + i) else {
+            break;
+        };
+        if rest.as_bytes()[brace] == b';' {
+            out += &rest[..=brace];
+            rest = &rest[brace + 1..];
+            continue;
+        }
+        let name = rest[start + "typedef struct ".len()..brace]
+            .trim()
+            .to_string();
+        let close = brace + rest[brace..].find('}').expect("unterminated struct");
+        let fields = rest[brace + 1..close]
+            .split(';')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .map(parse_decl)
+            .collect();
+        structs.push((name, fields));
+        out += &rest[..start];
+        rest = &rest[close + rest[close..].find(';').expect("struct typedef name") + 1..];
+    }
+    out += rest;
+    (out, structs)
+}
+
+/// `#define`s that are integer literals, or expressions over earlier ones.
+fn is_const_expr(value: &str, consts: &[(String, String)]) -> bool {
+    let idents = value
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|t| !t.is_empty());
+    let mut any = false;
+    for token in idents {
+        any = true;
+        if !(token.chars().all(|c| c.is_ascii_digit()) || consts.iter().any(|(n, _)| n == token)) {
+            return false;
+        }
+    }
+    any && value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "_()<>| &+-".contains(c))
+}
+
+/// Escape C parameter names that are C# keywords.
+fn ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "base",
+        "checked",
+        "decimal",
+        "default",
+        "event",
+        "fixed",
+        "in",
+        "internal",
+        "is",
+        "lock",
+        "namespace",
+        "new",
+        "object",
+        "operator",
+        "out",
+        "params",
+        "ref",
+        "string",
+        "this",
+    ];
+    if KEYWORDS.contains(&name) {
+        format!("@{name}")
+    } else {
+        name.to_string()
+    }
+}
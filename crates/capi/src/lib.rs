@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! C library build of `edgefirst-schemas`.
+//!
+//! The C API is implemented in the `edgefirst-schemas` crate (the `ffi`
+//! feature); this crate exists only to emit it as `libedgefirst_schemas.so`
+//! and `libedgefirst_schemas.a`. See `CAPI.md` for the API itself.
+
+// Nothing references the crate by path; link it so its `#[no_mangle]`
+// exports end up in the library.
+extern crate edgefirst_schemas as _;
@@ -0,0 +1,354 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! `#[derive(EdgeFirstMessage)]` for message types defined outside
+//! `edgefirst-schemas`.
+//!
+//! Enable the `derive` feature of `edgefirst-schemas` and use it as
+//! `edgefirst_schemas::EdgeFirstMessage`; the generated code refers to that
+//! crate by name.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr, Type};
+
+/// Make a struct a CDR message of a user-defined schema.
+///
+/// The struct's named fields, in order, are the message's fields; each
+/// field type must implement `edgefirst_schemas::custom::CdrValue`: ROS
+/// primitives (`bool`, `i8`…`u64`, `f32`, `f64`), `String`, `Vec<T>`
+/// sequences, `[T; N]` arrays, fixed-size built-in messages such as
+/// `geometry_msgs::Point`, `std_msgs::Header<Vec<u8>>`, and other derived
+/// messages. Field names should follow the ROS snake_case convention.
+///
+/// The derive implements `SchemaType` (the schema name), `CdrValue`,
+/// `CustomMessage` (CDR encoding, the `.msg` definition and registration)
+/// and the type-erased `message::Message`, plus inherent `to_cdr` and
+/// `from_cdr` like the built-in messages have.
+///
+/// # Attributes
+///
+/// - `#[edgefirst(schema = "pkg/msg/Type")]` (required): the schema name.
+/// - `#[edgefirst(ffi = "prefix")]`: also export C functions
+///   `prefix_register()` and, for each primitive field,
+///   `prefix_get_<field>(data, len, out)` reading it from CDR bytes. They
+///   return 0, or -1 with `errno` set (`EINVAL` for NULL arguments or a
+///   failed registration, `EBADMSG` for bytes that do not decode). Needs
+///   the `ffi` feature of `edgefirst-schemas`.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::custom::CustomMessage;
+/// use edgefirst_schemas::geometry_msgs::Point;
+/// use edgefirst_schemas::schema_registry::definition;
+/// use edgefirst_schemas::std_msgs::Header;
+/// use edgefirst_schemas_derive::EdgeFirstMessage;
+///
+/// #[derive(EdgeFirstMessage)]
+/// #[edgefirst(schema = "acme_msgs/msg/Target")]
+/// struct Target {
+///     header: Header<Vec<u8>>,
+///     position: Point,
+///     label: String,
+///     scores: Vec<f32>,
+/// }
+///
+/// Target::register().unwrap();
+/// assert_eq!(
+///     definition("acme_msgs/msg/Target"),
+///     Some("std_msgs/Header header\ngeometry_msgs/Point position\nstring label\nfloat32[] scores\n")
+/// );
+///
+/// let target = Target {
+///     header: Header::builder().frame_id("map").build().unwrap(),
+///     position: Point { x: 1.0, y: 2.0, z: 0.0 },
+///     label: "crate".into(),
+///     scores: vec![0.9],
+/// };
+/// let back = Target::from_cdr(&target.to_cdr()).unwrap();
+/// assert_eq!((back.header.frame_id(), back.label.as_str()), ("map", "crate"));
+/// ```
+#[proc_macro_derive(EdgeFirstMessage, attributes(edgefirst))]
+pub fn derive_message(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Container attributes, `#[edgefirst(schema = "...", ffi = "...")]`.
+struct Options {
+    schema: LitStr,
+    ffi: Option<LitStr>,
+}
+
+fn parse_options(input: &DeriveInput) -> syn::Result<Options> {
+    let mut schema = None;
+    let mut ffi = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("edgefirst"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("schema") {
+                schema = Some(meta.value()?.parse::<LitStr>()?);
+            } else if meta.path.is_ident("ffi") {
+                ffi = Some(meta.value()?.parse::<LitStr>()?);
+            } else {
+                return Err(meta.error("expected `schema` or `ffi`"));
+            }
+            Ok(())
+        })?;
+    }
+    let schema = schema.ok_or_else(|| {
+        syn::Error::new(
+            Span::call_site(),
+            "missing #[edgefirst(schema = \"pkg/msg/Type\")]",
+        )
+    })?;
+    Ok(Options { schema, ffi })
+}
+
+/// `pkg/msg/Type` as written in another message's definition, `pkg/Type`.
+fn msg_type(schema: &LitStr) -> syn::Result<String> {
+    let name = schema.value();
+    match name.split_once("/msg/") {
+        Some((package, ty))
+            if !package.is_empty()
+                && !ty.is_empty()
+                && !package.contains('/')
+                && !ty.contains('/') =>
+        {
+            Ok(format!("{package}/{ty}"))
+        }
+        _ => Err(syn::Error::new(
+            schema.span(),
+            "schema name must have the form \"pkg/msg/Type\"",
+        )),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let options = parse_options(input)?;
+    let msg_type = msg_type(&options.schema)?;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "EdgeFirstMessage cannot be derived for generic types",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "EdgeFirstMessage needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "EdgeFirstMessage can only be derived for structs",
+            ))
+        }
+    };
+
+    let krate = quote!(::edgefirst_schemas);
+    let name = &input.ident;
+    let schema = &options.schema;
+    let idents: Vec<&Ident> = fields.iter().filter_map(|f| f.ident.as_ref()).collect();
+    let names: Vec<String> = idents
+        .iter()
+        .map(|i| i.to_string().trim_start_matches("r#").to_owned())
+        .collect();
+    let types: Vec<&Type> = fields.iter().map(|f| &f.ty).collect();
+
+    let ffi = match &options.ffi {
+        Some(prefix) => ffi_accessors(name, prefix, &idents, &names, &types),
+        None => TokenStream2::new(),
+    };
+
+    Ok(quote! {
+        impl #krate::schema_registry::SchemaType for #name {
+            const SCHEMA_NAME: &'static str = #schema;
+        }
+
+        impl #krate::custom::CdrValue for #name {
+            fn msg_type() -> ::std::string::String {
+                ::std::string::String::from(#msg_type)
+            }
+
+            fn read_value(
+                cursor: &mut #krate::cdr::CdrCursor<'_>,
+            ) -> ::core::result::Result<Self, #krate::cdr::CdrError> {
+                ::core::result::Result::Ok(Self {
+                    #(#idents: <#types as #krate::custom::CdrValue>::read_value(cursor)
+                        .map_err(|e| e.in_field(#names))?,)*
+                })
+            }
+
+            fn write_value(&self, writer: &mut #krate::cdr::CdrWriter<'_>) {
+                #(#krate::custom::CdrValue::write_value(&self.#idents, writer);)*
+            }
+
+            fn size_value(&self, sizer: &mut #krate::cdr::CdrSizer) {
+                #(#krate::custom::CdrValue::size_value(&self.#idents, sizer);)*
+            }
+
+            fn register_nested() -> ::core::result::Result<(), #krate::schema_registry::RegisterError> {
+                <Self as #krate::custom::CustomMessage>::register()
+            }
+        }
+
+        impl #krate::custom::CustomMessage for #name {
+            fn definition() -> &'static str {
+                static DEFINITION: #krate::custom::__private::OnceLock<::std::string::String> =
+                    #krate::custom::__private::OnceLock::new();
+                DEFINITION.get_or_init(|| {
+                    let mut text = ::std::string::String::new();
+                    #(
+                        text.push_str(&<#types as #krate::custom::CdrValue>::msg_type());
+                        text.push(' ');
+                        text.push_str(#names);
+                        text.push('\n');
+                    )*
+                    text
+                })
+            }
+
+            fn register_fields() -> ::core::result::Result<(), #krate::schema_registry::RegisterError> {
+                #(<#types as #krate::custom::CdrValue>::register_nested()?;)*
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        // Inherent like the built-in messages' own, so calls need no
+        // disambiguation between `CustomMessage` and `Message`.
+        impl #name {
+            /// CDR bytes, encapsulation header included.
+            pub fn to_cdr(&self) -> ::std::vec::Vec<u8> {
+                #krate::custom::CustomMessage::to_cdr(self)
+            }
+
+            /// Decode a complete CDR message, in either byte order.
+            pub fn from_cdr(cdr: &[u8]) -> ::core::result::Result<Self, #krate::cdr::CdrError> {
+                <Self as #krate::custom::CustomMessage>::from_cdr(cdr)
+            }
+        }
+
+        impl #krate::message::Message for #name {
+            fn schema_name(&self) -> &'static str {
+                #schema
+            }
+
+            fn to_cdr(&self) -> ::std::vec::Vec<u8> {
+                #krate::custom::CustomMessage::to_cdr(self)
+            }
+
+            fn clone_box(&self) -> ::std::boxed::Box<dyn #krate::message::Message> {
+                // Through CDR, as for the built-in views: fields such as
+                // `Header` are not `Clone`.
+                ::std::boxed::Box::new(
+                    Self::from_cdr(&self.to_cdr())
+                        .expect(::core::concat!("a valid ", #schema, " decodes again")),
+                )
+            }
+
+            fn as_any(&self) -> &dyn ::core::any::Any {
+                self
+            }
+        }
+
+        #ffi
+    })
+}
+
+/// Types with a C equivalent a getter can write through `out`.
+const PRIMITIVES: &[&str] = &[
+    "bool", "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "f32", "f64",
+];
+
+fn is_primitive(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) if p.qself.is_none() => p
+            .path
+            .get_ident()
+            .is_some_and(|i| PRIMITIVES.contains(&i.to_string().as_str())),
+        _ => false,
+    }
+}
+
+fn ffi_accessors(
+    name: &Ident,
+    prefix: &LitStr,
+    idents: &[&Ident],
+    names: &[String],
+    types: &[&Type],
+) -> TokenStream2 {
+    let krate = quote!(::edgefirst_schemas);
+    let private = quote!(#krate::custom::__private);
+    let schema = quote!(<#name as #krate::schema_registry::SchemaType>::SCHEMA_NAME);
+    let register = format_ident!("{}_register", prefix.value(), span = prefix.span());
+    let register_doc = format!("Register `{name}`'s schema. Returns 0, or -1 with errno EINVAL.");
+
+    let getters = idents
+        .iter()
+        .zip(names)
+        .zip(types)
+        .filter(|(_, ty)| is_primitive(ty))
+        .map(|((ident, field), ty)| {
+            let getter = format_ident!("{}_get_{}", prefix.value(), field, span = prefix.span());
+            let doc = format!(
+                "Read `{field}` of a CDR-encoded `{name}` into `*out`. Returns 0, or -1 with \
+                 errno EINVAL (NULL argument) or EBADMSG (bytes that do not decode)."
+            );
+            quote! {
+                #[doc = #doc]
+                ///
+                /// # Safety
+                ///
+                /// `data` must point to `len` readable bytes and `out` must be
+                /// valid for writes.
+                // `unsafe(...)` so the attribute is accepted in every edition.
+                #[unsafe(no_mangle)]
+                pub unsafe extern "C" fn #getter(data: *const u8, len: usize, out: *mut #ty) -> i32 {
+                    if data.is_null() || out.is_null() {
+                        #private::set_error(#private::EINVAL, "null argument");
+                        return -1;
+                    }
+                    let cdr = unsafe { ::core::slice::from_raw_parts(data, len) };
+                    match <#name as #krate::custom::CustomMessage>::from_cdr(cdr) {
+                        ::core::result::Result::Ok(msg) => {
+                            unsafe { *out = msg.#ident };
+                            0
+                        }
+                        ::core::result::Result::Err(e) => {
+                            #private::set_error(#private::EBADMSG, ::core::format_args!("{}: {}", #schema, e));
+                            -1
+                        }
+                    }
+                }
+            }
+        });
+
+    quote! {
+        #[doc = #register_doc]
+        #[unsafe(no_mangle)]
+        pub extern "C" fn #register() -> i32 {
+            match <#name as #krate::custom::CustomMessage>::register() {
+                ::core::result::Result::Ok(()) => 0,
+                ::core::result::Result::Err(e) => {
+                    #private::set_error(#private::EINVAL, e);
+                    -1
+                }
+            }
+        }
+
+        #(#getters)*
+    }
+}
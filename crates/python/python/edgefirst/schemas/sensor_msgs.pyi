@@ -4,7 +4,9 @@
 """Type stubs for ``edgefirst.schemas.sensor_msgs``."""
 
 from __future__ import annotations
-from typing import List, Optional, Sequence
+from typing import Dict, List, Optional, Sequence
+
+import numpy as np
 
 from . import BorrowedBuf, BufferLike
 from .builtin_interfaces import Time
@@ -25,6 +27,7 @@ __all__ = [
     "PointField",
     "RegionOfInterest",
     "Temperature",
+    "decode_pcd",
 ]
 
 
@@ -290,6 +293,22 @@ class PointCloud2:
     def __repr__(self) -> str: ...
 
 
+def decode_pcd(cloud: PointCloud2) -> Dict[str, np.ndarray]:
+    """Decode every field of ``cloud`` into a numpy array in the field's
+    own dtype (``datatype=7`` → ``float32``, ``2`` → ``uint8``, …): 1-D
+    with one element per point, or shaped ``(N, count)`` for fields whose
+    ``count`` is not 1.
+
+    Raises ``ValueError`` for big-endian clouds or fields that do not fit
+    in ``point_step``.
+
+    ::
+
+        pts = decode_pcd(PointCloud2.from_cdr(payload))
+        xyz = np.stack([pts["x"], pts["y"], pts["z"]], axis=1)
+    """
+
+
 class Imu:
     """``sensor_msgs.Imu`` — orientation, angular velocity and linear
     acceleration each with a 3×3 row-major covariance matrix.
@@ -56,19 +56,21 @@ use edgefirst_schemas::mavros_msgs::{
 };
 use edgefirst_schemas::nav_msgs::Odometry;
 use edgefirst_schemas::rosgraph_msgs::Clock;
+use edgefirst_schemas::sensor_msgs::pointcloud::{DynPointCloud, PointFieldType};
 use edgefirst_schemas::sensor_msgs::{
     BatteryState, CameraInfo, CompressedImage, FluidPressure, Image, Imu, MagneticField, NavSatFix,
     NavSatStatus, PointCloud2, PointFieldView, RegionOfInterest, Temperature,
 };
 use edgefirst_schemas::std_msgs::{ColorRGBA, Header};
 
+use numpy::{PyArray1, PyArrayMethods};
 #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
 use pyo3::exceptions::PyBufferError;
 use pyo3::exceptions::PyValueError;
 #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
 use pyo3::ffi::{self, Py_buffer};
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyType};
+use pyo3::types::{PyBytes, PyDict, PyType};
 
 // ── Error mapping ────────────────────────────────────────────────────
 
@@ -2151,6 +2153,53 @@ impl PyPointCloud2 {
     }
 }
 
+/// Decode a `PointCloud2` into `{field name: numpy array}` in each field's
+/// own dtype (`x`/`y`/`z` as float32, `intensity` as uint8, …): 1-D with
+/// one element per point, or `(N, count)` for fields whose `count` is not 1.
+#[pyfunction]
+fn decode_pcd<'py>(py: Python<'py>, cloud: &PyPointCloud2) -> PyResult<Bound<'py, PyDict>> {
+    let points = DynPointCloud::from_pointcloud2(&cloud.inner)
+        .map_err(|e| PyValueError::new_err(format!("point cloud error: {e}")))?;
+    let out = PyDict::new(py);
+    for f in points.fields() {
+        // Read `count` consecutive elements per point; `from_pointcloud2`
+        // checked they fit in `point_step`.
+        let count = f.count as usize;
+        macro_rules! gather {
+            ($t:ty) => {{
+                const SIZE: usize = std::mem::size_of::<$t>();
+                let mut values = Vec::with_capacity(points.len() * count);
+                for p in points.iter() {
+                    let data = p.data();
+                    for k in 0..count {
+                        let at = f.byte_offset as usize + k * SIZE;
+                        let bytes: [u8; SIZE] = data[at..at + SIZE].try_into().unwrap();
+                        values.push(<$t>::from_le_bytes(bytes));
+                    }
+                }
+                let array = PyArray1::from_vec(py, values);
+                if count == 1 {
+                    array.into_any()
+                } else {
+                    array.reshape([points.len(), count])?.into_any()
+                }
+            }};
+        }
+        let array = match f.field_type {
+            PointFieldType::Int8 => gather!(i8),
+            PointFieldType::Uint8 => gather!(u8),
+            PointFieldType::Int16 => gather!(i16),
+            PointFieldType::Uint16 => gather!(u16),
+            PointFieldType::Int32 => gather!(i32),
+            PointFieldType::Uint32 => gather!(u32),
+            PointFieldType::Float32 => gather!(f32),
+            PointFieldType::Float64 => gather!(f64),
+        };
+        out.set_item(f.name, array)?;
+    }
+    Ok(out)
+}
+
 // ── FoxgloveCompressedVideo (foxglove_msgs) ─────────────────────────
 
 /// `foxglove_msgs.CompressedVideo` — H.264/H.265 NAL units plus a format
@@ -6261,6 +6310,7 @@ fn schemas(m: &Bound<'_, PyModule>) -> PyResult<()> {
     sensor.add_class::<PyTemperature>()?;
     sensor.add_class::<PyCameraInfo>()?;
     sensor.add_class::<PyBatteryState>()?;
+    sensor.add_function(wrap_pyfunction!(decode_pcd, &sensor)?)?;
     m.add_submodule(&sensor)?;
     register_submodule(py, m, "sensor_msgs", &sensor)?;
 
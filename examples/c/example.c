@@ -8,6 +8,7 @@
  * Demonstrates:
  * - CdrFixed encode/decode (Time, Vector3) into stack buffers
  * - Buffer-backed encode (Header, Image) with allocated output
+ * - Builder encode (Detect) with parent-borrowed child box handles
  * - Buffer-backed decode from CDR bytes via opaque view handles
  * - Proper memory management (ros_bytes_free, _free, borrowed pointers)
  * - errno-based error handling
@@ -193,6 +194,10 @@ static int example_image(void) {
     return 0;
 }
 
+// DmaBuffer is deprecated in favour of CameraFrame but is still shipped
+// until 4.0.0; keep exercising it so the legacy ABI stays covered.
+#pragma GCC diagnostic push
+#pragma GCC diagnostic ignored "-Wdeprecated-declarations"
 static int example_dmabuffer(void) {
     printf("\n=== Example: DmaBuffer (buffer-backed) ===\n");
 
@@ -240,6 +245,76 @@ static int example_dmabuffer(void) {
     return 0;
 }
 
+#pragma GCC diagnostic pop
+
+static int example_detect(void) {
+    printf("\n=== Example: Detect (builder) ===\n");
+
+    ros_detect_box_elem_t boxes[2] = {
+        { .center_x = 0.25f, .center_y = 0.5f, .width = 0.1f, .height = 0.2f,
+          .label = "person", .score = 0.9f, .track_id = "t-1" },
+        { .center_x = 0.75f, .center_y = 0.5f, .width = 0.3f, .height = 0.4f,
+          .label = "car", .score = 0.6f, .track_id = "" },
+    };
+
+    ros_detect_builder_t* b = ros_detect_builder_new();
+    if (!b) {
+        fprintf(stderr, "ros_detect_builder_new failed: %s\n", strerror(errno));
+        return -1;
+    }
+    ros_detect_builder_set_stamp(b, 42, 7);
+    if (ros_detect_builder_set_frame_id(b, "camera") != 0
+        || ros_detect_builder_set_boxes(b, boxes, 2) != 0) {
+        fprintf(stderr, "ros_detect_builder setters failed: %s\n", strerror(errno));
+        ros_detect_builder_free(b);
+        return -1;
+    }
+
+    uint8_t* bytes = NULL;
+    size_t len = 0;
+    int rc = ros_detect_builder_build(b, &bytes, &len);
+    ros_detect_builder_free(b);
+    if (rc != 0) {
+        fprintf(stderr, "ros_detect_builder_build failed: %s\n", strerror(errno));
+        return -1;
+    }
+    printf("Encoded Detect: %zu CDR bytes\n", len);
+
+    ros_detect_t* det = ros_detect_from_cdr(bytes, len);
+    if (!det) {
+        fprintf(stderr, "ros_detect_from_cdr failed: %s\n", strerror(errno));
+        ros_bytes_free(bytes, len);
+        return -1;
+    }
+
+    // Child box handles are borrowed from `det` — do NOT free them
+    uint32_t n = ros_detect_get_boxes_len(det);
+    const ros_box_t* first = ros_detect_get_box(det, 0);
+    const ros_box_t* second = ros_detect_get_box(det, 1);
+
+    printf("Decoded: stamp=%d.%09u boxes=%u\n",
+           ros_detect_get_stamp_sec(det), ros_detect_get_stamp_nanosec(det), n);
+
+    int ok = (n == 2 && first && second
+              && strcmp(ros_detect_get_frame_id(det), "camera") == 0
+              && strcmp(ros_box_get_label(first), "person") == 0
+              && strcmp(ros_box_get_track_id(first), "t-1") == 0
+              && ros_box_get_score(first) == 0.9f
+              && strcmp(ros_box_get_label(second), "car") == 0
+              && ros_box_get_width(second) == 0.3f);
+
+    ros_detect_free(det);
+    ros_bytes_free(bytes, len);
+
+    if (!ok) {
+        fprintf(stderr, "Detect roundtrip mismatch\n");
+        return -1;
+    }
+
+    printf("Detect example completed successfully!\n");
+    return 0;
+}
+
 static int example_error_handling(void) {
     printf("\n=== Example: Error Handling ===\n");
 
@@ -293,6 +368,7 @@ int main(void) {
     if (example_header() != 0)         failures++;
     if (example_image() != 0)          failures++;
     if (example_dmabuffer() != 0)      failures++;
+    if (example_detect() != 0)         failures++;
     if (example_error_handling() != 0) failures++;
 
     printf("\n=================================\n");
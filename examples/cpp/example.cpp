@@ -167,6 +167,10 @@ static int example_image() {
     return 0;
 }
 
+// DmaBuffer is deprecated in favour of CameraFrame but is still shipped
+// until 4.0.0; keep exercising it so the legacy ABI stays covered.
+#pragma GCC diagnostic push
+#pragma GCC diagnostic ignored "-Wdeprecated-declarations"
 static int example_dmabuffer() {
     std::cout << "\n=== Example: DmaBuffer (buffer-backed) ===\n";
 
@@ -205,6 +209,7 @@ static int example_dmabuffer() {
     std::cout << "DmaBuffer example completed successfully!\n";
     return 0;
 }
+#pragma GCC diagnostic pop
 
 int main() {
     std::cout << "EdgeFirst Schemas C++ API Examples\n";
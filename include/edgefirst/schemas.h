@@ -31,7 +31,11 @@
  *   - `ros_<type>_get_<field>(handle)` -- O(1) field access
  *   - `ros_<type>_as_cdr(handle, &out_len)` -- borrow the raw CDR bytes
  *   - `ros_<type>_free(handle)` -- release the handle
- *   - `ros_<type>_encode(&out_bytes, &out_len, ...fields)` -- allocate + write CDR
+ *   - `ros_<type>_builder_encode_into(b, buf, cap, &out_len)` -- write CDR to
+ *     caller buffer; pass `buf = NULL` to query the required size (3.4.0+)
+ *   - `ros_<type>_builder_build(b, &out_bytes, &out_len)` -- allocate + write CDR
+ *   - `ros_<type>_encode(&out_bytes, &out_len, ...fields)` -- deprecated one-shot
+ *     encoder, removed in 4.0
  *
  * @section Error Handling
  *
@@ -43,6 +47,17 @@
  * - EINVAL: Invalid argument (NULL pointer, bad UTF-8, invalid parameter)
  * - ENOBUFS: Buffer too small (for CdrFixed encode with insufficient capacity)
  * - EBADMSG: Bad message (deserialization/decoding failure)
+ * - EIO: Internal error (a panic inside the library, reported instead of
+ *   aborting the process)
+ *
+ * NULL handles never abort: getters return a sentinel (0, 0.0, false, NULL,
+ * or -1 for file descriptors) and void setters do nothing, both setting
+ * errno to EINVAL. ros_<type>_free(NULL) is a no-op that leaves errno alone.
+ * A library built with the capi crate's `checked` feature also rejects, with
+ * EINVAL, handles of the wrong type and handles already freed.
+ *
+ * edgefirst_last_error_message() describes the last failure in words, e.g.
+ * which field failed to decode.
  *
  * Thread Safety: errno is thread-local in POSIX systems. Functions are thread-safe
  * for distinct message instances. Shared instances require external synchronization.
@@ -55,7 +70,8 @@
  * - Byte blob getters return `const uint8_t*` with an `out_len` parameter,
  *   also borrowing into the CDR buffer.
  * - Encode functions for buffer-backed types allocate output via `uint8_t**` and
- *   `size_t*`; callers must free with `ros_bytes_free()`.
+ *   `size_t*`; callers must free with `ros_bytes_free()`, or with their own
+ *   allocator after registering it through `edgefirst_set_allocator()`.
  *
  * **Parent-borrowed child handles.** Functions like ros_detect_get_box() and
  * ros_model_get_box() / ros_model_get_mask() return borrowed child handles whose
@@ -65,6 +81,14 @@
  * parent's underlying CDR buffer (passed to the parent's _from_cdr function)
  * must also remain valid for as long as the child pointers are used.
  *
+ * **Opaque-handle mode.** Builders also accept their array fields one element
+ * at a time through `ros_<type>_builder_add_<field>()`, which copies the
+ * element so the caller may free or reuse it immediately. Defining
+ * `EDGEFIRST_OPAQUE_HANDLES` before including this header hides the
+ * `ros_*_elem_t` descriptor structs and their `set_<field>()` array setters,
+ * so no struct layout is shared with the library; build the library with the
+ * capi crate's `opaque` feature to match.
+ *
  * @subsection Example
  * @code
  * #include <edgefirst/schemas.h>
@@ -111,6 +135,226 @@
 extern "C" {
 #endif
 
+/* ============================================================================
+ * ABI Version
+ * ========================================================================= */
+
+/**
+ * @brief C ABI major version this header describes.
+ *
+ * Bumped when a function is removed, a signature changes or a public struct
+ * layout changes. Matches the SONAME major of libedgefirst_schemas.so.
+ */
+#define EDGEFIRST_ABI_VERSION_MAJOR 3
+/** @brief C ABI minor version this header describes; bumped when functions are added. */
+#define EDGEFIRST_ABI_VERSION_MINOR 4
+/** @brief Packed ABI version, `(major << 16) | minor`. */
+#define EDGEFIRST_ABI_VERSION \
+    ((uint32_t) ((EDGEFIRST_ABI_VERSION_MAJOR << 16) | EDGEFIRST_ABI_VERSION_MINOR))
+
+/**
+ * @brief ABI version of the loaded library, `(major << 16) | minor`.
+ *
+ * Compare against EDGEFIRST_ABI_VERSION (or use edgefirst_abi_compatible())
+ * once at startup, before calling anything else.
+ */
+uint32_t edgefirst_abi_version(void);
+
+/**
+ * @brief NUL-terminated ABI tag embedded in the library, e.g. "EDGEFIRST_ABI 3.0".
+ *
+ * Lets tools identify the ABI of a library file with `strings`.
+ */
+extern const char EDGEFIRST_ABI_TAG[];
+
+/**
+ * @brief Check that the loaded library can serve code built against this header.
+ * @return true when the majors match and the library minor is at least the
+ *         header minor.
+ */
+static inline bool edgefirst_abi_compatible(void) {
+    uint32_t v = edgefirst_abi_version();
+    /* With equal majors, the packed comparison orders by minor. */
+    return (v >> 16) == EDGEFIRST_ABI_VERSION_MAJOR && v >= EDGEFIRST_ABI_VERSION;
+}
+
+/**
+ * @brief Crate version of the loaded library, e.g. "3.3.0" (3.4.0+).
+ *
+ * Static string, for logs and bug reports; check compatibility with
+ * edgefirst_abi_compatible().
+ */
+const char* edgefirst_schemas_version(void);
+
+/**
+ * @brief Whether the library was built with an optional feature (3.4.0+).
+ * @param name One of "json", "opaque", "checked", "shm", "zstd",
+ *             "blob-file", "blob-http"
+ * @return 1 if built in, 0 if not or if @p name is unknown to this library,
+ *         -1 with errno EINVAL for NULL
+ */
+int32_t edgefirst_schemas_has_feature(const char* name);
+
+/* ============================================================================
+ * Error Reporting
+ * ========================================================================= */
+
+/**
+ * @brief Description of the last failure on the calling thread (3.4.0+).
+ *
+ * Every function that sets errno also records a human-readable message,
+ * e.g. "CDR buffer too short: need 24 bytes, have 16" or "`data` is NULL".
+ * Successful calls leave it unchanged, so read it right after the failing
+ * call.
+ * @return Message valid until the next failure or edgefirst_clear_last_error()
+ *         on this thread, or NULL if there is none. Do not free.
+ */
+const char* edgefirst_last_error_message(void);
+
+/** @brief Forget the calling thread's last error message (3.4.0+). */
+void edgefirst_clear_last_error(void);
+
+/* ============================================================================
+ * Generic Message Handle
+ * ========================================================================= */
+
+/**
+ * @brief Type-erased message of any schema in the registry (3.4.0+).
+ *
+ * Keyed by schema name, e.g. "edgefirst_msgs/msg/Detect", and walked from
+ * the schema's message definition, so every supported type (including ones
+ * without dedicated ros_<type>_* functions) is reachable through the same
+ * five calls. The handle owns a copy of the CDR bytes.
+ */
+typedef struct edgefirst_message_t edgefirst_message_t;
+
+/**
+ * @brief Create a message of @p schema with every field zero, empty or false.
+ * @param schema Schema name, e.g. "sensor_msgs/msg/Imu"
+ * @return Handle (free with edgefirst_message_free()) or NULL with errno
+ *         EINVAL for a NULL or unknown schema name
+ */
+edgefirst_message_t* edgefirst_message_new(const char* schema);
+
+/**
+ * @brief Create a message of @p schema from CDR bytes.
+ *
+ * The bytes are checked against the schema's definition and copied; @p data
+ * may be freed after the call.
+ * @return Handle or NULL with errno EINVAL (NULL argument, unknown schema)
+ *         or EBADMSG (@p data does not decode as @p schema)
+ */
+edgefirst_message_t* edgefirst_message_deserialize(const char* schema,
+                                                   const uint8_t* data,
+                                                   size_t len);
+
+/**
+ * @brief Copy the message's CDR bytes into @p buf.
+ *
+ * Pass @p buf = NULL to query the required size via @p written.
+ * @return 0 on success, -1 with errno EINVAL (NULL handle) or ENOBUFS
+ *         (@p cap too small)
+ */
+int edgefirst_message_serialize(const edgefirst_message_t* msg,
+                                uint8_t* buf,
+                                size_t cap,
+                                size_t* written);
+
+/** @brief Borrow the message's CDR bytes; valid while the handle lives. */
+const uint8_t* edgefirst_message_as_cdr(const edgefirst_message_t* msg, size_t* out_len);
+
+/** @brief Schema name of the message; valid while the handle lives (NULL + EINVAL for NULL). */
+const char* edgefirst_message_schema(const edgefirst_message_t* msg);
+
+/**
+ * @brief Heap bytes owned by the message (CDR bytes and schema name) (3.4.0+).
+ *
+ * For budgeting and monitoring per-topic memory. Returns 0 with errno
+ * EINVAL for NULL.
+ */
+size_t edgefirst_message_heap_size(const edgefirst_message_t* msg);
+
+/** @brief Free a message handle. NULL is a no-op. */
+void edgefirst_message_free(edgefirst_message_t* msg);
+
+/**
+ * @brief Deep-copy a message handle (3.4.0+).
+ * @return New handle (free with edgefirst_message_free()) or NULL with errno
+ *         EINVAL for NULL
+ */
+edgefirst_message_t* edgefirst_message_clone(const edgefirst_message_t* msg);
+
+/**
+ * @brief Compare two messages field by field (3.4.0+).
+ *
+ * Byte order and alignment padding are ignored; floats compare by value.
+ * @return 1 if both have the same schema and field values, 0 if not, -1
+ *         with errno EINVAL for NULL or EBADMSG if either does not decode
+ */
+int32_t edgefirst_message_equals(const edgefirst_message_t* a, const edgefirst_message_t* b);
+
+/**
+ * @brief Add a reference to a message handle (3.4.0+).
+ *
+ * Each reference is dropped by edgefirst_message_free() or its alias
+ * edgefirst_message_release(); the last one destroys the handle.
+ * @return msg, or NULL with errno EINVAL for NULL
+ */
+edgefirst_message_t* edgefirst_message_retain(edgefirst_message_t* msg);
+
+/** @brief Alias of edgefirst_message_free() (3.4.0+). */
+void edgefirst_message_release(edgefirst_message_t* msg);
+
+/**
+ * @brief Length of the message's CDR bytes (3.4.0+).
+ * @return 0, or -1 with errno EINVAL for NULL msg or out
+ */
+int32_t edgefirst_message_serialized_size(const edgefirst_message_t* msg, size_t* out);
+
+/**
+ * @brief Render the message as NUL-terminated JSON (3.4.0+).
+ *
+ * Field names follow the message definition; byte arrays are arrays of
+ * numbers. @p written receives the length excluding the NUL, so @p cap must
+ * be at least *written + 1. Pass buf=NULL to query the length.
+ * @return 0 on success, -1 on error (errno EINVAL for NULL msg or written,
+ *         ENOBUFS if cap is too small, EBADMSG if the message does not
+ *         transcode)
+ */
+int edgefirst_message_to_json(const edgefirst_message_t* msg, char* buf, size_t cap,
+                              size_t* written);
+
+/**
+ * @brief Create a message of @p schema from a JSON object (3.4.0+).
+ *
+ * Missing fields are written as zero or empty; unknown fields are rejected.
+ * @param schema Schema name, e.g. "sensor_msgs/msg/Imu"
+ * @param json NUL-terminated JSON text
+ * @return Handle (free with edgefirst_message_free()) or NULL with errno
+ *         EINVAL (NULL argument or unknown schema) or EBADMSG (bad JSON or
+ *         a field that does not match; see edgefirst_last_error_message())
+ */
+edgefirst_message_t* edgefirst_message_from_json(const char* schema, const char* json);
+
+/* ============================================================================
+ * Schema Registry
+ * ========================================================================= */
+
+/**
+ * @brief Full message definition of @p schema (3.4.0+).
+ *
+ * The schema's .msg text followed by every type it nests, each after a line
+ * of 80 '=' and a "MSG: package/Type" line: the data of an MCAP schema
+ * record with encoding edgefirst_schema_encoding().
+ * @param schema Schema name, e.g. "sensor_msgs/msg/Image"
+ * @return NUL-terminated text owned by the library and valid until the
+ *         process exits, or NULL with errno EINVAL for a NULL or unknown name
+ */
+const char* edgefirst_schema_definition(const char* schema);
+
+/** @brief Encoding of edgefirst_schema_definition() text, "ros2msg" (3.4.0+). */
+const char* edgefirst_schema_encoding(void);
+
 /* ============================================================================
  * Opaque Handle Types (buffer-backed views)
  * ========================================================================= */
@@ -142,6 +386,8 @@ typedef struct ros_twist_stamped_t ros_twist_stamped_t;
 typedef struct ros_accel_stamped_t ros_accel_stamped_t;
 /** @brief Opaque buffer-backed view handle for geometry_msgs::PointStamped. */
 typedef struct ros_point_stamped_t ros_point_stamped_t;
+/** @brief Opaque buffer-backed view handle for geometry_msgs::PoseStamped. */
+typedef struct ros_pose_stamped_t ros_pose_stamped_t;
 /** @brief Opaque buffer-backed view handle for geometry_msgs::InertiaStamped. */
 typedef struct ros_inertia_stamped_t ros_inertia_stamped_t;
 
@@ -227,6 +473,30 @@ typedef struct ros_camera_plane_t ros_camera_plane_t;
  */
 void ros_bytes_free(uint8_t* bytes, size_t len);
 
+/**
+ * @brief Same as ros_bytes_free(), under the edgefirst_ prefix (3.4.0+).
+ */
+void edgefirst_bytes_free(uint8_t* bytes, size_t len);
+
+/**
+ * @brief Allocate every returned byte buffer with the host's allocator
+ *        (3.4.0+).
+ *
+ * After registration, buffers from *_encode() and *_builder_build() come
+ * from malloc_fn and ros_bytes_free() / edgefirst_bytes_free() release
+ * them with free_fn, so they may also be passed to free_fn directly.
+ * A zero-length buffer is NULL with length 0; malloc_fn is not called.
+ * Register once at startup, before the first buffer is returned.
+ *
+ * @param malloc_fn Allocation function, e.g. malloc
+ * @param free_fn Matching release function, e.g. free
+ * @return 0 on success, -1 on error (errno: EINVAL if either function is
+ *         NULL, EBUSY if an allocator is already registered or a buffer
+ *         was already returned)
+ */
+int edgefirst_set_allocator(void* (*malloc_fn)(size_t),
+                            void (*free_fn)(void*));
+
 /* ============================================================================
  * builtin_interfaces - Time (CdrFixed)
  * ========================================================================= */
@@ -261,6 +531,22 @@ int ros_time_encode(uint8_t* buf, size_t cap, size_t* written,
 int ros_time_decode(const uint8_t* data, size_t len,
                     int32_t* sec, uint32_t* nanosec);
 
+/** Clocks for ros_time_set_now() and ros_header_set_stamp_now(). */
+#define ROS_CLOCK_REALTIME  0 /**< Wall-clock time since the Unix epoch */
+#define ROS_CLOCK_MONOTONIC 1 /**< Time since boot, never stepped (unix only) */
+
+/**
+ * @brief Read the current time of a clock as a Time (3.4.0+).
+ * @param sec Receives seconds (may be NULL)
+ * @param nanosec Receives nanoseconds (may be NULL)
+ * @param clock ROS_CLOCK_REALTIME or ROS_CLOCK_MONOTONIC
+ * @return 0 on success, -1 on error
+ *
+ * @par Errors (errno):
+ * - EINVAL: Unknown clock, or one the platform does not have
+ */
+int ros_time_set_now(int32_t* sec, uint32_t* nanosec, int32_t clock);
+
 /* ============================================================================
  * builtin_interfaces - Duration (CdrFixed)
  * ========================================================================= */
@@ -584,6 +870,16 @@ ros_header_t* ros_header_from_cdr(const uint8_t* data, size_t len);
  * @param view Handle to free (NULL is safe)
  */
 void ros_header_free(ros_header_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_header_free() (3.4.0+). */
+ros_header_t* ros_header_clone(const ros_header_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_header_equals(const ros_header_t* a, const ros_header_t* b);
+/** @brief Add a reference, dropped by ros_header_free() or _release() (3.4.0+). */
+ros_header_t* ros_header_retain(ros_header_t* view);
+/** @brief Alias of ros_header_free() (3.4.0+). */
+void ros_header_release(ros_header_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_header_serialized_size(const ros_header_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_header_get_stamp_sec(const ros_header_t* view);
@@ -657,12 +953,18 @@ int  ros_header_builder_build(ros_header_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_header_builder_encode_into(ros_header_builder_t* b,
                                     uint8_t* buf, size_t cap,
                                     size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_header_builder_serialized_size(const ros_header_builder_t* b, size_t* out);
 
 /* ============================================================================
  * sensor_msgs - Image (buffer-backed)
@@ -678,6 +980,16 @@ ros_image_t* ros_image_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free an Image view handle. */
 void ros_image_free(ros_image_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_image_free() (3.4.0+). */
+ros_image_t* ros_image_clone(const ros_image_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_image_equals(const ros_image_t* a, const ros_image_t* b);
+/** @brief Add a reference, dropped by ros_image_free() or _release() (3.4.0+). */
+ros_image_t* ros_image_retain(ros_image_t* view);
+/** @brief Alias of ros_image_free() (3.4.0+). */
+void ros_image_release(ros_image_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_image_serialized_size(const ros_image_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_image_get_stamp_sec(const ros_image_t* view);
@@ -711,6 +1023,30 @@ uint32_t ros_image_get_step(const ros_image_t* view);
  */
 const uint8_t* ros_image_get_data(const ros_image_t* view, size_t* out_len);
 
+/**
+ * @brief Lock the pixel data for a long-lived borrow (3.4.0+).
+ *
+ * For bindings that hand the payload to a garbage-collected buffer object
+ * (numpy array, Java ByteBuffer) instead of copying it. While any lock is
+ * held, ros_image_free() only marks the handle; the last
+ * ros_image_data_unlock() destroys it along with its bytes.
+ *
+ * Only a handle from ros_image_clone() owns its bytes. One from
+ * ros_image_from_cdr() borrows the caller's buffer, which no lock can keep
+ * alive, so locking it fails.
+ * @param view Image handle from ros_image_clone()
+ * @param out_len Receives byte count
+ * @return Pointer to pixel data, or NULL with errno EINVAL for a NULL handle
+ *         or one that borrows its bytes
+ */
+const uint8_t* ros_image_data_lock(ros_image_t* view, size_t* out_len);
+
+/**
+ * @brief Release a ros_image_data_lock() borrow (3.4.0+).
+ * @return 0 on success, -1 with errno EINVAL for NULL or an unlocked handle
+ */
+int ros_image_data_unlock(ros_image_t* view);
+
 /** @brief Borrow raw CDR bytes from the handle. */
 const uint8_t* ros_image_as_cdr(const ros_image_t* view, size_t* out_len);
 
@@ -793,6 +1129,23 @@ void ros_image_builder_set_step(ros_image_builder_t* b, uint32_t v);
 int  ros_image_builder_set_data(ros_image_builder_t* b,
                                 const uint8_t* data, size_t len);
 
+/**
+ * @brief Set the data bulk byte sequence, transferring ownership of it to
+ *        the builder (3.4.0+).
+ *
+ * The builder calls free_fn(ctx) once it no longer needs @p data: on the
+ * next set_data / set_data_owned call or on ros_image_builder_free().
+ * This ties a V4L2 or GStreamer buffer's lifetime to the builder instead
+ * of the call site. build and encode_into copy the bytes into the CDR
+ * message; encode_parts leaves them in place.
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle, NULL
+ *         free_fn, or NULL pointer with non-zero len). On error free_fn is
+ *         not called and the caller keeps ownership.
+ */
+int  ros_image_builder_set_data_owned(ros_image_builder_t* b,
+                                      const uint8_t* data, size_t len,
+                                      void (*free_fn)(void* ctx), void* ctx);
+
 /**
  * @brief Allocate a fresh CDR buffer and encode the message.
  * @return 0 on success (out_bytes/out_len written; free via ros_bytes_free),
@@ -803,12 +1156,34 @@ int  ros_image_builder_build(ros_image_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_image_builder_encode_into(ros_image_builder_t* b,
                                    uint8_t* buf, size_t cap,
                                    size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_image_builder_serialized_size(const ros_image_builder_t* b, size_t* out);
+/**
+ * @brief Encode everything but the data bytes, for gather writes (3.4.0+).
+ *
+ * The CDR message is head ‖ data ‖ tail, where data is the buffer given to
+ * set_data / set_data_owned, unchanged: pass the three pieces to writev(),
+ * a zenoh ZBytes writer or any other scatter/gather sink and the pixels
+ * are never copied. head_len and tail_len always receive the sizes (a few
+ * hundred bytes at most); pass head = tail = NULL to query them. tail is
+ * empty for Image.
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL b, head_len or
+ *         tail_len or data over 4 GiB, ENOBUFS for a buffer too small,
+ *         EBADMSG for encoding error).
+ */
+int32_t ros_image_builder_encode_parts(ros_image_builder_t* b,
+                                       uint8_t* head, size_t head_cap, size_t* head_len,
+                                       uint8_t* tail, size_t tail_cap, size_t* tail_len);
 
 /* ============================================================================
  * sensor_msgs - CompressedImage (buffer-backed)
@@ -824,6 +1199,16 @@ ros_compressed_image_t* ros_compressed_image_from_cdr(const uint8_t* data, size_
 
 /** @brief Free a CompressedImage view handle. */
 void ros_compressed_image_free(ros_compressed_image_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_compressed_image_free() (3.4.0+). */
+ros_compressed_image_t* ros_compressed_image_clone(const ros_compressed_image_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_compressed_image_equals(const ros_compressed_image_t* a, const ros_compressed_image_t* b);
+/** @brief Add a reference, dropped by ros_compressed_image_free() or _release() (3.4.0+). */
+ros_compressed_image_t* ros_compressed_image_retain(ros_compressed_image_t* view);
+/** @brief Alias of ros_compressed_image_free() (3.4.0+). */
+void ros_compressed_image_release(ros_compressed_image_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_compressed_image_serialized_size(const ros_compressed_image_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_compressed_image_get_stamp_sec(const ros_compressed_image_t* view);
@@ -911,6 +1296,21 @@ int  ros_compressed_image_builder_set_format(
 int  ros_compressed_image_builder_set_data(ros_compressed_image_builder_t* b,
                                            const uint8_t* data, size_t len);
 
+/**
+ * @brief Set the data bulk byte sequence, transferring ownership of it to
+ *        the builder (3.4.0+).
+ *
+ * The builder calls free_fn(ctx) on the next set_data / set_data_owned
+ * call or on ros_compressed_image_builder_free(); see
+ * ros_image_builder_set_data_owned().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle, NULL
+ *         free_fn, or NULL pointer with non-zero len; ownership stays
+ *         with the caller).
+ */
+int  ros_compressed_image_builder_set_data_owned(ros_compressed_image_builder_t* b,
+                                                 const uint8_t* data, size_t len,
+                                                 void (*free_fn)(void* ctx), void* ctx);
+
 /**
  * @brief Allocate a fresh CDR buffer and encode the message.
  * @return 0 on success (out_bytes/out_len written; free via ros_bytes_free),
@@ -921,12 +1321,25 @@ int  ros_compressed_image_builder_build(ros_compressed_image_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_compressed_image_builder_encode_into(
     ros_compressed_image_builder_t* b, uint8_t* buf, size_t cap,
     size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_compressed_image_builder_serialized_size(const ros_compressed_image_builder_t* b, size_t* out);
+/**
+ * @brief Encode everything but the data bytes, for gather writes (3.4.0+).
+ * tail is empty. See ros_image_builder_encode_parts().
+ */
+int32_t ros_compressed_image_builder_encode_parts(ros_compressed_image_builder_t* b,
+    uint8_t* head, size_t head_cap, size_t* head_len,
+    uint8_t* tail, size_t tail_cap, size_t* tail_len);
 
 /* ============================================================================
  * foxglove_msgs - CompressedVideo (buffer-backed)
@@ -942,6 +1355,16 @@ ros_compressed_video_t* ros_compressed_video_from_cdr(const uint8_t* data, size_
 
 /** @brief Free a CompressedVideo view handle. */
 void ros_compressed_video_free(ros_compressed_video_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_compressed_video_free() (3.4.0+). */
+ros_compressed_video_t* ros_compressed_video_clone(const ros_compressed_video_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_compressed_video_equals(const ros_compressed_video_t* a, const ros_compressed_video_t* b);
+/** @brief Add a reference, dropped by ros_compressed_video_free() or _release() (3.4.0+). */
+ros_compressed_video_t* ros_compressed_video_retain(ros_compressed_video_t* view);
+/** @brief Alias of ros_compressed_video_free() (3.4.0+). */
+void ros_compressed_video_release(ros_compressed_video_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_compressed_video_serialized_size(const ros_compressed_video_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_compressed_video_get_stamp_sec(const ros_compressed_video_t* view);
@@ -1063,6 +1486,16 @@ ros_dmabuffer_t* ros_dmabuffer_from_cdr(const uint8_t* data, size_t len);
 /** @brief Free a DmaBuffer view handle. */
 __attribute__((deprecated("Use CameraFrame (ros_camera_frame_t) instead; removed in 4.0.0.")))
 void ros_dmabuffer_free(ros_dmabuffer_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_dmabuffer_free() (3.4.0+). */
+ros_dmabuffer_t* ros_dmabuffer_clone(const ros_dmabuffer_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_dmabuffer_equals(const ros_dmabuffer_t* a, const ros_dmabuffer_t* b);
+/** @brief Add a reference, dropped by ros_dmabuffer_free() or _release() (3.4.0+). */
+ros_dmabuffer_t* ros_dmabuffer_retain(ros_dmabuffer_t* view);
+/** @brief Alias of ros_dmabuffer_free() (3.4.0+). */
+void ros_dmabuffer_release(ros_dmabuffer_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_dmabuffer_serialized_size(const ros_dmabuffer_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 __attribute__((deprecated("Use CameraFrame (ros_camera_frame_t) instead; removed in 4.0.0.")))
@@ -1146,6 +1579,16 @@ ros_camera_frame_t* ros_camera_frame_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a CameraFrame view handle. Safe to call with NULL. */
 void ros_camera_frame_free(ros_camera_frame_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_camera_frame_free() (3.4.0+). */
+ros_camera_frame_t* ros_camera_frame_clone(const ros_camera_frame_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_camera_frame_equals(const ros_camera_frame_t* a, const ros_camera_frame_t* b);
+/** @brief Add a reference, dropped by ros_camera_frame_free() or _release() (3.4.0+). */
+ros_camera_frame_t* ros_camera_frame_retain(ros_camera_frame_t* view);
+/** @brief Alias of ros_camera_frame_free() (3.4.0+). */
+void ros_camera_frame_release(ros_camera_frame_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_camera_frame_serialized_size(const ros_camera_frame_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t  ros_camera_frame_get_stamp_sec(const ros_camera_frame_t* view);
@@ -1238,6 +1681,16 @@ ros_imu_t* ros_imu_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free an Imu view handle. */
 void ros_imu_free(ros_imu_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_imu_free() (3.4.0+). */
+ros_imu_t* ros_imu_clone(const ros_imu_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_imu_equals(const ros_imu_t* a, const ros_imu_t* b);
+/** @brief Add a reference, dropped by ros_imu_free() or _release() (3.4.0+). */
+ros_imu_t* ros_imu_retain(ros_imu_t* view);
+/** @brief Alias of ros_imu_free() (3.4.0+). */
+void ros_imu_release(ros_imu_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_imu_serialized_size(const ros_imu_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_imu_get_stamp_sec(const ros_imu_t* view);
@@ -1377,12 +1830,18 @@ int  ros_imu_builder_build(ros_imu_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_imu_builder_encode_into(ros_imu_builder_t* b,
                                  uint8_t* buf, size_t cap,
                                  size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_imu_builder_serialized_size(const ros_imu_builder_t* b, size_t* out);
 
 /* ============================================================================
  * sensor_msgs - NavSatFix (buffer-backed)
@@ -1398,6 +1857,16 @@ ros_nav_sat_fix_t* ros_nav_sat_fix_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a NavSatFix view handle. */
 void ros_nav_sat_fix_free(ros_nav_sat_fix_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_nav_sat_fix_free() (3.4.0+). */
+ros_nav_sat_fix_t* ros_nav_sat_fix_clone(const ros_nav_sat_fix_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_nav_sat_fix_equals(const ros_nav_sat_fix_t* a, const ros_nav_sat_fix_t* b);
+/** @brief Add a reference, dropped by ros_nav_sat_fix_free() or _release() (3.4.0+). */
+ros_nav_sat_fix_t* ros_nav_sat_fix_retain(ros_nav_sat_fix_t* view);
+/** @brief Alias of ros_nav_sat_fix_free() (3.4.0+). */
+void ros_nav_sat_fix_release(ros_nav_sat_fix_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_nav_sat_fix_serialized_size(const ros_nav_sat_fix_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_nav_sat_fix_get_stamp_sec(const ros_nav_sat_fix_t* view);
@@ -1488,12 +1957,18 @@ int  ros_nav_sat_fix_builder_build(ros_nav_sat_fix_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_nav_sat_fix_builder_encode_into(ros_nav_sat_fix_builder_t* b,
                                          uint8_t* buf, size_t cap,
                                          size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_nav_sat_fix_builder_serialized_size(const ros_nav_sat_fix_builder_t* b, size_t* out);
 
 /* ============================================================================
  * geometry_msgs - TransformStamped (buffer-backed)
@@ -1509,6 +1984,16 @@ ros_transform_stamped_t* ros_transform_stamped_from_cdr(const uint8_t* data, siz
 
 /** @brief Free a TransformStamped view handle. */
 void ros_transform_stamped_free(ros_transform_stamped_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_transform_stamped_free() (3.4.0+). */
+ros_transform_stamped_t* ros_transform_stamped_clone(const ros_transform_stamped_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_transform_stamped_equals(const ros_transform_stamped_t* a, const ros_transform_stamped_t* b);
+/** @brief Add a reference, dropped by ros_transform_stamped_free() or _release() (3.4.0+). */
+ros_transform_stamped_t* ros_transform_stamped_retain(ros_transform_stamped_t* view);
+/** @brief Alias of ros_transform_stamped_free() (3.4.0+). */
+void ros_transform_stamped_release(ros_transform_stamped_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_transform_stamped_serialized_size(const ros_transform_stamped_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_transform_stamped_get_stamp_sec(const ros_transform_stamped_t* view);
@@ -1533,6 +2018,16 @@ const uint8_t* ros_transform_stamped_as_cdr(const ros_transform_stamped_t* view,
 ros_twist_stamped_t* ros_twist_stamped_from_cdr(const uint8_t* data, size_t len);
 /** @brief Free a TwistStamped view handle. */
 void ros_twist_stamped_free(ros_twist_stamped_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_twist_stamped_free() (3.4.0+). */
+ros_twist_stamped_t* ros_twist_stamped_clone(const ros_twist_stamped_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_twist_stamped_equals(const ros_twist_stamped_t* a, const ros_twist_stamped_t* b);
+/** @brief Add a reference, dropped by ros_twist_stamped_free() or _release() (3.4.0+). */
+ros_twist_stamped_t* ros_twist_stamped_retain(ros_twist_stamped_t* view);
+/** @brief Alias of ros_twist_stamped_free() (3.4.0+). */
+void ros_twist_stamped_release(ros_twist_stamped_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_twist_stamped_serialized_size(const ros_twist_stamped_t* view, size_t* out);
 /** @brief Get stamp seconds. */
 int32_t ros_twist_stamped_get_stamp_sec(const ros_twist_stamped_t* view);
 /** @brief Get stamp nanoseconds. */
@@ -1550,6 +2045,16 @@ const uint8_t* ros_twist_stamped_as_cdr(const ros_twist_stamped_t* view, size_t*
 ros_accel_stamped_t* ros_accel_stamped_from_cdr(const uint8_t* data, size_t len);
 /** @brief Free an AccelStamped view handle. */
 void ros_accel_stamped_free(ros_accel_stamped_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_accel_stamped_free() (3.4.0+). */
+ros_accel_stamped_t* ros_accel_stamped_clone(const ros_accel_stamped_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_accel_stamped_equals(const ros_accel_stamped_t* a, const ros_accel_stamped_t* b);
+/** @brief Add a reference, dropped by ros_accel_stamped_free() or _release() (3.4.0+). */
+ros_accel_stamped_t* ros_accel_stamped_retain(ros_accel_stamped_t* view);
+/** @brief Alias of ros_accel_stamped_free() (3.4.0+). */
+void ros_accel_stamped_release(ros_accel_stamped_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_accel_stamped_serialized_size(const ros_accel_stamped_t* view, size_t* out);
 /** @brief Get stamp seconds. */
 int32_t ros_accel_stamped_get_stamp_sec(const ros_accel_stamped_t* view);
 /** @brief Get stamp nanoseconds. */
@@ -1567,6 +2072,16 @@ const uint8_t* ros_accel_stamped_as_cdr(const ros_accel_stamped_t* view, size_t*
 ros_point_stamped_t* ros_point_stamped_from_cdr(const uint8_t* data, size_t len);
 /** @brief Free a PointStamped view handle. */
 void ros_point_stamped_free(ros_point_stamped_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_point_stamped_free() (3.4.0+). */
+ros_point_stamped_t* ros_point_stamped_clone(const ros_point_stamped_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_point_stamped_equals(const ros_point_stamped_t* a, const ros_point_stamped_t* b);
+/** @brief Add a reference, dropped by ros_point_stamped_free() or _release() (3.4.0+). */
+ros_point_stamped_t* ros_point_stamped_retain(ros_point_stamped_t* view);
+/** @brief Alias of ros_point_stamped_free() (3.4.0+). */
+void ros_point_stamped_release(ros_point_stamped_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_point_stamped_serialized_size(const ros_point_stamped_t* view, size_t* out);
 /** @brief Get stamp seconds. */
 int32_t ros_point_stamped_get_stamp_sec(const ros_point_stamped_t* view);
 /** @brief Get stamp nanoseconds. */
@@ -1576,6 +2091,49 @@ const char* ros_point_stamped_get_frame_id(const ros_point_stamped_t* view);
 /** @brief Borrow raw CDR bytes from the handle. */
 const uint8_t* ros_point_stamped_as_cdr(const ros_point_stamped_t* view, size_t* out_len);
 
+/* ============================================================================
+ * geometry_msgs - PoseStamped (buffer-backed)
+ * ========================================================================= */
+
+/** @brief Create a PoseStamped view from CDR bytes. */
+ros_pose_stamped_t* ros_pose_stamped_from_cdr(const uint8_t* data, size_t len);
+/** @brief Free a PoseStamped view handle. */
+void ros_pose_stamped_free(ros_pose_stamped_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_pose_stamped_free() (3.4.0+). */
+ros_pose_stamped_t* ros_pose_stamped_clone(const ros_pose_stamped_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_pose_stamped_equals(const ros_pose_stamped_t* a, const ros_pose_stamped_t* b);
+/** @brief Add a reference, dropped by ros_pose_stamped_free() or _release() (3.4.0+). */
+ros_pose_stamped_t* ros_pose_stamped_retain(ros_pose_stamped_t* view);
+/** @brief Alias of ros_pose_stamped_free() (3.4.0+). */
+void ros_pose_stamped_release(ros_pose_stamped_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_pose_stamped_serialized_size(const ros_pose_stamped_t* view, size_t* out);
+/** @brief Get stamp seconds. */
+int32_t ros_pose_stamped_get_stamp_sec(const ros_pose_stamped_t* view);
+/** @brief Get stamp nanoseconds. */
+uint32_t ros_pose_stamped_get_stamp_nanosec(const ros_pose_stamped_t* view);
+/** @brief Get frame_id (borrowed). */
+const char* ros_pose_stamped_get_frame_id(const ros_pose_stamped_t* view);
+/** @brief Get the pose position and orientation; NULL outputs are skipped (3.4.0+). */
+void ros_pose_stamped_get_pose(const ros_pose_stamped_t* view,
+                               double* px, double* py, double* pz,
+                               double* ox, double* oy, double* oz, double* ow);
+/** @brief Borrow raw CDR bytes from the handle. */
+const uint8_t* ros_pose_stamped_as_cdr(const ros_pose_stamped_t* view, size_t* out_len);
+/**
+ * @brief Encode a PoseStamped to CDR (3.4.0+).
+ * @param out_bytes Receives the allocated buffer; free with ros_bytes_free()
+ * @param out_len Receives the buffer length
+ * @param frame_id Frame ID string (NULL treated as "")
+ * @return 0 on success, -1 on error
+ */
+int32_t ros_pose_stamped_encode(uint8_t** out_bytes, size_t* out_len,
+                                int32_t stamp_sec, uint32_t stamp_nanosec,
+                                const char* frame_id,
+                                double px, double py, double pz,
+                                double ox, double oy, double oz, double ow);
+
 /* ============================================================================
  * geometry_msgs - InertiaStamped (buffer-backed)
  * ========================================================================= */
@@ -1584,6 +2142,16 @@ const uint8_t* ros_point_stamped_as_cdr(const ros_point_stamped_t* view, size_t*
 ros_inertia_stamped_t* ros_inertia_stamped_from_cdr(const uint8_t* data, size_t len);
 /** @brief Free an InertiaStamped view handle. */
 void ros_inertia_stamped_free(ros_inertia_stamped_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_inertia_stamped_free() (3.4.0+). */
+ros_inertia_stamped_t* ros_inertia_stamped_clone(const ros_inertia_stamped_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_inertia_stamped_equals(const ros_inertia_stamped_t* a, const ros_inertia_stamped_t* b);
+/** @brief Add a reference, dropped by ros_inertia_stamped_free() or _release() (3.4.0+). */
+ros_inertia_stamped_t* ros_inertia_stamped_retain(ros_inertia_stamped_t* view);
+/** @brief Alias of ros_inertia_stamped_free() (3.4.0+). */
+void ros_inertia_stamped_release(ros_inertia_stamped_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_inertia_stamped_serialized_size(const ros_inertia_stamped_t* view, size_t* out);
 /** @brief Get stamp seconds. */
 int32_t ros_inertia_stamped_get_stamp_sec(const ros_inertia_stamped_t* view);
 /** @brief Get stamp nanoseconds. */
@@ -1607,6 +2175,16 @@ ros_radar_cube_t* ros_radar_cube_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a RadarCube view handle. */
 void ros_radar_cube_free(ros_radar_cube_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_radar_cube_free() (3.4.0+). */
+ros_radar_cube_t* ros_radar_cube_clone(const ros_radar_cube_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_radar_cube_equals(const ros_radar_cube_t* a, const ros_radar_cube_t* b);
+/** @brief Add a reference, dropped by ros_radar_cube_free() or _release() (3.4.0+). */
+ros_radar_cube_t* ros_radar_cube_retain(ros_radar_cube_t* view);
+/** @brief Alias of ros_radar_cube_free() (3.4.0+). */
+void ros_radar_cube_release(ros_radar_cube_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_radar_cube_serialized_size(const ros_radar_cube_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_radar_cube_get_stamp_sec(const ros_radar_cube_t* view);
@@ -1636,6 +2214,18 @@ const uint8_t* ros_radar_cube_get_layout(const ros_radar_cube_t* view, size_t* o
  */
 const uint8_t* ros_radar_cube_get_cube_raw(const ros_radar_cube_t* view, size_t* out_len);
 
+/**
+ * @brief Lock the raw cube data for a long-lived borrow (3.4.0+); see
+ *        ros_image_data_lock().
+ */
+const uint8_t* ros_radar_cube_cube_lock(ros_radar_cube_t* view, size_t* out_len);
+
+/**
+ * @brief Release a ros_radar_cube_cube_lock() borrow (3.4.0+).
+ * @return 0 on success, -1 with errno EINVAL for NULL or an unlocked handle
+ */
+int ros_radar_cube_cube_unlock(ros_radar_cube_t* view);
+
 /** @brief Get cube length. */
 uint32_t ros_radar_cube_get_cube_len(const ros_radar_cube_t* view);
 
@@ -1659,6 +2249,16 @@ ros_radar_info_t* ros_radar_info_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a RadarInfo view handle. */
 void ros_radar_info_free(ros_radar_info_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_radar_info_free() (3.4.0+). */
+ros_radar_info_t* ros_radar_info_clone(const ros_radar_info_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_radar_info_equals(const ros_radar_info_t* a, const ros_radar_info_t* b);
+/** @brief Add a reference, dropped by ros_radar_info_free() or _release() (3.4.0+). */
+ros_radar_info_t* ros_radar_info_retain(ros_radar_info_t* view);
+/** @brief Alias of ros_radar_info_free() (3.4.0+). */
+void ros_radar_info_release(ros_radar_info_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_radar_info_serialized_size(const ros_radar_info_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_radar_info_get_stamp_sec(const ros_radar_info_t* view);
@@ -1701,6 +2301,16 @@ ros_detect_t* ros_detect_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a Detect view handle. */
 void ros_detect_free(ros_detect_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_detect_free() (3.4.0+). */
+ros_detect_t* ros_detect_clone(const ros_detect_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_detect_equals(const ros_detect_t* a, const ros_detect_t* b);
+/** @brief Add a reference, dropped by ros_detect_free() or _release() (3.4.0+). */
+ros_detect_t* ros_detect_retain(ros_detect_t* view);
+/** @brief Alias of ros_detect_free() (3.4.0+). */
+void ros_detect_release(ros_detect_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_detect_serialized_size(const ros_detect_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_detect_get_stamp_sec(const ros_detect_t* view);
@@ -1744,6 +2354,16 @@ ros_model_t* ros_model_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a Model view handle. */
 void ros_model_free(ros_model_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_model_free() (3.4.0+). */
+ros_model_t* ros_model_clone(const ros_model_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_model_equals(const ros_model_t* a, const ros_model_t* b);
+/** @brief Add a reference, dropped by ros_model_free() or _release() (3.4.0+). */
+ros_model_t* ros_model_retain(ros_model_t* view);
+/** @brief Alias of ros_model_free() (3.4.0+). */
+void ros_model_release(ros_model_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_model_serialized_size(const ros_model_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_model_get_stamp_sec(const ros_model_t* view);
@@ -1803,6 +2423,16 @@ ros_model_info_t* ros_model_info_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a ModelInfo view handle. */
 void ros_model_info_free(ros_model_info_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_model_info_free() (3.4.0+). */
+ros_model_info_t* ros_model_info_clone(const ros_model_info_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_model_info_equals(const ros_model_info_t* a, const ros_model_info_t* b);
+/** @brief Add a reference, dropped by ros_model_info_free() or _release() (3.4.0+). */
+ros_model_info_t* ros_model_info_retain(ros_model_info_t* view);
+/** @brief Alias of ros_model_info_free() (3.4.0+). */
+void ros_model_info_release(ros_model_info_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_model_info_serialized_size(const ros_model_info_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_model_info_get_stamp_sec(const ros_model_info_t* view);
@@ -1840,6 +2470,15 @@ uint32_t ros_model_info_get_labels_len(const ros_model_info_t* view);
 /** @brief Get label at index (zero-copy pointer into CDR buffer). Sets errno EINVAL if out of bounds. */
 const char* ros_model_info_get_label(const ros_model_info_t* view, uint32_t index);
 
+/**
+ * @brief Fill @p out with up to @p cap label pointers (3.4.0+).
+ *
+ * The pointers borrow the CDR buffer like ros_model_info_get_label().
+ * @return Total number of labels; pass out=NULL to query it
+ */
+uint32_t ros_model_info_get_labels(const ros_model_info_t* view,
+                                   const char** out, size_t cap);
+
 /** @brief Borrow raw CDR bytes from the handle. */
 const uint8_t* ros_model_info_as_cdr(const ros_model_info_t* view, size_t* out_len);
 
@@ -1857,6 +2496,16 @@ ros_point_cloud2_t* ros_point_cloud2_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a PointCloud2 view handle. */
 void ros_point_cloud2_free(ros_point_cloud2_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_point_cloud2_free() (3.4.0+). */
+ros_point_cloud2_t* ros_point_cloud2_clone(const ros_point_cloud2_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_point_cloud2_equals(const ros_point_cloud2_t* a, const ros_point_cloud2_t* b);
+/** @brief Add a reference, dropped by ros_point_cloud2_free() or _release() (3.4.0+). */
+ros_point_cloud2_t* ros_point_cloud2_retain(ros_point_cloud2_t* view);
+/** @brief Alias of ros_point_cloud2_free() (3.4.0+). */
+void ros_point_cloud2_release(ros_point_cloud2_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_point_cloud2_serialized_size(const ros_point_cloud2_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_point_cloud2_get_stamp_sec(const ros_point_cloud2_t* view);
@@ -1887,6 +2536,18 @@ uint32_t ros_point_cloud2_get_row_step(const ros_point_cloud2_t* view);
  */
 const uint8_t* ros_point_cloud2_get_data(const ros_point_cloud2_t* view, size_t* out_len);
 
+/**
+ * @brief Lock the point data for a long-lived borrow (3.4.0+); see
+ *        ros_image_data_lock().
+ */
+const uint8_t* ros_point_cloud2_data_lock(ros_point_cloud2_t* view, size_t* out_len);
+
+/**
+ * @brief Release a ros_point_cloud2_data_lock() borrow (3.4.0+).
+ * @return 0 on success, -1 with errno EINVAL for NULL or an unlocked handle
+ */
+int ros_point_cloud2_data_unlock(ros_point_cloud2_t* view);
+
 /** @brief Get is_dense flag (true if no invalid points). */
 bool ros_point_cloud2_get_is_dense(const ros_point_cloud2_t* view);
 
@@ -1899,6 +2560,62 @@ uint32_t ros_point_cloud2_get_fields_len(const ros_point_cloud2_t* view);
 /** @brief Borrow raw CDR bytes from the handle. */
 const uint8_t* ros_point_cloud2_as_cdr(const ros_point_cloud2_t* view, size_t* out_len);
 
+/* ----------------------------------------------------------------------------
+ * sensor_msgs - PointCloud2 decoding (3.4.0+)
+ * --------------------------------------------------------------------------*/
+
+/**
+ * @brief A PointCloud2 unpacked into one float column per field.
+ *
+ * Every field is converted from its stored datatype, so callers need not
+ * walk the PointField layout. The handle owns its columns and may outlive
+ * the view it was decoded from.
+ */
+typedef struct edgefirst_pcd_t edgefirst_pcd_t;
+
+/**
+ * @brief Decode every field of a PointCloud2 view (3.4.0+).
+ * @return Handle (free with edgefirst_pcd_free()) or NULL with errno EINVAL
+ *         (NULL view) or EBADMSG (big-endian data, unknown datatype, or a
+ *         field outside point_step)
+ */
+edgefirst_pcd_t* edgefirst_pcd_decode(const ros_point_cloud2_t* cloud);
+
+/** @brief Number of points (height * width). */
+size_t edgefirst_pcd_count(const edgefirst_pcd_t* pcd);
+
+/**
+ * @brief Column of field @p name, edgefirst_pcd_count() floats long.
+ * @return Pointer valid while the handle lives, or NULL with errno EINVAL
+ *         (NULL argument) or ENOENT (no such field)
+ */
+const float* edgefirst_pcd_field(const edgefirst_pcd_t* pcd, const char* name);
+
+/** @brief The x column, or NULL with errno ENOENT. */
+const float* edgefirst_pcd_x(const edgefirst_pcd_t* pcd);
+
+/** @brief The y column, or NULL with errno ENOENT. */
+const float* edgefirst_pcd_y(const edgefirst_pcd_t* pcd);
+
+/** @brief The z column, or NULL with errno ENOENT. */
+const float* edgefirst_pcd_z(const edgefirst_pcd_t* pcd);
+
+/**
+ * @brief The cluster_id column as integers, exact for any stored integer
+ *        type (the float column rounds above 2^24).
+ * @return Pointer valid while the handle lives, or NULL with errno ENOENT
+ */
+const uint32_t* edgefirst_pcd_cluster_id(const edgefirst_pcd_t* pcd);
+
+/** @brief Number of fields, for enumerating with edgefirst_pcd_field_name(). */
+size_t edgefirst_pcd_field_count(const edgefirst_pcd_t* pcd);
+
+/** @brief Name of field @p index in PointField order (NULL + EINVAL if out of range). */
+const char* edgefirst_pcd_field_name(const edgefirst_pcd_t* pcd, size_t index);
+
+/** @brief Free a decoded point cloud. NULL is a no-op. */
+void edgefirst_pcd_free(edgefirst_pcd_t* pcd);
+
 /* ----------------------------------------------------------------------------
  * sensor_msgs - PointField (builder, 3.2.0+)
  * --------------------------------------------------------------------------*/
@@ -1946,17 +2663,24 @@ int  ros_point_field_builder_build(ros_point_field_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_point_field_builder_encode_into(ros_point_field_builder_t* b,
                                          uint8_t* buf, size_t cap,
                                          size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_point_field_builder_serialized_size(const ros_point_field_builder_t* b, size_t* out);
 
 /* ----------------------------------------------------------------------------
  * sensor_msgs - PointCloud2 (builder, 3.2.0+)
  * --------------------------------------------------------------------------*/
 
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /**
  * @brief C-POD descriptor for one element of a PointCloud2 `fields` sequence.
  *
@@ -1975,6 +2699,7 @@ typedef struct ros_point_field_elem_s {
     uint8_t     datatype;
     uint32_t    count;
 } ros_point_field_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
 /** @brief Opaque builder handle for ros_point_cloud2_t messages. */
 typedef struct ros_point_cloud2_builder_s ros_point_cloud2_builder_t;
@@ -2009,6 +2734,7 @@ void ros_point_cloud2_builder_set_height(ros_point_cloud2_builder_t* b,
 void ros_point_cloud2_builder_set_width(ros_point_cloud2_builder_t* b,
                                         uint32_t v);
 
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /**
  * @brief Set the field descriptor array (BORROWED — both the array and
  *        each element's `name` pointer must remain valid until the next
@@ -2021,6 +2747,17 @@ void ros_point_cloud2_builder_set_width(ros_point_cloud2_builder_t* b,
 int  ros_point_cloud2_builder_set_fields(
     ros_point_cloud2_builder_t* b,
     const ros_point_field_elem_t* fields, size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/**
+ * @brief Append a copy of `field` to the field sequence (3.4.0+).
+ *
+ * Nothing is borrowed: `field` may be freed or reused right away.
+ * Discards an array passed to ros_point_cloud2_builder_set_fields().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
+ *         or NULL element builder).
+ */
+int  ros_point_cloud2_builder_add_field(
+    ros_point_cloud2_builder_t* b, const ros_point_field_builder_t* field);
 
 /** @brief Set the is_bigendian flag. */
 void ros_point_cloud2_builder_set_is_bigendian(
@@ -2043,6 +2780,21 @@ void ros_point_cloud2_builder_set_row_step(ros_point_cloud2_builder_t* b,
 int  ros_point_cloud2_builder_set_data(ros_point_cloud2_builder_t* b,
                                        const uint8_t* data, size_t len);
 
+/**
+ * @brief Set the data bulk byte sequence, transferring ownership of it to
+ *        the builder (3.4.0+).
+ *
+ * The builder calls free_fn(ctx) on the next set_data / set_data_owned
+ * call or on ros_point_cloud2_builder_free(); see
+ * ros_image_builder_set_data_owned().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle, NULL
+ *         free_fn, or NULL pointer with non-zero len; ownership stays
+ *         with the caller).
+ */
+int  ros_point_cloud2_builder_set_data_owned(ros_point_cloud2_builder_t* b,
+                                             const uint8_t* data, size_t len,
+                                             void (*free_fn)(void* ctx), void* ctx);
+
 /** @brief Set the is_dense flag (true iff no invalid points). */
 void ros_point_cloud2_builder_set_is_dense(ros_point_cloud2_builder_t* b,
                                            bool v);
@@ -2057,12 +2809,25 @@ int  ros_point_cloud2_builder_build(ros_point_cloud2_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_point_cloud2_builder_encode_into(ros_point_cloud2_builder_t* b,
                                           uint8_t* buf, size_t cap,
                                           size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_point_cloud2_builder_serialized_size(const ros_point_cloud2_builder_t* b, size_t* out);
+/**
+ * @brief Encode everything but the data bytes, for gather writes (3.4.0+).
+ * tail holds is_dense. See ros_image_builder_encode_parts().
+ */
+int32_t ros_point_cloud2_builder_encode_parts(ros_point_cloud2_builder_t* b,
+    uint8_t* head, size_t head_cap, size_t* head_len,
+    uint8_t* tail, size_t tail_cap, size_t* tail_len);
 
 /* ============================================================================
  * sensor_msgs - CameraInfo (buffer-backed)
@@ -2078,6 +2843,16 @@ ros_camera_info_t* ros_camera_info_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a CameraInfo view handle. */
 void ros_camera_info_free(ros_camera_info_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_camera_info_free() (3.4.0+). */
+ros_camera_info_t* ros_camera_info_clone(const ros_camera_info_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_camera_info_equals(const ros_camera_info_t* a, const ros_camera_info_t* b);
+/** @brief Add a reference, dropped by ros_camera_info_free() or _release() (3.4.0+). */
+ros_camera_info_t* ros_camera_info_retain(ros_camera_info_t* view);
+/** @brief Alias of ros_camera_info_free() (3.4.0+). */
+void ros_camera_info_release(ros_camera_info_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_camera_info_serialized_size(const ros_camera_info_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_camera_info_get_stamp_sec(const ros_camera_info_t* view);
@@ -2209,12 +2984,18 @@ int  ros_camera_info_builder_build(ros_camera_info_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_camera_info_builder_encode_into(ros_camera_info_builder_t* b,
                                          uint8_t* buf, size_t cap,
                                          size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_camera_info_builder_serialized_size(const ros_camera_info_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - Track (buffer-backed)
@@ -2230,6 +3011,16 @@ ros_track_t* ros_track_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a Track view handle. */
 void ros_track_free(ros_track_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_track_free() (3.4.0+). */
+ros_track_t* ros_track_clone(const ros_track_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_track_equals(const ros_track_t* a, const ros_track_t* b);
+/** @brief Add a reference, dropped by ros_track_free() or _release() (3.4.0+). */
+ros_track_t* ros_track_retain(ros_track_t* view);
+/** @brief Alias of ros_track_free() (3.4.0+). */
+void ros_track_release(ros_track_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_track_serialized_size(const ros_track_t* view, size_t* out);
 
 /** @brief Get track ID string (borrowed). */
 const char* ros_track_get_id(const ros_track_t* view);
@@ -2305,6 +3096,16 @@ ros_local_time_t* ros_local_time_from_cdr(const uint8_t* data, size_t len);
 
 /** @brief Free a LocalTime view handle. */
 void ros_local_time_free(ros_local_time_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_local_time_free() (3.4.0+). */
+ros_local_time_t* ros_local_time_clone(const ros_local_time_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_local_time_equals(const ros_local_time_t* a, const ros_local_time_t* b);
+/** @brief Add a reference, dropped by ros_local_time_free() or _release() (3.4.0+). */
+ros_local_time_t* ros_local_time_retain(ros_local_time_t* view);
+/** @brief Alias of ros_local_time_free() (3.4.0+). */
+void ros_local_time_release(ros_local_time_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_local_time_serialized_size(const ros_local_time_t* view, size_t* out);
 
 /** @brief Get stamp seconds. */
 int32_t ros_local_time_get_stamp_sec(const ros_local_time_t* view);
@@ -2355,6 +3156,64 @@ int32_t ros_twist_with_covariance_decode(
     double* ax, double* ay, double* az,
     double* covariance_out);
 
+/* =========================================================================
+ * Point32, Pose2D, ColorRGBA, RegionOfInterest, Clock, Inertia,
+ * MapMetaData  (CdrFixed, 3.4.0+)
+ * Encode/decode follow ros_vector3_*: buf NULL queries the size, and
+ * decode out-params may be NULL.
+ * =========================================================================
+ */
+int32_t ros_point32_encode(uint8_t* buf, size_t cap, size_t* written,
+                           float x, float y, float z);
+int32_t ros_point32_decode(const uint8_t* data, size_t len,
+                           float* x, float* y, float* z);
+
+int32_t ros_pose2d_encode(uint8_t* buf, size_t cap, size_t* written,
+                          double x, double y, double theta);
+int32_t ros_pose2d_decode(const uint8_t* data, size_t len,
+                          double* x, double* y, double* theta);
+
+/** std_msgs/ColorRGBA */
+int32_t ros_color_rgba_encode(uint8_t* buf, size_t cap, size_t* written,
+                              float r, float g, float b, float a);
+int32_t ros_color_rgba_decode(const uint8_t* data, size_t len,
+                              float* r, float* g, float* b, float* a);
+
+/** sensor_msgs/RegionOfInterest */
+int32_t ros_region_of_interest_encode(uint8_t* buf, size_t cap, size_t* written,
+                                      uint32_t x_offset, uint32_t y_offset,
+                                      uint32_t height, uint32_t width,
+                                      bool do_rectify);
+int32_t ros_region_of_interest_decode(const uint8_t* data, size_t len,
+                                      uint32_t* x_offset, uint32_t* y_offset,
+                                      uint32_t* height, uint32_t* width,
+                                      bool* do_rectify);
+
+/** rosgraph_msgs/Clock */
+int32_t ros_clock_encode(uint8_t* buf, size_t cap, size_t* written,
+                         int32_t sec, uint32_t nanosec);
+int32_t ros_clock_decode(const uint8_t* data, size_t len,
+                         int32_t* sec, uint32_t* nanosec);
+
+/** `inertia` holds ixx, ixy, ixz, iyy, iyz, izz (6 doubles). */
+int32_t ros_inertia_encode(uint8_t* buf, size_t cap, size_t* written,
+                           double m, double com_x, double com_y, double com_z,
+                           const double* inertia);
+int32_t ros_inertia_decode(const uint8_t* data, size_t len,
+                           double* m, double* com_x, double* com_y, double* com_z,
+                           double* inertia_out);
+
+/** nav_msgs/MapMetaData; the origin is px, py, pz, ox, oy, oz, ow. */
+int32_t ros_map_meta_data_encode(uint8_t* buf, size_t cap, size_t* written,
+                                 int32_t load_sec, uint32_t load_nanosec,
+                                 float resolution, uint32_t width, uint32_t height,
+                                 double px, double py, double pz,
+                                 double ox, double oy, double oz, double ow);
+int32_t ros_map_meta_data_decode(const uint8_t* data, size_t len,
+                                 int32_t* load_sec, uint32_t* load_nanosec,
+                                 float* resolution, uint32_t* width, uint32_t* height,
+                                 double* origin_out);
+
 /* =========================================================================
  * sensor_msgs/MagneticField  (buffer-backed, decode-only)
  * =========================================================================
@@ -2363,6 +3222,16 @@ typedef struct ros_magnetic_field_t ros_magnetic_field_t;
 
 ros_magnetic_field_t* ros_magnetic_field_from_cdr(const uint8_t* data, size_t len);
 void ros_magnetic_field_free(ros_magnetic_field_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_magnetic_field_free() (3.4.0+). */
+ros_magnetic_field_t* ros_magnetic_field_clone(const ros_magnetic_field_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_magnetic_field_equals(const ros_magnetic_field_t* a, const ros_magnetic_field_t* b);
+/** @brief Add a reference, dropped by ros_magnetic_field_free() or _release() (3.4.0+). */
+ros_magnetic_field_t* ros_magnetic_field_retain(ros_magnetic_field_t* view);
+/** @brief Alias of ros_magnetic_field_free() (3.4.0+). */
+void ros_magnetic_field_release(ros_magnetic_field_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_magnetic_field_serialized_size(const ros_magnetic_field_t* view, size_t* out);
 int32_t ros_magnetic_field_get_stamp_sec(const ros_magnetic_field_t* view);
 uint32_t ros_magnetic_field_get_stamp_nanosec(const ros_magnetic_field_t* view);
 const char* ros_magnetic_field_get_frame_id(const ros_magnetic_field_t* view);
@@ -2423,12 +3292,18 @@ int  ros_magnetic_field_builder_build(ros_magnetic_field_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_magnetic_field_builder_encode_into(
     ros_magnetic_field_builder_t* b, uint8_t* buf, size_t cap,
     size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_magnetic_field_builder_serialized_size(const ros_magnetic_field_builder_t* b, size_t* out);
 
 /* =========================================================================
  * sensor_msgs/FluidPressure  (buffer-backed, decode-only)
@@ -2438,6 +3313,16 @@ typedef struct ros_fluid_pressure_t ros_fluid_pressure_t;
 
 ros_fluid_pressure_t* ros_fluid_pressure_from_cdr(const uint8_t* data, size_t len);
 void ros_fluid_pressure_free(ros_fluid_pressure_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_fluid_pressure_free() (3.4.0+). */
+ros_fluid_pressure_t* ros_fluid_pressure_clone(const ros_fluid_pressure_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_fluid_pressure_equals(const ros_fluid_pressure_t* a, const ros_fluid_pressure_t* b);
+/** @brief Add a reference, dropped by ros_fluid_pressure_free() or _release() (3.4.0+). */
+ros_fluid_pressure_t* ros_fluid_pressure_retain(ros_fluid_pressure_t* view);
+/** @brief Alias of ros_fluid_pressure_free() (3.4.0+). */
+void ros_fluid_pressure_release(ros_fluid_pressure_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_fluid_pressure_serialized_size(const ros_fluid_pressure_t* view, size_t* out);
 int32_t ros_fluid_pressure_get_stamp_sec(const ros_fluid_pressure_t* view);
 uint32_t ros_fluid_pressure_get_stamp_nanosec(const ros_fluid_pressure_t* view);
 const char* ros_fluid_pressure_get_frame_id(const ros_fluid_pressure_t* view);
@@ -2492,12 +3377,18 @@ int  ros_fluid_pressure_builder_build(ros_fluid_pressure_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_fluid_pressure_builder_encode_into(ros_fluid_pressure_builder_t* b,
                                             uint8_t* buf, size_t cap,
                                             size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_fluid_pressure_builder_serialized_size(const ros_fluid_pressure_builder_t* b, size_t* out);
 
 /* =========================================================================
  * sensor_msgs/Temperature  (buffer-backed, decode-only)
@@ -2507,6 +3398,16 @@ typedef struct ros_temperature_t ros_temperature_t;
 
 ros_temperature_t* ros_temperature_from_cdr(const uint8_t* data, size_t len);
 void ros_temperature_free(ros_temperature_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_temperature_free() (3.4.0+). */
+ros_temperature_t* ros_temperature_clone(const ros_temperature_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_temperature_equals(const ros_temperature_t* a, const ros_temperature_t* b);
+/** @brief Add a reference, dropped by ros_temperature_free() or _release() (3.4.0+). */
+ros_temperature_t* ros_temperature_retain(ros_temperature_t* view);
+/** @brief Alias of ros_temperature_free() (3.4.0+). */
+void ros_temperature_release(ros_temperature_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_temperature_serialized_size(const ros_temperature_t* view, size_t* out);
 int32_t ros_temperature_get_stamp_sec(const ros_temperature_t* view);
 uint32_t ros_temperature_get_stamp_nanosec(const ros_temperature_t* view);
 const char* ros_temperature_get_frame_id(const ros_temperature_t* view);
@@ -2561,12 +3462,18 @@ int  ros_temperature_builder_build(ros_temperature_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_temperature_builder_encode_into(ros_temperature_builder_t* b,
                                          uint8_t* buf, size_t cap,
                                          size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_temperature_builder_serialized_size(const ros_temperature_builder_t* b, size_t* out);
 
 /* =========================================================================
  * sensor_msgs/BatteryState  (buffer-backed, decode-only)
@@ -2603,6 +3510,16 @@ typedef struct ros_battery_state_t ros_battery_state_t;
 
 ros_battery_state_t* ros_battery_state_from_cdr(const uint8_t* data, size_t len);
 void ros_battery_state_free(ros_battery_state_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_battery_state_free() (3.4.0+). */
+ros_battery_state_t* ros_battery_state_clone(const ros_battery_state_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_battery_state_equals(const ros_battery_state_t* a, const ros_battery_state_t* b);
+/** @brief Add a reference, dropped by ros_battery_state_free() or _release() (3.4.0+). */
+ros_battery_state_t* ros_battery_state_retain(ros_battery_state_t* view);
+/** @brief Alias of ros_battery_state_free() (3.4.0+). */
+void ros_battery_state_release(ros_battery_state_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_battery_state_serialized_size(const ros_battery_state_t* view, size_t* out);
 int32_t ros_battery_state_get_stamp_sec(const ros_battery_state_t* view);
 uint32_t ros_battery_state_get_stamp_nanosec(const ros_battery_state_t* view);
 const char* ros_battery_state_get_frame_id(const ros_battery_state_t* view);
@@ -2728,12 +3645,18 @@ int  ros_battery_state_builder_build(ros_battery_state_builder_t* b,
 
 /**
  * @brief Encode the message into a caller-owned buffer.
+ *
+ * out_len always receives the encoded size. Pass buf = NULL to query the
+ * size first (3.4.0+; earlier versions fail with EINVAL).
  * @return 0 on success (out_len written), -1 on error (errno: EINVAL for
- *         NULL args, ENOBUFS for buffer too small, EBADMSG for encoding error).
+ *         NULL b or out_len, ENOBUFS for buffer too small, EBADMSG for
+ *         encoding error).
  */
 int  ros_battery_state_builder_encode_into(
     ros_battery_state_builder_t* b, uint8_t* buf, size_t cap,
     size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_battery_state_builder_serialized_size(const ros_battery_state_builder_t* b, size_t* out);
 
 /* =========================================================================
  * nav_msgs/Odometry  (buffer-backed, decode-only)
@@ -2743,6 +3666,16 @@ typedef struct ros_odometry_t ros_odometry_t;
 
 ros_odometry_t* ros_odometry_from_cdr(const uint8_t* data, size_t len);
 void ros_odometry_free(ros_odometry_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_odometry_free() (3.4.0+). */
+ros_odometry_t* ros_odometry_clone(const ros_odometry_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_odometry_equals(const ros_odometry_t* a, const ros_odometry_t* b);
+/** @brief Add a reference, dropped by ros_odometry_free() or _release() (3.4.0+). */
+ros_odometry_t* ros_odometry_retain(ros_odometry_t* view);
+/** @brief Alias of ros_odometry_free() (3.4.0+). */
+void ros_odometry_release(ros_odometry_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_odometry_serialized_size(const ros_odometry_t* view, size_t* out);
 int32_t ros_odometry_get_stamp_sec(const ros_odometry_t* view);
 uint32_t ros_odometry_get_stamp_nanosec(const ros_odometry_t* view);
 const char* ros_odometry_get_frame_id(const ros_odometry_t* view);
@@ -2780,6 +3713,16 @@ typedef struct ros_vibration_t ros_vibration_t;
 
 ros_vibration_t* ros_vibration_from_cdr(const uint8_t* data, size_t len);
 void ros_vibration_free(ros_vibration_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_vibration_free() (3.4.0+). */
+ros_vibration_t* ros_vibration_clone(const ros_vibration_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_vibration_equals(const ros_vibration_t* a, const ros_vibration_t* b);
+/** @brief Add a reference, dropped by ros_vibration_free() or _release() (3.4.0+). */
+ros_vibration_t* ros_vibration_retain(ros_vibration_t* view);
+/** @brief Alias of ros_vibration_free() (3.4.0+). */
+void ros_vibration_release(ros_vibration_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_vibration_serialized_size(const ros_vibration_t* view, size_t* out);
 int32_t ros_vibration_get_stamp_sec(const ros_vibration_t* view);
 uint32_t ros_vibration_get_stamp_nanosec(const ros_vibration_t* view);
 const char* ros_vibration_get_frame_id(const ros_vibration_t* view);
@@ -2795,6 +3738,77 @@ uint32_t ros_vibration_get_clipping(const ros_vibration_t* view,
                                     uint32_t* out, size_t cap);
 const uint8_t* ros_vibration_as_cdr(const ros_vibration_t* view, size_t* out_len);
 
+/* =========================================================================
+ * sensor_msgs/LaserScan  (buffer-backed, decode-only, 3.4.0+)
+ * =========================================================================
+ */
+typedef struct ros_laser_scan_t ros_laser_scan_t;
+
+ros_laser_scan_t* ros_laser_scan_from_cdr(const uint8_t* data, size_t len);
+void ros_laser_scan_free(ros_laser_scan_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_laser_scan_free(). */
+ros_laser_scan_t* ros_laser_scan_clone(const ros_laser_scan_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error. */
+int32_t ros_laser_scan_equals(const ros_laser_scan_t* a, const ros_laser_scan_t* b);
+/** @brief Add a reference, dropped by ros_laser_scan_free() or _release(). */
+ros_laser_scan_t* ros_laser_scan_retain(ros_laser_scan_t* view);
+/** @brief Alias of ros_laser_scan_free(). */
+void ros_laser_scan_release(ros_laser_scan_t* view);
+/** @brief Length of the message's CDR bytes. */
+int32_t ros_laser_scan_serialized_size(const ros_laser_scan_t* view, size_t* out);
+int32_t ros_laser_scan_get_stamp_sec(const ros_laser_scan_t* view);
+uint32_t ros_laser_scan_get_stamp_nanosec(const ros_laser_scan_t* view);
+const char* ros_laser_scan_get_frame_id(const ros_laser_scan_t* view);
+float ros_laser_scan_get_angle_min(const ros_laser_scan_t* view);
+float ros_laser_scan_get_angle_max(const ros_laser_scan_t* view);
+float ros_laser_scan_get_angle_increment(const ros_laser_scan_t* view);
+float ros_laser_scan_get_time_increment(const ros_laser_scan_t* view);
+float ros_laser_scan_get_scan_time(const ros_laser_scan_t* view);
+float ros_laser_scan_get_range_min(const ros_laser_scan_t* view);
+float ros_laser_scan_get_range_max(const ros_laser_scan_t* view);
+uint32_t ros_laser_scan_get_ranges_len(const ros_laser_scan_t* view);
+/** Copy up to `cap` ranges into `out`; returns total element count. */
+uint32_t ros_laser_scan_get_ranges(const ros_laser_scan_t* view,
+                                   float* out, size_t cap);
+uint32_t ros_laser_scan_get_intensities_len(const ros_laser_scan_t* view);
+uint32_t ros_laser_scan_get_intensities(const ros_laser_scan_t* view,
+                                        float* out, size_t cap);
+const uint8_t* ros_laser_scan_as_cdr(const ros_laser_scan_t* view, size_t* out_len);
+
+/* =========================================================================
+ * nav_msgs/OccupancyGrid  (buffer-backed, decode-only, 3.4.0+)
+ * =========================================================================
+ */
+typedef struct ros_occupancy_grid_t ros_occupancy_grid_t;
+
+ros_occupancy_grid_t* ros_occupancy_grid_from_cdr(const uint8_t* data, size_t len);
+void ros_occupancy_grid_free(ros_occupancy_grid_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_occupancy_grid_free(). */
+ros_occupancy_grid_t* ros_occupancy_grid_clone(const ros_occupancy_grid_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error. */
+int32_t ros_occupancy_grid_equals(const ros_occupancy_grid_t* a,
+                                  const ros_occupancy_grid_t* b);
+/** @brief Add a reference, dropped by ros_occupancy_grid_free() or _release(). */
+ros_occupancy_grid_t* ros_occupancy_grid_retain(ros_occupancy_grid_t* view);
+/** @brief Alias of ros_occupancy_grid_free(). */
+void ros_occupancy_grid_release(ros_occupancy_grid_t* view);
+/** @brief Length of the message's CDR bytes. */
+int32_t ros_occupancy_grid_serialized_size(const ros_occupancy_grid_t* view, size_t* out);
+int32_t ros_occupancy_grid_get_stamp_sec(const ros_occupancy_grid_t* view);
+uint32_t ros_occupancy_grid_get_stamp_nanosec(const ros_occupancy_grid_t* view);
+const char* ros_occupancy_grid_get_frame_id(const ros_occupancy_grid_t* view);
+/** MapMetaData fields as in ros_map_meta_data_decode(); returns 0 or -1. */
+int32_t ros_occupancy_grid_get_info(const ros_occupancy_grid_t* view,
+                                    int32_t* load_sec, uint32_t* load_nanosec,
+                                    float* resolution, uint32_t* width,
+                                    uint32_t* height, double* origin_out);
+uint32_t ros_occupancy_grid_get_data_len(const ros_occupancy_grid_t* view);
+/** Borrowed cells, row-major: -1 unknown, 0..100 occupancy probability. */
+const int8_t* ros_occupancy_grid_get_data(const ros_occupancy_grid_t* view,
+                                          size_t* out_len);
+const uint8_t* ros_occupancy_grid_as_cdr(const ros_occupancy_grid_t* view,
+                                         size_t* out_len);
+
 /* ============================================================================
  * edgefirst_msgs - Mask (builder, 3.2.0+)
  * ========================================================================= */
@@ -2816,6 +3830,8 @@ int  ros_mask_builder_build(ros_mask_builder_t* b,
                             uint8_t** out_bytes, size_t* out_len);
 int  ros_mask_builder_encode_into(ros_mask_builder_t* b,
                                   uint8_t* buf, size_t cap, size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_mask_builder_serialized_size(const ros_mask_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - LocalTime (builder, 3.2.0+)
@@ -2837,6 +3853,8 @@ int  ros_local_time_builder_build(ros_local_time_builder_t* b,
 int  ros_local_time_builder_encode_into(ros_local_time_builder_t* b,
                                         uint8_t* buf, size_t cap,
                                         size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_local_time_builder_serialized_size(const ros_local_time_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - RadarCube (builder, 3.2.0+)
@@ -2879,6 +3897,8 @@ int  ros_radar_cube_builder_build(ros_radar_cube_builder_t* b,
 int  ros_radar_cube_builder_encode_into(ros_radar_cube_builder_t* b,
                                         uint8_t* buf, size_t cap,
                                         size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_radar_cube_builder_serialized_size(const ros_radar_cube_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - RadarInfo (builder, 3.2.0+)
@@ -2904,6 +3924,8 @@ int  ros_radar_info_builder_build(ros_radar_info_builder_t* b,
 int  ros_radar_info_builder_encode_into(ros_radar_info_builder_t* b,
                                         uint8_t* buf, size_t cap,
                                         size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_radar_info_builder_serialized_size(const ros_radar_info_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - Track (builder, 3.2.0+)
@@ -2919,10 +3941,13 @@ int  ros_track_builder_build(ros_track_builder_t* b,
                              uint8_t** out_bytes, size_t* out_len);
 int  ros_track_builder_encode_into(ros_track_builder_t* b,
                                    uint8_t* buf, size_t cap, size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_track_builder_serialized_size(const ros_track_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - DetectBox / shared descriptors (builder, 3.2.0+)
  * ========================================================================= */
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /**
  * C-POD descriptor for a DetectBox element. Used both for the standalone
  * DetectBox builder and for the `boxes` sequence on Detect and Model.
@@ -2949,6 +3974,7 @@ typedef struct ros_detect_box_elem_s {
     int32_t  track_created_sec;
     uint32_t track_created_nanosec;
 } ros_detect_box_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
 typedef struct ros_detect_box_builder_s ros_detect_box_builder_t;
 ros_detect_box_builder_t* ros_detect_box_builder_new(void);
@@ -2972,6 +3998,8 @@ int  ros_detect_box_builder_build(ros_detect_box_builder_t* b,
 int  ros_detect_box_builder_encode_into(ros_detect_box_builder_t* b,
                                         uint8_t* buf, size_t cap,
                                         size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_detect_box_builder_serialized_size(const ros_detect_box_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - Detect (builder, 3.2.0+)
@@ -2988,6 +4016,7 @@ void ros_detect_builder_set_model_time(ros_detect_builder_t* b,
                                        int32_t sec, uint32_t nsec);
 void ros_detect_builder_set_output_time(ros_detect_builder_t* b,
                                         int32_t sec, uint32_t nsec);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED — each element's label/track_id must remain valid until next
  *  setter, build, encode_into, or free.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
@@ -2996,14 +4025,33 @@ void ros_detect_builder_set_output_time(ros_detect_builder_t* b,
 int  ros_detect_builder_set_boxes(ros_detect_builder_t* b,
                                   const ros_detect_box_elem_t* boxes,
                                   size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/** Append a copy of `detect_box` to the boxes sequence (3.4.0+). Nothing
+ *  is borrowed; discards an array passed to ros_detect_builder_set_boxes().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
+ *         or NULL element builder).
+ */
+int  ros_detect_builder_add_box(ros_detect_builder_t* b,
+                                 const ros_detect_box_builder_t* detect_box);
+/** Number of boxes in the builder, from set_boxes or add_box (3.4.0+). */
+size_t ros_detect_builder_get_boxes_len(const ros_detect_builder_t* b);
+/** Remove box `index`, shifting later boxes down (3.4.0+). An array passed
+ *  to ros_detect_builder_set_boxes() is copied first and left untouched.
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle or
+ *         index out of range).
+ */
+int  ros_detect_builder_remove_box(ros_detect_builder_t* b, size_t index);
 int  ros_detect_builder_build(ros_detect_builder_t* b,
                               uint8_t** out_bytes, size_t* out_len);
 int  ros_detect_builder_encode_into(ros_detect_builder_t* b,
                                     uint8_t* buf, size_t cap, size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_detect_builder_serialized_size(const ros_detect_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - CameraFrame (builder, 3.2.0+)
  * ========================================================================= */
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /**
  * C-POD descriptor for a CameraPlane element. `data` is BORROWED: it must
  * remain valid until the consuming builder is finalised or freed.
@@ -3017,6 +4065,7 @@ typedef struct ros_camera_plane_elem_s {
     const uint8_t* data;
     size_t   data_len;
 } ros_camera_plane_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
 typedef struct ros_camera_frame_builder_s ros_camera_frame_builder_t;
 ros_camera_frame_builder_t* ros_camera_frame_builder_new(void);
@@ -3040,6 +4089,7 @@ int  ros_camera_frame_builder_set_color_encoding(ros_camera_frame_builder_t* b,
 int  ros_camera_frame_builder_set_color_range(ros_camera_frame_builder_t* b,
                                               const char* s);
 void ros_camera_frame_builder_set_fence_fd(ros_camera_frame_builder_t* b, int32_t v);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED — each element's data must remain valid until next setter,
  *  build, encode_into, or free.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
@@ -3048,15 +4098,33 @@ void ros_camera_frame_builder_set_fence_fd(ros_camera_frame_builder_t* b, int32_
 int  ros_camera_frame_builder_set_planes(ros_camera_frame_builder_t* b,
                                          const ros_camera_plane_elem_t* planes,
                                          size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/**
+ * @brief Append a plane to the planes sequence (3.4.0+).
+ *
+ * `data_len` bytes of inlined `data` (NULL when `data_len == 0`) are
+ * copied; nothing is borrowed. Discards an array passed to
+ * ros_camera_frame_builder_set_planes().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
+ *         or NULL `data` with non-zero `data_len`).
+ */
+int  ros_camera_frame_builder_add_plane(ros_camera_frame_builder_t* b,
+                                        int32_t fd, uint32_t offset,
+                                        uint32_t stride, uint32_t size,
+                                        uint32_t used, const uint8_t* data,
+                                        size_t data_len);
 int  ros_camera_frame_builder_build(ros_camera_frame_builder_t* b,
                                     uint8_t** out_bytes, size_t* out_len);
 int  ros_camera_frame_builder_encode_into(ros_camera_frame_builder_t* b,
                                           uint8_t* buf, size_t cap,
                                           size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_camera_frame_builder_serialized_size(const ros_camera_frame_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - Model (builder, 3.2.0+)
  * ========================================================================= */
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /**
  * C-POD descriptor for a Mask element used by the `masks` sequence on
  * Model. `encoding` and `mask` are BORROWED; both must remain valid until
@@ -3076,6 +4144,7 @@ typedef struct ros_mask_elem_s {
     size_t   mask_len;
     bool     boxed;
 } ros_mask_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
 typedef struct ros_model_builder_s ros_model_builder_t;
 ros_model_builder_t* ros_model_builder_new(void);
@@ -3091,6 +4160,7 @@ void ros_model_builder_set_output_time(ros_model_builder_t* b,
                                        int32_t sec, uint32_t nsec);
 void ros_model_builder_set_decode_time(ros_model_builder_t* b,
                                        int32_t sec, uint32_t nsec);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED — see ros_detect_builder_set_boxes.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
  *         or NULL pointer with non-zero count/len).
@@ -3098,6 +4168,15 @@ void ros_model_builder_set_decode_time(ros_model_builder_t* b,
 int  ros_model_builder_set_boxes(ros_model_builder_t* b,
                                  const ros_detect_box_elem_t* boxes,
                                  size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/** Append a copy of `detect_box` to the boxes sequence (3.4.0+). Nothing
+ *  is borrowed; discards an array passed to ros_model_builder_set_boxes().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
+ *         or NULL element builder).
+ */
+int  ros_model_builder_add_box(ros_model_builder_t* b,
+                                 const ros_detect_box_builder_t* detect_box);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED — each element's encoding/mask must remain valid.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
  *         or NULL pointer with non-zero count/len).
@@ -3105,10 +4184,21 @@ int  ros_model_builder_set_boxes(ros_model_builder_t* b,
 int  ros_model_builder_set_masks(ros_model_builder_t* b,
                                  const ros_mask_elem_t* masks,
                                  size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/** Append a copy of `mask`, mask bytes included, to the masks sequence
+ *  (3.4.0+). Nothing is borrowed; discards an array passed to
+ *  ros_model_builder_set_masks().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
+ *         or NULL element builder).
+ */
+int  ros_model_builder_add_mask(ros_model_builder_t* b,
+                                const ros_mask_builder_t* mask);
 int  ros_model_builder_build(ros_model_builder_t* b,
                              uint8_t** out_bytes, size_t* out_len);
 int  ros_model_builder_encode_into(ros_model_builder_t* b,
                                    uint8_t* buf, size_t cap, size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_model_builder_serialized_size(const ros_model_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - ModelInfo (builder, 3.2.0+)
@@ -3155,6 +4245,8 @@ int  ros_model_info_builder_build(ros_model_info_builder_t* b,
 int  ros_model_info_builder_encode_into(ros_model_info_builder_t* b,
                                         uint8_t* buf, size_t cap,
                                         size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_model_info_builder_serialized_size(const ros_model_info_builder_t* b, size_t* out);
 
 /* ============================================================================
  * edgefirst_msgs - Vibration (builder, 3.2.0+)
@@ -3183,6 +4275,8 @@ int  ros_vibration_builder_build(ros_vibration_builder_t* b,
 int  ros_vibration_builder_encode_into(ros_vibration_builder_t* b,
                                        uint8_t* buf, size_t cap,
                                        size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_vibration_builder_serialized_size(const ros_vibration_builder_t* b, size_t* out);
 
 /* ============================================================================
  * foxglove_msgs - CompressedVideo (builder, 3.2.0+)
@@ -3207,6 +4301,11 @@ int  ros_foxglove_compressed_video_builder_set_frame_id(
 int  ros_foxglove_compressed_video_builder_set_data(
     ros_foxglove_compressed_video_builder_t* b,
     const uint8_t* data, size_t len);
+/** Builder owns @p data and calls free_fn(ctx) when done with it; see
+ *  ros_image_builder_set_data_owned() (3.4.0+). */
+int  ros_foxglove_compressed_video_builder_set_data_owned(
+    ros_foxglove_compressed_video_builder_t* b,
+    const uint8_t* data, size_t len, void (*free_fn)(void* ctx), void* ctx);
 int  ros_foxglove_compressed_video_builder_set_format(
     ros_foxglove_compressed_video_builder_t* b, const char* s);
 int  ros_foxglove_compressed_video_builder_build(
@@ -3215,6 +4314,15 @@ int  ros_foxglove_compressed_video_builder_build(
 int  ros_foxglove_compressed_video_builder_encode_into(
     ros_foxglove_compressed_video_builder_t* b,
     uint8_t* buf, size_t cap, size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_foxglove_compressed_video_builder_serialized_size(const ros_foxglove_compressed_video_builder_t* b, size_t* out);
+/**
+ * @brief Encode everything but the data bytes, for gather writes (3.4.0+).
+ * tail holds the padding and format. See ros_image_builder_encode_parts().
+ */
+int32_t ros_foxglove_compressed_video_builder_encode_parts(ros_foxglove_compressed_video_builder_t* b,
+    uint8_t* head, size_t head_cap, size_t* head_len,
+    uint8_t* tail, size_t tail_cap, size_t* tail_len);
 
 /* ============================================================================
  * foxglove_msgs - FoxgloveTextAnnotation (builder, 3.2.0+)
@@ -3245,16 +4353,21 @@ int  ros_foxglove_text_annotation_builder_build(
 int  ros_foxglove_text_annotation_builder_encode_into(
     ros_foxglove_text_annotation_builder_t* b,
     uint8_t* buf, size_t cap, size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_foxglove_text_annotation_builder_serialized_size(const ros_foxglove_text_annotation_builder_t* b, size_t* out);
 
 /* ============================================================================
  * foxglove_msgs - FoxglovePointAnnotation (builder, 3.2.0+)
  * ========================================================================= */
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** C-POD descriptor for a FoxglovePoint2 element (no borrowed fields). */
 typedef struct ros_foxglove_point2_elem_s {
     double x;
     double y;
 } ros_foxglove_point2_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** C-POD descriptor for a FoxgloveColor element (no borrowed fields). */
 typedef struct ros_foxglove_color_elem_s {
     double r;
@@ -3262,6 +4375,7 @@ typedef struct ros_foxglove_color_elem_s {
     double b;
     double a;
 } ros_foxglove_color_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
 typedef struct ros_foxglove_point_annotation_builder_s
     ros_foxglove_point_annotation_builder_t;
@@ -3273,6 +4387,7 @@ void ros_foxglove_point_annotation_builder_set_timestamp(
     ros_foxglove_point_annotation_builder_t* b, int32_t sec, uint32_t nsec);
 void ros_foxglove_point_annotation_builder_set_type(
     ros_foxglove_point_annotation_builder_t* b, uint8_t v);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED until next setter / build / free.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
  *         or NULL pointer with non-zero count/len).
@@ -3280,9 +4395,17 @@ void ros_foxglove_point_annotation_builder_set_type(
 int  ros_foxglove_point_annotation_builder_set_points(
     ros_foxglove_point_annotation_builder_t* b,
     const ros_foxglove_point2_elem_t* points, size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/** Append a point to the points sequence (3.4.0+). Discards an array
+ *  passed to ros_foxglove_point_annotation_builder_set_points().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle).
+ */
+int  ros_foxglove_point_annotation_builder_add_point(
+    ros_foxglove_point_annotation_builder_t* b, double x, double y);
 void ros_foxglove_point_annotation_builder_set_outline_color(
     ros_foxglove_point_annotation_builder_t* b,
     double r, double g, double b_, double a);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED until next setter / build / free.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
  *         or NULL pointer with non-zero count/len).
@@ -3290,6 +4413,15 @@ void ros_foxglove_point_annotation_builder_set_outline_color(
 int  ros_foxglove_point_annotation_builder_set_outline_colors(
     ros_foxglove_point_annotation_builder_t* b,
     const ros_foxglove_color_elem_t* colors, size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/** Append a per-point color to the outline_colors sequence (3.4.0+); not
+ *  to be confused with the single fallback set_outline_color(). Discards
+ *  an array passed to ros_foxglove_point_annotation_builder_set_outline_colors().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle).
+ */
+int  ros_foxglove_point_annotation_builder_add_outline_color(
+    ros_foxglove_point_annotation_builder_t* b,
+    double r, double g, double b_, double a);
 void ros_foxglove_point_annotation_builder_set_fill_color(
     ros_foxglove_point_annotation_builder_t* b,
     double r, double g, double b_, double a);
@@ -3301,10 +4433,13 @@ int  ros_foxglove_point_annotation_builder_build(
 int  ros_foxglove_point_annotation_builder_encode_into(
     ros_foxglove_point_annotation_builder_t* b,
     uint8_t* buf, size_t cap, size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_foxglove_point_annotation_builder_serialized_size(const ros_foxglove_point_annotation_builder_t* b, size_t* out);
 
 /* ============================================================================
  * foxglove_msgs - FoxgloveImageAnnotation (builder, 3.2.0+)
  * ========================================================================= */
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** C-POD descriptor for a FoxgloveCircleAnnotations element (no borrows). */
 typedef struct ros_foxglove_circle_annotation_elem_s {
     int32_t  timestamp_sec;
@@ -3322,7 +4457,9 @@ typedef struct ros_foxglove_circle_annotation_elem_s {
     double   outline_color_b;
     double   outline_color_a;
 } ros_foxglove_circle_annotation_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /**
  * C-POD descriptor for a FoxglovePointAnnotation element. Inner `points`
  * and `outline_colors` arrays are BORROWED: they must remain valid until
@@ -3346,7 +4483,9 @@ typedef struct ros_foxglove_point_annotation_elem_s {
     double   fill_color_a;
     double   thickness;
 } ros_foxglove_point_annotation_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /**
  * C-POD descriptor for a FoxgloveTextAnnotation element. `text` is a
  * BORROWED NUL-terminated C string.
@@ -3371,6 +4510,7 @@ typedef struct ros_foxglove_text_annotation_elem_s {
     double   background_color_b;
     double   background_color_a;
 } ros_foxglove_text_annotation_elem_t;
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
 
 typedef struct ros_foxglove_image_annotation_builder_s
     ros_foxglove_image_annotation_builder_t;
@@ -3378,6 +4518,7 @@ ros_foxglove_image_annotation_builder_t*
 ros_foxglove_image_annotation_builder_new(void);
 void ros_foxglove_image_annotation_builder_free(
     ros_foxglove_image_annotation_builder_t* b);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED — caller keeps `circles` valid until next setter / build / free.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
  *         or NULL pointer with non-zero count/len).
@@ -3385,6 +4526,18 @@ void ros_foxglove_image_annotation_builder_free(
 int  ros_foxglove_image_annotation_builder_set_circles(
     ros_foxglove_image_annotation_builder_t* b,
     const ros_foxglove_circle_annotation_elem_t* circles, size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/** Append a circle to the circles sequence (3.4.0+). Discards an array
+ *  passed to ros_foxglove_image_annotation_builder_set_circles().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle).
+ */
+int  ros_foxglove_image_annotation_builder_add_circle_annotation(
+    ros_foxglove_image_annotation_builder_t* b,
+    int32_t timestamp_sec, uint32_t timestamp_nanosec,
+    double position_x, double position_y, double diameter, double thickness,
+    double fill_r, double fill_g, double fill_b, double fill_a,
+    double outline_r, double outline_g, double outline_b, double outline_a);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED — each element's inner `points`/`outline_colors` arrays must
  *  remain valid until next setter / build / free.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
@@ -3393,6 +4546,17 @@ int  ros_foxglove_image_annotation_builder_set_circles(
 int  ros_foxglove_image_annotation_builder_set_points(
     ros_foxglove_image_annotation_builder_t* b,
     const ros_foxglove_point_annotation_elem_t* points, size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/** Append a copy of `annotation`, its points and outline colors included,
+ *  to the points sequence (3.4.0+). Nothing is borrowed; discards an array
+ *  passed to ros_foxglove_image_annotation_builder_set_points().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
+ *         or NULL element builder).
+ */
+int  ros_foxglove_image_annotation_builder_add_point_annotation(
+    ros_foxglove_image_annotation_builder_t* b,
+    const ros_foxglove_point_annotation_builder_t* annotation);
+#ifndef EDGEFIRST_OPAQUE_HANDLES
 /** BORROWED — each element's `text` C string must remain valid until next
  *  setter / build / free.
  * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
@@ -3401,12 +4565,24 @@ int  ros_foxglove_image_annotation_builder_set_points(
 int  ros_foxglove_image_annotation_builder_set_texts(
     ros_foxglove_image_annotation_builder_t* b,
     const ros_foxglove_text_annotation_elem_t* texts, size_t count);
+#endif /* EDGEFIRST_OPAQUE_HANDLES */
+/** Append a copy of `annotation` to the texts sequence (3.4.0+). Nothing
+ *  is borrowed; discards an array passed to
+ *  ros_foxglove_image_annotation_builder_set_texts().
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL handle
+ *         or NULL element builder).
+ */
+int  ros_foxglove_image_annotation_builder_add_text_annotation(
+    ros_foxglove_image_annotation_builder_t* b,
+    const ros_foxglove_text_annotation_builder_t* annotation);
 int  ros_foxglove_image_annotation_builder_build(
     ros_foxglove_image_annotation_builder_t* b,
     uint8_t** out_bytes, size_t* out_len);
 int  ros_foxglove_image_annotation_builder_encode_into(
     ros_foxglove_image_annotation_builder_t* b,
     uint8_t* buf, size_t cap, size_t* out_len);
+/** @brief Size the next encode_into or build produces, without encoding (3.4.0+). */
+int32_t ros_foxglove_image_annotation_builder_serialized_size(const ros_foxglove_image_annotation_builder_t* b, size_t* out);
 
 /* ============================================================================
  * In-place scalar setters (3.2.0+)
@@ -3442,6 +4618,16 @@ int  ros_foxglove_image_annotation_builder_encode_into(
  */
 int32_t ros_header_set_stamp(uint8_t* buf, size_t len, int32_t sec, uint32_t nsec);
 
+/**
+ * @brief Set the stamp of a CDR-encoded Header in place to the current
+ *        time of `clock` (3.4.0+).
+ *
+ * @param clock ROS_CLOCK_REALTIME or ROS_CLOCK_MONOTONIC.
+ * @return 0 on success, -1 on error (errno: EINVAL for NULL buf or an
+ *         unknown clock, EBADMSG if buf is not a valid encoded Header).
+ */
+int32_t ros_header_set_stamp_now(uint8_t* buf, size_t len, int32_t clock);
+
 /* ---- sensor_msgs - Image ---- */
 
 /**
@@ -5178,6 +6364,16 @@ typedef struct ros_mavros_altitude_t ros_mavros_altitude_t;
 
 ros_mavros_altitude_t* ros_mavros_altitude_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_altitude_free(ros_mavros_altitude_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_altitude_free() (3.4.0+). */
+ros_mavros_altitude_t* ros_mavros_altitude_clone(const ros_mavros_altitude_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_altitude_equals(const ros_mavros_altitude_t* a, const ros_mavros_altitude_t* b);
+/** @brief Add a reference, dropped by ros_mavros_altitude_free() or _release() (3.4.0+). */
+ros_mavros_altitude_t* ros_mavros_altitude_retain(ros_mavros_altitude_t* view);
+/** @brief Alias of ros_mavros_altitude_free() (3.4.0+). */
+void ros_mavros_altitude_release(ros_mavros_altitude_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_altitude_serialized_size(const ros_mavros_altitude_t* view, size_t* out);
 const uint8_t* ros_mavros_altitude_as_cdr(const ros_mavros_altitude_t* view, size_t* out_len);
 int32_t ros_mavros_altitude_get_stamp_sec(const ros_mavros_altitude_t* view);
 uint32_t ros_mavros_altitude_get_stamp_nanosec(const ros_mavros_altitude_t* view);
@@ -5196,6 +6392,16 @@ typedef struct ros_mavros_vfrhud_t ros_mavros_vfrhud_t;
 
 ros_mavros_vfrhud_t* ros_mavros_vfrhud_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_vfrhud_free(ros_mavros_vfrhud_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_vfrhud_free() (3.4.0+). */
+ros_mavros_vfrhud_t* ros_mavros_vfrhud_clone(const ros_mavros_vfrhud_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_vfrhud_equals(const ros_mavros_vfrhud_t* a, const ros_mavros_vfrhud_t* b);
+/** @brief Add a reference, dropped by ros_mavros_vfrhud_free() or _release() (3.4.0+). */
+ros_mavros_vfrhud_t* ros_mavros_vfrhud_retain(ros_mavros_vfrhud_t* view);
+/** @brief Alias of ros_mavros_vfrhud_free() (3.4.0+). */
+void ros_mavros_vfrhud_release(ros_mavros_vfrhud_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_vfrhud_serialized_size(const ros_mavros_vfrhud_t* view, size_t* out);
 const uint8_t* ros_mavros_vfrhud_as_cdr(const ros_mavros_vfrhud_t* view, size_t* out_len);
 int32_t ros_mavros_vfrhud_get_stamp_sec(const ros_mavros_vfrhud_t* view);
 uint32_t ros_mavros_vfrhud_get_stamp_nanosec(const ros_mavros_vfrhud_t* view);
@@ -5214,6 +6420,16 @@ typedef struct ros_mavros_estimator_status_t ros_mavros_estimator_status_t;
 
 ros_mavros_estimator_status_t* ros_mavros_estimator_status_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_estimator_status_free(ros_mavros_estimator_status_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_estimator_status_free() (3.4.0+). */
+ros_mavros_estimator_status_t* ros_mavros_estimator_status_clone(const ros_mavros_estimator_status_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_estimator_status_equals(const ros_mavros_estimator_status_t* a, const ros_mavros_estimator_status_t* b);
+/** @brief Add a reference, dropped by ros_mavros_estimator_status_free() or _release() (3.4.0+). */
+ros_mavros_estimator_status_t* ros_mavros_estimator_status_retain(ros_mavros_estimator_status_t* view);
+/** @brief Alias of ros_mavros_estimator_status_free() (3.4.0+). */
+void ros_mavros_estimator_status_release(ros_mavros_estimator_status_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_estimator_status_serialized_size(const ros_mavros_estimator_status_t* view, size_t* out);
 const uint8_t* ros_mavros_estimator_status_as_cdr(const ros_mavros_estimator_status_t* view, size_t* out_len);
 int32_t ros_mavros_estimator_status_get_stamp_sec(const ros_mavros_estimator_status_t* view);
 uint32_t ros_mavros_estimator_status_get_stamp_nanosec(const ros_mavros_estimator_status_t* view);
@@ -5252,6 +6468,16 @@ typedef struct ros_mavros_extended_state_t ros_mavros_extended_state_t;
 
 ros_mavros_extended_state_t* ros_mavros_extended_state_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_extended_state_free(ros_mavros_extended_state_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_extended_state_free() (3.4.0+). */
+ros_mavros_extended_state_t* ros_mavros_extended_state_clone(const ros_mavros_extended_state_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_extended_state_equals(const ros_mavros_extended_state_t* a, const ros_mavros_extended_state_t* b);
+/** @brief Add a reference, dropped by ros_mavros_extended_state_free() or _release() (3.4.0+). */
+ros_mavros_extended_state_t* ros_mavros_extended_state_retain(ros_mavros_extended_state_t* view);
+/** @brief Alias of ros_mavros_extended_state_free() (3.4.0+). */
+void ros_mavros_extended_state_release(ros_mavros_extended_state_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_extended_state_serialized_size(const ros_mavros_extended_state_t* view, size_t* out);
 const uint8_t* ros_mavros_extended_state_as_cdr(const ros_mavros_extended_state_t* view, size_t* out_len);
 int32_t ros_mavros_extended_state_get_stamp_sec(const ros_mavros_extended_state_t* view);
 uint32_t ros_mavros_extended_state_get_stamp_nanosec(const ros_mavros_extended_state_t* view);
@@ -5272,6 +6498,16 @@ typedef struct ros_mavros_sys_status_t ros_mavros_sys_status_t;
 
 ros_mavros_sys_status_t* ros_mavros_sys_status_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_sys_status_free(ros_mavros_sys_status_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_sys_status_free() (3.4.0+). */
+ros_mavros_sys_status_t* ros_mavros_sys_status_clone(const ros_mavros_sys_status_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_sys_status_equals(const ros_mavros_sys_status_t* a, const ros_mavros_sys_status_t* b);
+/** @brief Add a reference, dropped by ros_mavros_sys_status_free() or _release() (3.4.0+). */
+ros_mavros_sys_status_t* ros_mavros_sys_status_retain(ros_mavros_sys_status_t* view);
+/** @brief Alias of ros_mavros_sys_status_free() (3.4.0+). */
+void ros_mavros_sys_status_release(ros_mavros_sys_status_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_sys_status_serialized_size(const ros_mavros_sys_status_t* view, size_t* out);
 const uint8_t* ros_mavros_sys_status_as_cdr(const ros_mavros_sys_status_t* view, size_t* out_len);
 int32_t ros_mavros_sys_status_get_stamp_sec(const ros_mavros_sys_status_t* view);
 uint32_t ros_mavros_sys_status_get_stamp_nanosec(const ros_mavros_sys_status_t* view);
@@ -5321,6 +6557,16 @@ typedef struct ros_mavros_state_t ros_mavros_state_t;
 
 ros_mavros_state_t* ros_mavros_state_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_state_free(ros_mavros_state_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_state_free() (3.4.0+). */
+ros_mavros_state_t* ros_mavros_state_clone(const ros_mavros_state_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_state_equals(const ros_mavros_state_t* a, const ros_mavros_state_t* b);
+/** @brief Add a reference, dropped by ros_mavros_state_free() or _release() (3.4.0+). */
+ros_mavros_state_t* ros_mavros_state_retain(ros_mavros_state_t* view);
+/** @brief Alias of ros_mavros_state_free() (3.4.0+). */
+void ros_mavros_state_release(ros_mavros_state_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_state_serialized_size(const ros_mavros_state_t* view, size_t* out);
 const uint8_t* ros_mavros_state_as_cdr(const ros_mavros_state_t* view, size_t* out_len);
 int32_t ros_mavros_state_get_stamp_sec(const ros_mavros_state_t* view);
 uint32_t ros_mavros_state_get_stamp_nanosec(const ros_mavros_state_t* view);
@@ -5349,6 +6595,16 @@ typedef struct ros_mavros_status_text_t ros_mavros_status_text_t;
 
 ros_mavros_status_text_t* ros_mavros_status_text_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_status_text_free(ros_mavros_status_text_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_status_text_free() (3.4.0+). */
+ros_mavros_status_text_t* ros_mavros_status_text_clone(const ros_mavros_status_text_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_status_text_equals(const ros_mavros_status_text_t* a, const ros_mavros_status_text_t* b);
+/** @brief Add a reference, dropped by ros_mavros_status_text_free() or _release() (3.4.0+). */
+ros_mavros_status_text_t* ros_mavros_status_text_retain(ros_mavros_status_text_t* view);
+/** @brief Alias of ros_mavros_status_text_free() (3.4.0+). */
+void ros_mavros_status_text_release(ros_mavros_status_text_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_status_text_serialized_size(const ros_mavros_status_text_t* view, size_t* out);
 const uint8_t* ros_mavros_status_text_as_cdr(const ros_mavros_status_text_t* view, size_t* out_len);
 int32_t ros_mavros_status_text_get_stamp_sec(const ros_mavros_status_text_t* view);
 uint32_t ros_mavros_status_text_get_stamp_nanosec(const ros_mavros_status_text_t* view);
@@ -5380,6 +6636,16 @@ typedef struct ros_mavros_gps_raw_t ros_mavros_gps_raw_t;
 
 ros_mavros_gps_raw_t* ros_mavros_gps_raw_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_gps_raw_free(ros_mavros_gps_raw_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_gps_raw_free() (3.4.0+). */
+ros_mavros_gps_raw_t* ros_mavros_gps_raw_clone(const ros_mavros_gps_raw_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_gps_raw_equals(const ros_mavros_gps_raw_t* a, const ros_mavros_gps_raw_t* b);
+/** @brief Add a reference, dropped by ros_mavros_gps_raw_free() or _release() (3.4.0+). */
+ros_mavros_gps_raw_t* ros_mavros_gps_raw_retain(ros_mavros_gps_raw_t* view);
+/** @brief Alias of ros_mavros_gps_raw_free() (3.4.0+). */
+void ros_mavros_gps_raw_release(ros_mavros_gps_raw_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_gps_raw_serialized_size(const ros_mavros_gps_raw_t* view, size_t* out);
 const uint8_t* ros_mavros_gps_raw_as_cdr(const ros_mavros_gps_raw_t* view, size_t* out_len);
 int32_t ros_mavros_gps_raw_get_stamp_sec(const ros_mavros_gps_raw_t* view);
 uint32_t ros_mavros_gps_raw_get_stamp_nanosec(const ros_mavros_gps_raw_t* view);
@@ -5426,6 +6692,16 @@ typedef struct ros_mavros_timesync_status_t ros_mavros_timesync_status_t;
 
 ros_mavros_timesync_status_t* ros_mavros_timesync_status_from_cdr(const uint8_t* data, size_t len);
 void ros_mavros_timesync_status_free(ros_mavros_timesync_status_t* view);
+/** @brief Deep copy owning its CDR bytes; free with ros_mavros_timesync_status_free() (3.4.0+). */
+ros_mavros_timesync_status_t* ros_mavros_timesync_status_clone(const ros_mavros_timesync_status_t* view);
+/** @brief 1 if a and b hold equal field values, 0 if not, -1 on error (3.4.0+). */
+int32_t ros_mavros_timesync_status_equals(const ros_mavros_timesync_status_t* a, const ros_mavros_timesync_status_t* b);
+/** @brief Add a reference, dropped by ros_mavros_timesync_status_free() or _release() (3.4.0+). */
+ros_mavros_timesync_status_t* ros_mavros_timesync_status_retain(ros_mavros_timesync_status_t* view);
+/** @brief Alias of ros_mavros_timesync_status_free() (3.4.0+). */
+void ros_mavros_timesync_status_release(ros_mavros_timesync_status_t* view);
+/** @brief Length of the message's CDR bytes (3.4.0+). */
+int32_t ros_mavros_timesync_status_serialized_size(const ros_mavros_timesync_status_t* view, size_t* out);
 const uint8_t* ros_mavros_timesync_status_as_cdr(const ros_mavros_timesync_status_t* view, size_t* out_len);
 int32_t ros_mavros_timesync_status_get_stamp_sec(const ros_mavros_timesync_status_t* view);
 uint32_t ros_mavros_timesync_status_get_stamp_nanosec(const ros_mavros_timesync_status_t* view);
@@ -52,7 +52,15 @@
  * The Error struct carries the POSIX errno code and a static
  * `std::string_view` naming the underlying C function that failed.
  * Exceptions are never thrown by the wrapper; the C library's errno/NULL
- * conventions are mapped uniformly to `expected`.
+ * conventions are mapped uniformly to `expected`. Code that prefers
+ * exceptions can unwrap any result with `value_or_throw()`, which throws
+ * `Exception` (a `std::system_error` in the generic category) on error:
+ *
+ * @code{.cpp}
+ * namespace ef = edgefirst::schemas;
+ * auto det = ef::value_or_throw(ef::DetectView::from_cdr(payload));
+ * std::string frame{det.frame_id()};   // copy out of the borrowed view
+ * @endcode
  *
  * @section zerocopy Zero-copy guarantees
  *
@@ -156,6 +164,11 @@
 #include <cerrno>
 #include <iterator>
 #include <string_view>
+#include <utility>
+#if defined(__cpp_exceptions)
+#include <string>
+#include <system_error>
+#endif
 
 #include <edgefirst/schemas.h>
 #include <edgefirst/stdlib/expected.hpp>
@@ -217,6 +230,7 @@ struct Error {
         case EINVAL:  return "invalid argument";
         case ENOBUFS: return "buffer too small";
         case EBADMSG: return "bad message";
+        case EIO:     return "internal error";
         default:      return "unknown error";
         }
     }
@@ -230,6 +244,42 @@ struct Error {
     }
 };
 
+#if defined(__cpp_exceptions)
+/**
+ * @brief Exception thrown by `value_or_throw()`.
+ *
+ * A `std::system_error` whose `code()` is `Error::code` in
+ * `std::generic_category()`, so it compares equal to `std::errc` values;
+ * `what()` names the failing C function.
+ */
+class Exception : public std::system_error {
+public:
+    explicit Exception(Error e)
+        : std::system_error(e.code, std::generic_category(), std::string(e.where)),
+          error_(e) {}
+
+    /// @brief The wrapped `Error`.
+    [[nodiscard]] const Error& error() const noexcept { return error_; }
+
+private:
+    Error error_;
+};
+
+/**
+ * @brief Unwrap a wrapper result, throwing `Exception` on error.
+ *
+ * Only available when exceptions are enabled; the wrapper itself never
+ * throws.
+ */
+template <class T>
+[[nodiscard]] T value_or_throw(expected<T, Error>&& result) {
+    if (!result) {
+        throw Exception(result.error());
+    }
+    return std::move(*result);
+}
+#endif
+
 /**
  * @brief Raw ownership of an encoded CDR byte buffer transferred out of
  *        an owning wrapper via `release()`.
@@ -1320,6 +1370,14 @@ public:
         return len;
     }
 
+    /// @brief Size `encode_into()` needs for the current fields (3.4.0+).
+    [[nodiscard]] expected<std::size_t, Error> encoded_size() noexcept {
+        std::size_t len = 0;
+        if (BuilderTraits::encode_into_fn(b_, nullptr, 0, &len) != 0)
+            return unexpected<Error>(Error::from_errno(BuilderTraits::encode_into_name));
+        return len;
+    }
+
 protected:
     explicit BuilderBase(builder_type* raw) noexcept : b_(raw) {}
     [[nodiscard]] builder_type* ptr() const noexcept { return b_; }
@@ -1404,6 +1462,14 @@ struct PointStampedTraits {
     static constexpr std::string_view name = "ros_point_stamped";
 };
 
+struct PoseStampedTraits {
+    using handle_type = ros_pose_stamped_t;
+    static constexpr auto from_cdr = ros_pose_stamped_from_cdr;
+    static constexpr auto free     = ros_pose_stamped_free;
+    static constexpr auto as_cdr   = ros_pose_stamped_as_cdr;
+    static constexpr std::string_view name = "ros_pose_stamped";
+};
+
 struct InertiaStampedTraits {
     using handle_type = ros_inertia_stamped_t;
     static constexpr auto from_cdr = ros_inertia_stamped_from_cdr;
@@ -2754,6 +2820,43 @@ public:
     }
 };
 
+// ---------------------------------------------------------------------------
+// geometry_msgs - PoseStamped (view-only)
+// ---------------------------------------------------------------------------
+
+/**
+ * @brief Non-owning, move-only view over a `geometry_msgs::PoseStamped`.
+ *
+ * @warning Backing CDR buffer must outlive this view.
+ * @note Move-only.
+ */
+class PoseStampedView
+    : public detail::ViewBase<PoseStampedView, detail::PoseStampedTraits> {
+    using Base = detail::ViewBase<PoseStampedView, detail::PoseStampedTraits>;
+    friend Base;
+    using Base::Base;
+public:
+    using Base::from_cdr;
+    using Base::as_cdr;
+
+    /// @brief Message timestamp.
+    [[nodiscard]] Time stamp() const noexcept {
+        return {ros_pose_stamped_get_stamp_sec(handle()),
+                ros_pose_stamped_get_stamp_nanosec(handle())};
+    }
+    /// @brief Coordinate frame identifier.
+    [[nodiscard]] std::string_view frame_id() const noexcept {
+        return ros_pose_stamped_get_frame_id(handle());
+    }
+    /// @brief Position and orientation in `frame_id`.
+    [[nodiscard]] Pose pose() const noexcept {
+        Pose p;
+        ros_pose_stamped_get_pose(handle(), &p.px, &p.py, &p.pz,
+                                  &p.ox, &p.oy, &p.oz, &p.ow);
+        return p;
+    }
+};
+
 // ---------------------------------------------------------------------------
 // geometry_msgs - InertiaStamped (view-only)
 // ---------------------------------------------------------------------------
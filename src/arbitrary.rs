@@ -0,0 +1,665 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Arbitrary message generation for property tests and fuzzing.
+//!
+//! [`Arbitrary`] builds a message of any schema type from a stream of
+//! unstructured bytes, so the same input always yields the same message and
+//! a fuzzer's coverage feedback steers which messages get built. Every
+//! generated message is well formed: it decodes with the type's `from_cdr`,
+//! sequences and strings respect their bounds, and sequence lengths are
+//! kept small enough that a few hundred input bytes produce a populated
+//! message rather than a wall of zeros.
+//!
+//! Generation is driven by the registry's `.msg` definitions
+//! ([`schema_registry::definition`](crate::schema_registry::definition)),
+//! so [`arbitrary_cdr`] works for any registered schema name, typed view
+//! or not.
+//!
+//! # Fuzzing
+//!
+//! ```rust,ignore
+//! // fuzz/fuzz_targets/pipeline.rs
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+//!     let mut u = Unstructured::new(data);
+//!     let img = Image::<Vec<u8>>::arbitrary(&mut u);
+//!     my_pipeline::process(&img);
+//! });
+//! ```
+//!
+//! # Property tests
+//!
+//! ```rust
+//! use edgefirst_schemas::arbitrary::{Arbitrary, Unstructured};
+//! use edgefirst_schemas::sensor_msgs::Imu;
+//!
+//! // With proptest, draw `data` from `any::<Vec<u8>>()` instead.
+//! for seed in 0u8..32 {
+//!     let data: Vec<u8> = (0..256u32).map(|i| (i as u8).wrapping_mul(seed | 1)).collect();
+//!     let imu = Imu::<Vec<u8>>::arbitrary(&mut Unstructured::new(&data));
+//!     let copy = Imu::from_cdr(imu.to_cdr()).unwrap();
+//!     assert_eq!(copy.to_cdr(), imu.to_cdr());
+//! }
+//! ```
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::cdr::{decode_fixed, CdrEnum, CdrSizer, CdrWriter};
+use crate::msg_spec::{Arity, Elem, Field, Prim, Resolver, Sink, Spec};
+
+/// Upper bound on the element count of an unbounded sequence of anything
+/// other than bytes, so nested sequences cannot blow up the message size.
+const MAX_SEQ_LEN: usize = 16;
+
+/// A cursor over the raw bytes that drive generation.
+///
+/// Once the input is exhausted every read yields zeros, so generation
+/// never fails for lack of input; it just produces empty sequences and
+/// zeroed fields from then on.
+#[derive(Debug, Clone, Copy)]
+pub struct Unstructured<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Unstructured<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Unstructured { data }
+    }
+
+    /// Number of input bytes not yet consumed.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Fill `buf` from the input, zero-filling whatever the input can't cover.
+    pub fn fill_buffer(&mut self, buf: &mut [u8]) {
+        let n = buf.len().min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        buf[n..].fill(0);
+        self.data = &self.data[n..];
+    }
+
+    /// Take up to `max` bytes from the input.
+    pub fn bytes(&mut self, max: usize) -> &'a [u8] {
+        let (head, rest) = self.data.split_at(max.min(self.data.len()));
+        self.data = rest;
+        head
+    }
+
+    /// A length in `0..=max`.
+    pub fn arbitrary_len(&mut self, max: usize) -> usize {
+        if self.data.is_empty() || max == 0 {
+            return 0;
+        }
+        (self.u32() as usize) % (max + 1)
+    }
+
+    /// An index in `0..len`. `len` must be nonzero.
+    pub fn choose_index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "choose_index from an empty set");
+        (self.u32() as usize) % len
+    }
+
+    pub fn u8(&mut self) -> u8 {
+        let mut b = [0u8; 1];
+        self.fill_buffer(&mut b);
+        b[0]
+    }
+
+    pub fn u16(&mut self) -> u16 {
+        let mut b = [0u8; 2];
+        self.fill_buffer(&mut b);
+        u16::from_le_bytes(b)
+    }
+
+    pub fn u32(&mut self) -> u32 {
+        let mut b = [0u8; 4];
+        self.fill_buffer(&mut b);
+        u32::from_le_bytes(b)
+    }
+
+    pub fn u64(&mut self) -> u64 {
+        let mut b = [0u8; 8];
+        self.fill_buffer(&mut b);
+        u64::from_le_bytes(b)
+    }
+}
+
+/// Types that can be generated from unstructured input.
+pub trait Arbitrary: Sized {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Self;
+}
+
+/// Generate a little-endian CDR message of type `schema_name` (e.g.
+/// `"sensor_msgs/msg/Image"`), encapsulation header included.
+///
+/// Returns `None` when the schema has no registered definition.
+pub fn arbitrary_cdr(schema_name: &str, u: &mut Unstructured<'_>) -> Option<Vec<u8>> {
+    let spec = Resolver::default().resolve(schema_name, 0).ok()?;
+    // Size with a copy of the input so the writing pass draws the same bytes.
+    let mut sizer = CdrSizer::new();
+    gen_msg(&spec, &mut u.clone(), &mut sizer);
+    let mut buf = vec![0u8; sizer.size()];
+    let mut w = CdrWriter::new(&mut buf).expect("sized buffer holds the header");
+    gen_msg(&spec, u, &mut w);
+    w.finish().expect("sized buffer holds the message");
+    Some(buf)
+}
+
+fn gen_msg(spec: &Spec, u: &mut Unstructured<'_>, s: &mut dyn Sink) {
+    for f in &spec.fields {
+        gen_field(f, u, s);
+    }
+}
+
+fn gen_field(f: &Field, u: &mut Unstructured<'_>, s: &mut dyn Sink) {
+    let bytes = matches!(f.elem, Elem::Prim(Prim::U8));
+    let count = match f.arity {
+        Arity::Single => return gen_elem(&f.elem, u, s),
+        Arity::Fixed(n) => n,
+        Arity::Seq(bound) => {
+            let cap = if bytes { u.len() } else { MAX_SEQ_LEN };
+            let n = u.arbitrary_len(bound.map_or(cap, |b| b.min(cap)));
+            s.u32(n as u32);
+            n
+        }
+    };
+    if bytes {
+        let mut data = vec![0u8; count];
+        u.fill_buffer(&mut data);
+        s.raw(&data);
+    } else {
+        for _ in 0..count {
+            gen_elem(&f.elem, u, s);
+        }
+    }
+}
+
+fn gen_elem(elem: &Elem, u: &mut Unstructured<'_>, s: &mut dyn Sink) {
+    match elem {
+        Elem::Msg(spec) => gen_msg(spec, u, s),
+        Elem::String(bound) => s.string(&text(u, *bound)),
+        Elem::Prim(Prim::Bool) => s.u8(u.u8() & 1),
+        Elem::Prim(Prim::I8 | Prim::U8) => s.u8(u.u8()),
+        Elem::Prim(Prim::I16 | Prim::U16) => s.u16(u.u16()),
+        Elem::Prim(Prim::I32 | Prim::U32 | Prim::F32) => s.u32(u.u32()),
+        Elem::Prim(Prim::I64 | Prim::U64 | Prim::F64) => s.u64(u.u64()),
+    }
+}
+
+/// A string of at most `bound` bytes: the valid UTF-8 prefix of the input,
+/// minus any NULs (CDR strings are NUL-terminated).
+fn text(u: &mut Unstructured<'_>, bound: Option<usize>) -> String {
+    let len = u.arbitrary_len(bound.unwrap_or(u.len()));
+    let raw = u.bytes(len);
+    let valid = match core::str::from_utf8(raw) {
+        Ok(valid) => valid,
+        Err(e) => core::str::from_utf8(&raw[..e.valid_up_to()]).unwrap_or(""),
+    };
+    valid.chars().filter(|&c| c != '\0').collect()
+}
+
+/// Buffer-backed views, generated from their registered definition.
+macro_rules! impl_arbitrary_view {
+    ($($ty:ident => $schema:literal),+ $(,)?) => {$(
+        #[allow(deprecated)]
+        impl Arbitrary for $ty<Vec<u8>> {
+            fn arbitrary(u: &mut Unstructured<'_>) -> Self {
+                let cdr = arbitrary_cdr($schema, u).expect(concat!($schema, " is registered"));
+                $ty::from_cdr(cdr).expect(concat!("generated ", $schema, " decodes"))
+            }
+        }
+    )+};
+}
+
+/// `CdrFixed` value types, generated from their registered definition.
+macro_rules! impl_arbitrary_fixed {
+    ($($ty:ty => $schema:literal),+ $(,)?) => {$(
+        impl Arbitrary for $ty {
+            fn arbitrary(u: &mut Unstructured<'_>) -> Self {
+                let cdr = arbitrary_cdr($schema, u).expect(concat!($schema, " is registered"));
+                decode_fixed(&cdr).expect(concat!("generated ", $schema, " decodes"))
+            }
+        }
+    )+};
+}
+
+/// Closed enums declared with `cdr_enum!`: one of the declared variants.
+macro_rules! impl_arbitrary_enum {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl Arbitrary for $ty {
+            fn arbitrary(u: &mut Unstructured<'_>) -> Self {
+                Self::VARIANTS[u.choose_index(Self::VARIANTS.len())]
+            }
+        }
+    )+};
+}
+
+use crate::edgefirst_msgs::*;
+use crate::foxglove_msgs::*;
+use crate::geometry_msgs::*;
+use crate::mavros_msgs::*;
+use crate::nav_msgs::{OccupancyGrid, Odometry};
+use crate::sensor_msgs::*;
+use crate::std_msgs::*;
+use crate::vision_msgs::{Detection2D, Detection2DArray, Detection3D, ObjectHypothesisWithPose};
+
+impl_arbitrary_view!(
+    Header => "std_msgs/msg/Header",
+    MultiArrayDimension => "std_msgs/msg/MultiArrayDimension",
+    MultiArrayLayout => "std_msgs/msg/MultiArrayLayout",
+    ByteMultiArray => "std_msgs/msg/ByteMultiArray",
+    Float32MultiArray => "std_msgs/msg/Float32MultiArray",
+    Float64MultiArray => "std_msgs/msg/Float64MultiArray",
+    Int16MultiArray => "std_msgs/msg/Int16MultiArray",
+    Int32MultiArray => "std_msgs/msg/Int32MultiArray",
+    Int64MultiArray => "std_msgs/msg/Int64MultiArray",
+    Int8MultiArray => "std_msgs/msg/Int8MultiArray",
+    UInt16MultiArray => "std_msgs/msg/UInt16MultiArray",
+    UInt32MultiArray => "std_msgs/msg/UInt32MultiArray",
+    UInt64MultiArray => "std_msgs/msg/UInt64MultiArray",
+    UInt8MultiArray => "std_msgs/msg/UInt8MultiArray",
+    AccelStamped => "geometry_msgs/msg/AccelStamped",
+    TwistStamped => "geometry_msgs/msg/TwistStamped",
+    InertiaStamped => "geometry_msgs/msg/InertiaStamped",
+    PointStamped => "geometry_msgs/msg/PointStamped",
+    PoseStamped => "geometry_msgs/msg/PoseStamped",
+    Polygon => "geometry_msgs/msg/Polygon",
+    PolygonStamped => "geometry_msgs/msg/PolygonStamped",
+    TransformStamped => "geometry_msgs/msg/TransformStamped",
+    Odometry => "nav_msgs/msg/Odometry",
+    OccupancyGrid => "nav_msgs/msg/OccupancyGrid",
+    CompressedImage => "sensor_msgs/msg/CompressedImage",
+    Image => "sensor_msgs/msg/Image",
+    Imu => "sensor_msgs/msg/Imu",
+    NavSatFix => "sensor_msgs/msg/NavSatFix",
+    PointField => "sensor_msgs/msg/PointField",
+    PointCloud2 => "sensor_msgs/msg/PointCloud2",
+    CameraInfo => "sensor_msgs/msg/CameraInfo",
+    MagneticField => "sensor_msgs/msg/MagneticField",
+    FluidPressure => "sensor_msgs/msg/FluidPressure",
+    Temperature => "sensor_msgs/msg/Temperature",
+    BatteryState => "sensor_msgs/msg/BatteryState",
+    LaserEcho => "sensor_msgs/msg/LaserEcho",
+    MultiEchoLaserScan => "sensor_msgs/msg/MultiEchoLaserScan",
+    Joy => "sensor_msgs/msg/Joy",
+    ChannelFloat32 => "sensor_msgs/msg/ChannelFloat32",
+    PointCloud => "sensor_msgs/msg/PointCloud",
+    FoxgloveCompressedVideo => "foxglove_msgs/msg/CompressedVideo",
+    FoxgloveTextAnnotation => "foxglove_msgs/msg/TextAnnotation",
+    FoxglovePointAnnotation => "foxglove_msgs/msg/PointsAnnotation",
+    FoxgloveImageAnnotation => "foxglove_msgs/msg/ImageAnnotations",
+    FoxgloveSceneEntity => "foxglove_msgs/msg/SceneEntity",
+    FoxgloveSceneUpdate => "foxglove_msgs/msg/SceneUpdate",
+    FoxgloveLaserScan => "foxglove_msgs/msg/LaserScan",
+    FoxgloveLocationFix => "foxglove_msgs/msg/LocationFix",
+    FoxgloveCameraCalibration => "foxglove_msgs/msg/CameraCalibration",
+    FoxglovePoseInFrame => "foxglove_msgs/msg/PoseInFrame",
+    FoxglovePosesInFrame => "foxglove_msgs/msg/PosesInFrame",
+    FoxgloveGeoJson => "foxglove_msgs/msg/GeoJSON",
+    FoxgloveRawImage => "foxglove_msgs/msg/RawImage",
+    Altitude => "mavros_msgs/msg/Altitude",
+    VfrHud => "mavros_msgs/msg/VfrHud",
+    EstimatorStatus => "mavros_msgs/msg/EstimatorStatus",
+    ExtendedState => "mavros_msgs/msg/ExtendedState",
+    SysStatus => "mavros_msgs/msg/SysStatus",
+    State => "mavros_msgs/msg/State",
+    StatusText => "mavros_msgs/msg/StatusText",
+    TimesyncStatus => "mavros_msgs/msg/TimesyncStatus",
+    Mask => "edgefirst_msgs/msg/Mask",
+    DmaBuffer => "edgefirst_msgs/msg/DmaBuffer",
+    LocalTime => "edgefirst_msgs/msg/LocalTime",
+    RadarCube => "edgefirst_msgs/msg/RadarCube",
+    RadarInfo => "edgefirst_msgs/msg/RadarInfo",
+    Track => "edgefirst_msgs/msg/Track",
+    DetectBox => "edgefirst_msgs/msg/Box",
+    Detect => "edgefirst_msgs/msg/Detect",
+    Model => "edgefirst_msgs/msg/Model",
+    ModelInfo => "edgefirst_msgs/msg/ModelInfo",
+    Vibration => "edgefirst_msgs/msg/Vibration",
+    Aggregate => "edgefirst_msgs/msg/Aggregate",
+    Attribute => "edgefirst_msgs/msg/Attribute",
+    VideoStreamRequest => "edgefirst_msgs/msg/VideoStreamRequest",
+    VideoStreamInfo => "edgefirst_msgs/msg/VideoStreamInfo",
+    CameraControl => "edgefirst_msgs/msg/CameraControl",
+    CameraStatus => "edgefirst_msgs/msg/CameraStatus",
+    Provenance => "edgefirst_msgs/msg/Provenance",
+    ShmRing => "edgefirst_msgs/msg/ShmRing",
+    BlobRef => "edgefirst_msgs/msg/BlobRef",
+    ImageWithInfo => "edgefirst_msgs/msg/ImageWithInfo",
+    FusionDebug => "edgefirst_msgs/msg/FusionDebug",
+    ServiceResult => "edgefirst_msgs/msg/ServiceResult",
+    ObjectHypothesisWithPose => "vision_msgs/msg/ObjectHypothesisWithPose",
+    Detection2D => "vision_msgs/msg/Detection2D",
+    Detection2DArray => "vision_msgs/msg/Detection2DArray",
+    Detection3D => "vision_msgs/msg/Detection3D",
+);
+
+impl_arbitrary_fixed!(
+    crate::builtin_interfaces::Time => "builtin_interfaces/msg/Time",
+    crate::builtin_interfaces::Duration => "builtin_interfaces/msg/Duration",
+    ColorRGBA => "std_msgs/msg/ColorRGBA",
+    Vector3 => "geometry_msgs/msg/Vector3",
+    Point => "geometry_msgs/msg/Point",
+    Point32 => "geometry_msgs/msg/Point32",
+    Quaternion => "geometry_msgs/msg/Quaternion",
+    Pose => "geometry_msgs/msg/Pose",
+    Pose2D => "geometry_msgs/msg/Pose2D",
+    Transform => "geometry_msgs/msg/Transform",
+    Accel => "geometry_msgs/msg/Accel",
+    Twist => "geometry_msgs/msg/Twist",
+    PoseWithCovariance => "geometry_msgs/msg/PoseWithCovariance",
+    TwistWithCovariance => "geometry_msgs/msg/TwistWithCovariance",
+    Inertia => "geometry_msgs/msg/Inertia",
+    NavSatStatus => "sensor_msgs/msg/NavSatStatus",
+    RegionOfInterest => "sensor_msgs/msg/RegionOfInterest",
+    Date => "edgefirst_msgs/msg/Date",
+    FoxglovePoint2 => "foxglove_msgs/msg/FoxglovePoint2",
+    FoxgloveColor => "foxglove_msgs/msg/FoxgloveColor",
+    FoxgloveCircleAnnotations => "foxglove_msgs/msg/FoxgloveCircleAnnotations",
+);
+
+impl_arbitrary_enum!(
+    RadarCubeDimension,
+    HeartbeatStatus,
+    FusionRejectReason,
+    ServiceErrorCode,
+    FoxgloveLogLevel,
+);
+
+// The typed layout pads `fix_type` to a 4-byte boundary, which the `.msg`
+// definition can't express, so this one is built field by field.
+impl Arbitrary for GpsRaw<Vec<u8>> {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Self {
+        let header = Header::<Vec<u8>>::arbitrary(u);
+        GpsRaw::new(
+            header.stamp(),
+            header.frame_id(),
+            u.u8(),
+            u.u32() as i32,
+            u.u32() as i32,
+            u.u32() as i32,
+            u.u16(),
+            u.u16(),
+            u.u16(),
+            u.u16(),
+            u.u8(),
+            u.u32() as i32,
+            u.u32(),
+            u.u32(),
+            u.u32(),
+            u.u32() as i32,
+            u.u16(),
+            u.u8(),
+            u.u32(),
+        )
+        .expect("GpsRaw encodes")
+    }
+}
+
+// `status` must be a declared `HeartbeatStatus`.
+impl Arbitrary for Heartbeat<Vec<u8>> {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Self {
+        let header = Header::<Vec<u8>>::arbitrary(u);
+        let service = text(u, None);
+        let message = text(u, None);
+        Heartbeat::builder()
+            .stamp(header.stamp())
+            .frame_id(header.frame_id())
+            .service(service.as_str())
+            .message(message.as_str())
+            .seq(u.u64())
+            .period(Arbitrary::arbitrary(u))
+            .status(Arbitrary::arbitrary(u))
+            .build()
+            .expect("Heartbeat encodes")
+    }
+}
+
+// `level` must be a declared `FoxgloveLogLevel`.
+impl Arbitrary for FoxgloveLog<Vec<u8>> {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Self {
+        let message = text(u, None);
+        let name = text(u, None);
+        let file = text(u, None);
+        FoxgloveLog::builder()
+            .timestamp(Arbitrary::arbitrary(u))
+            .level(Arbitrary::arbitrary(u))
+            .message(message.as_str())
+            .name(name.as_str())
+            .file(file.as_str())
+            .line(u.u32())
+            .build()
+            .expect("FoxgloveLog encodes")
+    }
+}
+
+// Frames need a nonzero size, and each plane either references a DMA-BUF
+// (`fd >= 0`, no inline data) or inlines exactly `size` bytes (`fd == -1`).
+impl Arbitrary for CameraFrame<Vec<u8>> {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Self {
+        let header = Header::<Vec<u8>>::arbitrary(u);
+        let strings: [String; 5] = core::array::from_fn(|_| text(u, None));
+        let planes: Vec<CameraPlaneView<'_>> = (0..u.arbitrary_len(4))
+            .map(|_| {
+                let (fd, data) = if u.u8() & 1 == 0 {
+                    let len = u.arbitrary_len(u.len().min(u32::MAX as usize));
+                    (-1, u.bytes(len))
+                } else {
+                    ((u.u32() >> 1) as i32, &[][..])
+                };
+                let size = if fd == -1 { data.len() as u32 } else { u.u32() };
+                CameraPlaneView {
+                    fd,
+                    offset: u.u32(),
+                    stride: u.u32(),
+                    size,
+                    used: (u.u64() % (size as u64 + 1)) as u32,
+                    data,
+                }
+            })
+            .collect();
+        CameraFrame::builder()
+            .stamp(header.stamp())
+            .frame_id(header.frame_id())
+            .seq(u.u64())
+            .pid(u.u32())
+            .width(u.u32().max(1))
+            .height(u.u32().max(1))
+            .format(strings[0].as_str())
+            .color_space(strings[1].as_str())
+            .color_transfer(strings[2].as_str())
+            .color_encoding(strings[3].as_str())
+            .color_range(strings[4].as_str())
+            .fence_fd(u.u32() as i32)
+            .planes(&planes)
+            .build()
+            .expect("CameraFrame encodes")
+    }
+}
+
+impl Arbitrary for crate::rosgraph_msgs::Clock {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Self {
+        crate::rosgraph_msgs::Clock {
+            clock: Arbitrary::arbitrary(u),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random inputs of varying length.
+    fn inputs() -> impl Iterator<Item = Vec<u8>> {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        (0..64).map(move |i| {
+            (0..i * 24)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect()
+        })
+    }
+
+    fn check<T: Arbitrary>() {
+        for data in inputs() {
+            T::arbitrary(&mut Unstructured::new(&data));
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn every_type_generates_decodable_messages() {
+        macro_rules! check_all {
+            ($($ty:ty),+ $(,)?) => {$( check::<$ty>(); )+};
+        }
+        check_all!(
+            Header<Vec<u8>>,
+            MultiArrayDimension<Vec<u8>>,
+            MultiArrayLayout<Vec<u8>>,
+            ByteMultiArray<Vec<u8>>,
+            Float32MultiArray<Vec<u8>>,
+            Float64MultiArray<Vec<u8>>,
+            Int16MultiArray<Vec<u8>>,
+            Int32MultiArray<Vec<u8>>,
+            Int64MultiArray<Vec<u8>>,
+            Int8MultiArray<Vec<u8>>,
+            UInt16MultiArray<Vec<u8>>,
+            UInt32MultiArray<Vec<u8>>,
+            UInt64MultiArray<Vec<u8>>,
+            UInt8MultiArray<Vec<u8>>,
+            AccelStamped<Vec<u8>>,
+            TwistStamped<Vec<u8>>,
+            InertiaStamped<Vec<u8>>,
+            PointStamped<Vec<u8>>,
+            PoseStamped<Vec<u8>>,
+            Polygon<Vec<u8>>,
+            PolygonStamped<Vec<u8>>,
+            TransformStamped<Vec<u8>>,
+            Odometry<Vec<u8>>,
+            OccupancyGrid<Vec<u8>>,
+            CompressedImage<Vec<u8>>,
+            Image<Vec<u8>>,
+            Imu<Vec<u8>>,
+            NavSatFix<Vec<u8>>,
+            PointField<Vec<u8>>,
+            PointCloud2<Vec<u8>>,
+            CameraInfo<Vec<u8>>,
+            MagneticField<Vec<u8>>,
+            FluidPressure<Vec<u8>>,
+            Temperature<Vec<u8>>,
+            BatteryState<Vec<u8>>,
+            LaserEcho<Vec<u8>>,
+            MultiEchoLaserScan<Vec<u8>>,
+            FoxgloveCompressedVideo<Vec<u8>>,
+            FoxgloveTextAnnotation<Vec<u8>>,
+            FoxglovePointAnnotation<Vec<u8>>,
+            FoxgloveImageAnnotation<Vec<u8>>,
+            FoxgloveSceneEntity<Vec<u8>>,
+            FoxgloveSceneUpdate<Vec<u8>>,
+            FoxgloveLaserScan<Vec<u8>>,
+            FoxgloveLocationFix<Vec<u8>>,
+            FoxgloveLog<Vec<u8>>,
+            FoxgloveCameraCalibration<Vec<u8>>,
+            FoxglovePoseInFrame<Vec<u8>>,
+            FoxglovePosesInFrame<Vec<u8>>,
+            FoxgloveGeoJson<Vec<u8>>,
+            FoxgloveRawImage<Vec<u8>>,
+            Altitude<Vec<u8>>,
+            VfrHud<Vec<u8>>,
+            EstimatorStatus<Vec<u8>>,
+            ExtendedState<Vec<u8>>,
+            SysStatus<Vec<u8>>,
+            State<Vec<u8>>,
+            StatusText<Vec<u8>>,
+            GpsRaw<Vec<u8>>,
+            TimesyncStatus<Vec<u8>>,
+            Mask<Vec<u8>>,
+            DmaBuffer<Vec<u8>>,
+            LocalTime<Vec<u8>>,
+            RadarCube<Vec<u8>>,
+            RadarInfo<Vec<u8>>,
+            Track<Vec<u8>>,
+            DetectBox<Vec<u8>>,
+            Detect<Vec<u8>>,
+            CameraFrame<Vec<u8>>,
+            Model<Vec<u8>>,
+            ModelInfo<Vec<u8>>,
+            Vibration<Vec<u8>>,
+            Aggregate<Vec<u8>>,
+            Attribute<Vec<u8>>,
+            VideoStreamRequest<Vec<u8>>,
+            VideoStreamInfo<Vec<u8>>,
+            CameraControl<Vec<u8>>,
+            CameraStatus<Vec<u8>>,
+            Provenance<Vec<u8>>,
+            ShmRing<Vec<u8>>,
+            Heartbeat<Vec<u8>>,
+            BlobRef<Vec<u8>>,
+            ImageWithInfo<Vec<u8>>,
+            FusionDebug<Vec<u8>>,
+            ServiceResult<Vec<u8>>,
+            ObjectHypothesisWithPose<Vec<u8>>,
+            Detection2D<Vec<u8>>,
+            Detection2DArray<Vec<u8>>,
+            Detection3D<Vec<u8>>,
+            crate::builtin_interfaces::Time,
+            crate::builtin_interfaces::Duration,
+            crate::rosgraph_msgs::Clock,
+            ColorRGBA,
+            Vector3,
+            Point,
+            Point32,
+            Quaternion,
+            Pose,
+            Pose2D,
+            Transform,
+            Accel,
+            Twist,
+            PoseWithCovariance,
+            TwistWithCovariance,
+            Inertia,
+            NavSatStatus,
+            RegionOfInterest,
+            Date,
+            FoxglovePoint2,
+            FoxgloveColor,
+            FoxgloveCircleAnnotations,
+            RadarCubeDimension,
+            HeartbeatStatus,
+            FusionRejectReason,
+            ServiceErrorCode,
+            FoxgloveLogLevel,
+        );
+    }
+
+    #[test]
+    fn same_input_same_message() {
+        for data in inputs() {
+            let a = arbitrary_cdr("sensor_msgs/msg/PointCloud2", &mut Unstructured::new(&data));
+            let b = arbitrary_cdr("sensor_msgs/msg/PointCloud2", &mut Unstructured::new(&data));
+            assert_eq!(a, b);
+        }
+        assert_eq!(
+            arbitrary_cdr("nope/msg/Nope", &mut Unstructured::new(&[])),
+            None
+        );
+    }
+
+    #[test]
+    fn exhausted_input_yields_empty_message() {
+        let img = Image::<Vec<u8>>::arbitrary(&mut Unstructured::new(&[]));
+        assert_eq!(img.encoding(), "");
+        assert!(img.data().is_empty());
+    }
+}
@@ -0,0 +1,358 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Fetching and verifying the artifacts referenced by [`BlobRef`].
+//!
+//! [`verify`] checks fetched bytes against the reference's size and
+//! SHA-256 digest. [`resolve`] fetches and verifies in one step for the
+//! schemes enabled at build time:
+//!
+//! - `file://` with the `blob-file` feature
+//! - `http://` with the `blob-http` feature (plain HTTP/1.0 GET, no TLS)
+//!
+//! Other schemes fail with [`BlobError::UnsupportedScheme`]; fetch them
+//! with your own client and call [`verify`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::blob;
+//! use edgefirst_schemas::edgefirst_msgs::BlobRef;
+//!
+//! let still = vec![0u8; 1024];
+//! let msg = BlobRef::builder()
+//!     .uri("file:///data/stills/0001.jpg")
+//!     .media_type("image/jpeg")
+//!     .contents(&still)
+//!     .build()
+//!     .unwrap();
+//! assert!(blob::verify(&msg, &still).is_ok());
+//! assert!(blob::verify(&msg, &still[1..]).is_err());
+//! ```
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt;
+
+use crate::edgefirst_msgs::BlobRef;
+
+/// Errors from resolving or verifying a [`BlobRef`].
+#[derive(Debug)]
+pub enum BlobError {
+    /// No resolver is available for the URI's scheme.
+    UnsupportedScheme(String),
+    /// The URI could not be parsed.
+    InvalidUri(String),
+    /// Reading the blob failed.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    /// The HTTP server answered with a non-200 status.
+    Http(u16),
+    /// The blob has `actual` bytes, the reference announced `expected`.
+    SizeMismatch { expected: u64, actual: u64 },
+    /// The blob's SHA-256 digest differs from the reference.
+    DigestMismatch,
+}
+
+impl fmt::Display for BlobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlobError::UnsupportedScheme(uri) => write!(f, "no blob resolver for {}", uri),
+            BlobError::InvalidUri(uri) => write!(f, "invalid blob URI {}", uri),
+            #[cfg(feature = "std")]
+            BlobError::Io(e) => write!(f, "blob I/O error: {}", e),
+            BlobError::Http(status) => write!(f, "blob HTTP status {}", status),
+            BlobError::SizeMismatch { expected, actual } => write!(
+                f,
+                "blob size mismatch: expected {} bytes, got {}",
+                expected, actual
+            ),
+            BlobError::DigestMismatch => write!(f, "blob SHA-256 mismatch"),
+        }
+    }
+}
+
+impl core::error::Error for BlobError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            BlobError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for BlobError {
+    fn from(e: std::io::Error) -> Self {
+        BlobError::Io(e)
+    }
+}
+
+/// Check `data` against the size and digest announced by `blob`.
+pub fn verify<B: AsRef<[u8]>>(blob: &BlobRef<B>, data: &[u8]) -> Result<(), BlobError> {
+    if data.len() as u64 != blob.size() {
+        return Err(BlobError::SizeMismatch {
+            expected: blob.size(),
+            actual: data.len() as u64,
+        });
+    }
+    if &sha256(data) != blob.sha256() {
+        return Err(BlobError::DigestMismatch);
+    }
+    Ok(())
+}
+
+/// Fetch the blob referenced by `blob` and verify it.
+pub fn resolve<B: AsRef<[u8]>>(blob: &BlobRef<B>) -> Result<Vec<u8>, BlobError> {
+    let uri = blob.uri();
+    let data: Vec<u8> = match uri.split_once("://") {
+        #[cfg(feature = "blob-file")]
+        Some(("file", _)) => resolve_file(uri),
+        #[cfg(feature = "blob-http")]
+        Some(("http", _)) => resolve_http(uri),
+        Some(_) => Err(BlobError::UnsupportedScheme(uri.to_owned())),
+        None => Err(BlobError::InvalidUri(uri.to_owned())),
+    }?;
+    verify(blob, &data)?;
+    Ok(data)
+}
+
+/// Read a `file://` URI. Only local paths (empty or `localhost` host) are
+/// accepted; percent-escapes are decoded.
+#[cfg(feature = "blob-file")]
+pub fn resolve_file(uri: &str) -> Result<Vec<u8>, BlobError> {
+    let invalid = || BlobError::InvalidUri(uri.to_owned());
+    let rest = uri.strip_prefix("file://").ok_or_else(invalid)?;
+    let path = rest.strip_prefix("localhost").unwrap_or(rest);
+    if !path.starts_with('/') {
+        return Err(invalid());
+    }
+    let path = percent_decode(path).ok_or_else(invalid)?;
+    Ok(std::fs::read(path)?)
+}
+
+#[cfg(feature = "blob-file")]
+fn percent_decode(s: &str) -> Option<String> {
+    let mut out = Vec::with_capacity(s.len());
+    let mut bytes = s.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            let hi = (bytes.next()? as char).to_digit(16)?;
+            let lo = (bytes.next()? as char).to_digit(16)?;
+            out.push((hi * 16 + lo) as u8);
+        } else {
+            out.push(b);
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
+/// Fetch an `http://` URI with a blocking HTTP/1.0 GET.
+#[cfg(feature = "blob-http")]
+pub fn resolve_http(uri: &str) -> Result<Vec<u8>, BlobError> {
+    use std::io::{Read, Write};
+
+    let invalid = || BlobError::InvalidUri(uri.to_owned());
+    let rest = uri.strip_prefix("http://").ok_or_else(invalid)?;
+    let (authority, path) = match rest.find('/') {
+        Some(i) => rest.split_at(i),
+        None => (rest, "/"),
+    };
+    if authority.is_empty() {
+        return Err(invalid());
+    }
+    let addr = if authority.contains(':') {
+        authority.to_owned()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    let mut stream = std::net::TcpStream::connect(addr)?;
+    write!(
+        stream,
+        "GET {} HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        path, authority
+    )?;
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+
+    let body_start = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(|| BlobError::Io(std::io::ErrorKind::InvalidData.into()))?
+        + 4;
+    let head = std::str::from_utf8(&response[..body_start])
+        .map_err(|_| BlobError::Io(std::io::ErrorKind::InvalidData.into()))?;
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse::<u16>().ok())
+        .ok_or_else(|| BlobError::Io(std::io::ErrorKind::InvalidData.into()))?;
+    if status != 200 {
+        return Err(BlobError::Http(status));
+    }
+    response.drain(..body_start);
+    Ok(response)
+}
+
+// ── SHA-256 (FIPS 180-4) ─────────────────────────────────────────────
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 digest of `data`.
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut h: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    let bit_len = (data.len() as u64).wrapping_mul(8);
+    let full = data.len() / 64 * 64;
+    let mut tail = data[full..].to_vec();
+    tail.push(0x80);
+    while tail.len() % 64 != 56 {
+        tail.push(0);
+    }
+    tail.extend_from_slice(&bit_len.to_be_bytes());
+
+    for block in data[..full].chunks_exact(64).chain(tail.chunks_exact(64)) {
+        let mut w = [0u32; 64];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = hh
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (acc, v) in h.iter_mut().zip([a, b, c, d, e, f, g, hh]) {
+            *acc = acc.wrapping_add(v);
+        }
+    }
+
+    let mut out = [0u8; 32];
+    for (chunk, word) in out.chunks_exact_mut(4).zip(h) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(b: &[u8]) -> String {
+        b.iter().map(|x| format!("{:02x}", x)).collect()
+    }
+
+    #[test]
+    fn sha256_reference_vectors() {
+        assert_eq!(
+            hex(&sha256(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(&sha256(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex(&sha256(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn verify_checks_size_and_digest() {
+        let data = b"model weights";
+        let msg = BlobRef::builder()
+            .uri("s3://bucket/model.tflite")
+            .media_type("application/octet-stream")
+            .contents(data)
+            .build()
+            .unwrap();
+        let msg = BlobRef::from_cdr(msg.into_cdr()).unwrap();
+        assert_eq!(msg.size(), data.len() as u64);
+        assert!(verify(&msg, data).is_ok());
+        assert!(matches!(
+            verify(&msg, b"model weightz"),
+            Err(BlobError::DigestMismatch)
+        ));
+        assert!(matches!(
+            resolve(&msg),
+            Err(BlobError::UnsupportedScheme(_))
+        ));
+    }
+
+    #[cfg(feature = "blob-file")]
+    #[test]
+    fn resolve_file_uri() {
+        let path = std::env::temp_dir().join(format!("blob ref {}.bin", std::process::id()));
+        std::fs::write(&path, b"still").unwrap();
+        let uri = format!("file://{}", path.display()).replace(' ', "%20");
+        let msg = BlobRef::builder()
+            .uri(uri)
+            .contents(b"still")
+            .build()
+            .unwrap();
+        assert_eq!(resolve(&msg).unwrap(), b"still");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(feature = "blob-http")]
+    #[test]
+    fn resolve_http_uri() {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut s, _) = listener.accept().unwrap();
+            let mut req = [0u8; 256];
+            let _ = s.read(&mut req).unwrap();
+            s.write_all(b"HTTP/1.0 200 OK\r\nContent-Length: 4\r\n\r\nclip")
+                .unwrap();
+        });
+        let msg = BlobRef::builder()
+            .uri(format!("http://127.0.0.1:{}/seg/1.mp4", port))
+            .contents(b"clip")
+            .build()
+            .unwrap();
+        assert_eq!(resolve(&msg).unwrap(), b"clip");
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! IDL bounded types: `string<N>` and `sequence<T, N>`.
+//!
+//! [`BoundedString<N>`] and [`BoundedVec<T, N>`] hold at most `N` bytes or
+//! elements. The bound is checked when a value is constructed, so anything
+//! that reaches the wire is within bounds, and again when decoding, so an
+//! oversized value from a non-conforming peer is rejected with
+//! [`CdrError::BoundExceeded`] instead of being accepted silently.
+//!
+//! Buffer-backed message types that declare bounded fields read them with
+//! [`CdrCursor::read_string_bounded`] / [`CdrCursor::read_seq_len_bounded`]
+//! and write them with [`CdrWriter::write_string_bounded`].
+//!
+//! ```rust
+//! use edgefirst_schemas::bounded::BoundedString;
+//! use edgefirst_schemas::cdr::CdrError;
+//!
+//! let id = BoundedString::<8>::new("cam0").unwrap();
+//! assert_eq!(id.as_str(), "cam0");
+//! assert!(matches!(
+//!     BoundedString::<8>::new("front_camera"),
+//!     Err(CdrError::BoundExceeded { len: 12, bound: 8, offset: None })
+//! ));
+//! ```
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt;
+use core::ops::Deref;
+
+use crate::cdr::{CdrCursor, CdrError, CdrFixed, CdrSizer, CdrWriter};
+
+fn exceeded(len: usize, bound: usize) -> CdrError {
+    CdrError::BoundExceeded {
+        len,
+        bound,
+        offset: None,
+    }
+}
+
+// ── BoundedString<N> ────────────────────────────────────────────────
+
+/// A UTF-8 string of at most `N` bytes (IDL `string<N>`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedString<const N: usize>(String);
+
+impl<const N: usize> BoundedString<N> {
+    /// The bound in bytes, NUL terminator excluded.
+    pub const BOUND: usize = N;
+
+    /// Wrap `s`, failing with [`CdrError::BoundExceeded`] if it is longer
+    /// than `N` bytes.
+    pub fn new(s: impl Into<String>) -> Result<Self, CdrError> {
+        let s = s.into();
+        if s.len() > N {
+            return Err(exceeded(s.len(), N));
+        }
+        Ok(BoundedString(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Decode from `cursor`, rejecting strings longer than `N` bytes.
+    pub fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        cursor
+            .read_string_bounded(N)
+            .map(|s| BoundedString(s.to_owned()))
+    }
+
+    pub fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        writer.write_string_bounded(&self.0, N);
+    }
+
+    pub fn size_cdr(&self, sizer: &mut CdrSizer) {
+        sizer.size_string(&self.0);
+    }
+}
+
+impl<const N: usize> Deref for BoundedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsRef<str> for BoundedString<N> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> fmt::Display for BoundedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for BoundedString<N> {
+    type Error = CdrError;
+
+    fn try_from(s: &str) -> Result<Self, CdrError> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize> TryFrom<String> for BoundedString<N> {
+    type Error = CdrError;
+
+    fn try_from(s: String) -> Result<Self, CdrError> {
+        Self::new(s)
+    }
+}
+
+// ── BoundedVec<T, N> ────────────────────────────────────────────────
+
+/// A sequence of at most `N` fixed-size elements (IDL `sequence<T, N>`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundedVec<T, const N: usize>(Vec<T>);
+
+impl<T, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        BoundedVec(Vec::new())
+    }
+}
+
+impl<T: CdrFixed, const N: usize> BoundedVec<T, N> {
+    /// The maximum number of elements.
+    pub const BOUND: usize = N;
+
+    /// Wrap `v`, failing with [`CdrError::BoundExceeded`] if it holds more
+    /// than `N` elements.
+    pub fn new(v: Vec<T>) -> Result<Self, CdrError> {
+        if v.len() > N {
+            return Err(exceeded(v.len(), N));
+        }
+        Ok(BoundedVec(v))
+    }
+
+    /// Append `value`, failing (and leaving the sequence unchanged) if it is
+    /// already full.
+    pub fn push(&mut self, value: T) -> Result<(), CdrError> {
+        if self.0.len() >= N {
+            return Err(exceeded(self.0.len() + 1, N));
+        }
+        self.0.push(value);
+        Ok(())
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Decode from `cursor`, rejecting sequences of more than `N` elements
+    /// before any element is read.
+    pub fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        let len = cursor.read_seq_len_bounded(N)?;
+        let count = cursor.check_seq_count(len as u32, 1)?;
+        let mut v = Vec::with_capacity(count);
+        for _ in 0..count {
+            v.push(T::read_cdr(cursor)?);
+        }
+        Ok(BoundedVec(v))
+    }
+
+    pub fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        writer.write_u32(self.0.len() as u32);
+        for v in &self.0 {
+            v.write_cdr(writer);
+        }
+    }
+
+    pub fn size_cdr(&self, sizer: &mut CdrSizer) {
+        sizer.size_u32();
+        for _ in &self.0 {
+            T::size_cdr(sizer);
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: CdrFixed, const N: usize> TryFrom<Vec<T>> for BoundedVec<T, N> {
+    type Error = CdrError;
+
+    fn try_from(v: Vec<T>) -> Result<Self, CdrError> {
+        Self::new(v)
+    }
+}
+
+impl<T: CdrFixed, const N: usize> TryFrom<&[T]> for BoundedVec<T, N> {
+    type Error = CdrError;
+
+    fn try_from(v: &[T]) -> Result<Self, CdrError> {
+        Self::new(v.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+
+    fn encode(f: impl Fn(&mut CdrWriter<'_>), size: impl Fn(&mut CdrSizer)) -> Vec<u8> {
+        let mut s = CdrSizer::new();
+        size(&mut s);
+        let mut buf = vec![0u8; s.size()];
+        let mut w = CdrWriter::new(&mut buf).unwrap();
+        f(&mut w);
+        w.finish().unwrap();
+        buf
+    }
+
+    #[test]
+    fn bounded_roundtrip() {
+        let name = BoundedString::<16>::new("radar").unwrap();
+        let stamps =
+            BoundedVec::<Time, 4>::try_from(&[Time::new(1, 0), Time::new(2, 0)][..]).unwrap();
+        let buf = encode(
+            |w| {
+                name.write_cdr(w);
+                stamps.write_cdr(w);
+            },
+            |s| {
+                name.size_cdr(s);
+                stamps.size_cdr(s);
+            },
+        );
+        let mut c = CdrCursor::new(&buf).unwrap();
+        assert_eq!(BoundedString::<16>::read_cdr(&mut c).unwrap(), name);
+        assert_eq!(BoundedVec::<Time, 4>::read_cdr(&mut c).unwrap(), stamps);
+    }
+
+    #[test]
+    fn oversized_values_rejected() {
+        assert!(BoundedString::<3>::new("abcd").is_err());
+        let mut v = BoundedVec::<Time, 1>::default();
+        v.push(Time::new(0, 0)).unwrap();
+        assert!(v.push(Time::new(1, 0)).is_err());
+        assert_eq!(v.len(), 1);
+
+        // Decoding a value serialized under a looser bound.
+        let wide = BoundedString::<32>::new("front_camera").unwrap();
+        let buf = encode(|w| wide.write_cdr(w), |s| wide.size_cdr(s));
+        let mut c = CdrCursor::new(&buf).unwrap();
+        assert!(matches!(
+            BoundedString::<8>::read_cdr(&mut c),
+            Err(CdrError::BoundExceeded {
+                len: 12,
+                bound: 8,
+                offset: Some(4)
+            })
+        ));
+
+        let many = BoundedVec::<Time, 8>::new(vec![Time::new(0, 0); 3]).unwrap();
+        let buf = encode(|w| many.write_cdr(w), |s| many.size_cdr(s));
+        let mut c = CdrCursor::new(&buf).unwrap();
+        assert!(matches!(
+            BoundedVec::<Time, 2>::read_cdr(&mut c),
+            Err(CdrError::BoundExceeded {
+                len: 3,
+                bound: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn writer_enforces_bound() {
+        let mut buf = [0u8; 32];
+        let mut w = CdrWriter::new(&mut buf).unwrap();
+        w.write_string_bounded("toolong", 4);
+        assert!(matches!(
+            w.finish(),
+            Err(CdrError::BoundExceeded {
+                len: 7,
+                bound: 4,
+                offset: Some(4)
+            })
+        ));
+    }
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright © 2025 Au-Zone Technologies. All Rights Reserved.
 
-use std::time::Duration as Dur;
+use core::time::Duration as Dur;
 
 const NSEC_IN_SEC: u64 = 1_000_000_000;
 
@@ -75,6 +75,16 @@ pub fn list_types() -> &'static [&'static str] {
     ]
 }
 
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "Duration" => "int32 sec\nuint32 nanosec\n",
+        "Time" => "int32 sec\nuint32 nanosec\n",
+        _ => return None,
+    })
+}
+
 // CdrFixed implementations
 use crate::cdr::{CdrCursor, CdrError, CdrFixed, CdrSizer, CdrWriter};
 
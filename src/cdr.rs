@@ -24,29 +24,170 @@
 //! offsets, used by buffer-backed types after validation. These are `pub(crate)`
 //! and not part of the public API.
 
-use std::fmt;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 /// CDR1 Little-Endian encapsulation header.
 pub const CDR_LE_HEADER: [u8; 4] = [0x00, 0x01, 0x00, 0x00];
 
+/// CDR1 Big-Endian encapsulation header.
+pub const CDR_BE_HEADER: [u8; 4] = [0x00, 0x00, 0x00, 0x00];
+
+/// Byte order of an encoded CDR stream.
+///
+/// Everything in this crate produces [`Endianness::Little`] by default and
+/// buffer-backed messages only accept it. [`Endianness::Big`] output exists
+/// for interoperating with big-endian DDS participants and for generating
+/// test vectors: [`encode_fixed_with`], [`CdrEncode::encode_into_slice_with`]
+/// (every `CdrFixed` type and builder) and [`CdrSerializer::serialize_with`]
+/// write it. [`decode_fixed`] and [`CdrCursor::detect`] read either
+/// order, and [`crate::message::to_little_endian`] converts a big-endian
+/// message of any registered schema for the buffer-backed views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endianness {
+    #[default]
+    Little,
+    Big,
+}
+
+impl Endianness {
+    /// Encapsulation header announcing this byte order.
+    pub fn header(self) -> [u8; 4] {
+        match self {
+            Endianness::Little => CDR_LE_HEADER,
+            Endianness::Big => CDR_BE_HEADER,
+        }
+    }
+
+    /// Byte order announced by the encapsulation header at the start of
+    /// `buf`.
+    pub fn detect(buf: &[u8]) -> Result<Endianness, CdrError> {
+        if buf.len() < CDR_HEADER_SIZE {
+            return Err(CdrError::BufferTooShort {
+                need: CDR_HEADER_SIZE,
+                have: buf.len(),
+            });
+        }
+        match buf[0..4].try_into().expect("length checked above") {
+            CDR_LE_HEADER => Ok(Endianness::Little),
+            CDR_BE_HEADER => Ok(Endianness::Big),
+            _ => Err(CdrError::InvalidHeader),
+        }
+    }
+}
+
 /// Size of the CDR encapsulation header in bytes.
 pub const CDR_HEADER_SIZE: usize = 4;
 
 // ── Error ────────────────────────────────────────────────────────────
 
 /// Errors that can occur during CDR read/write operations.
+///
+/// Decode errors carry the absolute byte offset (encapsulation header
+/// included) at which the problem was found. Errors raised while scanning a
+/// named message field are wrapped in [`CdrError::Field`]; use
+/// [`CdrError::field`] and [`CdrError::offset`] to get at the details
+/// without matching on the wrapper.
 #[derive(Debug)]
 pub enum CdrError {
-    /// Buffer is too short for the requested operation.
+    /// Buffer is too short for the requested operation: the data ends at
+    /// byte `have` but `need` bytes are required.
     BufferTooShort { need: usize, have: usize },
-    /// A CDR string contained invalid UTF-8.
-    InvalidUtf8,
-    /// A CDR string was missing its NUL terminator.
-    MissingNul,
+    /// The string starting at `offset` contained invalid UTF-8.
+    InvalidUtf8 { offset: usize },
+    /// The string starting at `offset` was missing its NUL terminator.
+    MissingNul { offset: usize },
     /// The CDR encapsulation header was invalid.
     InvalidHeader,
-    /// A boolean value was not 0 or 1.
-    InvalidBool(u8),
+    /// The message is big-endian, which buffer-backed views cannot read in
+    /// place; convert it with [`message::to_little_endian`] first.
+    ///
+    /// [`message::to_little_endian`]: crate::message::to_little_endian
+    UnsupportedEndianness,
+    /// The boolean at `offset` was not 0 or 1.
+    InvalidBool { value: u8, offset: usize },
+    /// The sequence length prefix at `offset` announces more elements than
+    /// the remaining buffer could possibly hold.
+    SequenceTooLong { count: u32, offset: usize },
+    /// `count` bytes beyond alignment padding followed the last field,
+    /// which ended at `offset` (only reported under [`decode_exact`]).
+    TrailingBytes { offset: usize, count: usize },
+    /// A bounded string or sequence (IDL `string<N>` / `sequence<T, N>`)
+    /// held `len` bytes or elements, more than its `bound`. `offset` is the
+    /// position of the length prefix, or `None` when the value was rejected
+    /// before serialization.
+    BoundExceeded {
+        len: usize,
+        bound: usize,
+        offset: Option<usize>,
+    },
+    /// `value` is not a discriminant of the enum `type_name`. `offset` is
+    /// the position of the value on the wire, or `None` when it came from a
+    /// plain integer conversion.
+    UnknownDiscriminant {
+        type_name: &'static str,
+        value: u32,
+        offset: Option<usize>,
+    },
+    /// A length at `offset` exceeded the [`DecodeLimits`] field `limit`.
+    LimitExceeded {
+        limit: &'static str,
+        value: usize,
+        max: usize,
+        offset: usize,
+    },
+    /// `source` occurred while decoding the message field `field`.
+    Field {
+        field: &'static str,
+        source: Box<CdrError>,
+    },
+}
+
+impl CdrError {
+    /// Attach the name of the message field being decoded.
+    pub fn in_field(self, field: &'static str) -> CdrError {
+        CdrError::Field {
+            field,
+            source: Box::new(self),
+        }
+    }
+
+    /// Innermost field name attached to this error, if any.
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            CdrError::Field { field, source } => source.field().or(Some(field)),
+            _ => None,
+        }
+    }
+
+    /// Byte offset at which decoding failed, if known.
+    ///
+    /// For [`CdrError::BufferTooShort`] this is the end of the available
+    /// data, i.e. where the buffer ended prematurely.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            CdrError::BufferTooShort { have, .. } => Some(*have),
+            CdrError::InvalidUtf8 { offset }
+            | CdrError::MissingNul { offset }
+            | CdrError::InvalidBool { offset, .. }
+            | CdrError::SequenceTooLong { offset, .. }
+            | CdrError::LimitExceeded { offset, .. }
+            | CdrError::TrailingBytes { offset, .. } => Some(*offset),
+            CdrError::InvalidHeader | CdrError::UnsupportedEndianness => Some(0),
+            CdrError::BoundExceeded { offset, .. }
+            | CdrError::UnknownDiscriminant { offset, .. } => *offset,
+            CdrError::Field { source, .. } => source.offset(),
+        }
+    }
+
+    /// The error with any field wrappers removed.
+    pub fn root(&self) -> &CdrError {
+        match self {
+            CdrError::Field { source, .. } => source.root(),
+            e => e,
+        }
+    }
 }
 
 impl fmt::Display for CdrError {
@@ -59,15 +200,103 @@ impl fmt::Display for CdrError {
                     need, have
                 )
             }
-            CdrError::InvalidUtf8 => write!(f, "CDR string contains invalid UTF-8"),
-            CdrError::MissingNul => write!(f, "CDR string missing NUL terminator"),
+            CdrError::InvalidUtf8 { offset } => {
+                write!(f, "CDR string at offset {} contains invalid UTF-8", offset)
+            }
+            CdrError::MissingNul { offset } => {
+                write!(f, "CDR string at offset {} missing NUL terminator", offset)
+            }
             CdrError::InvalidHeader => write!(f, "invalid CDR encapsulation header"),
-            CdrError::InvalidBool(v) => write!(f, "invalid CDR bool value: {}", v),
+            CdrError::UnsupportedEndianness => {
+                write!(f, "big-endian CDR cannot be read in place")
+            }
+            CdrError::InvalidBool { value, offset } => {
+                write!(f, "invalid CDR bool value {} at offset {}", value, offset)
+            }
+            CdrError::SequenceTooLong { count, offset } => write!(
+                f,
+                "CDR sequence length {} at offset {} exceeds remaining buffer",
+                count, offset
+            ),
+            CdrError::BoundExceeded {
+                len,
+                bound,
+                offset: Some(offset),
+            } => write!(
+                f,
+                "bounded value at offset {} has length {}, bound is {}",
+                offset, len, bound
+            ),
+            CdrError::BoundExceeded { len, bound, .. } => {
+                write!(f, "bounded value has length {}, bound is {}", len, bound)
+            }
+            CdrError::UnknownDiscriminant {
+                type_name,
+                value,
+                offset: Some(offset),
+            } => write!(
+                f,
+                "unknown {} discriminant {} at offset {}",
+                type_name, value, offset
+            ),
+            CdrError::UnknownDiscriminant {
+                type_name, value, ..
+            } => write!(f, "unknown {} discriminant {}", type_name, value),
+            CdrError::TrailingBytes { offset, count } => write!(
+                f,
+                "{} unexpected trailing bytes after message end at offset {}",
+                count, offset
+            ),
+            CdrError::LimitExceeded {
+                limit,
+                value,
+                max,
+                offset,
+            } => write!(
+                f,
+                "CDR length {} at offset {} exceeds {} ({})",
+                value, offset, limit, max
+            ),
+            CdrError::Field { field, source } => {
+                write!(f, "field `{}`", field)?;
+                let mut inner = source.as_ref();
+                while let CdrError::Field { field, source } = inner {
+                    write!(f, ".{}", field)?;
+                    inner = source;
+                }
+                write!(f, ": {}", inner)
+            }
         }
     }
 }
 
-impl std::error::Error for CdrError {}
+impl core::error::Error for CdrError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            CdrError::Field { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Attach field names to decode results: `c.read_string().field("format")?`.
+pub(crate) trait FieldContext<T> {
+    fn field(self, name: &'static str) -> Result<T, CdrError>;
+}
+
+impl<T> FieldContext<T> for Result<T, CdrError> {
+    #[inline(always)]
+    fn field(self, name: &'static str) -> Result<T, CdrError> {
+        self.map_err(|e| e.in_field(name))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<CdrError> for std::io::Error {
+    fn from(e: CdrError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+    }
+}
 
 // ── Alignment ────────────────────────────────────────────────────────
 
@@ -89,35 +318,335 @@ pub const fn cdr_align(pos: usize, n: usize) -> usize {
     CDR_HEADER_SIZE + align(pos - CDR_HEADER_SIZE, n)
 }
 
+// ── Decode limits ────────────────────────────────────────────────────
+
+/// Upper bounds enforced while validating untrusted CDR input.
+///
+/// Decoding is zero-copy and every length is already checked against the
+/// bytes actually present, so a corrupt prefix cannot allocate; these limits
+/// additionally let a service bound what it is willing to accept from the
+/// network (and what accessors such as `Detect::boxes()` will later collect
+/// into a `Vec`). Exceeding a limit yields [`CdrError::LimitExceeded`].
+///
+/// `from_cdr` on every message type applies the process-wide limits set
+/// with [`DecodeLimits::set_global`], or those of an enclosing
+/// [`DecodeLimits::scoped`] call on the same thread; a [`CdrCursor`] built
+/// with [`CdrCursor::with_limits`] uses its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// Maximum size of a whole message in bytes, encapsulation included.
+    pub max_message_size: usize,
+    /// Maximum element count of any sequence (including byte sequences).
+    pub max_sequence_len: usize,
+    /// Maximum length of any string in bytes, NUL included.
+    pub max_string_len: usize,
+    /// Reject bytes left over after the last field, other than the zero
+    /// padding up to the next 4-byte boundary (see [`decode_exact`]).
+    pub reject_trailing_bytes: bool,
+}
+
+impl DecodeLimits {
+    /// Defaults: 1 GiB messages, 64 Mi-element sequences, 16 MiB strings —
+    /// generous for 4K images, point clouds and radar cubes.
+    pub const DEFAULT: DecodeLimits = DecodeLimits {
+        max_message_size: 1 << 30,
+        max_sequence_len: 1 << 26,
+        max_string_len: 1 << 24,
+        reject_trailing_bytes: false,
+    };
+
+    /// No limits beyond the buffer bounds themselves.
+    pub const UNLIMITED: DecodeLimits = DecodeLimits {
+        max_message_size: usize::MAX,
+        max_sequence_len: usize::MAX,
+        max_string_len: usize::MAX,
+        reject_trailing_bytes: false,
+    };
+
+    /// The process-wide limits applied by `from_cdr`.
+    pub fn global() -> DecodeLimits {
+        DecodeLimits {
+            max_message_size: GLOBAL_LIMITS[0].load(Ordering::Relaxed),
+            max_sequence_len: GLOBAL_LIMITS[1].load(Ordering::Relaxed),
+            max_string_len: GLOBAL_LIMITS[2].load(Ordering::Relaxed),
+            reject_trailing_bytes: GLOBAL_REJECT_TRAILING.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Replace the process-wide limits applied by `from_cdr`.
+    ///
+    /// Messages already decoded are unaffected.
+    pub fn set_global(limits: DecodeLimits) {
+        GLOBAL_LIMITS[0].store(limits.max_message_size, Ordering::Relaxed);
+        GLOBAL_LIMITS[1].store(limits.max_sequence_len, Ordering::Relaxed);
+        GLOBAL_LIMITS[2].store(limits.max_string_len, Ordering::Relaxed);
+        GLOBAL_REJECT_TRAILING.store(limits.reject_trailing_bytes, Ordering::Relaxed);
+    }
+
+    /// The limits in effect on this thread: those of the innermost
+    /// [`scoped`](DecodeLimits::scoped) call, else the global ones.
+    ///
+    /// Without the `std` feature there are no scoped limits and this is
+    /// [`global`](DecodeLimits::global).
+    pub fn current() -> DecodeLimits {
+        #[cfg(feature = "std")]
+        if let Some(limits) = SCOPED_LIMITS.with(|l| l.get()) {
+            return limits;
+        }
+        DecodeLimits::global()
+    }
+
+    /// Run `f` with these limits applied to every decode on the current
+    /// thread, restoring the previous limits afterwards (even on panic).
+    #[cfg(feature = "std")]
+    pub fn scoped<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<DecodeLimits>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SCOPED_LIMITS.with(|l| l.set(self.0));
+            }
+        }
+        let _restore = Restore(SCOPED_LIMITS.with(|l| l.replace(Some(self))));
+        f()
+    }
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        DecodeLimits::DEFAULT
+    }
+}
+
+static GLOBAL_LIMITS: [AtomicUsize; 3] = [
+    AtomicUsize::new(DecodeLimits::DEFAULT.max_message_size),
+    AtomicUsize::new(DecodeLimits::DEFAULT.max_sequence_len),
+    AtomicUsize::new(DecodeLimits::DEFAULT.max_string_len),
+];
+static GLOBAL_REJECT_TRAILING: AtomicBool =
+    AtomicBool::new(DecodeLimits::DEFAULT.reject_trailing_bytes);
+
+#[cfg(feature = "std")]
+thread_local! {
+    static SCOPED_LIMITS: core::cell::Cell<Option<DecodeLimits>> = const { core::cell::Cell::new(None) };
+}
+
+/// Run a decode with trailing-byte rejection enabled, on top of the limits
+/// currently in effect.
+///
+/// `from_cdr` normally ignores bytes after the last field. Inside
+/// `decode_exact` any such bytes — other than zero padding up to the next
+/// 4-byte boundary — fail with [`CdrError::TrailingBytes`], which catches
+/// publisher/subscriber schema mismatches that would otherwise decode
+/// "successfully".
+///
+/// ```rust
+/// use edgefirst_schemas::cdr::{decode_exact, CdrError};
+/// use edgefirst_schemas::std_msgs::Header;
+///
+/// let mut wire = Header::builder().frame_id("cam").build().unwrap().into_cdr();
+/// assert!(decode_exact(|| Header::from_cdr(&wire[..])).is_ok());
+/// wire.extend_from_slice(&[1, 2, 3, 4]);
+/// assert!(Header::from_cdr(&wire[..]).is_ok());
+/// assert!(matches!(
+///     decode_exact(|| Header::from_cdr(&wire[..])),
+///     Err(CdrError::TrailingBytes { .. })
+/// ));
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_exact<T>(f: impl FnOnce() -> Result<T, CdrError>) -> Result<T, CdrError> {
+    DecodeLimits {
+        reject_trailing_bytes: true,
+        ..DecodeLimits::current()
+    }
+    .scoped(f)
+}
+
 // ── CdrCursor — zero-copy reader ─────────────────────────────────────
 
 /// A zero-copy cursor for reading CDR-encoded data from a byte buffer.
+///
+/// Cursors created with [`new`](Self::new) accept only little-endian
+/// buffers, because buffer-backed messages read their fields in place at
+/// little-endian offsets. [`detect`](Self::detect) follows the byte order
+/// announced by the encapsulation header and
+/// [`with_endianness`](Self::with_endianness) overrides it; both suit
+/// decoders that copy values out, such as [`decode_fixed`].
 pub struct CdrCursor<'a> {
     buf: &'a [u8],
     pos: usize,
+    /// Where the data starts and alignment is measured from: after the
+    /// encapsulation header, or 0 for a bare payload.
+    base: usize,
+    limits: DecodeLimits,
+    big_endian: bool,
 }
 
 impl<'a> CdrCursor<'a> {
-    /// Create a new cursor over `buf`, starting after the 4-byte CDR header.
+    /// Create a new cursor over a little-endian `buf`, starting after the
+    /// 4-byte CDR header, enforcing the current [`DecodeLimits`]. A
+    /// big-endian `buf` fails with [`CdrError::UnsupportedEndianness`].
     pub fn new(buf: &'a [u8]) -> Result<Self, CdrError> {
+        Self::with_limits(buf, DecodeLimits::current())
+    }
+
+    /// Create a new cursor over a little-endian `buf` enforcing `limits`.
+    pub fn with_limits(buf: &'a [u8], limits: DecodeLimits) -> Result<Self, CdrError> {
+        Self::open(buf, limits, None)?.expect_order(Endianness::Little)
+    }
+
+    /// Create a cursor decoding in the byte order announced by the
+    /// encapsulation header (CDR LE or CDR BE).
+    pub fn detect(buf: &'a [u8]) -> Result<Self, CdrError> {
+        Self::open(buf, DecodeLimits::current(), None)
+    }
+
+    /// Create a cursor decoding in `endianness` whatever the encapsulation
+    /// header announces, for peers that mislabel their payloads.
+    pub fn with_endianness(buf: &'a [u8], endianness: Endianness) -> Result<Self, CdrError> {
+        Self::open(buf, DecodeLimits::current(), Some(endianness))
+    }
+
+    fn expect_order(self, endianness: Endianness) -> Result<Self, CdrError> {
+        if self.endianness() == endianness {
+            Ok(self)
+        } else {
+            Err(CdrError::UnsupportedEndianness)
+        }
+    }
+
+    fn open(
+        buf: &'a [u8],
+        limits: DecodeLimits,
+        order: Option<Endianness>,
+    ) -> Result<Self, CdrError> {
+        if buf.len() > limits.max_message_size {
+            return Err(CdrError::LimitExceeded {
+                limit: "max_message_size",
+                value: buf.len(),
+                max: limits.max_message_size,
+                offset: 0,
+            });
+        }
         if buf.len() < CDR_HEADER_SIZE {
             return Err(CdrError::BufferTooShort {
                 need: CDR_HEADER_SIZE,
                 have: buf.len(),
             });
         }
-        if buf[0..4] != CDR_LE_HEADER {
-            return Err(CdrError::InvalidHeader);
-        }
+        let order = match order {
+            Some(order) => order,
+            None => Endianness::detect(buf)?,
+        };
         Ok(CdrCursor {
             buf,
             pos: CDR_HEADER_SIZE,
+            base: CDR_HEADER_SIZE,
+            limits,
+            big_endian: order == Endianness::Big,
         })
     }
 
+    /// Create a cursor over a bare payload without the encapsulation
+    /// header (see [`strip_encapsulation`]), decoding in `endianness` and
+    /// enforcing the current [`DecodeLimits`]. Offsets are relative to the
+    /// payload start.
+    pub fn from_payload(payload: &'a [u8], endianness: Endianness) -> Result<Self, CdrError> {
+        let limits = DecodeLimits::current();
+        if payload.len() > limits.max_message_size {
+            return Err(CdrError::LimitExceeded {
+                limit: "max_message_size",
+                value: payload.len(),
+                max: limits.max_message_size,
+                offset: 0,
+            });
+        }
+        Ok(CdrCursor {
+            buf: payload,
+            pos: 0,
+            base: 0,
+            limits,
+            big_endian: endianness == Endianness::Big,
+        })
+    }
+
+    /// Byte order this cursor decodes.
+    pub fn endianness(&self) -> Endianness {
+        if self.big_endian {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+
     /// Resume scanning from a known-valid buffer at the given offset.
+    ///
+    /// No [`DecodeLimits`] are enforced; use [`resume_checked`] to continue
+    /// validating untrusted input.
+    ///
+    /// [`resume_checked`]: CdrCursor::resume_checked
     pub fn resume(buf: &'a [u8], offset: usize) -> Self {
-        CdrCursor { buf, pos: offset }
+        CdrCursor {
+            buf,
+            pos: offset,
+            base: CDR_HEADER_SIZE,
+            limits: DecodeLimits::UNLIMITED,
+            big_endian: false,
+        }
+    }
+
+    /// Continue validating untrusted input from `offset` (e.g. after the
+    /// Header has been scanned), enforcing the current [`DecodeLimits`].
+    pub fn resume_checked(buf: &'a [u8], offset: usize) -> Self {
+        CdrCursor {
+            buf,
+            pos: offset,
+            base: CDR_HEADER_SIZE,
+            limits: DecodeLimits::current(),
+            big_endian: false,
+        }
+    }
+
+    /// The limits this cursor enforces.
+    pub fn limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
+    /// Called once the last field has been read: if the cursor's limits
+    /// reject trailing bytes, fail unless only zero padding up to the next
+    /// 4-byte boundary remains.
+    pub fn expect_end(&self) -> Result<(), CdrError> {
+        if !self.limits.reject_trailing_bytes {
+            return Ok(());
+        }
+        let pad_end = cdr_align(self.pos, 4).min(self.buf.len());
+        let padding = &self.buf[self.pos..pad_end];
+        if pad_end == self.buf.len() && padding.iter().all(|&b| b == 0) {
+            Ok(())
+        } else {
+            Err(CdrError::TrailingBytes {
+                offset: self.pos,
+                count: self.buf.len() - self.pos,
+            })
+        }
+    }
+
+    fn check_limit(
+        &self,
+        limit: &'static str,
+        value: usize,
+        max: usize,
+        offset: usize,
+    ) -> Result<(), CdrError> {
+        if value > max {
+            Err(CdrError::LimitExceeded {
+                limit,
+                value,
+                max,
+                offset,
+            })
+        } else {
+            Ok(())
+        }
     }
 
     /// Current byte offset (including the CDR header).
@@ -138,7 +667,7 @@ impl<'a> CdrCursor<'a> {
     /// (after the 4-byte encapsulation header), not from the buffer start.
     #[inline(always)]
     pub fn align(&mut self, n: usize) {
-        self.pos = CDR_HEADER_SIZE + align(self.pos - CDR_HEADER_SIZE, n);
+        self.pos = self.base + align(self.pos - self.base, n);
     }
 
     /// Skip `n` bytes, checking bounds.
@@ -181,18 +710,24 @@ impl<'a> CdrCursor<'a> {
     }
 
     pub fn read_bool(&mut self) -> Result<bool, CdrError> {
+        let offset = self.pos;
         let v = self.read_u8()?;
         match v {
             0 => Ok(false),
             1 => Ok(true),
-            _ => Err(CdrError::InvalidBool(v)),
+            _ => Err(CdrError::InvalidBool { value: v, offset }),
         }
     }
 
     pub fn read_u16(&mut self) -> Result<u16, CdrError> {
         self.align(2);
         self.ensure(2)?;
-        let v = u16::from_le_bytes([self.buf[self.pos], self.buf[self.pos + 1]]);
+        let bytes = [self.buf[self.pos], self.buf[self.pos + 1]];
+        let v = if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        };
         self.pos += 2;
         Ok(v)
     }
@@ -204,11 +739,14 @@ impl<'a> CdrCursor<'a> {
     pub fn read_u32(&mut self) -> Result<u32, CdrError> {
         self.align(4);
         self.ensure(4)?;
-        let v = u32::from_le_bytes(
-            self.buf[self.pos..self.pos + 4]
-                .try_into()
-                .expect("slice is exactly 4 bytes after bounds check"),
-        );
+        let bytes = self.buf[self.pos..self.pos + 4]
+            .try_into()
+            .expect("slice is exactly 4 bytes after bounds check");
+        let v = if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        };
         self.pos += 4;
         Ok(v)
     }
@@ -220,11 +758,14 @@ impl<'a> CdrCursor<'a> {
     pub fn read_u64(&mut self) -> Result<u64, CdrError> {
         self.align(8);
         self.ensure(8)?;
-        let v = u64::from_le_bytes(
-            self.buf[self.pos..self.pos + 8]
-                .try_into()
-                .expect("slice is exactly 8 bytes after bounds check"),
-        );
+        let bytes = self.buf[self.pos..self.pos + 8]
+            .try_into()
+            .expect("slice is exactly 8 bytes after bounds check");
+        let v = if self.big_endian {
+            u64::from_be_bytes(bytes)
+        } else {
+            u64::from_le_bytes(bytes)
+        };
         self.pos += 8;
         Ok(v)
     }
@@ -253,15 +794,18 @@ impl<'a> CdrCursor<'a> {
     ///
     /// This is a hardening check — the cursor's per-element bounds checking
     /// would eventually catch an invalid count, but this avoids attempting
-    /// billions of loop iterations on malformed input.
+    /// billions of loop iterations on malformed input. Call it directly after
+    /// reading the u32 count so the reported offset is that of the prefix.
     pub fn check_seq_count(&self, count: u32, min_element_bytes: usize) -> Result<usize, CdrError> {
         let n = count as usize;
+        let prefix = self.pos.saturating_sub(4);
         if min_element_bytes > 0 && n > self.remaining() / min_element_bytes {
-            return Err(CdrError::BufferTooShort {
-                need: self.pos + n * min_element_bytes,
-                have: self.buf.len(),
+            return Err(CdrError::SequenceTooLong {
+                count,
+                offset: prefix,
             });
         }
+        self.check_limit("max_sequence_len", n, self.limits.max_sequence_len, prefix)?;
         Ok(n)
     }
 
@@ -269,7 +813,10 @@ impl<'a> CdrCursor<'a> {
     ///
     /// CDR strings are encoded as: u32 length (including NUL), UTF-8 bytes, NUL.
     pub fn read_string(&mut self) -> Result<&'a str, CdrError> {
+        self.align(4);
+        let offset = self.pos;
         let len = self.read_u32()? as usize;
+        self.check_limit("max_string_len", len, self.limits.max_string_len, offset)?;
         if len == 0 {
             return Ok("");
         }
@@ -278,15 +825,58 @@ impl<'a> CdrCursor<'a> {
         self.pos += len;
         // CDR strings include the NUL terminator in the length
         if bytes[len - 1] != 0 {
-            return Err(CdrError::MissingNul);
+            return Err(CdrError::MissingNul { offset });
+        }
+        let s = core::str::from_utf8(&bytes[..len - 1])
+            .map_err(|_| CdrError::InvalidUtf8 { offset })?;
+        Ok(s)
+    }
+
+    /// Read an IDL bounded string (`string<bound>`), failing with
+    /// [`CdrError::BoundExceeded`] if it holds more than `bound` bytes
+    /// (NUL excluded).
+    pub fn read_string_bounded(&mut self, bound: usize) -> Result<&'a str, CdrError> {
+        self.align(4);
+        let offset = self.pos;
+        let s = self.read_string()?;
+        if s.len() > bound {
+            return Err(CdrError::BoundExceeded {
+                len: s.len(),
+                bound,
+                offset: Some(offset),
+            });
         }
-        let s = std::str::from_utf8(&bytes[..len - 1]).map_err(|_| CdrError::InvalidUtf8)?;
         Ok(s)
     }
 
+    /// Read the element count of an IDL bounded sequence
+    /// (`sequence<T, bound>`), failing with [`CdrError::BoundExceeded`] if it
+    /// exceeds `bound`.
+    pub fn read_seq_len_bounded(&mut self, bound: usize) -> Result<usize, CdrError> {
+        self.align(4);
+        let offset = self.pos;
+        let len = self.read_u32()? as usize;
+        if len > bound {
+            return Err(CdrError::BoundExceeded {
+                len,
+                bound,
+                offset: Some(offset),
+            });
+        }
+        Ok(len)
+    }
+
     /// Read a CDR byte sequence as a zero-copy `&[u8]`.
     pub fn read_bytes(&mut self) -> Result<&'a [u8], CdrError> {
+        self.align(4);
+        let offset = self.pos;
         let len = self.read_u32()? as usize;
+        self.check_limit(
+            "max_sequence_len",
+            len,
+            self.limits.max_sequence_len,
+            offset,
+        )?;
         self.ensure(len)?;
         let bytes = &self.buf[self.pos..self.pos + len];
         self.pos += len;
@@ -299,8 +889,15 @@ impl<'a> CdrCursor<'a> {
     /// byte order, so the `from_cdr` scan only needs to advance past them.
     fn skip_typed_seq(&mut self, count: usize, elem_size: usize) -> Result<(), CdrError> {
         if count > 0 {
+            let max = self.limits.max_sequence_len;
+            self.check_limit("max_sequence_len", count, max, self.pos)?;
             self.align(elem_size);
-            let byte_len = count * elem_size;
+            let byte_len = count
+                .checked_mul(elem_size)
+                .ok_or(CdrError::SequenceTooLong {
+                    count: u32::try_from(count).unwrap_or(u32::MAX),
+                    offset: self.pos,
+                })?;
             self.ensure(byte_len)?;
             self.pos += byte_len;
         }
@@ -342,25 +939,42 @@ pub struct CdrWriter<'a> {
     buf: &'a mut [u8],
     pos: usize,
     err: Option<CdrError>,
+    big_endian: bool,
 }
 
 impl<'a> CdrWriter<'a> {
     /// Create a new writer, writing the CDR LE header at the start.
     pub fn new(buf: &'a mut [u8]) -> Result<Self, CdrError> {
+        Self::with_endianness(buf, Endianness::Little)
+    }
+
+    /// Create a new writer producing the given byte order, writing the
+    /// matching encapsulation header at the start.
+    pub fn with_endianness(buf: &'a mut [u8], endianness: Endianness) -> Result<Self, CdrError> {
         if buf.len() < CDR_HEADER_SIZE {
             return Err(CdrError::BufferTooShort {
                 need: CDR_HEADER_SIZE,
                 have: buf.len(),
             });
         }
-        buf[0..4].copy_from_slice(&CDR_LE_HEADER);
+        buf[0..4].copy_from_slice(&endianness.header());
         Ok(CdrWriter {
             buf,
             pos: CDR_HEADER_SIZE,
             err: None,
+            big_endian: endianness == Endianness::Big,
         })
     }
 
+    /// Byte order this writer produces.
+    pub fn endianness(&self) -> Endianness {
+        if self.big_endian {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+
     /// Current byte offset (including CDR header).
     #[inline(always)]
     pub fn offset(&self) -> usize {
@@ -438,7 +1052,12 @@ impl<'a> CdrWriter<'a> {
         if !self.check(2) {
             return;
         }
-        self.buf[self.pos..self.pos + 2].copy_from_slice(&v.to_le_bytes());
+        let bytes = if self.big_endian {
+            v.to_be_bytes()
+        } else {
+            v.to_le_bytes()
+        };
+        self.buf[self.pos..self.pos + 2].copy_from_slice(&bytes);
         self.pos += 2;
     }
 
@@ -451,7 +1070,12 @@ impl<'a> CdrWriter<'a> {
         if !self.check(4) {
             return;
         }
-        self.buf[self.pos..self.pos + 4].copy_from_slice(&v.to_le_bytes());
+        let bytes = if self.big_endian {
+            v.to_be_bytes()
+        } else {
+            v.to_le_bytes()
+        };
+        self.buf[self.pos..self.pos + 4].copy_from_slice(&bytes);
         self.pos += 4;
     }
 
@@ -464,7 +1088,12 @@ impl<'a> CdrWriter<'a> {
         if !self.check(8) {
             return;
         }
-        self.buf[self.pos..self.pos + 8].copy_from_slice(&v.to_le_bytes());
+        let bytes = if self.big_endian {
+            v.to_be_bytes()
+        } else {
+            v.to_le_bytes()
+        };
+        self.buf[self.pos..self.pos + 8].copy_from_slice(&bytes);
         self.pos += 8;
     }
 
@@ -495,6 +1124,23 @@ impl<'a> CdrWriter<'a> {
         self.pos += 1;
     }
 
+    /// Write an IDL bounded string (`string<bound>`). A string longer than
+    /// `bound` bytes is not written and records [`CdrError::BoundExceeded`],
+    /// reported by [`finish()`](CdrWriter::finish).
+    pub fn write_string_bounded(&mut self, s: &str, bound: usize) {
+        if s.len() > bound {
+            if self.err.is_none() {
+                self.err = Some(CdrError::BoundExceeded {
+                    len: s.len(),
+                    bound,
+                    offset: Some(cdr_align(self.pos, 4)),
+                });
+            }
+            return;
+        }
+        self.write_string(s);
+    }
+
     /// Write a CDR byte sequence: u32(len) + raw bytes.
     pub fn write_bytes(&mut self, data: &[u8]) {
         self.write_u32(data.len() as u32);
@@ -518,7 +1164,8 @@ impl<'a> CdrWriter<'a> {
     ///
     /// On little-endian targets, CDR1-LE primitives share native byte order,
     /// so a `&[u16]`, `&[i16]`, `&[u32]`, `&[f32]`, `&[f64]` etc. can be
-    /// written as a single `memcpy` after aligning to `elem_size`.
+    /// written as a single `memcpy` after aligning to `elem_size`. Big-endian
+    /// writers fall back to per-element writes in the public wrappers.
     fn write_typed_slice(&mut self, data: &[u8], elem_size: usize) {
         if !data.is_empty() {
             self.align(elem_size);
@@ -532,36 +1179,66 @@ impl<'a> CdrWriter<'a> {
 
     /// Write a `&[u16]` (or `&[i16]`) slice as bulk bytes.
     pub fn write_slice_u16(&mut self, data: &[u16]) {
+        if self.big_endian {
+            for v in data {
+                self.write_u16(*v);
+            }
+            return;
+        }
         let bytes =
-            unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 2) };
+            unsafe { core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 2) };
         self.write_typed_slice(bytes, 2);
     }
 
     /// Write a `&[i16]` slice as bulk bytes.
     pub fn write_slice_i16(&mut self, data: &[i16]) {
+        if self.big_endian {
+            for v in data {
+                self.write_i16(*v);
+            }
+            return;
+        }
         let bytes =
-            unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 2) };
+            unsafe { core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 2) };
         self.write_typed_slice(bytes, 2);
     }
 
     /// Write a `&[u32]` (or `&[i32]`) slice as bulk bytes.
     pub fn write_slice_u32(&mut self, data: &[u32]) {
+        if self.big_endian {
+            for v in data {
+                self.write_u32(*v);
+            }
+            return;
+        }
         let bytes =
-            unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 4) };
+            unsafe { core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 4) };
         self.write_typed_slice(bytes, 4);
     }
 
     /// Write a `&[f32]` slice as bulk bytes.
     pub fn write_slice_f32(&mut self, data: &[f32]) {
+        if self.big_endian {
+            for v in data {
+                self.write_f32(*v);
+            }
+            return;
+        }
         let bytes =
-            unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 4) };
+            unsafe { core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 4) };
         self.write_typed_slice(bytes, 4);
     }
 
     /// Write a `&[f64]` slice as bulk bytes.
     pub fn write_slice_f64(&mut self, data: &[f64]) {
+        if self.big_endian {
+            for v in data {
+                self.write_f64(*v);
+            }
+            return;
+        }
         let bytes =
-            unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 8) };
+            unsafe { core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * 8) };
         self.write_typed_slice(bytes, 8);
     }
 }
@@ -740,6 +1417,177 @@ pub trait CdrFixed: Copy + Sized {
     fn size_cdr(sizer: &mut CdrSizer);
 }
 
+// ── Typed IDL enums ──────────────────────────────────────────────────
+
+/// Integer types usable as the wire representation of a [`CdrEnum`].
+///
+/// IDL enums are 32-bit on the wire; the `uint8` constant sets used by
+/// most EdgeFirst messages map to `u8`.
+pub trait CdrDiscriminant: Copy + Eq + Into<u32> {
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError>;
+    fn write_cdr(self, writer: &mut CdrWriter<'_>);
+    fn size_cdr(sizer: &mut CdrSizer);
+}
+
+impl CdrDiscriminant for u8 {
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        cursor.read_u8()
+    }
+    fn write_cdr(self, writer: &mut CdrWriter<'_>) {
+        writer.write_u8(self)
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        sizer.size_u8()
+    }
+}
+
+impl CdrDiscriminant for u16 {
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        cursor.read_u16()
+    }
+    fn write_cdr(self, writer: &mut CdrWriter<'_>) {
+        writer.write_u16(self)
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        sizer.size_u16()
+    }
+}
+
+impl CdrDiscriminant for u32 {
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        cursor.read_u32()
+    }
+    fn write_cdr(self, writer: &mut CdrWriter<'_>) {
+        writer.write_u32(self)
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        sizer.size_u32()
+    }
+}
+
+/// A fieldless Rust enum carried on the wire as its integer discriminant.
+///
+/// Implemented by [`cdr_enum!`](crate::cdr_enum), which also provides
+/// [`CdrFixed`], `TryFrom<Repr>` and `From<Self> for Repr`. Decoding an
+/// unlisted discriminant fails with [`CdrError::UnknownDiscriminant`].
+pub trait CdrEnum: CdrFixed + 'static {
+    /// Wire representation.
+    type Repr: CdrDiscriminant;
+    /// Enum name used in error messages.
+    const NAME: &'static str;
+    /// Every variant, in declaration order.
+    const VARIANTS: &'static [Self];
+
+    fn to_repr(self) -> Self::Repr;
+    fn from_repr(value: Self::Repr) -> Option<Self>;
+
+    /// Read and check a discriminant; used by the generated `read_cdr`.
+    fn read_enum(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        let value = Self::Repr::read_cdr(cursor)?;
+        Self::from_repr(value).ok_or_else(|| CdrError::UnknownDiscriminant {
+            type_name: Self::NAME,
+            value: value.into(),
+            offset: Some(cursor.offset() - core::mem::size_of::<Self::Repr>()),
+        })
+    }
+}
+
+/// Declare a fieldless enum with an integer CDR representation.
+///
+/// Replaces a module of bare constants with a typed enum while keeping
+/// the same wire format:
+///
+/// ```rust
+/// use edgefirst_schemas::cdr::{decode_fixed, encode_fixed, CdrEnum, CdrError};
+/// use edgefirst_schemas::cdr_enum;
+///
+/// cdr_enum! {
+///     /// Tracking state.
+///     pub enum TrackState: u8 {
+///         Tentative = 0,
+///         Confirmed = 1,
+///         Lost = 2,
+///     }
+/// }
+///
+/// let bytes = encode_fixed(&TrackState::Confirmed).unwrap();
+/// assert_eq!(decode_fixed::<TrackState>(&bytes).unwrap(), TrackState::Confirmed);
+/// assert_eq!(u8::from(TrackState::Lost), 2);
+/// assert!(matches!(
+///     TrackState::try_from(7),
+///     Err(CdrError::UnknownDiscriminant { value: 7, .. })
+/// ));
+/// assert_eq!(TrackState::VARIANTS.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! cdr_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident : $repr:ident {
+            $( $(#[$vmeta:meta])* $variant:ident = $value:literal ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr($repr)]
+        $vis enum $name {
+            $( $(#[$vmeta])* $variant = $value ),+
+        }
+
+        impl $crate::cdr::CdrEnum for $name {
+            type Repr = $repr;
+            const NAME: &'static str = stringify!($name);
+            const VARIANTS: &'static [Self] = &[$($name::$variant),+];
+
+            #[inline]
+            fn to_repr(self) -> $repr {
+                self as $repr
+            }
+            #[inline]
+            fn from_repr(value: $repr) -> Option<Self> {
+                match value {
+                    $( $value => Some($name::$variant), )+
+                    _ => None,
+                }
+            }
+        }
+
+        impl $crate::cdr::CdrFixed for $name {
+            const CDR_SIZE: usize = ::core::mem::size_of::<$repr>();
+            fn read_cdr(
+                cursor: &mut $crate::cdr::CdrCursor<'_>,
+            ) -> Result<Self, $crate::cdr::CdrError> {
+                <Self as $crate::cdr::CdrEnum>::read_enum(cursor)
+            }
+            fn write_cdr(&self, writer: &mut $crate::cdr::CdrWriter<'_>) {
+                $crate::cdr::CdrDiscriminant::write_cdr(*self as $repr, writer)
+            }
+            fn size_cdr(sizer: &mut $crate::cdr::CdrSizer) {
+                <$repr as $crate::cdr::CdrDiscriminant>::size_cdr(sizer)
+            }
+        }
+
+        impl ::core::convert::TryFrom<$repr> for $name {
+            type Error = $crate::cdr::CdrError;
+            fn try_from(value: $repr) -> ::core::result::Result<Self, $crate::cdr::CdrError> {
+                <Self as $crate::cdr::CdrEnum>::from_repr(value).ok_or(
+                    $crate::cdr::CdrError::UnknownDiscriminant {
+                        type_name: stringify!($name),
+                        value: value.into(),
+                        offset: None,
+                    },
+                )
+            }
+        }
+
+        impl ::core::convert::From<$name> for $repr {
+            fn from(value: $name) -> $repr {
+                value as $repr
+            }
+        }
+    };
+}
+
 // ── Inline helpers: read/write primitives at known absolute offsets ──
 
 #[inline(always)]
@@ -906,7 +1754,7 @@ pub(crate) fn rd_string(b: &[u8], pos: usize) -> (&str, usize) {
         ("", p + 4 + len)
     } else {
         let start = p + 4;
-        let s = std::str::from_utf8(&b[start..start + len - 1]).unwrap_or("");
+        let s = core::str::from_utf8(&b[start..start + len - 1]).unwrap_or("");
         (s, start + len)
     }
 }
@@ -956,15 +1804,22 @@ pub(crate) fn rd_duration(b: &[u8], pos: usize) -> crate::builtin_interfaces::Du
 // alignment rules. The debug_assert! below catches any violation during
 // testing, ensuring these functions are only called at valid offsets.
 
+/// View a region of `b` as `&[i8]` (zero-copy; no alignment requirement).
+#[inline(always)]
+pub(crate) fn rd_slice_i8(b: &[u8], pos: usize, count: usize) -> &[i8] {
+    let bytes = &b[pos..pos + count];
+    unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const i8, count) }
+}
+
 /// View a region of `b` as `&[u16]` (zero-copy on LE targets).
 #[inline(always)]
 pub(crate) fn rd_slice_u16(b: &[u8], pos: usize, count: usize) -> &[u16] {
     let ptr = b[pos..].as_ptr();
     debug_assert!(
-        (ptr as usize).is_multiple_of(std::mem::align_of::<u16>()),
+        (ptr as usize).is_multiple_of(core::mem::align_of::<u16>()),
         "rd_slice_u16: misaligned pointer"
     );
-    unsafe { std::slice::from_raw_parts(ptr as *const u16, count) }
+    unsafe { core::slice::from_raw_parts(ptr as *const u16, count) }
 }
 
 /// View a region of `b` as `&[i16]` (zero-copy on LE targets).
@@ -972,10 +1827,10 @@ pub(crate) fn rd_slice_u16(b: &[u8], pos: usize, count: usize) -> &[u16] {
 pub(crate) fn rd_slice_i16(b: &[u8], pos: usize, count: usize) -> &[i16] {
     let ptr = b[pos..].as_ptr();
     debug_assert!(
-        (ptr as usize).is_multiple_of(std::mem::align_of::<i16>()),
+        (ptr as usize).is_multiple_of(core::mem::align_of::<i16>()),
         "rd_slice_i16: misaligned pointer"
     );
-    unsafe { std::slice::from_raw_parts(ptr as *const i16, count) }
+    unsafe { core::slice::from_raw_parts(ptr as *const i16, count) }
 }
 
 /// View a region of `b` as `&[u32]` (zero-copy on LE targets).
@@ -983,10 +1838,10 @@ pub(crate) fn rd_slice_i16(b: &[u8], pos: usize, count: usize) -> &[i16] {
 pub(crate) fn rd_slice_u32(b: &[u8], pos: usize, count: usize) -> &[u32] {
     let ptr = b[pos..].as_ptr();
     debug_assert!(
-        (ptr as usize).is_multiple_of(std::mem::align_of::<u32>()),
+        (ptr as usize).is_multiple_of(core::mem::align_of::<u32>()),
         "rd_slice_u32: misaligned pointer"
     );
-    unsafe { std::slice::from_raw_parts(ptr as *const u32, count) }
+    unsafe { core::slice::from_raw_parts(ptr as *const u32, count) }
 }
 
 /// View a region of `b` as `&[f32]` (zero-copy on LE targets).
@@ -994,10 +1849,10 @@ pub(crate) fn rd_slice_u32(b: &[u8], pos: usize, count: usize) -> &[u32] {
 pub(crate) fn rd_slice_f32(b: &[u8], pos: usize, count: usize) -> &[f32] {
     let ptr = b[pos..].as_ptr();
     debug_assert!(
-        (ptr as usize).is_multiple_of(std::mem::align_of::<f32>()),
+        (ptr as usize).is_multiple_of(core::mem::align_of::<f32>()),
         "rd_slice_f32: misaligned pointer"
     );
-    unsafe { std::slice::from_raw_parts(ptr as *const f32, count) }
+    unsafe { core::slice::from_raw_parts(ptr as *const f32, count) }
 }
 
 /// Encode a CdrFixed type to a new CDR buffer (with header).
@@ -1015,10 +1870,373 @@ pub fn encode_fixed<T: CdrFixed>(val: &T) -> Result<Vec<u8>, CdrError> {
     Ok(buf)
 }
 
+/// Encode a CdrFixed type to a new CDR buffer in the given byte order.
+///
+/// The result is only decodable by this crate when `endianness` is
+/// [`Endianness::Little`]; big-endian output is for peers that expect it.
+pub fn encode_fixed_with<T: CdrFixed>(
+    val: &T,
+    endianness: Endianness,
+) -> Result<Vec<u8>, CdrError> {
+    let mut sizer = CdrSizer::new();
+    T::size_cdr(&mut sizer);
+    let mut buf = vec![0u8; sizer.size()];
+    let mut writer = CdrWriter::with_endianness(&mut buf, endianness)?;
+    val.write_cdr(&mut writer);
+    writer.finish()?;
+    Ok(buf)
+}
+
+/// Exact CDR size in bytes (header included) of a CdrFixed value, computed
+/// without serializing.
+///
+/// Buffer-backed messages report the same via their own `cdr_size()`, and
+/// their builders via `FooBuilder::cdr_size()` before encoding.
+pub fn serialized_size<T: CdrFixed>(_val: &T) -> usize {
+    let mut sizer = CdrSizer::new();
+    T::size_cdr(&mut sizer);
+    sizer.size()
+}
+
+/// Encode a CdrFixed type into a caller-provided buffer (with header) and
+/// return the number of bytes written.
+///
+/// Errors with `BufferTooShort` when `buf` is smaller than the encoded size;
+/// nothing is written in that case. Lets hot paths reuse one buffer instead
+/// of allocating per message.
+pub fn encode_fixed_into<T: CdrFixed>(val: &T, buf: &mut [u8]) -> Result<usize, CdrError> {
+    let mut sizer = CdrSizer::new();
+    T::size_cdr(&mut sizer);
+    let need = sizer.size();
+    if buf.len() < need {
+        return Err(CdrError::BufferTooShort {
+            need,
+            have: buf.len(),
+        });
+    }
+    let mut writer = CdrWriter::new(&mut buf[..need])?;
+    val.write_cdr(&mut writer);
+    writer.finish()?;
+    Ok(need)
+}
+
+/// Encode a CdrFixed type (with header) straight to an [`std::io::Write`]
+/// sink and return the number of bytes written.
+///
+/// Encoding goes through a stack buffer, so no heap allocation happens for
+/// any of the message types in this crate.
+#[cfg(feature = "std")]
+pub fn write_fixed<T: CdrFixed, W: std::io::Write>(val: &T, w: &mut W) -> std::io::Result<usize> {
+    let mut stack = [0u8; 512];
+    match encode_fixed_into(val, &mut stack) {
+        Ok(n) => {
+            w.write_all(&stack[..n])?;
+            Ok(n)
+        }
+        Err(CdrError::BufferTooShort { .. }) => {
+            let buf = encode_fixed(val)?;
+            w.write_all(&buf)?;
+            Ok(buf.len())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Decode a CdrFixed type (with header) from an [`std::io::Read`] source,
+/// consuming exactly its encoded size and nothing more, so consecutive
+/// values can be read from one stream.
+///
+/// Like [`write_fixed`], this goes through a stack buffer.
+#[cfg(feature = "std")]
+pub fn read_fixed<T: CdrFixed, R: std::io::Read>(r: &mut R) -> std::io::Result<T> {
+    let mut sizer = CdrSizer::new();
+    T::size_cdr(&mut sizer);
+    let need = sizer.size();
+    let mut stack = [0u8; 512];
+    if need <= stack.len() {
+        r.read_exact(&mut stack[..need])?;
+        Ok(decode_fixed(&stack[..need])?)
+    } else {
+        let mut buf = vec![0u8; need];
+        r.read_exact(&mut buf)?;
+        Ok(decode_fixed(&buf)?)
+    }
+}
+
 /// Helper to decode a CdrFixed type from a CDR buffer (with header).
+///
+/// The byte order follows the encapsulation header, so both CDR LE and
+/// CDR BE buffers decode; see [`decode_fixed_with`] to override it.
 pub fn decode_fixed<T: CdrFixed>(buf: &[u8]) -> Result<T, CdrError> {
-    let mut cursor = CdrCursor::new(buf)?;
-    T::read_cdr(&mut cursor)
+    let mut cursor = CdrCursor::detect(buf)?;
+    let v = T::read_cdr(&mut cursor)?;
+    cursor.expect_end()?;
+    Ok(v)
+}
+
+/// Like [`decode_fixed`], but decode in `endianness` regardless of what the
+/// encapsulation header announces.
+pub fn decode_fixed_with<T: CdrFixed>(buf: &[u8], endianness: Endianness) -> Result<T, CdrError> {
+    let mut cursor = CdrCursor::with_endianness(buf, endianness)?;
+    let v = T::read_cdr(&mut cursor)?;
+    cursor.expect_end()?;
+    Ok(v)
+}
+
+/// Like [`decode_fixed`], but fail with [`CdrError::TrailingBytes`] if `buf`
+/// holds more than the encoded value plus alignment padding.
+pub fn decode_fixed_exact<T: CdrFixed>(buf: &[u8]) -> Result<T, CdrError> {
+    let limits = DecodeLimits {
+        reject_trailing_bytes: true,
+        ..DecodeLimits::current()
+    };
+    let mut cursor = CdrCursor::open(buf, limits, None)?;
+    let v = T::read_cdr(&mut cursor)?;
+    cursor.expect_end()?;
+    Ok(v)
+}
+
+/// Decode a CdrFixed value from the start of a larger CDR message, ignoring
+/// whatever follows it. Returns the value and the offset just past it, from
+/// which a [`CdrCursor`] can resume.
+///
+/// The byte order follows the encapsulation header, as in [`decode_fixed`].
+pub fn decode_fixed_prefix<T: CdrFixed>(buf: &[u8]) -> Result<(T, usize), CdrError> {
+    let mut cursor = CdrCursor::detect(buf)?;
+    let v = T::read_cdr(&mut cursor)?;
+    Ok((v, cursor.offset()))
+}
+
+/// Decode only the leading `std_msgs/Header` of a message without scanning
+/// the rest of it.
+///
+/// Every stamped message starts with a Header, so recorders and latency
+/// monitors can read `stamp` and `frame_id` from an Image or PointCloud2 at
+/// the cost of one string. The returned view borrows just the header bytes,
+/// so `as_cdr()` is a standalone Header encoding. `buf` may be truncated
+/// after the header.
+pub fn peek_header(buf: &[u8]) -> Result<crate::std_msgs::Header<&[u8]>, CdrError> {
+    let end = crate::std_msgs::Header::<&[u8]>::from_cdr_prefix(buf)?.end_offset();
+    crate::std_msgs::Header::from_cdr_prefix(&buf[..end])
+}
+
+/// Decode only the leading `header.stamp` of a message; cheaper still than
+/// [`peek_header`] as `frame_id` is not read. Follows the byte order in the
+/// encapsulation header.
+pub fn peek_stamp(buf: &[u8]) -> Result<crate::builtin_interfaces::Time, CdrError> {
+    decode_fixed_prefix(buf).map(|(t, _)| t)
+}
+
+// ── CdrSerializer — reusable encode buffer ───────────────────────────
+
+/// Anything [`CdrSerializer`] can encode: every `CdrFixed` type and every
+/// message builder.
+pub trait CdrEncode {
+    /// Exact CDR size in bytes (header included).
+    fn cdr_size(&self) -> usize;
+    /// Encode into `buf`, which must hold at least `cdr_size()` bytes, and
+    /// return the number of bytes written.
+    fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError>;
+    /// [`encode_into_slice`](CdrEncode::encode_into_slice) in the given byte
+    /// order. The size does not depend on it.
+    fn encode_into_slice_with(
+        &self,
+        buf: &mut [u8],
+        endianness: Endianness,
+    ) -> Result<usize, CdrError>;
+}
+
+impl<T: CdrFixed> CdrEncode for T {
+    fn cdr_size(&self) -> usize {
+        serialized_size(self)
+    }
+    fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        encode_fixed_into(self, buf)
+    }
+    fn encode_into_slice_with(
+        &self,
+        buf: &mut [u8],
+        endianness: Endianness,
+    ) -> Result<usize, CdrError> {
+        let need = serialized_size(self);
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        let mut writer = CdrWriter::with_endianness(&mut buf[..need], endianness)?;
+        self.write_cdr(&mut writer);
+        writer.finish()?;
+        Ok(need)
+    }
+}
+
+/// Implement [`CdrEncode`] for builders exposing the standard inherent
+/// `cdr_size` / `encode_into_slice` pair and a private
+/// `write_into(buf, endianness)`.
+macro_rules! impl_cdr_encode {
+    ($($builder:ident),+ $(,)?) => {
+        $(
+            impl $crate::cdr::CdrEncode for $builder<'_> {
+                fn cdr_size(&self) -> usize {
+                    $builder::cdr_size(self)
+                }
+                fn encode_into_slice(
+                    &self,
+                    buf: &mut [u8],
+                ) -> Result<usize, $crate::cdr::CdrError> {
+                    $builder::encode_into_slice(self, buf)
+                }
+                fn encode_into_slice_with(
+                    &self,
+                    buf: &mut [u8],
+                    endianness: $crate::cdr::Endianness,
+                ) -> Result<usize, $crate::cdr::CdrError> {
+                    let need = $builder::cdr_size(self);
+                    if buf.len() < need {
+                        return Err($crate::cdr::CdrError::BufferTooShort {
+                            need,
+                            have: buf.len(),
+                        });
+                    }
+                    self.write_into(&mut buf[..need], endianness)?;
+                    Ok(need)
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_cdr_encode;
+
+/// Serializer owning a growable scratch buffer, so publishing at a steady
+/// rate does not allocate once the buffer has grown to the largest message.
+///
+/// ```rust
+/// use edgefirst_schemas::builtin_interfaces::Time;
+/// use edgefirst_schemas::cdr::CdrSerializer;
+/// use edgefirst_schemas::sensor_msgs::Image;
+///
+/// let pixels = vec![0u8; 640 * 480 * 3];
+/// let mut ser = CdrSerializer::new();
+/// let mut image = Image::builder();
+/// image.frame_id("camera").width(640).height(480).encoding("rgb8").step(1920);
+/// for i in 0..3 {
+///     image.stamp(Time::new(i, 0)).data(&pixels);
+///     let bytes = ser.serialize(&image).unwrap();
+///     assert_eq!(Image::from_cdr(bytes).unwrap().stamp(), Time::new(i, 0));
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CdrSerializer {
+    buf: Vec<u8>,
+}
+
+impl CdrSerializer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start with room for `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        CdrSerializer {
+            buf: vec![0; capacity],
+        }
+    }
+
+    /// Encode `msg` and return its bytes, valid until the next call.
+    pub fn serialize<M: CdrEncode + ?Sized>(&mut self, msg: &M) -> Result<&[u8], CdrError> {
+        let need = msg.cdr_size();
+        if self.buf.len() < need {
+            self.buf.resize(need, 0);
+        }
+        let n = msg.encode_into_slice(&mut self.buf[..need])?;
+        Ok(&self.buf[..n])
+    }
+
+    /// [`serialize`](CdrSerializer::serialize) in the given byte order, for
+    /// publishing to big-endian DDS participants.
+    pub fn serialize_with<M: CdrEncode + ?Sized>(
+        &mut self,
+        msg: &M,
+        endianness: Endianness,
+    ) -> Result<&[u8], CdrError> {
+        let need = msg.cdr_size();
+        if self.buf.len() < need {
+            self.buf.resize(need, 0);
+        }
+        let n = msg.encode_into_slice_with(&mut self.buf[..need], endianness)?;
+        Ok(&self.buf[..n])
+    }
+
+    /// Bytes available without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Release scratch space beyond `capacity` bytes, e.g. after a one-off
+    /// oversized message.
+    pub fn shrink_to(&mut self, capacity: usize) {
+        self.buf.truncate(capacity);
+        self.buf.shrink_to(capacity);
+    }
+}
+
+impl crate::heap_size::HeapSize for CdrSerializer {
+    fn heap_size(&self) -> usize {
+        self.buf.capacity()
+    }
+}
+
+// ── Encapsulation header ─────────────────────────────────────────────
+//
+// Every buffer produced or accepted by this crate carries the 4-byte RTPS
+// encapsulation header (representation identifier + options). Some transports
+// deliver or expect the bare payload instead; these helpers convert between
+// the two forms. Alignment is always computed relative to the payload start,
+// so adding or removing the header never changes the payload bytes.
+
+/// Validate the encapsulation header of `buf` (CDR LE or CDR BE) and
+/// return the bare payload that follows it (zero-copy) with the byte order
+/// the header announced, which the payload no longer records.
+pub fn strip_encapsulation(buf: &[u8]) -> Result<(&[u8], Endianness), CdrError> {
+    let order = Endianness::detect(buf)?;
+    Ok((&buf[CDR_HEADER_SIZE..], order))
+}
+
+/// Prepend the CDR1 LE encapsulation header to a bare payload.
+///
+/// The result can be passed to any `from_cdr` constructor.
+pub fn add_encapsulation(payload: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(CDR_HEADER_SIZE + payload.len());
+    buf.extend_from_slice(&CDR_LE_HEADER);
+    buf.extend_from_slice(payload);
+    buf
+}
+
+/// Encode a CdrFixed type to a bare payload (no encapsulation header).
+pub fn encode_fixed_payload<T: CdrFixed>(val: &T) -> Result<Vec<u8>, CdrError> {
+    let mut buf = encode_fixed(val)?;
+    buf.drain(..CDR_HEADER_SIZE);
+    Ok(buf)
+}
+
+/// Decode a CdrFixed type from a little-endian bare payload (no
+/// encapsulation header).
+pub fn decode_fixed_payload<T: CdrFixed>(payload: &[u8]) -> Result<T, CdrError> {
+    decode_fixed_payload_with(payload, Endianness::Little)
+}
+
+/// Decode a CdrFixed type from a bare payload in `endianness`, e.g. the
+/// order [`strip_encapsulation`] reported.
+pub fn decode_fixed_payload_with<T: CdrFixed>(
+    payload: &[u8],
+    endianness: Endianness,
+) -> Result<T, CdrError> {
+    let mut cursor = CdrCursor::from_payload(payload, endianness)?;
+    let v = T::read_cdr(&mut cursor)?;
+    cursor.expect_end()?;
+    Ok(v)
 }
 
 #[cfg(test)]
@@ -1085,13 +2303,13 @@ mod tests {
         // f32 at data offset 0 (buf 4..8), f64 at data offset 8 (buf 12..20)
         // f64 needs data-relative alignment: data offset 4 → 8 (4 bytes padding)
         let mut buf = vec![0x00, 0x01, 0x00, 0x00]; // CDR header (offset 0..4)
-        buf.extend_from_slice(&std::f32::consts::PI.to_le_bytes()); // f32 at buf 4..8
+        buf.extend_from_slice(&core::f32::consts::PI.to_le_bytes()); // f32 at buf 4..8
         buf.extend_from_slice(&[0; 4]); // padding for f64 alignment (data offset 4 → 8)
-        buf.extend_from_slice(&std::f64::consts::E.to_le_bytes()); // f64 at buf 12..20
+        buf.extend_from_slice(&core::f64::consts::E.to_le_bytes()); // f64 at buf 12..20
 
         let mut cursor = CdrCursor::new(&buf).unwrap();
-        assert!((cursor.read_f32().unwrap() - std::f32::consts::PI).abs() < 1e-7);
-        assert!((cursor.read_f64().unwrap() - std::f64::consts::E).abs() < 1e-15);
+        assert!((cursor.read_f32().unwrap() - core::f32::consts::PI).abs() < 1e-7);
+        assert!((cursor.read_f64().unwrap() - core::f64::consts::E).abs() < 1e-15);
     }
 
     #[test]
@@ -1154,8 +2372,8 @@ mod tests {
             w.write_u32(123456);
             w.write_u64(9876543210);
             w.write_i32(-42);
-            w.write_f32(std::f32::consts::PI);
-            w.write_f64(std::f64::consts::E);
+            w.write_f32(core::f32::consts::PI);
+            w.write_f64(core::f64::consts::E);
             w.write_bool(true);
             w.write_bool(false);
         }
@@ -1166,8 +2384,8 @@ mod tests {
         assert_eq!(cursor.read_u32().unwrap(), 123456);
         assert_eq!(cursor.read_u64().unwrap(), 9876543210);
         assert_eq!(cursor.read_i32().unwrap(), -42);
-        assert!((cursor.read_f32().unwrap() - std::f32::consts::PI).abs() < 1e-7);
-        assert!((cursor.read_f64().unwrap() - std::f64::consts::E).abs() < 1e-15);
+        assert!((cursor.read_f32().unwrap() - core::f32::consts::PI).abs() < 1e-7);
+        assert!((cursor.read_f64().unwrap() - core::f64::consts::E).abs() < 1e-15);
         assert!(cursor.read_bool().unwrap());
         assert!(!cursor.read_bool().unwrap());
     }
@@ -1236,10 +2454,128 @@ mod tests {
         assert_eq!(sizer.size(), pos, "sizer and writer disagree on total size");
     }
 
+    #[test]
+    fn big_endian_writer() {
+        let mut buf = [0u8; 32];
+        let mut w = CdrWriter::with_endianness(&mut buf, Endianness::Big).unwrap();
+        assert_eq!(w.endianness(), Endianness::Big);
+        w.write_u8(1);
+        w.write_u32(0x0102_0304);
+        w.write_slice_u16(&[0x0506, 0x0708]);
+        w.write_string("a");
+        let end = w.offset();
+        w.finish().unwrap();
+        assert_eq!(
+            &buf[..end],
+            &[
+                0, 0, 0, 0, // BE header
+                1, 0, 0, 0, // u8 + pad
+                1, 2, 3, 4, // u32
+                5, 6, 7, 8, // u16 slice
+                0, 0, 0, 2, b'a', 0, // string
+            ]
+        );
+
+        use crate::builtin_interfaces::Time;
+        let t = Time::new(1, 2);
+        let be = encode_fixed_with(&t, Endianness::Big).unwrap();
+        assert_eq!(be, [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2]);
+        assert_eq!(
+            encode_fixed_with(&t, Endianness::Little).unwrap(),
+            encode_fixed(&t).unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_fixed_into_reuses_buffer() {
+        use crate::geometry_msgs::Vector3;
+        let v = Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let mut buf = [0xAAu8; 64];
+        let n = encode_fixed_into(&v, &mut buf).unwrap();
+        assert_eq!(&buf[..n], encode_fixed(&v).unwrap().as_slice());
+        assert!(matches!(
+            encode_fixed_into(&v, &mut buf[..n - 1]),
+            Err(CdrError::BufferTooShort { .. })
+        ));
+
+        assert_eq!(serialized_size(&v), n);
+
+        #[cfg(feature = "std")]
+        {
+            let mut out = Vec::new();
+            assert_eq!(write_fixed(&v, &mut out).unwrap(), n);
+            assert_eq!(out, &buf[..n]);
+        }
+    }
+
+    #[test]
+    fn encapsulation_strip_and_add() {
+        use crate::builtin_interfaces::Time;
+        let full = encode_fixed(&Time::new(7, 42)).unwrap();
+        let (payload, order) = strip_encapsulation(&full).unwrap();
+        assert_eq!(payload, &full[CDR_HEADER_SIZE..]);
+        assert_eq!(order, Endianness::Little);
+        assert_eq!(add_encapsulation(payload), full);
+
+        let be = encode_fixed_with(&Time::new(7, 42), Endianness::Big).unwrap();
+        let (payload, order) = strip_encapsulation(&be).unwrap();
+        assert_eq!(payload, &be[CDR_HEADER_SIZE..]);
+        assert_eq!(order, Endianness::Big);
+        assert_eq!(
+            decode_fixed_payload_with::<Time>(payload, order).unwrap(),
+            Time::new(7, 42)
+        );
+
+        assert!(matches!(
+            strip_encapsulation(&[0x00, 0x01]),
+            Err(CdrError::BufferTooShort { need: 4, have: 2 })
+        ));
+        assert!(matches!(
+            strip_encapsulation(&[0x00, 0x02, 0x00, 0x00]),
+            Err(CdrError::InvalidHeader)
+        ));
+    }
+
+    #[test]
+    fn encapsulation_fixed_payload_roundtrip() {
+        use crate::geometry_msgs::Vector3;
+        let v = Vector3 {
+            x: 1.0,
+            y: -2.0,
+            z: 3.5,
+        };
+        let payload = encode_fixed_payload(&v).unwrap();
+        assert_eq!(payload.len(), 24);
+        assert_eq!(&payload[..8], &1.0f64.to_le_bytes());
+        assert_eq!(decode_fixed_payload::<Vector3>(&payload).unwrap(), v);
+
+        assert!(matches!(
+            decode_fixed_payload::<Vector3>(&payload[..23]),
+            Err(CdrError::BufferTooShort { .. })
+        ));
+
+        // Alignment is measured from the payload start: an f64 after a
+        // u32 sits at payload offset 8, not 4.
+        let mut full = [0u8; 20];
+        let mut w = CdrWriter::new(&mut full).unwrap();
+        w.write_u32(7);
+        w.write_f64(2.5);
+        w.finish().unwrap();
+        let (payload, order) = strip_encapsulation(&full).unwrap();
+        let mut c = CdrCursor::from_payload(payload, order).unwrap();
+        assert_eq!(c.read_u32().unwrap(), 7);
+        assert_eq!(c.read_f64().unwrap(), 2.5);
+        assert_eq!(c.offset(), 16);
+    }
+
     // ── Wire-compatibility tests: CdrFixed roundtrip ───────────────
 
     /// Verify CdrFixed encode → decode roundtrip preserves values.
-    fn assert_roundtrip<T: CdrFixed + PartialEq + std::fmt::Debug>(val: &T, name: &str) {
+    fn assert_roundtrip<T: CdrFixed + PartialEq + core::fmt::Debug>(val: &T, name: &str) {
         let bytes = encode_fixed(val).unwrap();
         let decoded = decode_fixed::<T>(&bytes).unwrap();
         assert_eq!(*val, decoded, "{}: CdrFixed roundtrip failed", name);
@@ -1441,4 +2777,292 @@ mod tests {
             "foxglove_circle",
         );
     }
+
+    #[test]
+    fn decode_errors_report_offset_and_field() {
+        use crate::sensor_msgs::CompressedImage;
+
+        // Header at 4..20 ("cam"), then format "jpeg" at 20.
+        let mut wire = CompressedImage::builder()
+            .frame_id("cam")
+            .format("jpeg")
+            .data(&[1, 2, 3])
+            .build()
+            .unwrap()
+            .into_cdr();
+        wire[25] = 0xFF;
+        let err = CompressedImage::from_cdr(&wire[..]).err().unwrap();
+        assert_eq!(err.field(), Some("format"));
+        assert_eq!(err.offset(), Some(20));
+        assert!(matches!(err.root(), CdrError::InvalidUtf8 { offset: 20 }));
+        assert_eq!(
+            err.to_string(),
+            "field `format`: CDR string at offset 20 contains invalid UTF-8"
+        );
+
+        let mut c = CdrCursor::new(&[0, 1, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap();
+        let count = c.read_u32().unwrap();
+        assert!(matches!(
+            c.check_seq_count(count, 4),
+            Err(CdrError::SequenceTooLong {
+                count: u32::MAX,
+                offset: 4
+            })
+        ));
+
+        let mut c = CdrCursor::new(&[0, 1, 0, 0, 0, 7]).unwrap();
+        c.read_bool().unwrap();
+        let err = c.read_bool().unwrap_err();
+        assert!(matches!(
+            err,
+            CdrError::InvalidBool {
+                value: 7,
+                offset: 5
+            }
+        ));
+        assert_eq!(err.offset(), Some(5));
+        assert_eq!(err.field(), None);
+    }
+
+    #[test]
+    fn decode_limits_reject_oversized_lengths() {
+        let limits = DecodeLimits {
+            max_message_size: 64,
+            max_sequence_len: 4,
+            max_string_len: 8,
+            reject_trailing_bytes: false,
+        };
+        let mut w = vec![0u8; 4 + 4 + 9 + 3 + 4 + 5];
+        {
+            let mut cw = CdrWriter::new(&mut w).unwrap();
+            cw.write_string("too long");
+            cw.write_bytes(&[1, 2, 3, 4, 5]);
+            cw.finish().unwrap();
+        }
+
+        let mut c = CdrCursor::with_limits(&w, limits).unwrap();
+        assert!(matches!(
+            c.read_string(),
+            Err(CdrError::LimitExceeded {
+                limit: "max_string_len",
+                value: 9,
+                max: 8,
+                offset: 4
+            })
+        ));
+        let mut c = CdrCursor::with_limits(&w, DecodeLimits::UNLIMITED).unwrap();
+        assert_eq!(c.read_string().unwrap(), "too long");
+        let mut c = CdrCursor::resume(&w, c.offset());
+        assert_eq!(c.read_bytes().unwrap(), &[1, 2, 3, 4, 5]);
+
+        let mut c = CdrCursor::with_limits(&w, limits).unwrap();
+        c.skip(4 + 9).unwrap();
+        let err = c.read_bytes().unwrap_err();
+        assert_eq!(err.offset(), Some(20));
+        assert!(err.to_string().contains("max_sequence_len"));
+
+        let big = [&CDR_LE_HEADER[..], &[0u8; 64]].concat();
+        assert!(matches!(
+            CdrCursor::with_limits(&big, limits),
+            Err(CdrError::LimitExceeded {
+                limit: "max_message_size",
+                ..
+            })
+        ));
+        assert_eq!(DecodeLimits::default(), DecodeLimits::DEFAULT);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decode_exact_rejects_trailing_bytes() {
+        use crate::builtin_interfaces::Time;
+        use crate::sensor_msgs::Image;
+
+        let mut wire = Image::builder()
+            .frame_id("cam")
+            .width(2)
+            .height(1)
+            .encoding("mono8")
+            .data(&[1, 2])
+            .build()
+            .unwrap()
+            .into_cdr();
+        assert!(decode_exact(|| Image::from_cdr(&wire[..])).is_ok());
+
+        // Zero padding up to the next 4-byte boundary is accepted.
+        let end = wire.len();
+        wire.resize(cdr_align(end, 4), 0);
+        assert!(decode_exact(|| Image::from_cdr(&wire[..])).is_ok());
+
+        wire.extend_from_slice(&[0xAB; 4]);
+        assert!(Image::from_cdr(&wire[..]).is_ok());
+        let err = decode_exact(|| Image::from_cdr(&wire[..])).err().unwrap();
+        assert!(matches!(err, CdrError::TrailingBytes { offset, .. } if offset == end));
+        // The scoped setting does not leak out of decode_exact.
+        assert!(!DecodeLimits::current().reject_trailing_bytes);
+
+        let t = Time::new(1, 2);
+        let mut buf = encode_fixed(&t).unwrap();
+        assert_eq!(decode_fixed_exact::<Time>(&buf).unwrap(), t);
+        buf.push(0);
+        buf.push(1);
+        assert_eq!(decode_fixed::<Time>(&buf).unwrap(), t);
+        assert!(matches!(
+            decode_fixed_exact::<Time>(&buf),
+            Err(CdrError::TrailingBytes {
+                offset: 12,
+                count: 2
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn read_fixed_consumes_exact_size() {
+        use crate::builtin_interfaces::Time;
+        use crate::geometry_msgs::Vector3;
+
+        let mut stream = Vec::new();
+        write_fixed(&Time::new(3, 4), &mut stream).unwrap();
+        write_fixed(
+            &Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            &mut stream,
+        )
+        .unwrap();
+        let mut r = &stream[..];
+        assert_eq!(read_fixed::<Time, _>(&mut r).unwrap(), Time::new(3, 4));
+        assert_eq!(read_fixed::<Vector3, _>(&mut r).unwrap().z, 3.0);
+        assert!(r.is_empty());
+        let err = read_fixed::<Time, _>(&mut r).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    crate::cdr_enum! {
+        enum Wide: u32 {
+            A = 1,
+            B = 70000,
+        }
+    }
+
+    #[test]
+    fn cdr_enum_roundtrip_and_unknown_discriminant() {
+        assert_eq!(<Wide as CdrFixed>::CDR_SIZE, 4);
+        let bytes = encode_fixed(&Wide::B).unwrap();
+        assert_eq!(&bytes[4..], &70000u32.to_le_bytes());
+        assert_eq!(decode_fixed::<Wide>(&bytes).unwrap(), Wide::B);
+
+        let mut bad = bytes.clone();
+        bad[4..8].copy_from_slice(&2u32.to_le_bytes());
+        let err = decode_fixed::<Wide>(&bad).unwrap_err();
+        assert!(matches!(
+            err,
+            CdrError::UnknownDiscriminant {
+                type_name: "Wide",
+                value: 2,
+                offset: Some(4)
+            }
+        ));
+        assert_eq!(err.to_string(), "unknown Wide discriminant 2 at offset 4");
+        assert_eq!(Wide::VARIANTS, &[Wide::A, Wide::B]);
+        assert_eq!(Wide::from_repr(1), Some(Wide::A));
+    }
+
+    #[test]
+    fn cdr_serializer_reuses_buffer() {
+        use crate::builtin_interfaces::Time;
+        use crate::std_msgs::Header;
+
+        let mut ser = CdrSerializer::new();
+        let mut hdr = Header::builder();
+        hdr.stamp(Time::new(1, 2)).frame_id("a_long_frame_name");
+        let first = ser.serialize(&hdr).unwrap().to_vec();
+        assert_eq!(first, hdr.build().unwrap().into_cdr());
+        let cap = ser.capacity();
+        let ptr = ser.buf.as_ptr();
+
+        hdr.frame_id("short");
+        let second = ser.serialize(&hdr).unwrap();
+        assert_eq!(second, hdr.build().unwrap().as_cdr());
+        assert_eq!(ser.capacity(), cap);
+        assert_eq!(ser.buf.as_ptr(), ptr);
+
+        let t = ser.serialize(&Time::new(5, 6)).unwrap();
+        assert_eq!(decode_fixed::<Time>(t).unwrap(), Time::new(5, 6));
+    }
+
+    #[test]
+    fn decode_follows_header_byte_order() {
+        use crate::builtin_interfaces::Time;
+        use crate::geometry_msgs::Vector3;
+
+        let t = Time::new(-3, 7);
+        let be = encode_fixed_with(&t, Endianness::Big).unwrap();
+        assert_eq!(Endianness::detect(&be).unwrap(), Endianness::Big);
+        assert_eq!(decode_fixed::<Time>(&be).unwrap(), t);
+
+        let v = Vector3 {
+            x: 1.5,
+            y: -2.0,
+            z: 1e10,
+        };
+        let be = encode_fixed_with(&v, Endianness::Big).unwrap();
+        assert_eq!(decode_fixed::<Vector3>(&be).unwrap(), v);
+
+        // Override: a BE payload mislabelled as LE.
+        let mut mislabelled = be.clone();
+        mislabelled[..4].copy_from_slice(&CDR_LE_HEADER);
+        assert_ne!(decode_fixed::<Vector3>(&mislabelled).unwrap(), v);
+        assert_eq!(
+            decode_fixed_with::<Vector3>(&mislabelled, Endianness::Big).unwrap(),
+            v
+        );
+
+        let mut c = CdrCursor::detect(&be).unwrap();
+        assert_eq!(c.endianness(), Endianness::Big);
+        assert_eq!(c.read_f64().unwrap(), 1.5);
+        assert!(matches!(
+            CdrCursor::new(&be),
+            Err(CdrError::UnsupportedEndianness)
+        ));
+        assert!(matches!(
+            Endianness::detect(&[0, 7, 0, 0]),
+            Err(CdrError::InvalidHeader)
+        ));
+    }
+
+    #[test]
+    fn peek_reads_only_the_header() {
+        use crate::builtin_interfaces::Time;
+        use crate::sensor_msgs::Image;
+
+        let img = Image::builder()
+            .stamp(Time::new(7, 250))
+            .frame_id("camera")
+            .height(2)
+            .width(2)
+            .encoding("mono8")
+            .step(2)
+            .data(&[1, 2, 3, 4])
+            .build()
+            .unwrap();
+        let bytes = img.to_cdr();
+
+        let header = peek_header(&bytes).unwrap();
+        assert_eq!(header.stamp(), Time::new(7, 250));
+        assert_eq!(header.frame_id(), "camera");
+        assert_eq!(header.as_cdr().len(), header.end_offset());
+        assert_eq!(peek_stamp(&bytes).unwrap(), Time::new(7, 250));
+
+        // Only the header bytes need to be present.
+        let end = header.end_offset();
+        assert_eq!(peek_header(&bytes[..end]).unwrap().frame_id(), "camera");
+        assert!(peek_header(&bytes[..end - 1]).is_err());
+
+        let (t, off) = decode_fixed_prefix::<Time>(&bytes).unwrap();
+        assert_eq!((t, off), (Time::new(7, 250), CDR_HEADER_SIZE + 8));
+    }
 }
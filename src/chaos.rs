@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Fault-injection transport for consumer robustness testing.
+//!
+//! [`ChaosTransport`] is an in-memory loopback for serialized CDR messages
+//! that drops, duplicates, reorders, truncates and corrupts frames according
+//! to a seeded [`ChaosPolicy`]. Service authors push encoded messages in with
+//! [`ChaosTransport::send`] and feed whatever comes out of
+//! [`ChaosTransport::recv`] to their decode/validation path, which must
+//! reject damaged frames with a `CdrError` rather than panic.
+//!
+//! The same seed always yields the same fault sequence, so a failing run can
+//! be replayed exactly.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::chaos::{ChaosPolicy, ChaosTransport};
+//! use edgefirst_schemas::builtin_interfaces::Time;
+//! use edgefirst_schemas::std_msgs::Header;
+//!
+//! let policy = ChaosPolicy { truncate: 0.5, corrupt: 0.5, ..ChaosPolicy::default() };
+//! let mut chaos = ChaosTransport::new(policy, 42);
+//!
+//! for i in 0..16 {
+//!     let hdr = Header::builder().stamp(Time::new(i, 0)).frame_id("cam").build().unwrap();
+//!     chaos.send(hdr.as_cdr());
+//! }
+//! while let Some(frame) = chaos.recv() {
+//!     // Must never panic, whatever the transport did to the bytes.
+//!     let _ = Header::from_cdr(frame);
+//! }
+//! ```
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Per-message fault probabilities, each in `0.0..=1.0`.
+///
+/// Faults are rolled independently for every sent message in the order
+/// drop → truncate → corrupt → duplicate → reorder. A dropped message is
+/// not subject to any further fault.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ChaosPolicy {
+    /// Probability that a message is silently discarded.
+    pub drop: f64,
+    /// Probability that a message is delivered twice.
+    pub duplicate: f64,
+    /// Probability that a message is held back and delivered after the
+    /// next one.
+    pub reorder: f64,
+    /// Probability that a message is cut short at a random length.
+    pub truncate: f64,
+    /// Probability that a single random bit of the message is flipped.
+    pub corrupt: f64,
+}
+
+/// Counts of the faults injected so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChaosStats {
+    pub sent: u64,
+    pub dropped: u64,
+    pub duplicated: u64,
+    pub reordered: u64,
+    pub truncated: u64,
+    pub corrupted: u64,
+}
+
+/// Seeded in-memory transport that damages serialized messages.
+#[derive(Debug, Clone)]
+pub struct ChaosTransport {
+    policy: ChaosPolicy,
+    rng: SplitMix64,
+    queue: VecDeque<Vec<u8>>,
+    held: Option<Vec<u8>>,
+    stats: ChaosStats,
+}
+
+impl ChaosTransport {
+    /// Create a transport applying `policy`, seeded with `seed`.
+    pub fn new(policy: ChaosPolicy, seed: u64) -> Self {
+        ChaosTransport {
+            policy,
+            rng: SplitMix64(seed),
+            queue: VecDeque::new(),
+            held: None,
+            stats: ChaosStats::default(),
+        }
+    }
+
+    /// The fault policy in effect.
+    pub fn policy(&self) -> &ChaosPolicy {
+        &self.policy
+    }
+
+    /// Replace the fault policy. The random sequence is not reset.
+    pub fn set_policy(&mut self, policy: ChaosPolicy) {
+        self.policy = policy;
+    }
+
+    /// Faults injected since construction.
+    pub fn stats(&self) -> ChaosStats {
+        self.stats
+    }
+
+    /// Send one serialized message through the transport.
+    pub fn send(&mut self, msg: &[u8]) {
+        self.stats.sent += 1;
+        if self.roll(self.policy.drop) {
+            self.stats.dropped += 1;
+            return;
+        }
+
+        let mut frame = msg.to_vec();
+        if !frame.is_empty() && self.roll(self.policy.truncate) {
+            let keep = self.rng.below(frame.len() as u64) as usize;
+            frame.truncate(keep);
+            self.stats.truncated += 1;
+        }
+        if !frame.is_empty() && self.roll(self.policy.corrupt) {
+            let bit = self.rng.below(frame.len() as u64 * 8) as usize;
+            frame[bit / 8] ^= 1 << (bit % 8);
+            self.stats.corrupted += 1;
+        }
+        let copies = if self.roll(self.policy.duplicate) {
+            self.stats.duplicated += 1;
+            2
+        } else {
+            1
+        };
+
+        if self.held.is_none() && self.roll(self.policy.reorder) {
+            self.stats.reordered += 1;
+            if copies == 2 {
+                self.queue.push_back(frame.clone());
+            }
+            self.held = Some(frame);
+            return;
+        }
+
+        for _ in 1..copies {
+            self.queue.push_back(frame.clone());
+        }
+        self.queue.push_back(frame);
+        if let Some(late) = self.held.take() {
+            self.queue.push_back(late);
+        }
+    }
+
+    /// Receive the next delivered message, if any.
+    ///
+    /// A message held back for reordering is released once the transport
+    /// has nothing else to deliver, so nothing that was not dropped is lost.
+    pub fn recv(&mut self) -> Option<Vec<u8>> {
+        self.queue.pop_front().or_else(|| self.held.take())
+    }
+
+    /// Number of messages waiting to be received.
+    pub fn len(&self) -> usize {
+        self.queue.len() + usize::from(self.held.is_some())
+    }
+
+    /// `true` if there is nothing waiting to be received.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn roll(&mut self, p: f64) -> bool {
+        p > 0.0 && self.rng.unit() < p
+    }
+}
+
+/// SplitMix64 — tiny, seedable, and good enough for fault selection.
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform in `[0, n)`; `n` must be non-zero.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+    use crate::std_msgs::Header;
+
+    fn drain(t: &mut ChaosTransport) -> Vec<Vec<u8>> {
+        core::iter::from_fn(|| t.recv()).collect()
+    }
+
+    #[test]
+    fn passthrough_with_default_policy() {
+        let mut t = ChaosTransport::new(ChaosPolicy::default(), 1);
+        for i in 0u8..10 {
+            t.send(&[i]);
+        }
+        let out = drain(&mut t);
+        assert_eq!(out, (0u8..10).map(|i| vec![i]).collect::<Vec<_>>());
+        assert_eq!(t.stats().sent, 10);
+    }
+
+    #[test]
+    fn drop_and_duplicate_extremes() {
+        let drop_all = ChaosPolicy {
+            drop: 1.0,
+            ..Default::default()
+        };
+        let mut t = ChaosTransport::new(drop_all, 7);
+        t.send(&[1, 2, 3]);
+        assert!(t.is_empty());
+        assert_eq!(t.stats().dropped, 1);
+
+        let dup_all = ChaosPolicy {
+            duplicate: 1.0,
+            ..Default::default()
+        };
+        let mut t = ChaosTransport::new(dup_all, 7);
+        t.send(&[9]);
+        assert_eq!(drain(&mut t), vec![vec![9], vec![9]]);
+    }
+
+    #[test]
+    fn reorder_swaps_adjacent_messages() {
+        let policy = ChaosPolicy {
+            reorder: 1.0,
+            ..Default::default()
+        };
+        let mut t = ChaosTransport::new(policy, 3);
+        t.send(&[1]);
+        t.send(&[2]);
+        t.send(&[3]);
+        assert_eq!(drain(&mut t), vec![vec![2], vec![1], vec![3]]);
+    }
+
+    #[test]
+    fn same_seed_same_faults() {
+        let policy = ChaosPolicy {
+            drop: 0.2,
+            duplicate: 0.2,
+            reorder: 0.2,
+            truncate: 0.2,
+            corrupt: 0.2,
+        };
+        let run = |seed| {
+            let mut t = ChaosTransport::new(policy, seed);
+            for i in 0u8..64 {
+                t.send(&[i; 16]);
+            }
+            (drain(&mut t), t.stats())
+        };
+        assert_eq!(run(99), run(99));
+        assert_ne!(run(99).0, run(100).0);
+    }
+
+    #[test]
+    fn damaged_headers_never_panic() {
+        let policy = ChaosPolicy {
+            truncate: 0.5,
+            corrupt: 0.5,
+            ..Default::default()
+        };
+        let mut t = ChaosTransport::new(policy, 0xED6E);
+        for i in 0..256 {
+            let hdr = Header::builder()
+                .stamp(Time::new(i, 0))
+                .frame_id("camera_optical_frame")
+                .build()
+                .unwrap();
+            t.send(hdr.as_cdr());
+        }
+        let stats = t.stats();
+        assert!(stats.truncated > 0 && stats.corrupted > 0);
+        for frame in drain(&mut t) {
+            let _ = Header::from_cdr(frame);
+        }
+    }
+}
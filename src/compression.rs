@@ -0,0 +1,358 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Zstandard compression with per-schema trained dictionaries.
+//!
+//! Small messages of one schema (a `Detect` per frame, periodic statistics)
+//! compress poorly one at a time: every zstd frame starts from an empty
+//! window. [`Dictionary::train`] builds a dictionary from sample payloads
+//! of a schema, and a [`DictionarySet`] compresses each payload with its
+//! schema's dictionary. Payloads may be CDR or JSON.
+//!
+//! zstd records the dictionary ID in every frame header, so
+//! [`DictionarySet::decompress`] needs no side channel to pick the right
+//! dictionary. Peers negotiate by exchanging [`DictionarySet::ids`] and
+//! only inserting dictionaries the other side holds; frames compressed
+//! without a dictionary carry no ID and always decode.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::compression::{Dictionary, DictionarySet};
+//!
+//! let samples: Vec<Vec<u8>> = (0..500)
+//!     .map(|i| format!(r#"{{"topic":"/camera/detect","frames":{},"dropped":{}}}"#, i, i % 7))
+//!     .map(String::into_bytes)
+//!     .collect();
+//! let dict = Dictionary::train("edgefirst_msgs/msg/Detect", &samples, 4096).unwrap();
+//!
+//! let mut set = DictionarySet::new();
+//! set.insert(dict);
+//! let frame = set.compress("edgefirst_msgs/msg/Detect", &samples[42]).unwrap();
+//! assert_eq!(set.decompress(&frame).unwrap(), samples[42]);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Read};
+
+use zstd::dict::{DecoderDictionary, EncoderDictionary};
+
+/// Errors from training dictionaries or compressing payloads.
+#[derive(Debug)]
+pub enum CompressionError {
+    /// Training was given no samples.
+    NoSamples,
+    /// The bytes are not a zstd dictionary with an ID.
+    InvalidDictionary,
+    /// The frame was compressed with a dictionary this set does not hold.
+    UnknownDictionary(u32),
+    /// The frame decompresses to more than
+    /// [`DecodeLimits::max_message_size`](crate::cdr::DecodeLimits) bytes.
+    TooLarge { max: usize },
+    /// zstd reported an error.
+    Zstd(io::Error),
+}
+
+impl fmt::Display for CompressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompressionError::NoSamples => write!(f, "no samples to train a dictionary from"),
+            CompressionError::InvalidDictionary => write!(f, "not a zstd dictionary"),
+            CompressionError::UnknownDictionary(id) => write!(f, "unknown zstd dictionary {}", id),
+            CompressionError::TooLarge { max } => {
+                write!(f, "decompressed payload exceeds {} bytes", max)
+            }
+            CompressionError::Zstd(e) => write!(f, "zstd: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CompressionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CompressionError::Zstd(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CompressionError {
+    fn from(e: io::Error) -> Self {
+        CompressionError::Zstd(e)
+    }
+}
+
+// ── Dictionary ──────────────────────────────────────────────────────
+
+/// A zstd dictionary trained for one schema.
+pub struct Dictionary {
+    schema: String,
+    id: u32,
+    bytes: Vec<u8>,
+    decoder: DecoderDictionary<'static>,
+}
+
+impl Dictionary {
+    /// Train a dictionary of at most `max_size` bytes from sample payloads.
+    ///
+    /// zstd wants roughly a hundred times `max_size` of sample data; with
+    /// too little it fails with [`CompressionError::Zstd`].
+    pub fn train<S: AsRef<[u8]>>(
+        schema: &str,
+        samples: &[S],
+        max_size: usize,
+    ) -> Result<Self, CompressionError> {
+        if samples.is_empty() {
+            return Err(CompressionError::NoSamples);
+        }
+        let bytes = zstd::dict::from_samples(samples, max_size)?;
+        Self::from_bytes(schema, bytes)
+    }
+
+    /// Load a previously trained dictionary, e.g. one read back from
+    /// [`as_bytes`](Self::as_bytes).
+    pub fn from_bytes(schema: &str, bytes: Vec<u8>) -> Result<Self, CompressionError> {
+        let id = zstd::zstd_safe::get_dict_id_from_dict(&bytes)
+            .ok_or(CompressionError::InvalidDictionary)?
+            .get();
+        Ok(Dictionary {
+            schema: schema.to_owned(),
+            id,
+            decoder: DecoderDictionary::copy(&bytes),
+            bytes,
+        })
+    }
+
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+    /// The dictionary ID recorded in frames compressed with it.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Debug for Dictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dictionary")
+            .field("schema", &self.schema)
+            .field("id", &self.id)
+            .field("len", &self.bytes.len())
+            .finish()
+    }
+}
+
+// ── DictionarySet ───────────────────────────────────────────────────
+
+/// Dictionaries by ID, with the current one for each schema.
+///
+/// Inserting a new dictionary for a schema makes it the one used for
+/// compression; the older one stays available for decompressing frames
+/// already in flight until [`remove`](Self::remove)d.
+pub struct DictionarySet {
+    level: i32,
+    dicts: BTreeMap<u32, Dictionary>,
+    encoders: BTreeMap<String, (u32, EncoderDictionary<'static>)>,
+}
+
+impl Default for DictionarySet {
+    fn default() -> Self {
+        DictionarySet {
+            level: zstd::DEFAULT_COMPRESSION_LEVEL,
+            dicts: BTreeMap::new(),
+            encoders: BTreeMap::new(),
+        }
+    }
+}
+
+impl DictionarySet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An empty set compressing at zstd `level` (default 3).
+    pub fn with_level(level: i32) -> Self {
+        DictionarySet {
+            level,
+            ..Self::default()
+        }
+    }
+
+    /// Add a dictionary and make it the one used for its schema.
+    pub fn insert(&mut self, dict: Dictionary) {
+        let encoder = EncoderDictionary::copy(dict.as_bytes(), self.level);
+        self.encoders
+            .insert(dict.schema.clone(), (dict.id, encoder));
+        self.dicts.insert(dict.id, dict);
+    }
+
+    /// Drop a dictionary; its schema falls back to plain zstd if it was
+    /// the current one.
+    pub fn remove(&mut self, id: u32) -> Option<Dictionary> {
+        let dict = self.dicts.remove(&id)?;
+        if self
+            .encoders
+            .get(&dict.schema)
+            .is_some_and(|(cur, _)| *cur == id)
+        {
+            self.encoders.remove(&dict.schema);
+        }
+        Some(dict)
+    }
+
+    pub fn get(&self, id: u32) -> Option<&Dictionary> {
+        self.dicts.get(&id)
+    }
+
+    /// The dictionary `compress` uses for `schema`.
+    pub fn for_schema(&self, schema: &str) -> Option<&Dictionary> {
+        self.encoders
+            .get(schema)
+            .and_then(|(id, _)| self.dicts.get(id))
+    }
+
+    /// IDs of every dictionary held, to advertise to a peer.
+    pub fn ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.dicts.keys().copied()
+    }
+
+    /// Compress a payload with its schema's dictionary, or without one if
+    /// the schema has none.
+    pub fn compress(&self, schema: &str, payload: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        Ok(match self.encoders.get(schema) {
+            Some((_, dict)) => {
+                zstd::bulk::Compressor::with_prepared_dictionary(dict)?.compress(payload)?
+            }
+            None => zstd::bulk::compress(payload, self.level)?,
+        })
+    }
+
+    /// Decompress a frame from `compress`, using the dictionary named in
+    /// its header. Output beyond the current
+    /// [`DecodeLimits::max_message_size`](crate::cdr::DecodeLimits) is
+    /// rejected with [`CompressionError::TooLarge`] before it is buffered,
+    /// so a small crafted frame cannot expand without bound.
+    pub fn decompress(&self, frame: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        let max = crate::cdr::DecodeLimits::current().max_message_size;
+        let take = (max as u64).saturating_add(1);
+        let mut out = Vec::new();
+        match zstd::zstd_safe::get_dict_id_from_frame(frame) {
+            None => zstd::stream::read::Decoder::with_buffer(frame)?
+                .take(take)
+                .read_to_end(&mut out)?,
+            Some(id) => {
+                let dict = self
+                    .dicts
+                    .get(&id.get())
+                    .ok_or(CompressionError::UnknownDictionary(id.get()))?;
+                zstd::stream::read::Decoder::with_prepared_dictionary(frame, &dict.decoder)?
+                    .take(take)
+                    .read_to_end(&mut out)?
+            }
+        };
+        if out.len() > max {
+            return Err(CompressionError::TooLarge { max });
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "edgefirst_msgs/msg/Detect";
+
+    fn samples() -> Vec<Vec<u8>> {
+        (0..2000)
+            .map(|i| {
+                format!(
+                    r#"{{"topic":"/camera/detect","schema":"{}","frames":{},"dropped":{},"latency_ms":{}.{}}}"#,
+                    SCHEMA,
+                    i,
+                    i % 7,
+                    i % 30,
+                    i % 10
+                )
+                .into_bytes()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dictionary_shrinks_and_roundtrips() {
+        let samples = samples();
+        let dict = Dictionary::train(SCHEMA, &samples, 2048).unwrap();
+        let id = dict.id();
+        let plain = DictionarySet::new();
+        let mut set = DictionarySet::new();
+        set.insert(dict);
+        assert_eq!(set.ids().collect::<Vec<_>>(), [id]);
+        assert_eq!(set.for_schema(SCHEMA).unwrap().id(), id);
+
+        let msg = &samples[1234];
+        let with = set.compress(SCHEMA, msg).unwrap();
+        let without = plain.compress(SCHEMA, msg).unwrap();
+        assert!(with.len() < without.len());
+        assert_eq!(set.decompress(&with).unwrap(), *msg);
+        assert_eq!(set.decompress(&without).unwrap(), *msg);
+
+        assert!(matches!(
+            plain.decompress(&with),
+            Err(CompressionError::UnknownDictionary(got)) if got == id
+        ));
+
+        let reloaded = Dictionary::from_bytes(SCHEMA, set.get(id).unwrap().as_bytes().to_vec());
+        assert_eq!(reloaded.unwrap().id(), id);
+        assert!(set.remove(id).is_some());
+        assert!(set.for_schema(SCHEMA).is_none());
+    }
+
+    #[test]
+    fn decompress_is_bounded_by_decode_limits() {
+        let bomb = vec![0u8; 1 << 20];
+        let set = DictionarySet::new();
+        let frame = set.compress(SCHEMA, &bomb).unwrap();
+        assert!(frame.len() < 1024);
+
+        let tight = crate::cdr::DecodeLimits {
+            max_message_size: 64 * 1024,
+            ..crate::cdr::DecodeLimits::DEFAULT
+        };
+        assert!(matches!(
+            tight.scoped(|| set.decompress(&frame)),
+            Err(CompressionError::TooLarge { max }) if max == 64 * 1024
+        ));
+        let exact = crate::cdr::DecodeLimits {
+            max_message_size: bomb.len(),
+            ..crate::cdr::DecodeLimits::DEFAULT
+        };
+        assert_eq!(exact.scoped(|| set.decompress(&frame)).unwrap(), bomb);
+
+        let samples = samples();
+        let mut dicts = DictionarySet::new();
+        dicts.insert(Dictionary::train(SCHEMA, &samples, 2048).unwrap());
+        let frame = dicts.compress(SCHEMA, &bomb).unwrap();
+        assert!(matches!(
+            tight.scoped(|| dicts.decompress(&frame)),
+            Err(CompressionError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn training_needs_samples() {
+        let none: [&[u8]; 0] = [];
+        assert!(matches!(
+            Dictionary::train(SCHEMA, &none, 1024),
+            Err(CompressionError::NoSamples)
+        ));
+        assert!(matches!(
+            Dictionary::from_bytes(SCHEMA, b"not a dictionary".to_vec()),
+            Err(CompressionError::InvalidDictionary)
+        ));
+    }
+}
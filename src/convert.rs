@@ -0,0 +1,567 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Conversions between message representations.
+//!
+//! Message types that can be turned into another schema implement
+//! [`TryConvertTo`] (or [`ConvertTo`] when the conversion cannot fail), so
+//! typed code writes `let img: Image<Vec<u8>> = bundle.try_convert_to()?`
+//! whatever the pair.
+//!
+//! Bridges that only know schema names at runtime go through the
+//! conversion registry instead: [`conversion`] looks up a [`Conversion`]
+//! between two schemas and [`convert_cdr`] applies it to CDR bytes. The
+//! built-in conversions are listed by [`conversions_from`]; applications
+//! add their own with [`register_conversion`], which take precedence.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::convert::{conversions_from, convert_cdr, TryConvertTo};
+//! use edgefirst_schemas::edgefirst_msgs::ImageWithInfo;
+//! use edgefirst_schemas::sensor_msgs::{CameraInfo, Image};
+//!
+//! let bundle = ImageWithInfo::builder()
+//!     .frame_id("camera")
+//!     .width(2)
+//!     .height(1)
+//!     .encoding("mono8")
+//!     .step(2)
+//!     .data(&[10, 20])
+//!     .build()
+//!     .unwrap();
+//!
+//! // Typed: the target type picks the conversion.
+//! let info: CameraInfo<Vec<u8>> = bundle.try_convert_to().unwrap();
+//! assert_eq!(info.frame_id(), "camera");
+//!
+//! // By schema name, as a bridge sees it.
+//! let schema = "edgefirst_msgs/msg/ImageWithInfo";
+//! let targets: Vec<_> = conversions_from(schema).iter().map(|c| c.to).collect();
+//! assert!(targets.contains(&"sensor_msgs/msg/Image"));
+//! let cdr = convert_cdr(schema, "sensor_msgs/msg/Image", bundle.as_cdr()).unwrap();
+//! assert_eq!(Image::from_cdr(cdr).unwrap().data(), [10, 20]);
+//! ```
+
+use std::convert::Infallible;
+use std::fmt;
+use std::sync::RwLock;
+
+use crate::cdr::CdrError;
+use crate::edgefirst_msgs::{Detect, ImageWithInfo};
+use crate::foxglove_msgs::{FoxgloveCameraCalibration, FoxgloveLocationFix, FoxgloveRawImage};
+use crate::nav_msgs::OccupancyGrid;
+use crate::sensor_msgs::pixel_convert::{encodings, ConvertError};
+use crate::sensor_msgs::pointcloud::PointCloudError;
+use crate::sensor_msgs::{
+    CameraInfo, EchoSelection, Image, LaserScan, MultiEchoLaserScan, NavSatFix, PointCloud,
+    PointCloud2,
+};
+use crate::vision_msgs::Detection2DArray;
+
+// ── Traits ──────────────────────────────────────────────────────────
+
+/// Infallible conversion of a message into another representation.
+pub trait ConvertTo<T> {
+    fn convert_to(&self) -> T;
+}
+
+/// Fallible conversion of a message into another representation.
+///
+/// Implemented for every [`ConvertTo`] with `Error = Infallible`.
+pub trait TryConvertTo<T> {
+    type Error;
+    fn try_convert_to(&self) -> Result<T, Self::Error>;
+}
+
+impl<S: ConvertTo<T> + ?Sized, T> TryConvertTo<T> for S {
+    type Error = Infallible;
+    fn try_convert_to(&self) -> Result<T, Infallible> {
+        Ok(self.convert_to())
+    }
+}
+
+/// The bundled image; see [`ImageWithInfo::to_image`].
+impl<B: AsRef<[u8]>> TryConvertTo<Image<Vec<u8>>> for ImageWithInfo<B> {
+    type Error = CdrError;
+    fn try_convert_to(&self) -> Result<Image<Vec<u8>>, CdrError> {
+        self.to_image()
+    }
+}
+
+/// The bundled calibration; see [`ImageWithInfo::to_camera_info`].
+impl<B: AsRef<[u8]>> TryConvertTo<CameraInfo<Vec<u8>>> for ImageWithInfo<B> {
+    type Error = CdrError;
+    fn try_convert_to(&self) -> Result<CameraInfo<Vec<u8>>, CdrError> {
+        self.to_camera_info()
+    }
+}
+
+/// A `mono8` rendering; call [`OccupancyGrid::to_image`] directly for
+/// `rgb8`.
+impl<B: AsRef<[u8]>> TryConvertTo<Image<Vec<u8>>> for OccupancyGrid<B> {
+    type Error = ConvertError;
+    fn try_convert_to(&self) -> Result<Image<Vec<u8>>, ConvertError> {
+        self.to_image(encodings::MONO8)
+    }
+}
+
+/// One `FLOAT32` field per channel; see [`PointCloud::to_pointcloud2`].
+impl<B: AsRef<[u8]>> TryConvertTo<PointCloud2<Vec<u8>>> for PointCloud<B> {
+    type Error = PointCloudError;
+    fn try_convert_to(&self) -> Result<PointCloud2<Vec<u8>>, PointCloudError> {
+        self.to_pointcloud2()
+    }
+}
+
+/// One channel per non-coordinate field; see [`PointCloud2::to_point_cloud`].
+impl<B: AsRef<[u8]>> TryConvertTo<PointCloud<Vec<u8>>> for PointCloud2<B> {
+    type Error = PointCloudError;
+    fn try_convert_to(&self) -> Result<PointCloud<Vec<u8>>, PointCloudError> {
+        self.to_point_cloud()
+    }
+}
+
+/// The first return of each beam; call
+/// [`MultiEchoLaserScan::to_laser_scan`] for the strongest.
+impl<B: AsRef<[u8]>> TryConvertTo<LaserScan<Vec<u8>>> for MultiEchoLaserScan<B> {
+    type Error = CdrError;
+    fn try_convert_to(&self) -> Result<LaserScan<Vec<u8>>, CdrError> {
+        self.to_laser_scan(EchoSelection::First)
+    }
+}
+
+/// Boxes kept in normalized coordinates; call
+/// [`Detect::to_detection2d_array`] with the image size for pixels.
+impl<B: AsRef<[u8]>> TryConvertTo<Detection2DArray<Vec<u8>>> for Detect<B> {
+    type Error = CdrError;
+    fn try_convert_to(&self) -> Result<Detection2DArray<Vec<u8>>, CdrError> {
+        self.to_detection2d_array(1, 1)
+    }
+}
+
+/// Lossless without binning or a region of interest; see
+/// [`CameraInfo::to_camera_calibration`].
+impl<B: AsRef<[u8]>> TryConvertTo<FoxgloveCameraCalibration<Vec<u8>>> for CameraInfo<B> {
+    type Error = CdrError;
+    fn try_convert_to(&self) -> Result<FoxgloveCameraCalibration<Vec<u8>>, CdrError> {
+        self.to_camera_calibration()
+    }
+}
+
+/// Fails for encodings Foxglove cannot display; see
+/// [`Image::to_raw_image`].
+impl<B: AsRef<[u8]>> TryConvertTo<FoxgloveRawImage<Vec<u8>>> for Image<B> {
+    type Error = ConversionError;
+    fn try_convert_to(&self) -> Result<FoxgloveRawImage<Vec<u8>>, ConversionError> {
+        self.to_raw_image()?.ok_or_else(|| {
+            ConversionError::Invalid(format!(
+                "encoding {:?} has no Foxglove RawImage equivalent",
+                self.encoding()
+            ))
+        })
+    }
+}
+
+/// Position and covariance carried over; the receiver status is dropped.
+impl<B: AsRef<[u8]>> TryConvertTo<FoxgloveLocationFix<Vec<u8>>> for NavSatFix<B> {
+    type Error = CdrError;
+    fn try_convert_to(&self) -> Result<FoxgloveLocationFix<Vec<u8>>, CdrError> {
+        self.to_location_fix()
+    }
+}
+
+// ── Error ───────────────────────────────────────────────────────────
+
+/// Errors from [`convert_cdr`] and registered conversion functions.
+#[derive(Debug)]
+pub enum ConversionError {
+    /// No conversion is registered between the two schemas.
+    Unsupported { from: String, to: String },
+    /// The source did not decode, or the result did not encode.
+    Cdr(CdrError),
+    /// The source could not be represented in the target schema.
+    Invalid(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::Unsupported { from, to } => {
+                write!(f, "no conversion from {} to {}", from, to)
+            }
+            ConversionError::Cdr(e) => write!(f, "{}", e),
+            ConversionError::Invalid(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConversionError::Cdr(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CdrError> for ConversionError {
+    fn from(e: CdrError) -> Self {
+        ConversionError::Cdr(e)
+    }
+}
+
+impl From<ConvertError> for ConversionError {
+    fn from(e: ConvertError) -> Self {
+        match e {
+            ConvertError::Cdr(e) => ConversionError::Cdr(e),
+            e => ConversionError::Invalid(e.to_string()),
+        }
+    }
+}
+
+impl From<PointCloudError> for ConversionError {
+    fn from(e: PointCloudError) -> Self {
+        match e {
+            PointCloudError::Cdr(e) => ConversionError::Cdr(e),
+            e => ConversionError::Invalid(e.to_string()),
+        }
+    }
+}
+
+// ── Registry ────────────────────────────────────────────────────────
+
+/// Converts the CDR bytes of one schema into the CDR bytes of another.
+pub type ConvertFn = fn(&[u8]) -> Result<Vec<u8>, ConversionError>;
+
+/// A conversion between two schemas, by name.
+#[derive(Debug, Clone, Copy)]
+pub struct Conversion {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub convert: ConvertFn,
+}
+
+const BUILTIN: &[Conversion] = &[
+    Conversion {
+        from: "edgefirst_msgs/msg/Detect",
+        to: "vision_msgs/msg/Detection2DArray",
+        convert: |cdr| {
+            let array: Detection2DArray<Vec<u8>> = Detect::from_cdr(cdr)?.try_convert_to()?;
+            Ok(array.into_cdr())
+        },
+    },
+    Conversion {
+        from: "edgefirst_msgs/msg/ImageWithInfo",
+        to: "sensor_msgs/msg/Image",
+        convert: |cdr| {
+            let img: Image<Vec<u8>> = ImageWithInfo::from_cdr(cdr)?.try_convert_to()?;
+            Ok(img.into_cdr())
+        },
+    },
+    Conversion {
+        from: "edgefirst_msgs/msg/ImageWithInfo",
+        to: "sensor_msgs/msg/CameraInfo",
+        convert: |cdr| {
+            let info: CameraInfo<Vec<u8>> = ImageWithInfo::from_cdr(cdr)?.try_convert_to()?;
+            Ok(info.into_cdr())
+        },
+    },
+    Conversion {
+        from: "nav_msgs/msg/OccupancyGrid",
+        to: "sensor_msgs/msg/Image",
+        convert: |cdr| {
+            let img: Image<Vec<u8>> = OccupancyGrid::from_cdr(cdr)?.try_convert_to()?;
+            Ok(img.into_cdr())
+        },
+    },
+    Conversion {
+        from: "sensor_msgs/msg/CameraInfo",
+        to: "foxglove_msgs/msg/CameraCalibration",
+        convert: |cdr| {
+            let cal: FoxgloveCameraCalibration<Vec<u8>> =
+                CameraInfo::from_cdr(cdr)?.try_convert_to()?;
+            Ok(cal.into_cdr())
+        },
+    },
+    Conversion {
+        from: "sensor_msgs/msg/Image",
+        to: "foxglove_msgs/msg/RawImage",
+        convert: |cdr| {
+            let img: FoxgloveRawImage<Vec<u8>> = Image::from_cdr(cdr)?.try_convert_to()?;
+            Ok(img.into_cdr())
+        },
+    },
+    Conversion {
+        from: "sensor_msgs/msg/MultiEchoLaserScan",
+        to: "sensor_msgs/msg/LaserScan",
+        convert: |cdr| {
+            let scan: LaserScan<Vec<u8>> = MultiEchoLaserScan::from_cdr(cdr)?.try_convert_to()?;
+            Ok(scan.into_cdr())
+        },
+    },
+    Conversion {
+        from: "sensor_msgs/msg/NavSatFix",
+        to: "foxglove_msgs/msg/LocationFix",
+        convert: |cdr| {
+            let fix: FoxgloveLocationFix<Vec<u8>> = NavSatFix::from_cdr(cdr)?.try_convert_to()?;
+            Ok(fix.into_cdr())
+        },
+    },
+    Conversion {
+        from: "sensor_msgs/msg/PointCloud",
+        to: "sensor_msgs/msg/PointCloud2",
+        convert: |cdr| {
+            let cloud: PointCloud2<Vec<u8>> = PointCloud::from_cdr(cdr)?.try_convert_to()?;
+            Ok(cloud.into_cdr())
+        },
+    },
+    Conversion {
+        from: "sensor_msgs/msg/PointCloud2",
+        to: "sensor_msgs/msg/PointCloud",
+        convert: |cdr| {
+            let cloud: PointCloud<Vec<u8>> = PointCloud2::from_cdr(cdr)?.try_convert_to()?;
+            Ok(cloud.into_cdr())
+        },
+    },
+];
+
+static REGISTERED: RwLock<Vec<Conversion>> = RwLock::new(Vec::new());
+
+/// Register a conversion. Later registrations for the same pair take
+/// precedence over earlier ones and over the built-in conversions.
+pub fn register_conversion(conversion: Conversion) {
+    REGISTERED
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(conversion);
+}
+
+/// The conversion from schema `from` to schema `to`, if any.
+pub fn conversion(from: &str, to: &str) -> Option<Conversion> {
+    let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+    registered
+        .iter()
+        .rev()
+        .chain(BUILTIN)
+        .find(|c| c.from == from && c.to == to)
+        .copied()
+}
+
+/// Every conversion out of schema `from`, one per target schema.
+pub fn conversions_from(from: &str) -> Vec<Conversion> {
+    let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+    let mut out: Vec<Conversion> = Vec::new();
+    for c in registered.iter().rev().chain(BUILTIN) {
+        if c.from == from && !out.iter().any(|o| o.to == c.to) {
+            out.push(*c);
+        }
+    }
+    out
+}
+
+/// Convert a CDR message of schema `from` into one of schema `to`.
+pub fn convert_cdr(from: &str, to: &str, cdr: &[u8]) -> Result<Vec<u8>, ConversionError> {
+    let c = conversion(from, to).ok_or_else(|| ConversionError::Unsupported {
+        from: from.to_owned(),
+        to: to.to_owned(),
+    })?;
+    (c.convert)(cdr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+    use crate::cdr::{decode_fixed, encode_fixed};
+    use crate::std_msgs::Header;
+
+    #[test]
+    fn image_with_info_splits() {
+        let bundle = ImageWithInfo::builder()
+            .stamp(Time::new(7, 8))
+            .frame_id("cam")
+            .height(1)
+            .width(2)
+            .encoding("mono8")
+            .step(2)
+            .data(&[1, 2])
+            .distortion_model("plumb_bob")
+            .build()
+            .unwrap();
+
+        let img: Image<Vec<u8>> = bundle.try_convert_to().unwrap();
+        assert_eq!(img.stamp(), Time::new(7, 8));
+        assert_eq!(img.data(), [1, 2]);
+        let info: CameraInfo<Vec<u8>> = bundle.try_convert_to().unwrap();
+        assert_eq!(info.distortion_model(), "plumb_bob");
+
+        let cdr = convert_cdr(
+            "edgefirst_msgs/msg/ImageWithInfo",
+            "sensor_msgs/msg/Image",
+            bundle.as_cdr(),
+        )
+        .unwrap();
+        assert_eq!(cdr, img.to_cdr());
+    }
+
+    #[test]
+    fn legacy_point_cloud_normalizes_to_pointcloud2() {
+        let points = [crate::geometry_msgs::Point32 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        }];
+        let cloud = PointCloud::builder()
+            .frame_id("radar")
+            .points(&points)
+            .build()
+            .unwrap();
+
+        let cdr = convert_cdr(
+            "sensor_msgs/msg/PointCloud",
+            "sensor_msgs/msg/PointCloud2",
+            cloud.as_cdr(),
+        )
+        .unwrap();
+        let pc2 = PointCloud2::from_cdr(cdr).unwrap();
+        assert_eq!(pc2.frame_id(), "radar");
+        assert_eq!(pc2.fields().len(), 3);
+        let back: PointCloud<Vec<u8>> = pc2.try_convert_to().unwrap();
+        assert_eq!(back.to_cdr(), cloud.to_cdr());
+    }
+
+    #[test]
+    fn detect_bridges_to_vision_msgs() {
+        let detect = Detect::builder()
+            .frame_id("camera")
+            .boxes(&[crate::edgefirst_msgs::DetectBoxView {
+                center_x: 0.5,
+                center_y: 0.5,
+                width: 0.25,
+                height: 0.5,
+                label: "car",
+                score: 0.75,
+                distance: 0.0,
+                speed: 0.0,
+                track_id: "",
+                track_lifetime: 0,
+                track_created: Time::new(0, 0),
+            }])
+            .build()
+            .unwrap();
+
+        let cdr = convert_cdr(
+            "edgefirst_msgs/msg/Detect",
+            "vision_msgs/msg/Detection2DArray",
+            detect.as_cdr(),
+        )
+        .unwrap();
+        let array = Detection2DArray::from_cdr(cdr).unwrap();
+        assert_eq!(array.frame_id(), "camera");
+        let detections = array.detections();
+        assert_eq!(detections[0].results[0].class_id, "car");
+        assert_eq!(detections[0].bbox.size_x, 0.25);
+    }
+
+    #[test]
+    fn nav_sat_fix_bridges_to_location_fix() {
+        let fix = NavSatFix::builder()
+            .frame_id("gps")
+            .latitude(45.5)
+            .longitude(-73.6)
+            .build()
+            .unwrap();
+        let cdr = convert_cdr(
+            "sensor_msgs/msg/NavSatFix",
+            "foxglove_msgs/msg/LocationFix",
+            fix.as_cdr(),
+        )
+        .unwrap();
+        let loc = FoxgloveLocationFix::from_cdr(cdr).unwrap();
+        assert_eq!(loc.frame_id(), "gps");
+        assert_eq!((loc.latitude(), loc.longitude()), (45.5, -73.6));
+
+        let tight = crate::cdr::DecodeLimits {
+            max_string_len: 2,
+            ..crate::cdr::DecodeLimits::DEFAULT
+        };
+        let result: Result<FoxgloveLocationFix<Vec<u8>>, _> = tight.scoped(|| fix.try_convert_to());
+        assert!(matches!(
+            result.map(|_| ()).unwrap_err().root(),
+            CdrError::LimitExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn image_bridges_to_raw_image() {
+        let img = Image::builder()
+            .encoding("bgr8")
+            .width(1)
+            .height(1)
+            .step(3)
+            .data(&[1, 2, 3])
+            .build()
+            .unwrap();
+        let cdr = convert_cdr(
+            "sensor_msgs/msg/Image",
+            "foxglove_msgs/msg/RawImage",
+            img.as_cdr(),
+        )
+        .unwrap();
+        let raw = FoxgloveRawImage::from_cdr(cdr).unwrap();
+        assert_eq!((raw.encoding(), raw.data()), ("bgr8", &[1u8, 2, 3][..]));
+
+        let nv12 = Image::builder().encoding("nv12").build().unwrap();
+        let err = convert_cdr(
+            "sensor_msgs/msg/Image",
+            "foxglove_msgs/msg/RawImage",
+            nv12.as_cdr(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ConversionError::Invalid(_)));
+    }
+
+    #[test]
+    fn registered_conversions_take_precedence() {
+        struct Frame(u32);
+        impl ConvertTo<u32> for Frame {
+            fn convert_to(&self) -> u32 {
+                self.0
+            }
+        }
+        assert_eq!(TryConvertTo::<u32>::try_convert_to(&Frame(3)), Ok(3));
+
+        register_conversion(Conversion {
+            from: "std_msgs/msg/Header",
+            to: "builtin_interfaces/msg/Time",
+            convert: |cdr| Ok(encode_fixed(&Header::from_cdr(cdr)?.stamp())?),
+        });
+        let header = Header::builder()
+            .stamp(Time::new(1, 2))
+            .frame_id("base_link")
+            .build()
+            .unwrap();
+        let cdr = convert_cdr(
+            "std_msgs/msg/Header",
+            "builtin_interfaces/msg/Time",
+            header.as_cdr(),
+        )
+        .unwrap();
+        assert_eq!(decode_fixed::<Time>(&cdr).unwrap(), Time::new(1, 2));
+        assert_eq!(conversions_from("std_msgs/msg/Header").len(), 1);
+
+        assert!(matches!(
+            convert_cdr("builtin_interfaces/msg/Time", "std_msgs/msg/Header", &[]),
+            Err(ConversionError::Unsupported { .. })
+        ));
+        assert!(matches!(
+            convert_cdr(
+                "nav_msgs/msg/OccupancyGrid",
+                "sensor_msgs/msg/Image",
+                &[0, 1]
+            ),
+            Err(ConversionError::Cdr(_))
+        ));
+    }
+}
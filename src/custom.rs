@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Message types defined outside this crate.
+//!
+//! An application that publishes its own messages alongside the built-in
+//! ones describes them as plain structs following the ROS field conventions
+//! (snake_case fields of ROS primitive types, strings, `Vec` sequences,
+//! fixed arrays and other messages). [`CdrValue`] encodes one such field,
+//! and [`CustomMessage`] turns a struct of them into a message: CDR
+//! encoding, a `.msg` definition and an entry in the
+//! [schema registry](crate::schema_registry), so JSON, dynamic decoding,
+//! MCAP helpers and the C API's generic handle see it like a built-in
+//! schema.
+//!
+//! The `derive` feature generates all of it with
+//! `#[derive(EdgeFirstMessage)]` from the `edgefirst-schemas-derive`
+//! crate; implementing the traits by hand is equivalent.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::custom::{CdrValue, CustomMessage};
+//! use edgefirst_schemas::geometry_msgs::Point;
+//! # use edgefirst_schemas::cdr::{CdrCursor, CdrError, CdrSizer, CdrWriter};
+//! # use edgefirst_schemas::schema_registry::{RegisterError, SchemaType};
+//!
+//! // Normally `#[derive(EdgeFirstMessage)]`.
+//! struct Waypoint {
+//!     target: Point,
+//!     speed: f32,
+//! }
+//! # impl SchemaType for Waypoint {
+//! #     const SCHEMA_NAME: &'static str = "acme_msgs/msg/Waypoint";
+//! # }
+//! # impl CdrValue for Waypoint {
+//! #     fn msg_type() -> String { "acme_msgs/Waypoint".into() }
+//! #     fn read_value(c: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+//! #         Ok(Waypoint { target: Point::read_value(c)?, speed: f32::read_value(c)? })
+//! #     }
+//! #     fn write_value(&self, w: &mut CdrWriter<'_>) {
+//! #         self.target.write_value(w);
+//! #         self.speed.write_value(w);
+//! #     }
+//! #     fn size_value(&self, s: &mut CdrSizer) {
+//! #         self.target.size_value(s);
+//! #         self.speed.size_value(s);
+//! #     }
+//! #     fn register_nested() -> Result<(), RegisterError> { Self::register() }
+//! # }
+//! # impl CustomMessage for Waypoint {
+//! #     fn definition() -> &'static str {
+//! #         "geometry_msgs/Point target\nfloat32 speed\n"
+//! #     }
+//! # }
+//!
+//! Waypoint::register().unwrap();
+//! assert_eq!(
+//!     edgefirst_schemas::schema_registry::definition("acme_msgs/msg/Waypoint"),
+//!     Some("geometry_msgs/Point target\nfloat32 speed\n")
+//! );
+//!
+//! let cdr = Waypoint { target: Point { x: 1.0, y: 2.0, z: 0.0 }, speed: 0.5 }.to_cdr();
+//! let back = Waypoint::from_cdr(&cdr).unwrap();
+//! assert_eq!((back.target.y, back.speed), (2.0, 0.5));
+//! ```
+
+use crate::cdr::{CdrCursor, CdrError, CdrFixed, CdrSizer, CdrWriter};
+use crate::schema_registry::{register_schema, RegisterError, SchemaType, UserSchema};
+use crate::std_msgs::Header;
+
+/// A value that can be a field of a [`CustomMessage`].
+pub trait CdrValue: Sized {
+    /// Type of the field in a `.msg` definition, e.g. `float32`,
+    /// `string[]` or `std_msgs/Header`.
+    fn msg_type() -> String;
+
+    /// Decode the value at the cursor's position.
+    fn read_value(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError>;
+
+    /// Encode the value at the writer's position.
+    fn write_value(&self, writer: &mut CdrWriter<'_>);
+
+    /// Account for the encoded value, alignment included.
+    fn size_value(&self, sizer: &mut CdrSizer);
+
+    /// Register the schemas this type needs in the registry before a
+    /// message using it is registered. Only custom messages have any.
+    fn register_nested() -> Result<(), RegisterError> {
+        Ok(())
+    }
+}
+
+/// A message type defined outside this crate, usually through
+/// `#[derive(EdgeFirstMessage)]`.
+pub trait CustomMessage: CdrValue + SchemaType {
+    /// The message's `.msg` definition, one line per field.
+    fn definition() -> &'static str;
+
+    /// CDR bytes, encapsulation header included.
+    fn to_cdr(&self) -> Vec<u8> {
+        let mut sizer = CdrSizer::new();
+        self.size_value(&mut sizer);
+        let mut buf = vec![0u8; sizer.size()];
+        let mut writer = CdrWriter::new(&mut buf).expect("sized from the value");
+        self.write_value(&mut writer);
+        writer.finish().expect("sized from the value");
+        buf
+    }
+
+    /// Decode a complete CDR message, in either byte order.
+    fn from_cdr(cdr: &[u8]) -> Result<Self, CdrError> {
+        let mut cursor = CdrCursor::detect(cdr)?;
+        let value = Self::read_value(&mut cursor)?;
+        cursor.expect_end()?;
+        Ok(value)
+    }
+
+    /// Register the custom messages among the fields, through
+    /// [`CdrValue::register_nested`].
+    fn register_fields() -> Result<(), RegisterError> {
+        Ok(())
+    }
+
+    /// Register the message, and any custom message it contains, with the
+    /// schema registry. Registering again is a no-op.
+    ///
+    /// Messages off the wire are checked by decoding them as `Self`.
+    fn register() -> Result<(), RegisterError> {
+        Self::register_fields()?;
+        register_schema(UserSchema {
+            name: Self::SCHEMA_NAME,
+            definition: Self::definition(),
+            validate: Some(|cdr| Self::from_cdr(cdr).map(drop)),
+        })
+    }
+}
+
+// ── Field types ──────────────────────────────────────────────────────
+
+macro_rules! impl_primitive {
+    ($($ty:ty => $name:literal, $read:ident, $write:ident, $size:ident;)*) => {$(
+        impl CdrValue for $ty {
+            fn msg_type() -> String {
+                $name.into()
+            }
+
+            fn read_value(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+                cursor.$read()
+            }
+
+            fn write_value(&self, writer: &mut CdrWriter<'_>) {
+                writer.$write(*self);
+            }
+
+            fn size_value(&self, sizer: &mut CdrSizer) {
+                sizer.$size();
+            }
+        }
+    )*};
+}
+
+impl_primitive! {
+    bool => "bool", read_bool, write_bool, size_u8;
+    i8 => "int8", read_i8, write_i8, size_u8;
+    u8 => "uint8", read_u8, write_u8, size_u8;
+    i16 => "int16", read_i16, write_i16, size_u16;
+    u16 => "uint16", read_u16, write_u16, size_u16;
+    i32 => "int32", read_i32, write_i32, size_u32;
+    u32 => "uint32", read_u32, write_u32, size_u32;
+    i64 => "int64", read_i64, write_i64, size_u64;
+    u64 => "uint64", read_u64, write_u64, size_u64;
+    f32 => "float32", read_f32, write_f32, size_u32;
+    f64 => "float64", read_f64, write_f64, size_u64;
+}
+
+impl CdrValue for String {
+    fn msg_type() -> String {
+        "string".into()
+    }
+
+    fn read_value(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        cursor.read_string().map(str::to_owned)
+    }
+
+    fn write_value(&self, writer: &mut CdrWriter<'_>) {
+        writer.write_string(self);
+    }
+
+    fn size_value(&self, sizer: &mut CdrSizer) {
+        sizer.size_string(self);
+    }
+}
+
+/// Unbounded sequence, `T[]`.
+impl<T: CdrValue> CdrValue for Vec<T> {
+    fn msg_type() -> String {
+        format!("{}[]", T::msg_type())
+    }
+
+    fn read_value(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        let count = cursor.read_seq_len()?;
+        let n = cursor.check_seq_count(count, 1)?;
+        (0..n).map(|_| T::read_value(cursor)).collect()
+    }
+
+    fn write_value(&self, writer: &mut CdrWriter<'_>) {
+        writer.write_u32(self.len() as u32);
+        self.iter().for_each(|v| v.write_value(writer));
+    }
+
+    fn size_value(&self, sizer: &mut CdrSizer) {
+        sizer.size_u32();
+        self.iter().for_each(|v| v.size_value(sizer));
+    }
+
+    fn register_nested() -> Result<(), RegisterError> {
+        T::register_nested()
+    }
+}
+
+/// Fixed-size array, `T[N]`.
+impl<T: CdrValue, const N: usize> CdrValue for [T; N] {
+    fn msg_type() -> String {
+        format!("{}[{N}]", T::msg_type())
+    }
+
+    fn read_value(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        let items = (0..N)
+            .map(|_| T::read_value(cursor))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("read exactly N elements")))
+    }
+
+    fn write_value(&self, writer: &mut CdrWriter<'_>) {
+        self.iter().for_each(|v| v.write_value(writer));
+    }
+
+    fn size_value(&self, sizer: &mut CdrSizer) {
+        self.iter().for_each(|v| v.size_value(sizer));
+    }
+
+    fn register_nested() -> Result<(), RegisterError> {
+        T::register_nested()
+    }
+}
+
+/// Built-in fixed-size messages such as `geometry_msgs/Point` or
+/// `builtin_interfaces/Time`.
+impl<T: CdrFixed + SchemaType> CdrValue for T {
+    fn msg_type() -> String {
+        T::SCHEMA_NAME.replacen("/msg/", "/", 1)
+    }
+
+    fn read_value(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        <T as CdrFixed>::read_cdr(cursor)
+    }
+
+    fn write_value(&self, writer: &mut CdrWriter<'_>) {
+        <T as CdrFixed>::write_cdr(self, writer);
+    }
+
+    fn size_value(&self, sizer: &mut CdrSizer) {
+        <T as CdrFixed>::size_cdr(sizer);
+    }
+}
+
+/// `std_msgs/Header`, the conventional first field of a stamped message.
+impl CdrValue for Header<Vec<u8>> {
+    fn msg_type() -> String {
+        "std_msgs/Header".into()
+    }
+
+    fn read_value(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        let stamp = crate::builtin_interfaces::Time::read_value(cursor)?;
+        let frame_id = cursor.read_string()?;
+        Header::builder().stamp(stamp).frame_id(frame_id).build()
+    }
+
+    fn write_value(&self, writer: &mut CdrWriter<'_>) {
+        self.stamp().write_value(writer);
+        writer.write_string(self.frame_id());
+    }
+
+    fn size_value(&self, sizer: &mut CdrSizer) {
+        self.stamp().size_value(sizer);
+        sizer.size_string(self.frame_id());
+    }
+}
+
+/// Support code for `#[derive(EdgeFirstMessage)]`; not a public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use std::sync::OnceLock;
+
+    /// Set `errno` and the last error message for a generated C accessor.
+    #[cfg(feature = "ffi")]
+    pub fn set_error(code: i32, msg: impl std::fmt::Display) {
+        crate::ffi::set_error(code, msg);
+    }
+
+    #[cfg(feature = "ffi")]
+    pub const EINVAL: i32 = libc::EINVAL;
+    #[cfg(feature = "ffi")]
+    pub const EBADMSG: i32 = libc::EBADMSG;
+}
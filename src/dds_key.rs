@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! DDS instance key hashes for bridging into keyed DDS topics.
+//!
+//! DDS identifies the instance a sample belongs to by a 16-byte key hash
+//! (RTPS 2.x §9.6.3.8, `PID_KEY_HASH`). The key fields are serialized in
+//! declaration order as big-endian CDR; if the key's *maximum* serialized
+//! size is at most 16 bytes the hash is those bytes zero-padded, otherwise
+//! it is the MD5 digest of them. Any key containing an unbounded string or
+//! sequence is therefore always hashed.
+//!
+//! Message types opt in by implementing [`DdsKeyed`], which names their key
+//! fields. Types bridged without a Rust impl can compute the hash directly
+//! with [`key_hash`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::dds_key::{key_hash, DdsKeyed};
+//! use edgefirst_schemas::edgefirst_msgs::Track;
+//!
+//! let track = Track::builder().id("t-42").build().unwrap();
+//! let same = key_hash(None, |w| w.write_string("t-42"));
+//! assert_eq!(track.dds_key_hash(), same);
+//!
+//! // A lone uint32 key fits in 16 bytes and is used verbatim.
+//! let small = key_hash(Some(4), |w| w.write_u32(7));
+//! assert_eq!(small[..4], [0, 0, 0, 7]);
+//! ```
+
+use alloc::vec::Vec;
+
+/// Big-endian CDR serializer for key fields.
+///
+/// Alignment is relative to the start of the key stream, as for a CDR
+/// payload without encapsulation header.
+#[derive(Debug, Default, Clone)]
+pub struct KeyWriter {
+    buf: Vec<u8>,
+}
+
+impl KeyWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The serialized key so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn align(&mut self, n: usize) {
+        let padded = (self.buf.len() + n - 1) & !(n - 1);
+        self.buf.resize(padded, 0);
+    }
+
+    pub fn write_u8(&mut self, v: u8) {
+        self.buf.push(v);
+    }
+    pub fn write_i8(&mut self, v: i8) {
+        self.buf.push(v as u8);
+    }
+    pub fn write_bool(&mut self, v: bool) {
+        self.buf.push(u8::from(v));
+    }
+    pub fn write_u16(&mut self, v: u16) {
+        self.align(2);
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+    pub fn write_i16(&mut self, v: i16) {
+        self.write_u16(v as u16);
+    }
+    pub fn write_u32(&mut self, v: u32) {
+        self.align(4);
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+    pub fn write_i32(&mut self, v: i32) {
+        self.write_u32(v as u32);
+    }
+    pub fn write_u64(&mut self, v: u64) {
+        self.align(8);
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+    pub fn write_i64(&mut self, v: i64) {
+        self.write_u64(v as u64);
+    }
+    pub fn write_f32(&mut self, v: f32) {
+        self.write_u32(v.to_bits());
+    }
+    pub fn write_f64(&mut self, v: f64) {
+        self.write_u64(v.to_bits());
+    }
+    /// Length prefix (including the NUL), bytes, then the NUL terminator.
+    pub fn write_string(&mut self, s: &str) {
+        self.write_u32(s.len() as u32 + 1);
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(0);
+    }
+}
+
+/// Compute a key hash from key fields written by `write`.
+///
+/// `max_size` is the largest size the key can serialize to, or `None` when
+/// it is unbounded; it selects between the verbatim and MD5 forms and must
+/// be the same for every instance of the type.
+pub fn key_hash(max_size: Option<usize>, write: impl FnOnce(&mut KeyWriter)) -> [u8; 16] {
+    let mut w = KeyWriter::new();
+    write(&mut w);
+    match max_size {
+        Some(max) if max <= 16 => {
+            let mut hash = [0u8; 16];
+            let n = w.buf.len().min(16);
+            hash[..n].copy_from_slice(&w.buf[..n]);
+            hash
+        }
+        _ => md5(&w.buf),
+    }
+}
+
+/// A message type with DDS key fields.
+pub trait DdsKeyed {
+    /// Maximum serialized size of the key fields, `None` if unbounded.
+    const KEY_MAX_SIZE: Option<usize>;
+
+    /// Write the key fields in IDL declaration order.
+    fn write_key(&self, w: &mut KeyWriter);
+
+    /// The 16-byte DDS key hash of this sample's instance.
+    fn dds_key_hash(&self) -> [u8; 16] {
+        key_hash(Self::KEY_MAX_SIZE, |w| self.write_key(w))
+    }
+}
+
+// ── MD5 (RFC 1321) ───────────────────────────────────────────────────
+
+const S: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// `floor(abs(sin(i + 1)) * 2^32)`.
+const K: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+pub(crate) fn md5(data: &[u8]) -> [u8; 16] {
+    let mut state: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+    let mut msg = data.to_vec();
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&((data.len() as u64).wrapping_mul(8)).to_le_bytes());
+
+    for block in msg.chunks_exact(64) {
+        let m: Vec<u32> = block
+            .chunks_exact(4)
+            .map(|w| u32::from_le_bytes([w[0], w[1], w[2], w[3]]))
+            .collect();
+        let [mut a, mut b, mut c, mut d] = state;
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let rotated = a
+                .wrapping_add(f)
+                .wrapping_add(K[i])
+                .wrapping_add(m[g])
+                .rotate_left(S[i]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(rotated);
+        }
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+    }
+
+    let mut out = [0u8; 16];
+    for (chunk, word) in out.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(b: &[u8]) -> String {
+        b.iter().map(|x| format!("{:02x}", x)).collect()
+    }
+
+    #[test]
+    fn md5_reference_vectors() {
+        assert_eq!(hex(&md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(hex(&md5(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex(&md5(
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+            )),
+            "57edf4a22be3c955ac49da2e2107b67a"
+        );
+    }
+
+    #[test]
+    fn key_writer_is_aligned_big_endian() {
+        let mut w = KeyWriter::new();
+        w.write_u8(1);
+        w.write_u32(0x0203_0405);
+        w.write_string("ab");
+        assert_eq!(
+            w.as_bytes(),
+            &[1, 0, 0, 0, 2, 3, 4, 5, 0, 0, 0, 3, b'a', b'b', 0]
+        );
+    }
+
+    #[test]
+    fn small_keys_are_verbatim_large_keys_hashed() {
+        let small = key_hash(Some(8), |w| w.write_u64(0x0102_0304_0506_0708));
+        assert_eq!(small, [1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let large = key_hash(None, |w| w.write_u32(7));
+        assert_eq!(large, md5(&[0, 0, 0, 7]));
+    }
+}
@@ -0,0 +1,443 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Dynamic decoding of any registered schema into a [`Value`] tree.
+//!
+//! Like the JSON transcoder, messages are walked at runtime from the
+//! `.msg` definitions returned by
+//! [`schema_registry::definition`](crate::schema_registry::definition), so
+//! topic echo tools, bridges and recorders can read and rewrite messages
+//! without linking every concrete type. Unlike JSON, each value keeps its
+//! exact wire type, so [`encode`] of a decoded tree reproduces the
+//! little-endian CDR bytes. Requires the `dynamic` feature.
+//!
+//! Mapping:
+//!
+//! - messages become [`Value::Struct`] with fields in declaration order
+//! - each primitive maps to the variant of the same width and signedness;
+//!   `byte` and `char` are `U8`
+//! - `uint8[]` / `byte[]` / `char[]` fields, fixed or not, become
+//!   [`Value::Bytes`]; other arrays and sequences become [`Value::Array`]
+//!
+//! When encoding, struct fields that are missing are written as zero,
+//! empty or default-valued; unknown fields and mismatched variants are
+//! rejected.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::builtin_interfaces::Time;
+//! use edgefirst_schemas::cdr::encode_fixed;
+//! use edgefirst_schemas::dynamic::{decode, encode, Value};
+//!
+//! let cdr = encode_fixed(&Time::new(12, 500)).unwrap();
+//! let mut value = decode("builtin_interfaces/msg/Time", &cdr).unwrap();
+//! assert_eq!(value.get("sec"), Some(&Value::I32(12)));
+//!
+//! *value.get_mut("sec").unwrap() = Value::I32(13);
+//! let cdr = encode("builtin_interfaces/msg/Time", &value).unwrap();
+//! assert_eq!(cdr, encode_fixed(&Time::new(13, 500)).unwrap());
+//! ```
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::cdr::{CdrCursor, CdrError, CdrSizer, CdrWriter, FieldContext};
+use crate::msg_spec::{
+    write_default_field, Arity, Elem, Field, Prim, Resolver, Sink, Spec, SpecError,
+};
+use crate::schema_registry::validate_registered;
+
+/// A decoded message, or one field of it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    I8(i8),
+    U8(u8),
+    I16(i16),
+    U16(u16),
+    I32(i32),
+    U32(u32),
+    I64(i64),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    /// A `uint8`, `byte` or `char` array or sequence.
+    Bytes(Vec<u8>),
+    /// Any other array or sequence.
+    Array(Vec<Value>),
+    /// A message: `(field name, value)` in declaration order.
+    Struct(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// The field `name` of a [`Value::Struct`].
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        match self {
+            Value::Struct(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Mutable access to the field `name` of a [`Value::Struct`].
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Value> {
+        match self {
+            Value::Struct(fields) => fields.iter_mut().find(|(n, _)| n == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+/// Errors from [`decode`] and [`encode`].
+#[derive(Debug)]
+pub enum DynamicError {
+    /// No definition is registered for the schema, or for a type it nests.
+    UnknownSchema(String),
+    /// A line of the schema's definition could not be interpreted.
+    InvalidDefinition { schema: String, line: String },
+    /// The CDR bytes did not decode against the definition.
+    Cdr(CdrError),
+    /// The value did not match the definition at `path`, e.g.
+    /// `boxes[2].label`.
+    Value { path: String, reason: String },
+}
+
+impl fmt::Display for DynamicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicError::UnknownSchema(schema) => write!(f, "unknown schema {}", schema),
+            DynamicError::InvalidDefinition { schema, line } => {
+                write!(f, "invalid definition line in {}: {}", schema, line)
+            }
+            DynamicError::Cdr(e) => write!(f, "{}", e),
+            DynamicError::Value { path, reason } if path.is_empty() => write!(f, "{}", reason),
+            DynamicError::Value { path, reason } => write!(f, "field `{}`: {}", path, reason),
+        }
+    }
+}
+
+impl core::error::Error for DynamicError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            DynamicError::Cdr(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CdrError> for DynamicError {
+    fn from(e: CdrError) -> Self {
+        DynamicError::Cdr(e)
+    }
+}
+
+impl From<SpecError> for DynamicError {
+    fn from(e: SpecError) -> Self {
+        match e {
+            SpecError::UnknownSchema(schema) => DynamicError::UnknownSchema(schema),
+            SpecError::InvalidDefinition { schema, line } => {
+                DynamicError::InvalidDefinition { schema, line }
+            }
+        }
+    }
+}
+
+/// Decode a CDR message of type `schema_name` (e.g.
+/// `"sensor_msgs/msg/Image"`) into a [`Value::Struct`]. The byte order
+/// follows the encapsulation header.
+pub fn decode(schema_name: &str, cdr: &[u8]) -> Result<Value, DynamicError> {
+    let spec = Resolver::default().resolve(schema_name, 0)?;
+    let mut c = CdrCursor::detect(cdr)?;
+    let value = read_msg(&spec, &mut c)?;
+    c.expect_end()?;
+    validate_registered(schema_name, cdr)?;
+    Ok(value)
+}
+
+/// Encode a [`Value::Struct`] as a little-endian CDR message of type
+/// `schema_name`, encapsulation header included.
+pub fn encode(schema_name: &str, value: &Value) -> Result<Vec<u8>, DynamicError> {
+    let spec = Resolver::default().resolve(schema_name, 0)?;
+    let mut sizer = CdrSizer::new();
+    write_msg(&spec, value, &mut sizer)?;
+    let mut buf = vec![0u8; sizer.size()];
+    let mut w = CdrWriter::new(&mut buf)?;
+    write_msg(&spec, value, &mut w)?;
+    w.finish()?;
+    Ok(buf)
+}
+
+// ── CDR → Value ──────────────────────────────────────────────────────
+
+fn read_msg(spec: &Spec, c: &mut CdrCursor<'_>) -> Result<Value, CdrError> {
+    let mut fields = Vec::with_capacity(spec.fields.len());
+    for f in &spec.fields {
+        let value = read_field(f, c).field(f.name)?;
+        fields.push((f.name.to_owned(), value));
+    }
+    Ok(Value::Struct(fields))
+}
+
+fn read_field(f: &Field, c: &mut CdrCursor<'_>) -> Result<Value, CdrError> {
+    let count = match f.arity {
+        Arity::Single => return read_elem(&f.elem, c),
+        Arity::Fixed(n) => n,
+        Arity::Seq(Some(bound)) => c.read_seq_len_bounded(bound)?,
+        Arity::Seq(None) => {
+            let raw = c.read_u32()?;
+            c.check_seq_count(raw, 1)?
+        }
+    };
+    if let Elem::Prim(Prim::U8) = f.elem {
+        return Ok(Value::Bytes(c.read_raw(count)?.to_vec()));
+    }
+    let mut items = Vec::with_capacity(count);
+    for _ in 0..count {
+        items.push(read_elem(&f.elem, c)?);
+    }
+    Ok(Value::Array(items))
+}
+
+fn read_elem(elem: &Elem, c: &mut CdrCursor<'_>) -> Result<Value, CdrError> {
+    Ok(match elem {
+        Elem::Prim(Prim::Bool) => Value::Bool(c.read_bool()?),
+        Elem::Prim(Prim::I8) => Value::I8(c.read_i8()?),
+        Elem::Prim(Prim::U8) => Value::U8(c.read_u8()?),
+        Elem::Prim(Prim::I16) => Value::I16(c.read_i16()?),
+        Elem::Prim(Prim::U16) => Value::U16(c.read_u16()?),
+        Elem::Prim(Prim::I32) => Value::I32(c.read_i32()?),
+        Elem::Prim(Prim::U32) => Value::U32(c.read_u32()?),
+        Elem::Prim(Prim::I64) => Value::I64(c.read_i64()?),
+        Elem::Prim(Prim::U64) => Value::U64(c.read_u64()?),
+        Elem::Prim(Prim::F32) => Value::F32(c.read_f32()?),
+        Elem::Prim(Prim::F64) => Value::F64(c.read_f64()?),
+        Elem::String(Some(bound)) => Value::String(c.read_string_bounded(*bound)?.to_owned()),
+        Elem::String(None) => Value::String(c.read_string()?.to_owned()),
+        Elem::Msg(spec) => read_msg(spec, c)?,
+    })
+}
+
+// ── Value → CDR ──────────────────────────────────────────────────────
+
+fn mismatch(reason: impl Into<String>) -> DynamicError {
+    DynamicError::Value {
+        path: String::new(),
+        reason: reason.into(),
+    }
+}
+
+/// Prefix the path of a value error with a field name or `[index]`.
+fn at(segment: String) -> impl FnOnce(DynamicError) -> DynamicError {
+    move |e| match e {
+        DynamicError::Value { path, reason } => {
+            let path = if path.is_empty() || path.starts_with('[') {
+                segment + &path
+            } else {
+                segment + "." + &path
+            };
+            DynamicError::Value { path, reason }
+        }
+        e => e,
+    }
+}
+
+fn write_msg(spec: &Spec, value: &Value, s: &mut dyn Sink) -> Result<(), DynamicError> {
+    let Value::Struct(fields) = value else {
+        return Err(mismatch("expected a struct"));
+    };
+    if let Some((name, _)) = fields
+        .iter()
+        .find(|(n, _)| !spec.fields.iter().any(|f| f.name == n.as_str()))
+    {
+        return Err(mismatch(format!("unknown field `{}`", name)));
+    }
+    for f in &spec.fields {
+        match value.get(f.name) {
+            Some(v) => write_field(f, v, s).map_err(at(f.name.to_owned()))?,
+            None => write_default_field(f, s),
+        }
+    }
+    Ok(())
+}
+
+fn write_field(f: &Field, value: &Value, s: &mut dyn Sink) -> Result<(), DynamicError> {
+    if let Arity::Single = f.arity {
+        return write_elem(&f.elem, value, s);
+    }
+    let len = match value {
+        Value::Bytes(bytes) if matches!(f.elem, Elem::Prim(Prim::U8)) => bytes.len(),
+        Value::Array(items) => items.len(),
+        _ => return Err(mismatch("expected an array")),
+    };
+    match f.arity {
+        Arity::Fixed(n) if len != n => {
+            return Err(mismatch(format!("expected {} elements, got {}", n, len)))
+        }
+        Arity::Seq(Some(bound)) if len > bound => {
+            return Err(mismatch(format!("{} elements exceed bound {}", len, bound)))
+        }
+        Arity::Fixed(_) => {}
+        _ => s.u32(len as u32),
+    }
+    match value {
+        Value::Bytes(bytes) => s.raw(bytes),
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                write_elem(&f.elem, item, s).map_err(at(format!("[{}]", i)))?;
+            }
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+fn write_elem(elem: &Elem, value: &Value, s: &mut dyn Sink) -> Result<(), DynamicError> {
+    match (elem, value) {
+        (Elem::Msg(spec), v) => return write_msg(spec, v, s),
+        (Elem::String(bound), Value::String(v)) => {
+            if let Some(bound) = bound.filter(|&b| v.len() > b) {
+                return Err(mismatch(format!(
+                    "{} bytes exceed bound {}",
+                    v.len(),
+                    bound
+                )));
+            }
+            s.string(v);
+        }
+        (Elem::Prim(Prim::Bool), Value::Bool(v)) => s.u8(*v as u8),
+        (Elem::Prim(Prim::I8), Value::I8(v)) => s.u8(*v as u8),
+        (Elem::Prim(Prim::U8), Value::U8(v)) => s.u8(*v),
+        (Elem::Prim(Prim::I16), Value::I16(v)) => s.u16(*v as u16),
+        (Elem::Prim(Prim::U16), Value::U16(v)) => s.u16(*v),
+        (Elem::Prim(Prim::I32), Value::I32(v)) => s.u32(*v as u32),
+        (Elem::Prim(Prim::U32), Value::U32(v)) => s.u32(*v),
+        (Elem::Prim(Prim::I64), Value::I64(v)) => s.u64(*v as u64),
+        (Elem::Prim(Prim::U64), Value::U64(v)) => s.u64(*v),
+        (Elem::Prim(Prim::F32), Value::F32(v)) => s.u32(v.to_bits()),
+        (Elem::Prim(Prim::F64), Value::F64(v)) => s.u64(v.to_bits()),
+        (Elem::String(_), _) => return Err(mismatch("expected a string")),
+        (Elem::Prim(p), _) => return Err(mismatch(format!("expected {:?}", p))),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+    use crate::edgefirst_msgs::{Detect, DetectBoxView};
+    use crate::schema_registry::list_schemas;
+    use crate::sensor_msgs::{Image, NavSatFix, NavSatStatus};
+
+    #[test]
+    fn every_schema_roundtrips_defaults() {
+        for schema in list_schemas() {
+            let cdr = encode(schema, &Value::Struct(Vec::new()))
+                .unwrap_or_else(|e| panic!("{}: {}", schema, e));
+            let value = decode(schema, &cdr).unwrap();
+            assert_eq!(encode(schema, &value).unwrap(), cdr, "{}", schema);
+        }
+    }
+
+    #[test]
+    fn image_matches_typed_encoding() {
+        let img = Image::builder()
+            .stamp(Time::new(5, 6))
+            .frame_id("cam")
+            .height(1)
+            .width(3)
+            .encoding("mono8")
+            .step(3)
+            .data(&[1, 2, 3])
+            .build()
+            .unwrap();
+        let value = decode("sensor_msgs/msg/Image", img.as_cdr()).unwrap();
+        let header = value.get("header").unwrap();
+        assert_eq!(header.get("frame_id"), Some(&Value::String("cam".into())));
+        assert_eq!(
+            header.get("stamp").and_then(|t| t.get("sec")),
+            Some(&Value::I32(5))
+        );
+        assert_eq!(value.get("data"), Some(&Value::Bytes(vec![1, 2, 3])));
+        let back = encode("sensor_msgs/msg/Image", &value).unwrap();
+        assert_eq!(back, img.as_cdr());
+    }
+
+    #[test]
+    fn nested_sequences_and_alignment_match_typed_encoding() {
+        let boxes = [DetectBoxView {
+            center_x: 0.5,
+            center_y: 0.25,
+            width: 0.1,
+            height: 0.2,
+            label: "person",
+            score: 0.9,
+            distance: 3.0,
+            speed: 0.0,
+            track_id: "t1",
+            track_lifetime: 4,
+            track_created: Time::new(1, 0),
+        }];
+        let det = Detect::builder()
+            .frame_id("cam")
+            .boxes(&boxes)
+            .build()
+            .unwrap();
+        let value = decode("edgefirst_msgs/msg/Detect", det.as_cdr()).unwrap();
+        let Some(Value::Array(items)) = value.get("boxes") else {
+            panic!("boxes is not an array");
+        };
+        assert_eq!(items[0].get("width"), Some(&Value::F32(0.1)));
+        let back = encode("edgefirst_msgs/msg/Detect", &value).unwrap();
+        assert_eq!(back, det.as_cdr());
+
+        let fix = NavSatFix::builder()
+            .status(NavSatStatus {
+                status: -1,
+                service: 3,
+            })
+            .latitude(45.0)
+            .position_covariance([1.0; 9])
+            .build()
+            .unwrap();
+        let value = decode("sensor_msgs/msg/NavSatFix", fix.as_cdr()).unwrap();
+        assert_eq!(
+            value.get("status").and_then(|s| s.get("status")),
+            Some(&Value::I8(-1))
+        );
+        let back = encode("sensor_msgs/msg/NavSatFix", &value).unwrap();
+        assert_eq!(back, fix.as_cdr());
+    }
+
+    #[test]
+    fn errors_name_the_offending_field() {
+        let schema = "edgefirst_msgs/msg/Detect";
+        let value = Value::Struct(vec![(
+            "boxes".into(),
+            Value::Array(vec![
+                Value::Struct(Vec::new()),
+                Value::Struct(vec![("score".into(), Value::F64(0.5))]),
+            ]),
+        )]);
+        let err = encode(schema, &value).unwrap_err();
+        assert_eq!(err.to_string(), "field `boxes[1].score`: expected F32");
+
+        let value = Value::Struct(vec![("frame".into(), Value::Bool(true))]);
+        let err = encode(schema, &value).unwrap_err();
+        assert_eq!(err.to_string(), "unknown field `frame`");
+
+        let cdr = encode(schema, &Value::Struct(Vec::new())).unwrap();
+        let err = decode(schema, &cdr[..cdr.len() - 1]).unwrap_err();
+        assert!(matches!(err, DynamicError::Cdr(_)));
+
+        assert!(matches!(
+            decode("unknown_msgs/msg/Foo", &cdr),
+            Err(DynamicError::UnknownSchema(_))
+        ));
+    }
+}
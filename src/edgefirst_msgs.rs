@@ -3,15 +3,32 @@
 
 //! EdgeFirst custom message types for perception pipelines.
 //!
-//! CdrFixed: `Date`
+//! CdrFixed: `Date`, `RadarCubeDimension` (typed enum)
 //!
 //! Buffer-backed: `Mask` (`MaskView`), `DmaBuffer`, `LocalTime`,
 //! `RadarCube`, `RadarInfo`, `Track`, `DetectBox` (`DetectBoxView`),
-//! `Detect`, `Model`, `ModelInfo`
+//! `Detect`, `Model`, `ModelInfo`, `Aggregate` (`AggregateCountView`,
+//! `AggregateZoneView`), `Attribute` (`AttributeEntryView`),
+//! `VideoStreamRequest`, `VideoStreamInfo` (`VideoStreamVariantView`),
+//! `CameraControl`, `CameraStatus`, `Provenance` (`ProvenanceInputView`),
+//! `ShmRing`, `Heartbeat`, `BlobRef`, `ImageWithInfo`,
+//! `FusionDebug` (`FusionAssociationView`), `ServiceResult`
+//!
+//! Services: `SetRadarProfileRequest` / `SetRadarProfileResponse`
+//!
+//! Analytics: [`Aggregator`] folds a Detect stream into `Aggregate` windows.
+//!
+//! Supervision: [`HeartbeatMonitor`] turns `Heartbeat` streams into
+//! [`HeartbeatEvent`]s.
 
 use crate::builtin_interfaces::{Duration, Time};
 use crate::cdr::*;
+use crate::sensor_msgs::{
+    read_f64_array12, read_f64_array9, size_f64_array12, size_f64_array9, write_f64_array12,
+    write_f64_array9, CameraInfo, Image, RegionOfInterest,
+};
 use crate::std_msgs::Header;
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
 
 // ── CdrFixed types ──────────────────────────────────────────────────
 
@@ -55,6 +72,19 @@ pub mod radar_cube_dimension {
     pub const SEQUENCE: u8 = 6;
 }
 
+crate::cdr_enum! {
+    /// Typed form of the [`radar_cube_dimension`] constants.
+    pub enum RadarCubeDimension: u8 {
+        Undefined = 0,
+        Range = 1,
+        Doppler = 2,
+        Azimuth = 3,
+        Elevation = 4,
+        RxChannel = 5,
+        Sequence = 6,
+    }
+}
+
 pub mod model_info {
     pub const RAW: u8 = 0;
     pub const INT8: u8 = 1;
@@ -100,14 +130,15 @@ impl<B> Mask<B> {
 impl<B: AsRef<[u8]>> Mask<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
         let mut c = CdrCursor::new(buf.as_ref())?;
-        let _ = c.read_u32()?; // height
-        let _ = c.read_u32()?; // width
-        let _ = c.read_u32()?; // length
-        let _ = c.read_string()?; // encoding
+        let _ = c.read_u32().field("height")?;
+        let _ = c.read_u32().field("width")?;
+        let _ = c.read_u32().field("length")?;
+        let _ = c.read_string().field("encoding")?;
         let o0 = c.offset();
-        let _ = c.read_bytes()?; // mask
+        let _ = c.read_bytes().field("mask")?;
         let o1 = c.offset();
-        let _ = c.read_bool()?; // boxed
+        let _ = c.read_bool().field("boxed")?;
+        c.expect_end()?;
         Ok(Mask {
             offsets: [o0, o1],
             buf,
@@ -219,7 +250,7 @@ pub struct MaskBuilder<'a> {
     height: u32,
     width: u32,
     length: u32,
-    encoding: std::borrow::Cow<'a, str>,
+    encoding: alloc::borrow::Cow<'a, str>,
     mask: &'a [u8],
     boxed: bool,
 }
@@ -230,7 +261,7 @@ impl<'a> Default for MaskBuilder<'a> {
             height: 0,
             width: 0,
             length: 0,
-            encoding: std::borrow::Cow::Borrowed(""),
+            encoding: alloc::borrow::Cow::Borrowed(""),
             mask: &[],
             boxed: false,
         }
@@ -254,7 +285,7 @@ impl<'a> MaskBuilder<'a> {
         self.length = v;
         self
     }
-    pub fn encoding(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn encoding(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.encoding = s.into();
         self
     }
@@ -267,7 +298,8 @@ impl<'a> MaskBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         s.size_u32(); // height
         s.size_u32(); // width
@@ -278,8 +310,8 @@ impl<'a> MaskBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         w.write_u32(self.height);
         w.write_u32(self.width);
         w.write_u32(self.length);
@@ -290,29 +322,30 @@ impl<'a> MaskBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<Mask<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Mask::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
 
+#[cfg(feature = "ffi")]
 impl Mask<&'static [u8]> {
     /// Parse a standalone Mask CDR buffer and return a `'static`-lifetimed view.
     ///
@@ -431,18 +464,19 @@ impl<B> DmaBuffer<B> {
 #[allow(deprecated)]
 impl<B: AsRef<[u8]>> DmaBuffer<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         for _ in 0..7 {
             c.read_u32()?;
         }
+        c.expect_end()?;
         Ok(DmaBuffer { offsets: [o0], buf })
     }
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
 
     #[inline]
@@ -572,18 +606,19 @@ impl<B> LocalTime<B> {
 
 impl<B: AsRef<[u8]>> LocalTime<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         Date::read_cdr(&mut c)?;
         Time::read_cdr(&mut c)?;
-        c.read_i16()?; // timezone
+        c.read_i16().field("timezone")?;
+        c.expect_end()?;
         Ok(LocalTime { offsets: [o0], buf })
     }
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
 
     #[inline]
@@ -672,7 +707,7 @@ impl LocalTime<Vec<u8>> {
 /// Builder for `LocalTime<Vec<u8>>` with buffer-reuse finalizers.
 pub struct LocalTimeBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     date: Date,
     time: Time,
     timezone: i16,
@@ -682,7 +717,7 @@ impl<'a> Default for LocalTimeBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             date: Date {
                 year: 0,
                 month: 0,
@@ -703,7 +738,7 @@ impl<'a> LocalTimeBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -720,7 +755,8 @@ impl<'a> LocalTimeBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -730,8 +766,8 @@ impl<'a> LocalTimeBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         self.date.write_cdr(&mut w);
@@ -741,25 +777,25 @@ impl<'a> LocalTimeBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<LocalTime<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         LocalTime::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -824,10 +860,10 @@ impl<B> RadarCube<B> {
 
 impl<B: AsRef<[u8]>> RadarCube<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        c.read_u64()?; // timestamp
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_u64().field("timestamp")?;
         let layout_count = c.read_u32()? as usize;
         c.skip(layout_count)?;
         let o1 = c.offset();
@@ -841,6 +877,7 @@ impl<B: AsRef<[u8]>> RadarCube<B> {
         c.skip_seq_2(cube_count)?;
         let o4 = c.offset();
         c.read_bool()?;
+        c.expect_end()?;
         Ok(RadarCube {
             offsets: [o0, o1, o2, o3, o4],
             buf,
@@ -851,7 +888,7 @@ impl<B: AsRef<[u8]>> RadarCube<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -874,6 +911,14 @@ impl<B: AsRef<[u8]>> RadarCube<B> {
         &b[p + 4..p + 4 + count]
     }
 
+    /// `layout()` as typed labels; unknown labels yield
+    /// `CdrError::UnknownDiscriminant`.
+    pub fn dimensions(&self) -> impl Iterator<Item = Result<RadarCubeDimension, CdrError>> + '_ {
+        self.layout()
+            .iter()
+            .map(|&v| RadarCubeDimension::try_from(v))
+    }
+
     pub fn shape(&self) -> &[u16] {
         let b = self.buf.as_ref();
         let p = align(self.offsets[1], 4);
@@ -920,6 +965,28 @@ impl<B: AsRef<[u8]>> RadarCube<B> {
     }
 }
 
+/// Zero-copy `RadarCube` borrowing its CDR buffer, e.g. straight from a
+/// Zenoh sample.
+pub type RadarCubeRef<'a> = RadarCube<&'a [u8]>;
+
+impl<'a> RadarCube<&'a [u8]> {
+    /// Consume the view and return the cube borrowed for the full lifetime
+    /// of the input buffer, so it can outlive the view.
+    pub fn into_cube(self) -> &'a [i16] {
+        let p = align(self.offsets[3], 4);
+        let count = rd_u32(self.buf, p) as usize;
+        rd_slice_i16(self.buf, align(p + 4, 2), count)
+    }
+
+    /// Raw little-endian bytes of the cube, borrowed for the full lifetime of
+    /// the input buffer.
+    pub fn into_cube_raw(self) -> &'a [u8] {
+        let p = align(self.offsets[3], 4);
+        let count = rd_u32(self.buf, p) as usize;
+        &self.buf[p + 4..p + 4 + count * 2]
+    }
+}
+
 impl RadarCube<Vec<u8>> {
     #[deprecated(
         since = "3.2.0",
@@ -994,7 +1061,7 @@ impl RadarCube<Vec<u8>> {
 /// `build()`, `encode_into_vec()`, or `encode_into_slice()` is called.
 pub struct RadarCubeBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     timestamp: u64,
     layout: &'a [u8],
     shape: &'a [u16],
@@ -1007,7 +1074,7 @@ impl<'a> Default for RadarCubeBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             timestamp: 0,
             layout: &[],
             shape: &[],
@@ -1027,7 +1094,7 @@ impl<'a> RadarCubeBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -1056,7 +1123,8 @@ impl<'a> RadarCubeBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -1072,8 +1140,8 @@ impl<'a> RadarCubeBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_u64(self.timestamp);
@@ -1089,25 +1157,25 @@ impl<'a> RadarCubeBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<RadarCube<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         RadarCube::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -1154,9 +1222,9 @@ impl<B> RadarInfo<B> {
 
 impl<B: AsRef<[u8]>> RadarInfo<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         let _ = c.read_string()?;
         let o1 = c.offset();
         let _ = c.read_string()?;
@@ -1166,6 +1234,7 @@ impl<B: AsRef<[u8]>> RadarInfo<B> {
         let _ = c.read_string()?;
         let o4 = c.offset();
         c.read_bool()?;
+        c.expect_end()?;
         Ok(RadarInfo {
             offsets: [o0, o1, o2, o3, o4],
             buf,
@@ -1176,7 +1245,7 @@ impl<B: AsRef<[u8]>> RadarInfo<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -1276,11 +1345,11 @@ impl RadarInfo<Vec<u8>> {
 /// Builder for `RadarInfo<Vec<u8>>` with buffer-reuse finalizers.
 pub struct RadarInfoBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
-    center_frequency: std::borrow::Cow<'a, str>,
-    frequency_sweep: std::borrow::Cow<'a, str>,
-    range_toggle: std::borrow::Cow<'a, str>,
-    detection_sensitivity: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    center_frequency: alloc::borrow::Cow<'a, str>,
+    frequency_sweep: alloc::borrow::Cow<'a, str>,
+    range_toggle: alloc::borrow::Cow<'a, str>,
+    detection_sensitivity: alloc::borrow::Cow<'a, str>,
     cube: bool,
 }
 
@@ -1288,11 +1357,11 @@ impl<'a> Default for RadarInfoBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
-            center_frequency: std::borrow::Cow::Borrowed(""),
-            frequency_sweep: std::borrow::Cow::Borrowed(""),
-            range_toggle: std::borrow::Cow::Borrowed(""),
-            detection_sensitivity: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            center_frequency: alloc::borrow::Cow::Borrowed(""),
+            frequency_sweep: alloc::borrow::Cow::Borrowed(""),
+            range_toggle: alloc::borrow::Cow::Borrowed(""),
+            detection_sensitivity: alloc::borrow::Cow::Borrowed(""),
             cube: false,
         }
     }
@@ -1307,23 +1376,26 @@ impl<'a> RadarInfoBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
-    pub fn center_frequency(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn center_frequency(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.center_frequency = s.into();
         self
     }
-    pub fn frequency_sweep(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frequency_sweep(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frequency_sweep = s.into();
         self
     }
-    pub fn range_toggle(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn range_toggle(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.range_toggle = s.into();
         self
     }
-    pub fn detection_sensitivity(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn detection_sensitivity(
+        &mut self,
+        s: impl Into<alloc::borrow::Cow<'a, str>>,
+    ) -> &mut Self {
         self.detection_sensitivity = s.into();
         self
     }
@@ -1332,7 +1404,8 @@ impl<'a> RadarInfoBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -1344,8 +1417,8 @@ impl<'a> RadarInfoBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_string(&self.center_frequency);
@@ -1357,25 +1430,25 @@ impl<'a> RadarInfoBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<RadarInfo<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         RadarInfo::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -1419,6 +1492,7 @@ impl<B: AsRef<[u8]>> Track<B> {
         let o0 = c.offset();
         c.read_i32()?;
         Time::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(Track { offsets: [o0], buf })
     }
 
@@ -1487,7 +1561,7 @@ impl Track<Vec<u8>> {
 /// `Track` has no `Header` — it is a standalone sequence element-style
 /// message whose CDR payload is just `(id, lifetime, created)`.
 pub struct TrackBuilder<'a> {
-    id: std::borrow::Cow<'a, str>,
+    id: alloc::borrow::Cow<'a, str>,
     lifetime: i32,
     created: Time,
 }
@@ -1495,7 +1569,7 @@ pub struct TrackBuilder<'a> {
 impl<'a> Default for TrackBuilder<'a> {
     fn default() -> Self {
         Self {
-            id: std::borrow::Cow::Borrowed(""),
+            id: alloc::borrow::Cow::Borrowed(""),
             lifetime: 0,
             created: Time { sec: 0, nanosec: 0 },
         }
@@ -1507,7 +1581,7 @@ impl<'a> TrackBuilder<'a> {
         Self::default()
     }
 
-    pub fn id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.id = s.into();
         self
     }
@@ -1520,7 +1594,8 @@ impl<'a> TrackBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         s.size_string(&self.id);
         s.size_i32();
@@ -1528,8 +1603,8 @@ impl<'a> TrackBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         w.write_string(&self.id);
         w.write_i32(self.lifetime);
         self.created.write_cdr(&mut w);
@@ -1537,25 +1612,25 @@ impl<'a> TrackBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<Track<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Track::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -1667,6 +1742,7 @@ pub(crate) fn size_box_element(s: &mut CdrSizer, label: &str, track_id: &str) {
     Time::size_cdr(s);
 }
 
+#[cfg(feature = "ffi")]
 impl DetectBox<&'static [u8]> {
     /// Parse a standalone DetectBox CDR buffer and return a `'static`-lifetimed
     /// view.
@@ -1702,6 +1778,7 @@ impl<B: AsRef<[u8]>> DetectBox<B> {
         let o1 = c.offset();
         c.read_i32()?;
         Time::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(DetectBox {
             offsets: [o0, o1],
             buf,
@@ -1836,11 +1913,11 @@ pub struct DetectBoxBuilder<'a> {
     center_y: f32,
     width: f32,
     height: f32,
-    label: std::borrow::Cow<'a, str>,
+    label: alloc::borrow::Cow<'a, str>,
     score: f32,
     distance: f32,
     speed: f32,
-    track_id: std::borrow::Cow<'a, str>,
+    track_id: alloc::borrow::Cow<'a, str>,
     track_lifetime: i32,
     track_created: Time,
 }
@@ -1852,11 +1929,11 @@ impl<'a> Default for DetectBoxBuilder<'a> {
             center_y: 0.0,
             width: 0.0,
             height: 0.0,
-            label: std::borrow::Cow::Borrowed(""),
+            label: alloc::borrow::Cow::Borrowed(""),
             score: 0.0,
             distance: 0.0,
             speed: 0.0,
-            track_id: std::borrow::Cow::Borrowed(""),
+            track_id: alloc::borrow::Cow::Borrowed(""),
             track_lifetime: 0,
             track_created: Time { sec: 0, nanosec: 0 },
         }
@@ -1884,7 +1961,7 @@ impl<'a> DetectBoxBuilder<'a> {
         self.height = v;
         self
     }
-    pub fn label(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn label(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.label = s.into();
         self
     }
@@ -1900,7 +1977,7 @@ impl<'a> DetectBoxBuilder<'a> {
         self.speed = v;
         self
     }
-    pub fn track_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn track_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.track_id = s.into();
         self
     }
@@ -1913,14 +1990,15 @@ impl<'a> DetectBoxBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         size_box_element(&mut s, &self.label, &self.track_id);
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         let view = DetectBoxView {
             center_x: self.center_x,
             center_y: self.center_y,
@@ -1939,25 +2017,25 @@ impl<'a> DetectBoxBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<DetectBox<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         DetectBox::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -2027,18 +2105,19 @@ impl<B> Detect<B> {
 
 impl<B: AsRef<[u8]>> Detect<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        Time::read_cdr(&mut c)?; // input_timestamp
-        Time::read_cdr(&mut c)?; // model_time
-        Time::read_cdr(&mut c)?; // output_time
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        Time::read_cdr(&mut c).field("input_timestamp")?;
+        Time::read_cdr(&mut c).field("model_time")?;
+        Time::read_cdr(&mut c).field("output_time")?;
         let raw_count = c.read_u32()?;
         let count = c.check_seq_count(raw_count, 24)?;
         for _ in 0..count {
             scan_box_element(&mut c)?;
         }
         let o1 = c.offset();
+        c.expect_end()?;
         Ok(Detect {
             offsets: [o0, o1],
             buf,
@@ -2049,7 +2128,7 @@ impl<B: AsRef<[u8]>> Detect<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -2096,6 +2175,7 @@ impl<B: AsRef<[u8]>> Detect<B> {
     }
 }
 
+#[cfg(any(feature = "ffi", test))]
 impl Detect<&'static [u8]> {
     /// Parse a Detect message and simultaneously collect the box views
     /// encountered during validation, avoiding a second parse pass in the
@@ -2110,12 +2190,12 @@ impl Detect<&'static [u8]> {
     pub(crate) fn from_cdr_collect_boxes(
         buf: &'static [u8],
     ) -> Result<(Self, Vec<DetectBoxView<'static>>), CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf)?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf)?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf, o0);
-        Time::read_cdr(&mut c)?; // input_timestamp
-        Time::read_cdr(&mut c)?; // model_time
-        Time::read_cdr(&mut c)?; // output_time
+        let mut c = CdrCursor::resume_checked(buf, o0);
+        Time::read_cdr(&mut c).field("input_timestamp")?;
+        Time::read_cdr(&mut c).field("model_time")?;
+        Time::read_cdr(&mut c).field("output_time")?;
         let raw_count = c.read_u32()?;
         let count = c.check_seq_count(raw_count, 24)?;
         let mut box_views = Vec::with_capacity(count);
@@ -2198,7 +2278,7 @@ impl Detect<Vec<u8>> {
 /// `encode_into_vec()`, or `encode_into_slice()` is called.
 pub struct DetectBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     input_timestamp: Time,
     model_time: Time,
     output_time: Time,
@@ -2209,7 +2289,7 @@ impl<'a> Default for DetectBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             input_timestamp: Time { sec: 0, nanosec: 0 },
             model_time: Time { sec: 0, nanosec: 0 },
             output_time: Time { sec: 0, nanosec: 0 },
@@ -2227,7 +2307,7 @@ impl<'a> DetectBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -2248,7 +2328,8 @@ impl<'a> DetectBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -2262,8 +2343,8 @@ impl<'a> DetectBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         self.input_timestamp.write_cdr(&mut w);
@@ -2277,25 +2358,25 @@ impl<'a> DetectBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<Detect<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Detect::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -2470,19 +2551,19 @@ impl<B> CameraFrame<B> {
 
 impl<B: AsRef<[u8]>> CameraFrame<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        c.read_u64()?; // seq
-        c.read_u32()?; // pid
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_u64().field("seq")?;
+        c.read_u32().field("pid")?;
         let width = c.read_u32()?;
         let height = c.read_u32()?;
-        c.read_string()?; // format
-        c.read_string()?; // color_space
-        c.read_string()?; // color_transfer
-        c.read_string()?; // color_encoding
-        c.read_string()?; // color_range
-        c.read_i32()?; // fence_fd
+        c.read_string().field("format")?;
+        c.read_string().field("color_space")?;
+        c.read_string().field("color_transfer")?;
+        c.read_string().field("color_encoding")?;
+        c.read_string().field("color_range")?;
+        c.read_i32().field("fence_fd")?;
         let planes_pos = c.offset();
         let raw_count = c.read_u32()?;
         // min plane size: 5×u32 + 4-byte data seq count = 24 bytes
@@ -2496,6 +2577,7 @@ impl<B: AsRef<[u8]>> CameraFrame<B> {
             return Err(CdrError::InvalidHeader);
         }
 
+        c.expect_end()?;
         Ok(CameraFrame {
             offsets: [o0, planes_pos],
             buf,
@@ -2506,7 +2588,7 @@ impl<B: AsRef<[u8]>> CameraFrame<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -2606,15 +2688,16 @@ impl<B: AsRef<[u8]>> CameraFrame<B> {
     }
 }
 
+#[cfg(feature = "ffi")]
 impl CameraFrame<&'static [u8]> {
     /// Parse and simultaneously collect plane views for the FFI layer,
     /// avoiding a second walk after `from_cdr`. Mirrors `Detect::from_cdr_collect_boxes`.
     pub(crate) fn from_cdr_collect_planes(
         buf: &'static [u8],
     ) -> Result<(Self, Vec<CameraPlaneView<'static>>), CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf)?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf)?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf, o0);
+        let mut c = CdrCursor::resume_checked(buf, o0);
         c.read_u64()?;
         c.read_u32()?;
         let width = c.read_u32()?;
@@ -2755,16 +2838,16 @@ impl CameraFrame<Vec<u8>> {
 /// `encode_into_vec()`, or `encode_into_slice()` is called.
 pub struct CameraFrameBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     seq: u64,
     pid: u32,
     width: u32,
     height: u32,
-    format: std::borrow::Cow<'a, str>,
-    color_space: std::borrow::Cow<'a, str>,
-    color_transfer: std::borrow::Cow<'a, str>,
-    color_encoding: std::borrow::Cow<'a, str>,
-    color_range: std::borrow::Cow<'a, str>,
+    format: alloc::borrow::Cow<'a, str>,
+    color_space: alloc::borrow::Cow<'a, str>,
+    color_transfer: alloc::borrow::Cow<'a, str>,
+    color_encoding: alloc::borrow::Cow<'a, str>,
+    color_range: alloc::borrow::Cow<'a, str>,
     fence_fd: i32,
     planes: &'a [CameraPlaneView<'a>],
 }
@@ -2773,16 +2856,16 @@ impl<'a> Default for CameraFrameBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             seq: 0,
             pid: 0,
             width: 0,
             height: 0,
-            format: std::borrow::Cow::Borrowed(""),
-            color_space: std::borrow::Cow::Borrowed(""),
-            color_transfer: std::borrow::Cow::Borrowed(""),
-            color_encoding: std::borrow::Cow::Borrowed(""),
-            color_range: std::borrow::Cow::Borrowed(""),
+            format: alloc::borrow::Cow::Borrowed(""),
+            color_space: alloc::borrow::Cow::Borrowed(""),
+            color_transfer: alloc::borrow::Cow::Borrowed(""),
+            color_encoding: alloc::borrow::Cow::Borrowed(""),
+            color_range: alloc::borrow::Cow::Borrowed(""),
             fence_fd: -1,
             planes: &[],
         }
@@ -2798,7 +2881,7 @@ impl<'a> CameraFrameBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -2818,23 +2901,23 @@ impl<'a> CameraFrameBuilder<'a> {
         self.height = v;
         self
     }
-    pub fn format(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn format(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.format = s.into();
         self
     }
-    pub fn color_space(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn color_space(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.color_space = s.into();
         self
     }
-    pub fn color_transfer(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn color_transfer(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.color_transfer = s.into();
         self
     }
-    pub fn color_encoding(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn color_encoding(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.color_encoding = s.into();
         self
     }
-    pub fn color_range(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn color_range(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.color_range = s.into();
         self
     }
@@ -2857,7 +2940,8 @@ impl<'a> CameraFrameBuilder<'a> {
         Ok(())
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -2878,8 +2962,8 @@ impl<'a> CameraFrameBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_u64(self.seq);
@@ -2901,27 +2985,27 @@ impl<'a> CameraFrameBuilder<'a> {
 
     pub fn build(&self) -> Result<CameraFrame<Vec<u8>>, CdrError> {
         self.validate()?;
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         CameraFrame::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
         self.validate()?;
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
         self.validate()?;
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -2997,9 +3081,9 @@ impl<B> Model<B> {
 
 impl<B: AsRef<[u8]>> Model<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         Duration::read_cdr(&mut c)?;
         Duration::read_cdr(&mut c)?;
         Duration::read_cdr(&mut c)?;
@@ -3016,6 +3100,7 @@ impl<B: AsRef<[u8]>> Model<B> {
             scan_mask_element(&mut c)?;
         }
         let o2 = c.offset();
+        c.expect_end()?;
         Ok(Model {
             offsets: [o0, o1, o2],
             buf,
@@ -3026,7 +3111,7 @@ impl<B: AsRef<[u8]>> Model<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -3090,6 +3175,7 @@ impl<B: AsRef<[u8]>> Model<B> {
     }
 }
 
+#[cfg(any(feature = "ffi", test))]
 impl Model<&'static [u8]> {
     /// Parse a Model message and simultaneously collect the box and mask views
     /// encountered during validation, avoiding a second parse pass in the
@@ -3105,9 +3191,9 @@ impl Model<&'static [u8]> {
     pub(crate) fn from_cdr_collect_children(
         buf: &'static [u8],
     ) -> Result<(Self, Vec<DetectBoxView<'static>>, Vec<MaskView<'static>>), CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf)?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf)?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf, o0);
+        let mut c = CdrCursor::resume_checked(buf, o0);
         Duration::read_cdr(&mut c)?;
         Duration::read_cdr(&mut c)?;
         Duration::read_cdr(&mut c)?;
@@ -3216,7 +3302,7 @@ impl Model<Vec<u8>> {
 /// `encode_into_vec()`, or `encode_into_slice()` is called.
 pub struct ModelBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     input_time: Duration,
     model_time: Duration,
     output_time: Duration,
@@ -3229,7 +3315,7 @@ impl<'a> Default for ModelBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             input_time: Duration { sec: 0, nanosec: 0 },
             model_time: Duration { sec: 0, nanosec: 0 },
             output_time: Duration { sec: 0, nanosec: 0 },
@@ -3249,7 +3335,7 @@ impl<'a> ModelBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -3278,7 +3364,8 @@ impl<'a> ModelBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -3297,8 +3384,8 @@ impl<'a> ModelBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         self.input_time.write_cdr(&mut w);
@@ -3317,25 +3404,25 @@ impl<'a> ModelBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<Model<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Model::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -3403,17 +3490,17 @@ impl<B> ModelInfo<B> {
 
 impl<B: AsRef<[u8]>> ModelInfo<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         let is_count = c.read_u32()? as usize;
         c.skip_seq_4(is_count)?;
         let o1 = c.offset();
-        c.read_u8()?; // input_type
+        c.read_u8().field("input_type")?;
         let os_count = c.read_u32()? as usize;
         c.skip_seq_4(os_count)?;
         let o2 = c.offset();
-        c.read_u8()?; // output_type
+        c.read_u8().field("output_type")?;
         let raw_lab = c.read_u32()?;
         let lab_count = c.check_seq_count(raw_lab, 5)?;
         for _ in 0..lab_count {
@@ -3425,6 +3512,7 @@ impl<B: AsRef<[u8]>> ModelInfo<B> {
         let _ = c.read_string()?;
         let o5 = c.offset();
         let _ = c.read_string()?;
+        c.expect_end()?;
         Ok(ModelInfo {
             offsets: [o0, o1, o2, o3, o4, o5],
             buf,
@@ -3435,7 +3523,7 @@ impl<B: AsRef<[u8]>> ModelInfo<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -3590,30 +3678,30 @@ impl ModelInfo<Vec<u8>> {
 /// owned string slices flow through without copy.
 pub struct ModelInfoBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     input_shape: &'a [u32],
     input_type: u8,
     output_shape: &'a [u32],
     output_type: u8,
     labels: &'a [&'a str],
-    model_type: std::borrow::Cow<'a, str>,
-    model_format: std::borrow::Cow<'a, str>,
-    model_name: std::borrow::Cow<'a, str>,
+    model_type: alloc::borrow::Cow<'a, str>,
+    model_format: alloc::borrow::Cow<'a, str>,
+    model_name: alloc::borrow::Cow<'a, str>,
 }
 
 impl<'a> Default for ModelInfoBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             input_shape: &[],
             input_type: 0,
             output_shape: &[],
             output_type: 0,
             labels: &[],
-            model_type: std::borrow::Cow::Borrowed(""),
-            model_format: std::borrow::Cow::Borrowed(""),
-            model_name: std::borrow::Cow::Borrowed(""),
+            model_type: alloc::borrow::Cow::Borrowed(""),
+            model_format: alloc::borrow::Cow::Borrowed(""),
+            model_name: alloc::borrow::Cow::Borrowed(""),
         }
     }
 }
@@ -3627,7 +3715,7 @@ impl<'a> ModelInfoBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -3651,20 +3739,21 @@ impl<'a> ModelInfoBuilder<'a> {
         self.labels = v;
         self
     }
-    pub fn model_type(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn model_type(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.model_type = s.into();
         self
     }
-    pub fn model_format(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn model_format(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.model_format = s.into();
         self
     }
-    pub fn model_name(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn model_name(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.model_name = s.into();
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -3684,8 +3773,8 @@ impl<'a> ModelInfoBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_u32(self.input_shape.len() as u32);
@@ -3705,25 +3794,25 @@ impl<'a> ModelInfoBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<ModelInfo<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         ModelInfo::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -3815,16 +3904,16 @@ impl<B> Vibration<B> {
 impl<B: AsRef<[u8]>> Vibration<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
         use crate::geometry_msgs::Vector3;
-        let header = crate::std_msgs::Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = crate::std_msgs::Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(8);
         let o0 = c.offset();
         Vector3::read_cdr(&mut c)?;
-        c.read_f32()?; // band_lower_hz
-        c.read_f32()?; // band_upper_hz
-        c.read_u8()?; // measurement_type
-        c.read_u8()?; // unit
+        c.read_f32().field("band_lower_hz")?;
+        c.read_f32().field("band_upper_hz")?;
+        c.read_u8().field("measurement_type")?;
+        c.read_u8().field("unit")?;
         c.align(4);
         // u32 = 4 bytes each; hardening check against pathological counts.
         let raw = c.read_u32()?;
@@ -3832,12 +3921,13 @@ impl<B: AsRef<[u8]>> Vibration<B> {
         for _ in 0..n {
             c.read_u32()?;
         }
+        c.expect_end()?;
         Ok(Vibration { offsets: [o0], buf })
     }
 
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     pub fn header(&self) -> crate::std_msgs::Header<&[u8]> {
-        crate::std_msgs::Header::from_cdr(self.buf.as_ref())
+        crate::std_msgs::Header::from_cdr_prefix(self.buf.as_ref())
             .expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> crate::builtin_interfaces::Time {
@@ -3964,7 +4054,7 @@ impl Vibration<Vec<u8>> {
 /// `encode_into_vec()`, or `encode_into_slice()` is called.
 pub struct VibrationBuilder<'a> {
     stamp: crate::builtin_interfaces::Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     measurement_type: u8,
     unit: u8,
     band_lower_hz: f32,
@@ -3977,7 +4067,7 @@ impl<'a> Default for VibrationBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: crate::builtin_interfaces::Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             measurement_type: 0,
             unit: 0,
             band_lower_hz: 0.0,
@@ -4001,7 +4091,7 @@ impl<'a> VibrationBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -4030,7 +4120,8 @@ impl<'a> VibrationBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         use crate::builtin_interfaces::Time;
         use crate::geometry_msgs::Vector3;
         let mut s = CdrSizer::new();
@@ -4050,8 +4141,8 @@ impl<'a> VibrationBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         self.vibration.write_cdr(&mut w);
@@ -4067,25 +4158,25 @@ impl<'a> VibrationBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<Vibration<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Vibration::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -4122,189 +4213,4720 @@ impl<B: AsRef<[u8]> + AsMut<[u8]>> Vibration<B> {
     }
 }
 
-// ── Registry ────────────────────────────────────────────────────────
+// ── Aggregate<B> — edgefirst_msgs/msg/Aggregate ─────────────────────
+//
+// CDR layout: Header → offsets[0],
+//   window_start(Time) + window_end(Time),
+//   counts(seq<AggregateCount>) → offsets[1],
+//   zones(seq<AggregateZone>)
+//
+// AggregateCount element: label(string) + count(u32)
+// AggregateZone element:  zone(string) + occupancy(u32) + visits(u32)
+//                         + avg_dwell(f32)
 
-/// Check if a type name is supported by this module.
-pub fn is_type_supported(type_name: &str) -> bool {
-    matches!(
-        type_name,
-        "Box"
-            | "CameraFrame"
-            | "CameraPlane"
-            | "Date"
-            | "Detect"
-            | "DmaBuffer"
-            | "LocalTime"
-            | "Mask"
-            | "Model"
-            | "ModelInfo"
-            | "RadarCube"
-            | "RadarInfo"
-            | "Track"
-            | "Vibration"
-    )
+/// Zero-copy view of a single AggregateCount element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AggregateCountView<'a> {
+    pub label: &'a str,
+    pub count: u32,
 }
 
-/// List all type schema names in this module.
-pub fn list_types() -> &'static [&'static str] {
-    &[
-        "edgefirst_msgs/msg/Box",
-        "edgefirst_msgs/msg/CameraFrame",
-        "edgefirst_msgs/msg/CameraPlane",
-        "edgefirst_msgs/msg/Date",
-        "edgefirst_msgs/msg/Detect",
-        "edgefirst_msgs/msg/DmaBuffer",
-        "edgefirst_msgs/msg/LocalTime",
-        "edgefirst_msgs/msg/Mask",
-        "edgefirst_msgs/msg/Model",
-        "edgefirst_msgs/msg/ModelInfo",
-        "edgefirst_msgs/msg/RadarCube",
-        "edgefirst_msgs/msg/RadarInfo",
-        "edgefirst_msgs/msg/Track",
-        "edgefirst_msgs/msg/Vibration",
-    ]
+/// Zero-copy view of a single AggregateZone element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AggregateZoneView<'a> {
+    pub zone: &'a str,
+    pub occupancy: u32,
+    pub visits: u32,
+    pub avg_dwell: f32,
 }
 
-// SchemaType implementations
-use crate::schema_registry::SchemaType;
+fn scan_count_element<'a>(c: &mut CdrCursor<'a>) -> Result<AggregateCountView<'a>, CdrError> {
+    let label = c.read_string()?;
+    let count = c.read_u32()?;
+    Ok(AggregateCountView { label, count })
+}
 
-impl SchemaType for Date {
-    const SCHEMA_NAME: &'static str = "edgefirst_msgs/msg/Date";
+fn scan_zone_element<'a>(c: &mut CdrCursor<'a>) -> Result<AggregateZoneView<'a>, CdrError> {
+    let zone = c.read_string()?;
+    let occupancy = c.read_u32()?;
+    let visits = c.read_u32()?;
+    let avg_dwell = c.read_f32()?;
+    Ok(AggregateZoneView {
+        zone,
+        occupancy,
+        visits,
+        avg_dwell,
+    })
 }
 
-#[cfg(test)]
-#[allow(deprecated)] // Tests exercise CameraFrame::new, which is deprecated in 3.2.0 but still supported until 4.0.
-mod tests {
-    use super::*;
-    use crate::builtin_interfaces::Time;
-    use crate::cdr::{decode_fixed, encode_fixed};
+pub struct Aggregate<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
 
-    #[test]
-    fn date_roundtrip() {
-        let cases = [
-            (2025, 1, 27, "typical"),
-            (2000, 12, 31, "end of year"),
-            (1970, 1, 1, "unix epoch"),
-        ];
-        for (year, month, day, name) in cases {
-            let date = Date { year, month, day };
-            let bytes = encode_fixed(&date).unwrap();
-            let decoded: Date = decode_fixed(&bytes).unwrap();
-            assert_eq!(date, decoded, "failed for case: {}", name);
+impl<B> Aggregate<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> Aggregate<C> {
+        Aggregate {
+            buf: f(self.buf),
+            offsets: self.offsets,
         }
     }
+}
 
-    #[test]
-    fn mask_roundtrip() {
-        let mask = Mask::new(480, 640, 0, "", &vec![0u8; 480 * 640], false).unwrap();
-        assert_eq!(mask.height(), 480);
-        assert_eq!(mask.width(), 640);
-        assert_eq!(mask.length(), 0);
-        assert_eq!(mask.encoding(), "");
-        assert_eq!(mask.mask_data().len(), 480 * 640);
-        assert!(!mask.boxed());
+impl<B: AsRef<[u8]>> Aggregate<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        Time::read_cdr(&mut c).field("window_start")?;
+        Time::read_cdr(&mut c).field("window_end")?;
+        let raw = c.read_u32()?;
+        let count = c.check_seq_count(raw, 9)?;
+        for _ in 0..count {
+            scan_count_element(&mut c)?;
+        }
+        let o1 = c.offset();
+        let raw = c.read_u32()?;
+        let count = c.check_seq_count(raw, 17)?;
+        for _ in 0..count {
+            scan_zone_element(&mut c)?;
+        }
+        c.expect_end()?;
+        Ok(Aggregate {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
 
-        let bytes = mask.to_cdr();
-        let decoded = Mask::from_cdr(bytes).unwrap();
-        assert_eq!(decoded.height(), 480);
-        assert_eq!(decoded.width(), 640);
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    #[inline]
+    pub fn window_start(&self) -> Time {
+        rd_time(self.buf.as_ref(), align(self.offsets[0], 4))
+    }
+    #[inline]
+    pub fn window_end(&self) -> Time {
+        rd_time(self.buf.as_ref(), align(self.offsets[0], 4) + 8)
+    }
 
-        // Compressed mask
-        let compressed = Mask::new(1080, 1920, 5, "zstd", &[1, 2, 3, 4, 5], true).unwrap();
-        assert_eq!(compressed.encoding(), "zstd");
-        assert_eq!(compressed.mask_data(), &[1, 2, 3, 4, 5]);
-        assert!(compressed.boxed());
+    pub fn counts_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[0], 4) + 16)
+    }
 
-        let bytes = compressed.to_cdr();
-        let decoded = Mask::from_cdr(bytes).unwrap();
-        assert_eq!(decoded.encoding(), "zstd");
-        assert!(decoded.boxed());
+    pub fn counts(&self) -> Vec<AggregateCountView<'_>> {
+        let b = self.buf.as_ref();
+        let p = align(self.offsets[0], 4) + 16;
+        let count = rd_u32(b, p) as usize;
+        let mut c = CdrCursor::resume(b, p + 4);
+        (0..count)
+            .map(|_| scan_count_element(&mut c).expect("count elements validated during from_cdr"))
+            .collect()
     }
 
-    #[test]
-    #[allow(deprecated)]
-    fn dmabuf_roundtrip() {
-        let dmabuf = DmaBuffer::new(
-            Time::new(100, 0),
-            "camera",
-            12345,
-            42,
-            1920,
-            1080,
-            1920 * 3,
-            0x34325247,
-            1920 * 1080 * 3,
-        )
-        .unwrap();
-        assert_eq!(dmabuf.stamp(), Time::new(100, 0));
-        assert_eq!(dmabuf.frame_id(), "camera");
-        assert_eq!(dmabuf.pid(), 12345);
-        assert_eq!(dmabuf.fd(), 42);
-        assert_eq!(dmabuf.width(), 1920);
-        assert_eq!(dmabuf.height(), 1080);
-        assert_eq!(dmabuf.stride(), 1920 * 3);
-        assert_eq!(dmabuf.fourcc(), 0x34325247);
-        assert_eq!(dmabuf.length(), 1920 * 1080 * 3);
+    pub fn zones_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[1], 4))
+    }
 
-        let bytes = dmabuf.to_cdr();
-        let decoded = DmaBuffer::from_cdr(bytes).unwrap();
-        assert_eq!(decoded.pid(), 12345);
-        assert_eq!(decoded.fd(), 42);
+    pub fn zones(&self) -> Vec<AggregateZoneView<'_>> {
+        let b = self.buf.as_ref();
+        let p = align(self.offsets[1], 4);
+        let count = rd_u32(b, p) as usize;
+        let mut c = CdrCursor::resume(b, p + 4);
+        (0..count)
+            .map(|_| scan_zone_element(&mut c).expect("zone elements validated during from_cdr"))
+            .collect()
     }
 
-    #[test]
-    fn camera_frame_roundtrip_empty() {
-        let cf = CameraFrame::new(
-            Time::new(1, 0),
-            "cam0",
-            42,
-            1234,
-            1920,
-            1080,
-            "NV12",
-            "bt709",
-            "bt709",
-            "bt709",
-            "limited",
-            -1,
-            &[],
-        )
-        .unwrap();
-        assert_eq!(cf.seq(), 42);
-        assert_eq!(cf.pid(), 1234);
-        assert_eq!(cf.width(), 1920);
-        assert_eq!(cf.height(), 1080);
-        assert_eq!(cf.format(), "NV12");
-        assert_eq!(cf.color_space(), "bt709");
-        assert_eq!(cf.color_range(), "limited");
-        assert_eq!(cf.fence_fd(), -1);
-        assert_eq!(cf.num_planes(), 0);
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
 
-        let bytes = cf.to_cdr();
-        let decoded = CameraFrame::<&[u8]>::from_cdr(&bytes[..]).unwrap();
-        assert_eq!(decoded.seq(), 42);
-        assert_eq!(decoded.format(), "NV12");
-        assert_eq!(decoded.num_planes(), 0);
+impl Aggregate<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
     }
 
-    #[test]
-    fn camera_frame_roundtrip_two_planes() {
-        let y = CameraPlaneView {
-            fd: 42,
-            offset: 0,
-            stride: 1920,
-            size: 2_073_600,
-            used: 2_073_600,
-            data: &[],
-        };
-        let uv = CameraPlaneView {
-            fd: 42,
-            offset: 2_073_600,
-            stride: 1920,
-            size: 1_036_800,
-            used: 1_036_800,
-            data: &[],
-        };
+    /// Start a new `AggregateBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> AggregateBuilder<'a> {
+        AggregateBuilder::new()
+    }
+}
+
+// ── AggregateBuilder<'a> ────────────────────────────────────────────
+
+/// Builder for `Aggregate<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `counts` and `zones` are borrowed from caller-owned slices; the borrows
+/// must remain valid until `build()`, `encode_into_vec()`, or
+/// `encode_into_slice()` is called.
+pub struct AggregateBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    window_start: Time,
+    window_end: Time,
+    counts: &'a [AggregateCountView<'a>],
+    zones: &'a [AggregateZoneView<'a>],
+}
+
+impl<'a> Default for AggregateBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            window_start: Time { sec: 0, nanosec: 0 },
+            window_end: Time { sec: 0, nanosec: 0 },
+            counts: &[],
+            zones: &[],
+        }
+    }
+}
+
+impl<'a> AggregateBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn window_start(&mut self, t: Time) -> &mut Self {
+        self.window_start = t;
+        self
+    }
+    pub fn window_end(&mut self, t: Time) -> &mut Self {
+        self.window_end = t;
+        self
+    }
+    pub fn counts(&mut self, v: &'a [AggregateCountView<'a>]) -> &mut Self {
+        self.counts = v;
+        self
+    }
+    pub fn zones(&mut self, v: &'a [AggregateZoneView<'a>]) -> &mut Self {
+        self.zones = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        Time::size_cdr(&mut s);
+        Time::size_cdr(&mut s);
+        s.size_u32();
+        for e in self.counts {
+            s.size_string(e.label);
+            s.size_u32();
+        }
+        s.size_u32();
+        for z in self.zones {
+            s.size_string(z.zone);
+            s.size_u32();
+            s.size_u32();
+            s.size_f32();
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        self.window_start.write_cdr(&mut w);
+        self.window_end.write_cdr(&mut w);
+        w.write_u32(self.counts.len() as u32);
+        for e in self.counts {
+            w.write_string(e.label);
+            w.write_u32(e.count);
+        }
+        w.write_u32(self.zones.len() as u32);
+        for z in self.zones {
+            w.write_string(z.zone);
+            w.write_u32(z.occupancy);
+            w.write_u32(z.visits);
+            w.write_f32(z.avg_dwell);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<Aggregate<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        Aggregate::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Aggregate<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+
+    pub fn set_window_start(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        let p = align(self.offsets[0], 4);
+        wr_i32(b, p, t.sec)?;
+        wr_u32(b, p + 4, t.nanosec)
+    }
+
+    pub fn set_window_end(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        let p = align(self.offsets[0], 4) + 8;
+        wr_i32(b, p, t.sec)?;
+        wr_u32(b, p + 4, t.nanosec)
+    }
+}
+
+// ── Attribute<B> — edgefirst_msgs/msg/Attribute ─────────────────────
+//
+// CDR layout: Header → offsets[0],
+//   track_id(string) → offsets[1],
+//   attributes(seq<AttributeEntry>)
+//
+// AttributeEntry element: key(string) + value(string) + confidence(f32)
+
+/// Zero-copy view of a single AttributeEntry element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AttributeEntryView<'a> {
+    pub key: &'a str,
+    pub value: &'a str,
+    pub confidence: f32,
+}
+
+fn scan_attribute_entry<'a>(c: &mut CdrCursor<'a>) -> Result<AttributeEntryView<'a>, CdrError> {
+    let key = c.read_string()?;
+    let value = c.read_string()?;
+    let confidence = c.read_f32()?;
+    Ok(AttributeEntryView {
+        key,
+        value,
+        confidence,
+    })
+}
+
+pub struct Attribute<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
+
+impl<B> Attribute<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> Attribute<C> {
+        Attribute {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Attribute<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_string().field("track_id")?;
+        let o1 = c.offset();
+        let raw = c.read_u32()?;
+        let count = c.check_seq_count(raw, 14)?;
+        for _ in 0..count {
+            scan_attribute_entry(&mut c)?;
+        }
+        c.expect_end()?;
+        Ok(Attribute {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    #[inline]
+    pub fn track_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+
+    pub fn attributes_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[1], 4))
+    }
+
+    pub fn attributes(&self) -> Vec<AttributeEntryView<'_>> {
+        let b = self.buf.as_ref();
+        let p = align(self.offsets[1], 4);
+        let count = rd_u32(b, p) as usize;
+        let mut c = CdrCursor::resume(b, p + 4);
+        (0..count)
+            .map(|_| {
+                scan_attribute_entry(&mut c).expect("attribute elements validated during from_cdr")
+            })
+            .collect()
+    }
+
+    /// Highest-confidence entry for `key`, if any.
+    pub fn get(&self, key: &str) -> Option<AttributeEntryView<'_>> {
+        self.attributes()
+            .into_iter()
+            .filter(|a| a.key == key)
+            .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl Attribute<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `AttributeBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> AttributeBuilder<'a> {
+        AttributeBuilder::new()
+    }
+}
+
+// ── AttributeBuilder<'a> ────────────────────────────────────────────
+
+/// Builder for `Attribute<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `attributes` is borrowed from a caller-owned slice whose elements borrow
+/// their `key` / `value` strings; all borrows must remain valid until
+/// `build()`, `encode_into_vec()`, or `encode_into_slice()` is called.
+pub struct AttributeBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    track_id: alloc::borrow::Cow<'a, str>,
+    attributes: &'a [AttributeEntryView<'a>],
+}
+
+impl<'a> Default for AttributeBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            track_id: alloc::borrow::Cow::Borrowed(""),
+            attributes: &[],
+        }
+    }
+}
+
+impl<'a> AttributeBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn track_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.track_id = s.into();
+        self
+    }
+    pub fn attributes(&mut self, v: &'a [AttributeEntryView<'a>]) -> &mut Self {
+        self.attributes = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_string(&self.track_id);
+        s.size_u32();
+        for a in self.attributes {
+            s.size_string(a.key);
+            s.size_string(a.value);
+            s.size_f32();
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_string(&self.track_id);
+        w.write_u32(self.attributes.len() as u32);
+        for a in self.attributes {
+            w.write_string(a.key);
+            w.write_string(a.value);
+            w.write_f32(a.confidence);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<Attribute<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        Attribute::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Attribute<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── VideoStreamRequest<B> — edgefirst_msgs/msg/VideoStreamRequest ────
+//
+// CDR layout: Header → offsets[0],
+//   consumer_id(string) → offsets[1],
+//   codec(string) → offsets[2],
+//   width(u32) + height(u32) + bitrate_kbps(u32) + framerate(f32)
+
+pub struct VideoStreamRequest<B> {
+    buf: B,
+    offsets: [usize; 3],
+}
+
+impl<B> VideoStreamRequest<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> VideoStreamRequest<C> {
+        VideoStreamRequest {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> VideoStreamRequest<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_string().field("consumer_id")?;
+        let o1 = c.offset();
+        let _ = c.read_string().field("codec")?;
+        let o2 = c.offset();
+        c.read_u32().field("width")?;
+        c.read_u32().field("height")?;
+        c.read_u32().field("bitrate_kbps")?;
+        c.read_f32().field("framerate")?;
+        c.expect_end()?;
+        Ok(VideoStreamRequest {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    #[inline]
+    pub fn consumer_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+    #[inline]
+    pub fn codec(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[1]).0
+    }
+    #[inline]
+    pub fn width(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[2], 4))
+    }
+    #[inline]
+    pub fn height(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[2], 4) + 4)
+    }
+    #[inline]
+    pub fn bitrate_kbps(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[2], 4) + 8)
+    }
+    #[inline]
+    pub fn framerate(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), align(self.offsets[2], 4) + 12)
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl VideoStreamRequest<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `VideoStreamRequestBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> VideoStreamRequestBuilder<'a> {
+        VideoStreamRequestBuilder::new()
+    }
+}
+
+// ── VideoStreamRequestBuilder<'a> ───────────────────────────────────
+
+/// Builder for `VideoStreamRequest<Vec<u8>>` with buffer-reuse finalizers.
+pub struct VideoStreamRequestBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    consumer_id: alloc::borrow::Cow<'a, str>,
+    codec: alloc::borrow::Cow<'a, str>,
+    width: u32,
+    height: u32,
+    bitrate_kbps: u32,
+    framerate: f32,
+}
+
+impl<'a> Default for VideoStreamRequestBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            consumer_id: alloc::borrow::Cow::Borrowed(""),
+            codec: alloc::borrow::Cow::Borrowed(""),
+            width: 0,
+            height: 0,
+            bitrate_kbps: 0,
+            framerate: 0.0,
+        }
+    }
+}
+
+impl<'a> VideoStreamRequestBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn consumer_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.consumer_id = s.into();
+        self
+    }
+    pub fn codec(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.codec = s.into();
+        self
+    }
+    pub fn width(&mut self, v: u32) -> &mut Self {
+        self.width = v;
+        self
+    }
+    pub fn height(&mut self, v: u32) -> &mut Self {
+        self.height = v;
+        self
+    }
+    pub fn bitrate_kbps(&mut self, v: u32) -> &mut Self {
+        self.bitrate_kbps = v;
+        self
+    }
+    pub fn framerate(&mut self, v: f32) -> &mut Self {
+        self.framerate = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_string(&self.consumer_id);
+        s.size_string(&self.codec);
+        s.size_u32();
+        s.size_u32();
+        s.size_u32();
+        s.size_f32();
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_string(&self.consumer_id);
+        w.write_string(&self.codec);
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_u32(self.bitrate_kbps);
+        w.write_f32(self.framerate);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<VideoStreamRequest<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        VideoStreamRequest::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> VideoStreamRequest<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+
+    pub fn set_bitrate_kbps(&mut self, v: u32) -> Result<(), CdrError> {
+        let p = align(self.offsets[2], 4) + 8;
+        wr_u32(self.buf.as_mut(), p, v)
+    }
+}
+
+// ── VideoStreamInfo<B> — edgefirst_msgs/msg/VideoStreamInfo ──────────
+//
+// CDR layout: Header → offsets[0],
+//   variants(seq<VideoStreamVariant>)
+//
+// VideoStreamVariant element: topic(string) + codec(string) + width(u32)
+//   + height(u32) + bitrate_kbps(u32) + framerate(f32) + consumers(u32)
+
+/// Zero-copy view of a single VideoStreamVariant element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VideoStreamVariantView<'a> {
+    pub topic: &'a str,
+    pub codec: &'a str,
+    pub width: u32,
+    pub height: u32,
+    pub bitrate_kbps: u32,
+    pub framerate: f32,
+    pub consumers: u32,
+}
+
+fn scan_variant_element<'a>(c: &mut CdrCursor<'a>) -> Result<VideoStreamVariantView<'a>, CdrError> {
+    let topic = c.read_string()?;
+    let codec = c.read_string()?;
+    let width = c.read_u32()?;
+    let height = c.read_u32()?;
+    let bitrate_kbps = c.read_u32()?;
+    let framerate = c.read_f32()?;
+    let consumers = c.read_u32()?;
+    Ok(VideoStreamVariantView {
+        topic,
+        codec,
+        width,
+        height,
+        bitrate_kbps,
+        framerate,
+        consumers,
+    })
+}
+
+pub struct VideoStreamInfo<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> VideoStreamInfo<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> VideoStreamInfo<C> {
+        VideoStreamInfo {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> VideoStreamInfo<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let raw = c.read_u32()?;
+        // min variant size: 2 × empty string (4) + 5 × u32 = 28 bytes
+        let count = c.check_seq_count(raw, 28)?;
+        for _ in 0..count {
+            scan_variant_element(&mut c).field("variants")?;
+        }
+        c.expect_end()?;
+        Ok(VideoStreamInfo { offsets: [o0], buf })
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+
+    pub fn variants_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[0], 4))
+    }
+
+    pub fn variants(&self) -> Vec<VideoStreamVariantView<'_>> {
+        let b = self.buf.as_ref();
+        let p = align(self.offsets[0], 4);
+        let count = rd_u32(b, p) as usize;
+        let mut c = CdrCursor::resume(b, p + 4);
+        (0..count)
+            .map(|_| {
+                scan_variant_element(&mut c).expect("variant elements validated during from_cdr")
+            })
+            .collect()
+    }
+
+    /// Pick the advertised variant that best satisfies `req`.
+    ///
+    /// Variants with a different codec (when `req.codec()` is set) or
+    /// exceeding the requested width, height or bitrate (when non-zero) are
+    /// rejected; among the rest the largest resolution wins, then the
+    /// highest bitrate, then the fewest existing consumers.
+    pub fn select<R: AsRef<[u8]>>(
+        &self,
+        req: &VideoStreamRequest<R>,
+    ) -> Option<VideoStreamVariantView<'_>> {
+        let within = |limit: u32, v: u32| limit == 0 || v <= limit;
+        self.variants()
+            .into_iter()
+            .filter(|v| req.codec().is_empty() || v.codec.eq_ignore_ascii_case(req.codec()))
+            .filter(|v| within(req.width(), v.width) && within(req.height(), v.height))
+            .filter(|v| within(req.bitrate_kbps(), v.bitrate_kbps))
+            .max_by_key(|v| {
+                (
+                    u64::from(v.width) * u64::from(v.height),
+                    v.bitrate_kbps,
+                    core::cmp::Reverse(v.consumers),
+                )
+            })
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl VideoStreamInfo<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `VideoStreamInfoBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> VideoStreamInfoBuilder<'a> {
+        VideoStreamInfoBuilder::new()
+    }
+}
+
+// ── VideoStreamInfoBuilder<'a> ──────────────────────────────────────
+
+/// Builder for `VideoStreamInfo<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `variants` is borrowed from a caller-owned slice; the borrow must remain
+/// valid until `build()`, `encode_into_vec()`, or `encode_into_slice()` is
+/// called.
+pub struct VideoStreamInfoBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    variants: &'a [VideoStreamVariantView<'a>],
+}
+
+impl<'a> Default for VideoStreamInfoBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            variants: &[],
+        }
+    }
+}
+
+impl<'a> VideoStreamInfoBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn variants(&mut self, v: &'a [VideoStreamVariantView<'a>]) -> &mut Self {
+        self.variants = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_u32();
+        for v in self.variants {
+            s.size_string(v.topic);
+            s.size_string(v.codec);
+            s.size_u32();
+            s.size_u32();
+            s.size_u32();
+            s.size_f32();
+            s.size_u32();
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_u32(self.variants.len() as u32);
+        for v in self.variants {
+            w.write_string(v.topic);
+            w.write_string(v.codec);
+            w.write_u32(v.width);
+            w.write_u32(v.height);
+            w.write_u32(v.bitrate_kbps);
+            w.write_f32(v.framerate);
+            w.write_u32(v.consumers);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<VideoStreamInfo<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        VideoStreamInfo::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> VideoStreamInfo<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── CameraControl<B> — edgefirst_msgs/msg/CameraControl ──────────────
+//
+// CDR layout: Header → offsets[0],
+//   pan(f32) + tilt(f32) + zoom(f32) + focus(f32)
+//   + mode(u8) + focus_mode(u8) + ir_cut(u8)
+
+/// `mode`, `focus_mode`, `ir_cut` and `fault` values for [`CameraControl`]
+/// and [`CameraStatus`].
+pub mod camera_control {
+    /// pan/tilt/zoom are velocities (rad/s, rad/s, normalized -1..1).
+    pub const MODE_VELOCITY: u8 = 0;
+    /// pan/tilt/zoom are absolute positions (rad, rad, zoom factor).
+    pub const MODE_ABSOLUTE: u8 = 1;
+
+    pub const FOCUS_AUTO: u8 = 0;
+    pub const FOCUS_MANUAL: u8 = 1;
+    pub const FOCUS_ONE_SHOT: u8 = 2;
+
+    pub const IR_CUT_AUTO: u8 = 0;
+    /// Filter engaged, color imaging.
+    pub const IR_CUT_DAY: u8 = 1;
+    /// Filter removed, IR-sensitive imaging.
+    pub const IR_CUT_NIGHT: u8 = 2;
+
+    pub const FAULT_NONE: u8 = 0;
+    pub const FAULT_LIMIT: u8 = 1;
+    pub const FAULT_STALL: u8 = 2;
+    pub const FAULT_COMMS: u8 = 3;
+}
+
+pub struct CameraControl<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> CameraControl<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> CameraControl<C> {
+        CameraControl {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> CameraControl<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_f32().field("pan")?;
+        c.read_f32().field("tilt")?;
+        c.read_f32().field("zoom")?;
+        c.read_f32().field("focus")?;
+        c.read_u8().field("mode")?;
+        c.read_u8().field("focus_mode")?;
+        c.read_u8().field("ir_cut")?;
+        c.expect_end()?;
+        Ok(CameraControl { offsets: [o0], buf })
+    }
+
+    #[inline]
+    fn fixed_base(&self) -> usize {
+        align(self.offsets[0], 4)
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    #[inline]
+    pub fn pan(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.fixed_base())
+    }
+    #[inline]
+    pub fn tilt(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.fixed_base() + 4)
+    }
+    #[inline]
+    pub fn zoom(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.fixed_base() + 8)
+    }
+    #[inline]
+    pub fn focus(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.fixed_base() + 12)
+    }
+    #[inline]
+    pub fn mode(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), self.fixed_base() + 16)
+    }
+    #[inline]
+    pub fn focus_mode(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), self.fixed_base() + 17)
+    }
+    #[inline]
+    pub fn ir_cut(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), self.fixed_base() + 18)
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl CameraControl<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `CameraControlBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> CameraControlBuilder<'a> {
+        CameraControlBuilder::new()
+    }
+}
+
+// ── CameraControlBuilder<'a> ────────────────────────────────────────
+
+/// Builder for `CameraControl<Vec<u8>>` with buffer-reuse finalizers.
+pub struct CameraControlBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    pan: f32,
+    tilt: f32,
+    zoom: f32,
+    focus: f32,
+    mode: u8,
+    focus_mode: u8,
+    ir_cut: u8,
+}
+
+impl<'a> Default for CameraControlBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            pan: 0.0,
+            tilt: 0.0,
+            zoom: 0.0,
+            focus: 0.0,
+            mode: 0,
+            focus_mode: 0,
+            ir_cut: 0,
+        }
+    }
+}
+
+impl<'a> CameraControlBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn pan(&mut self, v: f32) -> &mut Self {
+        self.pan = v;
+        self
+    }
+    pub fn tilt(&mut self, v: f32) -> &mut Self {
+        self.tilt = v;
+        self
+    }
+    pub fn zoom(&mut self, v: f32) -> &mut Self {
+        self.zoom = v;
+        self
+    }
+    pub fn focus(&mut self, v: f32) -> &mut Self {
+        self.focus = v;
+        self
+    }
+    pub fn mode(&mut self, v: u8) -> &mut Self {
+        self.mode = v;
+        self
+    }
+    pub fn focus_mode(&mut self, v: u8) -> &mut Self {
+        self.focus_mode = v;
+        self
+    }
+    pub fn ir_cut(&mut self, v: u8) -> &mut Self {
+        self.ir_cut = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_f32();
+        s.size_f32();
+        s.size_f32();
+        s.size_f32();
+        s.size_u8();
+        s.size_u8();
+        s.size_u8();
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_f32(self.pan);
+        w.write_f32(self.tilt);
+        w.write_f32(self.zoom);
+        w.write_f32(self.focus);
+        w.write_u8(self.mode);
+        w.write_u8(self.focus_mode);
+        w.write_u8(self.ir_cut);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<CameraControl<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        CameraControl::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> CameraControl<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+
+    pub fn set_pan(&mut self, v: f32) -> Result<(), CdrError> {
+        let p = self.fixed_base();
+        wr_f32(self.buf.as_mut(), p, v)
+    }
+
+    pub fn set_tilt(&mut self, v: f32) -> Result<(), CdrError> {
+        let p = self.fixed_base() + 4;
+        wr_f32(self.buf.as_mut(), p, v)
+    }
+
+    pub fn set_zoom(&mut self, v: f32) -> Result<(), CdrError> {
+        let p = self.fixed_base() + 8;
+        wr_f32(self.buf.as_mut(), p, v)
+    }
+}
+
+// ── CameraStatus<B> — edgefirst_msgs/msg/CameraStatus ────────────────
+//
+// CDR layout: Header → offsets[0],
+//   pan(f32) + tilt(f32) + zoom(f32) + focus(f32)
+//   + focus_mode(u8) + ir_cut(u8) + moving(bool) + fault(u8)
+
+pub struct CameraStatus<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> CameraStatus<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> CameraStatus<C> {
+        CameraStatus {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> CameraStatus<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_f32().field("pan")?;
+        c.read_f32().field("tilt")?;
+        c.read_f32().field("zoom")?;
+        c.read_f32().field("focus")?;
+        c.read_u8().field("focus_mode")?;
+        c.read_u8().field("ir_cut")?;
+        c.read_bool().field("moving")?;
+        c.read_u8().field("fault")?;
+        c.expect_end()?;
+        Ok(CameraStatus { offsets: [o0], buf })
+    }
+
+    #[inline]
+    fn fixed_base(&self) -> usize {
+        align(self.offsets[0], 4)
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    /// Current absolute pan angle in radians.
+    #[inline]
+    pub fn pan(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.fixed_base())
+    }
+    /// Current absolute tilt angle in radians.
+    #[inline]
+    pub fn tilt(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.fixed_base() + 4)
+    }
+    /// Current zoom factor (1.0 = widest).
+    #[inline]
+    pub fn zoom(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.fixed_base() + 8)
+    }
+    /// Current normalized focus position (0.0 near, 1.0 far).
+    #[inline]
+    pub fn focus(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.fixed_base() + 12)
+    }
+    #[inline]
+    pub fn focus_mode(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), self.fixed_base() + 16)
+    }
+    #[inline]
+    pub fn ir_cut(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), self.fixed_base() + 17)
+    }
+    #[inline]
+    pub fn moving(&self) -> bool {
+        rd_bool(self.buf.as_ref(), self.fixed_base() + 18)
+    }
+    #[inline]
+    pub fn fault(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), self.fixed_base() + 19)
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl CameraStatus<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `CameraStatusBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> CameraStatusBuilder<'a> {
+        CameraStatusBuilder::new()
+    }
+}
+
+// ── CameraStatusBuilder<'a> ─────────────────────────────────────────
+
+/// Builder for `CameraStatus<Vec<u8>>` with buffer-reuse finalizers.
+pub struct CameraStatusBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    pan: f32,
+    tilt: f32,
+    zoom: f32,
+    focus: f32,
+    focus_mode: u8,
+    ir_cut: u8,
+    moving: bool,
+    fault: u8,
+}
+
+impl<'a> Default for CameraStatusBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            pan: 0.0,
+            tilt: 0.0,
+            zoom: 0.0,
+            focus: 0.0,
+            focus_mode: 0,
+            ir_cut: 0,
+            moving: false,
+            fault: 0,
+        }
+    }
+}
+
+impl<'a> CameraStatusBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn pan(&mut self, v: f32) -> &mut Self {
+        self.pan = v;
+        self
+    }
+    pub fn tilt(&mut self, v: f32) -> &mut Self {
+        self.tilt = v;
+        self
+    }
+    pub fn zoom(&mut self, v: f32) -> &mut Self {
+        self.zoom = v;
+        self
+    }
+    pub fn focus(&mut self, v: f32) -> &mut Self {
+        self.focus = v;
+        self
+    }
+    pub fn focus_mode(&mut self, v: u8) -> &mut Self {
+        self.focus_mode = v;
+        self
+    }
+    pub fn ir_cut(&mut self, v: u8) -> &mut Self {
+        self.ir_cut = v;
+        self
+    }
+    pub fn moving(&mut self, v: bool) -> &mut Self {
+        self.moving = v;
+        self
+    }
+    pub fn fault(&mut self, v: u8) -> &mut Self {
+        self.fault = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_f32();
+        s.size_f32();
+        s.size_f32();
+        s.size_f32();
+        s.size_u8();
+        s.size_u8();
+        s.size_bool();
+        s.size_u8();
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_f32(self.pan);
+        w.write_f32(self.tilt);
+        w.write_f32(self.zoom);
+        w.write_f32(self.focus);
+        w.write_u8(self.focus_mode);
+        w.write_u8(self.ir_cut);
+        w.write_bool(self.moving);
+        w.write_u8(self.fault);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<CameraStatus<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        CameraStatus::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> CameraStatus<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── Provenance<B> — edgefirst_msgs/msg/Provenance ───────────────────
+//
+// CDR layout: Header → offsets[0],
+//   output_topic(string) → offsets[1],
+//   service_name(string) → offsets[2],
+//   service_version(string) → offsets[3],
+//   model_name(string) → offsets[4],
+//   inputs(seq<ProvenanceInput>)
+//
+// ProvenanceInput element: topic(string) + schema(string)
+//   + schema_hash(string) + stamp(Time)
+
+/// Zero-copy view of a single ProvenanceInput element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ProvenanceInputView<'a> {
+    pub topic: &'a str,
+    pub schema: &'a str,
+    pub schema_hash: &'a str,
+    pub stamp: Time,
+}
+
+fn scan_provenance_input<'a>(c: &mut CdrCursor<'a>) -> Result<ProvenanceInputView<'a>, CdrError> {
+    let topic = c.read_string()?;
+    let schema = c.read_string()?;
+    let schema_hash = c.read_string()?;
+    let stamp = Time::read_cdr(c)?;
+    Ok(ProvenanceInputView {
+        topic,
+        schema,
+        schema_hash,
+        stamp,
+    })
+}
+
+pub struct Provenance<B> {
+    buf: B,
+    offsets: [usize; 5],
+}
+
+impl<B> Provenance<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> Provenance<C> {
+        Provenance {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Provenance<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_string().field("output_topic")?;
+        let o1 = c.offset();
+        let _ = c.read_string().field("service_name")?;
+        let o2 = c.offset();
+        let _ = c.read_string().field("service_version")?;
+        let o3 = c.offset();
+        let _ = c.read_string().field("model_name")?;
+        let o4 = c.offset();
+        let raw = c.read_u32()?;
+        // min input size: 3 × empty string (4) + Time (8) = 20 bytes
+        let count = c.check_seq_count(raw, 20)?;
+        for _ in 0..count {
+            scan_provenance_input(&mut c).field("inputs")?;
+        }
+        c.expect_end()?;
+        Ok(Provenance {
+            offsets: [o0, o1, o2, o3, o4],
+            buf,
+        })
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    /// Stamp of the output message this record describes.
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    #[inline]
+    pub fn output_topic(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+    #[inline]
+    pub fn service_name(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[1]).0
+    }
+    #[inline]
+    pub fn service_version(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[2]).0
+    }
+    #[inline]
+    pub fn model_name(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[3]).0
+    }
+
+    pub fn inputs_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[4], 4))
+    }
+
+    pub fn inputs(&self) -> Vec<ProvenanceInputView<'_>> {
+        let b = self.buf.as_ref();
+        let p = align(self.offsets[4], 4);
+        let count = rd_u32(b, p) as usize;
+        let mut c = CdrCursor::resume(b, p + 4);
+        (0..count)
+            .map(|_| {
+                scan_provenance_input(&mut c).expect("input elements validated during from_cdr")
+            })
+            .collect()
+    }
+
+    /// The input received on `topic`, if this output was derived from it.
+    pub fn input(&self, topic: &str) -> Option<ProvenanceInputView<'_>> {
+        self.inputs().into_iter().find(|i| i.topic == topic)
+    }
+
+    /// `true` if this record describes the message published on
+    /// `output_topic` with header stamp `stamp` — the key used to join
+    /// provenance records with the messages they describe in a recording.
+    pub fn describes(&self, output_topic: &str, stamp: Time) -> bool {
+        self.output_topic() == output_topic && self.stamp() == stamp
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl Provenance<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `ProvenanceBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> ProvenanceBuilder<'a> {
+        ProvenanceBuilder::new()
+    }
+}
+
+// ── ProvenanceBuilder<'a> ───────────────────────────────────────────
+
+/// Builder for `Provenance<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `inputs` is borrowed from a caller-owned slice; the borrow must remain
+/// valid until `build()`, `encode_into_vec()`, or `encode_into_slice()` is
+/// called.
+pub struct ProvenanceBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    output_topic: alloc::borrow::Cow<'a, str>,
+    service_name: alloc::borrow::Cow<'a, str>,
+    service_version: alloc::borrow::Cow<'a, str>,
+    model_name: alloc::borrow::Cow<'a, str>,
+    inputs: &'a [ProvenanceInputView<'a>],
+}
+
+impl<'a> Default for ProvenanceBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            output_topic: alloc::borrow::Cow::Borrowed(""),
+            service_name: alloc::borrow::Cow::Borrowed(""),
+            service_version: alloc::borrow::Cow::Borrowed(""),
+            model_name: alloc::borrow::Cow::Borrowed(""),
+            inputs: &[],
+        }
+    }
+}
+
+impl<'a> ProvenanceBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn output_topic(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.output_topic = s.into();
+        self
+    }
+    pub fn service_name(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.service_name = s.into();
+        self
+    }
+    pub fn service_version(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.service_version = s.into();
+        self
+    }
+    pub fn model_name(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.model_name = s.into();
+        self
+    }
+    pub fn inputs(&mut self, v: &'a [ProvenanceInputView<'a>]) -> &mut Self {
+        self.inputs = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_string(&self.output_topic);
+        s.size_string(&self.service_name);
+        s.size_string(&self.service_version);
+        s.size_string(&self.model_name);
+        s.size_u32();
+        for i in self.inputs {
+            s.size_string(i.topic);
+            s.size_string(i.schema);
+            s.size_string(i.schema_hash);
+            Time::size_cdr(&mut s);
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_string(&self.output_topic);
+        w.write_string(&self.service_name);
+        w.write_string(&self.service_version);
+        w.write_string(&self.model_name);
+        w.write_u32(self.inputs.len() as u32);
+        for i in self.inputs {
+            w.write_string(i.topic);
+            w.write_string(i.schema);
+            w.write_string(i.schema_hash);
+            i.stamp.write_cdr(&mut w);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<Provenance<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        Provenance::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Provenance<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── ShmRing<B> — edgefirst_msgs/msg/ShmRing ─────────────────────────
+//
+// CDR layout: Header → offsets[0],
+//   name(string) → offsets[1],
+//   schema(string) → offsets[2],
+//   pid(u32) + slot_size(u32) + slot_count(u32) + length(u32)
+//   + seq(u64, CDR-aligned to 8)
+
+pub struct ShmRing<B> {
+    buf: B,
+    offsets: [usize; 3],
+}
+
+impl<B> ShmRing<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> ShmRing<C> {
+        ShmRing {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> ShmRing<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_string().field("name")?;
+        let o1 = c.offset();
+        let _ = c.read_string().field("schema")?;
+        let o2 = c.offset();
+        c.read_u32().field("pid")?;
+        c.read_u32().field("slot_size")?;
+        c.read_u32().field("slot_count")?;
+        c.read_u32().field("length")?;
+        c.read_u64().field("seq")?;
+        c.expect_end()?;
+        Ok(ShmRing {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
+
+    #[inline]
+    fn fixed_base(&self) -> usize {
+        align(self.offsets[2], 4)
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    /// POSIX shared-memory object name, e.g. `/edgefirst.camera`.
+    #[inline]
+    pub fn name(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+    /// Schema of the slot payload, or empty for raw bytes.
+    #[inline]
+    pub fn schema(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[1]).0
+    }
+    #[inline]
+    pub fn pid(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.fixed_base())
+    }
+    #[inline]
+    pub fn slot_size(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.fixed_base() + 4)
+    }
+    #[inline]
+    pub fn slot_count(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.fixed_base() + 8)
+    }
+    /// Valid bytes in the announced slot.
+    #[inline]
+    pub fn length(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.fixed_base() + 12)
+    }
+    /// Sequence number of the announced write; it lives in slot
+    /// `seq % slot_count`.
+    #[inline]
+    pub fn seq(&self) -> u64 {
+        rd_u64(self.buf.as_ref(), cdr_align(self.fixed_base() + 16, 8))
+    }
+    /// Index of the slot holding the announced write.
+    #[inline]
+    pub fn slot(&self) -> u32 {
+        match self.slot_count() {
+            0 => 0,
+            n => (self.seq() % u64::from(n)) as u32,
+        }
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl ShmRing<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `ShmRingBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> ShmRingBuilder<'a> {
+        ShmRingBuilder::new()
+    }
+}
+
+// ── ShmRingBuilder<'a> ──────────────────────────────────────────────
+
+/// Builder for `ShmRing<Vec<u8>>` with buffer-reuse finalizers.
+pub struct ShmRingBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    name: alloc::borrow::Cow<'a, str>,
+    schema: alloc::borrow::Cow<'a, str>,
+    pid: u32,
+    slot_size: u32,
+    slot_count: u32,
+    length: u32,
+    seq: u64,
+}
+
+impl<'a> Default for ShmRingBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            name: alloc::borrow::Cow::Borrowed(""),
+            schema: alloc::borrow::Cow::Borrowed(""),
+            pid: 0,
+            slot_size: 0,
+            slot_count: 0,
+            length: 0,
+            seq: 0,
+        }
+    }
+}
+
+impl<'a> ShmRingBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn name(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.name = s.into();
+        self
+    }
+    pub fn schema(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.schema = s.into();
+        self
+    }
+    pub fn pid(&mut self, v: u32) -> &mut Self {
+        self.pid = v;
+        self
+    }
+    pub fn slot_size(&mut self, v: u32) -> &mut Self {
+        self.slot_size = v;
+        self
+    }
+    pub fn slot_count(&mut self, v: u32) -> &mut Self {
+        self.slot_count = v;
+        self
+    }
+    pub fn length(&mut self, v: u32) -> &mut Self {
+        self.length = v;
+        self
+    }
+    pub fn seq(&mut self, v: u64) -> &mut Self {
+        self.seq = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_string(&self.name);
+        s.size_string(&self.schema);
+        s.size_u32();
+        s.size_u32();
+        s.size_u32();
+        s.size_u32();
+        s.size_u64();
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_string(&self.name);
+        w.write_string(&self.schema);
+        w.write_u32(self.pid);
+        w.write_u32(self.slot_size);
+        w.write_u32(self.slot_count);
+        w.write_u32(self.length);
+        w.write_u64(self.seq);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<ShmRing<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        ShmRing::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> ShmRing<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+
+    /// Announce another write to the same ring without rebuilding.
+    pub fn set_write(&mut self, seq: u64, length: u32) -> Result<(), CdrError> {
+        let base = self.fixed_base();
+        let b = self.buf.as_mut();
+        wr_u32(b, base + 12, length)?;
+        wr_u64(b, cdr_align(base + 16, 8), seq)
+    }
+}
+
+// ── Heartbeat<B> — edgefirst_msgs/msg/Heartbeat ─────────────────────
+//
+// CDR layout: Header → offsets[0],
+//   service(string) → offsets[1],
+//   message(string) → offsets[2],
+//   seq(u64, CDR-aligned to 8) + period(Duration) + status(u8)
+
+pub mod heartbeat {
+    pub const STATUS_OK: u8 = 0;
+    pub const STATUS_DEGRADED: u8 = 1;
+    pub const STATUS_ERROR: u8 = 2;
+    pub const STATUS_STOPPING: u8 = 3;
+}
+
+crate::cdr_enum! {
+    /// Typed form of the [`heartbeat`] status constants.
+    pub enum HeartbeatStatus: u8 {
+        Ok = 0,
+        Degraded = 1,
+        Error = 2,
+        /// The service is shutting down; silence afterwards is expected.
+        Stopping = 3,
+    }
+}
+
+pub struct Heartbeat<B> {
+    buf: B,
+    offsets: [usize; 3],
+}
+
+impl<B> Heartbeat<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> Heartbeat<C> {
+        Heartbeat {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Heartbeat<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_string().field("service")?;
+        let o1 = c.offset();
+        let _ = c.read_string().field("message")?;
+        let o2 = c.offset();
+        c.read_u64().field("seq")?;
+        Duration::read_cdr(&mut c).field("period")?;
+        HeartbeatStatus::read_cdr(&mut c).field("status")?;
+        c.expect_end()?;
+        Ok(Heartbeat {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
+
+    #[inline]
+    fn fixed_base(&self) -> usize {
+        cdr_align(self.offsets[2], 8)
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    /// Name of the publishing service.
+    #[inline]
+    pub fn service(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+    /// Human-readable detail for a non-OK status.
+    #[inline]
+    pub fn message(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[1]).0
+    }
+    /// Monotonic counter, reset to zero when the service restarts.
+    #[inline]
+    pub fn seq(&self) -> u64 {
+        rd_u64(self.buf.as_ref(), self.fixed_base())
+    }
+    /// Interval at which the service promises to publish.
+    #[inline]
+    pub fn period(&self) -> Duration {
+        rd_duration(self.buf.as_ref(), self.fixed_base() + 8)
+    }
+    #[inline]
+    pub fn status(&self) -> HeartbeatStatus {
+        HeartbeatStatus::from_repr(rd_u8(self.buf.as_ref(), self.fixed_base() + 16))
+            .expect("status validated during from_cdr")
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl Heartbeat<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `HeartbeatBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> HeartbeatBuilder<'a> {
+        HeartbeatBuilder::new()
+    }
+}
+
+// ── HeartbeatBuilder<'a> ────────────────────────────────────────────
+
+/// Builder for `Heartbeat<Vec<u8>>` with buffer-reuse finalizers.
+pub struct HeartbeatBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    service: alloc::borrow::Cow<'a, str>,
+    message: alloc::borrow::Cow<'a, str>,
+    seq: u64,
+    period: Duration,
+    status: HeartbeatStatus,
+}
+
+impl<'a> Default for HeartbeatBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            service: alloc::borrow::Cow::Borrowed(""),
+            message: alloc::borrow::Cow::Borrowed(""),
+            seq: 0,
+            period: Duration { sec: 0, nanosec: 0 },
+            status: HeartbeatStatus::Ok,
+        }
+    }
+}
+
+impl<'a> HeartbeatBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn service(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.service = s.into();
+        self
+    }
+    pub fn message(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.message = s.into();
+        self
+    }
+    pub fn seq(&mut self, v: u64) -> &mut Self {
+        self.seq = v;
+        self
+    }
+    pub fn period(&mut self, d: Duration) -> &mut Self {
+        self.period = d;
+        self
+    }
+    pub fn status(&mut self, s: HeartbeatStatus) -> &mut Self {
+        self.status = s;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_string(&self.service);
+        s.size_string(&self.message);
+        s.size_u64();
+        Duration::size_cdr(&mut s);
+        HeartbeatStatus::size_cdr(&mut s);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_string(&self.service);
+        w.write_string(&self.message);
+        w.write_u64(self.seq);
+        self.period.write_cdr(&mut w);
+        self.status.write_cdr(&mut w);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<Heartbeat<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        Heartbeat::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Heartbeat<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+    /// Advance to the next beat without rebuilding.
+    pub fn set_seq(&mut self, v: u64) -> Result<(), CdrError> {
+        let p = self.fixed_base();
+        wr_u64(self.buf.as_mut(), p, v)
+    }
+    pub fn set_status(&mut self, s: HeartbeatStatus) -> Result<(), CdrError> {
+        let p = self.fixed_base() + 16;
+        wr_u8(self.buf.as_mut(), p, s.to_repr())
+    }
+}
+
+// ── HeartbeatMonitor ────────────────────────────────────────────────
+
+/// Liveness change reported by [`HeartbeatMonitor`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum HeartbeatEvent {
+    /// First heartbeat from `service`.
+    Joined { service: String },
+    /// `service` reported a different status than in its previous beat.
+    StatusChanged {
+        service: String,
+        from: HeartbeatStatus,
+        to: HeartbeatStatus,
+    },
+    /// The sequence number went backwards: the service restarted.
+    Restarted { service: String },
+    /// No heartbeat from `service` within its deadline; `last_seen` is when
+    /// the previous one arrived. Reported once per outage.
+    Missed { service: String, last_seen: Time },
+    /// `service` is beating again after a `Missed`.
+    Recovered { service: String },
+    /// `service` announced `Stopping` and then fell silent as expected; it
+    /// is no longer tracked.
+    Left { service: String },
+}
+
+struct Liveness {
+    last_seen: Time,
+    period: f64,
+    seq: u64,
+    status: HeartbeatStatus,
+    missed: bool,
+}
+
+/// Tracks the heartbeats of a set of services and reports liveness changes.
+///
+/// Feed every received [`Heartbeat`] to [`observe`](Self::observe) and
+/// call [`poll`](Self::poll) periodically. A service is missed once no
+/// beat arrived for `tolerance` of its own advertised periods; `now` is
+/// supplied by the caller so the monitor works on live and recorded data.
+pub struct HeartbeatMonitor {
+    tolerance: f64,
+    services: alloc::collections::BTreeMap<String, Liveness>,
+}
+
+impl Default for HeartbeatMonitor {
+    /// Tolerates one lost beat: a service is missed after two periods.
+    fn default() -> Self {
+        HeartbeatMonitor::new(2.0)
+    }
+}
+
+impl HeartbeatMonitor {
+    pub fn new(tolerance: f64) -> Self {
+        HeartbeatMonitor {
+            tolerance,
+            services: Default::default(),
+        }
+    }
+
+    /// Account for one heartbeat received at `now`.
+    pub fn observe<B: AsRef<[u8]>>(&mut self, hb: &Heartbeat<B>, now: Time) -> Vec<HeartbeatEvent> {
+        let service = hb.service();
+        let period = hb.period();
+        let next = Liveness {
+            last_seen: now,
+            period: period.sec as f64 + period.nanosec as f64 * 1e-9,
+            seq: hb.seq(),
+            status: hb.status(),
+            missed: false,
+        };
+        let mut events = Vec::new();
+        match self.services.insert(service.to_owned(), next) {
+            None => events.push(HeartbeatEvent::Joined {
+                service: service.to_owned(),
+            }),
+            Some(prev) => {
+                if prev.missed {
+                    events.push(HeartbeatEvent::Recovered {
+                        service: service.to_owned(),
+                    });
+                }
+                if hb.seq() < prev.seq {
+                    events.push(HeartbeatEvent::Restarted {
+                        service: service.to_owned(),
+                    });
+                }
+                if hb.status() != prev.status {
+                    events.push(HeartbeatEvent::StatusChanged {
+                        service: service.to_owned(),
+                        from: prev.status,
+                        to: hb.status(),
+                    });
+                }
+            }
+        }
+        events
+    }
+
+    /// Report services whose deadline has passed at `now`.
+    pub fn poll(&mut self, now: Time) -> Vec<HeartbeatEvent> {
+        let now = time_secs(now);
+        let mut events = Vec::new();
+        self.services.retain(|service, l| {
+            if l.missed || now <= time_secs(l.last_seen) + l.period * self.tolerance {
+                return true;
+            }
+            if l.status == HeartbeatStatus::Stopping {
+                events.push(HeartbeatEvent::Left {
+                    service: service.clone(),
+                });
+                return false;
+            }
+            l.missed = true;
+            events.push(HeartbeatEvent::Missed {
+                service: service.clone(),
+                last_seen: l.last_seen,
+            });
+            true
+        });
+        events
+    }
+
+    /// `true` if `service` is tracked and not currently missed.
+    pub fn is_alive(&self, service: &str) -> bool {
+        self.services.get(service).is_some_and(|l| !l.missed)
+    }
+
+    /// Last reported status of `service`.
+    pub fn status(&self, service: &str) -> Option<HeartbeatStatus> {
+        self.services.get(service).map(|l| l.status)
+    }
+
+    /// Names of all tracked services, sorted.
+    pub fn services(&self) -> impl Iterator<Item = &str> {
+        self.services.keys().map(String::as_str)
+    }
+}
+
+// ── BlobRef<B> — edgefirst_msgs/msg/BlobRef ─────────────────────────
+//
+// CDR layout: Header → offsets[0],
+//   uri(string) → offsets[1],
+//   media_type(string) → offsets[2],
+//   size(u64, CDR-aligned to 8) + sha256(u8[32])
+
+pub struct BlobRef<B> {
+    buf: B,
+    offsets: [usize; 3],
+}
+
+impl<B> BlobRef<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> BlobRef<C> {
+        BlobRef {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> BlobRef<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_string().field("uri")?;
+        let o1 = c.offset();
+        let _ = c.read_string().field("media_type")?;
+        let o2 = c.offset();
+        c.read_u64().field("size")?;
+        c.read_raw(32).field("sha256")?;
+        c.expect_end()?;
+        Ok(BlobRef {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
+
+    #[inline]
+    fn fixed_base(&self) -> usize {
+        cdr_align(self.offsets[2], 8)
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    /// Location of the blob, e.g. `file:///data/model.tflite`.
+    #[inline]
+    pub fn uri(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+    /// IANA media type of the blob, e.g. `video/mp4`.
+    #[inline]
+    pub fn media_type(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[1]).0
+    }
+    /// Size of the blob in bytes.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        rd_u64(self.buf.as_ref(), self.fixed_base())
+    }
+    /// SHA-256 digest of the blob contents.
+    #[inline]
+    pub fn sha256(&self) -> &[u8; 32] {
+        let p = self.fixed_base() + 8;
+        self.buf.as_ref()[p..p + 32]
+            .try_into()
+            .expect("sha256 validated during from_cdr")
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl BlobRef<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `BlobRefBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> BlobRefBuilder<'a> {
+        BlobRefBuilder::new()
+    }
+}
+
+// ── BlobRefBuilder<'a> ──────────────────────────────────────────────
+
+/// Builder for `BlobRef<Vec<u8>>` with buffer-reuse finalizers.
+pub struct BlobRefBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    uri: alloc::borrow::Cow<'a, str>,
+    media_type: alloc::borrow::Cow<'a, str>,
+    size: u64,
+    sha256: [u8; 32],
+}
+
+impl<'a> Default for BlobRefBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            uri: alloc::borrow::Cow::Borrowed(""),
+            media_type: alloc::borrow::Cow::Borrowed(""),
+            size: 0,
+            sha256: [0; 32],
+        }
+    }
+}
+
+impl<'a> BlobRefBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn uri(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.uri = s.into();
+        self
+    }
+    pub fn media_type(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.media_type = s.into();
+        self
+    }
+    pub fn size(&mut self, v: u64) -> &mut Self {
+        self.size = v;
+        self
+    }
+    pub fn sha256(&mut self, digest: [u8; 32]) -> &mut Self {
+        self.sha256 = digest;
+        self
+    }
+    /// Set `size` and `sha256` from the blob contents.
+    pub fn contents(&mut self, data: &[u8]) -> &mut Self {
+        self.size = data.len() as u64;
+        self.sha256 = crate::blob::sha256(data);
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_string(&self.uri);
+        s.size_string(&self.media_type);
+        s.size_u64();
+        s.size_raw(32);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_string(&self.uri);
+        w.write_string(&self.media_type);
+        w.write_u64(self.size);
+        w.write_raw(&self.sha256);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<BlobRef<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        BlobRef::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> BlobRef<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── ImageWithInfo<B> — edgefirst_msgs/msg/ImageWithInfo ─────────────
+//
+// CDR layout: Header → offsets[0],
+//   image: stamp(Time) + frame_id(string) → offsets[1],
+//     height(u32) + width(u32) + encoding(string) → offsets[2],
+//     is_bigendian(u8) + step(u32) + data(byte seq) → offsets[3],
+//   camera_info: stamp(Time) + frame_id(string) → offsets[4],
+//     height(u32) + width(u32) + distortion_model(string) → offsets[5],
+//     d(Vec<f64>) → offsets[6], k[9], r[9], p[12],
+//     binning_x(u32), binning_y(u32), roi(RegionOfInterest)
+//
+// The nested headers repeat the outer stamp and frame_id.
+
+pub struct ImageWithInfo<B> {
+    buf: B,
+    offsets: [usize; 7],
+}
+
+impl<B> ImageWithInfo<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> ImageWithInfo<C> {
+        ImageWithInfo {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> ImageWithInfo<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        Time::read_cdr(&mut c).field("image.header.stamp")?;
+        let _ = c.read_string().field("image.header.frame_id")?;
+        let o1 = c.offset();
+        c.read_u32().field("image.height")?;
+        c.read_u32().field("image.width")?;
+        let _ = c.read_string().field("image.encoding")?;
+        let o2 = c.offset();
+        c.read_u8().field("image.is_bigendian")?;
+        c.read_u32().field("image.step")?;
+        let _ = c.read_bytes().field("image.data")?;
+        let o3 = c.offset();
+        Time::read_cdr(&mut c).field("camera_info.header.stamp")?;
+        let _ = c.read_string().field("camera_info.header.frame_id")?;
+        let o4 = c.offset();
+        c.read_u32().field("camera_info.height")?;
+        c.read_u32().field("camera_info.width")?;
+        let _ = c.read_string().field("camera_info.distortion_model")?;
+        let o5 = c.offset();
+        let d_count = c.read_u32().field("camera_info.d")?;
+        c.skip_seq_8(d_count as usize).field("camera_info.d")?;
+        let o6 = c.offset();
+        read_f64_array9(&mut c).field("camera_info.k")?;
+        read_f64_array9(&mut c).field("camera_info.r")?;
+        read_f64_array12(&mut c).field("camera_info.p")?;
+        c.read_u32().field("camera_info.binning_x")?;
+        c.read_u32().field("camera_info.binning_y")?;
+        RegionOfInterest::read_cdr(&mut c).field("camera_info.roi")?;
+        c.expect_end()?;
+        Ok(ImageWithInfo {
+            offsets: [o0, o1, o2, o3, o4, o5, o6],
+            buf,
+        })
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    /// Capture time shared by the image and its calibration.
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+
+    // ── image ──
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[1], 4))
+    }
+    #[inline]
+    pub fn width(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[1], 4) + 4)
+    }
+    #[inline]
+    pub fn encoding(&self) -> &str {
+        rd_string(self.buf.as_ref(), align(self.offsets[1], 4) + 8).0
+    }
+    #[inline]
+    pub fn is_bigendian(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), self.offsets[2])
+    }
+    #[inline]
+    pub fn step(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[2] + 1, 4))
+    }
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        rd_bytes(self.buf.as_ref(), align(self.offsets[2] + 1, 4) + 4).0
+    }
+
+    // ── camera_info ──
+
+    /// Calibrated image height; differs from `height()` only under binning.
+    #[inline]
+    pub fn info_height(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[4], 4))
+    }
+    /// Calibrated image width; differs from `width()` only under binning.
+    #[inline]
+    pub fn info_width(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[4], 4) + 4)
+    }
+    #[inline]
+    pub fn distortion_model(&self) -> &str {
+        rd_string(self.buf.as_ref(), align(self.offsets[4], 4) + 8).0
+    }
+    /// Number of distortion coefficients.
+    #[inline]
+    pub fn d_len(&self) -> usize {
+        rd_u32(self.buf.as_ref(), align(self.offsets[5], 4)) as usize
+    }
+    /// Read the i-th distortion coefficient (zero-copy, on-demand).
+    #[inline]
+    pub fn d_get(&self, i: usize) -> f64 {
+        let start = cdr_align(align(self.offsets[5], 4) + 4, 8);
+        rd_f64(self.buf.as_ref(), start + i * 8)
+    }
+
+    // Fixed region after d: k[9](72) + r[9](72) + p[12](96) + binning(8) + roi(17)
+    #[inline]
+    fn fixed_base(&self) -> usize {
+        cdr_align(self.offsets[6], 8)
+    }
+
+    pub fn k(&self) -> [f64; 9] {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.fixed_base());
+        read_f64_array9(&mut c).expect("k validated during from_cdr")
+    }
+    pub fn r(&self) -> [f64; 9] {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.fixed_base() + 72);
+        read_f64_array9(&mut c).expect("r validated during from_cdr")
+    }
+    pub fn p(&self) -> [f64; 12] {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.fixed_base() + 144);
+        read_f64_array12(&mut c).expect("p validated during from_cdr")
+    }
+    #[inline]
+    pub fn binning_x(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.fixed_base() + 240)
+    }
+    #[inline]
+    pub fn binning_y(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.fixed_base() + 244)
+    }
+    pub fn roi(&self) -> RegionOfInterest {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.fixed_base() + 248);
+        RegionOfInterest::read_cdr(&mut c).expect("roi validated during from_cdr")
+    }
+
+    // ── separate-topic conversion ──
+
+    /// The image as a standalone `sensor_msgs/Image` with the bundle's
+    /// stamp and frame_id, for publishing on the image topic.
+    pub fn to_image(&self) -> Result<Image<Vec<u8>>, CdrError> {
+        Image::builder()
+            .stamp(self.stamp())
+            .frame_id(self.frame_id())
+            .height(self.height())
+            .width(self.width())
+            .encoding(self.encoding())
+            .is_bigendian(self.is_bigendian())
+            .step(self.step())
+            .data(self.data())
+            .build()
+    }
+
+    /// The calibration as a standalone `sensor_msgs/CameraInfo` with the
+    /// bundle's stamp and frame_id, for publishing on the camera_info topic.
+    pub fn to_camera_info(&self) -> Result<CameraInfo<Vec<u8>>, CdrError> {
+        let d: Vec<f64> = (0..self.d_len()).map(|i| self.d_get(i)).collect();
+        CameraInfo::builder()
+            .stamp(self.stamp())
+            .frame_id(self.frame_id())
+            .height(self.info_height())
+            .width(self.info_width())
+            .distortion_model(self.distortion_model())
+            .d(&d)
+            .k(self.k())
+            .r(self.r())
+            .p(self.p())
+            .binning_x(self.binning_x())
+            .binning_y(self.binning_y())
+            .roi(self.roi())
+            .build()
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl ImageWithInfo<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `ImageWithInfoBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> ImageWithInfoBuilder<'a> {
+        ImageWithInfoBuilder::new()
+    }
+
+    /// Bundle an image and the calibration received for it on separate
+    /// topics. The bundle takes the image's stamp and frame_id.
+    pub fn from_parts<I: AsRef<[u8]>, C: AsRef<[u8]>>(
+        image: &Image<I>,
+        camera_info: &CameraInfo<C>,
+    ) -> Result<Self, CdrError> {
+        ImageWithInfoBuilder::new()
+            .image(image)
+            .camera_info(camera_info)
+            .build()
+    }
+}
+
+// ── ImageWithInfoBuilder<'a> ────────────────────────────────────────
+
+/// Builder for `ImageWithInfo<Vec<u8>>` with buffer-reuse finalizers.
+pub struct ImageWithInfoBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    height: u32,
+    width: u32,
+    encoding: alloc::borrow::Cow<'a, str>,
+    is_bigendian: u8,
+    step: u32,
+    data: &'a [u8],
+    info_height: u32,
+    info_width: u32,
+    distortion_model: alloc::borrow::Cow<'a, str>,
+    d: alloc::borrow::Cow<'a, [f64]>,
+    k: [f64; 9],
+    r: [f64; 9],
+    p: [f64; 12],
+    binning_x: u32,
+    binning_y: u32,
+    roi: RegionOfInterest,
+}
+
+impl<'a> Default for ImageWithInfoBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            height: 0,
+            width: 0,
+            encoding: alloc::borrow::Cow::Borrowed(""),
+            is_bigendian: 0,
+            step: 0,
+            data: &[],
+            info_height: 0,
+            info_width: 0,
+            distortion_model: alloc::borrow::Cow::Borrowed(""),
+            d: alloc::borrow::Cow::Borrowed(&[]),
+            k: [0.0; 9],
+            r: [0.0; 9],
+            p: [0.0; 12],
+            binning_x: 0,
+            binning_y: 0,
+            roi: RegionOfInterest {
+                x_offset: 0,
+                y_offset: 0,
+                height: 0,
+                width: 0,
+                do_rectify: false,
+            },
+        }
+    }
+}
+
+impl<'a> ImageWithInfoBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+
+    /// Copy every image field, including stamp and frame_id, from `image`.
+    /// Pixel data is borrowed, not copied.
+    pub fn image<B: AsRef<[u8]>>(&mut self, image: &'a Image<B>) -> &mut Self {
+        self.stamp = image.stamp();
+        self.frame_id = image.frame_id().into();
+        self.height = image.height();
+        self.width = image.width();
+        self.encoding = image.encoding().into();
+        self.is_bigendian = image.is_bigendian();
+        self.step = image.step();
+        self.data = image.data();
+        self
+    }
+    pub fn height(&mut self, v: u32) -> &mut Self {
+        self.height = v;
+        self
+    }
+    pub fn width(&mut self, v: u32) -> &mut Self {
+        self.width = v;
+        self
+    }
+    pub fn encoding(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.encoding = s.into();
+        self
+    }
+    pub fn is_bigendian(&mut self, v: u8) -> &mut Self {
+        self.is_bigendian = v;
+        self
+    }
+    pub fn step(&mut self, v: u32) -> &mut Self {
+        self.step = v;
+        self
+    }
+    pub fn data(&mut self, d: &'a [u8]) -> &mut Self {
+        self.data = d;
+        self
+    }
+
+    /// Copy every calibration field from `info`; its stamp and frame_id
+    /// are ignored in favour of the bundle's.
+    pub fn camera_info<B: AsRef<[u8]>>(&mut self, info: &'a CameraInfo<B>) -> &mut Self {
+        self.info_height = info.height();
+        self.info_width = info.width();
+        self.distortion_model = info.distortion_model().into();
+        self.d = (0..info.d_len()).map(|i| info.d_get(i)).collect();
+        self.k = info.k();
+        self.r = info.r();
+        self.p = info.p();
+        self.binning_x = info.binning_x();
+        self.binning_y = info.binning_y();
+        self.roi = info.roi();
+        self
+    }
+    pub fn info_height(&mut self, v: u32) -> &mut Self {
+        self.info_height = v;
+        self
+    }
+    pub fn info_width(&mut self, v: u32) -> &mut Self {
+        self.info_width = v;
+        self
+    }
+    pub fn distortion_model(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.distortion_model = s.into();
+        self
+    }
+    pub fn d(&mut self, d: impl Into<alloc::borrow::Cow<'a, [f64]>>) -> &mut Self {
+        self.d = d.into();
+        self
+    }
+    pub fn k(&mut self, k: [f64; 9]) -> &mut Self {
+        self.k = k;
+        self
+    }
+    pub fn r(&mut self, r: [f64; 9]) -> &mut Self {
+        self.r = r;
+        self
+    }
+    pub fn p(&mut self, p: [f64; 12]) -> &mut Self {
+        self.p = p;
+        self
+    }
+    pub fn binning_x(&mut self, v: u32) -> &mut Self {
+        self.binning_x = v;
+        self
+    }
+    pub fn binning_y(&mut self, v: u32) -> &mut Self {
+        self.binning_y = v;
+        self
+    }
+    pub fn roi(&mut self, r: RegionOfInterest) -> &mut Self {
+        self.roi = r;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_u32();
+        s.size_u32();
+        s.size_string(&self.encoding);
+        s.size_u8();
+        s.size_u32();
+        s.size_bytes(self.data.len());
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_u32();
+        s.size_u32();
+        s.size_string(&self.distortion_model);
+        s.size_u32();
+        s.size_seq_8(self.d.len());
+        size_f64_array9(&mut s);
+        size_f64_array9(&mut s);
+        size_f64_array12(&mut s);
+        s.size_u32();
+        s.size_u32();
+        RegionOfInterest::size_cdr(&mut s);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_u32(self.height);
+        w.write_u32(self.width);
+        w.write_string(&self.encoding);
+        w.write_u8(self.is_bigendian);
+        w.write_u32(self.step);
+        w.write_bytes(self.data);
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_u32(self.info_height);
+        w.write_u32(self.info_width);
+        w.write_string(&self.distortion_model);
+        w.write_u32(self.d.len() as u32);
+        w.write_slice_f64(&self.d);
+        write_f64_array9(&mut w, &self.k);
+        write_f64_array9(&mut w, &self.r);
+        write_f64_array12(&mut w, &self.p);
+        w.write_u32(self.binning_x);
+        w.write_u32(self.binning_y);
+        self.roi.write_cdr(&mut w);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<ImageWithInfo<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        ImageWithInfo::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── FusionDebug<B> — edgefirst_msgs/msg/FusionDebug ─────────────────
+//
+// CDR layout: Header → offsets[0],
+//   detect_stamp(Time) + radar_stamp(Time)
+//   + associations(seq<FusionAssociation>)
+//
+// FusionAssociation element: box_index(u32) + cluster_id(i32) + cost(f32)
+//   + reject_reason(u8) + point_indices(seq<u32>)
+
+pub mod fusion_debug {
+    pub const REJECT_NONE: u8 = 0;
+    pub const REJECT_COST: u8 = 1;
+    pub const REJECT_GATE: u8 = 2;
+    pub const REJECT_DUPLICATE: u8 = 3;
+    pub const REJECT_VELOCITY: u8 = 4;
+
+    /// `cluster_id` of an association built from unclustered points.
+    pub const CLUSTER_NONE: i32 = -1;
+}
+
+crate::cdr_enum! {
+    /// Typed form of the [`fusion_debug`] `REJECT_*` constants.
+    pub enum FusionRejectReason: u8 {
+        /// The candidate was accepted.
+        None = 0,
+        /// Association cost above the acceptance threshold.
+        Cost = 1,
+        /// Outside the spatial gate around the box.
+        Gate = 2,
+        /// Another candidate won the assignment for this box.
+        Duplicate = 3,
+        /// Radial velocity inconsistent with the track.
+        Velocity = 4,
+    }
+}
+
+/// Zero-copy view of a single FusionAssociation element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FusionAssociationView<'a> {
+    pub box_index: u32,
+    pub cluster_id: i32,
+    pub cost: f32,
+    pub reject_reason: u8,
+    pub point_indices: &'a [u32],
+}
+
+impl FusionAssociationView<'_> {
+    /// `true` if fusion kept this candidate.
+    #[inline]
+    pub fn accepted(&self) -> bool {
+        self.reject_reason == fusion_debug::REJECT_NONE
+    }
+}
+
+fn scan_fusion_association<'a>(
+    c: &mut CdrCursor<'a>,
+) -> Result<FusionAssociationView<'a>, CdrError> {
+    let box_index = c.read_u32()?;
+    let cluster_id = c.read_i32()?;
+    let cost = c.read_f32()?;
+    let reject_reason = c.read_u8()?;
+    let raw = c.read_u32()?;
+    let count = c.check_seq_count(raw, 4)?;
+    let bytes = c.read_raw(count * 4)?;
+    Ok(FusionAssociationView {
+        box_index,
+        cluster_id,
+        cost,
+        reject_reason,
+        point_indices: rd_slice_u32(bytes, 0, count),
+    })
+}
+
+pub struct FusionDebug<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> FusionDebug<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FusionDebug<C> {
+        FusionDebug {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FusionDebug<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        Time::read_cdr(&mut c).field("detect_stamp")?;
+        Time::read_cdr(&mut c).field("radar_stamp")?;
+        let raw = c.read_u32()?;
+        // min association size: 3 × 4 + u8 (padded to 4) + empty seq (4) = 20 bytes
+        let count = c.check_seq_count(raw, 20)?;
+        for _ in 0..count {
+            scan_fusion_association(&mut c).field("associations")?;
+        }
+        c.expect_end()?;
+        Ok(FusionDebug { offsets: [o0], buf })
+    }
+
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    /// Stamp of the Detect message whose boxes are indexed by `box_index`.
+    #[inline]
+    pub fn detect_stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), align(self.offsets[0], 4))
+    }
+    /// Stamp of the radar PointCloud2 indexed by `point_indices`.
+    #[inline]
+    pub fn radar_stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), align(self.offsets[0], 4) + 8)
+    }
+
+    pub fn associations_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[0], 4) + 16)
+    }
+
+    pub fn associations(&self) -> Vec<FusionAssociationView<'_>> {
+        let b = self.buf.as_ref();
+        let p = align(self.offsets[0], 4) + 16;
+        let count = rd_u32(b, p) as usize;
+        let mut c = CdrCursor::resume(b, p + 4);
+        (0..count)
+            .map(|_| {
+                scan_fusion_association(&mut c)
+                    .expect("association elements validated during from_cdr")
+            })
+            .collect()
+    }
+
+    /// Every candidate considered for detection box `box_index`, accepted
+    /// or not, in publication order.
+    pub fn candidates(&self, box_index: u32) -> Vec<FusionAssociationView<'_>> {
+        self.associations()
+            .into_iter()
+            .filter(|a| a.box_index == box_index)
+            .collect()
+    }
+
+    /// The candidate fusion kept for detection box `box_index`, if any.
+    pub fn accepted(&self, box_index: u32) -> Option<FusionAssociationView<'_>> {
+        self.associations()
+            .into_iter()
+            .find(|a| a.box_index == box_index && a.accepted())
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FusionDebug<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FusionDebugBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> FusionDebugBuilder<'a> {
+        FusionDebugBuilder::new()
+    }
+}
+
+// ── FusionDebugBuilder<'a> ──────────────────────────────────────────
+
+/// Builder for `FusionDebug<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `associations` is borrowed from a caller-owned slice; the borrow must
+/// remain valid until `build()`, `encode_into_vec()`, or
+/// `encode_into_slice()` is called.
+pub struct FusionDebugBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    detect_stamp: Time,
+    radar_stamp: Time,
+    associations: &'a [FusionAssociationView<'a>],
+}
+
+impl<'a> Default for FusionDebugBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            detect_stamp: Time { sec: 0, nanosec: 0 },
+            radar_stamp: Time { sec: 0, nanosec: 0 },
+            associations: &[],
+        }
+    }
+}
+
+impl<'a> FusionDebugBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn detect_stamp(&mut self, t: Time) -> &mut Self {
+        self.detect_stamp = t;
+        self
+    }
+    pub fn radar_stamp(&mut self, t: Time) -> &mut Self {
+        self.radar_stamp = t;
+        self
+    }
+    pub fn associations(&mut self, v: &'a [FusionAssociationView<'a>]) -> &mut Self {
+        self.associations = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        Time::size_cdr(&mut s);
+        Time::size_cdr(&mut s);
+        s.size_u32();
+        for a in self.associations {
+            s.size_u32();
+            s.size_i32();
+            s.size_f32();
+            s.size_u8();
+            s.size_u32();
+            s.size_seq_4(a.point_indices.len());
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        self.detect_stamp.write_cdr(&mut w);
+        self.radar_stamp.write_cdr(&mut w);
+        w.write_u32(self.associations.len() as u32);
+        for a in self.associations {
+            w.write_u32(a.box_index);
+            w.write_i32(a.cluster_id);
+            w.write_f32(a.cost);
+            w.write_u8(a.reject_reason);
+            w.write_u32(a.point_indices.len() as u32);
+            w.write_slice_u32(a.point_indices);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FusionDebug<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FusionDebug::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FusionDebug<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── ServiceResult<B> — edgefirst_msgs/msg/ServiceResult ─────────────
+//
+// Uniform response envelope for EdgeFirst Zenoh services. The service's
+// own response message travels CDR-encoded (encapsulation header included)
+// in `payload`, so any message type can be carried.
+//
+// CDR layout (no Header):
+//   4: ok(bool)   8: code(u32)
+//  12: message(string) → offsets[0]
+//   ~: payload(seq<u8>)
+
+/// `code` values for [`ServiceResult`].
+pub mod service_result {
+    pub const CODE_OK: u32 = 0;
+    pub const CODE_INVALID_ARGUMENT: u32 = 1;
+    pub const CODE_NOT_FOUND: u32 = 2;
+    pub const CODE_UNAVAILABLE: u32 = 3;
+    pub const CODE_TIMEOUT: u32 = 4;
+    pub const CODE_BUSY: u32 = 5;
+    pub const CODE_UNSUPPORTED: u32 = 6;
+    pub const CODE_PERMISSION_DENIED: u32 = 7;
+    pub const CODE_INTERNAL: u32 = 8;
+}
+
+crate::cdr_enum! {
+    /// Typed form of the [`service_result`] codes.
+    pub enum ServiceErrorCode: u32 {
+        Ok = 0,
+        /// The request was malformed or a value was out of range.
+        InvalidArgument = 1,
+        /// The request named something the service does not have.
+        NotFound = 2,
+        /// A device or dependency the service needs is not available.
+        Unavailable = 3,
+        Timeout = 4,
+        /// The service is handling another request; retrying may succeed.
+        Busy = 5,
+        Unsupported = 6,
+        PermissionDenied = 7,
+        Internal = 8,
+    }
+}
+
+/// A failed [`ServiceResult`], or one whose payload could not be decoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceError {
+    /// Raw code as sent, see [`service_result`].
+    pub code: u32,
+    pub message: String,
+}
+
+impl ServiceError {
+    /// The code as a [`ServiceErrorCode`], `None` for codes newer than this
+    /// crate.
+    pub fn kind(&self) -> Option<ServiceErrorCode> {
+        ServiceErrorCode::from_repr(self.code)
+    }
+}
+
+impl core::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind() {
+            Some(kind) => write!(f, "service error {:?}: {}", kind, self.message),
+            None => write!(f, "service error {}: {}", self.code, self.message),
+        }
+    }
+}
+
+impl core::error::Error for ServiceError {}
+
+pub struct ServiceResult<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> ServiceResult<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> ServiceResult<C> {
+        ServiceResult {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> ServiceResult<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        c.read_bool().field("ok")?;
+        c.read_u32().field("code")?;
+        let _ = c.read_string().field("message")?;
+        let o0 = c.offset();
+        let _ = c.read_bytes().field("payload")?;
+        c.expect_end()?;
+        Ok(ServiceResult { offsets: [o0], buf })
+    }
+
+    #[inline]
+    pub fn ok(&self) -> bool {
+        rd_bool(self.buf.as_ref(), 4)
+    }
+    #[inline]
+    pub fn code(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), 8)
+    }
+    #[inline]
+    pub fn message(&self) -> &str {
+        rd_string(self.buf.as_ref(), 12).0
+    }
+    /// CDR-encoded response body, empty on failure.
+    #[inline]
+    pub fn payload(&self) -> &[u8] {
+        rd_bytes(self.buf.as_ref(), align(self.offsets[0], 4)).0
+    }
+
+    /// `Ok(payload)` on success, otherwise the code and message as a
+    /// [`ServiceError`].
+    pub fn result(&self) -> Result<&[u8], ServiceError> {
+        if self.ok() {
+            Ok(self.payload())
+        } else {
+            Err(ServiceError {
+                code: self.code(),
+                message: self.message().to_owned(),
+            })
+        }
+    }
+
+    /// Decode the payload of a successful result with `decode`, typically
+    /// a message's `from_cdr` or [`decode_fixed`]. A payload that fails to
+    /// decode is reported as [`ServiceErrorCode::Internal`].
+    pub fn decode<'s, T>(
+        &'s self,
+        decode: impl FnOnce(&'s [u8]) -> Result<T, CdrError>,
+    ) -> Result<T, ServiceError> {
+        decode(self.result()?).map_err(|e| ServiceError {
+            code: service_result::CODE_INTERNAL,
+            message: format!("undecodable payload: {}", e),
+        })
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl ServiceResult<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `ServiceResultBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> ServiceResultBuilder<'a> {
+        ServiceResultBuilder::new()
+    }
+
+    /// A successful result carrying `body`, which may be any message
+    /// builder or `CdrFixed` value.
+    pub fn success<T: CdrEncode + ?Sized>(body: &T) -> Result<Self, CdrError> {
+        let mut payload = vec![0u8; body.cdr_size()];
+        body.encode_into_slice(&mut payload)?;
+        ServiceResultBuilder::new().payload(&payload).build()
+    }
+
+    /// A failed result with an empty payload.
+    pub fn failure(code: ServiceErrorCode, message: &str) -> Result<Self, CdrError> {
+        ServiceResultBuilder::new()
+            .code(code.into())
+            .message(message)
+            .build()
+    }
+}
+
+// ── ServiceResultBuilder<'a> ────────────────────────────────────────
+
+/// Builder for `ServiceResult<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `ok` is not set directly: it is written as `code == CODE_OK`.
+#[derive(Default)]
+pub struct ServiceResultBuilder<'a> {
+    code: u32,
+    message: alloc::borrow::Cow<'a, str>,
+    payload: &'a [u8],
+}
+
+impl<'a> ServiceResultBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn code(&mut self, v: u32) -> &mut Self {
+        self.code = v;
+        self
+    }
+    pub fn message(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.message = s.into();
+        self
+    }
+    pub fn payload(&mut self, v: &'a [u8]) -> &mut Self {
+        self.payload = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        s.size_bool();
+        s.size_u32();
+        s.size_string(&self.message);
+        s.size_bytes(self.payload.len());
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        w.write_bool(self.code == service_result::CODE_OK);
+        w.write_u32(self.code);
+        w.write_string(&self.message);
+        w.write_bytes(self.payload);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<ServiceResult<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        ServiceResult::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── SetRadarProfile — edgefirst_msgs/srv/SetRadarProfile ────────────
+//
+// Runtime radar waveform reconfiguration. The effective configuration is
+// reported on the RadarInfo topic once applied.
+//
+// Request CDR layout (no Header):
+//   4: start_frequency_ghz(f32)   8: bandwidth_mhz(f32)
+//  12: chirp_duration_us(f32)    16: frame_period_ms(f32)
+//  20: chirps_per_frame(u32)     24: profile(u8)   25: cube(bool)
+//  26: center_frequency(string) → offsets[0]
+//   ~: range_toggle(string) → offsets[1]
+//   ~: detection_sensitivity(string)
+//
+// Response CDR layout:
+//   4: success(bool)   5: error(u8)   6: profile(u8)
+//   7: message(string)
+
+/// `profile` and `error` values for [`SetRadarProfileRequest`] /
+/// [`SetRadarProfileResponse`].
+pub mod set_radar_profile {
+    /// Chirp parameters in the request are used as given.
+    pub const PROFILE_CUSTOM: u8 = 0;
+    pub const PROFILE_ULTRA_SHORT: u8 = 1;
+    pub const PROFILE_SHORT: u8 = 2;
+    pub const PROFILE_MEDIUM: u8 = 3;
+    pub const PROFILE_LONG: u8 = 4;
+
+    pub const ERROR_NONE: u8 = 0;
+    pub const ERROR_UNSUPPORTED_PROFILE: u8 = 1;
+    pub const ERROR_INVALID_CHIRP: u8 = 2;
+    pub const ERROR_INVALID_SETTING: u8 = 3;
+    pub const ERROR_INCOMPATIBLE_FREQUENCY: u8 = 4;
+    pub const ERROR_BUSY: u8 = 5;
+    pub const ERROR_DEVICE: u8 = 6;
+}
+
+pub struct SetRadarProfileRequest<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
+
+impl<B> SetRadarProfileRequest<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> SetRadarProfileRequest<C> {
+        SetRadarProfileRequest {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> SetRadarProfileRequest<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        c.read_f32().field("start_frequency_ghz")?;
+        c.read_f32().field("bandwidth_mhz")?;
+        c.read_f32().field("chirp_duration_us")?;
+        c.read_f32().field("frame_period_ms")?;
+        c.read_u32().field("chirps_per_frame")?;
+        c.read_u8().field("profile")?;
+        c.read_bool().field("cube")?;
+        let _ = c.read_string()?;
+        let o0 = c.offset();
+        let _ = c.read_string()?;
+        let o1 = c.offset();
+        let _ = c.read_string()?;
+        c.expect_end()?;
+        Ok(SetRadarProfileRequest {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
+
+    #[inline]
+    pub fn start_frequency_ghz(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), 4)
+    }
+    #[inline]
+    pub fn bandwidth_mhz(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), 8)
+    }
+    #[inline]
+    pub fn chirp_duration_us(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), 12)
+    }
+    #[inline]
+    pub fn frame_period_ms(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), 16)
+    }
+    #[inline]
+    pub fn chirps_per_frame(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), 20)
+    }
+    #[inline]
+    pub fn profile(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), 24)
+    }
+    #[inline]
+    pub fn cube(&self) -> bool {
+        rd_bool(self.buf.as_ref(), 25)
+    }
+    #[inline]
+    pub fn center_frequency(&self) -> &str {
+        rd_string(self.buf.as_ref(), 26).0
+    }
+    #[inline]
+    pub fn range_toggle(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+    #[inline]
+    pub fn detection_sensitivity(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[1]).0
+    }
+
+    /// Check the request against the values documented in
+    /// `SetRadarProfile.srv`, returning the `set_radar_profile::ERROR_*`
+    /// code a server should reply with. Empty strings mean "unchanged".
+    pub fn validate(&self) -> Result<(), u8> {
+        use set_radar_profile::*;
+        if self.profile() > PROFILE_LONG {
+            return Err(ERROR_UNSUPPORTED_PROFILE);
+        }
+        if self.profile() == PROFILE_CUSTOM {
+            let positive = |v: f32| v.is_finite() && v > 0.0;
+            if !(positive(self.start_frequency_ghz())
+                && positive(self.bandwidth_mhz())
+                && positive(self.chirp_duration_us())
+                && positive(self.frame_period_ms())
+                && self.chirps_per_frame() > 0)
+            {
+                return Err(ERROR_INVALID_CHIRP);
+            }
+        }
+        let level = |s: &str| matches!(s, "" | "low" | "medium" | "high");
+        let toggle = matches!(
+            self.range_toggle(),
+            "" | "off"
+                | "short-medium"
+                | "short-long"
+                | "medium-long"
+                | "long-ultra-short"
+                | "medium-ultra-short"
+                | "short-ultra-short"
+        );
+        if !level(self.center_frequency()) || !level(self.detection_sensitivity()) || !toggle {
+            return Err(ERROR_INVALID_SETTING);
+        }
+        // Ultra-short sweeps need the low center frequency (see RadarInfo).
+        if self.profile() == PROFILE_ULTRA_SHORT && !matches!(self.center_frequency(), "" | "low") {
+            return Err(ERROR_INCOMPATIBLE_FREQUENCY);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl SetRadarProfileRequest<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `SetRadarProfileRequestBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> SetRadarProfileRequestBuilder<'a> {
+        SetRadarProfileRequestBuilder::new()
+    }
+}
+
+// ── SetRadarProfileRequestBuilder<'a> ───────────────────────────────
+
+/// Builder for `SetRadarProfileRequest<Vec<u8>>` with buffer-reuse
+/// finalizers.
+#[derive(Default)]
+pub struct SetRadarProfileRequestBuilder<'a> {
+    start_frequency_ghz: f32,
+    bandwidth_mhz: f32,
+    chirp_duration_us: f32,
+    frame_period_ms: f32,
+    chirps_per_frame: u32,
+    profile: u8,
+    cube: bool,
+    center_frequency: alloc::borrow::Cow<'a, str>,
+    range_toggle: alloc::borrow::Cow<'a, str>,
+    detection_sensitivity: alloc::borrow::Cow<'a, str>,
+}
+
+impl<'a> SetRadarProfileRequestBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start_frequency_ghz(&mut self, v: f32) -> &mut Self {
+        self.start_frequency_ghz = v;
+        self
+    }
+    pub fn bandwidth_mhz(&mut self, v: f32) -> &mut Self {
+        self.bandwidth_mhz = v;
+        self
+    }
+    pub fn chirp_duration_us(&mut self, v: f32) -> &mut Self {
+        self.chirp_duration_us = v;
+        self
+    }
+    pub fn frame_period_ms(&mut self, v: f32) -> &mut Self {
+        self.frame_period_ms = v;
+        self
+    }
+    pub fn chirps_per_frame(&mut self, v: u32) -> &mut Self {
+        self.chirps_per_frame = v;
+        self
+    }
+    pub fn profile(&mut self, v: u8) -> &mut Self {
+        self.profile = v;
+        self
+    }
+    pub fn cube(&mut self, v: bool) -> &mut Self {
+        self.cube = v;
+        self
+    }
+    pub fn center_frequency(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.center_frequency = s.into();
+        self
+    }
+    pub fn range_toggle(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.range_toggle = s.into();
+        self
+    }
+    pub fn detection_sensitivity(
+        &mut self,
+        s: impl Into<alloc::borrow::Cow<'a, str>>,
+    ) -> &mut Self {
+        self.detection_sensitivity = s.into();
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        s.size_f32();
+        s.size_f32();
+        s.size_f32();
+        s.size_f32();
+        s.size_u32();
+        s.size_u8();
+        s.size_bool();
+        s.size_string(&self.center_frequency);
+        s.size_string(&self.range_toggle);
+        s.size_string(&self.detection_sensitivity);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        w.write_f32(self.start_frequency_ghz);
+        w.write_f32(self.bandwidth_mhz);
+        w.write_f32(self.chirp_duration_us);
+        w.write_f32(self.frame_period_ms);
+        w.write_u32(self.chirps_per_frame);
+        w.write_u8(self.profile);
+        w.write_bool(self.cube);
+        w.write_string(&self.center_frequency);
+        w.write_string(&self.range_toggle);
+        w.write_string(&self.detection_sensitivity);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<SetRadarProfileRequest<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        SetRadarProfileRequest::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+pub struct SetRadarProfileResponse<B> {
+    buf: B,
+}
+
+impl<B> SetRadarProfileResponse<B> {
+    /// Convert the buffer type without re-parsing.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> SetRadarProfileResponse<C> {
+        SetRadarProfileResponse { buf: f(self.buf) }
+    }
+}
+
+impl<B: AsRef<[u8]>> SetRadarProfileResponse<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        c.read_bool().field("success")?;
+        c.read_u8().field("error")?;
+        c.read_u8().field("profile")?;
+        let _ = c.read_string().field("message")?;
+        c.expect_end()?;
+        Ok(SetRadarProfileResponse { buf })
+    }
+
+    #[inline]
+    pub fn success(&self) -> bool {
+        rd_bool(self.buf.as_ref(), 4)
+    }
+    #[inline]
+    pub fn error(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), 5)
+    }
+    /// Profile in effect after the request was handled.
+    #[inline]
+    pub fn profile(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), 6)
+    }
+    #[inline]
+    pub fn message(&self) -> &str {
+        rd_string(self.buf.as_ref(), 7).0
+    }
+
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl SetRadarProfileResponse<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `SetRadarProfileResponseBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> SetRadarProfileResponseBuilder<'a> {
+        SetRadarProfileResponseBuilder::new()
+    }
+}
+
+// ── SetRadarProfileResponseBuilder<'a> ──────────────────────────────
+
+/// Builder for `SetRadarProfileResponse<Vec<u8>>` with buffer-reuse
+/// finalizers.
+#[derive(Default)]
+pub struct SetRadarProfileResponseBuilder<'a> {
+    success: bool,
+    error: u8,
+    profile: u8,
+    message: alloc::borrow::Cow<'a, str>,
+}
+
+impl<'a> SetRadarProfileResponseBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn success(&mut self, v: bool) -> &mut Self {
+        self.success = v;
+        self
+    }
+    pub fn error(&mut self, v: u8) -> &mut Self {
+        self.error = v;
+        self
+    }
+    pub fn profile(&mut self, v: u8) -> &mut Self {
+        self.profile = v;
+        self
+    }
+    pub fn message(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.message = s.into();
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        s.size_bool();
+        s.size_u8();
+        s.size_u8();
+        s.size_string(&self.message);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        w.write_bool(self.success);
+        w.write_u8(self.error);
+        w.write_u8(self.profile);
+        w.write_string(&self.message);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<SetRadarProfileResponse<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        SetRadarProfileResponse::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── Aggregator ──────────────────────────────────────────────────────
+
+/// A named rectangular zone in the normalized box coordinate space used by
+/// [`DetectBoxView`] (`0.0..=1.0` on both axes).
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregateZoneDef {
+    pub name: String,
+    pub x_min: f32,
+    pub y_min: f32,
+    pub x_max: f32,
+    pub y_max: f32,
+}
+
+impl AggregateZoneDef {
+    fn contains(&self, b: &DetectBoxView<'_>) -> bool {
+        (self.x_min..=self.x_max).contains(&b.center_x)
+            && (self.y_min..=self.y_max).contains(&b.center_y)
+    }
+}
+
+#[derive(Default)]
+struct ZoneAcc {
+    /// track id → (first, last) stamp in seconds, for tracks currently inside.
+    inside: alloc::collections::BTreeMap<String, (f64, f64)>,
+    dwell_sum: f64,
+    visits: u32,
+    occupancy: u32,
+}
+
+/// Folds a stream of [`Detect`] messages into [`Aggregate`] windows.
+///
+/// Feed every frame with [`push`](Self::push); call
+/// [`finish`](Self::finish) at the end of each window to produce the
+/// message and start the next window. Tracks still inside a zone when the
+/// window closes contribute their dwell so far.
+pub struct Aggregator {
+    zones: Vec<AggregateZoneDef>,
+    acc: Vec<ZoneAcc>,
+    tracks: alloc::collections::BTreeMap<String, alloc::collections::BTreeSet<String>>,
+    untracked_peak: alloc::collections::BTreeMap<String, u32>,
+    window: Option<(Time, Time)>,
+}
+
+fn time_secs(t: Time) -> f64 {
+    t.sec as f64 + t.nanosec as f64 * 1e-9
+}
+
+impl Aggregator {
+    pub fn new(zones: Vec<AggregateZoneDef>) -> Self {
+        let acc = zones.iter().map(|_| ZoneAcc::default()).collect();
+        Aggregator {
+            zones,
+            acc,
+            tracks: Default::default(),
+            untracked_peak: Default::default(),
+            window: None,
+        }
+    }
+
+    /// Account for one Detect frame.
+    pub fn push<B: AsRef<[u8]>>(&mut self, detect: &Detect<B>) {
+        let stamp = detect.stamp();
+        self.window = Some(match self.window {
+            Some((start, _)) => (start, stamp),
+            None => (stamp, stamp),
+        });
+        let now = time_secs(stamp);
+        let boxes = detect.boxes();
+
+        let mut untracked = alloc::collections::BTreeMap::<&str, u32>::new();
+        for b in &boxes {
+            if b.track_id.is_empty() {
+                *untracked.entry(b.label).or_default() += 1;
+            } else {
+                self.tracks
+                    .entry(b.label.to_owned())
+                    .or_default()
+                    .insert(b.track_id.to_owned());
+            }
+        }
+        for (label, n) in untracked {
+            let peak = self.untracked_peak.entry(label.to_owned()).or_default();
+            *peak = (*peak).max(n);
+        }
+
+        for (zone, acc) in self.zones.iter().zip(&mut self.acc) {
+            let mut seen = alloc::collections::BTreeSet::new();
+            acc.occupancy = 0;
+            for b in boxes.iter().filter(|b| zone.contains(b)) {
+                acc.occupancy += 1;
+                if b.track_id.is_empty() {
+                    continue;
+                }
+                seen.insert(b.track_id);
+                match acc.inside.get_mut(b.track_id) {
+                    Some(span) => span.1 = now,
+                    None => {
+                        acc.visits += 1;
+                        acc.inside.insert(b.track_id.to_owned(), (now, now));
+                    }
+                }
+            }
+            let mut dwell = 0.0;
+            acc.inside.retain(|id, (first, last)| {
+                let keep = seen.contains(id.as_str());
+                if !keep {
+                    dwell += *last - *first;
+                }
+                keep
+            });
+            acc.dwell_sum += dwell;
+        }
+    }
+
+    /// Close the current window and encode it as an `Aggregate` stamped
+    /// with `stamp`. Counts are sorted by label; zones keep their
+    /// configured order. Returns `None` if no frame was pushed.
+    pub fn finish(
+        &mut self,
+        stamp: Time,
+        frame_id: &str,
+    ) -> Option<Result<Aggregate<Vec<u8>>, CdrError>> {
+        let (start, end) = self.window.take()?;
+
+        let mut labels: alloc::collections::BTreeMap<&str, u32> = self
+            .tracks
+            .iter()
+            .map(|(l, ids)| (l.as_str(), ids.len() as u32))
+            .collect();
+        for (label, peak) in &self.untracked_peak {
+            *labels.entry(label.as_str()).or_default() += peak;
+        }
+        let counts: Vec<AggregateCountView<'_>> = labels
+            .into_iter()
+            .map(|(label, count)| AggregateCountView { label, count })
+            .collect();
+
+        let zones: Vec<AggregateZoneView<'_>> = self
+            .zones
+            .iter()
+            .zip(&self.acc)
+            .map(|(z, acc)| {
+                let open: f64 = acc.inside.values().map(|(f, l)| l - f).sum();
+                let avg_dwell = if acc.visits == 0 {
+                    f32::NAN
+                } else {
+                    ((acc.dwell_sum + open) / acc.visits as f64) as f32
+                };
+                AggregateZoneView {
+                    zone: &z.name,
+                    occupancy: acc.occupancy,
+                    visits: acc.visits,
+                    avg_dwell,
+                }
+            })
+            .collect();
+
+        let msg = Aggregate::builder()
+            .stamp(stamp)
+            .frame_id(frame_id)
+            .window_start(start)
+            .window_end(end)
+            .counts(&counts)
+            .zones(&zones)
+            .build();
+
+        self.tracks.clear();
+        self.untracked_peak.clear();
+        for acc in &mut self.acc {
+            // Tracks still inside carry over into the next window as fresh
+            // visits starting from their last sighting.
+            for span in acc.inside.values_mut() {
+                span.0 = span.1;
+            }
+            acc.visits = acc.inside.len() as u32;
+            acc.dwell_sum = 0.0;
+        }
+        Some(msg)
+    }
+}
+
+// ── Registry ────────────────────────────────────────────────────────
+
+/// Check if a type name is supported by this module.
+pub fn is_type_supported(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "Aggregate"
+            | "AggregateCount"
+            | "AggregateZone"
+            | "Attribute"
+            | "AttributeEntry"
+            | "BlobRef"
+            | "Box"
+            | "CameraControl"
+            | "CameraFrame"
+            | "CameraPlane"
+            | "CameraStatus"
+            | "Date"
+            | "Detect"
+            | "DmaBuffer"
+            | "FusionAssociation"
+            | "FusionDebug"
+            | "Heartbeat"
+            | "ImageWithInfo"
+            | "LocalTime"
+            | "Mask"
+            | "Model"
+            | "ModelInfo"
+            | "Provenance"
+            | "ProvenanceInput"
+            | "RadarCube"
+            | "RadarInfo"
+            | "ServiceResult"
+            | "ShmRing"
+            | "Track"
+            | "Vibration"
+            | "VideoStreamInfo"
+            | "VideoStreamRequest"
+            | "VideoStreamVariant"
+    )
+}
+
+/// List all type schema names in this module.
+pub fn list_types() -> &'static [&'static str] {
+    &[
+        "edgefirst_msgs/msg/Aggregate",
+        "edgefirst_msgs/msg/AggregateCount",
+        "edgefirst_msgs/msg/AggregateZone",
+        "edgefirst_msgs/msg/Attribute",
+        "edgefirst_msgs/msg/AttributeEntry",
+        "edgefirst_msgs/msg/BlobRef",
+        "edgefirst_msgs/msg/Box",
+        "edgefirst_msgs/msg/CameraControl",
+        "edgefirst_msgs/msg/CameraFrame",
+        "edgefirst_msgs/msg/CameraPlane",
+        "edgefirst_msgs/msg/CameraStatus",
+        "edgefirst_msgs/msg/Date",
+        "edgefirst_msgs/msg/Detect",
+        "edgefirst_msgs/msg/DmaBuffer",
+        "edgefirst_msgs/msg/FusionAssociation",
+        "edgefirst_msgs/msg/FusionDebug",
+        "edgefirst_msgs/msg/Heartbeat",
+        "edgefirst_msgs/msg/ImageWithInfo",
+        "edgefirst_msgs/msg/LocalTime",
+        "edgefirst_msgs/msg/Mask",
+        "edgefirst_msgs/msg/Model",
+        "edgefirst_msgs/msg/ModelInfo",
+        "edgefirst_msgs/msg/Provenance",
+        "edgefirst_msgs/msg/ProvenanceInput",
+        "edgefirst_msgs/msg/RadarCube",
+        "edgefirst_msgs/msg/RadarInfo",
+        "edgefirst_msgs/msg/ServiceResult",
+        "edgefirst_msgs/msg/ShmRing",
+        "edgefirst_msgs/msg/Track",
+        "edgefirst_msgs/msg/Vibration",
+        "edgefirst_msgs/msg/VideoStreamInfo",
+        "edgefirst_msgs/msg/VideoStreamRequest",
+        "edgefirst_msgs/msg/VideoStreamVariant",
+    ]
+}
+
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "Aggregate" => include_str!("../edgefirst_msgs/msg/Aggregate.msg"),
+        "AggregateCount" => include_str!("../edgefirst_msgs/msg/AggregateCount.msg"),
+        "AggregateZone" => include_str!("../edgefirst_msgs/msg/AggregateZone.msg"),
+        "Attribute" => include_str!("../edgefirst_msgs/msg/Attribute.msg"),
+        "AttributeEntry" => include_str!("../edgefirst_msgs/msg/AttributeEntry.msg"),
+        "BlobRef" => include_str!("../edgefirst_msgs/msg/BlobRef.msg"),
+        "Box" => include_str!("../edgefirst_msgs/msg/Box.msg"),
+        "CameraControl" => include_str!("../edgefirst_msgs/msg/CameraControl.msg"),
+        "CameraFrame" => include_str!("../edgefirst_msgs/msg/CameraFrame.msg"),
+        "CameraPlane" => include_str!("../edgefirst_msgs/msg/CameraPlane.msg"),
+        "CameraStatus" => include_str!("../edgefirst_msgs/msg/CameraStatus.msg"),
+        "Date" => include_str!("../edgefirst_msgs/msg/Date.msg"),
+        "Detect" => include_str!("../edgefirst_msgs/msg/Detect.msg"),
+        "DmaBuffer" => include_str!("../edgefirst_msgs/msg/DmaBuffer.msg"),
+        "FusionAssociation" => include_str!("../edgefirst_msgs/msg/FusionAssociation.msg"),
+        "FusionDebug" => include_str!("../edgefirst_msgs/msg/FusionDebug.msg"),
+        "Heartbeat" => include_str!("../edgefirst_msgs/msg/Heartbeat.msg"),
+        "ImageWithInfo" => include_str!("../edgefirst_msgs/msg/ImageWithInfo.msg"),
+        "LocalTime" => include_str!("../edgefirst_msgs/msg/LocalTime.msg"),
+        "Mask" => include_str!("../edgefirst_msgs/msg/Mask.msg"),
+        "Model" => include_str!("../edgefirst_msgs/msg/Model.msg"),
+        "ModelInfo" => include_str!("../edgefirst_msgs/msg/ModelInfo.msg"),
+        "Provenance" => include_str!("../edgefirst_msgs/msg/Provenance.msg"),
+        "ProvenanceInput" => include_str!("../edgefirst_msgs/msg/ProvenanceInput.msg"),
+        "RadarCube" => include_str!("../edgefirst_msgs/msg/RadarCube.msg"),
+        "RadarInfo" => include_str!("../edgefirst_msgs/msg/RadarInfo.msg"),
+        "ServiceResult" => include_str!("../edgefirst_msgs/msg/ServiceResult.msg"),
+        "ShmRing" => include_str!("../edgefirst_msgs/msg/ShmRing.msg"),
+        "Track" => include_str!("../edgefirst_msgs/msg/Track.msg"),
+        "Vibration" => include_str!("../edgefirst_msgs/msg/Vibration.msg"),
+        "VideoStreamInfo" => include_str!("../edgefirst_msgs/msg/VideoStreamInfo.msg"),
+        "VideoStreamRequest" => include_str!("../edgefirst_msgs/msg/VideoStreamRequest.msg"),
+        "VideoStreamVariant" => include_str!("../edgefirst_msgs/msg/VideoStreamVariant.msg"),
+        _ => return None,
+    })
+}
+
+// CdrEncode implementations
+crate::cdr::impl_cdr_encode!(
+    MaskBuilder,
+    LocalTimeBuilder,
+    RadarCubeBuilder,
+    RadarInfoBuilder,
+    TrackBuilder,
+    DetectBoxBuilder,
+    DetectBuilder,
+    CameraFrameBuilder,
+    ModelBuilder,
+    ModelInfoBuilder,
+    VibrationBuilder,
+    AggregateBuilder,
+    AttributeBuilder,
+    VideoStreamRequestBuilder,
+    VideoStreamInfoBuilder,
+    CameraControlBuilder,
+    CameraStatusBuilder,
+    ProvenanceBuilder,
+    ShmRingBuilder,
+    HeartbeatBuilder,
+    BlobRefBuilder,
+    ImageWithInfoBuilder,
+    FusionDebugBuilder,
+    ServiceResultBuilder,
+    SetRadarProfileRequestBuilder,
+    SetRadarProfileResponseBuilder,
+);
+
+// HeapSize implementations
+crate::heap_size::impl_heap_size!(
+    Mask,
+    DmaBuffer,
+    LocalTime,
+    RadarCube,
+    RadarInfo,
+    Track,
+    DetectBox,
+    Detect,
+    CameraFrame,
+    Model,
+    ModelInfo,
+    Vibration,
+    Aggregate,
+    Attribute,
+    VideoStreamRequest,
+    VideoStreamInfo,
+    CameraControl,
+    CameraStatus,
+    Provenance,
+    ShmRing,
+    Heartbeat,
+    BlobRef,
+    ImageWithInfo,
+    FusionDebug,
+    ServiceResult,
+    SetRadarProfileRequest,
+    SetRadarProfileResponse,
+);
+
+// SchemaType implementations
+use crate::schema_registry::SchemaType;
+
+impl SchemaType for Date {
+    const SCHEMA_NAME: &'static str = "edgefirst_msgs/msg/Date";
+}
+
+// DDS key fields
+use crate::dds_key::{DdsKeyed, KeyWriter};
+
+impl<B: AsRef<[u8]>> DdsKeyed for Track<B> {
+    const KEY_MAX_SIZE: Option<usize> = None;
+    fn write_key(&self, w: &mut KeyWriter) {
+        w.write_string(self.id());
+    }
+}
+
+impl<B: AsRef<[u8]>> DdsKeyed for Heartbeat<B> {
+    const KEY_MAX_SIZE: Option<usize> = None;
+    fn write_key(&self, w: &mut KeyWriter) {
+        w.write_string(self.service());
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)] // Tests exercise CameraFrame::new, which is deprecated in 3.2.0 but still supported until 4.0.
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+    use crate::cdr::{decode_fixed, encode_fixed};
+
+    #[test]
+    fn date_roundtrip() {
+        let cases = [
+            (2025, 1, 27, "typical"),
+            (2000, 12, 31, "end of year"),
+            (1970, 1, 1, "unix epoch"),
+        ];
+        for (year, month, day, name) in cases {
+            let date = Date { year, month, day };
+            let bytes = encode_fixed(&date).unwrap();
+            let decoded: Date = decode_fixed(&bytes).unwrap();
+            assert_eq!(date, decoded, "failed for case: {}", name);
+        }
+    }
+
+    #[test]
+    fn mask_roundtrip() {
+        let mask = Mask::new(480, 640, 0, "", &vec![0u8; 480 * 640], false).unwrap();
+        assert_eq!(mask.height(), 480);
+        assert_eq!(mask.width(), 640);
+        assert_eq!(mask.length(), 0);
+        assert_eq!(mask.encoding(), "");
+        assert_eq!(mask.mask_data().len(), 480 * 640);
+        assert!(!mask.boxed());
+
+        let bytes = mask.to_cdr();
+        let decoded = Mask::from_cdr(bytes).unwrap();
+        assert_eq!(decoded.height(), 480);
+        assert_eq!(decoded.width(), 640);
+
+        // Compressed mask
+        let compressed = Mask::new(1080, 1920, 5, "zstd", &[1, 2, 3, 4, 5], true).unwrap();
+        assert_eq!(compressed.encoding(), "zstd");
+        assert_eq!(compressed.mask_data(), &[1, 2, 3, 4, 5]);
+        assert!(compressed.boxed());
+
+        let bytes = compressed.to_cdr();
+        let decoded = Mask::from_cdr(bytes).unwrap();
+        assert_eq!(decoded.encoding(), "zstd");
+        assert!(decoded.boxed());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn dmabuf_roundtrip() {
+        let dmabuf = DmaBuffer::new(
+            Time::new(100, 0),
+            "camera",
+            12345,
+            42,
+            1920,
+            1080,
+            1920 * 3,
+            0x34325247,
+            1920 * 1080 * 3,
+        )
+        .unwrap();
+        assert_eq!(dmabuf.stamp(), Time::new(100, 0));
+        assert_eq!(dmabuf.frame_id(), "camera");
+        assert_eq!(dmabuf.pid(), 12345);
+        assert_eq!(dmabuf.fd(), 42);
+        assert_eq!(dmabuf.width(), 1920);
+        assert_eq!(dmabuf.height(), 1080);
+        assert_eq!(dmabuf.stride(), 1920 * 3);
+        assert_eq!(dmabuf.fourcc(), 0x34325247);
+        assert_eq!(dmabuf.length(), 1920 * 1080 * 3);
+
+        let bytes = dmabuf.to_cdr();
+        let decoded = DmaBuffer::from_cdr(bytes).unwrap();
+        assert_eq!(decoded.pid(), 12345);
+        assert_eq!(decoded.fd(), 42);
+    }
+
+    #[test]
+    fn camera_frame_roundtrip_empty() {
+        let cf = CameraFrame::new(
+            Time::new(1, 0),
+            "cam0",
+            42,
+            1234,
+            1920,
+            1080,
+            "NV12",
+            "bt709",
+            "bt709",
+            "bt709",
+            "limited",
+            -1,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(cf.seq(), 42);
+        assert_eq!(cf.pid(), 1234);
+        assert_eq!(cf.width(), 1920);
+        assert_eq!(cf.height(), 1080);
+        assert_eq!(cf.format(), "NV12");
+        assert_eq!(cf.color_space(), "bt709");
+        assert_eq!(cf.color_range(), "limited");
+        assert_eq!(cf.fence_fd(), -1);
+        assert_eq!(cf.num_planes(), 0);
+
+        let bytes = cf.to_cdr();
+        let decoded = CameraFrame::<&[u8]>::from_cdr(&bytes[..]).unwrap();
+        assert_eq!(decoded.seq(), 42);
+        assert_eq!(decoded.format(), "NV12");
+        assert_eq!(decoded.num_planes(), 0);
+    }
+
+    #[test]
+    fn camera_frame_roundtrip_two_planes() {
+        let y = CameraPlaneView {
+            fd: 42,
+            offset: 0,
+            stride: 1920,
+            size: 2_073_600,
+            used: 2_073_600,
+            data: &[],
+        };
+        let uv = CameraPlaneView {
+            fd: 42,
+            offset: 2_073_600,
+            stride: 1920,
+            size: 1_036_800,
+            used: 1_036_800,
+            data: &[],
+        };
         let cf = CameraFrame::new(
             Time::new(2, 0),
             "cam0",
@@ -5214,4 +9836,579 @@ mod tests {
             assert_eq!(a.boxed, b.boxed, "mask[{i}].boxed");
         }
     }
+
+    #[test]
+    fn aggregate_roundtrip() {
+        let counts = [
+            AggregateCountView {
+                label: "car",
+                count: 3,
+            },
+            AggregateCountView {
+                label: "person",
+                count: 12,
+            },
+        ];
+        let zones = [AggregateZoneView {
+            zone: "entrance",
+            occupancy: 2,
+            visits: 7,
+            avg_dwell: 4.5,
+        }];
+        let agg = Aggregate::builder()
+            .stamp(Time::new(100, 0))
+            .frame_id("analytics")
+            .window_start(Time::new(40, 5))
+            .window_end(Time::new(99, 6))
+            .counts(&counts)
+            .zones(&zones)
+            .build()
+            .unwrap();
+
+        let decoded = Aggregate::from_cdr(agg.to_cdr()).unwrap();
+        assert_eq!(decoded.frame_id(), "analytics");
+        assert_eq!(decoded.window_start(), Time::new(40, 5));
+        assert_eq!(decoded.window_end(), Time::new(99, 6));
+        assert_eq!(decoded.counts_len(), 2);
+        assert_eq!(decoded.counts(), counts);
+        assert_eq!(decoded.zones_len(), 1);
+        assert_eq!(decoded.zones(), zones);
+
+        let mut decoded = decoded;
+        decoded.set_window_end(Time::new(101, 0)).unwrap();
+        assert_eq!(decoded.window_end(), Time::new(101, 0));
+        assert_eq!(decoded.zones(), zones);
+    }
+
+    #[test]
+    fn aggregator_counts_and_dwell() {
+        fn frame(sec: i32, boxes: &[(&'static str, &'static str, f32)]) -> Detect<Vec<u8>> {
+            let views: Vec<DetectBoxView<'_>> = boxes
+                .iter()
+                .map(|&(label, track_id, x)| DetectBoxView {
+                    center_x: x,
+                    center_y: 0.5,
+                    width: 0.1,
+                    height: 0.1,
+                    label,
+                    score: 1.0,
+                    distance: 0.0,
+                    speed: 0.0,
+                    track_id,
+                    track_lifetime: 0,
+                    track_created: Time::new(0, 0),
+                })
+                .collect();
+            Detect::builder()
+                .stamp(Time::new(sec, 0))
+                .boxes(&views)
+                .build()
+                .unwrap()
+        }
+
+        let mut agg = Aggregator::new(vec![AggregateZoneDef {
+            name: "left".into(),
+            x_min: 0.0,
+            y_min: 0.0,
+            x_max: 0.5,
+            y_max: 1.0,
+        }]);
+        assert!(agg.finish(Time::new(0, 0), "").is_none());
+
+        // "a" stays left for 2 s then moves right; "b" is left throughout.
+        agg.push(&frame(10, &[("person", "a", 0.2), ("person", "b", 0.3)]));
+        agg.push(&frame(
+            12,
+            &[("person", "a", 0.2), ("person", "b", 0.3), ("car", "", 0.9)],
+        ));
+        agg.push(&frame(
+            13,
+            &[
+                ("person", "a", 0.8),
+                ("person", "b", 0.3),
+                ("car", "", 0.9),
+                ("car", "", 0.7),
+            ],
+        ));
+
+        let msg = agg.finish(Time::new(14, 0), "analytics").unwrap().unwrap();
+        assert_eq!(msg.window_start(), Time::new(10, 0));
+        assert_eq!(msg.window_end(), Time::new(13, 0));
+        assert_eq!(
+            msg.counts(),
+            [
+                AggregateCountView {
+                    label: "car",
+                    count: 2
+                },
+                AggregateCountView {
+                    label: "person",
+                    count: 2
+                },
+            ]
+        );
+        let zones = msg.zones();
+        assert_eq!(zones[0].zone, "left");
+        assert_eq!(zones[0].occupancy, 1);
+        assert_eq!(zones[0].visits, 2);
+        // a: 2 s (exited), b: 3 s (still inside) → 2.5 s
+        assert_eq!(zones[0].avg_dwell, 2.5);
+
+        // Next window starts empty apart from "b", still inside the zone.
+        agg.push(&frame(15, &[("person", "b", 0.3)]));
+        let msg = agg.finish(Time::new(16, 0), "analytics").unwrap().unwrap();
+        assert_eq!(msg.zones()[0].visits, 1);
+        assert_eq!(msg.zones()[0].avg_dwell, 2.0);
+    }
+
+    #[test]
+    fn attribute_roundtrip() {
+        let entries = [
+            AttributeEntryView {
+                key: "color",
+                value: "red",
+                confidence: 0.7,
+            },
+            AttributeEntryView {
+                key: "plate",
+                value: "ABC123",
+                confidence: 0.95,
+            },
+            AttributeEntryView {
+                key: "color",
+                value: "orange",
+                confidence: 0.2,
+            },
+        ];
+        let msg = Attribute::builder()
+            .stamp(Time::new(3, 4))
+            .frame_id("camera")
+            .track_id("track-17")
+            .attributes(&entries)
+            .build()
+            .unwrap();
+
+        let decoded = Attribute::from_cdr(msg.as_cdr()).unwrap();
+        assert_eq!(decoded.stamp(), Time::new(3, 4));
+        assert_eq!(decoded.frame_id(), "camera");
+        assert_eq!(decoded.track_id(), "track-17");
+        assert_eq!(decoded.attributes_len(), 3);
+        assert_eq!(decoded.attributes(), entries);
+        assert_eq!(decoded.get("color").unwrap().value, "red");
+        assert!(decoded.get("make").is_none());
+
+        let bytes = msg.to_cdr();
+        assert!(Attribute::from_cdr(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn set_radar_profile_roundtrip_and_validate() {
+        use set_radar_profile::*;
+        let req = SetRadarProfileRequest::builder()
+            .profile(PROFILE_CUSTOM)
+            .start_frequency_ghz(76.0)
+            .bandwidth_mhz(1000.0)
+            .chirp_duration_us(40.0)
+            .frame_period_ms(50.0)
+            .chirps_per_frame(128)
+            .cube(true)
+            .center_frequency("medium")
+            .range_toggle("short-long")
+            .detection_sensitivity("high")
+            .build()
+            .unwrap();
+        let decoded = SetRadarProfileRequest::from_cdr(req.as_cdr()).unwrap();
+        assert_eq!(decoded.profile(), PROFILE_CUSTOM);
+        assert_eq!(decoded.start_frequency_ghz(), 76.0);
+        assert_eq!(decoded.bandwidth_mhz(), 1000.0);
+        assert_eq!(decoded.chirp_duration_us(), 40.0);
+        assert_eq!(decoded.frame_period_ms(), 50.0);
+        assert_eq!(decoded.chirps_per_frame(), 128);
+        assert!(decoded.cube());
+        assert_eq!(decoded.center_frequency(), "medium");
+        assert_eq!(decoded.range_toggle(), "short-long");
+        assert_eq!(decoded.detection_sensitivity(), "high");
+        assert_eq!(decoded.validate(), Ok(()));
+
+        let bad_chirp = SetRadarProfileRequest::builder().build().unwrap();
+        assert_eq!(bad_chirp.validate(), Err(ERROR_INVALID_CHIRP));
+        let preset = SetRadarProfileRequest::builder()
+            .profile(PROFILE_ULTRA_SHORT)
+            .center_frequency("high")
+            .build()
+            .unwrap();
+        assert_eq!(preset.validate(), Err(ERROR_INCOMPATIBLE_FREQUENCY));
+        let unknown = SetRadarProfileRequest::builder()
+            .profile(9)
+            .build()
+            .unwrap();
+        assert_eq!(unknown.validate(), Err(ERROR_UNSUPPORTED_PROFILE));
+        let setting = SetRadarProfileRequest::builder()
+            .profile(PROFILE_LONG)
+            .range_toggle("sideways")
+            .build()
+            .unwrap();
+        assert_eq!(setting.validate(), Err(ERROR_INVALID_SETTING));
+
+        let resp = SetRadarProfileResponse::builder()
+            .success(false)
+            .error(ERROR_BUSY)
+            .profile(PROFILE_MEDIUM)
+            .message("reconfiguration in progress")
+            .build()
+            .unwrap();
+        let decoded = SetRadarProfileResponse::from_cdr(resp.to_cdr()).unwrap();
+        assert!(!decoded.success());
+        assert_eq!(decoded.error(), ERROR_BUSY);
+        assert_eq!(decoded.profile(), PROFILE_MEDIUM);
+        assert_eq!(decoded.message(), "reconfiguration in progress");
+    }
+
+    #[test]
+    fn video_stream_negotiation() {
+        let req = VideoStreamRequest::builder()
+            .stamp(Time::new(5, 0))
+            .frame_id("camera")
+            .consumer_id("dashboard")
+            .codec("h264")
+            .width(1280)
+            .height(720)
+            .framerate(15.0)
+            .build()
+            .unwrap();
+        let req = VideoStreamRequest::from_cdr(req.to_cdr()).unwrap();
+        assert_eq!(req.consumer_id(), "dashboard");
+        assert_eq!(req.codec(), "h264");
+        assert_eq!((req.width(), req.height()), (1280, 720));
+        assert_eq!(req.bitrate_kbps(), 0);
+        assert_eq!(req.framerate(), 15.0);
+
+        let variant =
+            |topic, codec, width, height, bitrate_kbps, consumers| VideoStreamVariantView {
+                topic,
+                codec,
+                width,
+                height,
+                bitrate_kbps,
+                framerate: 30.0,
+                consumers,
+            };
+        let variants = [
+            variant("rt/camera/h264/1080p", "h264", 1920, 1080, 8000, 0),
+            variant("rt/camera/h264/720p", "h264", 1280, 720, 4000, 2),
+            variant("rt/camera/h264/720p_lo", "h264", 1280, 720, 4000, 0),
+            variant("rt/camera/h265/720p", "h265", 1280, 720, 2000, 0),
+        ];
+        let info = VideoStreamInfo::builder()
+            .frame_id("camera")
+            .variants(&variants)
+            .build()
+            .unwrap();
+        let info = VideoStreamInfo::from_cdr(info.as_cdr()).unwrap();
+        assert_eq!(info.variants_len(), 4);
+        assert_eq!(info.variants(), variants);
+        assert_eq!(info.select(&req).unwrap().topic, "rt/camera/h264/720p_lo");
+
+        let any = VideoStreamRequest::builder().build().unwrap();
+        assert_eq!(info.select(&any).unwrap().topic, "rt/camera/h264/1080p");
+        let mjpeg = VideoStreamRequest::builder()
+            .codec("mjpeg")
+            .build()
+            .unwrap();
+        assert!(info.select(&mjpeg).is_none());
+    }
+
+    #[test]
+    fn camera_control_and_status_roundtrip() {
+        use camera_control::*;
+        let mut ctl = CameraControl::builder()
+            .stamp(Time::new(1, 0))
+            .frame_id("ptz0")
+            .mode(MODE_ABSOLUTE)
+            .pan(0.5)
+            .tilt(-0.25)
+            .zoom(4.0)
+            .focus_mode(FOCUS_MANUAL)
+            .focus(0.75)
+            .ir_cut(IR_CUT_NIGHT)
+            .build()
+            .unwrap();
+        ctl.set_pan(0.6).unwrap();
+        let ctl = CameraControl::from_cdr(ctl.as_cdr()).unwrap();
+        assert_eq!(ctl.frame_id(), "ptz0");
+        assert_eq!(ctl.mode(), MODE_ABSOLUTE);
+        assert_eq!((ctl.pan(), ctl.tilt(), ctl.zoom()), (0.6, -0.25, 4.0));
+        assert_eq!(ctl.focus_mode(), FOCUS_MANUAL);
+        assert_eq!(ctl.focus(), 0.75);
+        assert_eq!(ctl.ir_cut(), IR_CUT_NIGHT);
+
+        let status = CameraStatus::builder()
+            .frame_id("ptz0")
+            .pan(0.6)
+            .tilt(-0.25)
+            .zoom(4.0)
+            .focus(0.75)
+            .focus_mode(FOCUS_MANUAL)
+            .ir_cut(IR_CUT_NIGHT)
+            .moving(true)
+            .fault(FAULT_LIMIT)
+            .build()
+            .unwrap();
+        let status = CameraStatus::from_cdr(status.to_cdr()).unwrap();
+        assert_eq!(status.pan(), 0.6);
+        assert_eq!(status.focus(), 0.75);
+        assert_eq!(status.ir_cut(), IR_CUT_NIGHT);
+        assert!(status.moving());
+        assert_eq!(status.fault(), FAULT_LIMIT);
+    }
+
+    #[test]
+    fn provenance_roundtrip() {
+        let inputs = [
+            ProvenanceInputView {
+                topic: "rt/camera/dma",
+                schema: "edgefirst_msgs/msg/DmaBuffer",
+                schema_hash: "RIHS01_00",
+                stamp: Time::new(10, 500),
+            },
+            ProvenanceInputView {
+                topic: "rt/radar/cube",
+                schema: "edgefirst_msgs/msg/RadarCube",
+                schema_hash: "",
+                stamp: Time::new(10, 400),
+            },
+        ];
+        let p = Provenance::builder()
+            .stamp(Time::new(10, 900))
+            .frame_id("camera")
+            .output_topic("rt/model/detect")
+            .service_name("edgefirst-model")
+            .service_version("2.4.1")
+            .model_name("yolov8n")
+            .inputs(&inputs)
+            .build()
+            .unwrap();
+        let p = Provenance::from_cdr(p.as_cdr()).unwrap();
+        assert_eq!(p.output_topic(), "rt/model/detect");
+        assert_eq!(p.service_name(), "edgefirst-model");
+        assert_eq!(p.service_version(), "2.4.1");
+        assert_eq!(p.model_name(), "yolov8n");
+        assert_eq!(p.inputs_len(), 2);
+        assert_eq!(p.inputs(), inputs);
+        assert_eq!(p.input("rt/radar/cube").unwrap().stamp, Time::new(10, 400));
+        assert!(p.input("rt/lidar").is_none());
+        assert!(p.describes("rt/model/detect", Time::new(10, 900)));
+        assert!(!p.describes("rt/model/detect", Time::new(11, 0)));
+    }
+
+    #[test]
+    fn radar_cube_dimensions_are_typed() {
+        let cube = RadarCube::builder()
+            .layout(&[
+                radar_cube_dimension::RANGE,
+                radar_cube_dimension::DOPPLER,
+                9,
+            ])
+            .build()
+            .unwrap();
+        let dims: Vec<_> = cube.dimensions().collect();
+        assert_eq!(dims[0].as_ref().unwrap(), &RadarCubeDimension::Range);
+        assert_eq!(dims[1].as_ref().unwrap(), &RadarCubeDimension::Doppler);
+        assert!(matches!(
+            dims[2],
+            Err(CdrError::UnknownDiscriminant {
+                type_name: "RadarCubeDimension",
+                value: 9,
+                offset: None
+            })
+        ));
+        assert_eq!(
+            u8::from(RadarCubeDimension::RxChannel),
+            radar_cube_dimension::RXCHANNEL
+        );
+    }
+
+    #[test]
+    fn heartbeat_monitor_tracks_liveness() {
+        let mut hb = Heartbeat::builder()
+            .service("camera")
+            .seq(5)
+            .period(Duration::new(1, 0))
+            .build()
+            .unwrap();
+        let mut mon = HeartbeatMonitor::default();
+        let joined = mon.observe(&hb, Time::new(10, 0));
+        assert_eq!(
+            joined,
+            vec![HeartbeatEvent::Joined {
+                service: "camera".into()
+            }]
+        );
+        assert!(mon.poll(Time::new(11, 500_000_000)).is_empty());
+        assert_eq!(
+            mon.poll(Time::new(12, 1)),
+            vec![HeartbeatEvent::Missed {
+                service: "camera".into(),
+                last_seen: Time::new(10, 0)
+            }]
+        );
+        assert!(mon.poll(Time::new(13, 0)).is_empty());
+        assert!(!mon.is_alive("camera"));
+
+        hb.set_seq(0).unwrap();
+        hb.set_status(HeartbeatStatus::Stopping).unwrap();
+        let hb = Heartbeat::from_cdr(hb.into_cdr()).unwrap();
+        assert_eq!(hb.status(), HeartbeatStatus::Stopping);
+        let events = mon.observe(&hb, Time::new(14, 0));
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], HeartbeatEvent::Recovered { .. }));
+        assert!(matches!(events[1], HeartbeatEvent::Restarted { .. }));
+        assert!(matches!(
+            events[2],
+            HeartbeatEvent::StatusChanged {
+                to: HeartbeatStatus::Stopping,
+                ..
+            }
+        ));
+        assert_eq!(
+            mon.poll(Time::new(20, 0)),
+            vec![HeartbeatEvent::Left {
+                service: "camera".into()
+            }]
+        );
+        assert_eq!(mon.services().count(), 0);
+
+        let mut bad = hb.to_cdr();
+        let n = bad.len();
+        bad[n - 1] = 9;
+        let err = Heartbeat::from_cdr(bad).err().unwrap();
+        assert_eq!(err.field(), Some("status"));
+    }
+
+    #[test]
+    fn image_with_info_bundles_and_splits() {
+        let pixels = [7u8; 4 * 2 * 3];
+        let image = Image::builder()
+            .stamp(Time::new(3, 4))
+            .frame_id("camera")
+            .height(2)
+            .width(4)
+            .encoding("rgb8")
+            .step(12)
+            .data(&pixels)
+            .build()
+            .unwrap();
+        let d = [0.1, -0.2, 0.0, 0.0, 0.05];
+        let info = CameraInfo::builder()
+            .stamp(Time::new(3, 4))
+            .frame_id("camera")
+            .height(2)
+            .width(4)
+            .distortion_model("plumb_bob")
+            .d(&d)
+            .k([500.0, 0.0, 2.0, 0.0, 500.0, 1.0, 0.0, 0.0, 1.0])
+            .build()
+            .unwrap();
+
+        let bundle = ImageWithInfo::from_parts(&image, &info).unwrap();
+        let bundle = ImageWithInfo::from_cdr(bundle.as_cdr()).unwrap();
+        assert_eq!(bundle.stamp(), Time::new(3, 4));
+        assert_eq!(bundle.encoding(), "rgb8");
+        assert_eq!(bundle.data(), &pixels);
+        assert_eq!(bundle.d_len(), 5);
+        assert_eq!(bundle.d_get(1), -0.2);
+        assert_eq!(bundle.k()[0], 500.0);
+
+        assert_eq!(bundle.to_image().unwrap().as_cdr(), image.as_cdr());
+        assert_eq!(bundle.to_camera_info().unwrap().as_cdr(), info.as_cdr());
+    }
+
+    #[test]
+    fn fusion_debug_roundtrip() {
+        use fusion_debug::*;
+        let near = [3u32, 4, 9];
+        let far = [12u32];
+        let assoc = [
+            FusionAssociationView {
+                box_index: 0,
+                cluster_id: 2,
+                cost: 0.25,
+                reject_reason: REJECT_NONE,
+                point_indices: &near,
+            },
+            FusionAssociationView {
+                box_index: 0,
+                cluster_id: 5,
+                cost: 1.5,
+                reject_reason: REJECT_COST,
+                point_indices: &far,
+            },
+            FusionAssociationView {
+                box_index: 1,
+                cluster_id: CLUSTER_NONE,
+                cost: 9.0,
+                reject_reason: REJECT_GATE,
+                point_indices: &[],
+            },
+        ];
+        let msg = FusionDebug::builder()
+            .stamp(Time::new(10, 500))
+            .frame_id("base_link")
+            .detect_stamp(Time::new(10, 0))
+            .radar_stamp(Time::new(9, 950_000_000))
+            .associations(&assoc)
+            .build()
+            .unwrap();
+
+        let decoded = FusionDebug::from_cdr(msg.to_cdr()).unwrap();
+        assert_eq!(decoded.frame_id(), "base_link");
+        assert_eq!(decoded.detect_stamp(), Time::new(10, 0));
+        assert_eq!(decoded.radar_stamp(), Time::new(9, 950_000_000));
+        assert_eq!(decoded.associations_len(), 3);
+        assert_eq!(decoded.associations(), assoc);
+        assert_eq!(decoded.candidates(0).len(), 2);
+        assert_eq!(decoded.accepted(0).unwrap().point_indices, &near);
+        assert!(decoded.accepted(1).is_none());
+        assert_eq!(
+            FusionRejectReason::try_from(decoded.associations()[2].reject_reason).unwrap(),
+            FusionRejectReason::Gate
+        );
+
+        let bytes = msg.to_cdr();
+        assert!(FusionDebug::from_cdr(&bytes[..bytes.len() - 2]).is_err());
+    }
+
+    #[test]
+    fn service_result_roundtrip() {
+        let body = SetRadarProfileResponse::builder()
+            .success(true)
+            .profile(set_radar_profile::PROFILE_LONG)
+            .build()
+            .unwrap();
+        let ok = ServiceResult::builder()
+            .payload(body.as_cdr())
+            .build()
+            .unwrap();
+        let decoded = ServiceResult::from_cdr(ok.to_cdr()).unwrap();
+        assert!(decoded.ok());
+        assert_eq!(decoded.code(), service_result::CODE_OK);
+        let resp = decoded.decode(SetRadarProfileResponse::from_cdr).unwrap();
+        assert_eq!(resp.profile(), set_radar_profile::PROFILE_LONG);
+
+        let t = ServiceResult::success(&Time::new(4, 2)).unwrap();
+        assert_eq!(t.decode(decode_fixed::<Time>).unwrap(), Time::new(4, 2));
+        let bad = t.decode(SetRadarProfileResponse::from_cdr).err().unwrap();
+        assert_eq!(bad.kind(), Some(ServiceErrorCode::Internal));
+
+        let err = ServiceResult::failure(ServiceErrorCode::Busy, "cube streaming").unwrap();
+        let decoded = ServiceResult::from_cdr(err.to_cdr()).unwrap();
+        assert!(!decoded.ok());
+        assert!(decoded.payload().is_empty());
+        let e = decoded.result().unwrap_err();
+        assert!(matches!(e.kind(), Some(ServiceErrorCode::Busy)));
+        assert_eq!(e.message, "cube streaming");
+
+        let future = ServiceResult::builder().code(99).build().unwrap();
+        assert_eq!(future.result().unwrap_err().kind(), None);
+    }
 }
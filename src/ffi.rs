@@ -18,6 +18,20 @@
 //!   - `ros_<type>_free(handle)` → release handle
 //!   - `ros_<type>_encode(&out_bytes, &out_len, ...fields)` → allocate + write CDR
 //!
+//! ## NULL handles
+//!
+//! Functions never abort on a NULL handle. Getters return a sentinel (`0`,
+//! `0.0`, `false`, NULL, or `-1` for file descriptors) and void setters do
+//! nothing; both set `errno = EINVAL`. `_free(NULL)` is a silent no-op.
+//! With the `ffi-checked` feature a handle of the wrong type or one already
+//! freed is rejected the same way; see the handle tracking helpers.
+//!
+//! ## Panics
+//!
+//! Every function body runs under [`ffi_guard`], so a panic never unwinds
+//! into C: it returns the same sentinel (`-1` for signed integers) and sets
+//! `errno = EIO`.
+//!
 //! `from_cdr` borrows the caller's buffer — the returned handle stores a pointer
 //! into `data`, not a copy. The caller must keep `data` alive until `_free()`.
 //! String and blob getters return `const` pointers into the original `data` buffer.
@@ -37,8 +51,9 @@ use crate::foxglove_msgs;
 use crate::geometry_msgs::{self, *};
 use crate::mavros_msgs;
 use crate::nav_msgs;
+use crate::rosgraph_msgs::Clock;
 use crate::sensor_msgs::{self, NavSatStatus, PointFieldView, RegionOfInterest};
-use crate::std_msgs;
+use crate::std_msgs::{self, ColorRGBA};
 
 // =============================================================================
 // Helpers
@@ -47,9 +62,93 @@ use crate::std_msgs;
 const EINVAL: i32 = libc::EINVAL;
 const EBADMSG: i32 = libc::EBADMSG;
 const ENOBUFS: i32 = libc::ENOBUFS;
+const EIO: i32 = libc::EIO;
+const ENOENT: i32 = libc::ENOENT;
 
+std::thread_local! {
+    /// Description of the last failure on this thread, for
+    /// `edgefirst_last_error_message()`.
+    static LAST_ERROR: std::cell::RefCell<Option<std::ffi::CString>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Set `errno` and record a generic description of `code` as the last
+/// error. Call sites that know more use [`set_error`].
 fn set_errno(code: i32) {
+    let msg = match code {
+        EINVAL => "invalid argument",
+        EBADMSG => "message does not decode",
+        ENOBUFS => "buffer too small",
+        _ => "error",
+    };
+    set_error(code, msg);
+}
+
+/// Set `errno` and record `msg` as this thread's last error message.
+pub(crate) fn set_error(code: i32, msg: impl std::fmt::Display) {
     errno::set_errno(errno::Errno(code));
+    // Interior NULs would truncate the C string; they only come from
+    // caller-provided text.
+    let text = msg.to_string().replace('\0', "\\0");
+    LAST_ERROR.with(|e| *e.borrow_mut() = std::ffi::CString::new(text).ok());
+}
+
+/// Value an FFI function returns when its body panicked.
+trait PanicFallback {
+    fn fallback() -> Self;
+}
+
+impl PanicFallback for () {
+    fn fallback() -> Self {}
+}
+
+impl PanicFallback for bool {
+    fn fallback() -> Self {
+        false
+    }
+}
+
+macro_rules! impl_panic_fallback {
+    ($value:expr => $($t:ty),*) => {
+        $(impl PanicFallback for $t {
+            fn fallback() -> Self {
+                $value
+            }
+        })*
+    };
+}
+
+impl_panic_fallback!(-1 => i8, i16, i32, i64, isize);
+impl_panic_fallback!(0 => u8, u16, u32, u64, usize);
+impl_panic_fallback!(0.0 => f32, f64);
+
+impl<T> PanicFallback for *const T {
+    fn fallback() -> Self {
+        ptr::null()
+    }
+}
+
+impl<T> PanicFallback for *mut T {
+    fn fallback() -> Self {
+        ptr::null_mut()
+    }
+}
+
+/// Run an FFI function body, turning a panic into `errno = EIO` and the
+/// return type's failure value instead of aborting the C caller.
+fn ffi_guard<R: PanicFallback>(body: impl FnOnce() -> R) -> R {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(body)) {
+        Ok(r) => r,
+        Err(payload) => {
+            let what = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown cause");
+            set_error(EIO, format_args!("internal error: {what}"));
+            R::fallback()
+        }
+    }
 }
 
 /// Return a C string pointer. For non-empty strings from CDR buffers,
@@ -87,14 +186,17 @@ unsafe fn c_to_str<'a>(s: *const c_char) -> &'a str {
 /// `s` must either be NULL or a valid NUL-terminated C string.
 unsafe fn c_to_str_checked<'a>(s: *const c_char) -> Result<&'a str, ()> {
     if s.is_null() {
-        set_errno(EINVAL);
+        set_error(EINVAL, "string argument is NULL");
         return Err(());
     }
     let bytes = std::ffi::CStr::from_ptr(s).to_bytes();
     match std::str::from_utf8(bytes) {
         Ok(v) => Ok(v),
-        Err(_) => {
-            set_errno(EINVAL);
+        Err(e) => {
+            set_error(
+                EINVAL,
+                format_args!("string argument is not valid UTF-8: {}", e),
+            );
             Err(())
         }
     }
@@ -123,12 +225,251 @@ unsafe fn erase_lifetime(s: &[u8]) -> &'static [u8] {
 macro_rules! check_null_ret_null {
     ($ptr:expr) => {
         if $ptr.is_null() {
-            set_errno(EINVAL);
+            set_error(EINVAL, concat!("`", stringify!($ptr), "` is NULL"));
             return ptr::null_mut();
         }
     };
 }
 
+// =============================================================================
+// Handle tracking
+// =============================================================================
+//
+// Handles are plain `Box` pointers. In `ffi-checked` builds every handle is
+// also recorded with its type when it is created, and every call checks
+// the handle it is given against that record, so passing an Image where a
+// Detect is expected — or a freed handle — fails with `EINVAL` instead of
+// reading the wrong struct. Child handles borrowed from a parent
+// (`ros_detect_get_box()` and friends) are recorded with the parent and
+// dropped from the record when it is freed.
+
+/// A live handle, in `ffi-checked` builds.
+#[cfg(feature = "ffi-checked")]
+struct TrackedHandle {
+    ty: std::any::TypeId,
+    name: &'static str,
+    /// Address of the handle this one was allocated as, or is borrowed
+    /// from.
+    owner: usize,
+}
+
+#[cfg(feature = "ffi-checked")]
+type HandleMap = std::collections::BTreeMap<usize, TrackedHandle>;
+
+#[cfg(feature = "ffi-checked")]
+static HANDLES: std::sync::Mutex<HandleMap> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+#[cfg(feature = "ffi-checked")]
+fn tracked_handles() -> std::sync::MutexGuard<'static, HandleMap> {
+    HANDLES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The C name of handle type `T`, e.g. `ros_image_t`.
+fn handle_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// A C handle's allocation: the value the handle points at, followed by
+/// the reference count behind `ros_<type>_retain()`. `repr(C)` keeps the
+/// value at offset 0, so the handle is a plain `*mut T`.
+#[repr(C)]
+struct Counted<T> {
+    value: T,
+    refs: std::sync::atomic::AtomicUsize,
+}
+
+/// Box `v` and return it as a C handle holding one reference.
+fn new_handle<T: 'static>(v: T) -> *mut T {
+    let p = Box::into_raw(Box::new(Counted {
+        value: v,
+        refs: std::sync::atomic::AtomicUsize::new(1),
+    })) as *mut T;
+    #[cfg(feature = "ffi-checked")]
+    track_handle(p, p as usize);
+    p
+}
+
+/// Record `child`, which lives inside the handle `owner`, as a handle of
+/// its own until `owner` is freed.
+#[cfg_attr(not(feature = "ffi-checked"), allow(unused_variables))]
+fn track_child<T: 'static, O>(owner: *const O, child: &T) {
+    #[cfg(feature = "ffi-checked")]
+    track_handle(child, owner as usize);
+}
+
+#[cfg(feature = "ffi-checked")]
+fn track_handle<T: 'static>(p: *const T, owner: usize) {
+    tracked_handles().insert(
+        p as usize,
+        TrackedHandle {
+            ty: std::any::TypeId::of::<T>(),
+            name: handle_name::<T>(),
+            owner,
+        },
+    );
+}
+
+#[cfg(feature = "ffi-checked")]
+fn check_tracked<T: 'static>(handles: &HandleMap, p: *const T) -> Result<&TrackedHandle, String> {
+    match handles.get(&(p as usize)) {
+        Some(h) if h.ty == std::any::TypeId::of::<T>() => Ok(h),
+        Some(h) => Err(format!(
+            "expected a {} handle, got a {}",
+            handle_name::<T>(),
+            h.name
+        )),
+        None => Err(format!(
+            "{:p} is not a live {} handle",
+            p,
+            handle_name::<T>()
+        )),
+    }
+}
+
+/// Check a handle argument. Returns `true`, with `errno = EINVAL`, if `p`
+/// is NULL or, in `ffi-checked` builds, not a live handle of type `T`.
+fn bad_handle<T: 'static>(p: *const T) -> bool {
+    if p.is_null() {
+        set_error(
+            EINVAL,
+            format_args!("{} handle is NULL", handle_name::<T>()),
+        );
+        return true;
+    }
+    #[cfg(feature = "ffi-checked")]
+    if let Err(msg) = check_tracked(&tracked_handles(), p) {
+        set_error(EINVAL, msg);
+        return true;
+    }
+    false
+}
+
+/// Free a handle returned by [`new_handle`], or drop one reference to it
+/// if it was retained. NULL is a no-op; in `ffi-checked` builds so is
+/// anything but a live, owned handle of type `T`, with `errno = EINVAL`.
+///
+/// # Safety
+/// `p` must be NULL or a handle from [`new_handle`] that was not freed.
+unsafe fn free_handle<T: 'static>(p: *mut T) {
+    if p.is_null() {
+        return;
+    }
+    #[cfg(feature = "ffi-checked")]
+    {
+        let err = match check_tracked(&tracked_handles(), p) {
+            Ok(h) if h.owner != p as usize => Some(format!(
+                "{} handle is borrowed from its parent",
+                handle_name::<T>()
+            )),
+            Ok(_) => None,
+            Err(msg) => Some(msg),
+        };
+        if let Some(msg) = err {
+            set_error(EINVAL, msg);
+            return;
+        }
+    }
+    if unref_handle(p) {
+        destroy_handle(p);
+    }
+}
+
+/// Destroy a handle whose last reference is gone, with the children and
+/// CDR copy it owns.
+///
+/// # Safety
+/// `p` must be a handle from [`new_handle`] that [`unref_handle`] just
+/// released for the last time.
+unsafe fn destroy_handle<T: 'static>(p: *mut T) {
+    #[cfg(feature = "ffi-checked")]
+    tracked_handles().retain(|_, h| h.owner != p as usize);
+    drop(Box::from_raw(p as *mut Counted<T>));
+    if HANDLE_COPY_COUNT.load(std::sync::atomic::Ordering::Acquire) > 0 {
+        let copy = handle_copies().remove(&(p as usize));
+        if copy.is_some() {
+            HANDLE_COPY_COUNT.fetch_sub(1, std::sync::atomic::Ordering::Release);
+        }
+    }
+}
+
+/// CDR bytes owned by handles from `ros_<type>_clone()`, by handle address.
+/// [`free_handle`] drops the copy after the handle's view of it.
+static HANDLE_COPIES: std::sync::Mutex<std::collections::BTreeMap<usize, Box<[u8]>>> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+/// Entries in `HANDLE_COPIES`, so frees skip its lock while no clone is
+/// alive.
+static HANDLE_COPY_COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn handle_copies() -> std::sync::MutexGuard<'static, std::collections::BTreeMap<usize, Box<[u8]>>> {
+    HANDLE_COPIES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The reference count of a handle from [`new_handle`].
+///
+/// # Safety
+/// `p` must be a live handle from [`new_handle`].
+unsafe fn handle_refs<'a, T>(p: *const T) -> &'a std::sync::atomic::AtomicUsize {
+    &(*(p as *const Counted<T>)).refs
+}
+
+/// Add a reference to a live handle.
+fn retain_handle<T>(p: *const T) {
+    // A new reference is made from an existing one, so nothing needs to be
+    // ordered before it.
+    unsafe { handle_refs(p) }.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Drop a reference: `true` if it was the last one and the handle must be
+/// destroyed.
+fn unref_handle<T>(p: *const T) -> bool {
+    use std::sync::atomic::{fence, Ordering};
+    if unsafe { handle_refs(p) }.fetch_sub(1, Ordering::Release) != 1 {
+        return false;
+    }
+    // Every other holder's use of the handle happens before its destruction.
+    fence(Ordering::Acquire);
+    true
+}
+
+/// Deep-copy a view handle: parse a private copy of its CDR bytes with the
+/// type's `_from_cdr`, so the clone no longer borrows the caller's buffer.
+fn clone_handle<T>(cdr: &[u8], from_cdr: extern "C" fn(*const u8, usize) -> *mut T) -> *mut T {
+    let copy: Box<[u8]> = cdr.into();
+    let h = from_cdr(copy.as_ptr(), copy.len());
+    if !h.is_null() {
+        handle_copies().insert(h as usize, copy);
+        HANDLE_COPY_COUNT.fetch_add(1, std::sync::atomic::Ordering::Release);
+    }
+    h
+}
+
+/// Check a handle for a payload lock: it must be live and come from
+/// [`clone_handle`], owning its CDR bytes. A handle from `_from_cdr`
+/// borrows the caller's buffer, which no lock could keep alive. Sets
+/// `errno = EINVAL` and returns `false` otherwise.
+fn lockable<T: 'static>(p: *const T) -> bool {
+    if bad_handle(p) {
+        return false;
+    }
+    let owned = HANDLE_COPY_COUNT.load(std::sync::atomic::Ordering::Acquire) > 0
+        && handle_copies().contains_key(&(p as usize));
+    if !owned {
+        let name = handle_name::<T>();
+        set_error(
+            EINVAL,
+            format_args!(
+                "{} borrows its CDR bytes; lock a {}_clone() copy",
+                name,
+                name.trim_end_matches("_t")
+            ),
+        );
+    }
+    owned
+}
+
 // =============================================================================
 // Memory management
 // =============================================================================
@@ -142,16 +483,201 @@ macro_rules! check_null_ret_null {
 /// Capacity invariant: `return_cdr_bytes()` converts `Vec<u8>` →
 /// `Box<[u8]>` via `into_boxed_slice()`, which calls `shrink_to_fit()`
 /// first, guaranteeing `capacity == len`. We reconstruct the Vec with
-/// `capacity = len` here, matching the original allocation.
+/// `capacity = len` here, matching the original allocation. With an
+/// allocator registered through `edgefirst_set_allocator()` the buffer
+/// came from its `malloc` and goes back to its `free` instead.
 #[no_mangle]
 pub extern "C" fn ros_bytes_free(bytes: *mut u8, len: usize) {
-    if !bytes.is_null() && len > 0 {
-        unsafe {
-            drop(Vec::from_raw_parts(bytes, len, len));
+    ffi_guard(|| {
+        if bytes.is_null() {
+            return;
+        }
+        if let Some(a) = ALLOCATOR.get() {
+            unsafe { (a.free)(bytes.cast()) };
+        } else if len > 0 {
+            unsafe {
+                drop(Vec::from_raw_parts(bytes, len, len));
+            }
+        }
+    })
+}
+
+/// Free a byte buffer returned by the library; same as `ros_bytes_free`.
+#[no_mangle]
+pub extern "C" fn edgefirst_bytes_free(bytes: *mut u8, len: usize) {
+    ros_bytes_free(bytes, len)
+}
+
+/// Host allocator registered with `edgefirst_set_allocator`.
+struct Allocator {
+    malloc: unsafe extern "C" fn(usize) -> *mut std::ffi::c_void,
+    free: unsafe extern "C" fn(*mut std::ffi::c_void),
+}
+
+static ALLOCATOR: std::sync::OnceLock<Allocator> = std::sync::OnceLock::new();
+
+/// Which allocator returned buffers come from. Leaves `ALLOC_UNSET` once,
+/// for `ALLOC_RUST` with the first buffer handed out or, through
+/// `ALLOC_INSTALLING`, for `ALLOC_HOST` when an allocator is registered, so
+/// the two are never mixed: `ros_bytes_free` could not tell them apart.
+static ALLOCATOR_STATE: std::sync::atomic::AtomicU8 = std::sync::atomic::AtomicU8::new(ALLOC_UNSET);
+
+const ALLOC_UNSET: u8 = 0;
+const ALLOC_RUST: u8 = 1;
+const ALLOC_INSTALLING: u8 = 2;
+const ALLOC_HOST: u8 = 3;
+
+/// The allocator for a buffer about to be returned: the host's if one is
+/// registered, otherwise `None`, after which registration fails.
+fn returned_buffer_allocator() -> Option<&'static Allocator> {
+    use std::sync::atomic::Ordering;
+    loop {
+        match ALLOCATOR_STATE.compare_exchange(
+            ALLOC_UNSET,
+            ALLOC_RUST,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) | Err(ALLOC_RUST) => return None,
+            Err(ALLOC_HOST) => return ALLOCATOR.get(),
+            // Registration in progress on another thread; it only stores
+            // the allocator before publishing `ALLOC_HOST`.
+            Err(_) => std::hint::spin_loop(),
         }
     }
 }
 
+/// Route every buffer the library hands to C through `malloc_fn` and
+/// `free_fn`, so callers may release them with their own allocator.
+///
+/// Must be called once, before the first buffer is returned: otherwise
+/// returns -1 with `errno = EBUSY`. `EINVAL` if either function is NULL.
+/// Zero-length buffers are returned as NULL without calling `malloc_fn`.
+#[no_mangle]
+pub extern "C" fn edgefirst_set_allocator(
+    malloc_fn: Option<unsafe extern "C" fn(usize) -> *mut std::ffi::c_void>,
+    free_fn: Option<unsafe extern "C" fn(*mut std::ffi::c_void)>,
+) -> i32 {
+    use std::sync::atomic::Ordering;
+    ffi_guard(|| {
+        let (Some(malloc), Some(free)) = (malloc_fn, free_fn) else {
+            set_error(EINVAL, "`malloc_fn` and `free_fn` must both be set");
+            return -1;
+        };
+        match ALLOCATOR_STATE.compare_exchange(
+            ALLOC_UNSET,
+            ALLOC_INSTALLING,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                // Only one thread ever leaves `ALLOC_UNSET` this way.
+                let _ = ALLOCATOR.set(Allocator { malloc, free });
+                ALLOCATOR_STATE.store(ALLOC_HOST, Ordering::Release);
+                0
+            }
+            Err(ALLOC_RUST) => {
+                set_error(libc::EBUSY, "buffers were already returned");
+                -1
+            }
+            Err(_) => {
+                set_error(libc::EBUSY, "an allocator is already registered");
+                -1
+            }
+        }
+    })
+}
+
+// =============================================================================
+// ABI version
+// =============================================================================
+
+/// C ABI major version. Bumped when a symbol is removed, a signature or a
+/// public struct layout changes; tracks the SONAME major.
+pub const ABI_VERSION_MAJOR: u32 = 3;
+
+/// C ABI minor version. Bumped when symbols are added.
+pub const ABI_VERSION_MINOR: u32 = 4;
+
+/// `(major << 16) | minor`, as returned by [`edgefirst_abi_version`].
+pub const ABI_VERSION: u32 = (ABI_VERSION_MAJOR << 16) | ABI_VERSION_MINOR;
+
+/// NUL-terminated tag embedded in the shared library so the ABI of a file
+/// on disk can be read with `strings` without loading it.
+#[used]
+#[no_mangle]
+pub static EDGEFIRST_ABI_TAG: [u8; 18] = *b"EDGEFIRST_ABI 3.4\0";
+
+/// ABI version of the loaded library, encoded as `(major << 16) | minor`.
+///
+/// C consumers compare this against `EDGEFIRST_ABI_VERSION` from the header
+/// they were compiled with (see `edgefirst_abi_compatible()`): the majors
+/// must match and the library minor must be at least the header minor.
+#[no_mangle]
+pub extern "C" fn edgefirst_abi_version() -> u32 {
+    ffi_guard(|| ABI_VERSION)
+}
+
+/// Crate version of the loaded library, e.g. `"3.3.0"` (static string).
+///
+/// Informational: compatibility checks go through `edgefirst_abi_version()`.
+#[no_mangle]
+pub extern "C" fn edgefirst_schemas_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
+}
+
+/// Optional features by the names `edgefirst_schemas_has_feature` accepts,
+/// which are the capi crate's feature names where it has one.
+const FEATURES: &[(&str, bool)] = &[
+    ("json", cfg!(feature = "json")),
+    ("opaque", cfg!(feature = "ffi-opaque")),
+    ("checked", cfg!(feature = "ffi-checked")),
+    ("shm", cfg!(feature = "shm")),
+    ("zstd", cfg!(feature = "zstd")),
+    ("blob-file", cfg!(feature = "blob-file")),
+    ("blob-http", cfg!(feature = "blob-http")),
+];
+
+/// 1 if the library was built with optional feature `name` (e.g. `"json"`),
+/// 0 if not or if the name is unknown to this version, -1 with
+/// `errno = EINVAL` for NULL.
+#[no_mangle]
+pub extern "C" fn edgefirst_schemas_has_feature(name: *const c_char) -> i32 {
+    ffi_guard(|| {
+        let Ok(name) = (unsafe { c_to_str_checked(name) }) else {
+            return -1;
+        };
+        FEATURES
+            .iter()
+            .any(|&(feature, enabled)| enabled && feature == name) as i32
+    })
+}
+
+// =============================================================================
+// Error reporting
+// =============================================================================
+
+/// Description of the last failure on the calling thread, e.g.
+/// `"CDR buffer too short: need 24 bytes, have 16"`, or NULL if no call
+/// has failed since the thread started or since
+/// `edgefirst_clear_last_error()`.
+///
+/// Every function that sets `errno` records one. Successful calls leave it
+/// unchanged, so read it right after the failing call. The pointer is
+/// valid until the next failure or clear on the same thread.
+#[no_mangle]
+pub extern "C" fn edgefirst_last_error_message() -> *const c_char {
+    ffi_guard(|| LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr())))
+}
+
+/// Forget the calling thread's last error message.
+#[no_mangle]
+pub extern "C" fn edgefirst_clear_last_error() {
+    ffi_guard(|| {
+        LAST_ERROR.with(|e| *e.borrow_mut() = None);
+    })
+}
+
 // =============================================================================
 // CdrFixed encode/decode helpers
 // =============================================================================
@@ -169,8 +695,8 @@ pub extern "C" fn ros_bytes_free(bytes: *mut u8, len: usize) {
 fn encode_fixed_to_buf<T: CdrFixed>(val: &T, buf: *mut u8, cap: usize, written: *mut usize) -> i32 {
     let bytes = match cdr::encode_fixed(val) {
         Ok(b) => b,
-        Err(_) => {
-            set_errno(EBADMSG);
+        Err(e) => {
+            set_error(EBADMSG, e);
             return -1;
         }
     };
@@ -193,6 +719,112 @@ fn encode_fixed_to_buf<T: CdrFixed>(val: &T, buf: *mut u8, cap: usize, written:
     0
 }
 
+/// Encode a buffer-backed message into caller memory.
+///
+/// `*out_len` always receives the encoded size. A NULL `buf` is a size
+/// query and succeeds; a `cap` smaller than the size fails with `ENOBUFS`
+/// and writes nothing, so callers can retry with a larger buffer.
+fn encode_into_caller_buf(
+    buf: *mut u8,
+    cap: usize,
+    out_len: *mut usize,
+    encode: impl FnOnce(&mut [u8]) -> Result<usize, cdr::CdrError>,
+) -> i32 {
+    if out_len.is_null() {
+        set_error(EINVAL, "`out_len` is NULL");
+        return -1;
+    }
+    let dst: &mut [u8] = if buf.is_null() {
+        &mut []
+    } else {
+        unsafe { slice::from_raw_parts_mut(buf, cap) }
+    };
+    match encode(dst) {
+        Ok(n) => {
+            unsafe { *out_len = n };
+            0
+        }
+        Err(cdr::CdrError::BufferTooShort { need, .. }) if buf.is_null() => {
+            unsafe { *out_len = need };
+            0
+        }
+        Err(e @ cdr::CdrError::BufferTooShort { need, .. }) => {
+            unsafe { *out_len = need };
+            set_error(ENOBUFS, e);
+            -1
+        }
+        Err(e) => {
+            set_error(EBADMSG, e);
+            -1
+        }
+    }
+}
+
+/// Backs `ros_<type>_builder_encode_parts`: encode a message as
+/// `head ‖ data ‖ tail` so a gather write can send the caller's `len`
+/// data bytes where they are. `encode(pad)` encodes the message with `pad`
+/// in place of the data and returns it with the offset of `pad`. CDR
+/// alignment never exceeds 8, so with a pad congruent to `len` mod 8 every
+/// byte outside the data matches the real message except the sequence
+/// length, which is patched here.
+#[allow(clippy::too_many_arguments)]
+fn encode_parts_into_caller_bufs(
+    len: usize,
+    head: *mut u8,
+    head_cap: usize,
+    head_len: *mut usize,
+    tail: *mut u8,
+    tail_cap: usize,
+    tail_len: *mut usize,
+    encode: impl FnOnce(&[u8]) -> Result<(Vec<u8>, usize), cdr::CdrError>,
+) -> i32 {
+    if head_len.is_null() || tail_len.is_null() {
+        set_error(EINVAL, "`head_len` or `tail_len` is NULL");
+        return -1;
+    }
+    let Ok(count) = u32::try_from(len) else {
+        set_error(EINVAL, "data is longer than a CDR sequence can hold");
+        return -1;
+    };
+    // Never empty, so the view hands back a slice inside the buffer.
+    let pad = &[0u8; 16][..8 + len % 8];
+    let (mut cdr, at) = match encode(pad) {
+        Ok(v) => v,
+        Err(e) => {
+            set_error(EBADMSG, e);
+            return -1;
+        }
+    };
+    cdr[at - 4..at].copy_from_slice(&count.to_le_bytes());
+    let (head_bytes, rest) = cdr.split_at(at);
+    let tail_bytes = &rest[pad.len()..];
+    unsafe {
+        *head_len = head_bytes.len();
+        *tail_len = tail_bytes.len();
+    }
+    if head.is_null() && tail.is_null() {
+        return 0;
+    }
+    let head_cap = if head.is_null() { 0 } else { head_cap };
+    let tail_cap = if tail.is_null() { 0 } else { tail_cap };
+    if head_cap < head_bytes.len() || tail_cap < tail_bytes.len() {
+        set_error(ENOBUFS, "`head` or `tail` is smaller than reported");
+        return -1;
+    }
+    unsafe {
+        ptr::copy_nonoverlapping(head_bytes.as_ptr(), head, head_bytes.len());
+        if !tail_bytes.is_empty() {
+            ptr::copy_nonoverlapping(tail_bytes.as_ptr(), tail, tail_bytes.len());
+        }
+    }
+    0
+}
+
+/// Offset of `part` within `buf`, for the `encode_parts` closures.
+fn offset_in(buf: &[u8], part: &[u8]) -> usize {
+    part.as_ptr() as usize - buf.as_ptr() as usize
+}
+
 /// Generic decode for CdrFixed types. Sets errno and returns Err on failure.
 /// EINVAL for NULL data pointer, EBADMSG for decode failures.
 fn decode_fixed_from_buf<T: CdrFixed>(data: *const u8, len: usize) -> Result<T, ()> {
@@ -205,7 +837,132 @@ fn decode_fixed_from_buf<T: CdrFixed>(data: *const u8, len: usize) -> Result<T,
         return Err(());
     }
     let slice = unsafe { slice::from_raw_parts(data, len) };
-    cdr::decode_fixed(slice).map_err(|_| set_errno(EBADMSG))
+    cdr::decode_fixed(slice).map_err(|e| set_error(EBADMSG, e))
+}
+
+/// Outstanding `ros_<type>_<field>_lock` borrows on a view handle.
+///
+/// One atomic word holds the lock count and a FREED bit, so whichever of
+/// `ros_<type>_free` and the last unlock comes second (on any thread)
+/// destroys the handle, exactly once.
+#[derive(Default)]
+struct PayloadLocks(std::sync::atomic::AtomicU32);
+
+impl PayloadLocks {
+    const FREED: u32 = 1 << 31;
+
+    fn lock(&self) {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::AcqRel);
+    }
+
+    /// Drop one lock: `Err` if none is held, `Ok(true)` if the handle was
+    /// freed meanwhile and must now be destroyed.
+    fn unlock(&self) -> Result<bool, ()> {
+        use std::sync::atomic::Ordering;
+        self.0
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |s| {
+                (s & !Self::FREED != 0).then(|| s - 1)
+            })
+            .map(|prev| prev == Self::FREED | 1)
+            .map_err(|_| ())
+    }
+
+    /// Mark the handle freed: `true` if no lock is held and it can be
+    /// destroyed now.
+    fn release(&self) -> bool {
+        let prev = self
+            .0
+            .fetch_or(Self::FREED, std::sync::atomic::Ordering::AcqRel);
+        prev & !Self::FREED == 0
+    }
+}
+
+/// Caller buffer adopted by a `ros_*_builder_set_data_owned` call.
+///
+/// The builder keeps it next to its borrowed `(data, data_len)` slot;
+/// dropping it (the next data setter, or freeing the builder) hands the
+/// buffer back through the caller's release callback.
+struct AdoptedData {
+    release: unsafe extern "C" fn(*mut std::ffi::c_void),
+    ctx: *mut std::ffi::c_void,
+}
+
+impl Drop for AdoptedData {
+    fn drop(&mut self) {
+        unsafe { (self.release)(self.ctx) }
+    }
+}
+
+/// Builder-owned element sequence behind the `ros_*_builder_add_*`
+/// functions, the layout-independent alternative to passing a C-POD
+/// descriptor array to `ros_*_builder_set_*`.
+///
+/// `elems` holds the same descriptors a C caller would have built, so the
+/// builders' build paths stay unchanged: each add re-points the builder's
+/// borrowed `(ptr, count)` slot at `elems`. Strings, blobs and inner
+/// arrays the descriptors point to are boxed in `keep`, whose heap
+/// allocations don't move as the vectors grow.
+struct ElemStore<T> {
+    elems: Vec<T>,
+    keep: Vec<Box<dyn std::any::Any>>,
+}
+
+impl<T> ElemStore<T> {
+    fn new() -> Self {
+        ElemStore {
+            elems: Vec::new(),
+            keep: Vec::new(),
+        }
+    }
+
+    /// Drop the stored elements; a `set_*` call replaced the sequence.
+    #[cfg_attr(feature = "ffi-opaque", allow(dead_code))]
+    fn clear(&mut self) {
+        self.elems.clear();
+        self.keep.clear();
+    }
+
+    /// Append an element and return the new `(ptr, count)` for the slot.
+    fn push(&mut self, elem: T) -> (*const T, usize) {
+        self.elems.push(elem);
+        (self.elems.as_ptr(), self.elems.len())
+    }
+
+    /// Remove element `index` from the sequence in `slot` and return the
+    /// slot's new `(ptr, count)`, or `None` if `index` is out of range. A
+    /// borrowed `set_*` array is first copied into the store (its strings
+    /// stay borrowed, as before).
+    fn remove(&mut self, slot: (*const T, usize), index: usize) -> Option<(*const T, usize)>
+    where
+        T: Copy,
+    {
+        let (ptr, count) = slot;
+        if index >= count {
+            return None;
+        }
+        if ptr != self.elems.as_ptr() {
+            self.elems = unsafe { slice::from_raw_parts(ptr, count) }.to_vec();
+        }
+        self.elems.remove(index);
+        Some((self.elems.as_ptr(), self.elems.len()))
+    }
+
+    /// Keep a copy of `s` alive for as long as the store, as a C string.
+    fn keep_str(&mut self, s: &str) -> *const c_char {
+        // Builder strings come from C strings, so they hold no NUL.
+        let s = std::ffi::CString::new(s).unwrap_or_default();
+        let p = s.as_ptr();
+        self.keep.push(Box::new(s));
+        p
+    }
+
+    /// Keep a copy of `v` alive for as long as the store.
+    fn keep_slice<U: Copy + 'static>(&mut self, v: &[U]) -> *const U {
+        let v = v.to_vec();
+        let p = v.as_ptr();
+        self.keep.push(Box::new(v));
+        p
+    }
 }
 
 // =============================================================================
@@ -220,8 +977,10 @@ pub extern "C" fn ros_time_encode(
     sec: i32,
     nanosec: u32,
 ) -> i32 {
-    let t = Time { sec, nanosec };
-    encode_fixed_to_buf(&t, buf, cap, written)
+    ffi_guard(|| {
+        let t = Time { sec, nanosec };
+        encode_fixed_to_buf(&t, buf, cap, written)
+    })
 }
 
 #[no_mangle]
@@ -231,7 +990,7 @@ pub extern "C" fn ros_time_decode(
     sec: *mut i32,
     nanosec: *mut u32,
 ) -> i32 {
-    match decode_fixed_from_buf::<Time>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Time>(data, len) {
         Ok(t) => unsafe {
             if !sec.is_null() {
                 *sec = t.sec;
@@ -242,9 +1001,68 @@ pub extern "C" fn ros_time_decode(
             0
         },
         Err(()) => -1,
+    })
+}
+
+/// `ROS_CLOCK_REALTIME`: wall-clock time since the Unix epoch.
+const ROS_CLOCK_REALTIME: i32 = 0;
+/// `ROS_CLOCK_MONOTONIC`: time since an unspecified start, never stepped
+/// (unix only).
+#[cfg_attr(not(unix), allow(dead_code))]
+const ROS_CLOCK_MONOTONIC: i32 = 1;
+
+/// Read `clock` as a Time. Sets `errno = EINVAL` for an unknown clock, or
+/// one this platform does not have.
+fn clock_now(clock: i32) -> Option<Time> {
+    match clock {
+        ROS_CLOCK_REALTIME => {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default();
+            Some(Time::new(now.as_secs() as i32, now.subsec_nanos()))
+        }
+        #[cfg(unix)]
+        ROS_CLOCK_MONOTONIC => {
+            let mut ts = libc::timespec {
+                tv_sec: 0,
+                tv_nsec: 0,
+            };
+            if unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts) } != 0 {
+                set_error(EINVAL, "clock_gettime(CLOCK_MONOTONIC) failed");
+                return None;
+            }
+            Some(Time::new(ts.tv_sec as i32, ts.tv_nsec as u32))
+        }
+        _ => {
+            set_error(EINVAL, format_args!("unsupported clock {}", clock));
+            None
+        }
     }
 }
 
+/// Read the current time of `clock` (`ROS_CLOCK_REALTIME` or
+/// `ROS_CLOCK_MONOTONIC`) into `sec` / `nanosec`, either of which may be
+/// NULL.
+///
+/// Returns 0 on success, -1 with `errno = EINVAL` for an unknown clock.
+#[no_mangle]
+pub extern "C" fn ros_time_set_now(sec: *mut i32, nanosec: *mut u32, clock: i32) -> i32 {
+    ffi_guard(|| {
+        let Some(t) = clock_now(clock) else {
+            return -1;
+        };
+        unsafe {
+            if !sec.is_null() {
+                *sec = t.sec;
+            }
+            if !nanosec.is_null() {
+                *nanosec = t.nanosec;
+            }
+        }
+        0
+    })
+}
+
 // =============================================================================
 // builtin_interfaces::Duration
 // =============================================================================
@@ -257,8 +1075,10 @@ pub extern "C" fn ros_duration_encode(
     sec: i32,
     nanosec: u32,
 ) -> i32 {
-    let d = Duration { sec, nanosec };
-    encode_fixed_to_buf(&d, buf, cap, written)
+    ffi_guard(|| {
+        let d = Duration { sec, nanosec };
+        encode_fixed_to_buf(&d, buf, cap, written)
+    })
 }
 
 #[no_mangle]
@@ -268,7 +1088,7 @@ pub extern "C" fn ros_duration_decode(
     sec: *mut i32,
     nanosec: *mut u32,
 ) -> i32 {
-    match decode_fixed_from_buf::<Duration>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Duration>(data, len) {
         Ok(d) => unsafe {
             if !sec.is_null() {
                 *sec = d.sec;
@@ -279,7 +1099,7 @@ pub extern "C" fn ros_duration_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // =============================================================================
@@ -296,7 +1116,7 @@ pub extern "C" fn ros_vector3_encode(
     y: f64,
     z: f64,
 ) -> i32 {
-    encode_fixed_to_buf(&Vector3 { x, y, z }, buf, cap, written)
+    ffi_guard(|| encode_fixed_to_buf(&Vector3 { x, y, z }, buf, cap, written))
 }
 
 #[no_mangle]
@@ -307,7 +1127,7 @@ pub extern "C" fn ros_vector3_decode(
     y: *mut f64,
     z: *mut f64,
 ) -> i32 {
-    match decode_fixed_from_buf::<Vector3>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Vector3>(data, len) {
         Ok(v) => unsafe {
             if !x.is_null() {
                 *x = v.x;
@@ -321,7 +1141,7 @@ pub extern "C" fn ros_vector3_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // Point
@@ -334,7 +1154,7 @@ pub extern "C" fn ros_point_encode(
     y: f64,
     z: f64,
 ) -> i32 {
-    encode_fixed_to_buf(&Point { x, y, z }, buf, cap, written)
+    ffi_guard(|| encode_fixed_to_buf(&Point { x, y, z }, buf, cap, written))
 }
 
 #[no_mangle]
@@ -345,7 +1165,7 @@ pub extern "C" fn ros_point_decode(
     y: *mut f64,
     z: *mut f64,
 ) -> i32 {
-    match decode_fixed_from_buf::<Point>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Point>(data, len) {
         Ok(v) => unsafe {
             if !x.is_null() {
                 *x = v.x;
@@ -359,7 +1179,7 @@ pub extern "C" fn ros_point_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // Quaternion
@@ -373,7 +1193,7 @@ pub extern "C" fn ros_quaternion_encode(
     z: f64,
     w: f64,
 ) -> i32 {
-    encode_fixed_to_buf(&Quaternion { x, y, z, w }, buf, cap, written)
+    ffi_guard(|| encode_fixed_to_buf(&Quaternion { x, y, z, w }, buf, cap, written))
 }
 
 #[no_mangle]
@@ -385,7 +1205,7 @@ pub extern "C" fn ros_quaternion_decode(
     z: *mut f64,
     w: *mut f64,
 ) -> i32 {
-    match decode_fixed_from_buf::<Quaternion>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Quaternion>(data, len) {
         Ok(v) => unsafe {
             if !x.is_null() {
                 *x = v.x;
@@ -402,7 +1222,7 @@ pub extern "C" fn ros_quaternion_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // Pose
@@ -419,20 +1239,22 @@ pub extern "C" fn ros_pose_encode(
     oz: f64,
     ow: f64,
 ) -> i32 {
-    let val = Pose {
-        position: Point {
-            x: px,
-            y: py,
-            z: pz,
-        },
-        orientation: Quaternion {
-            x: ox,
-            y: oy,
-            z: oz,
-            w: ow,
-        },
-    };
-    encode_fixed_to_buf(&val, buf, cap, written)
+    ffi_guard(|| {
+        let val = Pose {
+            position: Point {
+                x: px,
+                y: py,
+                z: pz,
+            },
+            orientation: Quaternion {
+                x: ox,
+                y: oy,
+                z: oz,
+                w: ow,
+            },
+        };
+        encode_fixed_to_buf(&val, buf, cap, written)
+    })
 }
 
 #[no_mangle]
@@ -447,7 +1269,7 @@ pub extern "C" fn ros_pose_decode(
     oz: *mut f64,
     ow: *mut f64,
 ) -> i32 {
-    match decode_fixed_from_buf::<Pose>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Pose>(data, len) {
         Ok(v) => unsafe {
             if !px.is_null() {
                 *px = v.position.x;
@@ -473,7 +1295,7 @@ pub extern "C" fn ros_pose_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // Transform
@@ -490,20 +1312,22 @@ pub extern "C" fn ros_transform_encode(
     rz: f64,
     rw: f64,
 ) -> i32 {
-    let val = Transform {
-        translation: Vector3 {
-            x: tx,
-            y: ty,
-            z: tz,
-        },
-        rotation: Quaternion {
-            x: rx,
-            y: ry,
-            z: rz,
-            w: rw,
-        },
-    };
-    encode_fixed_to_buf(&val, buf, cap, written)
+    ffi_guard(|| {
+        let val = Transform {
+            translation: Vector3 {
+                x: tx,
+                y: ty,
+                z: tz,
+            },
+            rotation: Quaternion {
+                x: rx,
+                y: ry,
+                z: rz,
+                w: rw,
+            },
+        };
+        encode_fixed_to_buf(&val, buf, cap, written)
+    })
 }
 
 #[no_mangle]
@@ -518,7 +1342,7 @@ pub extern "C" fn ros_transform_decode(
     rz: *mut f64,
     rw: *mut f64,
 ) -> i32 {
-    match decode_fixed_from_buf::<Transform>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Transform>(data, len) {
         Ok(v) => unsafe {
             if !tx.is_null() {
                 *tx = v.translation.x;
@@ -544,7 +1368,7 @@ pub extern "C" fn ros_transform_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // Twist
@@ -560,19 +1384,21 @@ pub extern "C" fn ros_twist_encode(
     ay: f64,
     az: f64,
 ) -> i32 {
-    let val = Twist {
-        linear: Vector3 {
-            x: lx,
-            y: ly,
-            z: lz,
-        },
-        angular: Vector3 {
-            x: ax,
-            y: ay,
-            z: az,
-        },
-    };
-    encode_fixed_to_buf(&val, buf, cap, written)
+    ffi_guard(|| {
+        let val = Twist {
+            linear: Vector3 {
+                x: lx,
+                y: ly,
+                z: lz,
+            },
+            angular: Vector3 {
+                x: ax,
+                y: ay,
+                z: az,
+            },
+        };
+        encode_fixed_to_buf(&val, buf, cap, written)
+    })
 }
 
 #[no_mangle]
@@ -586,7 +1412,7 @@ pub extern "C" fn ros_twist_decode(
     ay: *mut f64,
     az: *mut f64,
 ) -> i32 {
-    match decode_fixed_from_buf::<Twist>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Twist>(data, len) {
         Ok(v) => unsafe {
             if !lx.is_null() {
                 *lx = v.linear.x;
@@ -609,7 +1435,7 @@ pub extern "C" fn ros_twist_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // Accel
@@ -625,19 +1451,21 @@ pub extern "C" fn ros_accel_encode(
     ay: f64,
     az: f64,
 ) -> i32 {
-    let val = Accel {
-        linear: Vector3 {
-            x: lx,
-            y: ly,
-            z: lz,
-        },
-        angular: Vector3 {
-            x: ax,
-            y: ay,
-            z: az,
-        },
-    };
-    encode_fixed_to_buf(&val, buf, cap, written)
+    ffi_guard(|| {
+        let val = Accel {
+            linear: Vector3 {
+                x: lx,
+                y: ly,
+                z: lz,
+            },
+            angular: Vector3 {
+                x: ax,
+                y: ay,
+                z: az,
+            },
+        };
+        encode_fixed_to_buf(&val, buf, cap, written)
+    })
 }
 
 #[no_mangle]
@@ -651,7 +1479,7 @@ pub extern "C" fn ros_accel_decode(
     ay: *mut f64,
     az: *mut f64,
 ) -> i32 {
-    match decode_fixed_from_buf::<Accel>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<Accel>(data, len) {
         Ok(v) => unsafe {
             if !lx.is_null() {
                 *lx = v.linear.x;
@@ -674,7 +1502,7 @@ pub extern "C" fn ros_accel_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // NavSatStatus
@@ -686,7 +1514,7 @@ pub extern "C" fn ros_nav_sat_status_encode(
     status: i8,
     service: u16,
 ) -> i32 {
-    encode_fixed_to_buf(&NavSatStatus { status, service }, buf, cap, written)
+    ffi_guard(|| encode_fixed_to_buf(&NavSatStatus { status, service }, buf, cap, written))
 }
 
 #[no_mangle]
@@ -696,7 +1524,7 @@ pub extern "C" fn ros_nav_sat_status_decode(
     status: *mut i8,
     service: *mut u16,
 ) -> i32 {
-    match decode_fixed_from_buf::<NavSatStatus>(data, len) {
+    ffi_guard(|| match decode_fixed_from_buf::<NavSatStatus>(data, len) {
         Ok(v) => unsafe {
             if !status.is_null() {
                 *status = v.status;
@@ -707,7 +1535,7 @@ pub extern "C" fn ros_nav_sat_status_decode(
             0
         },
         Err(()) => -1,
-    }
+    })
 }
 
 // =============================================================================
@@ -775,7 +1603,21 @@ fn copy_le_u32_seq(data: &[u8], seq_off: usize, out: *mut u32, cap: usize) -> u3
 /// Leaks the Vec as a raw pointer; caller must use ros_bytes_free().
 fn return_cdr_bytes(cdr: Vec<u8>, out_bytes: *mut *mut u8, out_len: *mut usize) -> i32 {
     let len = cdr.len();
-    let ptr = Box::into_raw(cdr.into_boxed_slice()) as *mut u8;
+    let ptr = match returned_buffer_allocator() {
+        // `malloc(0)` may legitimately return NULL, so an empty buffer is
+        // handed out as NULL without asking the host; freeing it is a no-op.
+        Some(_) if len == 0 => ptr::null_mut(),
+        Some(a) => {
+            let p = unsafe { (a.malloc)(len) }.cast::<u8>();
+            if p.is_null() {
+                set_error(libc::ENOMEM, "registered allocator returned NULL");
+                return -1;
+            }
+            unsafe { ptr::copy_nonoverlapping(cdr.as_ptr(), p, len) };
+            p
+        }
+        None => Box::into_raw(cdr.into_boxed_slice()) as *mut u8,
+    };
     unsafe {
         if !out_bytes.is_null() {
             *out_bytes = ptr;
@@ -799,48 +1641,58 @@ pub struct ros_header_t(std_msgs::Header<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_header_from_cdr(data: *const u8, len: usize) -> *mut ros_header_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match std_msgs::Header::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_header_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match std_msgs::Header::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_header_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_header_free(view: *mut ros_header_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_header_get_stamp_sec(view: *const ros_header_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_header_get_stamp_nanosec(view: *const ros_header_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_header_get_frame_id(view: *const ros_header_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
@@ -851,26 +1703,28 @@ pub extern "C" fn ros_header_encode(
     stamp_nanosec: u32,
     frame_id: *const c_char,
 ) -> i32 {
-    let fid = unsafe { c_to_str(frame_id) };
-    let v = match std_msgs::Header::builder()
-        .stamp(Time::new(stamp_sec, stamp_nanosec))
-        .frame_id(fid)
-        .build()
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
-            return -1;
-        }
-    };
-    return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    ffi_guard(|| {
+        let fid = unsafe { c_to_str(frame_id) };
+        let v = match std_msgs::Header::builder()
+            .stamp(Time::new(stamp_sec, stamp_nanosec))
+            .frame_id(fid)
+            .build()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    })
 }
 
 // =============================================================================
 // Image (buffer-backed)
 // =============================================================================
 
-pub struct ros_image_t(sensor_msgs::Image<&'static [u8]>);
+pub struct ros_image_t(sensor_msgs::Image<&'static [u8]>, PayloadLocks);
 
 /// @brief Create an Image view from CDR bytes.
 /// @param data CDR encoded bytes (borrowed; must outlive the returned handle)
@@ -878,107 +1732,188 @@ pub struct ros_image_t(sensor_msgs::Image<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_image_from_cdr(data: *const u8, len: usize) -> *mut ros_image_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::Image::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_image_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::Image::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_image_t(v, PayloadLocks::default())),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_free(view: *mut ros_image_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null()
+            && !bad_handle(view)
+            && unref_handle(view)
+            && unsafe { (*view).1.release() }
+        {
+            unsafe {
+                destroy_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_stamp_sec(view: *const ros_image_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_stamp_nanosec(view: *const ros_image_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_frame_id(view: *const ros_image_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_height(view: *const ros_image_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.height() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.height() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_width(view: *const ros_image_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.width() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.width() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_encoding(view: *const ros_image_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.encoding() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.encoding() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_is_bigendian(view: *const ros_image_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.is_bigendian() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.is_bigendian() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_step(view: *const ros_image_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.step() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.step() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_get_data(view: *const ros_image_t, out_len: *mut usize) -> *const u8 {
-    if view.is_null() {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let data = unsafe { (*view).0.data() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
+        }
+        data.as_ptr()
+    })
+}
+
+/// Borrow the data payload for as long as the caller holds the lock,
+/// independent of when `ros_image_free` is called: freeing a locked handle
+/// defers its destruction to the last `ros_image_data_unlock`.
+///
+/// Only handles from `ros_image_clone` own their bytes; one from
+/// `ros_image_from_cdr` borrows the caller's buffer, which a lock could not
+/// keep alive, so it returns NULL with `errno = EINVAL`.
+#[no_mangle]
+pub extern "C" fn ros_image_data_lock(view: *mut ros_image_t, out_len: *mut usize) -> *const u8 {
+    ffi_guard(|| {
+        if !lockable(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let view = unsafe { &*view };
+        view.1.lock();
+        let data = view.0.data();
         if !out_len.is_null() {
             unsafe {
-                *out_len = 0;
+                *out_len = data.len();
             }
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).0.data() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        data.as_ptr()
+    })
+}
+
+/// Release a `ros_image_data_lock` borrow. Returns -1 with `errno = EINVAL`
+/// for a NULL handle or one with no lock held.
+#[no_mangle]
+pub extern "C" fn ros_image_data_unlock(view: *mut ros_image_t) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return -1;
         }
-    }
-    data.as_ptr()
+        match unsafe { (*view).1.unlock() } {
+            Ok(destroy) => {
+                if destroy {
+                    unsafe {
+                        destroy_handle(view);
+                    }
+                }
+                0
+            }
+            Err(()) => {
+                set_error(EINVAL, "payload is not locked");
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -996,31 +1931,33 @@ pub extern "C" fn ros_image_encode(
     data: *const u8,
     data_len: usize,
 ) -> i32 {
-    let fid = unsafe { c_to_str(frame_id) };
-    let enc = unsafe { c_to_str(encoding) };
-    let d = if data.is_null() {
-        &[]
-    } else {
-        unsafe { slice::from_raw_parts(data, data_len) }
-    };
-    let v = match sensor_msgs::Image::builder()
-        .stamp(Time::new(stamp_sec, stamp_nanosec))
-        .frame_id(fid)
-        .height(height)
-        .width(width)
-        .encoding(enc)
-        .is_bigendian(is_bigendian)
-        .step(step)
-        .data(d)
-        .build()
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
-            return -1;
-        }
-    };
-    return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    ffi_guard(|| {
+        let fid = unsafe { c_to_str(frame_id) };
+        let enc = unsafe { c_to_str(encoding) };
+        let d = if data.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(data, data_len) }
+        };
+        let v = match sensor_msgs::Image::builder()
+            .stamp(Time::new(stamp_sec, stamp_nanosec))
+            .frame_id(fid)
+            .height(height)
+            .width(width)
+            .encoding(enc)
+            .is_bigendian(is_bigendian)
+            .step(step)
+            .data(d)
+            .build()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    })
 }
 
 // =============================================================================
@@ -1038,62 +1975,74 @@ pub extern "C" fn ros_compressed_image_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_compressed_image_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::CompressedImage::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_compressed_image_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::CompressedImage::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_compressed_image_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_image_free(view: *mut ros_compressed_image_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_image_get_stamp_sec(view: *const ros_compressed_image_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_image_get_stamp_nanosec(
     view: *const ros_compressed_image_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_image_get_frame_id(
     view: *const ros_compressed_image_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_image_get_format(
     view: *const ros_compressed_image_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.format() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.format() })
+    })
 }
 
 #[no_mangle]
@@ -1101,21 +2050,23 @@ pub extern "C" fn ros_compressed_image_get_data(
     view: *const ros_compressed_image_t,
     out_len: *mut usize,
 ) -> *const u8 {
-    if view.is_null() {
-        if !out_len.is_null() {
-            unsafe {
-                *out_len = 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
             }
+            return ptr::null();
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).0.data() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        let data = unsafe { (*view).0.data() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
         }
-    }
-    data.as_ptr()
+        data.as_ptr()
+    })
 }
 
 #[no_mangle]
@@ -1129,27 +2080,29 @@ pub extern "C" fn ros_compressed_image_encode(
     data: *const u8,
     data_len: usize,
 ) -> i32 {
-    let fid = unsafe { c_to_str(frame_id) };
-    let fmt = unsafe { c_to_str(format) };
-    let d = if data.is_null() {
-        &[]
-    } else {
-        unsafe { slice::from_raw_parts(data, data_len) }
-    };
-    let v = match sensor_msgs::CompressedImage::builder()
-        .stamp(Time::new(stamp_sec, stamp_nanosec))
-        .frame_id(fid)
-        .format(fmt)
-        .data(d)
-        .build()
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
-            return -1;
-        }
-    };
-    return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    ffi_guard(|| {
+        let fid = unsafe { c_to_str(frame_id) };
+        let fmt = unsafe { c_to_str(format) };
+        let d = if data.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(data, data_len) }
+        };
+        let v = match sensor_msgs::CompressedImage::builder()
+            .stamp(Time::new(stamp_sec, stamp_nanosec))
+            .frame_id(fid)
+            .format(fmt)
+            .data(d)
+            .build()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    })
 }
 
 // =============================================================================
@@ -1167,32 +2120,38 @@ pub extern "C" fn ros_compressed_video_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_compressed_video_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match foxglove_msgs::FoxgloveCompressedVideo::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_compressed_video_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match foxglove_msgs::FoxgloveCompressedVideo::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_compressed_video_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_video_free(view: *mut ros_compressed_video_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_video_get_stamp_sec(view: *const ros_compressed_video_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 /// Alias for `ros_compressed_video_get_stamp_sec`; matches the Foxglove schema field name.
@@ -1200,17 +2159,19 @@ pub extern "C" fn ros_compressed_video_get_stamp_sec(view: *const ros_compressed
 pub unsafe extern "C" fn ros_compressed_video_get_timestamp_sec(
     view: *const ros_compressed_video_t,
 ) -> i32 {
-    ros_compressed_video_get_stamp_sec(view)
+    ffi_guard(|| ros_compressed_video_get_stamp_sec(view))
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_video_get_stamp_nanosec(
     view: *const ros_compressed_video_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 /// Alias for `ros_compressed_video_get_stamp_nanosec`; matches the Foxglove schema field name.
@@ -1218,17 +2179,19 @@ pub extern "C" fn ros_compressed_video_get_stamp_nanosec(
 pub unsafe extern "C" fn ros_compressed_video_get_timestamp_nanosec(
     view: *const ros_compressed_video_t,
 ) -> u32 {
-    ros_compressed_video_get_stamp_nanosec(view)
+    ffi_guard(|| ros_compressed_video_get_stamp_nanosec(view))
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_video_get_frame_id(
     view: *const ros_compressed_video_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
@@ -1236,31 +2199,35 @@ pub extern "C" fn ros_compressed_video_get_data(
     view: *const ros_compressed_video_t,
     out_len: *mut usize,
 ) -> *const u8 {
-    if view.is_null() {
-        if !out_len.is_null() {
-            unsafe {
-                *out_len = 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
             }
+            return ptr::null();
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).0.data() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        let data = unsafe { (*view).0.data() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
         }
-    }
-    data.as_ptr()
+        data.as_ptr()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_video_get_format(
     view: *const ros_compressed_video_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.format() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.format() })
+    })
 }
 
 #[no_mangle]
@@ -1274,27 +2241,29 @@ pub extern "C" fn ros_compressed_video_encode(
     data_len: usize,
     format: *const c_char,
 ) -> i32 {
-    let fid = unsafe { c_to_str(frame_id) };
-    let fmt = unsafe { c_to_str(format) };
-    let d = if data.is_null() {
-        &[]
-    } else {
-        unsafe { slice::from_raw_parts(data, data_len) }
-    };
-    let v = match foxglove_msgs::FoxgloveCompressedVideo::builder()
-        .stamp(Time::new(stamp_sec, stamp_nanosec))
-        .frame_id(fid)
-        .data(d)
-        .format(fmt)
-        .build()
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
-            return -1;
-        }
-    };
-    return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    ffi_guard(|| {
+        let fid = unsafe { c_to_str(frame_id) };
+        let fmt = unsafe { c_to_str(format) };
+        let d = if data.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(data, data_len) }
+        };
+        let v = match foxglove_msgs::FoxgloveCompressedVideo::builder()
+            .stamp(Time::new(stamp_sec, stamp_nanosec))
+            .frame_id(fid)
+            .data(d)
+            .format(fmt)
+            .build()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    })
 }
 
 // =============================================================================
@@ -1319,20 +2288,22 @@ pub struct ros_mask_t {
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_mask_from_cdr(data: *const u8, len: usize) -> *mut ros_mask_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    let static_slice: &'static [u8] = unsafe { erase_lifetime(slice) };
-    // Parse directly into a `MaskView<'static>`. The helper calls
-    // `scan_mask_element` which returns a view whose `&str` / `&[u8]` fields
-    // are structurally tied to the buffer's `'static` lifetime, so no unsafe
-    // `mem::transmute` is required to widen method-returned references.
-    match edgefirst_msgs::Mask::from_cdr_as_view(static_slice) {
-        Ok(view) => Box::into_raw(Box::new(ros_mask_t { view, owned: true })),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        let static_slice: &'static [u8] = unsafe { erase_lifetime(slice) };
+        // Parse directly into a `MaskView<'static>`. The helper calls
+        // `scan_mask_element` which returns a view whose `&str` / `&[u8]` fields
+        // are structurally tied to the buffer's `'static` lifetime, so no unsafe
+        // `mem::transmute` is required to widen method-returned references.
+        match edgefirst_msgs::Mask::from_cdr_as_view(static_slice) {
+            Ok(view) => new_handle(ros_mask_t { view, owned: true }),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 /// @brief Free a Mask handle obtained from `ros_mask_from_cdr`.
@@ -1346,76 +2317,90 @@ pub extern "C" fn ros_mask_from_cdr(data: *const u8, len: usize) -> *mut ros_mas
 /// misuse (Rule 5 in CAPI.md); this is defense-in-depth against it.
 #[no_mangle]
 pub extern "C" fn ros_mask_free(view: *mut ros_mask_t) {
-    if view.is_null() {
-        return;
-    }
-    unsafe {
-        if (*view).owned {
-            drop(Box::from_raw(view));
-        } else {
-            // Parent-borrowed child — must not free.
-            set_errno(EINVAL);
+    ffi_guard(|| {
+        if view.is_null() || bad_handle(view) {
+            return;
         }
-    }
+        unsafe {
+            if (*view).owned {
+                free_handle(view);
+            } else {
+                // Parent-borrowed child — must not free.
+                set_errno(EINVAL);
+            }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_get_height(view: *const ros_mask_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.height }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.height }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_get_width(view: *const ros_mask_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.width }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.width }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_get_length(view: *const ros_mask_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.length }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.length }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_get_encoding(view: *const ros_mask_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).view.encoding })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).view.encoding })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_get_data(view: *const ros_mask_t, out_len: *mut usize) -> *const u8 {
-    if view.is_null() {
-        if !out_len.is_null() {
-            unsafe {
-                *out_len = 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
             }
+            return ptr::null();
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).view.mask };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        let data = unsafe { (*view).view.mask };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
         }
-    }
-    data.as_ptr()
+        data.as_ptr()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_get_boxed(view: *const ros_mask_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).view.boxed }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).view.boxed }
+    })
 }
 
 #[no_mangle]
@@ -1430,28 +2415,30 @@ pub extern "C" fn ros_mask_encode(
     data_len: usize,
     boxed: bool,
 ) -> i32 {
-    let enc = unsafe { c_to_str(encoding) };
-    let d = if data.is_null() {
-        &[]
-    } else {
-        unsafe { slice::from_raw_parts(data, data_len) }
-    };
-    let v = match edgefirst_msgs::Mask::builder()
-        .height(height)
-        .width(width)
-        .length(length)
-        .encoding(enc)
-        .mask(d)
-        .boxed(boxed)
-        .build()
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
-            return -1;
-        }
-    };
-    return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    ffi_guard(|| {
+        let enc = unsafe { c_to_str(encoding) };
+        let d = if data.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(data, data_len) }
+        };
+        let v = match edgefirst_msgs::Mask::builder()
+            .height(height)
+            .width(width)
+            .length(length)
+            .encoding(enc)
+            .mask(d)
+            .boxed(boxed)
+            .build()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    })
 }
 
 // =============================================================================
@@ -1471,104 +2458,128 @@ pub struct ros_dmabuffer_t(edgefirst_msgs::DmaBuffer<&'static [u8]>);
 #[allow(deprecated)]
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_from_cdr(data: *const u8, len: usize) -> *mut ros_dmabuffer_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::DmaBuffer::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_dmabuffer_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::DmaBuffer::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_dmabuffer_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_free(view: *mut ros_dmabuffer_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_stamp_sec(view: *const ros_dmabuffer_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_stamp_nanosec(view: *const ros_dmabuffer_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_frame_id(view: *const ros_dmabuffer_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_pid(view: *const ros_dmabuffer_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.pid() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.pid() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_fd(view: *const ros_dmabuffer_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.fd() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.fd() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_width(view: *const ros_dmabuffer_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.width() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.width() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_height(view: *const ros_dmabuffer_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.height() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.height() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_stride(view: *const ros_dmabuffer_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stride() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stride() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_fourcc(view: *const ros_dmabuffer_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.fourcc() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.fourcc() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_dmabuffer_get_length(view: *const ros_dmabuffer_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.length() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.length() }
+    })
 }
 
 #[allow(deprecated)]
@@ -1587,25 +2598,27 @@ pub extern "C" fn ros_dmabuffer_encode(
     fourcc: u32,
     length: u32,
 ) -> i32 {
-    let fid = unsafe { c_to_str(frame_id) };
-    let v = match edgefirst_msgs::DmaBuffer::new(
-        Time::new(stamp_sec, stamp_nanosec),
-        fid,
-        pid,
-        fd,
-        width,
-        height,
-        stride,
-        fourcc,
-        length,
-    ) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
-            return -1;
-        }
-    };
-    return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    ffi_guard(|| {
+        let fid = unsafe { c_to_str(frame_id) };
+        let v = match edgefirst_msgs::DmaBuffer::new(
+            Time::new(stamp_sec, stamp_nanosec),
+            fid,
+            pid,
+            fd,
+            width,
+            height,
+            stride,
+            fourcc,
+            length,
+        ) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        return_cdr_bytes(v.into_cdr(), out_bytes, out_len)
+    })
 }
 
 // =============================================================================
@@ -1640,153 +2653,190 @@ pub extern "C" fn ros_camera_frame_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_camera_frame_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::CameraFrame::from_cdr_collect_planes(unsafe { erase_lifetime(slice) }) {
-        Ok((inner, plane_views)) => {
-            let child_planes: Vec<ros_camera_plane_t> = plane_views
-                .into_iter()
-                .map(|view| ros_camera_plane_t { view, owned: false })
-                .collect();
-            Box::into_raw(Box::new(ros_camera_frame_t {
-                inner,
-                child_planes,
-            }))
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::CameraFrame::from_cdr_collect_planes(unsafe { erase_lifetime(slice) })
+        {
+            Ok((inner, plane_views)) => {
+                let child_planes: Vec<ros_camera_plane_t> = plane_views
+                    .into_iter()
+                    .map(|view| ros_camera_plane_t { view, owned: false })
+                    .collect();
+                let h = new_handle(ros_camera_frame_t {
+                    inner,
+                    child_planes,
+                });
+                for plane in unsafe { &(*h).child_planes } {
+                    track_child(h, plane);
+                }
+                h
+            }
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_free(view: *mut ros_camera_frame_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_stamp_sec(view: *const ros_camera_frame_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_stamp_nanosec(view: *const ros_camera_frame_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_frame_id(view: *const ros_camera_frame_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).inner.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).inner.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_seq(view: *const ros_camera_frame_t) -> u64 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.seq() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.seq() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_pid(view: *const ros_camera_frame_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.pid() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.pid() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_width(view: *const ros_camera_frame_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.width() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.width() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_height(view: *const ros_camera_frame_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.height() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.height() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_fence_fd(view: *const ros_camera_frame_t) -> i32 {
-    if view.is_null() {
-        return -1;
-    }
-    unsafe { (*view).inner.fence_fd() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return -1;
+        }
+        unsafe { (*view).inner.fence_fd() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_format(view: *const ros_camera_frame_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).inner.format() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).inner.format() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_color_space(
     view: *const ros_camera_frame_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).inner.color_space() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).inner.color_space() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_color_transfer(
     view: *const ros_camera_frame_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).inner.color_transfer() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).inner.color_transfer() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_color_encoding(
     view: *const ros_camera_frame_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).inner.color_encoding() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).inner.color_encoding() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_color_range(
     view: *const ros_camera_frame_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).inner.color_range() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).inner.color_range() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_get_planes_len(view: *const ros_camera_frame_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).child_planes.len() as u32 }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).child_planes.len() as u32 }
+    })
 }
 
 /// @brief Get a borrowed view of the i-th plane.
@@ -1800,17 +2850,18 @@ pub extern "C" fn ros_camera_frame_get_plane(
     view: *const ros_camera_frame_t,
     index: u32,
 ) -> *const ros_camera_plane_t {
-    if view.is_null() {
-        set_errno(EINVAL);
-        return ptr::null();
-    }
-    let v = unsafe { &*view };
-    let idx = index as usize;
-    if idx >= v.child_planes.len() {
-        set_errno(EINVAL);
-        return ptr::null();
-    }
-    &v.child_planes[idx] as *const ros_camera_plane_t
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        let v = unsafe { &*view };
+        let idx = index as usize;
+        if idx >= v.child_planes.len() {
+            set_errno(EINVAL);
+            return ptr::null();
+        }
+        &v.child_planes[idx] as *const ros_camera_plane_t
+    })
 }
 
 /// @brief Free a CameraPlane handle.
@@ -1820,56 +2871,68 @@ pub extern "C" fn ros_camera_frame_get_plane(
 /// — the parent owns it.
 #[no_mangle]
 pub extern "C" fn ros_camera_plane_free(view: *mut ros_camera_plane_t) {
-    if view.is_null() {
-        return;
-    }
-    unsafe {
-        if (*view).owned {
-            drop(Box::from_raw(view));
-        } else {
-            set_errno(EINVAL);
+    ffi_guard(|| {
+        if view.is_null() || bad_handle(view) {
+            return;
         }
-    }
+        unsafe {
+            if (*view).owned {
+                free_handle(view);
+            } else {
+                set_errno(EINVAL);
+            }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_plane_get_fd(view: *const ros_camera_plane_t) -> i32 {
-    if view.is_null() {
-        return -1;
-    }
-    unsafe { (*view).view.fd }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return -1;
+        }
+        unsafe { (*view).view.fd }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_plane_get_offset(view: *const ros_camera_plane_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.offset }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.offset }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_plane_get_stride(view: *const ros_camera_plane_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.stride }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.stride }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_plane_get_size(view: *const ros_camera_plane_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.size }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.size }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_plane_get_used(view: *const ros_camera_plane_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.used }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.used }
+    })
 }
 
 /// @brief Get the inlined plane data (only populated when fd == -1).
@@ -1881,25 +2944,27 @@ pub extern "C" fn ros_camera_plane_get_data(
     view: *const ros_camera_plane_t,
     out_len: *mut usize,
 ) -> *const u8 {
-    if view.is_null() {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let data = unsafe { (*view).view.data };
         if !out_len.is_null() {
             unsafe {
-                *out_len = 0;
+                *out_len = data.len();
             }
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).view.data };
-    if !out_len.is_null() {
-        unsafe {
-            *out_len = data.len();
+        if data.is_empty() {
+            ptr::null()
+        } else {
+            data.as_ptr()
         }
-    }
-    if data.is_empty() {
-        ptr::null()
-    } else {
-        data.as_ptr()
-    }
+    })
 }
 
 // =============================================================================
@@ -1914,48 +2979,58 @@ pub struct ros_imu_t(sensor_msgs::Imu<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_imu_from_cdr(data: *const u8, len: usize) -> *mut ros_imu_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::Imu::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_imu_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::Imu::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_imu_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_imu_free(view: *mut ros_imu_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_imu_get_stamp_sec(view: *const ros_imu_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_imu_get_stamp_nanosec(view: *const ros_imu_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_imu_get_frame_id(view: *const ros_imu_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 /// Write the IMU orientation quaternion (x, y, z, w) to the provided output pointers.
@@ -1967,36 +3042,44 @@ pub extern "C" fn ros_imu_get_orientation(
     z: *mut f64,
     w: *mut f64,
 ) {
-    if view.is_null() {
-        return;
-    }
-    let q = unsafe { (*view).0.orientation() };
-    unsafe {
-        if !x.is_null() {
-            *x = q.x;
-        }
-        if !y.is_null() {
-            *y = q.y;
-        }
-        if !z.is_null() {
-            *z = q.z;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
         }
-        if !w.is_null() {
-            *w = q.w;
+        let q = unsafe { (*view).0.orientation() };
+        unsafe {
+            if !x.is_null() {
+                *x = q.x;
+            }
+            if !y.is_null() {
+                *y = q.y;
+            }
+            if !z.is_null() {
+                *z = q.z;
+            }
+            if !w.is_null() {
+                *w = q.w;
+            }
         }
-    }
+    })
 }
 
 /// Write the 9-element orientation covariance to `out` (row-major 3×3).
 #[no_mangle]
 pub extern "C" fn ros_imu_get_orientation_covariance(view: *const ros_imu_t, out: *mut f64) {
-    if view.is_null() || out.is_null() {
-        return;
-    }
-    let cov = unsafe { (*view).0.orientation_covariance() };
-    unsafe {
-        ptr::copy_nonoverlapping(cov.as_ptr(), out, 9);
-    }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
+        }
+        if out.is_null() {
+            set_errno(EINVAL);
+            return;
+        }
+        let cov = unsafe { (*view).0.orientation_covariance() };
+        unsafe {
+            ptr::copy_nonoverlapping(cov.as_ptr(), out, 9);
+        }
+    })
 }
 
 /// Write the IMU angular velocity (x, y, z) to the provided output pointers.
@@ -2007,33 +3090,41 @@ pub extern "C" fn ros_imu_get_angular_velocity(
     y: *mut f64,
     z: *mut f64,
 ) {
-    if view.is_null() {
-        return;
-    }
-    let v = unsafe { (*view).0.angular_velocity() };
-    unsafe {
-        if !x.is_null() {
-            *x = v.x;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
         }
-        if !y.is_null() {
-            *y = v.y;
-        }
-        if !z.is_null() {
-            *z = v.z;
+        let v = unsafe { (*view).0.angular_velocity() };
+        unsafe {
+            if !x.is_null() {
+                *x = v.x;
+            }
+            if !y.is_null() {
+                *y = v.y;
+            }
+            if !z.is_null() {
+                *z = v.z;
+            }
         }
-    }
+    })
 }
 
 /// Write the 9-element angular velocity covariance to `out` (row-major 3×3).
 #[no_mangle]
 pub extern "C" fn ros_imu_get_angular_velocity_covariance(view: *const ros_imu_t, out: *mut f64) {
-    if view.is_null() || out.is_null() {
-        return;
-    }
-    let cov = unsafe { (*view).0.angular_velocity_covariance() };
-    unsafe {
-        ptr::copy_nonoverlapping(cov.as_ptr(), out, 9);
-    }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
+        }
+        if out.is_null() {
+            set_errno(EINVAL);
+            return;
+        }
+        let cov = unsafe { (*view).0.angular_velocity_covariance() };
+        unsafe {
+            ptr::copy_nonoverlapping(cov.as_ptr(), out, 9);
+        }
+    })
 }
 
 /// Write the IMU linear acceleration (x, y, z) to the provided output pointers.
@@ -2044,21 +3135,23 @@ pub extern "C" fn ros_imu_get_linear_acceleration(
     y: *mut f64,
     z: *mut f64,
 ) {
-    if view.is_null() {
-        return;
-    }
-    let v = unsafe { (*view).0.linear_acceleration() };
-    unsafe {
-        if !x.is_null() {
-            *x = v.x;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
         }
-        if !y.is_null() {
-            *y = v.y;
-        }
-        if !z.is_null() {
-            *z = v.z;
+        let v = unsafe { (*view).0.linear_acceleration() };
+        unsafe {
+            if !x.is_null() {
+                *x = v.x;
+            }
+            if !y.is_null() {
+                *y = v.y;
+            }
+            if !z.is_null() {
+                *z = v.z;
+            }
         }
-    }
+    })
 }
 
 /// Write the 9-element linear acceleration covariance to `out` (row-major 3×3).
@@ -2067,13 +3160,19 @@ pub extern "C" fn ros_imu_get_linear_acceleration_covariance(
     view: *const ros_imu_t,
     out: *mut f64,
 ) {
-    if view.is_null() || out.is_null() {
-        return;
-    }
-    let cov = unsafe { (*view).0.linear_acceleration_covariance() };
-    unsafe {
-        ptr::copy_nonoverlapping(cov.as_ptr(), out, 9);
-    }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
+        }
+        if out.is_null() {
+            set_errno(EINVAL);
+            return;
+        }
+        let cov = unsafe { (*view).0.linear_acceleration_covariance() };
+        unsafe {
+            ptr::copy_nonoverlapping(cov.as_ptr(), out, 9);
+        }
+    })
 }
 
 // =============================================================================
@@ -2088,72 +3187,88 @@ pub struct ros_nav_sat_fix_t(sensor_msgs::NavSatFix<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_from_cdr(data: *const u8, len: usize) -> *mut ros_nav_sat_fix_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::NavSatFix::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_nav_sat_fix_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::NavSatFix::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_nav_sat_fix_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_free(view: *mut ros_nav_sat_fix_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_get_stamp_sec(view: *const ros_nav_sat_fix_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_get_stamp_nanosec(view: *const ros_nav_sat_fix_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_get_frame_id(view: *const ros_nav_sat_fix_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_get_latitude(view: *const ros_nav_sat_fix_t) -> f64 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.latitude() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.latitude() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_get_longitude(view: *const ros_nav_sat_fix_t) -> f64 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.longitude() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.longitude() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_get_altitude(view: *const ros_nav_sat_fix_t) -> f64 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.altitude() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.altitude() }
+    })
 }
 
 // =============================================================================
@@ -2171,67 +3286,79 @@ pub extern "C" fn ros_transform_stamped_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_transform_stamped_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match geometry_msgs::TransformStamped::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_transform_stamped_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match geometry_msgs::TransformStamped::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_transform_stamped_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_transform_stamped_free(view: *mut ros_transform_stamped_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_transform_stamped_get_stamp_sec(view: *const ros_transform_stamped_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_transform_stamped_get_stamp_nanosec(
     view: *const ros_transform_stamped_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_transform_stamped_get_frame_id(
     view: *const ros_transform_stamped_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_transform_stamped_get_child_frame_id(
     view: *const ros_transform_stamped_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.child_frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.child_frame_id() })
+    })
 }
 
 // =============================================================================
 // Simple stamped geometry types (TwistStamped, AccelStamped, PointStamped,
-// InertiaStamped) — all follow the same from_cdr / free / stamp / frame_id
+// PoseStamped, InertiaStamped) — all follow the same from_cdr / free / stamp / frame_id
 // pattern with no additional string fields.
 // =============================================================================
 
@@ -2242,52 +3369,62 @@ macro_rules! impl_simple_stamped {
         /// Parse CDR bytes into an opaque stamped view handle.
         #[no_mangle]
         pub extern "C" fn $from_cdr(data: *const u8, len: usize) -> *mut $c_name {
-            check_null_ret_null!(data);
-            let slice = unsafe { slice::from_raw_parts(data, len) };
-            match <$rust_type>::from_cdr(unsafe { erase_lifetime(slice) }) {
-                Ok(v) => Box::into_raw(Box::new($c_name(v))),
-                Err(_) => {
-                    set_errno(EBADMSG);
-                    ptr::null_mut()
+            ffi_guard(|| {
+                check_null_ret_null!(data);
+                let slice = unsafe { slice::from_raw_parts(data, len) };
+                match <$rust_type>::from_cdr(unsafe { erase_lifetime(slice) }) {
+                    Ok(v) => new_handle($c_name(v)),
+                    Err(e) => {
+                        set_error(EBADMSG, e);
+                        ptr::null_mut()
+                    }
                 }
-            }
+            })
         }
 
         /// Free a stamped view handle.
         #[no_mangle]
         pub extern "C" fn $free(view: *mut $c_name) {
-            if !view.is_null() {
-                unsafe {
-                    drop(Box::from_raw(view));
+            ffi_guard(|| {
+                if !view.is_null() {
+                    unsafe {
+                        free_handle(view);
+                    }
                 }
-            }
+            })
         }
 
         /// Get stamp seconds.
         #[no_mangle]
         pub extern "C" fn $sec(view: *const $c_name) -> i32 {
-            if view.is_null() {
-                return 0;
-            }
-            unsafe { (*view).0.stamp().sec }
+            ffi_guard(|| {
+                if bad_handle(view) {
+                    return 0;
+                }
+                unsafe { (*view).0.stamp().sec }
+            })
         }
 
         /// Get stamp nanoseconds.
         #[no_mangle]
         pub extern "C" fn $nanosec(view: *const $c_name) -> u32 {
-            if view.is_null() {
-                return 0;
-            }
-            unsafe { (*view).0.stamp().nanosec }
+            ffi_guard(|| {
+                if bad_handle(view) {
+                    return 0;
+                }
+                unsafe { (*view).0.stamp().nanosec }
+            })
         }
 
         /// Get frame_id (borrowed pointer valid while handle lives).
         #[no_mangle]
         pub extern "C" fn $frame_id(view: *const $c_name) -> *const c_char {
-            if view.is_null() {
-                return ptr::null();
-            }
-            str_as_c(unsafe { (*view).0.frame_id() })
+            ffi_guard(|| {
+                if bad_handle(view) {
+                    return ptr::null();
+                }
+                str_as_c(unsafe { (*view).0.frame_id() })
+            })
         }
     };
 }
@@ -2322,6 +3459,102 @@ impl_simple_stamped!(
     ros_point_stamped_get_frame_id
 );
 
+impl_simple_stamped!(
+    ros_pose_stamped_t,
+    geometry_msgs::PoseStamped<&'static [u8]>,
+    ros_pose_stamped_from_cdr,
+    ros_pose_stamped_free,
+    ros_pose_stamped_get_stamp_sec,
+    ros_pose_stamped_get_stamp_nanosec,
+    ros_pose_stamped_get_frame_id
+);
+
+/// Write the pose position (px, py, pz) and orientation (ox, oy, oz, ow)
+/// to the provided output pointers; NULL outputs are skipped.
+#[no_mangle]
+pub extern "C" fn ros_pose_stamped_get_pose(
+    view: *const ros_pose_stamped_t,
+    px: *mut f64,
+    py: *mut f64,
+    pz: *mut f64,
+    ox: *mut f64,
+    oy: *mut f64,
+    oz: *mut f64,
+    ow: *mut f64,
+) {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
+        }
+        let p = unsafe { (*view).0.pose() };
+        unsafe {
+            if !px.is_null() {
+                *px = p.position.x;
+            }
+            if !py.is_null() {
+                *py = p.position.y;
+            }
+            if !pz.is_null() {
+                *pz = p.position.z;
+            }
+            if !ox.is_null() {
+                *ox = p.orientation.x;
+            }
+            if !oy.is_null() {
+                *oy = p.orientation.y;
+            }
+            if !oz.is_null() {
+                *oz = p.orientation.z;
+            }
+            if !ow.is_null() {
+                *ow = p.orientation.w;
+            }
+        }
+    })
+}
+
+/// Encode a PoseStamped into a newly allocated buffer; free it with
+/// `ros_bytes_free()`.
+#[no_mangle]
+pub extern "C" fn ros_pose_stamped_encode(
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+    stamp_sec: i32,
+    stamp_nanosec: u32,
+    frame_id: *const c_char,
+    px: f64,
+    py: f64,
+    pz: f64,
+    ox: f64,
+    oy: f64,
+    oz: f64,
+    ow: f64,
+) -> i32 {
+    ffi_guard(|| {
+        let fid = unsafe { c_to_str(frame_id) };
+        let pose = Pose {
+            position: Point {
+                x: px,
+                y: py,
+                z: pz,
+            },
+            orientation: Quaternion {
+                x: ox,
+                y: oy,
+                z: oz,
+                w: ow,
+            },
+        };
+        match geometry_msgs::PoseStamped::new(Time::new(stamp_sec, stamp_nanosec), fid, pose) {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
+}
+
 impl_simple_stamped!(
     ros_inertia_stamped_t,
     geometry_msgs::InertiaStamped<&'static [u8]>,
@@ -2344,98 +3577,120 @@ pub extern "C" fn ros_mavros_altitude_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_altitude_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::Altitude::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_altitude_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::Altitude::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_altitude_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_free(view: *mut ros_mavros_altitude_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_stamp_sec(view: *const ros_mavros_altitude_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_stamp_nanosec(view: *const ros_mavros_altitude_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_frame_id(
     view: *const ros_mavros_altitude_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_monotonic(view: *const ros_mavros_altitude_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.monotonic() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.monotonic() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_amsl(view: *const ros_mavros_altitude_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.amsl() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.amsl() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_local(view: *const ros_mavros_altitude_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.local() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.local() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_relative(view: *const ros_mavros_altitude_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.relative() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.relative() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_terrain(view: *const ros_mavros_altitude_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.terrain() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.terrain() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_altitude_get_bottom_clearance(
     view: *const ros_mavros_altitude_t,
 ) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.bottom_clearance() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.bottom_clearance() }
+    })
 }
 
 // =============================================================================
@@ -2449,96 +3704,118 @@ pub extern "C" fn ros_mavros_vfrhud_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_vfrhud_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::VfrHud::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_vfrhud_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::VfrHud::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_vfrhud_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_free(view: *mut ros_mavros_vfrhud_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_stamp_sec(view: *const ros_mavros_vfrhud_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_stamp_nanosec(view: *const ros_mavros_vfrhud_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_frame_id(
     view: *const ros_mavros_vfrhud_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_airspeed(view: *const ros_mavros_vfrhud_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.airspeed() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.airspeed() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_groundspeed(view: *const ros_mavros_vfrhud_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.groundspeed() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.groundspeed() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_heading(view: *const ros_mavros_vfrhud_t) -> i16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.heading() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.heading() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_throttle(view: *const ros_mavros_vfrhud_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.throttle() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.throttle() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_altitude(view: *const ros_mavros_vfrhud_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.altitude() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.altitude() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_vfrhud_get_climb(view: *const ros_mavros_vfrhud_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.climb() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.climb() }
+    })
 }
 
 // =============================================================================
@@ -2552,172 +3829,206 @@ pub extern "C" fn ros_mavros_estimator_status_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_estimator_status_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::EstimatorStatus::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_estimator_status_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::EstimatorStatus::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_estimator_status_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_free(view: *mut ros_mavros_estimator_status_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_stamp_sec(
     view: *const ros_mavros_estimator_status_t,
 ) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_stamp_nanosec(
     view: *const ros_mavros_estimator_status_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_frame_id(
     view: *const ros_mavros_estimator_status_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_attitude_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.attitude_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.attitude_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_velocity_horiz_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.velocity_horiz_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.velocity_horiz_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_velocity_vert_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.velocity_vert_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.velocity_vert_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_pos_horiz_rel_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.pos_horiz_rel_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.pos_horiz_rel_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_pos_horiz_abs_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.pos_horiz_abs_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.pos_horiz_abs_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_pos_vert_abs_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.pos_vert_abs_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.pos_vert_abs_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_pos_vert_agl_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.pos_vert_agl_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.pos_vert_agl_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_const_pos_mode_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.const_pos_mode_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.const_pos_mode_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_pred_pos_horiz_rel_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.pred_pos_horiz_rel_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.pred_pos_horiz_rel_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_pred_pos_horiz_abs_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.pred_pos_horiz_abs_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.pred_pos_horiz_abs_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_gps_glitch_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.gps_glitch_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.gps_glitch_status_flag() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_estimator_status_get_accel_error_status_flag(
     view: *const ros_mavros_estimator_status_t,
 ) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.accel_error_status_flag() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.accel_error_status_flag() }
+    })
 }
 
 // =============================================================================
@@ -2731,72 +4042,86 @@ pub extern "C" fn ros_mavros_extended_state_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_extended_state_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::ExtendedState::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_extended_state_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::ExtendedState::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_extended_state_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_extended_state_free(view: *mut ros_mavros_extended_state_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_extended_state_get_stamp_sec(
     view: *const ros_mavros_extended_state_t,
 ) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_extended_state_get_stamp_nanosec(
     view: *const ros_mavros_extended_state_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_extended_state_get_frame_id(
     view: *const ros_mavros_extended_state_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_extended_state_get_vtol_state(
     view: *const ros_mavros_extended_state_t,
 ) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.vtol_state() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.vtol_state() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_extended_state_get_landed_state(
     view: *const ros_mavros_extended_state_t,
 ) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.landed_state() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.landed_state() }
+    })
 }
 
 // =============================================================================
@@ -2810,178 +4135,214 @@ pub extern "C" fn ros_mavros_sys_status_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_sys_status_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::SysStatus::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_sys_status_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::SysStatus::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_sys_status_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_free(view: *mut ros_mavros_sys_status_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_stamp_sec(view: *const ros_mavros_sys_status_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_stamp_nanosec(
     view: *const ros_mavros_sys_status_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_frame_id(
     view: *const ros_mavros_sys_status_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_sensors_present(
     view: *const ros_mavros_sys_status_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.sensors_present() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.sensors_present() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_sensors_enabled(
     view: *const ros_mavros_sys_status_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.sensors_enabled() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.sensors_enabled() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_sensors_health(
     view: *const ros_mavros_sys_status_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.sensors_health() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.sensors_health() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_load(view: *const ros_mavros_sys_status_t) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.load() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.load() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_voltage_battery(
     view: *const ros_mavros_sys_status_t,
 ) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.voltage_battery() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.voltage_battery() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_current_battery(
     view: *const ros_mavros_sys_status_t,
 ) -> i16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.current_battery() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.current_battery() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_battery_remaining(
     view: *const ros_mavros_sys_status_t,
 ) -> i8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.battery_remaining() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.battery_remaining() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_drop_rate_comm(
     view: *const ros_mavros_sys_status_t,
 ) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.drop_rate_comm() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.drop_rate_comm() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_errors_comm(
     view: *const ros_mavros_sys_status_t,
 ) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.errors_comm() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.errors_comm() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_errors_count1(
     view: *const ros_mavros_sys_status_t,
 ) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.errors_count1() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.errors_count1() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_errors_count2(
     view: *const ros_mavros_sys_status_t,
 ) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.errors_count2() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.errors_count2() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_errors_count3(
     view: *const ros_mavros_sys_status_t,
 ) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.errors_count3() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.errors_count3() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_sys_status_get_errors_count4(
     view: *const ros_mavros_sys_status_t,
 ) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.errors_count4() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.errors_count4() }
+    })
 }
 
 // =============================================================================
@@ -2995,94 +4356,116 @@ pub extern "C" fn ros_mavros_state_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_state_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::State::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_state_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::State::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_state_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_free(view: *mut ros_mavros_state_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_stamp_sec(view: *const ros_mavros_state_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_stamp_nanosec(view: *const ros_mavros_state_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_frame_id(view: *const ros_mavros_state_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_connected(view: *const ros_mavros_state_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.connected() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.connected() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_armed(view: *const ros_mavros_state_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.armed() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.armed() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_guided(view: *const ros_mavros_state_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.guided() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.guided() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_manual_input(view: *const ros_mavros_state_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.manual_input() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.manual_input() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_mode(view: *const ros_mavros_state_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.mode() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.mode() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_state_get_system_status(view: *const ros_mavros_state_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.system_status() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.system_status() }
+    })
 }
 
 // =============================================================================
@@ -3096,70 +4479,84 @@ pub extern "C" fn ros_mavros_status_text_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_status_text_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::StatusText::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_status_text_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::StatusText::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_status_text_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_status_text_free(view: *mut ros_mavros_status_text_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_status_text_get_stamp_sec(
     view: *const ros_mavros_status_text_t,
 ) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_status_text_get_stamp_nanosec(
     view: *const ros_mavros_status_text_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_status_text_get_frame_id(
     view: *const ros_mavros_status_text_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_status_text_get_severity(view: *const ros_mavros_status_text_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.severity() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.severity() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_status_text_get_text(
     view: *const ros_mavros_status_text_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.text() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.text() })
+    })
 }
 
 // =============================================================================
@@ -3173,186 +4570,230 @@ pub extern "C" fn ros_mavros_gps_raw_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_gps_raw_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::GpsRaw::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_gps_raw_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::GpsRaw::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_gps_raw_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_free(view: *mut ros_mavros_gps_raw_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_stamp_sec(view: *const ros_mavros_gps_raw_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_stamp_nanosec(view: *const ros_mavros_gps_raw_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_frame_id(
     view: *const ros_mavros_gps_raw_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_fix_type(view: *const ros_mavros_gps_raw_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.fix_type() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.fix_type() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_lat(view: *const ros_mavros_gps_raw_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.lat() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.lat() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_lon(view: *const ros_mavros_gps_raw_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.lon() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.lon() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_alt(view: *const ros_mavros_gps_raw_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.alt() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.alt() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_eph(view: *const ros_mavros_gps_raw_t) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.eph() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.eph() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_epv(view: *const ros_mavros_gps_raw_t) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.epv() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.epv() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_vel(view: *const ros_mavros_gps_raw_t) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.vel() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.vel() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_cog(view: *const ros_mavros_gps_raw_t) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.cog() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.cog() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_satellites_visible(
     view: *const ros_mavros_gps_raw_t,
 ) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.satellites_visible() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.satellites_visible() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_alt_ellipsoid(view: *const ros_mavros_gps_raw_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.alt_ellipsoid() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.alt_ellipsoid() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_h_acc(view: *const ros_mavros_gps_raw_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.h_acc() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.h_acc() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_v_acc(view: *const ros_mavros_gps_raw_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.v_acc() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.v_acc() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_vel_acc(view: *const ros_mavros_gps_raw_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.vel_acc() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.vel_acc() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_hdg_acc(view: *const ros_mavros_gps_raw_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.hdg_acc() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.hdg_acc() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_yaw(view: *const ros_mavros_gps_raw_t) -> u16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.yaw() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.yaw() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_dgps_numch(view: *const ros_mavros_gps_raw_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.dgps_numch() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.dgps_numch() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_gps_raw_get_dgps_age(view: *const ros_mavros_gps_raw_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.dgps_age() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.dgps_age() }
+    })
 }
 
 // =============================================================================
@@ -3366,99 +4807,117 @@ pub extern "C" fn ros_mavros_timesync_status_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_mavros_timesync_status_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match mavros_msgs::TimesyncStatus::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_mavros_timesync_status_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match mavros_msgs::TimesyncStatus::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_mavros_timesync_status_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_timesync_status_free(view: *mut ros_mavros_timesync_status_t) {
-    if !view.is_null() {
-        unsafe { drop(Box::from_raw(view)) }
-    }
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe { free_handle(view) }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_timesync_status_get_stamp_sec(
     view: *const ros_mavros_timesync_status_t,
 ) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_timesync_status_get_stamp_nanosec(
     view: *const ros_mavros_timesync_status_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_timesync_status_get_frame_id(
     view: *const ros_mavros_timesync_status_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_timesync_status_get_remote_timestamp_ns(
     view: *const ros_mavros_timesync_status_t,
 ) -> u64 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.remote_timestamp_ns() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.remote_timestamp_ns() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_timesync_status_get_observed_offset_ns(
     view: *const ros_mavros_timesync_status_t,
 ) -> i64 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.observed_offset_ns() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.observed_offset_ns() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_timesync_status_get_estimated_offset_ns(
     view: *const ros_mavros_timesync_status_t,
 ) -> i64 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.estimated_offset_ns() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.estimated_offset_ns() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mavros_timesync_status_get_round_trip_time_ms(
     view: *const ros_mavros_timesync_status_t,
 ) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.round_trip_time_ms() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.round_trip_time_ms() }
+    })
 }
 
 // =============================================================================
 // RadarCube (buffer-backed)
 // =============================================================================
 
-pub struct ros_radar_cube_t(edgefirst_msgs::RadarCube<&'static [u8]>);
+pub struct ros_radar_cube_t(edgefirst_msgs::RadarCube<&'static [u8]>, PayloadLocks);
 
 /// @brief Create a RadarCube view from CDR bytes.
 /// @param data CDR encoded bytes (borrowed; must outlive the returned handle)
@@ -3466,56 +4925,72 @@ pub struct ros_radar_cube_t(edgefirst_msgs::RadarCube<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_from_cdr(data: *const u8, len: usize) -> *mut ros_radar_cube_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::RadarCube::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_radar_cube_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::RadarCube::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_radar_cube_t(v, PayloadLocks::default())),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_free(view: *mut ros_radar_cube_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null()
+            && !bad_handle(view)
+            && unref_handle(view)
+            && unsafe { (*view).1.release() }
+        {
+            unsafe {
+                destroy_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_get_stamp_sec(view: *const ros_radar_cube_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_get_stamp_nanosec(view: *const ros_radar_cube_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_get_frame_id(view: *const ros_radar_cube_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_get_timestamp(view: *const ros_radar_cube_t) -> u64 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.timestamp() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.timestamp() }
+    })
 }
 
 #[no_mangle]
@@ -3523,21 +4998,23 @@ pub extern "C" fn ros_radar_cube_get_layout(
     view: *const ros_radar_cube_t,
     out_len: *mut usize,
 ) -> *const u8 {
-    if view.is_null() {
-        if !out_len.is_null() {
-            unsafe {
-                *out_len = 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
             }
+            return ptr::null();
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).0.layout() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        let data = unsafe { (*view).0.layout() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
         }
-    }
-    data.as_ptr()
+        data.as_ptr()
+    })
 }
 
 #[no_mangle]
@@ -3545,37 +5022,101 @@ pub extern "C" fn ros_radar_cube_get_cube_raw(
     view: *const ros_radar_cube_t,
     out_len: *mut usize,
 ) -> *const u8 {
-    if view.is_null() {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let data = unsafe { (*view).0.cube_raw() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
+        }
+        data.as_ptr()
+    })
+}
+
+/// Borrow the cube payload for as long as the caller holds the lock,
+/// independent of when `ros_radar_cube_free` is called: freeing a locked handle
+/// defers its destruction to the last `ros_radar_cube_cube_unlock`.
+///
+/// Only handles from `ros_radar_cube_clone` own their bytes; one from
+/// `ros_radar_cube_from_cdr` borrows the caller's buffer, which a lock could not
+/// keep alive, so it returns NULL with `errno = EINVAL`.
+#[no_mangle]
+pub extern "C" fn ros_radar_cube_cube_lock(
+    view: *mut ros_radar_cube_t,
+    out_len: *mut usize,
+) -> *const u8 {
+    ffi_guard(|| {
+        if !lockable(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let view = unsafe { &*view };
+        view.1.lock();
+        let data = view.0.cube_raw();
         if !out_len.is_null() {
             unsafe {
-                *out_len = 0;
+                *out_len = data.len();
             }
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).0.cube_raw() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        data.as_ptr()
+    })
+}
+
+/// Release a `ros_radar_cube_cube_lock` borrow. Returns -1 with `errno = EINVAL`
+/// for a NULL handle or one with no lock held.
+#[no_mangle]
+pub extern "C" fn ros_radar_cube_cube_unlock(view: *mut ros_radar_cube_t) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return -1;
         }
-    }
-    data.as_ptr()
+        match unsafe { (*view).1.unlock() } {
+            Ok(destroy) => {
+                if destroy {
+                    unsafe {
+                        destroy_handle(view);
+                    }
+                }
+                0
+            }
+            Err(()) => {
+                set_error(EINVAL, "payload is not locked");
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_get_cube_len(view: *const ros_radar_cube_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.cube_len() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.cube_len() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_get_is_complex(view: *const ros_radar_cube_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.is_complex() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.is_complex() }
+    })
 }
 
 // =============================================================================
@@ -3590,94 +5131,114 @@ pub struct ros_radar_info_t(edgefirst_msgs::RadarInfo<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_radar_info_from_cdr(data: *const u8, len: usize) -> *mut ros_radar_info_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::RadarInfo::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_radar_info_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::RadarInfo::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_radar_info_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_free(view: *mut ros_radar_info_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_get_stamp_sec(view: *const ros_radar_info_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_get_stamp_nanosec(view: *const ros_radar_info_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_get_frame_id(view: *const ros_radar_info_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_get_center_frequency(
     view: *const ros_radar_info_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.center_frequency() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.center_frequency() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_get_frequency_sweep(
     view: *const ros_radar_info_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frequency_sweep() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frequency_sweep() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_get_range_toggle(view: *const ros_radar_info_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.range_toggle() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.range_toggle() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_get_detection_sensitivity(
     view: *const ros_radar_info_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.detection_sensitivity() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.detection_sensitivity() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_get_cube(view: *const ros_radar_info_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.cube() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.cube() }
+    })
 }
 
 // =============================================================================
@@ -3699,72 +5260,88 @@ pub struct ros_detect_t {
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_detect_from_cdr(data: *const u8, len: usize) -> *mut ros_detect_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::Detect::from_cdr_collect_boxes(unsafe { erase_lifetime(slice) }) {
-        Ok((v, box_views)) => {
-            // box_views were collected during the single validation walk;
-            // no second pass over the CDR buffer is needed here. Each child
-            // is marked `owned: false` so ros_box_free safely no-ops if a
-            // caller mistakenly casts away const and passes a borrowed child.
-            let child_boxes: Vec<ros_box_t> = box_views
-                .into_iter()
-                .map(|bv| ros_box_t {
-                    view: bv,
-                    owned: false,
-                })
-                .collect();
-            Box::into_raw(Box::new(ros_detect_t {
-                inner: v,
-                child_boxes,
-            }))
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::Detect::from_cdr_collect_boxes(unsafe { erase_lifetime(slice) }) {
+            Ok((v, box_views)) => {
+                // box_views were collected during the single validation walk;
+                // no second pass over the CDR buffer is needed here. Each child
+                // is marked `owned: false` so ros_box_free safely no-ops if a
+                // caller mistakenly casts away const and passes a borrowed child.
+                let child_boxes: Vec<ros_box_t> = box_views
+                    .into_iter()
+                    .map(|bv| ros_box_t {
+                        view: bv,
+                        owned: false,
+                    })
+                    .collect();
+                let h = new_handle(ros_detect_t {
+                    inner: v,
+                    child_boxes,
+                });
+                for b in unsafe { &(*h).child_boxes } {
+                    track_child(h, b);
+                }
+                h
+            }
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_free(view: *mut ros_detect_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_get_stamp_sec(view: *const ros_detect_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_get_stamp_nanosec(view: *const ros_detect_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_get_frame_id(view: *const ros_detect_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).inner.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).inner.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_get_boxes_len(view: *const ros_detect_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).child_boxes.len() as u32 }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).child_boxes.len() as u32 }
+    })
 }
 
 /// @brief Get a borrowed view of the i-th detection box.
@@ -3786,17 +5363,18 @@ pub extern "C" fn ros_detect_get_boxes_len(view: *const ros_detect_t) -> u32 {
 /// touched, so heap corruption from misuse is not possible.
 #[no_mangle]
 pub extern "C" fn ros_detect_get_box(view: *const ros_detect_t, index: u32) -> *const ros_box_t {
-    if view.is_null() {
-        set_errno(EINVAL);
-        return ptr::null();
-    }
-    let v = unsafe { &*view };
-    let idx = index as usize;
-    if idx >= v.child_boxes.len() {
-        set_errno(EINVAL);
-        return ptr::null();
-    }
-    &v.child_boxes[idx] as *const ros_box_t
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        let v = unsafe { &*view };
+        let idx = index as usize;
+        if idx >= v.child_boxes.len() {
+            set_errno(EINVAL);
+            return ptr::null();
+        }
+        &v.child_boxes[idx] as *const ros_box_t
+    })
 }
 
 // =============================================================================
@@ -3820,89 +5398,111 @@ pub struct ros_model_t {
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_model_from_cdr(data: *const u8, len: usize) -> *mut ros_model_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::Model::from_cdr_collect_children(unsafe { erase_lifetime(slice) }) {
-        Ok((v, box_views, mask_views)) => {
-            // box_views and mask_views were collected during the single
-            // validation walk; no second pass over the CDR buffer is needed.
-            // Each child is marked `owned: false` so ros_box_free /
-            // ros_mask_free safely no-op if a caller mistakenly casts
-            // away const and passes a borrowed child.
-            let child_boxes: Vec<ros_box_t> = box_views
-                .into_iter()
-                .map(|bv| ros_box_t {
-                    view: bv,
-                    owned: false,
-                })
-                .collect();
-            let child_masks: Vec<ros_mask_t> = mask_views
-                .into_iter()
-                .map(|mv| ros_mask_t {
-                    view: mv,
-                    owned: false,
-                })
-                .collect();
-            Box::into_raw(Box::new(ros_model_t {
-                inner: v,
-                child_boxes,
-                child_masks,
-            }))
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::Model::from_cdr_collect_children(unsafe { erase_lifetime(slice) }) {
+            Ok((v, box_views, mask_views)) => {
+                // box_views and mask_views were collected during the single
+                // validation walk; no second pass over the CDR buffer is needed.
+                // Each child is marked `owned: false` so ros_box_free /
+                // ros_mask_free safely no-op if a caller mistakenly casts
+                // away const and passes a borrowed child.
+                let child_boxes: Vec<ros_box_t> = box_views
+                    .into_iter()
+                    .map(|bv| ros_box_t {
+                        view: bv,
+                        owned: false,
+                    })
+                    .collect();
+                let child_masks: Vec<ros_mask_t> = mask_views
+                    .into_iter()
+                    .map(|mv| ros_mask_t {
+                        view: mv,
+                        owned: false,
+                    })
+                    .collect();
+                let h = new_handle(ros_model_t {
+                    inner: v,
+                    child_boxes,
+                    child_masks,
+                });
+                let model = unsafe { &*h };
+                for b in &model.child_boxes {
+                    track_child(h, b);
+                }
+                for m in &model.child_masks {
+                    track_child(h, m);
+                }
+                h
+            }
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_free(view: *mut ros_model_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_get_stamp_sec(view: *const ros_model_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_get_stamp_nanosec(view: *const ros_model_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).inner.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).inner.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_get_frame_id(view: *const ros_model_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).inner.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).inner.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_get_boxes_len(view: *const ros_model_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).child_boxes.len() as u32 }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).child_boxes.len() as u32 }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_get_masks_len(view: *const ros_model_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).child_masks.len() as u32 }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).child_masks.len() as u32 }
+    })
 }
 
 /// @brief Get a borrowed view of the i-th model box.
@@ -3922,17 +5522,18 @@ pub extern "C" fn ros_model_get_masks_len(view: *const ros_model_t) -> u32 {
 /// not possible.
 #[no_mangle]
 pub extern "C" fn ros_model_get_box(view: *const ros_model_t, index: u32) -> *const ros_box_t {
-    if view.is_null() {
-        set_errno(EINVAL);
-        return ptr::null();
-    }
-    let v = unsafe { &*view };
-    let idx = index as usize;
-    if idx >= v.child_boxes.len() {
-        set_errno(EINVAL);
-        return ptr::null();
-    }
-    &v.child_boxes[idx] as *const ros_box_t
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        let v = unsafe { &*view };
+        let idx = index as usize;
+        if idx >= v.child_boxes.len() {
+            set_errno(EINVAL);
+            return ptr::null();
+        }
+        &v.child_boxes[idx] as *const ros_box_t
+    })
 }
 
 /// @brief Get a borrowed view of the i-th model mask.
@@ -3952,17 +5553,18 @@ pub extern "C" fn ros_model_get_box(view: *const ros_model_t, index: u32) -> *co
 /// not possible.
 #[no_mangle]
 pub extern "C" fn ros_model_get_mask(view: *const ros_model_t, index: u32) -> *const ros_mask_t {
-    if view.is_null() {
-        set_errno(EINVAL);
-        return ptr::null();
-    }
-    let v = unsafe { &*view };
-    let idx = index as usize;
-    if idx >= v.child_masks.len() {
-        set_errno(EINVAL);
-        return ptr::null();
-    }
-    &v.child_masks[idx] as *const ros_mask_t
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        let v = unsafe { &*view };
+        let idx = index as usize;
+        if idx >= v.child_masks.len() {
+            set_errno(EINVAL);
+            return ptr::null();
+        }
+        &v.child_masks[idx] as *const ros_mask_t
+    })
 }
 
 // =============================================================================
@@ -3977,88 +5579,108 @@ pub struct ros_model_info_t(edgefirst_msgs::ModelInfo<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_model_info_from_cdr(data: *const u8, len: usize) -> *mut ros_model_info_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::ModelInfo::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_model_info_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::ModelInfo::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_model_info_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_free(view: *mut ros_model_info_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_stamp_sec(view: *const ros_model_info_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_stamp_nanosec(view: *const ros_model_info_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_frame_id(view: *const ros_model_info_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_model_type(view: *const ros_model_info_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.model_type() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.model_type() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_model_format(view: *const ros_model_info_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.model_format() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.model_format() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_model_name(view: *const ros_model_info_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.model_name() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.model_name() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_input_type(view: *const ros_model_info_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.input_type() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.input_type() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_output_type(view: *const ros_model_info_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.output_type() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.output_type() }
+    })
 }
 
 #[no_mangle]
@@ -4066,21 +5688,23 @@ pub extern "C" fn ros_model_info_get_input_shape(
     view: *const ros_model_info_t,
     out_len: *mut usize,
 ) -> *const u32 {
-    if view.is_null() {
-        if !out_len.is_null() {
-            unsafe {
-                *out_len = 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
             }
+            return ptr::null();
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).0.input_shape() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        let data = unsafe { (*view).0.input_shape() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
         }
-    }
-    data.as_ptr()
+        data.as_ptr()
+    })
 }
 
 #[no_mangle]
@@ -4088,29 +5712,33 @@ pub extern "C" fn ros_model_info_get_output_shape(
     view: *const ros_model_info_t,
     out_len: *mut usize,
 ) -> *const u32 {
-    if view.is_null() {
-        if !out_len.is_null() {
-            unsafe {
-                *out_len = 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
             }
+            return ptr::null();
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).0.output_shape() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        let data = unsafe { (*view).0.output_shape() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
         }
-    }
-    data.as_ptr()
+        data.as_ptr()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_get_labels_len(view: *const ros_model_info_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.labels_len() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.labels_len() }
+    })
 }
 
 #[no_mangle]
@@ -4118,24 +5746,51 @@ pub extern "C" fn ros_model_info_get_label(
     view: *const ros_model_info_t,
     index: u32,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    let labels = unsafe { (*view).0.labels() };
-    match labels.get(index as usize) {
-        Some(s) => str_as_c(s),
-        None => {
-            set_errno(EINVAL);
-            ptr::null()
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        let labels = unsafe { (*view).0.labels() };
+        match labels.get(index as usize) {
+            Some(s) => str_as_c(s),
+            None => {
+                set_errno(EINVAL);
+                ptr::null()
+            }
         }
-    }
+    })
+}
+
+/// Fill `out` with up to `cap` label pointers (borrowed, valid while the
+/// handle lives); returns the total label count, so a NULL `out` or a `cap`
+/// of 0 queries it.
+#[no_mangle]
+pub extern "C" fn ros_model_info_get_labels(
+    view: *const ros_model_info_t,
+    out: *mut *const c_char,
+    cap: usize,
+) -> u32 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        let labels = unsafe { (*view).0.labels() };
+        if !out.is_null() {
+            for (i, s) in labels.iter().take(cap).enumerate() {
+                unsafe {
+                    *out.add(i) = str_as_c(s);
+                }
+            }
+        }
+        labels.len() as u32
+    })
 }
 
 // =============================================================================
 // PointCloud2 (buffer-backed)
 // =============================================================================
 
-pub struct ros_point_cloud2_t(sensor_msgs::PointCloud2<&'static [u8]>);
+pub struct ros_point_cloud2_t(sensor_msgs::PointCloud2<&'static [u8]>, PayloadLocks);
 
 /// @brief Create a PointCloud2 view from CDR bytes.
 /// @param data CDR encoded bytes (borrowed; must outlive the returned handle)
@@ -4146,80 +5801,102 @@ pub extern "C" fn ros_point_cloud2_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_point_cloud2_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::PointCloud2::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_point_cloud2_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::PointCloud2::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_point_cloud2_t(v, PayloadLocks::default())),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_free(view: *mut ros_point_cloud2_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null()
+            && !bad_handle(view)
+            && unref_handle(view)
+            && unsafe { (*view).1.release() }
+        {
+            unsafe {
+                destroy_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_stamp_sec(view: *const ros_point_cloud2_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_stamp_nanosec(view: *const ros_point_cloud2_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_frame_id(view: *const ros_point_cloud2_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_height(view: *const ros_point_cloud2_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.height() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.height() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_width(view: *const ros_point_cloud2_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.width() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.width() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_point_step(view: *const ros_point_cloud2_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.point_step() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.point_step() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_row_step(view: *const ros_point_cloud2_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.row_step() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.row_step() }
+    })
 }
 
 #[no_mangle]
@@ -4227,45 +5904,307 @@ pub extern "C" fn ros_point_cloud2_get_data(
     view: *const ros_point_cloud2_t,
     out_len: *mut usize,
 ) -> *const u8 {
-    if view.is_null() {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let data = unsafe { (*view).0.data() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
+        }
+        data.as_ptr()
+    })
+}
+
+/// Borrow the data payload for as long as the caller holds the lock,
+/// independent of when `ros_point_cloud2_free` is called: freeing a locked handle
+/// defers its destruction to the last `ros_point_cloud2_data_unlock`.
+///
+/// Only handles from `ros_point_cloud2_clone` own their bytes; one from
+/// `ros_point_cloud2_from_cdr` borrows the caller's buffer, which a lock could not
+/// keep alive, so it returns NULL with `errno = EINVAL`.
+#[no_mangle]
+pub extern "C" fn ros_point_cloud2_data_lock(
+    view: *mut ros_point_cloud2_t,
+    out_len: *mut usize,
+) -> *const u8 {
+    ffi_guard(|| {
+        if !lockable(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let view = unsafe { &*view };
+        view.1.lock();
+        let data = view.0.data();
         if !out_len.is_null() {
             unsafe {
-                *out_len = 0;
+                *out_len = data.len();
             }
         }
-        return ptr::null();
-    }
-    let data = unsafe { (*view).0.data() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = data.len();
+        data.as_ptr()
+    })
+}
+
+/// Release a `ros_point_cloud2_data_lock` borrow. Returns -1 with `errno = EINVAL`
+/// for a NULL handle or one with no lock held.
+#[no_mangle]
+pub extern "C" fn ros_point_cloud2_data_unlock(view: *mut ros_point_cloud2_t) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return -1;
         }
-    }
-    data.as_ptr()
+        match unsafe { (*view).1.unlock() } {
+            Ok(destroy) => {
+                if destroy {
+                    unsafe {
+                        destroy_handle(view);
+                    }
+                }
+                0
+            }
+            Err(()) => {
+                set_error(EINVAL, "payload is not locked");
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_is_dense(view: *const ros_point_cloud2_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.is_dense() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.is_dense() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_is_bigendian(view: *const ros_point_cloud2_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.is_bigendian() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.is_bigendian() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_get_fields_len(view: *const ros_point_cloud2_t) -> u32 {
-    if view.is_null() {
-        return 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.fields_len() }
+    })
+}
+
+// =============================================================================
+// Decoded point clouds
+// =============================================================================
+
+/// A PointCloud2 unpacked into one `float` column per field, plus
+/// `cluster_id` as integers. Owns its columns, so it outlives the view it
+/// was decoded from.
+pub struct edgefirst_pcd_t {
+    count: usize,
+    columns: Vec<(std::ffi::CString, Vec<f32>)>,
+    cluster_id: Option<Vec<u32>>,
+}
+
+impl edgefirst_pcd_t {
+    fn column(&self, name: &str) -> Option<&[f32]> {
+        self.columns
+            .iter()
+            .find(|(n, _)| n.as_bytes() == name.as_bytes())
+            .map(|(_, v)| v.as_slice())
+    }
+}
+
+/// Unpack every field of a PointCloud2 view, whatever its stored type, so
+/// C consumers need not walk the PointField layout themselves.
+///
+/// Returns NULL with `errno = EINVAL` for a NULL view, `EBADMSG` for an
+/// unsupported layout (big-endian data, unknown datatypes, fields past
+/// `point_step`). Free with `edgefirst_pcd_free()`.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_decode(cloud: *const ros_point_cloud2_t) -> *mut edgefirst_pcd_t {
+    ffi_guard(|| {
+        check_null_ret_null!(cloud);
+        let cloud = unsafe { &(*cloud).0 };
+        let points = match sensor_msgs::pointcloud::DynPointCloud::from_pointcloud2(cloud) {
+            Ok(p) => p,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return ptr::null_mut();
+            }
+        };
+        let mut columns = Vec::with_capacity(points.field_count());
+        for f in points.fields() {
+            let (Ok(name), Some(values)) =
+                (std::ffi::CString::new(f.name), points.gather_as_f32(f.name))
+            else {
+                set_error(
+                    EBADMSG,
+                    format_args!("field `{}` could not be read", f.name),
+                );
+                return ptr::null_mut();
+            };
+            columns.push((name, values));
+        }
+        let cluster_id = points
+            .gather_as_f64("cluster_id")
+            .map(|ids| ids.into_iter().map(|id| id as u32).collect());
+        new_handle(edgefirst_pcd_t {
+            count: points.len(),
+            columns,
+            cluster_id,
+        })
+    })
+}
+
+/// Number of points. Returns 0 with `errno = EINVAL` for NULL.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_count(pcd: *const edgefirst_pcd_t) -> usize {
+    ffi_guard(|| {
+        if bad_handle(pcd) {
+            return 0;
+        }
+        unsafe { (*pcd).count }
+    })
+}
+
+/// Column of field `name` as `float`, `edgefirst_pcd_count()` long; valid
+/// while the handle lives. Returns NULL with `errno = EINVAL` for a NULL
+/// argument, `ENOENT` if the cloud has no such field.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_field(
+    pcd: *const edgefirst_pcd_t,
+    name: *const c_char,
+) -> *const f32 {
+    ffi_guard(|| {
+        if bad_handle(pcd) {
+            return ptr::null();
+        }
+        let Ok(name) = (unsafe { c_to_str_checked(name) }) else {
+            return ptr::null();
+        };
+        match unsafe { (*pcd).column(name) } {
+            Some(v) => v.as_ptr(),
+            None => {
+                set_error(ENOENT, format_args!("no field `{}`", name));
+                ptr::null()
+            }
+        }
+    })
+}
+
+fn pcd_axis(pcd: *const edgefirst_pcd_t, axis: &str) -> *const f32 {
+    if bad_handle(pcd) {
+        return ptr::null();
+    }
+    match unsafe { (*pcd).column(axis) } {
+        Some(v) => v.as_ptr(),
+        None => {
+            set_error(ENOENT, format_args!("no field `{}`", axis));
+            ptr::null()
+        }
     }
-    unsafe { (*view).0.fields_len() }
+}
+
+/// The `x` column; shorthand for `edgefirst_pcd_field(pcd, "x")`.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_x(pcd: *const edgefirst_pcd_t) -> *const f32 {
+    ffi_guard(|| pcd_axis(pcd, "x"))
+}
+
+/// The `y` column; shorthand for `edgefirst_pcd_field(pcd, "y")`.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_y(pcd: *const edgefirst_pcd_t) -> *const f32 {
+    ffi_guard(|| pcd_axis(pcd, "y"))
+}
+
+/// The `z` column; shorthand for `edgefirst_pcd_field(pcd, "z")`.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_z(pcd: *const edgefirst_pcd_t) -> *const f32 {
+    ffi_guard(|| pcd_axis(pcd, "z"))
+}
+
+/// The `cluster_id` column as integers, exact for any stored integer type
+/// (the `float` column from `edgefirst_pcd_field` rounds above 2^24).
+/// Returns NULL with `errno = EINVAL` for NULL, `ENOENT` if the cloud has
+/// no `cluster_id` field.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_cluster_id(pcd: *const edgefirst_pcd_t) -> *const u32 {
+    ffi_guard(|| {
+        if bad_handle(pcd) {
+            return ptr::null();
+        }
+        match unsafe { &(*pcd).cluster_id } {
+            Some(v) => v.as_ptr(),
+            None => {
+                set_error(ENOENT, "no field `cluster_id`");
+                ptr::null()
+            }
+        }
+    })
+}
+
+/// Number of fields, for enumerating them with `edgefirst_pcd_field_name()`.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_field_count(pcd: *const edgefirst_pcd_t) -> usize {
+    ffi_guard(|| {
+        if bad_handle(pcd) {
+            return 0;
+        }
+        unsafe { (*pcd).columns.len() }
+    })
+}
+
+/// Name of field `index`, in PointField order; valid while the handle
+/// lives. Returns NULL with `errno = EINVAL` for NULL or an index out of
+/// range.
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_field_name(
+    pcd: *const edgefirst_pcd_t,
+    index: usize,
+) -> *const c_char {
+    ffi_guard(|| {
+        if bad_handle(pcd) {
+            return ptr::null();
+        }
+        let pcd = unsafe { &*pcd };
+        match pcd.columns.get(index) {
+            Some((name, _)) => name.as_ptr(),
+            None => {
+                set_errno(EINVAL);
+                ptr::null()
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn edgefirst_pcd_free(pcd: *mut edgefirst_pcd_t) {
+    ffi_guard(|| {
+        if !pcd.is_null() {
+            unsafe {
+                free_handle(pcd);
+            }
+        }
+    })
 }
 
 // =============================================================================
@@ -4280,90 +6219,110 @@ pub struct ros_camera_info_t(sensor_msgs::CameraInfo<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_camera_info_from_cdr(data: *const u8, len: usize) -> *mut ros_camera_info_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::CameraInfo::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_camera_info_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::CameraInfo::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_camera_info_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_free(view: *mut ros_camera_info_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_get_stamp_sec(view: *const ros_camera_info_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_get_stamp_nanosec(view: *const ros_camera_info_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_get_frame_id(view: *const ros_camera_info_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_get_height(view: *const ros_camera_info_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.height() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.height() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_get_width(view: *const ros_camera_info_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.width() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.width() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_get_distortion_model(
     view: *const ros_camera_info_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.distortion_model() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.distortion_model() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_get_binning_x(view: *const ros_camera_info_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.binning_x() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.binning_x() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_get_binning_y(view: *const ros_camera_info_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.binning_y() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.binning_y() }
+    })
 }
 
 // =============================================================================
@@ -4378,40 +6337,48 @@ pub struct ros_track_t(edgefirst_msgs::Track<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_track_from_cdr(data: *const u8, len: usize) -> *mut ros_track_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::Track::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_track_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::Track::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_track_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_track_free(view: *mut ros_track_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_track_get_id(view: *const ros_track_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_track_get_lifetime(view: *const ros_track_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.lifetime() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.lifetime() }
+    })
 }
 
 // =============================================================================
@@ -4437,20 +6404,22 @@ pub struct ros_box_t {
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_box_from_cdr(data: *const u8, len: usize) -> *mut ros_box_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    let static_slice: &'static [u8] = unsafe { erase_lifetime(slice) };
-    // Parse directly into a `DetectBoxView<'static>`. The helper calls
-    // `scan_box_element` which returns a view whose `&str` fields are
-    // structurally tied to the buffer's `'static` lifetime, so no unsafe
-    // `mem::transmute` is required to widen method-returned references.
-    match edgefirst_msgs::DetectBox::from_cdr_as_view(static_slice) {
-        Ok(view) => Box::into_raw(Box::new(ros_box_t { view, owned: true })),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        let static_slice: &'static [u8] = unsafe { erase_lifetime(slice) };
+        // Parse directly into a `DetectBoxView<'static>`. The helper calls
+        // `scan_box_element` which returns a view whose `&str` fields are
+        // structurally tied to the buffer's `'static` lifetime, so no unsafe
+        // `mem::transmute` is required to widen method-returned references.
+        match edgefirst_msgs::DetectBox::from_cdr_as_view(static_slice) {
+            Ok(view) => new_handle(ros_box_t { view, owned: true }),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 /// @brief Free a DetectBox handle obtained from `ros_box_from_cdr`.
@@ -4465,97 +6434,119 @@ pub extern "C" fn ros_box_from_cdr(data: *const u8, len: usize) -> *mut ros_box_
 /// in CAPI.md); this is defense-in-depth against it.
 #[no_mangle]
 pub extern "C" fn ros_box_free(view: *mut ros_box_t) {
-    if view.is_null() {
-        return;
-    }
-    unsafe {
-        if (*view).owned {
-            drop(Box::from_raw(view));
-        } else {
-            // Parent-borrowed child — must not free.
-            set_errno(EINVAL);
+    ffi_guard(|| {
+        if view.is_null() || bad_handle(view) {
+            return;
         }
-    }
+        unsafe {
+            if (*view).owned {
+                free_handle(view);
+            } else {
+                // Parent-borrowed child — must not free.
+                set_errno(EINVAL);
+            }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_center_x(view: *const ros_box_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).view.center_x }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).view.center_x }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_center_y(view: *const ros_box_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).view.center_y }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).view.center_y }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_width(view: *const ros_box_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).view.width }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).view.width }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_height(view: *const ros_box_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).view.height }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).view.height }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_label(view: *const ros_box_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).view.label })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).view.label })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_score(view: *const ros_box_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).view.score }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).view.score }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_distance(view: *const ros_box_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).view.distance }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).view.distance }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_speed(view: *const ros_box_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).view.speed }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).view.speed }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_track_id(view: *const ros_box_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).view.track_id })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).view.track_id })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_box_get_track_lifetime(view: *const ros_box_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.track_lifetime }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.track_lifetime }
+    })
 }
 
 /// @brief Get the box's track_created timestamp seconds component.
@@ -4563,10 +6554,12 @@ pub extern "C" fn ros_box_get_track_lifetime(view: *const ros_box_t) -> i32 {
 /// @return Time.sec or 0 if view is NULL
 #[no_mangle]
 pub extern "C" fn ros_box_get_track_created_sec(view: *const ros_box_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.track_created.sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.track_created.sec }
+    })
 }
 
 /// @brief Get the box's track_created timestamp nanoseconds component.
@@ -4574,10 +6567,12 @@ pub extern "C" fn ros_box_get_track_created_sec(view: *const ros_box_t) -> i32 {
 /// @return Time.nanosec or 0 if view is NULL
 #[no_mangle]
 pub extern "C" fn ros_box_get_track_created_nanosec(view: *const ros_box_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).view.track_created.nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).view.track_created.nanosec }
+    })
 }
 
 // =============================================================================
@@ -4592,56 +6587,68 @@ pub struct ros_local_time_t(edgefirst_msgs::LocalTime<&'static [u8]>);
 /// @return Opaque handle or NULL on error (errno set)
 #[no_mangle]
 pub extern "C" fn ros_local_time_from_cdr(data: *const u8, len: usize) -> *mut ros_local_time_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::LocalTime::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_local_time_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::LocalTime::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_local_time_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_local_time_free(view: *mut ros_local_time_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_local_time_get_stamp_sec(view: *const ros_local_time_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_local_time_get_stamp_nanosec(view: *const ros_local_time_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_local_time_get_frame_id(view: *const ros_local_time_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_local_time_get_timezone(view: *const ros_local_time_t) -> i16 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.timezone() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.timezone() }
+    })
 }
 
 // =============================================================================
@@ -4655,21 +6662,23 @@ macro_rules! impl_as_cdr {
     ($fn_name:ident, $view_type:ty) => {
         #[no_mangle]
         pub extern "C" fn $fn_name(view: *const $view_type, out_len: *mut usize) -> *const u8 {
-            if view.is_null() {
-                if !out_len.is_null() {
-                    unsafe {
-                        *out_len = 0;
+            ffi_guard(|| {
+                if bad_handle(view) {
+                    if !out_len.is_null() {
+                        unsafe {
+                            *out_len = 0;
+                        }
                     }
+                    return ptr::null();
                 }
-                return ptr::null();
-            }
-            let cdr = unsafe { (*view).0.as_cdr() };
-            unsafe {
-                if !out_len.is_null() {
-                    *out_len = cdr.len();
+                let cdr = unsafe { (*view).0.as_cdr() };
+                unsafe {
+                    if !out_len.is_null() {
+                        *out_len = cdr.len();
+                    }
                 }
-            }
-            cdr.as_ptr()
+                cdr.as_ptr()
+            })
         }
     };
 }
@@ -4685,6 +6694,7 @@ impl_as_cdr!(ros_transform_stamped_as_cdr, ros_transform_stamped_t);
 impl_as_cdr!(ros_twist_stamped_as_cdr, ros_twist_stamped_t);
 impl_as_cdr!(ros_accel_stamped_as_cdr, ros_accel_stamped_t);
 impl_as_cdr!(ros_point_stamped_as_cdr, ros_point_stamped_t);
+impl_as_cdr!(ros_pose_stamped_as_cdr, ros_pose_stamped_t);
 impl_as_cdr!(ros_inertia_stamped_as_cdr, ros_inertia_stamped_t);
 impl_as_cdr!(ros_radar_cube_as_cdr, ros_radar_cube_t);
 impl_as_cdr!(ros_radar_info_as_cdr, ros_radar_info_t);
@@ -4699,6 +6709,8 @@ impl_as_cdr!(ros_temperature_as_cdr, ros_temperature_t);
 impl_as_cdr!(ros_battery_state_as_cdr, ros_battery_state_t);
 impl_as_cdr!(ros_odometry_as_cdr, ros_odometry_t);
 impl_as_cdr!(ros_vibration_as_cdr, ros_vibration_t);
+impl_as_cdr!(ros_laser_scan_as_cdr, ros_laser_scan_t);
+impl_as_cdr!(ros_occupancy_grid_as_cdr, ros_occupancy_grid_t);
 impl_as_cdr!(ros_mavros_altitude_as_cdr, ros_mavros_altitude_t);
 impl_as_cdr!(ros_mavros_vfrhud_as_cdr, ros_mavros_vfrhud_t);
 impl_as_cdr!(
@@ -4722,40 +6734,44 @@ impl_as_cdr!(
 
 #[no_mangle]
 pub extern "C" fn ros_detect_as_cdr(view: *const ros_detect_t, out_len: *mut usize) -> *const u8 {
-    if view.is_null() {
-        if !out_len.is_null() {
-            unsafe {
-                *out_len = 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
             }
+            return ptr::null();
         }
-        return ptr::null();
-    }
-    let cdr = unsafe { (*view).inner.as_cdr() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = cdr.len();
+        let cdr = unsafe { (*view).inner.as_cdr() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = cdr.len();
+            }
         }
-    }
-    cdr.as_ptr()
+        cdr.as_ptr()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_as_cdr(view: *const ros_model_t, out_len: *mut usize) -> *const u8 {
-    if view.is_null() {
-        if !out_len.is_null() {
-            unsafe {
-                *out_len = 0;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
             }
+            return ptr::null();
         }
-        return ptr::null();
-    }
-    let cdr = unsafe { (*view).inner.as_cdr() };
-    unsafe {
-        if !out_len.is_null() {
-            *out_len = cdr.len();
+        let cdr = unsafe { (*view).inner.as_cdr() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = cdr.len();
+            }
         }
-    }
-    cdr.as_ptr()
+        cdr.as_ptr()
+    })
 }
 
 // ros_box_as_cdr and ros_mask_as_cdr have been removed. Forwarding an embedded
@@ -4763,234 +6779,1555 @@ pub extern "C" fn ros_model_as_cdr(view: *const ros_model_t, out_len: *mut usize
 // the zero-copy contract. See CAPI.md for details.
 
 // =============================================================================
-// PoseWithCovariance (CdrFixed)
+// Deep copies
 // =============================================================================
 
-#[no_mangle]
-pub extern "C" fn ros_pose_with_covariance_encode(
-    buf: *mut u8,
-    cap: usize,
-    written: *mut usize,
-    px: f64,
-    py: f64,
-    pz: f64,
-    ox: f64,
-    oy: f64,
-    oz: f64,
-    ow: f64,
-    covariance: *const f64,
-) -> i32 {
-    if covariance.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut cov = [0.0_f64; 36];
-    unsafe {
-        ptr::copy_nonoverlapping(covariance, cov.as_mut_ptr(), 36);
-    }
-    let val = PoseWithCovariance {
-        pose: Pose {
-            position: Point {
-                x: px,
-                y: py,
-                z: pz,
-            },
-            orientation: Quaternion {
-                x: ox,
-                y: oy,
-                z: oz,
-                w: ow,
-            },
-        },
-        covariance: cov,
+/// `ros_<type>_clone(view)`: a new handle over its own copy of the message's
+/// CDR bytes, valid after the source handle and its buffer are gone (e.g.
+/// queued for another thread). Free it with `ros_<type>_free()`. Child
+/// boxes and masks have no CDR of their own; clone their parent.
+macro_rules! impl_clone {
+    ($fn_name:ident, $view_type:ty, $from_cdr:ident, $view:tt) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(view: *const $view_type) -> *mut $view_type {
+            ffi_guard(|| {
+                if bad_handle(view) {
+                    return ptr::null_mut();
+                }
+                clone_handle(unsafe { (*view).$view.as_cdr() }, $from_cdr)
+            })
+        }
     };
-    encode_fixed_to_buf(&val, buf, cap, written)
 }
 
-#[no_mangle]
-pub extern "C" fn ros_pose_with_covariance_decode(
-    data: *const u8,
-    len: usize,
-    px: *mut f64,
-    py: *mut f64,
-    pz: *mut f64,
-    ox: *mut f64,
-    oy: *mut f64,
-    oz: *mut f64,
-    ow: *mut f64,
-    covariance_out: *mut f64,
-) -> i32 {
-    match decode_fixed_from_buf::<PoseWithCovariance>(data, len) {
-        Ok(v) => unsafe {
-            if !px.is_null() {
-                *px = v.pose.position.x;
-            }
-            if !py.is_null() {
-                *py = v.pose.position.y;
-            }
-            if !pz.is_null() {
-                *pz = v.pose.position.z;
-            }
-            if !ox.is_null() {
-                *ox = v.pose.orientation.x;
-            }
-            if !oy.is_null() {
-                *oy = v.pose.orientation.y;
-            }
-            if !oz.is_null() {
-                *oz = v.pose.orientation.z;
-            }
-            if !ow.is_null() {
-                *ow = v.pose.orientation.w;
-            }
-            if !covariance_out.is_null() {
-                ptr::copy_nonoverlapping(v.covariance.as_ptr(), covariance_out, 36);
-            }
-            0
-        },
-        Err(()) => -1,
-    }
-}
+impl_clone!(ros_header_clone, ros_header_t, ros_header_from_cdr, 0);
+impl_clone!(ros_image_clone, ros_image_t, ros_image_from_cdr, 0);
+impl_clone!(
+    ros_compressed_image_clone,
+    ros_compressed_image_t,
+    ros_compressed_image_from_cdr,
+    0
+);
+impl_clone!(
+    ros_compressed_video_clone,
+    ros_compressed_video_t,
+    ros_compressed_video_from_cdr,
+    0
+);
+impl_clone!(
+    ros_dmabuffer_clone,
+    ros_dmabuffer_t,
+    ros_dmabuffer_from_cdr,
+    0
+);
+impl_clone!(ros_imu_clone, ros_imu_t, ros_imu_from_cdr, 0);
+impl_clone!(
+    ros_nav_sat_fix_clone,
+    ros_nav_sat_fix_t,
+    ros_nav_sat_fix_from_cdr,
+    0
+);
+impl_clone!(
+    ros_transform_stamped_clone,
+    ros_transform_stamped_t,
+    ros_transform_stamped_from_cdr,
+    0
+);
+impl_clone!(
+    ros_twist_stamped_clone,
+    ros_twist_stamped_t,
+    ros_twist_stamped_from_cdr,
+    0
+);
+impl_clone!(
+    ros_accel_stamped_clone,
+    ros_accel_stamped_t,
+    ros_accel_stamped_from_cdr,
+    0
+);
+impl_clone!(
+    ros_point_stamped_clone,
+    ros_point_stamped_t,
+    ros_point_stamped_from_cdr,
+    0
+);
+impl_clone!(
+    ros_pose_stamped_clone,
+    ros_pose_stamped_t,
+    ros_pose_stamped_from_cdr,
+    0
+);
+impl_clone!(
+    ros_inertia_stamped_clone,
+    ros_inertia_stamped_t,
+    ros_inertia_stamped_from_cdr,
+    0
+);
+impl_clone!(
+    ros_radar_cube_clone,
+    ros_radar_cube_t,
+    ros_radar_cube_from_cdr,
+    0
+);
+impl_clone!(
+    ros_radar_info_clone,
+    ros_radar_info_t,
+    ros_radar_info_from_cdr,
+    0
+);
+impl_clone!(
+    ros_model_info_clone,
+    ros_model_info_t,
+    ros_model_info_from_cdr,
+    0
+);
+impl_clone!(
+    ros_point_cloud2_clone,
+    ros_point_cloud2_t,
+    ros_point_cloud2_from_cdr,
+    0
+);
+impl_clone!(
+    ros_camera_info_clone,
+    ros_camera_info_t,
+    ros_camera_info_from_cdr,
+    0
+);
+impl_clone!(ros_track_clone, ros_track_t, ros_track_from_cdr, 0);
+impl_clone!(
+    ros_local_time_clone,
+    ros_local_time_t,
+    ros_local_time_from_cdr,
+    0
+);
+impl_clone!(
+    ros_magnetic_field_clone,
+    ros_magnetic_field_t,
+    ros_magnetic_field_from_cdr,
+    0
+);
+impl_clone!(
+    ros_fluid_pressure_clone,
+    ros_fluid_pressure_t,
+    ros_fluid_pressure_from_cdr,
+    0
+);
+impl_clone!(
+    ros_temperature_clone,
+    ros_temperature_t,
+    ros_temperature_from_cdr,
+    0
+);
+impl_clone!(
+    ros_battery_state_clone,
+    ros_battery_state_t,
+    ros_battery_state_from_cdr,
+    0
+);
+impl_clone!(ros_odometry_clone, ros_odometry_t, ros_odometry_from_cdr, 0);
+impl_clone!(
+    ros_vibration_clone,
+    ros_vibration_t,
+    ros_vibration_from_cdr,
+    0
+);
+impl_clone!(
+    ros_laser_scan_clone,
+    ros_laser_scan_t,
+    ros_laser_scan_from_cdr,
+    0
+);
+impl_clone!(
+    ros_occupancy_grid_clone,
+    ros_occupancy_grid_t,
+    ros_occupancy_grid_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_altitude_clone,
+    ros_mavros_altitude_t,
+    ros_mavros_altitude_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_vfrhud_clone,
+    ros_mavros_vfrhud_t,
+    ros_mavros_vfrhud_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_estimator_status_clone,
+    ros_mavros_estimator_status_t,
+    ros_mavros_estimator_status_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_extended_state_clone,
+    ros_mavros_extended_state_t,
+    ros_mavros_extended_state_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_sys_status_clone,
+    ros_mavros_sys_status_t,
+    ros_mavros_sys_status_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_state_clone,
+    ros_mavros_state_t,
+    ros_mavros_state_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_status_text_clone,
+    ros_mavros_status_text_t,
+    ros_mavros_status_text_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_gps_raw_clone,
+    ros_mavros_gps_raw_t,
+    ros_mavros_gps_raw_from_cdr,
+    0
+);
+impl_clone!(
+    ros_mavros_timesync_status_clone,
+    ros_mavros_timesync_status_t,
+    ros_mavros_timesync_status_from_cdr,
+    0
+);
+impl_clone!(ros_detect_clone, ros_detect_t, ros_detect_from_cdr, inner);
+impl_clone!(ros_model_clone, ros_model_t, ros_model_from_cdr, inner);
+impl_clone!(
+    ros_camera_frame_clone,
+    ros_camera_frame_t,
+    ros_camera_frame_from_cdr,
+    inner
+);
 
 // =============================================================================
-// TwistWithCovariance (CdrFixed)
+// Structural equality
 // =============================================================================
 
-#[no_mangle]
-pub extern "C" fn ros_twist_with_covariance_encode(
-    buf: *mut u8,
-    cap: usize,
-    written: *mut usize,
-    lx: f64,
-    ly: f64,
-    lz: f64,
-    ax: f64,
-    ay: f64,
-    az: f64,
-    covariance: *const f64,
-) -> i32 {
-    if covariance.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut cov = [0.0_f64; 36];
-    unsafe {
-        ptr::copy_nonoverlapping(covariance, cov.as_mut_ptr(), 36);
-    }
-    let val = TwistWithCovariance {
-        twist: Twist {
-            linear: Vector3 {
-                x: lx,
-                y: ly,
-                z: lz,
-            },
-            angular: Vector3 {
-                x: ax,
-                y: ay,
-                z: az,
-            },
-        },
-        covariance: cov,
+/// Compare two CDR messages of `schema` field by field (see
+/// [`crate::msg_spec::equal_msg`]). Returns 1 if equal, 0 if not, -1 with
+/// `errno = EBADMSG` if either does not decode.
+fn cdr_equals(schema: &str, a: &[u8], b: &[u8]) -> i32 {
+    let spec = match crate::msg_spec::Resolver::default().resolve(schema, 0) {
+        Ok(spec) => spec,
+        Err(_) => {
+            set_error(EBADMSG, format_args!("no definition for {}", schema));
+            return -1;
+        }
     };
-    encode_fixed_to_buf(&val, buf, cap, written)
-}
-
-#[no_mangle]
-pub extern "C" fn ros_twist_with_covariance_decode(
-    data: *const u8,
-    len: usize,
-    lx: *mut f64,
-    ly: *mut f64,
-    lz: *mut f64,
-    ax: *mut f64,
-    ay: *mut f64,
-    az: *mut f64,
-    covariance_out: *mut f64,
-) -> i32 {
-    match decode_fixed_from_buf::<TwistWithCovariance>(data, len) {
-        Ok(v) => unsafe {
-            if !lx.is_null() {
-                *lx = v.twist.linear.x;
-            }
-            if !ly.is_null() {
-                *ly = v.twist.linear.y;
-            }
-            if !lz.is_null() {
-                *lz = v.twist.linear.z;
-            }
-            if !ax.is_null() {
-                *ax = v.twist.angular.x;
-            }
-            if !ay.is_null() {
-                *ay = v.twist.angular.y;
-            }
-            if !az.is_null() {
-                *az = v.twist.angular.z;
-            }
-            if !covariance_out.is_null() {
-                ptr::copy_nonoverlapping(v.covariance.as_ptr(), covariance_out, 36);
-            }
-            0
-        },
-        Err(()) => -1,
+    let equal = cdr::CdrCursor::detect(a).and_then(|mut ca| {
+        let mut cb = cdr::CdrCursor::detect(b)?;
+        crate::msg_spec::equal_msg(&spec, &mut ca, &mut cb)
+    });
+    match equal {
+        Ok(equal) => equal as i32,
+        Err(e) => {
+            set_error(EBADMSG, e);
+            -1
+        }
     }
 }
 
-// =============================================================================
-// MagneticField (buffer-backed)
-// =============================================================================
-
-pub struct ros_magnetic_field_t(sensor_msgs::MagneticField<&'static [u8]>);
+/// `ros_<type>_equals(a, b)`: 1 if the two messages hold the same field
+/// values, 0 if not, whatever their byte order or padding. Returns -1 with
+/// `errno = EINVAL` for a NULL or invalid handle.
+macro_rules! impl_equals {
+    ($fn_name:ident, $view_type:ty, $schema:literal, $view:tt) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(a: *const $view_type, b: *const $view_type) -> i32 {
+            ffi_guard(|| {
+                if bad_handle(a) || bad_handle(b) {
+                    return -1;
+                }
+                let (a, b) = unsafe { ((*a).$view.as_cdr(), (*b).$view.as_cdr()) };
+                cdr_equals($schema, a, b)
+            })
+        }
+    };
+}
 
-#[no_mangle]
+impl_equals!(ros_header_equals, ros_header_t, "std_msgs/msg/Header", 0);
+impl_equals!(ros_image_equals, ros_image_t, "sensor_msgs/msg/Image", 0);
+impl_equals!(
+    ros_compressed_image_equals,
+    ros_compressed_image_t,
+    "sensor_msgs/msg/CompressedImage",
+    0
+);
+impl_equals!(
+    ros_compressed_video_equals,
+    ros_compressed_video_t,
+    "foxglove_msgs/msg/CompressedVideo",
+    0
+);
+impl_equals!(
+    ros_dmabuffer_equals,
+    ros_dmabuffer_t,
+    "edgefirst_msgs/msg/DmaBuffer",
+    0
+);
+impl_equals!(ros_imu_equals, ros_imu_t, "sensor_msgs/msg/Imu", 0);
+impl_equals!(
+    ros_nav_sat_fix_equals,
+    ros_nav_sat_fix_t,
+    "sensor_msgs/msg/NavSatFix",
+    0
+);
+impl_equals!(
+    ros_transform_stamped_equals,
+    ros_transform_stamped_t,
+    "geometry_msgs/msg/TransformStamped",
+    0
+);
+impl_equals!(
+    ros_twist_stamped_equals,
+    ros_twist_stamped_t,
+    "geometry_msgs/msg/TwistStamped",
+    0
+);
+impl_equals!(
+    ros_accel_stamped_equals,
+    ros_accel_stamped_t,
+    "geometry_msgs/msg/AccelStamped",
+    0
+);
+impl_equals!(
+    ros_point_stamped_equals,
+    ros_point_stamped_t,
+    "geometry_msgs/msg/PointStamped",
+    0
+);
+impl_equals!(
+    ros_pose_stamped_equals,
+    ros_pose_stamped_t,
+    "geometry_msgs/msg/PoseStamped",
+    0
+);
+impl_equals!(
+    ros_inertia_stamped_equals,
+    ros_inertia_stamped_t,
+    "geometry_msgs/msg/InertiaStamped",
+    0
+);
+impl_equals!(
+    ros_radar_cube_equals,
+    ros_radar_cube_t,
+    "edgefirst_msgs/msg/RadarCube",
+    0
+);
+impl_equals!(
+    ros_radar_info_equals,
+    ros_radar_info_t,
+    "edgefirst_msgs/msg/RadarInfo",
+    0
+);
+impl_equals!(
+    ros_model_info_equals,
+    ros_model_info_t,
+    "edgefirst_msgs/msg/ModelInfo",
+    0
+);
+impl_equals!(
+    ros_point_cloud2_equals,
+    ros_point_cloud2_t,
+    "sensor_msgs/msg/PointCloud2",
+    0
+);
+impl_equals!(
+    ros_camera_info_equals,
+    ros_camera_info_t,
+    "sensor_msgs/msg/CameraInfo",
+    0
+);
+impl_equals!(ros_track_equals, ros_track_t, "edgefirst_msgs/msg/Track", 0);
+impl_equals!(
+    ros_local_time_equals,
+    ros_local_time_t,
+    "edgefirst_msgs/msg/LocalTime",
+    0
+);
+impl_equals!(
+    ros_magnetic_field_equals,
+    ros_magnetic_field_t,
+    "sensor_msgs/msg/MagneticField",
+    0
+);
+impl_equals!(
+    ros_fluid_pressure_equals,
+    ros_fluid_pressure_t,
+    "sensor_msgs/msg/FluidPressure",
+    0
+);
+impl_equals!(
+    ros_temperature_equals,
+    ros_temperature_t,
+    "sensor_msgs/msg/Temperature",
+    0
+);
+impl_equals!(
+    ros_battery_state_equals,
+    ros_battery_state_t,
+    "sensor_msgs/msg/BatteryState",
+    0
+);
+impl_equals!(
+    ros_odometry_equals,
+    ros_odometry_t,
+    "nav_msgs/msg/Odometry",
+    0
+);
+impl_equals!(
+    ros_vibration_equals,
+    ros_vibration_t,
+    "edgefirst_msgs/msg/Vibration",
+    0
+);
+impl_equals!(
+    ros_laser_scan_equals,
+    ros_laser_scan_t,
+    "sensor_msgs/msg/LaserScan",
+    0
+);
+impl_equals!(
+    ros_occupancy_grid_equals,
+    ros_occupancy_grid_t,
+    "nav_msgs/msg/OccupancyGrid",
+    0
+);
+impl_equals!(
+    ros_mavros_altitude_equals,
+    ros_mavros_altitude_t,
+    "mavros_msgs/msg/Altitude",
+    0
+);
+impl_equals!(
+    ros_mavros_vfrhud_equals,
+    ros_mavros_vfrhud_t,
+    "mavros_msgs/msg/VfrHud",
+    0
+);
+impl_equals!(
+    ros_mavros_estimator_status_equals,
+    ros_mavros_estimator_status_t,
+    "mavros_msgs/msg/EstimatorStatus",
+    0
+);
+impl_equals!(
+    ros_mavros_extended_state_equals,
+    ros_mavros_extended_state_t,
+    "mavros_msgs/msg/ExtendedState",
+    0
+);
+impl_equals!(
+    ros_mavros_sys_status_equals,
+    ros_mavros_sys_status_t,
+    "mavros_msgs/msg/SysStatus",
+    0
+);
+impl_equals!(
+    ros_mavros_state_equals,
+    ros_mavros_state_t,
+    "mavros_msgs/msg/State",
+    0
+);
+impl_equals!(
+    ros_mavros_status_text_equals,
+    ros_mavros_status_text_t,
+    "mavros_msgs/msg/StatusText",
+    0
+);
+impl_equals!(
+    ros_mavros_gps_raw_equals,
+    ros_mavros_gps_raw_t,
+    "mavros_msgs/msg/GPSRAW",
+    0
+);
+impl_equals!(
+    ros_mavros_timesync_status_equals,
+    ros_mavros_timesync_status_t,
+    "mavros_msgs/msg/TimesyncStatus",
+    0
+);
+impl_equals!(
+    ros_detect_equals,
+    ros_detect_t,
+    "edgefirst_msgs/msg/Detect",
+    inner
+);
+impl_equals!(
+    ros_model_equals,
+    ros_model_t,
+    "edgefirst_msgs/msg/Model",
+    inner
+);
+impl_equals!(
+    ros_camera_frame_equals,
+    ros_camera_frame_t,
+    "edgefirst_msgs/msg/CameraFrame",
+    inner
+);
+
+// =============================================================================
+// Reference counting
+// =============================================================================
+
+/// `ros_<type>_retain(view)` adds a reference to a handle so another
+/// thread can hold it; every reference is dropped with `ros_<type>_free()`
+/// or its alias `ros_<type>_release()`, and the last one destroys the
+/// handle. The source CDR buffer must outlive every reference. Returns
+/// `view`, or NULL with `errno = EINVAL` for a NULL or invalid handle.
+macro_rules! impl_retain {
+    ($retain:ident, $release:ident, $view_type:ty, $free:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $retain(view: *mut $view_type) -> *mut $view_type {
+            ffi_guard(|| {
+                if bad_handle(view) {
+                    return ptr::null_mut();
+                }
+                retain_handle(view);
+                view
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $release(view: *mut $view_type) {
+            $free(view)
+        }
+    };
+}
+
+impl_retain!(
+    ros_header_retain,
+    ros_header_release,
+    ros_header_t,
+    ros_header_free
+);
+impl_retain!(
+    ros_image_retain,
+    ros_image_release,
+    ros_image_t,
+    ros_image_free
+);
+impl_retain!(
+    ros_compressed_image_retain,
+    ros_compressed_image_release,
+    ros_compressed_image_t,
+    ros_compressed_image_free
+);
+impl_retain!(
+    ros_compressed_video_retain,
+    ros_compressed_video_release,
+    ros_compressed_video_t,
+    ros_compressed_video_free
+);
+impl_retain!(
+    ros_dmabuffer_retain,
+    ros_dmabuffer_release,
+    ros_dmabuffer_t,
+    ros_dmabuffer_free
+);
+impl_retain!(ros_imu_retain, ros_imu_release, ros_imu_t, ros_imu_free);
+impl_retain!(
+    ros_nav_sat_fix_retain,
+    ros_nav_sat_fix_release,
+    ros_nav_sat_fix_t,
+    ros_nav_sat_fix_free
+);
+impl_retain!(
+    ros_transform_stamped_retain,
+    ros_transform_stamped_release,
+    ros_transform_stamped_t,
+    ros_transform_stamped_free
+);
+impl_retain!(
+    ros_twist_stamped_retain,
+    ros_twist_stamped_release,
+    ros_twist_stamped_t,
+    ros_twist_stamped_free
+);
+impl_retain!(
+    ros_accel_stamped_retain,
+    ros_accel_stamped_release,
+    ros_accel_stamped_t,
+    ros_accel_stamped_free
+);
+impl_retain!(
+    ros_point_stamped_retain,
+    ros_point_stamped_release,
+    ros_point_stamped_t,
+    ros_point_stamped_free
+);
+impl_retain!(
+    ros_pose_stamped_retain,
+    ros_pose_stamped_release,
+    ros_pose_stamped_t,
+    ros_pose_stamped_free
+);
+impl_retain!(
+    ros_inertia_stamped_retain,
+    ros_inertia_stamped_release,
+    ros_inertia_stamped_t,
+    ros_inertia_stamped_free
+);
+impl_retain!(
+    ros_radar_cube_retain,
+    ros_radar_cube_release,
+    ros_radar_cube_t,
+    ros_radar_cube_free
+);
+impl_retain!(
+    ros_radar_info_retain,
+    ros_radar_info_release,
+    ros_radar_info_t,
+    ros_radar_info_free
+);
+impl_retain!(
+    ros_model_info_retain,
+    ros_model_info_release,
+    ros_model_info_t,
+    ros_model_info_free
+);
+impl_retain!(
+    ros_point_cloud2_retain,
+    ros_point_cloud2_release,
+    ros_point_cloud2_t,
+    ros_point_cloud2_free
+);
+impl_retain!(
+    ros_camera_info_retain,
+    ros_camera_info_release,
+    ros_camera_info_t,
+    ros_camera_info_free
+);
+impl_retain!(
+    ros_track_retain,
+    ros_track_release,
+    ros_track_t,
+    ros_track_free
+);
+impl_retain!(
+    ros_local_time_retain,
+    ros_local_time_release,
+    ros_local_time_t,
+    ros_local_time_free
+);
+impl_retain!(
+    ros_magnetic_field_retain,
+    ros_magnetic_field_release,
+    ros_magnetic_field_t,
+    ros_magnetic_field_free
+);
+impl_retain!(
+    ros_fluid_pressure_retain,
+    ros_fluid_pressure_release,
+    ros_fluid_pressure_t,
+    ros_fluid_pressure_free
+);
+impl_retain!(
+    ros_temperature_retain,
+    ros_temperature_release,
+    ros_temperature_t,
+    ros_temperature_free
+);
+impl_retain!(
+    ros_battery_state_retain,
+    ros_battery_state_release,
+    ros_battery_state_t,
+    ros_battery_state_free
+);
+impl_retain!(
+    ros_odometry_retain,
+    ros_odometry_release,
+    ros_odometry_t,
+    ros_odometry_free
+);
+impl_retain!(
+    ros_vibration_retain,
+    ros_vibration_release,
+    ros_vibration_t,
+    ros_vibration_free
+);
+impl_retain!(
+    ros_laser_scan_retain,
+    ros_laser_scan_release,
+    ros_laser_scan_t,
+    ros_laser_scan_free
+);
+impl_retain!(
+    ros_occupancy_grid_retain,
+    ros_occupancy_grid_release,
+    ros_occupancy_grid_t,
+    ros_occupancy_grid_free
+);
+impl_retain!(
+    ros_mavros_altitude_retain,
+    ros_mavros_altitude_release,
+    ros_mavros_altitude_t,
+    ros_mavros_altitude_free
+);
+impl_retain!(
+    ros_mavros_vfrhud_retain,
+    ros_mavros_vfrhud_release,
+    ros_mavros_vfrhud_t,
+    ros_mavros_vfrhud_free
+);
+impl_retain!(
+    ros_mavros_estimator_status_retain,
+    ros_mavros_estimator_status_release,
+    ros_mavros_estimator_status_t,
+    ros_mavros_estimator_status_free
+);
+impl_retain!(
+    ros_mavros_extended_state_retain,
+    ros_mavros_extended_state_release,
+    ros_mavros_extended_state_t,
+    ros_mavros_extended_state_free
+);
+impl_retain!(
+    ros_mavros_sys_status_retain,
+    ros_mavros_sys_status_release,
+    ros_mavros_sys_status_t,
+    ros_mavros_sys_status_free
+);
+impl_retain!(
+    ros_mavros_state_retain,
+    ros_mavros_state_release,
+    ros_mavros_state_t,
+    ros_mavros_state_free
+);
+impl_retain!(
+    ros_mavros_status_text_retain,
+    ros_mavros_status_text_release,
+    ros_mavros_status_text_t,
+    ros_mavros_status_text_free
+);
+impl_retain!(
+    ros_mavros_gps_raw_retain,
+    ros_mavros_gps_raw_release,
+    ros_mavros_gps_raw_t,
+    ros_mavros_gps_raw_free
+);
+impl_retain!(
+    ros_mavros_timesync_status_retain,
+    ros_mavros_timesync_status_release,
+    ros_mavros_timesync_status_t,
+    ros_mavros_timesync_status_free
+);
+impl_retain!(
+    ros_detect_retain,
+    ros_detect_release,
+    ros_detect_t,
+    ros_detect_free
+);
+impl_retain!(
+    ros_model_retain,
+    ros_model_release,
+    ros_model_t,
+    ros_model_free
+);
+impl_retain!(
+    ros_camera_frame_retain,
+    ros_camera_frame_release,
+    ros_camera_frame_t,
+    ros_camera_frame_free
+);
+
+// =============================================================================
+// Serialized sizes
+// =============================================================================
+
+/// `ros_<type>_serialized_size(view, &size)`: length of the message's CDR
+/// bytes, header included, for sizing a buffer or ring slot before copying
+/// it out. Returns 0, or -1 with `errno = EINVAL` for a NULL argument.
+macro_rules! impl_serialized_size {
+    ($fn_name:ident, $view_type:ty, $view:tt) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(view: *const $view_type, out: *mut usize) -> i32 {
+            ffi_guard(|| {
+                if bad_handle(view) {
+                    return -1;
+                }
+                if out.is_null() {
+                    set_error(EINVAL, "`out` is NULL");
+                    return -1;
+                }
+                unsafe {
+                    *out = (*view).$view.as_cdr().len();
+                }
+                0
+            })
+        }
+    };
+}
+
+/// `ros_<type>_builder_serialized_size(b, &size)`: the exact size the
+/// builder's next `encode_into` or `build` produces, computed without
+/// encoding. Same as `ros_<type>_builder_encode_into(b, NULL, 0, &size)`.
+macro_rules! impl_builder_serialized_size {
+    ($fn_name:ident, $builder_type:ty, $encode_into:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(b: *const $builder_type, out: *mut usize) -> i32 {
+            $encode_into(b as *mut $builder_type, ptr::null_mut(), 0, out)
+        }
+    };
+}
+
+impl_serialized_size!(ros_header_serialized_size, ros_header_t, 0);
+impl_serialized_size!(ros_image_serialized_size, ros_image_t, 0);
+impl_serialized_size!(
+    ros_compressed_image_serialized_size,
+    ros_compressed_image_t,
+    0
+);
+impl_serialized_size!(
+    ros_compressed_video_serialized_size,
+    ros_compressed_video_t,
+    0
+);
+impl_serialized_size!(ros_dmabuffer_serialized_size, ros_dmabuffer_t, 0);
+impl_serialized_size!(ros_imu_serialized_size, ros_imu_t, 0);
+impl_serialized_size!(ros_nav_sat_fix_serialized_size, ros_nav_sat_fix_t, 0);
+impl_serialized_size!(
+    ros_transform_stamped_serialized_size,
+    ros_transform_stamped_t,
+    0
+);
+impl_serialized_size!(ros_twist_stamped_serialized_size, ros_twist_stamped_t, 0);
+impl_serialized_size!(ros_accel_stamped_serialized_size, ros_accel_stamped_t, 0);
+impl_serialized_size!(ros_point_stamped_serialized_size, ros_point_stamped_t, 0);
+impl_serialized_size!(ros_pose_stamped_serialized_size, ros_pose_stamped_t, 0);
+impl_serialized_size!(
+    ros_inertia_stamped_serialized_size,
+    ros_inertia_stamped_t,
+    0
+);
+impl_serialized_size!(ros_radar_cube_serialized_size, ros_radar_cube_t, 0);
+impl_serialized_size!(ros_radar_info_serialized_size, ros_radar_info_t, 0);
+impl_serialized_size!(ros_model_info_serialized_size, ros_model_info_t, 0);
+impl_serialized_size!(ros_point_cloud2_serialized_size, ros_point_cloud2_t, 0);
+impl_serialized_size!(ros_camera_info_serialized_size, ros_camera_info_t, 0);
+impl_serialized_size!(ros_track_serialized_size, ros_track_t, 0);
+impl_serialized_size!(ros_local_time_serialized_size, ros_local_time_t, 0);
+impl_serialized_size!(ros_magnetic_field_serialized_size, ros_magnetic_field_t, 0);
+impl_serialized_size!(ros_fluid_pressure_serialized_size, ros_fluid_pressure_t, 0);
+impl_serialized_size!(ros_temperature_serialized_size, ros_temperature_t, 0);
+impl_serialized_size!(ros_battery_state_serialized_size, ros_battery_state_t, 0);
+impl_serialized_size!(ros_odometry_serialized_size, ros_odometry_t, 0);
+impl_serialized_size!(ros_vibration_serialized_size, ros_vibration_t, 0);
+impl_serialized_size!(ros_laser_scan_serialized_size, ros_laser_scan_t, 0);
+impl_serialized_size!(ros_occupancy_grid_serialized_size, ros_occupancy_grid_t, 0);
+impl_serialized_size!(
+    ros_mavros_altitude_serialized_size,
+    ros_mavros_altitude_t,
+    0
+);
+impl_serialized_size!(ros_mavros_vfrhud_serialized_size, ros_mavros_vfrhud_t, 0);
+impl_serialized_size!(
+    ros_mavros_estimator_status_serialized_size,
+    ros_mavros_estimator_status_t,
+    0
+);
+impl_serialized_size!(
+    ros_mavros_extended_state_serialized_size,
+    ros_mavros_extended_state_t,
+    0
+);
+impl_serialized_size!(
+    ros_mavros_sys_status_serialized_size,
+    ros_mavros_sys_status_t,
+    0
+);
+impl_serialized_size!(ros_mavros_state_serialized_size, ros_mavros_state_t, 0);
+impl_serialized_size!(
+    ros_mavros_status_text_serialized_size,
+    ros_mavros_status_text_t,
+    0
+);
+impl_serialized_size!(ros_mavros_gps_raw_serialized_size, ros_mavros_gps_raw_t, 0);
+impl_serialized_size!(
+    ros_mavros_timesync_status_serialized_size,
+    ros_mavros_timesync_status_t,
+    0
+);
+impl_serialized_size!(ros_detect_serialized_size, ros_detect_t, inner);
+impl_serialized_size!(ros_model_serialized_size, ros_model_t, inner);
+impl_serialized_size!(ros_camera_frame_serialized_size, ros_camera_frame_t, inner);
+
+impl_builder_serialized_size!(
+    ros_header_builder_serialized_size,
+    ros_header_builder_t,
+    ros_header_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_image_builder_serialized_size,
+    ros_image_builder_t,
+    ros_image_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_fluid_pressure_builder_serialized_size,
+    ros_fluid_pressure_builder_t,
+    ros_fluid_pressure_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_compressed_image_builder_serialized_size,
+    ros_compressed_image_builder_t,
+    ros_compressed_image_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_imu_builder_serialized_size,
+    ros_imu_builder_t,
+    ros_imu_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_nav_sat_fix_builder_serialized_size,
+    ros_nav_sat_fix_builder_t,
+    ros_nav_sat_fix_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_point_field_builder_serialized_size,
+    ros_point_field_builder_t,
+    ros_point_field_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_point_cloud2_builder_serialized_size,
+    ros_point_cloud2_builder_t,
+    ros_point_cloud2_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_camera_info_builder_serialized_size,
+    ros_camera_info_builder_t,
+    ros_camera_info_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_magnetic_field_builder_serialized_size,
+    ros_magnetic_field_builder_t,
+    ros_magnetic_field_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_battery_state_builder_serialized_size,
+    ros_battery_state_builder_t,
+    ros_battery_state_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_temperature_builder_serialized_size,
+    ros_temperature_builder_t,
+    ros_temperature_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_mask_builder_serialized_size,
+    ros_mask_builder_t,
+    ros_mask_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_local_time_builder_serialized_size,
+    ros_local_time_builder_t,
+    ros_local_time_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_radar_cube_builder_serialized_size,
+    ros_radar_cube_builder_t,
+    ros_radar_cube_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_radar_info_builder_serialized_size,
+    ros_radar_info_builder_t,
+    ros_radar_info_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_track_builder_serialized_size,
+    ros_track_builder_t,
+    ros_track_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_detect_box_builder_serialized_size,
+    ros_detect_box_builder_t,
+    ros_detect_box_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_detect_builder_serialized_size,
+    ros_detect_builder_t,
+    ros_detect_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_camera_frame_builder_serialized_size,
+    ros_camera_frame_builder_t,
+    ros_camera_frame_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_model_builder_serialized_size,
+    ros_model_builder_t,
+    ros_model_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_model_info_builder_serialized_size,
+    ros_model_info_builder_t,
+    ros_model_info_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_vibration_builder_serialized_size,
+    ros_vibration_builder_t,
+    ros_vibration_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_foxglove_compressed_video_builder_serialized_size,
+    ros_foxglove_compressed_video_builder_t,
+    ros_foxglove_compressed_video_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_foxglove_text_annotation_builder_serialized_size,
+    ros_foxglove_text_annotation_builder_t,
+    ros_foxglove_text_annotation_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_foxglove_point_annotation_builder_serialized_size,
+    ros_foxglove_point_annotation_builder_t,
+    ros_foxglove_point_annotation_builder_encode_into
+);
+impl_builder_serialized_size!(
+    ros_foxglove_image_annotation_builder_serialized_size,
+    ros_foxglove_image_annotation_builder_t,
+    ros_foxglove_image_annotation_builder_encode_into
+);
+
+// =============================================================================
+// PoseWithCovariance (CdrFixed)
+// =============================================================================
+
+#[no_mangle]
+pub extern "C" fn ros_pose_with_covariance_encode(
+    buf: *mut u8,
+    cap: usize,
+    written: *mut usize,
+    px: f64,
+    py: f64,
+    pz: f64,
+    ox: f64,
+    oy: f64,
+    oz: f64,
+    ow: f64,
+    covariance: *const f64,
+) -> i32 {
+    ffi_guard(|| {
+        if covariance.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let mut cov = [0.0_f64; 36];
+        unsafe {
+            ptr::copy_nonoverlapping(covariance, cov.as_mut_ptr(), 36);
+        }
+        let val = PoseWithCovariance {
+            pose: Pose {
+                position: Point {
+                    x: px,
+                    y: py,
+                    z: pz,
+                },
+                orientation: Quaternion {
+                    x: ox,
+                    y: oy,
+                    z: oz,
+                    w: ow,
+                },
+            },
+            covariance: cov,
+        };
+        encode_fixed_to_buf(&val, buf, cap, written)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ros_pose_with_covariance_decode(
+    data: *const u8,
+    len: usize,
+    px: *mut f64,
+    py: *mut f64,
+    pz: *mut f64,
+    ox: *mut f64,
+    oy: *mut f64,
+    oz: *mut f64,
+    ow: *mut f64,
+    covariance_out: *mut f64,
+) -> i32 {
+    ffi_guard(
+        || match decode_fixed_from_buf::<PoseWithCovariance>(data, len) {
+            Ok(v) => unsafe {
+                if !px.is_null() {
+                    *px = v.pose.position.x;
+                }
+                if !py.is_null() {
+                    *py = v.pose.position.y;
+                }
+                if !pz.is_null() {
+                    *pz = v.pose.position.z;
+                }
+                if !ox.is_null() {
+                    *ox = v.pose.orientation.x;
+                }
+                if !oy.is_null() {
+                    *oy = v.pose.orientation.y;
+                }
+                if !oz.is_null() {
+                    *oz = v.pose.orientation.z;
+                }
+                if !ow.is_null() {
+                    *ow = v.pose.orientation.w;
+                }
+                if !covariance_out.is_null() {
+                    ptr::copy_nonoverlapping(v.covariance.as_ptr(), covariance_out, 36);
+                }
+                0
+            },
+            Err(()) => -1,
+        },
+    )
+}
+
+// =============================================================================
+// TwistWithCovariance (CdrFixed)
+// =============================================================================
+
+#[no_mangle]
+pub extern "C" fn ros_twist_with_covariance_encode(
+    buf: *mut u8,
+    cap: usize,
+    written: *mut usize,
+    lx: f64,
+    ly: f64,
+    lz: f64,
+    ax: f64,
+    ay: f64,
+    az: f64,
+    covariance: *const f64,
+) -> i32 {
+    ffi_guard(|| {
+        if covariance.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let mut cov = [0.0_f64; 36];
+        unsafe {
+            ptr::copy_nonoverlapping(covariance, cov.as_mut_ptr(), 36);
+        }
+        let val = TwistWithCovariance {
+            twist: Twist {
+                linear: Vector3 {
+                    x: lx,
+                    y: ly,
+                    z: lz,
+                },
+                angular: Vector3 {
+                    x: ax,
+                    y: ay,
+                    z: az,
+                },
+            },
+            covariance: cov,
+        };
+        encode_fixed_to_buf(&val, buf, cap, written)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ros_twist_with_covariance_decode(
+    data: *const u8,
+    len: usize,
+    lx: *mut f64,
+    ly: *mut f64,
+    lz: *mut f64,
+    ax: *mut f64,
+    ay: *mut f64,
+    az: *mut f64,
+    covariance_out: *mut f64,
+) -> i32 {
+    ffi_guard(
+        || match decode_fixed_from_buf::<TwistWithCovariance>(data, len) {
+            Ok(v) => unsafe {
+                if !lx.is_null() {
+                    *lx = v.twist.linear.x;
+                }
+                if !ly.is_null() {
+                    *ly = v.twist.linear.y;
+                }
+                if !lz.is_null() {
+                    *lz = v.twist.linear.z;
+                }
+                if !ax.is_null() {
+                    *ax = v.twist.angular.x;
+                }
+                if !ay.is_null() {
+                    *ay = v.twist.angular.y;
+                }
+                if !az.is_null() {
+                    *az = v.twist.angular.z;
+                }
+                if !covariance_out.is_null() {
+                    ptr::copy_nonoverlapping(v.covariance.as_ptr(), covariance_out, 36);
+                }
+                0
+            },
+            Err(()) => -1,
+        },
+    )
+}
+
+// =============================================================================
+// Flat CdrFixed types — one C argument per field
+// =============================================================================
+
+/// `ros_<type>_encode` / `ros_<type>_decode` for a CdrFixed struct whose
+/// fields are all scalars; the C arguments take the field names, in order.
+macro_rules! impl_fixed_codec {
+    ($encode:ident, $decode:ident, $ty:ident, $($field:ident: $fty:ty),+ $(,)?) => {
+        #[no_mangle]
+        pub extern "C" fn $encode(
+            buf: *mut u8,
+            cap: usize,
+            written: *mut usize,
+            $($field: $fty),+
+        ) -> i32 {
+            ffi_guard(|| encode_fixed_to_buf(&$ty { $($field),+ }, buf, cap, written))
+        }
+
+        /// Decode into the out-params; any of them may be NULL.
+        #[no_mangle]
+        pub extern "C" fn $decode(
+            data: *const u8,
+            len: usize,
+            $($field: *mut $fty),+
+        ) -> i32 {
+            ffi_guard(|| match decode_fixed_from_buf::<$ty>(data, len) {
+                Ok(v) => unsafe {
+                    $(
+                        if !$field.is_null() {
+                            *$field = v.$field;
+                        }
+                    )+
+                    0
+                },
+                Err(()) => -1,
+            })
+        }
+    };
+}
+
+impl_fixed_codec!(ros_point32_encode, ros_point32_decode, Point32, x: f32, y: f32, z: f32);
+impl_fixed_codec!(
+    ros_pose2d_encode,
+    ros_pose2d_decode,
+    Pose2D,
+    x: f64,
+    y: f64,
+    theta: f64
+);
+impl_fixed_codec!(
+    ros_color_rgba_encode,
+    ros_color_rgba_decode,
+    ColorRGBA,
+    r: f32,
+    g: f32,
+    b: f32,
+    a: f32
+);
+impl_fixed_codec!(
+    ros_region_of_interest_encode,
+    ros_region_of_interest_decode,
+    RegionOfInterest,
+    x_offset: u32,
+    y_offset: u32,
+    height: u32,
+    width: u32,
+    do_rectify: bool
+);
+
+// =============================================================================
+// Clock, Inertia, MapMetaData (CdrFixed, nested fields)
+// =============================================================================
+
+#[no_mangle]
+pub extern "C" fn ros_clock_encode(
+    buf: *mut u8,
+    cap: usize,
+    written: *mut usize,
+    sec: i32,
+    nanosec: u32,
+) -> i32 {
+    let val = Clock {
+        clock: Time { sec, nanosec },
+    };
+    ffi_guard(|| encode_fixed_to_buf(&val, buf, cap, written))
+}
+
+#[no_mangle]
+pub extern "C" fn ros_clock_decode(
+    data: *const u8,
+    len: usize,
+    sec: *mut i32,
+    nanosec: *mut u32,
+) -> i32 {
+    ffi_guard(|| match decode_fixed_from_buf::<Clock>(data, len) {
+        Ok(v) => unsafe {
+            if !sec.is_null() {
+                *sec = v.clock.sec;
+            }
+            if !nanosec.is_null() {
+                *nanosec = v.clock.nanosec;
+            }
+            0
+        },
+        Err(()) => -1,
+    })
+}
+
+/// `inertia` holds ixx, ixy, ixz, iyy, iyz, izz in that order.
+#[no_mangle]
+pub extern "C" fn ros_inertia_encode(
+    buf: *mut u8,
+    cap: usize,
+    written: *mut usize,
+    m: f64,
+    com_x: f64,
+    com_y: f64,
+    com_z: f64,
+    inertia: *const f64,
+) -> i32 {
+    ffi_guard(|| {
+        if inertia.is_null() {
+            set_error(EINVAL, "`inertia` is NULL");
+            return -1;
+        }
+        let i = unsafe { slice::from_raw_parts(inertia, 6) };
+        let val = Inertia {
+            m,
+            com: Vector3 {
+                x: com_x,
+                y: com_y,
+                z: com_z,
+            },
+            ixx: i[0],
+            ixy: i[1],
+            ixz: i[2],
+            iyy: i[3],
+            iyz: i[4],
+            izz: i[5],
+        };
+        encode_fixed_to_buf(&val, buf, cap, written)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ros_inertia_decode(
+    data: *const u8,
+    len: usize,
+    m: *mut f64,
+    com_x: *mut f64,
+    com_y: *mut f64,
+    com_z: *mut f64,
+    inertia_out: *mut f64,
+) -> i32 {
+    ffi_guard(|| match decode_fixed_from_buf::<Inertia>(data, len) {
+        Ok(v) => unsafe {
+            if !m.is_null() {
+                *m = v.m;
+            }
+            if !com_x.is_null() {
+                *com_x = v.com.x;
+            }
+            if !com_y.is_null() {
+                *com_y = v.com.y;
+            }
+            if !com_z.is_null() {
+                *com_z = v.com.z;
+            }
+            if !inertia_out.is_null() {
+                let i = [v.ixx, v.ixy, v.ixz, v.iyy, v.iyz, v.izz];
+                ptr::copy_nonoverlapping(i.as_ptr(), inertia_out, 6);
+            }
+            0
+        },
+        Err(()) => -1,
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ros_map_meta_data_encode(
+    buf: *mut u8,
+    cap: usize,
+    written: *mut usize,
+    load_sec: i32,
+    load_nanosec: u32,
+    resolution: f32,
+    width: u32,
+    height: u32,
+    px: f64,
+    py: f64,
+    pz: f64,
+    ox: f64,
+    oy: f64,
+    oz: f64,
+    ow: f64,
+) -> i32 {
+    let val = nav_msgs::MapMetaData {
+        map_load_time: Time {
+            sec: load_sec,
+            nanosec: load_nanosec,
+        },
+        resolution,
+        width,
+        height,
+        origin: Pose {
+            position: Point {
+                x: px,
+                y: py,
+                z: pz,
+            },
+            orientation: Quaternion {
+                x: ox,
+                y: oy,
+                z: oz,
+                w: ow,
+            },
+        },
+    };
+    ffi_guard(|| encode_fixed_to_buf(&val, buf, cap, written))
+}
+
+/// Write a MapMetaData's fields to the out-params; any may be NULL.
+unsafe fn write_map_meta_data(
+    v: &nav_msgs::MapMetaData,
+    load_sec: *mut i32,
+    load_nanosec: *mut u32,
+    resolution: *mut f32,
+    width: *mut u32,
+    height: *mut u32,
+    origin_out: *mut f64,
+) {
+    if !load_sec.is_null() {
+        *load_sec = v.map_load_time.sec;
+    }
+    if !load_nanosec.is_null() {
+        *load_nanosec = v.map_load_time.nanosec;
+    }
+    if !resolution.is_null() {
+        *resolution = v.resolution;
+    }
+    if !width.is_null() {
+        *width = v.width;
+    }
+    if !height.is_null() {
+        *height = v.height;
+    }
+    if !origin_out.is_null() {
+        let (p, o) = (v.origin.position, v.origin.orientation);
+        let origin = [p.x, p.y, p.z, o.x, o.y, o.z, o.w];
+        ptr::copy_nonoverlapping(origin.as_ptr(), origin_out, 7);
+    }
+}
+
+/// `origin_out` receives px, py, pz, ox, oy, oz, ow (7 doubles).
+#[no_mangle]
+pub extern "C" fn ros_map_meta_data_decode(
+    data: *const u8,
+    len: usize,
+    load_sec: *mut i32,
+    load_nanosec: *mut u32,
+    resolution: *mut f32,
+    width: *mut u32,
+    height: *mut u32,
+    origin_out: *mut f64,
+) -> i32 {
+    ffi_guard(
+        || match decode_fixed_from_buf::<nav_msgs::MapMetaData>(data, len) {
+            Ok(v) => {
+                unsafe {
+                    write_map_meta_data(
+                        &v,
+                        load_sec,
+                        load_nanosec,
+                        resolution,
+                        width,
+                        height,
+                        origin_out,
+                    );
+                }
+                0
+            }
+            Err(()) => -1,
+        },
+    )
+}
+
+// =============================================================================
+// MagneticField (buffer-backed)
+// =============================================================================
+
+pub struct ros_magnetic_field_t(sensor_msgs::MagneticField<&'static [u8]>);
+
+#[no_mangle]
 pub extern "C" fn ros_magnetic_field_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_magnetic_field_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::MagneticField::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_magnetic_field_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::MagneticField::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_magnetic_field_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_magnetic_field_free(view: *mut ros_magnetic_field_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_magnetic_field_get_stamp_sec(view: *const ros_magnetic_field_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_magnetic_field_get_stamp_nanosec(view: *const ros_magnetic_field_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_magnetic_field_get_frame_id(
     view: *const ros_magnetic_field_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
@@ -5000,21 +8337,23 @@ pub extern "C" fn ros_magnetic_field_get_magnetic_field(
     y: *mut f64,
     z: *mut f64,
 ) {
-    if view.is_null() {
-        return;
-    }
-    let v = unsafe { (*view).0.magnetic_field() };
-    unsafe {
-        if !x.is_null() {
-            *x = v.x;
-        }
-        if !y.is_null() {
-            *y = v.y;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
         }
-        if !z.is_null() {
-            *z = v.z;
+        let v = unsafe { (*view).0.magnetic_field() };
+        unsafe {
+            if !x.is_null() {
+                *x = v.x;
+            }
+            if !y.is_null() {
+                *y = v.y;
+            }
+            if !z.is_null() {
+                *z = v.z;
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
@@ -5022,13 +8361,19 @@ pub extern "C" fn ros_magnetic_field_get_magnetic_field_covariance(
     view: *const ros_magnetic_field_t,
     out: *mut f64,
 ) {
-    if view.is_null() || out.is_null() {
-        return;
-    }
-    let cov = unsafe { (*view).0.magnetic_field_covariance() };
-    unsafe {
-        ptr::copy_nonoverlapping(cov.as_ptr(), out, 9);
-    }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
+        }
+        if out.is_null() {
+            set_errno(EINVAL);
+            return;
+        }
+        let cov = unsafe { (*view).0.magnetic_field_covariance() };
+        unsafe {
+            ptr::copy_nonoverlapping(cov.as_ptr(), out, 9);
+        }
+    })
 }
 
 // =============================================================================
@@ -5042,66 +8387,80 @@ pub extern "C" fn ros_fluid_pressure_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_fluid_pressure_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::FluidPressure::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_fluid_pressure_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::FluidPressure::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_fluid_pressure_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_free(view: *mut ros_fluid_pressure_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_get_stamp_sec(view: *const ros_fluid_pressure_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_get_stamp_nanosec(view: *const ros_fluid_pressure_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_get_frame_id(
     view: *const ros_fluid_pressure_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_get_fluid_pressure(view: *const ros_fluid_pressure_t) -> f64 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.fluid_pressure() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.fluid_pressure() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_get_variance(view: *const ros_fluid_pressure_t) -> f64 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.variance() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.variance() }
+    })
 }
 
 // =============================================================================
@@ -5112,64 +8471,78 @@ pub struct ros_temperature_t(sensor_msgs::Temperature<&'static [u8]>);
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_from_cdr(data: *const u8, len: usize) -> *mut ros_temperature_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::Temperature::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_temperature_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::Temperature::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_temperature_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_free(view: *mut ros_temperature_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_get_stamp_sec(view: *const ros_temperature_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_get_stamp_nanosec(view: *const ros_temperature_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_get_frame_id(view: *const ros_temperature_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_get_temperature(view: *const ros_temperature_t) -> f64 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.temperature() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.temperature() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_get_variance(view: *const ros_temperature_t) -> f64 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.variance() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.variance() }
+    })
 }
 
 // =============================================================================
@@ -5183,152 +8556,186 @@ pub extern "C" fn ros_battery_state_from_cdr(
     data: *const u8,
     len: usize,
 ) -> *mut ros_battery_state_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match sensor_msgs::BatteryState::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_battery_state_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match sensor_msgs::BatteryState::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_battery_state_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_free(view: *mut ros_battery_state_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_stamp_sec(view: *const ros_battery_state_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_stamp_nanosec(view: *const ros_battery_state_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_frame_id(
     view: *const ros_battery_state_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_voltage(view: *const ros_battery_state_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.voltage() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.voltage() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_temperature(view: *const ros_battery_state_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.temperature() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.temperature() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_current(view: *const ros_battery_state_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.current() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.current() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_charge(view: *const ros_battery_state_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.charge() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.charge() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_capacity(view: *const ros_battery_state_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.capacity() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.capacity() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_design_capacity(view: *const ros_battery_state_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.design_capacity() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.design_capacity() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_percentage(view: *const ros_battery_state_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.percentage() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.percentage() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_power_supply_status(
     view: *const ros_battery_state_t,
 ) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.power_supply_status() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.power_supply_status() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_power_supply_health(
     view: *const ros_battery_state_t,
 ) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.power_supply_health() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.power_supply_health() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_power_supply_technology(
     view: *const ros_battery_state_t,
 ) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.power_supply_technology() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.power_supply_technology() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_present(view: *const ros_battery_state_t) -> bool {
-    if view.is_null() {
-        return false;
-    }
-    unsafe { (*view).0.present() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return false;
+        }
+        unsafe { (*view).0.present() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_cell_voltage_len(view: *const ros_battery_state_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.cell_voltage_len() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.cell_voltage_len() }
+    })
 }
 
 /// Copy up to `cap` cell voltages into `out`; returns the total element count.
@@ -5341,21 +8748,25 @@ pub extern "C" fn ros_battery_state_get_cell_voltage(
     out: *mut f32,
     cap: usize,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    let msg = unsafe { &(*view).0 };
-    copy_le_f32_seq(msg.as_cdr(), msg.cell_voltage_seq_offset(), out, cap)
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        let msg = unsafe { &(*view).0 };
+        copy_le_f32_seq(msg.as_cdr(), msg.cell_voltage_seq_offset(), out, cap)
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_cell_temperature_len(
     view: *const ros_battery_state_t,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.cell_temperature_len() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.cell_temperature_len() }
+    })
 }
 
 /// Copy up to `cap` cell temperatures into `out`; returns the total element count.
@@ -5367,32 +8778,38 @@ pub extern "C" fn ros_battery_state_get_cell_temperature(
     view: *const ros_battery_state_t,
     out: *mut f32,
     cap: usize,
-) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    let msg = unsafe { &(*view).0 };
-    copy_le_f32_seq(msg.as_cdr(), msg.cell_temperature_seq_offset(), out, cap)
+) -> u32 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        let msg = unsafe { &(*view).0 };
+        copy_le_f32_seq(msg.as_cdr(), msg.cell_temperature_seq_offset(), out, cap)
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_location(
     view: *const ros_battery_state_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.location() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.location() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_get_serial_number(
     view: *const ros_battery_state_t,
 ) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.serial_number() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.serial_number() })
+    })
 }
 
 // =============================================================================
@@ -5403,56 +8820,68 @@ pub struct ros_odometry_t(nav_msgs::Odometry<&'static [u8]>);
 
 #[no_mangle]
 pub extern "C" fn ros_odometry_from_cdr(data: *const u8, len: usize) -> *mut ros_odometry_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match nav_msgs::Odometry::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_odometry_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match nav_msgs::Odometry::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_odometry_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_odometry_free(view: *mut ros_odometry_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_odometry_get_stamp_sec(view: *const ros_odometry_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_odometry_get_stamp_nanosec(view: *const ros_odometry_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_odometry_get_frame_id(view: *const ros_odometry_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_odometry_get_child_frame_id(view: *const ros_odometry_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.child_frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.child_frame_id() })
+    })
 }
 
 /// Write pose position (x,y,z) + orientation (x,y,z,w) out.
@@ -5467,45 +8896,53 @@ pub extern "C" fn ros_odometry_get_pose(
     oz: *mut f64,
     ow: *mut f64,
 ) {
-    if view.is_null() {
-        return;
-    }
-    let p = unsafe { (*view).0.pose() };
-    unsafe {
-        if !px.is_null() {
-            *px = p.pose.position.x;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
         }
-        if !py.is_null() {
-            *py = p.pose.position.y;
-        }
-        if !pz.is_null() {
-            *pz = p.pose.position.z;
-        }
-        if !ox.is_null() {
-            *ox = p.pose.orientation.x;
-        }
-        if !oy.is_null() {
-            *oy = p.pose.orientation.y;
-        }
-        if !oz.is_null() {
-            *oz = p.pose.orientation.z;
-        }
-        if !ow.is_null() {
-            *ow = p.pose.orientation.w;
+        let p = unsafe { (*view).0.pose() };
+        unsafe {
+            if !px.is_null() {
+                *px = p.pose.position.x;
+            }
+            if !py.is_null() {
+                *py = p.pose.position.y;
+            }
+            if !pz.is_null() {
+                *pz = p.pose.position.z;
+            }
+            if !ox.is_null() {
+                *ox = p.pose.orientation.x;
+            }
+            if !oy.is_null() {
+                *oy = p.pose.orientation.y;
+            }
+            if !oz.is_null() {
+                *oz = p.pose.orientation.z;
+            }
+            if !ow.is_null() {
+                *ow = p.pose.orientation.w;
+            }
         }
-    }
+    })
 }
 
 /// Write 36-element pose covariance.
 #[no_mangle]
 pub extern "C" fn ros_odometry_get_pose_covariance(view: *const ros_odometry_t, out: *mut f64) {
-    if view.is_null() || out.is_null() {
-        return;
-    }
-    let p = unsafe { (*view).0.pose() };
-    unsafe {
-        ptr::copy_nonoverlapping(p.covariance.as_ptr(), out, 36);
-    }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
+        }
+        if out.is_null() {
+            set_errno(EINVAL);
+            return;
+        }
+        let p = unsafe { (*view).0.pose() };
+        unsafe {
+            ptr::copy_nonoverlapping(p.covariance.as_ptr(), out, 36);
+        }
+    })
 }
 
 /// Write twist linear (x,y,z) + angular (x,y,z).
@@ -5519,41 +8956,49 @@ pub extern "C" fn ros_odometry_get_twist(
     ay: *mut f64,
     az: *mut f64,
 ) {
-    if view.is_null() {
-        return;
-    }
-    let t = unsafe { (*view).0.twist() };
-    unsafe {
-        if !lx.is_null() {
-            *lx = t.twist.linear.x;
-        }
-        if !ly.is_null() {
-            *ly = t.twist.linear.y;
-        }
-        if !lz.is_null() {
-            *lz = t.twist.linear.z;
-        }
-        if !ax.is_null() {
-            *ax = t.twist.angular.x;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
         }
-        if !ay.is_null() {
-            *ay = t.twist.angular.y;
-        }
-        if !az.is_null() {
-            *az = t.twist.angular.z;
+        let t = unsafe { (*view).0.twist() };
+        unsafe {
+            if !lx.is_null() {
+                *lx = t.twist.linear.x;
+            }
+            if !ly.is_null() {
+                *ly = t.twist.linear.y;
+            }
+            if !lz.is_null() {
+                *lz = t.twist.linear.z;
+            }
+            if !ax.is_null() {
+                *ax = t.twist.angular.x;
+            }
+            if !ay.is_null() {
+                *ay = t.twist.angular.y;
+            }
+            if !az.is_null() {
+                *az = t.twist.angular.z;
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_odometry_get_twist_covariance(view: *const ros_odometry_t, out: *mut f64) {
-    if view.is_null() || out.is_null() {
-        return;
-    }
-    let t = unsafe { (*view).0.twist() };
-    unsafe {
-        ptr::copy_nonoverlapping(t.covariance.as_ptr(), out, 36);
-    }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
+        }
+        if out.is_null() {
+            set_errno(EINVAL);
+            return;
+        }
+        let t = unsafe { (*view).0.twist() };
+        unsafe {
+            ptr::copy_nonoverlapping(t.covariance.as_ptr(), out, 36);
+        }
+    })
 }
 
 // =============================================================================
@@ -5564,80 +9009,98 @@ pub struct ros_vibration_t(edgefirst_msgs::Vibration<&'static [u8]>);
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_from_cdr(data: *const u8, len: usize) -> *mut ros_vibration_t {
-    check_null_ret_null!(data);
-    let slice = unsafe { slice::from_raw_parts(data, len) };
-    match edgefirst_msgs::Vibration::from_cdr(unsafe { erase_lifetime(slice) }) {
-        Ok(v) => Box::into_raw(Box::new(ros_vibration_t(v))),
-        Err(_) => {
-            set_errno(EBADMSG);
-            ptr::null_mut()
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        match edgefirst_msgs::Vibration::from_cdr(unsafe { erase_lifetime(slice) }) {
+            Ok(v) => new_handle(ros_vibration_t(v)),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_free(view: *mut ros_vibration_t) {
-    if !view.is_null() {
-        unsafe {
-            drop(Box::from_raw(view));
+    ffi_guard(|| {
+        if !view.is_null() {
+            unsafe {
+                free_handle(view);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_get_stamp_sec(view: *const ros_vibration_t) -> i32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().sec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().sec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_get_stamp_nanosec(view: *const ros_vibration_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.stamp().nanosec }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.stamp().nanosec }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_get_frame_id(view: *const ros_vibration_t) -> *const c_char {
-    if view.is_null() {
-        return ptr::null();
-    }
-    str_as_c(unsafe { (*view).0.frame_id() })
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return ptr::null();
+        }
+        str_as_c(unsafe { (*view).0.frame_id() })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_get_measurement_type(view: *const ros_vibration_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.measurement_type() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.measurement_type() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_get_unit(view: *const ros_vibration_t) -> u8 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.unit() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.unit() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_get_band_lower_hz(view: *const ros_vibration_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.band_lower_hz() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.band_lower_hz() }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_get_band_upper_hz(view: *const ros_vibration_t) -> f32 {
-    if view.is_null() {
-        return 0.0;
-    }
-    unsafe { (*view).0.band_upper_hz() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0.0;
+        }
+        unsafe { (*view).0.band_upper_hz() }
+    })
 }
 
 #[no_mangle]
@@ -5647,29 +9110,33 @@ pub extern "C" fn ros_vibration_get_vibration(
     y: *mut f64,
     z: *mut f64,
 ) {
-    if view.is_null() {
-        return;
-    }
-    let v = unsafe { (*view).0.vibration() };
-    unsafe {
-        if !x.is_null() {
-            *x = v.x;
-        }
-        if !y.is_null() {
-            *y = v.y;
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return;
         }
-        if !z.is_null() {
-            *z = v.z;
+        let v = unsafe { (*view).0.vibration() };
+        unsafe {
+            if !x.is_null() {
+                *x = v.x;
+            }
+            if !y.is_null() {
+                *y = v.y;
+            }
+            if !z.is_null() {
+                *z = v.z;
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_get_clipping_len(view: *const ros_vibration_t) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    unsafe { (*view).0.clipping_len() }
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        unsafe { (*view).0.clipping_len() }
+    })
 }
 
 /// Copy up to `cap` clipping counters into `out`; returns the total element count.
@@ -5682,11 +9149,206 @@ pub extern "C" fn ros_vibration_get_clipping(
     out: *mut u32,
     cap: usize,
 ) -> u32 {
-    if view.is_null() {
-        return 0;
-    }
-    let msg = unsafe { &(*view).0 };
-    copy_le_u32_seq(msg.as_cdr(), msg.clipping_seq_offset(), out, cap)
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        let msg = unsafe { &(*view).0 };
+        copy_le_u32_seq(msg.as_cdr(), msg.clipping_seq_offset(), out, cap)
+    })
+}
+
+// =============================================================================
+// LaserScan, OccupancyGrid (buffer-backed)
+// =============================================================================
+
+/// `ros_<type>_get_<field>` for a scalar field of a view handle; a bad
+/// handle reads as zero.
+macro_rules! impl_getter {
+    ($fn_name:ident, $c_name:ident, $ret:ty, $method:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $fn_name(view: *const $c_name) -> $ret {
+            ffi_guard(|| {
+                if bad_handle(view) {
+                    return <$ret>::default();
+                }
+                unsafe { (*view).0.$method() }
+            })
+        }
+    };
+}
+
+impl_simple_stamped!(
+    ros_laser_scan_t,
+    sensor_msgs::LaserScan<&'static [u8]>,
+    ros_laser_scan_from_cdr,
+    ros_laser_scan_free,
+    ros_laser_scan_get_stamp_sec,
+    ros_laser_scan_get_stamp_nanosec,
+    ros_laser_scan_get_frame_id
+);
+
+impl_getter!(
+    ros_laser_scan_get_angle_min,
+    ros_laser_scan_t,
+    f32,
+    angle_min
+);
+impl_getter!(
+    ros_laser_scan_get_angle_max,
+    ros_laser_scan_t,
+    f32,
+    angle_max
+);
+impl_getter!(
+    ros_laser_scan_get_angle_increment,
+    ros_laser_scan_t,
+    f32,
+    angle_increment
+);
+impl_getter!(
+    ros_laser_scan_get_time_increment,
+    ros_laser_scan_t,
+    f32,
+    time_increment
+);
+impl_getter!(
+    ros_laser_scan_get_scan_time,
+    ros_laser_scan_t,
+    f32,
+    scan_time
+);
+impl_getter!(
+    ros_laser_scan_get_range_min,
+    ros_laser_scan_t,
+    f32,
+    range_min
+);
+impl_getter!(
+    ros_laser_scan_get_range_max,
+    ros_laser_scan_t,
+    f32,
+    range_max
+);
+impl_getter!(
+    ros_laser_scan_get_ranges_len,
+    ros_laser_scan_t,
+    u32,
+    ranges_len
+);
+impl_getter!(
+    ros_laser_scan_get_intensities_len,
+    ros_laser_scan_t,
+    u32,
+    intensities_len
+);
+
+/// Copy up to `cap` ranges into `out`; returns the total element count.
+#[no_mangle]
+pub extern "C" fn ros_laser_scan_get_ranges(
+    view: *const ros_laser_scan_t,
+    out: *mut f32,
+    cap: usize,
+) -> u32 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        let msg = unsafe { &(*view).0 };
+        copy_le_f32_seq(msg.as_cdr(), msg.ranges_seq_offset(), out, cap)
+    })
+}
+
+/// Copy up to `cap` intensities into `out`; returns the total element count.
+#[no_mangle]
+pub extern "C" fn ros_laser_scan_get_intensities(
+    view: *const ros_laser_scan_t,
+    out: *mut f32,
+    cap: usize,
+) -> u32 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return 0;
+        }
+        let msg = unsafe { &(*view).0 };
+        copy_le_f32_seq(msg.as_cdr(), msg.intensities_seq_offset(), out, cap)
+    })
+}
+
+impl_simple_stamped!(
+    ros_occupancy_grid_t,
+    nav_msgs::OccupancyGrid<&'static [u8]>,
+    ros_occupancy_grid_from_cdr,
+    ros_occupancy_grid_free,
+    ros_occupancy_grid_get_stamp_sec,
+    ros_occupancy_grid_get_stamp_nanosec,
+    ros_occupancy_grid_get_frame_id
+);
+
+impl_getter!(
+    ros_occupancy_grid_get_data_len,
+    ros_occupancy_grid_t,
+    u32,
+    data_len
+);
+
+/// Read the grid's MapMetaData; the out-params match
+/// `ros_map_meta_data_decode` and any may be NULL. Returns 0, or -1 with
+/// errno `EINVAL` for a bad handle.
+#[no_mangle]
+pub extern "C" fn ros_occupancy_grid_get_info(
+    view: *const ros_occupancy_grid_t,
+    load_sec: *mut i32,
+    load_nanosec: *mut u32,
+    resolution: *mut f32,
+    width: *mut u32,
+    height: *mut u32,
+    origin_out: *mut f64,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            return -1;
+        }
+        let info = unsafe { (*view).0.info() };
+        unsafe {
+            write_map_meta_data(
+                &info,
+                load_sec,
+                load_nanosec,
+                resolution,
+                width,
+                height,
+                origin_out,
+            );
+        }
+        0
+    })
+}
+
+/// Borrow the occupancy values (-1 unknown, 0..=100 probability); valid
+/// while the handle lives.
+#[no_mangle]
+pub extern "C" fn ros_occupancy_grid_get_data(
+    view: *const ros_occupancy_grid_t,
+    out_len: *mut usize,
+) -> *const i8 {
+    ffi_guard(|| {
+        if bad_handle(view) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let data = unsafe { (*view).0.data() };
+        unsafe {
+            if !out_len.is_null() {
+                *out_len = data.len();
+            }
+        }
+        data.as_ptr()
+    })
 }
 
 // =============================================================================
@@ -5722,21 +9384,25 @@ pub struct ros_header_builder_t(HeaderBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_header_builder_new() -> *mut ros_header_builder_t {
-    Box::into_raw(Box::new(ros_header_builder_t(HeaderBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-    })))
+    ffi_guard(|| {
+        new_handle(ros_header_builder_t(HeaderBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_header_builder_free(b: *mut ros_header_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -5745,12 +9411,14 @@ pub extern "C" fn ros_header_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -5758,18 +9426,19 @@ pub extern "C" fn ros_header_builder_set_frame_id(
     b: *mut ros_header_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -5778,22 +9447,23 @@ pub extern "C" fn ros_header_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = std_msgs::Header::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = std_msgs::Header::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -5803,32 +9473,18 @@ pub extern "C" fn ros_header_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = std_msgs::Header::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            std_msgs::Header::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── sensor_msgs::Image ──────────────────────────────────────────────
@@ -5844,44 +9500,52 @@ struct ImageBuilderOwned {
     step: u32,
     data: *const u8,
     data_len: usize,
+    data_owner: Option<AdoptedData>,
 }
 
 pub struct ros_image_builder_t(ImageBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_image_builder_new() -> *mut ros_image_builder_t {
-    Box::into_raw(Box::new(ros_image_builder_t(ImageBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        height: 0,
-        width: 0,
-        encoding: String::new(),
-        is_bigendian: 0,
-        step: 0,
-        data: ptr::null(),
-        data_len: 0,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_image_builder_t(ImageBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            height: 0,
+            width: 0,
+            encoding: String::new(),
+            is_bigendian: 0,
+            step: 0,
+            data: ptr::null(),
+            data_len: 0,
+            data_owner: None,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_builder_free(b: *mut ros_image_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_builder_set_stamp(b: *mut ros_image_builder_t, sec: i32, nanosec: u32) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -5889,38 +9553,43 @@ pub extern "C" fn ros_image_builder_set_frame_id(
     b: *mut ros_image_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_builder_set_height(b: *mut ros_image_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.height = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.height = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_builder_set_width(b: *mut ros_image_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.width = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.width = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -5928,38 +9597,43 @@ pub extern "C" fn ros_image_builder_set_encoding(
     b: *mut ros_image_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.encoding = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.encoding = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_builder_set_is_bigendian(b: *mut ros_image_builder_t, v: u8) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.is_bigendian = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.is_bigendian = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_image_builder_set_step(b: *mut ros_image_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.step = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.step = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -5968,19 +9642,54 @@ pub extern "C" fn ros_image_builder_set_data(
     data: *const u8,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.data = data;
-        (*b).0.data_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.data = data;
+            (*b).0.data_len = len;
+            (*b).0.data_owner = None;
+        }
+        0
+    })
+}
+
+/// Like `ros_image_builder_set_data`, but the builder takes ownership of `data`
+/// and calls `free_fn(ctx)` once it no longer needs it: on the next data setter
+/// or on `ros_image_builder_free`. `build` and `encode_into` copy the bytes
+/// into the CDR message; `encode_parts` leaves them in place. On error
+/// ownership stays with the caller.
+#[no_mangle]
+pub extern "C" fn ros_image_builder_set_data_owned(
+    b: *mut ros_image_builder_t,
+    data: *const u8,
+    len: usize,
+    free_fn: Option<unsafe extern "C" fn(*mut std::ffi::c_void)>,
+    ctx: *mut std::ffi::c_void,
+) -> i32 {
+    ffi_guard(|| {
+        let Some(release) = free_fn else {
+            set_errno(EINVAL);
+            return -1;
+        };
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.data = data;
+        inner.data_len = len;
+        inner.data_owner = Some(AdoptedData { release, ctx });
+        0
+    })
 }
 
 fn ros_image_builder_data_slice(inner: &ImageBuilderOwned) -> &[u8] {
@@ -5997,29 +9706,30 @@ pub extern "C" fn ros_image_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let data_slice = ros_image_builder_data_slice(inner);
-    let r = sensor_msgs::Image::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .height(inner.height)
-        .width(inner.width)
-        .encoding(inner.encoding.as_str())
-        .is_bigendian(inner.is_bigendian)
-        .step(inner.step)
-        .data(data_slice)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let data_slice = ros_image_builder_data_slice(inner);
+        let r = sensor_msgs::Image::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .height(inner.height)
+            .width(inner.width)
+            .encoding(inner.encoding.as_str())
+            .is_bigendian(inner.is_bigendian)
+            .step(inner.step)
+            .data(data_slice)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -6029,39 +9739,70 @@ pub extern "C" fn ros_image_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let data_slice = ros_image_builder_data_slice(inner);
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::Image::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .height(inner.height)
-        .width(inner.width)
-        .encoding(inner.encoding.as_str())
-        .is_bigendian(inner.is_bigendian)
-        .step(inner.step)
-        .data(data_slice)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let inner = unsafe { &(*b).0 };
+        let data_slice = ros_image_builder_data_slice(inner);
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::Image::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .height(inner.height)
+                .width(inner.width)
+                .encoding(inner.encoding.as_str())
+                .is_bigendian(inner.is_bigendian)
+                .step(inner.step)
+                .data(data_slice)
+                .encode_into_slice(dst)
+        })
+    })
+}
+
+/// Encode everything but the data bytes: the message is `head ‖ data ‖
+/// tail`, with `data` the buffer given to `set_data` / `set_data_owned`.
+#[no_mangle]
+pub extern "C" fn ros_image_builder_encode_parts(
+    b: *mut ros_image_builder_t,
+    head: *mut u8,
+    head_cap: usize,
+    head_len: *mut usize,
+    tail: *mut u8,
+    tail_cap: usize,
+    tail_len: *mut usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let len = ros_image_builder_data_slice(inner).len();
+        encode_parts_into_caller_bufs(
+            len,
+            head,
+            head_cap,
+            head_len,
+            tail,
+            tail_cap,
+            tail_len,
+            |pad| {
+                let mut cdr = Vec::new();
+                sensor_msgs::Image::builder()
+                    .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                    .frame_id(inner.frame_id.as_str())
+                    .height(inner.height)
+                    .width(inner.width)
+                    .encoding(inner.encoding.as_str())
+                    .is_bigendian(inner.is_bigendian)
+                    .step(inner.step)
+                    .data(pad)
+                    .encode_into_vec(&mut cdr)?;
+                let at = offset_in(&cdr, sensor_msgs::Image::from_cdr(&cdr[..])?.data());
+                Ok((cdr, at))
+            },
+        )
+    })
 }
 
 // ── sensor_msgs::FluidPressure ──────────────────────────────────────
@@ -6078,25 +9819,27 @@ pub struct ros_fluid_pressure_builder_t(FluidPressureBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_builder_new() -> *mut ros_fluid_pressure_builder_t {
-    Box::into_raw(Box::new(ros_fluid_pressure_builder_t(
-        FluidPressureBuilderOwned {
+    ffi_guard(|| {
+        new_handle(ros_fluid_pressure_builder_t(FluidPressureBuilderOwned {
             stamp_sec: 0,
             stamp_nanosec: 0,
             frame_id: String::new(),
             fluid_pressure: 0.0,
             variance: 0.0,
-        },
-    )))
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_builder_free(b: *mut ros_fluid_pressure_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -6105,12 +9848,14 @@ pub extern "C" fn ros_fluid_pressure_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -6118,18 +9863,19 @@ pub extern "C" fn ros_fluid_pressure_builder_set_frame_id(
     b: *mut ros_fluid_pressure_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -6137,12 +9883,14 @@ pub extern "C" fn ros_fluid_pressure_builder_set_fluid_pressure(
     b: *mut ros_fluid_pressure_builder_t,
     v: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.fluid_pressure = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.fluid_pressure = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -6150,12 +9898,14 @@ pub extern "C" fn ros_fluid_pressure_builder_set_variance(
     b: *mut ros_fluid_pressure_builder_t,
     v: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.variance = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.variance = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -6164,24 +9914,25 @@ pub extern "C" fn ros_fluid_pressure_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = sensor_msgs::FluidPressure::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .fluid_pressure(inner.fluid_pressure)
-        .variance(inner.variance)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = sensor_msgs::FluidPressure::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .fluid_pressure(inner.fluid_pressure)
+            .variance(inner.variance)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -6191,34 +9942,20 @@ pub extern "C" fn ros_fluid_pressure_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::FluidPressure::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .fluid_pressure(inner.fluid_pressure)
-        .variance(inner.variance)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::FluidPressure::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .fluid_pressure(inner.fluid_pressure)
+                .variance(inner.variance)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── sensor_msgs::CompressedImage ────────────────────────────────────
@@ -6230,32 +9967,38 @@ struct CompressedImageBuilderOwned {
     format: String,
     data: *const u8,
     data_len: usize,
+    data_owner: Option<AdoptedData>,
 }
 
 pub struct ros_compressed_image_builder_t(CompressedImageBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_image_builder_new() -> *mut ros_compressed_image_builder_t {
-    Box::into_raw(Box::new(ros_compressed_image_builder_t(
-        CompressedImageBuilderOwned {
-            stamp_sec: 0,
-            stamp_nanosec: 0,
-            frame_id: String::new(),
-            format: String::new(),
-            data: ptr::null(),
-            data_len: 0,
-        },
-    )))
+    ffi_guard(|| {
+        new_handle(ros_compressed_image_builder_t(
+            CompressedImageBuilderOwned {
+                stamp_sec: 0,
+                stamp_nanosec: 0,
+                frame_id: String::new(),
+                format: String::new(),
+                data: ptr::null(),
+                data_len: 0,
+                data_owner: None,
+            },
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_compressed_image_builder_free(b: *mut ros_compressed_image_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -6264,12 +10007,14 @@ pub extern "C" fn ros_compressed_image_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -6277,18 +10022,19 @@ pub extern "C" fn ros_compressed_image_builder_set_frame_id(
     b: *mut ros_compressed_image_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -6296,39 +10042,75 @@ pub extern "C" fn ros_compressed_image_builder_set_format(
     b: *mut ros_compressed_image_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.format = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.format = s_str.to_string();
+        }
+        0
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ros_compressed_image_builder_set_data(
+    b: *mut ros_compressed_image_builder_t,
+    data: *const u8,
+    len: usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.data = data;
+            (*b).0.data_len = len;
+            (*b).0.data_owner = None;
+        }
+        0
+    })
 }
 
+/// Like `ros_compressed_image_builder_set_data`, but the builder takes
+/// ownership of `data` and calls `free_fn(ctx)` once it no longer needs it: on
+/// the next data setter or on `ros_compressed_image_builder_free`. Only
+/// `encode_parts` leaves the bytes in place. On error ownership stays with
+/// the caller.
 #[no_mangle]
-pub extern "C" fn ros_compressed_image_builder_set_data(
+pub extern "C" fn ros_compressed_image_builder_set_data_owned(
     b: *mut ros_compressed_image_builder_t,
     data: *const u8,
     len: usize,
+    free_fn: Option<unsafe extern "C" fn(*mut std::ffi::c_void)>,
+    ctx: *mut std::ffi::c_void,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.data = data;
-        (*b).0.data_len = len;
-    }
-    0
+    ffi_guard(|| {
+        let Some(release) = free_fn else {
+            set_errno(EINVAL);
+            return -1;
+        };
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.data = data;
+        inner.data_len = len;
+        inner.data_owner = Some(AdoptedData { release, ctx });
+        0
+    })
 }
 
 fn ros_compressed_image_builder_data_slice(inner: &CompressedImageBuilderOwned) -> &[u8] {
@@ -6345,25 +10127,26 @@ pub extern "C" fn ros_compressed_image_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let data_slice = ros_compressed_image_builder_data_slice(inner);
-    let r = sensor_msgs::CompressedImage::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .format(inner.format.as_str())
-        .data(data_slice)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let data_slice = ros_compressed_image_builder_data_slice(inner);
+        let r = sensor_msgs::CompressedImage::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .format(inner.format.as_str())
+            .data(data_slice)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -6373,35 +10156,64 @@ pub extern "C" fn ros_compressed_image_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let data_slice = ros_compressed_image_builder_data_slice(inner);
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::CompressedImage::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .format(inner.format.as_str())
-        .data(data_slice)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let inner = unsafe { &(*b).0 };
+        let data_slice = ros_compressed_image_builder_data_slice(inner);
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::CompressedImage::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .format(inner.format.as_str())
+                .data(data_slice)
+                .encode_into_slice(dst)
+        })
+    })
+}
+
+/// See `ros_image_builder_encode_parts`.
+#[no_mangle]
+pub extern "C" fn ros_compressed_image_builder_encode_parts(
+    b: *mut ros_compressed_image_builder_t,
+    head: *mut u8,
+    head_cap: usize,
+    head_len: *mut usize,
+    tail: *mut u8,
+    tail_cap: usize,
+    tail_len: *mut usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let len = ros_compressed_image_builder_data_slice(inner).len();
+        encode_parts_into_caller_bufs(
+            len,
+            head,
+            head_cap,
+            head_len,
+            tail,
+            tail_cap,
+            tail_len,
+            |pad| {
+                let mut cdr = Vec::new();
+                sensor_msgs::CompressedImage::builder()
+                    .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                    .frame_id(inner.frame_id.as_str())
+                    .format(inner.format.as_str())
+                    .data(pad)
+                    .encode_into_vec(&mut cdr)?;
+                let at = offset_in(
+                    &cdr,
+                    sensor_msgs::CompressedImage::from_cdr(&cdr[..])?.data(),
+                );
+                Ok((cdr, at))
+            },
+        )
+    })
 }
 
 // ── sensor_msgs::Imu ────────────────────────────────────────────────
@@ -6422,66 +10234,73 @@ pub struct ros_imu_builder_t(ImuBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_imu_builder_new() -> *mut ros_imu_builder_t {
-    Box::into_raw(Box::new(ros_imu_builder_t(ImuBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        orientation: Quaternion {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-            w: 0.0,
-        },
-        orientation_covariance: [0.0; 9],
-        angular_velocity: Vector3 {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-        },
-        angular_velocity_covariance: [0.0; 9],
-        linear_acceleration: Vector3 {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-        },
-        linear_acceleration_covariance: [0.0; 9],
-    })))
+    ffi_guard(|| {
+        new_handle(ros_imu_builder_t(ImuBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            orientation: Quaternion {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 0.0,
+            },
+            orientation_covariance: [0.0; 9],
+            angular_velocity: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            angular_velocity_covariance: [0.0; 9],
+            linear_acceleration: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            linear_acceleration_covariance: [0.0; 9],
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_imu_builder_free(b: *mut ros_imu_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_imu_builder_set_stamp(b: *mut ros_imu_builder_t, sec: i32, nanosec: u32) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_imu_builder_set_frame_id(b: *mut ros_imu_builder_t, s: *const c_char) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -6492,12 +10311,14 @@ pub extern "C" fn ros_imu_builder_set_orientation(
     z: f64,
     w: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.orientation = Quaternion { x, y, z, w };
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.orientation = Quaternion { x, y, z, w };
+        }
+    })
 }
 
 /// Copies 9 f64 elements from `cov` into the builder's orientation_covariance.
@@ -6507,15 +10328,20 @@ pub extern "C" fn ros_imu_builder_set_orientation_covariance(
     b: *mut ros_imu_builder_t,
     cov: *const f64,
 ) -> i32 {
-    if b.is_null() || cov.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        let src = slice::from_raw_parts(cov, 9);
-        (*b).0.orientation_covariance.copy_from_slice(src);
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if cov.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            let src = slice::from_raw_parts(cov, 9);
+            (*b).0.orientation_covariance.copy_from_slice(src);
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -6525,12 +10351,14 @@ pub extern "C" fn ros_imu_builder_set_angular_velocity(
     y: f64,
     z: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.angular_velocity = Vector3 { x, y, z };
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.angular_velocity = Vector3 { x, y, z };
+        }
+    })
 }
 
 #[no_mangle]
@@ -6538,15 +10366,20 @@ pub extern "C" fn ros_imu_builder_set_angular_velocity_covariance(
     b: *mut ros_imu_builder_t,
     cov: *const f64,
 ) -> i32 {
-    if b.is_null() || cov.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        let src = slice::from_raw_parts(cov, 9);
-        (*b).0.angular_velocity_covariance.copy_from_slice(src);
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if cov.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            let src = slice::from_raw_parts(cov, 9);
+            (*b).0.angular_velocity_covariance.copy_from_slice(src);
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -6556,12 +10389,14 @@ pub extern "C" fn ros_imu_builder_set_linear_acceleration(
     y: f64,
     z: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.linear_acceleration = Vector3 { x, y, z };
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.linear_acceleration = Vector3 { x, y, z };
+        }
+    })
 }
 
 #[no_mangle]
@@ -6569,15 +10404,20 @@ pub extern "C" fn ros_imu_builder_set_linear_acceleration_covariance(
     b: *mut ros_imu_builder_t,
     cov: *const f64,
 ) -> i32 {
-    if b.is_null() || cov.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        let src = slice::from_raw_parts(cov, 9);
-        (*b).0.linear_acceleration_covariance.copy_from_slice(src);
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if cov.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            let src = slice::from_raw_parts(cov, 9);
+            (*b).0.linear_acceleration_covariance.copy_from_slice(src);
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -6586,28 +10426,29 @@ pub extern "C" fn ros_imu_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = sensor_msgs::Imu::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .orientation(inner.orientation)
-        .orientation_covariance(inner.orientation_covariance)
-        .angular_velocity(inner.angular_velocity)
-        .angular_velocity_covariance(inner.angular_velocity_covariance)
-        .linear_acceleration(inner.linear_acceleration)
-        .linear_acceleration_covariance(inner.linear_acceleration_covariance)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = sensor_msgs::Imu::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .orientation(inner.orientation)
+            .orientation_covariance(inner.orientation_covariance)
+            .angular_velocity(inner.angular_velocity)
+            .angular_velocity_covariance(inner.angular_velocity_covariance)
+            .linear_acceleration(inner.linear_acceleration)
+            .linear_acceleration_covariance(inner.linear_acceleration_covariance)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -6617,38 +10458,24 @@ pub extern "C" fn ros_imu_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::Imu::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .orientation(inner.orientation)
-        .orientation_covariance(inner.orientation_covariance)
-        .angular_velocity(inner.angular_velocity)
-        .angular_velocity_covariance(inner.angular_velocity_covariance)
-        .linear_acceleration(inner.linear_acceleration)
-        .linear_acceleration_covariance(inner.linear_acceleration_covariance)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::Imu::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .orientation(inner.orientation)
+                .orientation_covariance(inner.orientation_covariance)
+                .angular_velocity(inner.angular_velocity)
+                .angular_velocity_covariance(inner.angular_velocity_covariance)
+                .linear_acceleration(inner.linear_acceleration)
+                .linear_acceleration_covariance(inner.linear_acceleration_covariance)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── sensor_msgs::NavSatFix ──────────────────────────────────────────
@@ -6669,30 +10496,34 @@ pub struct ros_nav_sat_fix_builder_t(NavSatFixBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_builder_new() -> *mut ros_nav_sat_fix_builder_t {
-    Box::into_raw(Box::new(ros_nav_sat_fix_builder_t(NavSatFixBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        status: NavSatStatus {
-            status: 0,
-            service: 0,
-        },
-        latitude: 0.0,
-        longitude: 0.0,
-        altitude: 0.0,
-        position_covariance: [0.0; 9],
-        position_covariance_type: 0,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_nav_sat_fix_builder_t(NavSatFixBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            status: NavSatStatus {
+                status: 0,
+                service: 0,
+            },
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: 0.0,
+            position_covariance: [0.0; 9],
+            position_covariance_type: 0,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_builder_free(b: *mut ros_nav_sat_fix_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -6701,12 +10532,14 @@ pub extern "C" fn ros_nav_sat_fix_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -6714,18 +10547,19 @@ pub extern "C" fn ros_nav_sat_fix_builder_set_frame_id(
     b: *mut ros_nav_sat_fix_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -6734,42 +10568,50 @@ pub extern "C" fn ros_nav_sat_fix_builder_set_status(
     status: i8,
     service: u16,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.status = NavSatStatus { status, service };
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.status = NavSatStatus { status, service };
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_builder_set_latitude(b: *mut ros_nav_sat_fix_builder_t, v: f64) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.latitude = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.latitude = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_builder_set_longitude(b: *mut ros_nav_sat_fix_builder_t, v: f64) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.longitude = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.longitude = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_builder_set_altitude(b: *mut ros_nav_sat_fix_builder_t, v: f64) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.altitude = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.altitude = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -6777,15 +10619,20 @@ pub extern "C" fn ros_nav_sat_fix_builder_set_position_covariance(
     b: *mut ros_nav_sat_fix_builder_t,
     cov: *const f64,
 ) -> i32 {
-    if b.is_null() || cov.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        let src = slice::from_raw_parts(cov, 9);
-        (*b).0.position_covariance.copy_from_slice(src);
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if cov.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            let src = slice::from_raw_parts(cov, 9);
+            (*b).0.position_covariance.copy_from_slice(src);
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -6793,12 +10640,14 @@ pub extern "C" fn ros_nav_sat_fix_builder_set_position_covariance_type(
     b: *mut ros_nav_sat_fix_builder_t,
     v: u8,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.position_covariance_type = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.position_covariance_type = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -6807,28 +10656,29 @@ pub extern "C" fn ros_nav_sat_fix_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = sensor_msgs::NavSatFix::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .status(inner.status)
-        .latitude(inner.latitude)
-        .longitude(inner.longitude)
-        .altitude(inner.altitude)
-        .position_covariance(inner.position_covariance)
-        .position_covariance_type(inner.position_covariance_type)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = sensor_msgs::NavSatFix::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .status(inner.status)
+            .latitude(inner.latitude)
+            .longitude(inner.longitude)
+            .altitude(inner.altitude)
+            .position_covariance(inner.position_covariance)
+            .position_covariance_type(inner.position_covariance_type)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -6838,38 +10688,24 @@ pub extern "C" fn ros_nav_sat_fix_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::NavSatFix::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .status(inner.status)
-        .latitude(inner.latitude)
-        .longitude(inner.longitude)
-        .altitude(inner.altitude)
-        .position_covariance(inner.position_covariance)
-        .position_covariance_type(inner.position_covariance_type)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::NavSatFix::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .status(inner.status)
+                .latitude(inner.latitude)
+                .longitude(inner.longitude)
+                .altitude(inner.altitude)
+                .position_covariance(inner.position_covariance)
+                .position_covariance_type(inner.position_covariance_type)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── sensor_msgs::PointField ─────────────────────────────────────────
@@ -6885,24 +10721,26 @@ pub struct ros_point_field_builder_t(PointFieldBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_point_field_builder_new() -> *mut ros_point_field_builder_t {
-    Box::into_raw(Box::new(ros_point_field_builder_t(
-        PointFieldBuilderOwned {
+    ffi_guard(|| {
+        new_handle(ros_point_field_builder_t(PointFieldBuilderOwned {
             name: String::new(),
             offset: 0,
             datatype: 0,
             count: 0,
-        },
-    )))
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_field_builder_free(b: *mut ros_point_field_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -6910,48 +10748,55 @@ pub extern "C" fn ros_point_field_builder_set_name(
     b: *mut ros_point_field_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.name = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.name = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_field_builder_set_offset(b: *mut ros_point_field_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.offset = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.offset = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_field_builder_set_datatype(b: *mut ros_point_field_builder_t, v: u8) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.datatype = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.datatype = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_field_builder_set_count(b: *mut ros_point_field_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.count = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.count = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -6960,24 +10805,25 @@ pub extern "C" fn ros_point_field_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = sensor_msgs::PointField::builder()
-        .name(inner.name.as_str())
-        .offset(inner.offset)
-        .datatype(inner.datatype)
-        .count(inner.count)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = sensor_msgs::PointField::builder()
+            .name(inner.name.as_str())
+            .offset(inner.offset)
+            .datatype(inner.datatype)
+            .count(inner.count)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -6987,34 +10833,20 @@ pub extern "C" fn ros_point_field_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::PointField::builder()
-        .name(inner.name.as_str())
-        .offset(inner.offset)
-        .datatype(inner.datatype)
-        .count(inner.count)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::PointField::builder()
+                .name(inner.name.as_str())
+                .offset(inner.offset)
+                .datatype(inner.datatype)
+                .count(inner.count)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── sensor_msgs::PointCloud2 ────────────────────────────────────────
@@ -7043,11 +10875,13 @@ struct PointCloud2BuilderOwned {
     width: u32,
     fields: *const ros_point_field_elem_t,
     fields_count: usize,
+    fields_store: ElemStore<ros_point_field_elem_t>,
     is_bigendian: bool,
     point_step: u32,
     row_step: u32,
     data: *const u8,
     data_len: usize,
+    data_owner: Option<AdoptedData>,
     is_dense: bool,
 }
 
@@ -7055,8 +10889,8 @@ pub struct ros_point_cloud2_builder_t(PointCloud2BuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_builder_new() -> *mut ros_point_cloud2_builder_t {
-    Box::into_raw(Box::new(ros_point_cloud2_builder_t(
-        PointCloud2BuilderOwned {
+    ffi_guard(|| {
+        new_handle(ros_point_cloud2_builder_t(PointCloud2BuilderOwned {
             stamp_sec: 0,
             stamp_nanosec: 0,
             frame_id: String::new(),
@@ -7064,24 +10898,28 @@ pub extern "C" fn ros_point_cloud2_builder_new() -> *mut ros_point_cloud2_builde
             width: 0,
             fields: ptr::null(),
             fields_count: 0,
+            fields_store: ElemStore::new(),
             is_bigendian: false,
             point_step: 0,
             row_step: 0,
             data: ptr::null(),
             data_len: 0,
+            data_owner: None,
             is_dense: false,
-        },
-    )))
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_builder_free(b: *mut ros_point_cloud2_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -7090,12 +10928,14 @@ pub extern "C" fn ros_point_cloud2_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -7103,62 +10943,95 @@ pub extern "C" fn ros_point_cloud2_builder_set_frame_id(
     b: *mut ros_point_cloud2_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_builder_set_height(b: *mut ros_point_cloud2_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.height = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.height = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_builder_set_width(b: *mut ros_point_cloud2_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.width = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.width = v;
+        }
+    })
 }
 
 /// Set the field descriptor sequence (BORROWED — `fields` and every `name`
 /// pointer inside it must remain valid until the next setter on the fields
 /// slot, a subsequent build/encode_into, or free).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_builder_set_fields(
     b: *mut ros_point_cloud2_builder_t,
     fields: *const ros_point_field_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if fields.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.fields = fields;
-        (*b).0.fields_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if fields.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.fields = fields;
+            (*b).0.fields_count = count;
+            (*b).0.fields_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a copy of `field` to the field sequence. Unlike
+/// `ros_point_cloud2_builder_set_fields` nothing is borrowed: `field` may
+/// be freed or reused right away. Discards an array passed to `set_fields`.
+#[no_mangle]
+pub extern "C" fn ros_point_cloud2_builder_add_field(
+    b: *mut ros_point_cloud2_builder_t,
+    field: *const ros_point_field_builder_t,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) || bad_handle(field) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        let f = unsafe { &(*field).0 };
+        let name = inner.fields_store.keep_str(&f.name);
+        (inner.fields, inner.fields_count) = inner.fields_store.push(ros_point_field_elem_t {
+            name,
+            offset: f.offset,
+            datatype: f.datatype,
+            count: f.count,
+        });
+        0
+    })
 }
 
 #[no_mangle]
@@ -7166,12 +11039,14 @@ pub extern "C" fn ros_point_cloud2_builder_set_is_bigendian(
     b: *mut ros_point_cloud2_builder_t,
     v: bool,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.is_bigendian = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.is_bigendian = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -7179,12 +11054,14 @@ pub extern "C" fn ros_point_cloud2_builder_set_point_step(
     b: *mut ros_point_cloud2_builder_t,
     v: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.point_step = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.point_step = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -7192,12 +11069,14 @@ pub extern "C" fn ros_point_cloud2_builder_set_row_step(
     b: *mut ros_point_cloud2_builder_t,
     v: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.row_step = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.row_step = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -7206,19 +11085,53 @@ pub extern "C" fn ros_point_cloud2_builder_set_data(
     data: *const u8,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.data = data;
-        (*b).0.data_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.data = data;
+            (*b).0.data_len = len;
+            (*b).0.data_owner = None;
+        }
+        0
+    })
+}
+
+/// Like `ros_point_cloud2_builder_set_data`, but the builder takes ownership of
+/// `data` and calls `free_fn(ctx)` once it no longer needs it: on the next data
+/// setter or on `ros_point_cloud2_builder_free`. Only `encode_parts` leaves
+/// the bytes in place. On error ownership stays with the caller.
+#[no_mangle]
+pub extern "C" fn ros_point_cloud2_builder_set_data_owned(
+    b: *mut ros_point_cloud2_builder_t,
+    data: *const u8,
+    len: usize,
+    free_fn: Option<unsafe extern "C" fn(*mut std::ffi::c_void)>,
+    ctx: *mut std::ffi::c_void,
+) -> i32 {
+    ffi_guard(|| {
+        let Some(release) = free_fn else {
+            set_errno(EINVAL);
+            return -1;
+        };
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.data = data;
+        inner.data_len = len;
+        inner.data_owner = Some(AdoptedData { release, ctx });
+        0
+    })
 }
 
 #[no_mangle]
@@ -7226,12 +11139,14 @@ pub extern "C" fn ros_point_cloud2_builder_set_is_dense(
     b: *mut ros_point_cloud2_builder_t,
     v: bool,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.is_dense = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.is_dense = v;
+        }
+    })
 }
 
 fn ros_point_cloud2_builder_data_slice(inner: &PointCloud2BuilderOwned) -> &[u8] {
@@ -7274,35 +11189,36 @@ pub extern "C" fn ros_point_cloud2_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let data_slice = ros_point_cloud2_builder_data_slice(inner);
-    let fields = match unsafe { point_cloud2_fields_to_views(inner) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let r = sensor_msgs::PointCloud2::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .height(inner.height)
-        .width(inner.width)
-        .fields(&fields)
-        .is_bigendian(inner.is_bigendian)
-        .point_step(inner.point_step)
-        .row_step(inner.row_step)
-        .data(data_slice)
-        .is_dense(inner.is_dense)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let data_slice = ros_point_cloud2_builder_data_slice(inner);
+        let fields = match unsafe { point_cloud2_fields_to_views(inner) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        let r = sensor_msgs::PointCloud2::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .height(inner.height)
+            .width(inner.width)
+            .fields(&fields)
+            .is_bigendian(inner.is_bigendian)
+            .point_step(inner.point_step)
+            .row_step(inner.row_step)
+            .data(data_slice)
+            .is_dense(inner.is_dense)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -7312,45 +11228,81 @@ pub extern "C" fn ros_point_cloud2_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let data_slice = ros_point_cloud2_builder_data_slice(inner);
-    let fields = match unsafe { point_cloud2_fields_to_views(inner) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::PointCloud2::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .height(inner.height)
-        .width(inner.width)
-        .fields(&fields)
-        .is_bigendian(inner.is_bigendian)
-        .point_step(inner.point_step)
-        .row_step(inner.row_step)
-        .data(data_slice)
-        .is_dense(inner.is_dense)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let inner = unsafe { &(*b).0 };
+        let data_slice = ros_point_cloud2_builder_data_slice(inner);
+        let fields = match unsafe { point_cloud2_fields_to_views(inner) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::PointCloud2::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .height(inner.height)
+                .width(inner.width)
+                .fields(&fields)
+                .is_bigendian(inner.is_bigendian)
+                .point_step(inner.point_step)
+                .row_step(inner.row_step)
+                .data(data_slice)
+                .is_dense(inner.is_dense)
+                .encode_into_slice(dst)
+        })
+    })
+}
+
+/// See `ros_image_builder_encode_parts`.
+#[no_mangle]
+pub extern "C" fn ros_point_cloud2_builder_encode_parts(
+    b: *mut ros_point_cloud2_builder_t,
+    head: *mut u8,
+    head_cap: usize,
+    head_len: *mut usize,
+    tail: *mut u8,
+    tail_cap: usize,
+    tail_len: *mut usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let len = ros_point_cloud2_builder_data_slice(inner).len();
+        let fields = match unsafe { point_cloud2_fields_to_views(inner) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        encode_parts_into_caller_bufs(
+            len,
+            head,
+            head_cap,
+            head_len,
+            tail,
+            tail_cap,
+            tail_len,
+            |pad| {
+                let mut cdr = Vec::new();
+                sensor_msgs::PointCloud2::builder()
+                    .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                    .frame_id(inner.frame_id.as_str())
+                    .height(inner.height)
+                    .width(inner.width)
+                    .fields(&fields)
+                    .is_bigendian(inner.is_bigendian)
+                    .point_step(inner.point_step)
+                    .row_step(inner.row_step)
+                    .data(pad)
+                    .is_dense(inner.is_dense)
+                    .encode_into_vec(&mut cdr)?;
+                let at = offset_in(&cdr, sensor_msgs::PointCloud2::from_cdr(&cdr[..])?.data());
+                Ok((cdr, at))
+            },
+        )
+    })
 }
 
 // ── sensor_msgs::CameraInfo ─────────────────────────────────────────
@@ -7376,8 +11328,8 @@ pub struct ros_camera_info_builder_t(CameraInfoBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_builder_new() -> *mut ros_camera_info_builder_t {
-    Box::into_raw(Box::new(ros_camera_info_builder_t(
-        CameraInfoBuilderOwned {
+    ffi_guard(|| {
+        new_handle(ros_camera_info_builder_t(CameraInfoBuilderOwned {
             stamp_sec: 0,
             stamp_nanosec: 0,
             frame_id: String::new(),
@@ -7398,18 +11350,20 @@ pub extern "C" fn ros_camera_info_builder_new() -> *mut ros_camera_info_builder_
                 width: 0,
                 do_rectify: false,
             },
-        },
-    )))
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_builder_free(b: *mut ros_camera_info_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -7418,12 +11372,14 @@ pub extern "C" fn ros_camera_info_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -7431,38 +11387,43 @@ pub extern "C" fn ros_camera_info_builder_set_frame_id(
     b: *mut ros_camera_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_builder_set_height(b: *mut ros_camera_info_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.height = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.height = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_builder_set_width(b: *mut ros_camera_info_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.width = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.width = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -7470,18 +11431,19 @@ pub extern "C" fn ros_camera_info_builder_set_distortion_model(
     b: *mut ros_camera_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.distortion_model = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.distortion_model = s_str.to_string();
+        }
+        0
+    })
 }
 
 /// Set the distortion coefficients (BORROWED `[f64; d_len]` slice —
@@ -7493,19 +11455,20 @@ pub extern "C" fn ros_camera_info_builder_set_d(
     data: *const f64,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.d = data;
-        (*b).0.d_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.d = data;
+            (*b).0.d_len = len;
+        }
+        0
+    })
 }
 
 /// Copy 9 f64 elements from `k` into the intrinsics matrix (row-major 3x3).
@@ -7514,15 +11477,20 @@ pub extern "C" fn ros_camera_info_builder_set_k(
     b: *mut ros_camera_info_builder_t,
     k: *const f64,
 ) -> i32 {
-    if b.is_null() || k.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        let src = slice::from_raw_parts(k, 9);
-        (*b).0.k.copy_from_slice(src);
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if k.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            let src = slice::from_raw_parts(k, 9);
+            (*b).0.k.copy_from_slice(src);
+        }
+        0
+    })
 }
 
 /// Copy 9 f64 elements from `r` into the rectification matrix (row-major 3x3).
@@ -7531,15 +11499,20 @@ pub extern "C" fn ros_camera_info_builder_set_r(
     b: *mut ros_camera_info_builder_t,
     r: *const f64,
 ) -> i32 {
-    if b.is_null() || r.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        let src = slice::from_raw_parts(r, 9);
-        (*b).0.r.copy_from_slice(src);
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if r.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            let src = slice::from_raw_parts(r, 9);
+            (*b).0.r.copy_from_slice(src);
+        }
+        0
+    })
 }
 
 /// Copy 12 f64 elements from `p` into the projection matrix (row-major 3x4).
@@ -7548,35 +11521,44 @@ pub extern "C" fn ros_camera_info_builder_set_p(
     b: *mut ros_camera_info_builder_t,
     p: *const f64,
 ) -> i32 {
-    if b.is_null() || p.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        let src = slice::from_raw_parts(p, 12);
-        (*b).0.p.copy_from_slice(src);
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if p.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            let src = slice::from_raw_parts(p, 12);
+            (*b).0.p.copy_from_slice(src);
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_builder_set_binning_x(b: *mut ros_camera_info_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.binning_x = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.binning_x = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_info_builder_set_binning_y(b: *mut ros_camera_info_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.binning_y = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.binning_y = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -7588,18 +11570,20 @@ pub extern "C" fn ros_camera_info_builder_set_roi(
     width: u32,
     do_rectify: u8,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.roi = RegionOfInterest {
-            x_offset,
-            y_offset,
-            height,
-            width,
-            do_rectify: do_rectify != 0,
-        };
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.roi = RegionOfInterest {
+                x_offset,
+                y_offset,
+                height,
+                width,
+                do_rectify: do_rectify != 0,
+            };
+        }
+    })
 }
 
 fn ros_camera_info_builder_d_slice(inner: &CameraInfoBuilderOwned) -> &[f64] {
@@ -7616,33 +11600,34 @@ pub extern "C" fn ros_camera_info_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let d_slice = ros_camera_info_builder_d_slice(inner);
-    let r = sensor_msgs::CameraInfo::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .height(inner.height)
-        .width(inner.width)
-        .distortion_model(inner.distortion_model.as_str())
-        .d(d_slice)
-        .k(inner.k)
-        .r(inner.r)
-        .p(inner.p)
-        .binning_x(inner.binning_x)
-        .binning_y(inner.binning_y)
-        .roi(inner.roi)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let d_slice = ros_camera_info_builder_d_slice(inner);
+        let r = sensor_msgs::CameraInfo::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .height(inner.height)
+            .width(inner.width)
+            .distortion_model(inner.distortion_model.as_str())
+            .d(d_slice)
+            .k(inner.k)
+            .r(inner.r)
+            .p(inner.p)
+            .binning_x(inner.binning_x)
+            .binning_y(inner.binning_y)
+            .roi(inner.roi)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -7652,43 +11637,29 @@ pub extern "C" fn ros_camera_info_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let d_slice = ros_camera_info_builder_d_slice(inner);
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::CameraInfo::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .height(inner.height)
-        .width(inner.width)
-        .distortion_model(inner.distortion_model.as_str())
-        .d(d_slice)
-        .k(inner.k)
-        .r(inner.r)
-        .p(inner.p)
-        .binning_x(inner.binning_x)
-        .binning_y(inner.binning_y)
-        .roi(inner.roi)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let d_slice = ros_camera_info_builder_d_slice(inner);
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::CameraInfo::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .height(inner.height)
+                .width(inner.width)
+                .distortion_model(inner.distortion_model.as_str())
+                .d(d_slice)
+                .k(inner.k)
+                .r(inner.r)
+                .p(inner.p)
+                .binning_x(inner.binning_x)
+                .binning_y(inner.binning_y)
+                .roi(inner.roi)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── sensor_msgs::MagneticField ──────────────────────────────────────
@@ -7705,8 +11676,8 @@ pub struct ros_magnetic_field_builder_t(MagneticFieldBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_magnetic_field_builder_new() -> *mut ros_magnetic_field_builder_t {
-    Box::into_raw(Box::new(ros_magnetic_field_builder_t(
-        MagneticFieldBuilderOwned {
+    ffi_guard(|| {
+        new_handle(ros_magnetic_field_builder_t(MagneticFieldBuilderOwned {
             stamp_sec: 0,
             stamp_nanosec: 0,
             frame_id: String::new(),
@@ -7716,18 +11687,20 @@ pub extern "C" fn ros_magnetic_field_builder_new() -> *mut ros_magnetic_field_bu
                 z: 0.0,
             },
             magnetic_field_covariance: [0.0; 9],
-        },
-    )))
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_magnetic_field_builder_free(b: *mut ros_magnetic_field_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -7736,12 +11709,14 @@ pub extern "C" fn ros_magnetic_field_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -7749,18 +11724,19 @@ pub extern "C" fn ros_magnetic_field_builder_set_frame_id(
     b: *mut ros_magnetic_field_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -7770,12 +11746,14 @@ pub extern "C" fn ros_magnetic_field_builder_set_magnetic_field(
     y: f64,
     z: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.magnetic_field = Vector3 { x, y, z };
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.magnetic_field = Vector3 { x, y, z };
+        }
+    })
 }
 
 #[no_mangle]
@@ -7783,15 +11761,20 @@ pub extern "C" fn ros_magnetic_field_builder_set_magnetic_field_covariance(
     b: *mut ros_magnetic_field_builder_t,
     cov: *const f64,
 ) -> i32 {
-    if b.is_null() || cov.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        let src = slice::from_raw_parts(cov, 9);
-        (*b).0.magnetic_field_covariance.copy_from_slice(src);
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if cov.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            let src = slice::from_raw_parts(cov, 9);
+            (*b).0.magnetic_field_covariance.copy_from_slice(src);
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -7800,24 +11783,25 @@ pub extern "C" fn ros_magnetic_field_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = sensor_msgs::MagneticField::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .magnetic_field(inner.magnetic_field)
-        .magnetic_field_covariance(inner.magnetic_field_covariance)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = sensor_msgs::MagneticField::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .magnetic_field(inner.magnetic_field)
+            .magnetic_field_covariance(inner.magnetic_field_covariance)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -7827,34 +11811,20 @@ pub extern "C" fn ros_magnetic_field_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::MagneticField::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .magnetic_field(inner.magnetic_field)
-        .magnetic_field_covariance(inner.magnetic_field_covariance)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::MagneticField::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .magnetic_field(inner.magnetic_field)
+                .magnetic_field_covariance(inner.magnetic_field_covariance)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── sensor_msgs::BatteryState ───────────────────────────────────────
@@ -7887,8 +11857,8 @@ pub struct ros_battery_state_builder_t(BatteryStateBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_builder_new() -> *mut ros_battery_state_builder_t {
-    Box::into_raw(Box::new(ros_battery_state_builder_t(
-        BatteryStateBuilderOwned {
+    ffi_guard(|| {
+        new_handle(ros_battery_state_builder_t(BatteryStateBuilderOwned {
             stamp_sec: 0,
             stamp_nanosec: 0,
             frame_id: String::new(),
@@ -7909,18 +11879,20 @@ pub extern "C" fn ros_battery_state_builder_new() -> *mut ros_battery_state_buil
             cell_temperature_len: 0,
             location: String::new(),
             serial_number: String::new(),
-        },
-    )))
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_battery_state_builder_free(b: *mut ros_battery_state_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -7929,12 +11901,14 @@ pub extern "C" fn ros_battery_state_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -7942,18 +11916,19 @@ pub extern "C" fn ros_battery_state_builder_set_frame_id(
     b: *mut ros_battery_state_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -7961,12 +11936,14 @@ pub extern "C" fn ros_battery_state_builder_set_voltage(
     b: *mut ros_battery_state_builder_t,
     v: f32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.voltage = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.voltage = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -7974,12 +11951,14 @@ pub extern "C" fn ros_battery_state_builder_set_temperature(
     b: *mut ros_battery_state_builder_t,
     v: f32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.temperature = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.temperature = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -7987,12 +11966,14 @@ pub extern "C" fn ros_battery_state_builder_set_current(
     b: *mut ros_battery_state_builder_t,
     v: f32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.current = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.current = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8000,12 +11981,14 @@ pub extern "C" fn ros_battery_state_builder_set_charge(
     b: *mut ros_battery_state_builder_t,
     v: f32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.charge = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.charge = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8013,12 +11996,14 @@ pub extern "C" fn ros_battery_state_builder_set_capacity(
     b: *mut ros_battery_state_builder_t,
     v: f32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.capacity = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.capacity = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8026,12 +12011,14 @@ pub extern "C" fn ros_battery_state_builder_set_design_capacity(
     b: *mut ros_battery_state_builder_t,
     v: f32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.design_capacity = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.design_capacity = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8039,12 +12026,14 @@ pub extern "C" fn ros_battery_state_builder_set_percentage(
     b: *mut ros_battery_state_builder_t,
     v: f32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.percentage = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.percentage = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8052,12 +12041,14 @@ pub extern "C" fn ros_battery_state_builder_set_power_supply_status(
     b: *mut ros_battery_state_builder_t,
     v: u8,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.power_supply_status = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.power_supply_status = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8065,12 +12056,14 @@ pub extern "C" fn ros_battery_state_builder_set_power_supply_health(
     b: *mut ros_battery_state_builder_t,
     v: u8,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.power_supply_health = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.power_supply_health = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8078,12 +12071,14 @@ pub extern "C" fn ros_battery_state_builder_set_power_supply_technology(
     b: *mut ros_battery_state_builder_t,
     v: u8,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.power_supply_technology = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.power_supply_technology = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8091,12 +12086,14 @@ pub extern "C" fn ros_battery_state_builder_set_present(
     b: *mut ros_battery_state_builder_t,
     v: bool,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.present = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.present = v;
+        }
+    })
 }
 
 /// Set the cell_voltage sequence (BORROWED `*const f32` — pointer must
@@ -8108,19 +12105,20 @@ pub extern "C" fn ros_battery_state_builder_set_cell_voltage(
     data: *const f32,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.cell_voltage = data;
-        (*b).0.cell_voltage_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.cell_voltage = data;
+            (*b).0.cell_voltage_len = len;
+        }
+        0
+    })
 }
 
 /// Set the cell_temperature sequence (BORROWED `*const f32` — pointer must
@@ -8132,19 +12130,20 @@ pub extern "C" fn ros_battery_state_builder_set_cell_temperature(
     data: *const f32,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.cell_temperature = data;
-        (*b).0.cell_temperature_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.cell_temperature = data;
+            (*b).0.cell_temperature_len = len;
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -8152,18 +12151,19 @@ pub extern "C" fn ros_battery_state_builder_set_location(
     b: *mut ros_battery_state_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.location = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.location = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -8171,18 +12171,19 @@ pub extern "C" fn ros_battery_state_builder_set_serial_number(
     b: *mut ros_battery_state_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.serial_number = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.serial_number = s_str.to_string();
+        }
+        0
+    })
 }
 
 fn ros_battery_state_cell_voltage_slice(inner: &BatteryStateBuilderOwned) -> &[f32] {
@@ -8207,39 +12208,40 @@ pub extern "C" fn ros_battery_state_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let cv = ros_battery_state_cell_voltage_slice(inner);
-    let ct = ros_battery_state_cell_temperature_slice(inner);
-    let r = sensor_msgs::BatteryState::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .voltage(inner.voltage)
-        .temperature(inner.temperature)
-        .current(inner.current)
-        .charge(inner.charge)
-        .capacity(inner.capacity)
-        .design_capacity(inner.design_capacity)
-        .percentage(inner.percentage)
-        .power_supply_status(inner.power_supply_status)
-        .power_supply_health(inner.power_supply_health)
-        .power_supply_technology(inner.power_supply_technology)
-        .present(inner.present)
-        .cell_voltage(cv)
-        .cell_temperature(ct)
-        .location(inner.location.as_str())
-        .serial_number(inner.serial_number.as_str())
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let cv = ros_battery_state_cell_voltage_slice(inner);
+        let ct = ros_battery_state_cell_temperature_slice(inner);
+        let r = sensor_msgs::BatteryState::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .voltage(inner.voltage)
+            .temperature(inner.temperature)
+            .current(inner.current)
+            .charge(inner.charge)
+            .capacity(inner.capacity)
+            .design_capacity(inner.design_capacity)
+            .percentage(inner.percentage)
+            .power_supply_status(inner.power_supply_status)
+            .power_supply_health(inner.power_supply_health)
+            .power_supply_technology(inner.power_supply_technology)
+            .present(inner.present)
+            .cell_voltage(cv)
+            .cell_temperature(ct)
+            .location(inner.location.as_str())
+            .serial_number(inner.serial_number.as_str())
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -8249,49 +12251,35 @@ pub extern "C" fn ros_battery_state_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let cv = ros_battery_state_cell_voltage_slice(inner);
-    let ct = ros_battery_state_cell_temperature_slice(inner);
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::BatteryState::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .voltage(inner.voltage)
-        .temperature(inner.temperature)
-        .current(inner.current)
-        .charge(inner.charge)
-        .capacity(inner.capacity)
-        .design_capacity(inner.design_capacity)
-        .percentage(inner.percentage)
-        .power_supply_status(inner.power_supply_status)
-        .power_supply_health(inner.power_supply_health)
-        .power_supply_technology(inner.power_supply_technology)
-        .present(inner.present)
-        .cell_voltage(cv)
-        .cell_temperature(ct)
-        .location(inner.location.as_str())
-        .serial_number(inner.serial_number.as_str())
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let cv = ros_battery_state_cell_voltage_slice(inner);
+        let ct = ros_battery_state_cell_temperature_slice(inner);
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::BatteryState::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .voltage(inner.voltage)
+                .temperature(inner.temperature)
+                .current(inner.current)
+                .charge(inner.charge)
+                .capacity(inner.capacity)
+                .design_capacity(inner.design_capacity)
+                .percentage(inner.percentage)
+                .power_supply_status(inner.power_supply_status)
+                .power_supply_health(inner.power_supply_health)
+                .power_supply_technology(inner.power_supply_technology)
+                .present(inner.present)
+                .cell_voltage(cv)
+                .cell_temperature(ct)
+                .location(inner.location.as_str())
+                .serial_number(inner.serial_number.as_str())
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── sensor_msgs::Temperature ────────────────────────────────────────
@@ -8308,25 +12296,27 @@ pub struct ros_temperature_builder_t(TemperatureBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_builder_new() -> *mut ros_temperature_builder_t {
-    Box::into_raw(Box::new(ros_temperature_builder_t(
-        TemperatureBuilderOwned {
+    ffi_guard(|| {
+        new_handle(ros_temperature_builder_t(TemperatureBuilderOwned {
             stamp_sec: 0,
             stamp_nanosec: 0,
             frame_id: String::new(),
             temperature: 0.0,
             variance: 0.0,
-        },
-    )))
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_builder_free(b: *mut ros_temperature_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -8335,12 +12325,14 @@ pub extern "C" fn ros_temperature_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -8348,18 +12340,19 @@ pub extern "C" fn ros_temperature_builder_set_frame_id(
     b: *mut ros_temperature_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -8367,22 +12360,26 @@ pub extern "C" fn ros_temperature_builder_set_temperature(
     b: *mut ros_temperature_builder_t,
     v: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.temperature = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.temperature = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_temperature_builder_set_variance(b: *mut ros_temperature_builder_t, v: f64) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.variance = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.variance = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8391,24 +12388,25 @@ pub extern "C" fn ros_temperature_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = sensor_msgs::Temperature::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .temperature(inner.temperature)
-        .variance(inner.variance)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = sensor_msgs::Temperature::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .temperature(inner.temperature)
+            .variance(inner.variance)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -8418,34 +12416,20 @@ pub extern "C" fn ros_temperature_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = sensor_msgs::Temperature::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .temperature(inner.temperature)
-        .variance(inner.variance)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            sensor_msgs::Temperature::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .temperature(inner.temperature)
+                .variance(inner.variance)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::Mask ────────────────────────────────────────────
@@ -8464,55 +12448,65 @@ pub struct ros_mask_builder_t(MaskBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_mask_builder_new() -> *mut ros_mask_builder_t {
-    Box::into_raw(Box::new(ros_mask_builder_t(MaskBuilderOwned {
-        height: 0,
-        width: 0,
-        length: 0,
-        encoding: String::new(),
-        mask: ptr::null(),
-        mask_len: 0,
-        boxed: false,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_mask_builder_t(MaskBuilderOwned {
+            height: 0,
+            width: 0,
+            length: 0,
+            encoding: String::new(),
+            mask: ptr::null(),
+            mask_len: 0,
+            boxed: false,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_builder_free(b: *mut ros_mask_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_builder_set_height(b: *mut ros_mask_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.height = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.height = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_builder_set_width(b: *mut ros_mask_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.width = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.width = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_builder_set_length(b: *mut ros_mask_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.length = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.length = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8520,18 +12514,19 @@ pub extern "C" fn ros_mask_builder_set_encoding(
     b: *mut ros_mask_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.encoding = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.encoding = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -8540,29 +12535,32 @@ pub extern "C" fn ros_mask_builder_set_mask(
     data: *const u8,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.mask = data;
-        (*b).0.mask_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.mask = data;
+            (*b).0.mask_len = len;
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_mask_builder_set_boxed(b: *mut ros_mask_builder_t, v: bool) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.boxed = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.boxed = v;
+        }
+    })
 }
 
 fn ros_mask_builder_mask_slice(inner: &MaskBuilderOwned) -> &[u8] {
@@ -8579,27 +12577,28 @@ pub extern "C" fn ros_mask_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let mask_slice = ros_mask_builder_mask_slice(inner);
-    let r = edgefirst_msgs::Mask::builder()
-        .height(inner.height)
-        .width(inner.width)
-        .length(inner.length)
-        .encoding(inner.encoding.as_str())
-        .mask(mask_slice)
-        .boxed(inner.boxed)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let mask_slice = ros_mask_builder_mask_slice(inner);
+        let r = edgefirst_msgs::Mask::builder()
+            .height(inner.height)
+            .width(inner.width)
+            .length(inner.length)
+            .encoding(inner.encoding.as_str())
+            .mask(mask_slice)
+            .boxed(inner.boxed)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -8609,37 +12608,23 @@ pub extern "C" fn ros_mask_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let mask_slice = ros_mask_builder_mask_slice(inner);
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::Mask::builder()
-        .height(inner.height)
-        .width(inner.width)
-        .length(inner.length)
-        .encoding(inner.encoding.as_str())
-        .mask(mask_slice)
-        .boxed(inner.boxed)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let mask_slice = ros_mask_builder_mask_slice(inner);
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::Mask::builder()
+                .height(inner.height)
+                .width(inner.width)
+                .length(inner.length)
+                .encoding(inner.encoding.as_str())
+                .mask(mask_slice)
+                .boxed(inner.boxed)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::LocalTime ───────────────────────────────────────
@@ -8660,27 +12645,31 @@ pub struct ros_local_time_builder_t(LocalTimeBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_local_time_builder_new() -> *mut ros_local_time_builder_t {
-    Box::into_raw(Box::new(ros_local_time_builder_t(LocalTimeBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        date_year: 0,
-        date_month: 0,
-        date_day: 0,
-        time_sec: 0,
-        time_nanosec: 0,
-        timezone: 0,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_local_time_builder_t(LocalTimeBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            date_year: 0,
+            date_month: 0,
+            date_day: 0,
+            time_sec: 0,
+            time_nanosec: 0,
+            timezone: 0,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_local_time_builder_free(b: *mut ros_local_time_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -8689,12 +12678,14 @@ pub extern "C" fn ros_local_time_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -8702,18 +12693,19 @@ pub extern "C" fn ros_local_time_builder_set_frame_id(
     b: *mut ros_local_time_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -8723,13 +12715,15 @@ pub extern "C" fn ros_local_time_builder_set_date(
     month: u8,
     day: u8,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.date_year = year;
-    inner.date_month = month;
-    inner.date_day = day;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.date_year = year;
+        inner.date_month = month;
+        inner.date_day = day;
+    })
 }
 
 #[no_mangle]
@@ -8738,22 +12732,26 @@ pub extern "C" fn ros_local_time_builder_set_time(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.time_sec = sec;
-    inner.time_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.time_sec = sec;
+        inner.time_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_local_time_builder_set_timezone(b: *mut ros_local_time_builder_t, v: i16) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.timezone = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.timezone = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8762,29 +12760,30 @@ pub extern "C" fn ros_local_time_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = edgefirst_msgs::LocalTime::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .date(edgefirst_msgs::Date {
-            year: inner.date_year,
-            month: inner.date_month,
-            day: inner.date_day,
-        })
-        .time(Time::new(inner.time_sec, inner.time_nanosec))
-        .timezone(inner.timezone)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = edgefirst_msgs::LocalTime::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .date(edgefirst_msgs::Date {
+                year: inner.date_year,
+                month: inner.date_month,
+                day: inner.date_day,
+            })
+            .time(Time::new(inner.time_sec, inner.time_nanosec))
+            .timezone(inner.timezone)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -8794,39 +12793,25 @@ pub extern "C" fn ros_local_time_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::LocalTime::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .date(edgefirst_msgs::Date {
-            year: inner.date_year,
-            month: inner.date_month,
-            day: inner.date_day,
-        })
-        .time(Time::new(inner.time_sec, inner.time_nanosec))
-        .timezone(inner.timezone)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::LocalTime::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .date(edgefirst_msgs::Date {
+                    year: inner.date_year,
+                    month: inner.date_month,
+                    day: inner.date_day,
+                })
+                .time(Time::new(inner.time_sec, inner.time_nanosec))
+                .timezone(inner.timezone)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::RadarCube ───────────────────────────────────────
@@ -8851,31 +12836,35 @@ pub struct ros_radar_cube_builder_t(RadarCubeBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_builder_new() -> *mut ros_radar_cube_builder_t {
-    Box::into_raw(Box::new(ros_radar_cube_builder_t(RadarCubeBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        timestamp: 0,
-        layout: ptr::null(),
-        layout_len: 0,
-        shape: ptr::null(),
-        shape_len: 0,
-        scales: ptr::null(),
-        scales_len: 0,
-        cube: ptr::null(),
-        cube_len: 0,
-        is_complex: false,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_radar_cube_builder_t(RadarCubeBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            timestamp: 0,
+            layout: ptr::null(),
+            layout_len: 0,
+            shape: ptr::null(),
+            shape_len: 0,
+            scales: ptr::null(),
+            scales_len: 0,
+            cube: ptr::null(),
+            cube_len: 0,
+            is_complex: false,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_builder_free(b: *mut ros_radar_cube_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -8884,12 +12873,14 @@ pub extern "C" fn ros_radar_cube_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -8897,28 +12888,31 @@ pub extern "C" fn ros_radar_cube_builder_set_frame_id(
     b: *mut ros_radar_cube_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_builder_set_timestamp(b: *mut ros_radar_cube_builder_t, v: u64) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.timestamp = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.timestamp = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -8927,19 +12921,20 @@ pub extern "C" fn ros_radar_cube_builder_set_layout(
     data: *const u8,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.layout = data;
-        (*b).0.layout_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.layout = data;
+            (*b).0.layout_len = len;
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -8948,19 +12943,20 @@ pub extern "C" fn ros_radar_cube_builder_set_shape(
     data: *const u16,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.shape = data;
-        (*b).0.shape_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.shape = data;
+            (*b).0.shape_len = len;
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -8969,19 +12965,20 @@ pub extern "C" fn ros_radar_cube_builder_set_scales(
     data: *const f32,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.scales = data;
-        (*b).0.scales_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.scales = data;
+            (*b).0.scales_len = len;
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -8990,29 +12987,32 @@ pub extern "C" fn ros_radar_cube_builder_set_cube(
     data: *const i16,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.cube = data;
-        (*b).0.cube_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.cube = data;
+            (*b).0.cube_len = len;
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_builder_set_is_complex(b: *mut ros_radar_cube_builder_t, v: bool) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.is_complex = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.is_complex = v;
+        }
+    })
 }
 
 fn radar_cube_layout_slice(inner: &RadarCubeBuilderOwned) -> &[u8] {
@@ -9050,28 +13050,29 @@ pub extern "C" fn ros_radar_cube_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = edgefirst_msgs::RadarCube::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .timestamp(inner.timestamp)
-        .layout(radar_cube_layout_slice(inner))
-        .shape(radar_cube_shape_slice(inner))
-        .scales(radar_cube_scales_slice(inner))
-        .cube(radar_cube_cube_slice(inner))
-        .is_complex(inner.is_complex)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = edgefirst_msgs::RadarCube::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .timestamp(inner.timestamp)
+            .layout(radar_cube_layout_slice(inner))
+            .shape(radar_cube_shape_slice(inner))
+            .scales(radar_cube_scales_slice(inner))
+            .cube(radar_cube_cube_slice(inner))
+            .is_complex(inner.is_complex)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -9081,38 +13082,24 @@ pub extern "C" fn ros_radar_cube_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::RadarCube::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .timestamp(inner.timestamp)
-        .layout(radar_cube_layout_slice(inner))
-        .shape(radar_cube_shape_slice(inner))
-        .scales(radar_cube_scales_slice(inner))
-        .cube(radar_cube_cube_slice(inner))
-        .is_complex(inner.is_complex)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::RadarCube::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .timestamp(inner.timestamp)
+                .layout(radar_cube_layout_slice(inner))
+                .shape(radar_cube_shape_slice(inner))
+                .scales(radar_cube_scales_slice(inner))
+                .cube(radar_cube_cube_slice(inner))
+                .is_complex(inner.is_complex)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::RadarInfo ───────────────────────────────────────
@@ -9132,26 +13119,30 @@ pub struct ros_radar_info_builder_t(RadarInfoBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_builder_new() -> *mut ros_radar_info_builder_t {
-    Box::into_raw(Box::new(ros_radar_info_builder_t(RadarInfoBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        center_frequency: String::new(),
-        frequency_sweep: String::new(),
-        range_toggle: String::new(),
-        detection_sensitivity: String::new(),
-        cube: false,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_radar_info_builder_t(RadarInfoBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            center_frequency: String::new(),
+            frequency_sweep: String::new(),
+            range_toggle: String::new(),
+            detection_sensitivity: String::new(),
+            cube: false,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_builder_free(b: *mut ros_radar_info_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -9160,12 +13151,14 @@ pub extern "C" fn ros_radar_info_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -9173,18 +13166,19 @@ pub extern "C" fn ros_radar_info_builder_set_frame_id(
     b: *mut ros_radar_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -9192,18 +13186,19 @@ pub extern "C" fn ros_radar_info_builder_set_center_frequency(
     b: *mut ros_radar_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.center_frequency = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.center_frequency = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -9211,18 +13206,19 @@ pub extern "C" fn ros_radar_info_builder_set_frequency_sweep(
     b: *mut ros_radar_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frequency_sweep = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frequency_sweep = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -9230,18 +13226,19 @@ pub extern "C" fn ros_radar_info_builder_set_range_toggle(
     b: *mut ros_radar_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.range_toggle = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.range_toggle = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -9249,28 +13246,31 @@ pub extern "C" fn ros_radar_info_builder_set_detection_sensitivity(
     b: *mut ros_radar_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.detection_sensitivity = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.detection_sensitivity = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_radar_info_builder_set_cube(b: *mut ros_radar_info_builder_t, v: bool) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.cube = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.cube = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -9279,27 +13279,28 @@ pub extern "C" fn ros_radar_info_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = edgefirst_msgs::RadarInfo::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .center_frequency(inner.center_frequency.as_str())
-        .frequency_sweep(inner.frequency_sweep.as_str())
-        .range_toggle(inner.range_toggle.as_str())
-        .detection_sensitivity(inner.detection_sensitivity.as_str())
-        .cube(inner.cube)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = edgefirst_msgs::RadarInfo::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .center_frequency(inner.center_frequency.as_str())
+            .frequency_sweep(inner.frequency_sweep.as_str())
+            .range_toggle(inner.range_toggle.as_str())
+            .detection_sensitivity(inner.detection_sensitivity.as_str())
+            .cube(inner.cube)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -9309,37 +13310,23 @@ pub extern "C" fn ros_radar_info_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::RadarInfo::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .center_frequency(inner.center_frequency.as_str())
-        .frequency_sweep(inner.frequency_sweep.as_str())
-        .range_toggle(inner.range_toggle.as_str())
-        .detection_sensitivity(inner.detection_sensitivity.as_str())
-        .cube(inner.cube)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::RadarInfo::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .center_frequency(inner.center_frequency.as_str())
+                .frequency_sweep(inner.frequency_sweep.as_str())
+                .range_toggle(inner.range_toggle.as_str())
+                .detection_sensitivity(inner.detection_sensitivity.as_str())
+                .cube(inner.cube)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::Track ───────────────────────────────────────────
@@ -9355,48 +13342,55 @@ pub struct ros_track_builder_t(TrackBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_track_builder_new() -> *mut ros_track_builder_t {
-    Box::into_raw(Box::new(ros_track_builder_t(TrackBuilderOwned {
-        id: String::new(),
-        lifetime: 0,
-        created_sec: 0,
-        created_nanosec: 0,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_track_builder_t(TrackBuilderOwned {
+            id: String::new(),
+            lifetime: 0,
+            created_sec: 0,
+            created_nanosec: 0,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_track_builder_free(b: *mut ros_track_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_track_builder_set_id(b: *mut ros_track_builder_t, s: *const c_char) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_track_builder_set_lifetime(b: *mut ros_track_builder_t, v: i32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.lifetime = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.lifetime = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -9405,12 +13399,14 @@ pub extern "C" fn ros_track_builder_set_created(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.created_sec = sec;
-    inner.created_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.created_sec = sec;
+        inner.created_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -9419,23 +13415,24 @@ pub extern "C" fn ros_track_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = edgefirst_msgs::Track::builder()
-        .id(inner.id.as_str())
-        .lifetime(inner.lifetime)
-        .created(Time::new(inner.created_sec, inner.created_nanosec))
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = edgefirst_msgs::Track::builder()
+            .id(inner.id.as_str())
+            .lifetime(inner.lifetime)
+            .created(Time::new(inner.created_sec, inner.created_nanosec))
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -9445,33 +13442,19 @@ pub extern "C" fn ros_track_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::Track::builder()
-        .id(inner.id.as_str())
-        .lifetime(inner.lifetime)
-        .created(Time::new(inner.created_sec, inner.created_nanosec))
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::Track::builder()
+                .id(inner.id.as_str())
+                .lifetime(inner.lifetime)
+                .created(Time::new(inner.created_sec, inner.created_nanosec))
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::DetectBox / Detect / Model shared element ───────
@@ -9486,6 +13469,7 @@ pub extern "C" fn ros_track_builder_encode_into(
 /// are borrowed C strings; both must remain valid until the consuming
 /// builder is finalised (build/encode_into) or freed.
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub struct ros_detect_box_elem_t {
     pub center_x: f32,
     pub center_y: f32,
@@ -9558,70 +13542,82 @@ pub struct ros_detect_box_builder_t(DetectBoxBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_new() -> *mut ros_detect_box_builder_t {
-    Box::into_raw(Box::new(ros_detect_box_builder_t(DetectBoxBuilderOwned {
-        center_x: 0.0,
-        center_y: 0.0,
-        width: 0.0,
-        height: 0.0,
-        label: String::new(),
-        score: 0.0,
-        distance: 0.0,
-        speed: 0.0,
-        track_id: String::new(),
-        track_lifetime: 0,
-        track_created_sec: 0,
-        track_created_nanosec: 0,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_detect_box_builder_t(DetectBoxBuilderOwned {
+            center_x: 0.0,
+            center_y: 0.0,
+            width: 0.0,
+            height: 0.0,
+            label: String::new(),
+            score: 0.0,
+            distance: 0.0,
+            speed: 0.0,
+            track_id: String::new(),
+            track_lifetime: 0,
+            track_created_sec: 0,
+            track_created_nanosec: 0,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_free(b: *mut ros_detect_box_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_set_center_x(b: *mut ros_detect_box_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.center_x = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.center_x = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_set_center_y(b: *mut ros_detect_box_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.center_y = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.center_y = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_set_width(b: *mut ros_detect_box_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.width = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.width = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_set_height(b: *mut ros_detect_box_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.height = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.height = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -9629,48 +13625,55 @@ pub extern "C" fn ros_detect_box_builder_set_label(
     b: *mut ros_detect_box_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.label = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.label = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_set_score(b: *mut ros_detect_box_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.score = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.score = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_set_distance(b: *mut ros_detect_box_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.distance = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.distance = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_box_builder_set_speed(b: *mut ros_detect_box_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.speed = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.speed = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -9678,18 +13681,19 @@ pub extern "C" fn ros_detect_box_builder_set_track_id(
     b: *mut ros_detect_box_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.track_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.track_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -9697,12 +13701,14 @@ pub extern "C" fn ros_detect_box_builder_set_track_lifetime(
     b: *mut ros_detect_box_builder_t,
     v: i32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.track_lifetime = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.track_lifetime = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -9711,12 +13717,14 @@ pub extern "C" fn ros_detect_box_builder_set_track_created(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.track_created_sec = sec;
-    inner.track_created_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.track_created_sec = sec;
+        inner.track_created_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -9725,34 +13733,35 @@ pub extern "C" fn ros_detect_box_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = edgefirst_msgs::DetectBox::builder()
-        .center_x(inner.center_x)
-        .center_y(inner.center_y)
-        .width(inner.width)
-        .height(inner.height)
-        .label(inner.label.as_str())
-        .score(inner.score)
-        .distance(inner.distance)
-        .speed(inner.speed)
-        .track_id(inner.track_id.as_str())
-        .track_lifetime(inner.track_lifetime)
-        .track_created(Time::new(
-            inner.track_created_sec,
-            inner.track_created_nanosec,
-        ))
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = edgefirst_msgs::DetectBox::builder()
+            .center_x(inner.center_x)
+            .center_y(inner.center_y)
+            .width(inner.width)
+            .height(inner.height)
+            .label(inner.label.as_str())
+            .score(inner.score)
+            .distance(inner.distance)
+            .speed(inner.speed)
+            .track_id(inner.track_id.as_str())
+            .track_lifetime(inner.track_lifetime)
+            .track_created(Time::new(
+                inner.track_created_sec,
+                inner.track_created_nanosec,
+            ))
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -9762,44 +13771,30 @@ pub extern "C" fn ros_detect_box_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::DetectBox::builder()
-        .center_x(inner.center_x)
-        .center_y(inner.center_y)
-        .width(inner.width)
-        .height(inner.height)
-        .label(inner.label.as_str())
-        .score(inner.score)
-        .distance(inner.distance)
-        .speed(inner.speed)
-        .track_id(inner.track_id.as_str())
-        .track_lifetime(inner.track_lifetime)
-        .track_created(Time::new(
-            inner.track_created_sec,
-            inner.track_created_nanosec,
-        ))
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::DetectBox::builder()
+                .center_x(inner.center_x)
+                .center_y(inner.center_y)
+                .width(inner.width)
+                .height(inner.height)
+                .label(inner.label.as_str())
+                .score(inner.score)
+                .distance(inner.distance)
+                .speed(inner.speed)
+                .track_id(inner.track_id.as_str())
+                .track_lifetime(inner.track_lifetime)
+                .track_created(Time::new(
+                    inner.track_created_sec,
+                    inner.track_created_nanosec,
+                ))
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::Detect ──────────────────────────────────────────
@@ -9816,35 +13811,41 @@ struct DetectBuilderOwned {
     output_nanosec: u32,
     boxes: *const ros_detect_box_elem_t,
     boxes_count: usize,
+    boxes_store: ElemStore<ros_detect_box_elem_t>,
 }
 
 pub struct ros_detect_builder_t(DetectBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_detect_builder_new() -> *mut ros_detect_builder_t {
-    Box::into_raw(Box::new(ros_detect_builder_t(DetectBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        input_sec: 0,
-        input_nanosec: 0,
-        model_sec: 0,
-        model_nanosec: 0,
-        output_sec: 0,
-        output_nanosec: 0,
-        boxes: ptr::null(),
-        boxes_count: 0,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_detect_builder_t(DetectBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            input_sec: 0,
+            input_nanosec: 0,
+            model_sec: 0,
+            model_nanosec: 0,
+            output_sec: 0,
+            output_nanosec: 0,
+            boxes: ptr::null(),
+            boxes_count: 0,
+            boxes_store: ElemStore::new(),
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_detect_builder_free(b: *mut ros_detect_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -9853,12 +13854,14 @@ pub extern "C" fn ros_detect_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -9866,18 +13869,19 @@ pub extern "C" fn ros_detect_builder_set_frame_id(
     b: *mut ros_detect_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -9886,12 +13890,14 @@ pub extern "C" fn ros_detect_builder_set_input_timestamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.input_sec = sec;
-    inner.input_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.input_sec = sec;
+        inner.input_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -9900,12 +13906,14 @@ pub extern "C" fn ros_detect_builder_set_model_time(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.model_sec = sec;
-    inner.model_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.model_sec = sec;
+        inner.model_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -9914,36 +13922,119 @@ pub extern "C" fn ros_detect_builder_set_output_time(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.output_sec = sec;
-    inner.output_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.output_sec = sec;
+        inner.output_nanosec = nanosec;
+    })
 }
 
 /// Set the boxes descriptor sequence (BORROWED — `boxes` and every
 /// `label`/`track_id` pointer inside it must remain valid until the next
 /// setter on the boxes slot, a subsequent build/encode_into, or free).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_detect_builder_set_boxes(
     b: *mut ros_detect_builder_t,
     boxes: *const ros_detect_box_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if boxes.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.boxes = boxes;
-        (*b).0.boxes_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if boxes.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.boxes = boxes;
+            (*b).0.boxes_count = count;
+            (*b).0.boxes_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a copy of `detect_box` to the boxes sequence. Unlike
+/// `ros_detect_builder_set_boxes` nothing is borrowed: `detect_box` may be
+/// freed or reused right away. Discards an array passed to `set_boxes`.
+#[no_mangle]
+pub extern "C" fn ros_detect_builder_add_box(
+    b: *mut ros_detect_builder_t,
+    detect_box: *const ros_detect_box_builder_t,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) || bad_handle(detect_box) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        let d = unsafe { &(*detect_box).0 };
+        let label = inner.boxes_store.keep_str(&d.label);
+        let track_id = inner.boxes_store.keep_str(&d.track_id);
+        (inner.boxes, inner.boxes_count) = inner.boxes_store.push(ros_detect_box_elem_t {
+            center_x: d.center_x,
+            center_y: d.center_y,
+            width: d.width,
+            height: d.height,
+            label,
+            score: d.score,
+            distance: d.distance,
+            speed: d.speed,
+            track_id,
+            track_lifetime: d.track_lifetime,
+            track_created_sec: d.track_created_sec,
+            track_created_nanosec: d.track_created_nanosec,
+        });
+        0
+    })
+}
+
+/// Number of boxes currently in the builder, from `set_boxes` or
+/// `add_box`. Returns 0 with `errno = EINVAL` for a NULL handle.
+#[no_mangle]
+pub extern "C" fn ros_detect_builder_get_boxes_len(b: *const ros_detect_builder_t) -> usize {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return 0;
+        }
+        unsafe { (*b).0.boxes_count }
+    })
+}
+
+/// Remove box `index`, shifting later boxes down. An array passed to
+/// `set_boxes` is copied first and left untouched. Returns -1 with
+/// `errno = EINVAL` for a NULL handle or an index out of range.
+#[no_mangle]
+pub extern "C" fn ros_detect_builder_remove_box(b: *mut ros_detect_builder_t, index: usize) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        match inner
+            .boxes_store
+            .remove((inner.boxes, inner.boxes_count), index)
+        {
+            Some(slot) => {
+                (inner.boxes, inner.boxes_count) = slot;
+                0
+            }
+            None => {
+                set_error(
+                    EINVAL,
+                    format_args!(
+                        "box index {} out of range ({} boxes)",
+                        index, inner.boxes_count
+                    ),
+                );
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -9952,30 +14043,31 @@ pub extern "C" fn ros_detect_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let boxes = match unsafe { detect_box_descs_to_views(inner.boxes, inner.boxes_count) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let r = edgefirst_msgs::Detect::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .input_timestamp(Time::new(inner.input_sec, inner.input_nanosec))
-        .model_time(Time::new(inner.model_sec, inner.model_nanosec))
-        .output_time(Time::new(inner.output_sec, inner.output_nanosec))
-        .boxes(&boxes)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let boxes = match unsafe { detect_box_descs_to_views(inner.boxes, inner.boxes_count) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        let r = edgefirst_msgs::Detect::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .input_timestamp(Time::new(inner.input_sec, inner.input_nanosec))
+            .model_time(Time::new(inner.model_sec, inner.model_nanosec))
+            .output_time(Time::new(inner.output_sec, inner.output_nanosec))
+            .boxes(&boxes)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -9985,40 +14077,26 @@ pub extern "C" fn ros_detect_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let boxes = match unsafe { detect_box_descs_to_views(inner.boxes, inner.boxes_count) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::Detect::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .input_timestamp(Time::new(inner.input_sec, inner.input_nanosec))
-        .model_time(Time::new(inner.model_sec, inner.model_nanosec))
-        .output_time(Time::new(inner.output_sec, inner.output_nanosec))
-        .boxes(&boxes)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let boxes = match unsafe { detect_box_descs_to_views(inner.boxes, inner.boxes_count) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::Detect::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .input_timestamp(Time::new(inner.input_sec, inner.input_nanosec))
+                .model_time(Time::new(inner.model_sec, inner.model_nanosec))
+                .output_time(Time::new(inner.output_sec, inner.output_nanosec))
+                .boxes(&boxes)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::CameraFrame ─────────────────────────────────────
@@ -10083,14 +14161,15 @@ struct CameraFrameBuilderOwned {
     fence_fd: i32,
     planes: *const ros_camera_plane_elem_t,
     planes_count: usize,
+    planes_store: ElemStore<ros_camera_plane_elem_t>,
 }
 
 pub struct ros_camera_frame_builder_t(CameraFrameBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_builder_new() -> *mut ros_camera_frame_builder_t {
-    Box::into_raw(Box::new(ros_camera_frame_builder_t(
-        CameraFrameBuilderOwned {
+    ffi_guard(|| {
+        new_handle(ros_camera_frame_builder_t(CameraFrameBuilderOwned {
             stamp_sec: 0,
             stamp_nanosec: 0,
             frame_id: String::new(),
@@ -10106,18 +14185,21 @@ pub extern "C" fn ros_camera_frame_builder_new() -> *mut ros_camera_frame_builde
             fence_fd: -1,
             planes: ptr::null(),
             planes_count: 0,
-        },
-    )))
+            planes_store: ElemStore::new(),
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_builder_free(b: *mut ros_camera_frame_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -10126,12 +14208,14 @@ pub extern "C" fn ros_camera_frame_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -10139,58 +14223,67 @@ pub extern "C" fn ros_camera_frame_builder_set_frame_id(
     b: *mut ros_camera_frame_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_builder_set_seq(b: *mut ros_camera_frame_builder_t, v: u64) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.seq = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.seq = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_builder_set_pid(b: *mut ros_camera_frame_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.pid = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.pid = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_builder_set_width(b: *mut ros_camera_frame_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.width = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.width = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_builder_set_height(b: *mut ros_camera_frame_builder_t, v: u32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.height = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.height = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -10198,37 +14291,39 @@ pub extern "C" fn ros_camera_frame_builder_set_format(
     b: *mut ros_camera_frame_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.format = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.format = s_str.to_string();
+        }
+        0
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn ros_camera_frame_builder_set_color_space(
-    b: *mut ros_camera_frame_builder_t,
-    s: *const c_char,
-) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.color_space = s_str.to_string();
-    }
-    0
+#[no_mangle]
+pub extern "C" fn ros_camera_frame_builder_set_color_space(
+    b: *mut ros_camera_frame_builder_t,
+    s: *const c_char,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.color_space = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -10236,18 +14331,19 @@ pub extern "C" fn ros_camera_frame_builder_set_color_transfer(
     b: *mut ros_camera_frame_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.color_transfer = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.color_transfer = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -10255,18 +14351,19 @@ pub extern "C" fn ros_camera_frame_builder_set_color_encoding(
     b: *mut ros_camera_frame_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.color_encoding = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.color_encoding = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -10274,18 +14371,19 @@ pub extern "C" fn ros_camera_frame_builder_set_color_range(
     b: *mut ros_camera_frame_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.color_range = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.color_range = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -10293,36 +14391,84 @@ pub extern "C" fn ros_camera_frame_builder_set_fence_fd(
     b: *mut ros_camera_frame_builder_t,
     v: i32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.fence_fd = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.fence_fd = v;
+        }
+    })
 }
 
 /// Set the planes descriptor sequence (BORROWED — `planes` and every
 /// `data` pointer inside it must remain valid until the next setter on
 /// the planes slot, a subsequent build/encode_into, or free).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_builder_set_planes(
     b: *mut ros_camera_frame_builder_t,
     planes: *const ros_camera_plane_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if planes.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.planes = planes;
-        (*b).0.planes_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if planes.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.planes = planes;
+            (*b).0.planes_count = count;
+            (*b).0.planes_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a plane to the planes sequence, copying `data_len` bytes of
+/// inlined `data` (NULL when `data_len == 0`). Unlike
+/// `ros_camera_frame_builder_set_planes` nothing is borrowed. Discards an
+/// array passed to `set_planes`.
+#[no_mangle]
+pub extern "C" fn ros_camera_frame_builder_add_plane(
+    b: *mut ros_camera_frame_builder_t,
+    fd: i32,
+    offset: u32,
+    stride: u32,
+    size: u32,
+    used: u32,
+    data: *const u8,
+    data_len: usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && data_len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        let data: &[u8] = if data.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(data, data_len) }
+        };
+        let data_ptr = inner.planes_store.keep_slice(data);
+        (inner.planes, inner.planes_count) = inner.planes_store.push(ros_camera_plane_elem_t {
+            fd,
+            offset,
+            stride,
+            size,
+            used,
+            data: data_ptr,
+            data_len: data.len(),
+        });
+        0
+    })
 }
 
 #[no_mangle]
@@ -10331,34 +14477,35 @@ pub extern "C" fn ros_camera_frame_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let planes = unsafe { camera_plane_descs_to_views(inner.planes, inner.planes_count) };
-    let r = edgefirst_msgs::CameraFrame::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .seq(inner.seq)
-        .pid(inner.pid)
-        .width(inner.width)
-        .height(inner.height)
-        .format(inner.format.as_str())
-        .color_space(inner.color_space.as_str())
-        .color_transfer(inner.color_transfer.as_str())
-        .color_encoding(inner.color_encoding.as_str())
-        .color_range(inner.color_range.as_str())
-        .fence_fd(inner.fence_fd)
-        .planes(&planes)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let planes = unsafe { camera_plane_descs_to_views(inner.planes, inner.planes_count) };
+        let r = edgefirst_msgs::CameraFrame::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .seq(inner.seq)
+            .pid(inner.pid)
+            .width(inner.width)
+            .height(inner.height)
+            .format(inner.format.as_str())
+            .color_space(inner.color_space.as_str())
+            .color_transfer(inner.color_transfer.as_str())
+            .color_encoding(inner.color_encoding.as_str())
+            .color_range(inner.color_range.as_str())
+            .fence_fd(inner.fence_fd)
+            .planes(&planes)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -10368,44 +14515,30 @@ pub extern "C" fn ros_camera_frame_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let planes = unsafe { camera_plane_descs_to_views(inner.planes, inner.planes_count) };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::CameraFrame::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .seq(inner.seq)
-        .pid(inner.pid)
-        .width(inner.width)
-        .height(inner.height)
-        .format(inner.format.as_str())
-        .color_space(inner.color_space.as_str())
-        .color_transfer(inner.color_transfer.as_str())
-        .color_encoding(inner.color_encoding.as_str())
-        .color_range(inner.color_range.as_str())
-        .fence_fd(inner.fence_fd)
-        .planes(&planes)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let planes = unsafe { camera_plane_descs_to_views(inner.planes, inner.planes_count) };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::CameraFrame::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .seq(inner.seq)
+                .pid(inner.pid)
+                .width(inner.width)
+                .height(inner.height)
+                .format(inner.format.as_str())
+                .color_space(inner.color_space.as_str())
+                .color_transfer(inner.color_transfer.as_str())
+                .color_encoding(inner.color_encoding.as_str())
+                .color_range(inner.color_range.as_str())
+                .fence_fd(inner.fence_fd)
+                .planes(&planes)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::Model ───────────────────────────────────────────
@@ -10480,51 +14613,61 @@ struct ModelBuilderOwned {
     decode_nanosec: u32,
     boxes: *const ros_detect_box_elem_t,
     boxes_count: usize,
+    boxes_store: ElemStore<ros_detect_box_elem_t>,
     masks: *const ros_mask_elem_t,
     masks_count: usize,
+    masks_store: ElemStore<ros_mask_elem_t>,
 }
 
 pub struct ros_model_builder_t(ModelBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_model_builder_new() -> *mut ros_model_builder_t {
-    Box::into_raw(Box::new(ros_model_builder_t(ModelBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        input_sec: 0,
-        input_nanosec: 0,
-        model_sec: 0,
-        model_nanosec: 0,
-        output_sec: 0,
-        output_nanosec: 0,
-        decode_sec: 0,
-        decode_nanosec: 0,
-        boxes: ptr::null(),
-        boxes_count: 0,
-        masks: ptr::null(),
-        masks_count: 0,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_model_builder_t(ModelBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            input_sec: 0,
+            input_nanosec: 0,
+            model_sec: 0,
+            model_nanosec: 0,
+            output_sec: 0,
+            output_nanosec: 0,
+            decode_sec: 0,
+            decode_nanosec: 0,
+            boxes: ptr::null(),
+            boxes_count: 0,
+            boxes_store: ElemStore::new(),
+            masks: ptr::null(),
+            masks_count: 0,
+            masks_store: ElemStore::new(),
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_builder_free(b: *mut ros_model_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_builder_set_stamp(b: *mut ros_model_builder_t, sec: i32, nanosec: u32) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -10532,18 +14675,19 @@ pub extern "C" fn ros_model_builder_set_frame_id(
     b: *mut ros_model_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -10552,12 +14696,14 @@ pub extern "C" fn ros_model_builder_set_input_time(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.input_sec = sec;
-    inner.input_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.input_sec = sec;
+        inner.input_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -10566,12 +14712,14 @@ pub extern "C" fn ros_model_builder_set_model_time(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.model_sec = sec;
-    inner.model_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.model_sec = sec;
+        inner.model_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -10580,12 +14728,14 @@ pub extern "C" fn ros_model_builder_set_output_time(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.output_sec = sec;
-    inner.output_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.output_sec = sec;
+        inner.output_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -10594,170 +14744,234 @@ pub extern "C" fn ros_model_builder_set_decode_time(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.decode_sec = sec;
-    inner.decode_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.decode_sec = sec;
+        inner.decode_nanosec = nanosec;
+    })
 }
 
 /// Set the boxes descriptor sequence (BORROWED — see
 /// `ros_detect_builder_set_boxes`).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_model_builder_set_boxes(
     b: *mut ros_model_builder_t,
     boxes: *const ros_detect_box_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if boxes.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.boxes = boxes;
-        (*b).0.boxes_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if boxes.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.boxes = boxes;
+            (*b).0.boxes_count = count;
+            (*b).0.boxes_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a copy of `detect_box` to the boxes sequence. Unlike
+/// `ros_model_builder_set_boxes` nothing is borrowed: `detect_box` may be
+/// freed or reused right away. Discards an array passed to `set_boxes`.
+#[no_mangle]
+pub extern "C" fn ros_model_builder_add_box(
+    b: *mut ros_model_builder_t,
+    detect_box: *const ros_detect_box_builder_t,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) || bad_handle(detect_box) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        let d = unsafe { &(*detect_box).0 };
+        let label = inner.boxes_store.keep_str(&d.label);
+        let track_id = inner.boxes_store.keep_str(&d.track_id);
+        (inner.boxes, inner.boxes_count) = inner.boxes_store.push(ros_detect_box_elem_t {
+            center_x: d.center_x,
+            center_y: d.center_y,
+            width: d.width,
+            height: d.height,
+            label,
+            score: d.score,
+            distance: d.distance,
+            speed: d.speed,
+            track_id,
+            track_lifetime: d.track_lifetime,
+            track_created_sec: d.track_created_sec,
+            track_created_nanosec: d.track_created_nanosec,
+        });
+        0
+    })
 }
 
 /// Set the masks descriptor sequence (BORROWED — `masks` and every
 /// `encoding`/`mask` pointer inside it must remain valid until the next
 /// setter on the masks slot, a subsequent build/encode_into, or free).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_model_builder_set_masks(
     b: *mut ros_model_builder_t,
     masks: *const ros_mask_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if masks.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.masks = masks;
-        (*b).0.masks_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if masks.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.masks = masks;
+            (*b).0.masks_count = count;
+            (*b).0.masks_store.clear();
+        }
+        0
+    })
 }
 
+/// Append a copy of `mask` to the masks sequence, mask bytes included.
+/// Unlike `ros_model_builder_set_masks` nothing is borrowed: `mask` and
+/// its data may be freed or reused right away. Discards an array passed
+/// to `set_masks`.
 #[no_mangle]
-pub extern "C" fn ros_model_builder_build(
+pub extern "C" fn ros_model_builder_add_mask(
     b: *mut ros_model_builder_t,
-    out_bytes: *mut *mut u8,
-    out_len: *mut usize,
+    mask: *const ros_mask_builder_t,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let boxes = match unsafe { detect_box_descs_to_views(inner.boxes, inner.boxes_count) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let masks = match unsafe { mask_descs_to_views(inner.masks, inner.masks_count) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let r = edgefirst_msgs::Model::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .input_time(Duration {
-            sec: inner.input_sec,
-            nanosec: inner.input_nanosec,
-        })
-        .model_time(Duration {
-            sec: inner.model_sec,
-            nanosec: inner.model_nanosec,
-        })
-        .output_time(Duration {
-            sec: inner.output_sec,
-            nanosec: inner.output_nanosec,
-        })
-        .decode_time(Duration {
-            sec: inner.decode_sec,
-            nanosec: inner.decode_nanosec,
-        })
-        .boxes(&boxes)
-        .masks(&masks)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) || bad_handle(mask) {
+            return -1;
         }
-    }
+        let inner = unsafe { &mut (*b).0 };
+        let m = unsafe { &(*mask).0 };
+        let data: &[u8] = if m.mask.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(m.mask, m.mask_len) }
+        };
+        let encoding = inner.masks_store.keep_str(&m.encoding);
+        let data_ptr = inner.masks_store.keep_slice(data);
+        (inner.masks, inner.masks_count) = inner.masks_store.push(ros_mask_elem_t {
+            height: m.height,
+            width: m.width,
+            length: m.length,
+            encoding,
+            mask: data_ptr,
+            mask_len: data.len(),
+            boxed: m.boxed,
+        });
+        0
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn ros_model_builder_encode_into(
+pub extern "C" fn ros_model_builder_build(
     b: *mut ros_model_builder_t,
-    buf: *mut u8,
-    cap: usize,
+    out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let boxes = match unsafe { detect_box_descs_to_views(inner.boxes, inner.boxes_count) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let masks = match unsafe { mask_descs_to_views(inner.masks, inner.masks_count) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::Model::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .input_time(Duration {
-            sec: inner.input_sec,
-            nanosec: inner.input_nanosec,
-        })
-        .model_time(Duration {
-            sec: inner.model_sec,
-            nanosec: inner.model_nanosec,
-        })
-        .output_time(Duration {
-            sec: inner.output_sec,
-            nanosec: inner.output_nanosec,
-        })
-        .decode_time(Duration {
-            sec: inner.decode_sec,
-            nanosec: inner.decode_nanosec,
-        })
-        .boxes(&boxes)
-        .masks(&masks)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
+        let inner = unsafe { &(*b).0 };
+        let boxes = match unsafe { detect_box_descs_to_views(inner.boxes, inner.boxes_count) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        let masks = match unsafe { mask_descs_to_views(inner.masks, inner.masks_count) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        let r = edgefirst_msgs::Model::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .input_time(Duration {
+                sec: inner.input_sec,
+                nanosec: inner.input_nanosec,
+            })
+            .model_time(Duration {
+                sec: inner.model_sec,
+                nanosec: inner.model_nanosec,
+            })
+            .output_time(Duration {
+                sec: inner.output_sec,
+                nanosec: inner.output_nanosec,
+            })
+            .decode_time(Duration {
+                sec: inner.decode_sec,
+                nanosec: inner.decode_nanosec,
+            })
+            .boxes(&boxes)
+            .masks(&masks)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ros_model_builder_encode_into(
+    b: *mut ros_model_builder_t,
+    buf: *mut u8,
+    cap: usize,
+    out_len: *mut usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let boxes = match unsafe { detect_box_descs_to_views(inner.boxes, inner.boxes_count) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        let masks = match unsafe { mask_descs_to_views(inner.masks, inner.masks_count) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::Model::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .input_time(Duration {
+                    sec: inner.input_sec,
+                    nanosec: inner.input_nanosec,
+                })
+                .model_time(Duration {
+                    sec: inner.model_sec,
+                    nanosec: inner.model_nanosec,
+                })
+                .output_time(Duration {
+                    sec: inner.output_sec,
+                    nanosec: inner.output_nanosec,
+                })
+                .decode_time(Duration {
+                    sec: inner.decode_sec,
+                    nanosec: inner.decode_nanosec,
+                })
+                .boxes(&boxes)
+                .masks(&masks)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::ModelInfo ───────────────────────────────────────
@@ -10785,31 +14999,35 @@ pub struct ros_model_info_builder_t(ModelInfoBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_builder_new() -> *mut ros_model_info_builder_t {
-    Box::into_raw(Box::new(ros_model_info_builder_t(ModelInfoBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        input_shape: ptr::null(),
-        input_shape_len: 0,
-        input_type: 0,
-        output_shape: ptr::null(),
-        output_shape_len: 0,
-        output_type: 0,
-        labels: Vec::new(),
-        model_type: String::new(),
-        model_format: String::new(),
-        model_name: String::new(),
-    })))
+    ffi_guard(|| {
+        new_handle(ros_model_info_builder_t(ModelInfoBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            input_shape: ptr::null(),
+            input_shape_len: 0,
+            input_type: 0,
+            output_shape: ptr::null(),
+            output_shape_len: 0,
+            output_type: 0,
+            labels: Vec::new(),
+            model_type: String::new(),
+            model_format: String::new(),
+            model_name: String::new(),
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_builder_free(b: *mut ros_model_info_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -10818,12 +15036,14 @@ pub extern "C" fn ros_model_info_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -10831,18 +15051,19 @@ pub extern "C" fn ros_model_info_builder_set_frame_id(
     b: *mut ros_model_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -10851,29 +15072,32 @@ pub extern "C" fn ros_model_info_builder_set_input_shape(
     data: *const u32,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.input_shape = data;
-        (*b).0.input_shape_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.input_shape = data;
+            (*b).0.input_shape_len = len;
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_builder_set_input_type(b: *mut ros_model_info_builder_t, v: u8) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.input_type = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.input_type = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -10882,29 +15106,32 @@ pub extern "C" fn ros_model_info_builder_set_output_shape(
     data: *const u32,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.output_shape = data;
-        (*b).0.output_shape_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.output_shape = data;
+            (*b).0.output_shape_len = len;
+        }
+        0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_model_info_builder_set_output_type(b: *mut ros_model_info_builder_t, v: u8) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.output_type = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.output_type = v;
+        }
+    })
 }
 
 /// Set labels by copying each C string into builder-owned storage. Returns 0
@@ -10916,29 +15143,33 @@ pub extern "C" fn ros_model_info_builder_set_labels(
     labels: *const *const c_char,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if count == 0 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if count == 0 {
+            unsafe {
+                (*b).0.labels.clear();
+            }
+            return 0;
+        }
+        if labels.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slc = unsafe { slice::from_raw_parts(labels, count) };
+        let mut out: Vec<String> = Vec::with_capacity(count);
+        for &p in slc {
+            let Ok(label) = (unsafe { c_to_str_checked(p) }) else {
+                return -1;
+            };
+            out.push(label.to_string());
+        }
         unsafe {
-            (*b).0.labels.clear();
+            (*b).0.labels = out;
         }
-        return 0;
-    }
-    if labels.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slc = unsafe { slice::from_raw_parts(labels, count) };
-    let mut out: Vec<String> = Vec::with_capacity(count);
-    for &p in slc {
-        out.push(unsafe { c_to_str(p) }.to_string());
-    }
-    unsafe {
-        (*b).0.labels = out;
-    }
-    0
+        0
+    })
 }
 
 #[no_mangle]
@@ -10946,18 +15177,19 @@ pub extern "C" fn ros_model_info_builder_set_model_type(
     b: *mut ros_model_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.model_type = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.model_type = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -10965,18 +15197,19 @@ pub extern "C" fn ros_model_info_builder_set_model_format(
     b: *mut ros_model_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.model_format = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.model_format = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -10984,18 +15217,19 @@ pub extern "C" fn ros_model_info_builder_set_model_name(
     b: *mut ros_model_info_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.model_name = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.model_name = s_str.to_string();
+        }
+        0
+    })
 }
 
 fn model_info_input_shape(inner: &ModelInfoBuilderOwned) -> &[u32] {
@@ -11019,31 +15253,32 @@ pub extern "C" fn ros_model_info_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let label_refs: Vec<&str> = inner.labels.iter().map(String::as_str).collect();
-    let r = edgefirst_msgs::ModelInfo::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .input_shape(model_info_input_shape(inner))
-        .input_type(inner.input_type)
-        .output_shape(model_info_output_shape(inner))
-        .output_type(inner.output_type)
-        .labels(&label_refs)
-        .model_type(inner.model_type.as_str())
-        .model_format(inner.model_format.as_str())
-        .model_name(inner.model_name.as_str())
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let label_refs: Vec<&str> = inner.labels.iter().map(String::as_str).collect();
+        let r = edgefirst_msgs::ModelInfo::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .input_shape(model_info_input_shape(inner))
+            .input_type(inner.input_type)
+            .output_shape(model_info_output_shape(inner))
+            .output_type(inner.output_type)
+            .labels(&label_refs)
+            .model_type(inner.model_type.as_str())
+            .model_format(inner.model_format.as_str())
+            .model_name(inner.model_name.as_str())
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -11053,41 +15288,27 @@ pub extern "C" fn ros_model_info_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let label_refs: Vec<&str> = inner.labels.iter().map(String::as_str).collect();
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::ModelInfo::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .input_shape(model_info_input_shape(inner))
-        .input_type(inner.input_type)
-        .output_shape(model_info_output_shape(inner))
-        .output_type(inner.output_type)
-        .labels(&label_refs)
-        .model_type(inner.model_type.as_str())
-        .model_format(inner.model_format.as_str())
-        .model_name(inner.model_name.as_str())
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let label_refs: Vec<&str> = inner.labels.iter().map(String::as_str).collect();
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::ModelInfo::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .input_shape(model_info_input_shape(inner))
+                .input_type(inner.input_type)
+                .output_shape(model_info_output_shape(inner))
+                .output_type(inner.output_type)
+                .labels(&label_refs)
+                .model_type(inner.model_type.as_str())
+                .model_format(inner.model_format.as_str())
+                .model_name(inner.model_name.as_str())
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── edgefirst_msgs::Vibration ───────────────────────────────────────
@@ -11111,30 +15332,34 @@ pub struct ros_vibration_builder_t(VibrationBuilderOwned);
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_builder_new() -> *mut ros_vibration_builder_t {
-    Box::into_raw(Box::new(ros_vibration_builder_t(VibrationBuilderOwned {
-        stamp_sec: 0,
-        stamp_nanosec: 0,
-        frame_id: String::new(),
-        vib_x: 0.0,
-        vib_y: 0.0,
-        vib_z: 0.0,
-        band_lower_hz: 0.0,
-        band_upper_hz: 0.0,
-        measurement_type: 0,
-        unit: 0,
-        clipping: ptr::null(),
-        clipping_len: 0,
-    })))
+    ffi_guard(|| {
+        new_handle(ros_vibration_builder_t(VibrationBuilderOwned {
+            stamp_sec: 0,
+            stamp_nanosec: 0,
+            frame_id: String::new(),
+            vib_x: 0.0,
+            vib_y: 0.0,
+            vib_z: 0.0,
+            band_lower_hz: 0.0,
+            band_upper_hz: 0.0,
+            measurement_type: 0,
+            unit: 0,
+            clipping: ptr::null(),
+            clipping_len: 0,
+        }))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_builder_free(b: *mut ros_vibration_builder_t) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -11143,12 +15368,14 @@ pub extern "C" fn ros_vibration_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -11156,18 +15383,19 @@ pub extern "C" fn ros_vibration_builder_set_frame_id(
     b: *mut ros_vibration_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -11177,33 +15405,39 @@ pub extern "C" fn ros_vibration_builder_set_vibration(
     y: f64,
     z: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.vib_x = x;
-    inner.vib_y = y;
-    inner.vib_z = z;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.vib_x = x;
+        inner.vib_y = y;
+        inner.vib_z = z;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_builder_set_band_lower_hz(b: *mut ros_vibration_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.band_lower_hz = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.band_lower_hz = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_builder_set_band_upper_hz(b: *mut ros_vibration_builder_t, v: f32) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.band_upper_hz = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.band_upper_hz = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -11211,43 +15445,48 @@ pub extern "C" fn ros_vibration_builder_set_measurement_type(
     b: *mut ros_vibration_builder_t,
     v: u8,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.measurement_type = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.measurement_type = v;
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_vibration_builder_set_unit(b: *mut ros_vibration_builder_t, v: u8) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.unit = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.unit = v;
+        }
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn ros_vibration_builder_set_clipping(
-    b: *mut ros_vibration_builder_t,
-    data: *const u32,
-    len: usize,
-) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.clipping = data;
-        (*b).0.clipping_len = len;
-    }
-    0
+#[no_mangle]
+pub extern "C" fn ros_vibration_builder_set_clipping(
+    b: *mut ros_vibration_builder_t,
+    data: *const u32,
+    len: usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.clipping = data;
+            (*b).0.clipping_len = len;
+        }
+        0
+    })
 }
 
 fn vibration_clipping_slice(inner: &VibrationBuilderOwned) -> &[u32] {
@@ -11264,32 +15503,33 @@ pub extern "C" fn ros_vibration_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = edgefirst_msgs::Vibration::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .vibration(crate::geometry_msgs::Vector3 {
-            x: inner.vib_x,
-            y: inner.vib_y,
-            z: inner.vib_z,
-        })
-        .band_lower_hz(inner.band_lower_hz)
-        .band_upper_hz(inner.band_upper_hz)
-        .measurement_type(inner.measurement_type)
-        .unit(inner.unit)
-        .clipping(vibration_clipping_slice(inner))
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = edgefirst_msgs::Vibration::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .vibration(crate::geometry_msgs::Vector3 {
+                x: inner.vib_x,
+                y: inner.vib_y,
+                z: inner.vib_z,
+            })
+            .band_lower_hz(inner.band_lower_hz)
+            .band_upper_hz(inner.band_upper_hz)
+            .measurement_type(inner.measurement_type)
+            .unit(inner.unit)
+            .clipping(vibration_clipping_slice(inner))
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -11299,42 +15539,28 @@ pub extern "C" fn ros_vibration_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = edgefirst_msgs::Vibration::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .vibration(crate::geometry_msgs::Vector3 {
-            x: inner.vib_x,
-            y: inner.vib_y,
-            z: inner.vib_z,
-        })
-        .band_lower_hz(inner.band_lower_hz)
-        .band_upper_hz(inner.band_upper_hz)
-        .measurement_type(inner.measurement_type)
-        .unit(inner.unit)
-        .clipping(vibration_clipping_slice(inner))
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            edgefirst_msgs::Vibration::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .vibration(crate::geometry_msgs::Vector3 {
+                    x: inner.vib_x,
+                    y: inner.vib_y,
+                    z: inner.vib_z,
+                })
+                .band_lower_hz(inner.band_lower_hz)
+                .band_upper_hz(inner.band_upper_hz)
+                .measurement_type(inner.measurement_type)
+                .unit(inner.unit)
+                .clipping(vibration_clipping_slice(inner))
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── foxglove_msgs::FoxgloveCompressedVideo ──────────────────────────
@@ -11345,6 +15571,7 @@ struct FoxgloveCompressedVideoBuilderOwned {
     frame_id: String,
     data: *const u8,
     data_len: usize,
+    data_owner: Option<AdoptedData>,
     format: String,
 }
 
@@ -11353,28 +15580,33 @@ pub struct ros_foxglove_compressed_video_builder_t(FoxgloveCompressedVideoBuilde
 #[no_mangle]
 pub extern "C" fn ros_foxglove_compressed_video_builder_new(
 ) -> *mut ros_foxglove_compressed_video_builder_t {
-    Box::into_raw(Box::new(ros_foxglove_compressed_video_builder_t(
-        FoxgloveCompressedVideoBuilderOwned {
-            stamp_sec: 0,
-            stamp_nanosec: 0,
-            frame_id: String::new(),
-            data: ptr::null(),
-            data_len: 0,
-            format: String::new(),
-        },
-    )))
+    ffi_guard(|| {
+        new_handle(ros_foxglove_compressed_video_builder_t(
+            FoxgloveCompressedVideoBuilderOwned {
+                stamp_sec: 0,
+                stamp_nanosec: 0,
+                frame_id: String::new(),
+                data: ptr::null(),
+                data_len: 0,
+                data_owner: None,
+                format: String::new(),
+            },
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_foxglove_compressed_video_builder_free(
     b: *mut ros_foxglove_compressed_video_builder_t,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -11383,12 +15615,14 @@ pub extern "C" fn ros_foxglove_compressed_video_builder_set_stamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.stamp_sec = sec;
-    inner.stamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.stamp_sec = sec;
+        inner.stamp_nanosec = nanosec;
+    })
 }
 
 /// Alias for `ros_foxglove_compressed_video_builder_set_stamp`; matches the Foxglove schema field name.
@@ -11398,7 +15632,7 @@ pub unsafe extern "C" fn ros_foxglove_compressed_video_builder_set_timestamp(
     sec: i32,
     nanosec: u32,
 ) {
-    ros_foxglove_compressed_video_builder_set_stamp(b, sec, nanosec)
+    ffi_guard(|| ros_foxglove_compressed_video_builder_set_stamp(b, sec, nanosec))
 }
 
 #[no_mangle]
@@ -11406,18 +15640,19 @@ pub extern "C" fn ros_foxglove_compressed_video_builder_set_frame_id(
     b: *mut ros_foxglove_compressed_video_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.frame_id = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.frame_id = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -11426,19 +15661,54 @@ pub extern "C" fn ros_foxglove_compressed_video_builder_set_data(
     data: *const u8,
     len: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if data.is_null() && len > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.data = data;
-        (*b).0.data_len = len;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.data = data;
+            (*b).0.data_len = len;
+            (*b).0.data_owner = None;
+        }
+        0
+    })
+}
+
+/// Like `ros_foxglove_compressed_video_builder_set_data`, but the builder takes
+/// ownership of `data` and calls `free_fn(ctx)` once it no longer needs it: on
+/// the next data setter or on `ros_foxglove_compressed_video_builder_free`.
+/// Only `encode_parts` leaves the bytes in place. On error ownership stays
+/// with the caller.
+#[no_mangle]
+pub extern "C" fn ros_foxglove_compressed_video_builder_set_data_owned(
+    b: *mut ros_foxglove_compressed_video_builder_t,
+    data: *const u8,
+    len: usize,
+    free_fn: Option<unsafe extern "C" fn(*mut std::ffi::c_void)>,
+    ctx: *mut std::ffi::c_void,
+) -> i32 {
+    ffi_guard(|| {
+        let Some(release) = free_fn else {
+            set_errno(EINVAL);
+            return -1;
+        };
+        if bad_handle(b) {
+            return -1;
+        }
+        if data.is_null() && len > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.data = data;
+        inner.data_len = len;
+        inner.data_owner = Some(AdoptedData { release, ctx });
+        0
+    })
 }
 
 #[no_mangle]
@@ -11446,18 +15716,19 @@ pub extern "C" fn ros_foxglove_compressed_video_builder_set_format(
     b: *mut ros_foxglove_compressed_video_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.format = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.format = s_str.to_string();
+        }
+        0
+    })
 }
 
 fn foxglove_compressed_video_data_slice(inner: &FoxgloveCompressedVideoBuilderOwned) -> &[u8] {
@@ -11474,24 +15745,25 @@ pub extern "C" fn ros_foxglove_compressed_video_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = foxglove_msgs::FoxgloveCompressedVideo::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .data(foxglove_compressed_video_data_slice(inner))
-        .format(inner.format.as_str())
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = foxglove_msgs::FoxgloveCompressedVideo::builder()
+            .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+            .frame_id(inner.frame_id.as_str())
+            .data(foxglove_compressed_video_data_slice(inner))
+            .format(inner.format.as_str())
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -11501,34 +15773,63 @@ pub extern "C" fn ros_foxglove_compressed_video_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = foxglove_msgs::FoxgloveCompressedVideo::builder()
-        .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
-        .frame_id(inner.frame_id.as_str())
-        .data(foxglove_compressed_video_data_slice(inner))
-        .format(inner.format.as_str())
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            foxglove_msgs::FoxgloveCompressedVideo::builder()
+                .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                .frame_id(inner.frame_id.as_str())
+                .data(foxglove_compressed_video_data_slice(inner))
+                .format(inner.format.as_str())
+                .encode_into_slice(dst)
+        })
+    })
+}
+
+/// See `ros_image_builder_encode_parts`.
+#[no_mangle]
+pub extern "C" fn ros_foxglove_compressed_video_builder_encode_parts(
+    b: *mut ros_foxglove_compressed_video_builder_t,
+    head: *mut u8,
+    head_cap: usize,
+    head_len: *mut usize,
+    tail: *mut u8,
+    tail_cap: usize,
+    tail_len: *mut usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let len = foxglove_compressed_video_data_slice(inner).len();
+        encode_parts_into_caller_bufs(
+            len,
+            head,
+            head_cap,
+            head_len,
+            tail,
+            tail_cap,
+            tail_len,
+            |pad| {
+                let mut cdr = Vec::new();
+                foxglove_msgs::FoxgloveCompressedVideo::builder()
+                    .stamp(Time::new(inner.stamp_sec, inner.stamp_nanosec))
+                    .frame_id(inner.frame_id.as_str())
+                    .data(pad)
+                    .format(inner.format.as_str())
+                    .encode_into_vec(&mut cdr)?;
+                let at = offset_in(
+                    &cdr,
+                    foxglove_msgs::FoxgloveCompressedVideo::from_cdr(&cdr[..])?.data(),
+                );
+                Ok((cdr, at))
+            },
+        )
+    })
 }
 
 // ── foxglove_msgs::FoxgloveTextAnnotation ───────────────────────────
@@ -11555,36 +15856,40 @@ pub struct ros_foxglove_text_annotation_builder_t(FoxgloveTextAnnotationBuilderO
 #[no_mangle]
 pub extern "C" fn ros_foxglove_text_annotation_builder_new(
 ) -> *mut ros_foxglove_text_annotation_builder_t {
-    Box::into_raw(Box::new(ros_foxglove_text_annotation_builder_t(
-        FoxgloveTextAnnotationBuilderOwned {
-            timestamp_sec: 0,
-            timestamp_nanosec: 0,
-            pos_x: 0.0,
-            pos_y: 0.0,
-            text: String::new(),
-            font_size: 0.0,
-            text_color_r: 0.0,
-            text_color_g: 0.0,
-            text_color_b: 0.0,
-            text_color_a: 0.0,
-            bg_color_r: 0.0,
-            bg_color_g: 0.0,
-            bg_color_b: 0.0,
-            bg_color_a: 0.0,
-        },
-    )))
+    ffi_guard(|| {
+        new_handle(ros_foxglove_text_annotation_builder_t(
+            FoxgloveTextAnnotationBuilderOwned {
+                timestamp_sec: 0,
+                timestamp_nanosec: 0,
+                pos_x: 0.0,
+                pos_y: 0.0,
+                text: String::new(),
+                font_size: 0.0,
+                text_color_r: 0.0,
+                text_color_g: 0.0,
+                text_color_b: 0.0,
+                text_color_a: 0.0,
+                bg_color_r: 0.0,
+                bg_color_g: 0.0,
+                bg_color_b: 0.0,
+                bg_color_a: 0.0,
+            },
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_foxglove_text_annotation_builder_free(
     b: *mut ros_foxglove_text_annotation_builder_t,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -11593,12 +15898,14 @@ pub extern "C" fn ros_foxglove_text_annotation_builder_set_timestamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.timestamp_sec = sec;
-    inner.timestamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.timestamp_sec = sec;
+        inner.timestamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -11607,12 +15914,14 @@ pub extern "C" fn ros_foxglove_text_annotation_builder_set_position(
     x: f64,
     y: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.pos_x = x;
-    inner.pos_y = y;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.pos_x = x;
+        inner.pos_y = y;
+    })
 }
 
 #[no_mangle]
@@ -11620,18 +15929,19 @@ pub extern "C" fn ros_foxglove_text_annotation_builder_set_text(
     b: *mut ros_foxglove_text_annotation_builder_t,
     s: *const c_char,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let s_str = match unsafe { c_to_str_checked(s) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    unsafe {
-        (*b).0.text = s_str.to_string();
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let s_str = match unsafe { c_to_str_checked(s) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        unsafe {
+            (*b).0.text = s_str.to_string();
+        }
+        0
+    })
 }
 
 #[no_mangle]
@@ -11639,12 +15949,14 @@ pub extern "C" fn ros_foxglove_text_annotation_builder_set_font_size(
     b: *mut ros_foxglove_text_annotation_builder_t,
     v: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.font_size = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.font_size = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -11655,14 +15967,16 @@ pub extern "C" fn ros_foxglove_text_annotation_builder_set_text_color(
     bc: f64,
     a: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.text_color_r = r;
-    inner.text_color_g = g;
-    inner.text_color_b = bc;
-    inner.text_color_a = a;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.text_color_r = r;
+        inner.text_color_g = g;
+        inner.text_color_b = bc;
+        inner.text_color_a = a;
+    })
 }
 
 #[no_mangle]
@@ -11673,14 +15987,16 @@ pub extern "C" fn ros_foxglove_text_annotation_builder_set_background_color(
     bc: f64,
     a: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.bg_color_r = r;
-    inner.bg_color_g = g;
-    inner.bg_color_b = bc;
-    inner.bg_color_a = a;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.bg_color_r = r;
+        inner.bg_color_g = g;
+        inner.bg_color_b = bc;
+        inner.bg_color_a = a;
+    })
 }
 
 #[no_mangle]
@@ -11689,39 +16005,40 @@ pub extern "C" fn ros_foxglove_text_annotation_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let r = foxglove_msgs::FoxgloveTextAnnotation::builder()
-        .timestamp(Time::new(inner.timestamp_sec, inner.timestamp_nanosec))
-        .position(foxglove_msgs::FoxglovePoint2 {
-            x: inner.pos_x,
-            y: inner.pos_y,
-        })
-        .text(inner.text.as_str())
-        .font_size(inner.font_size)
-        .text_color(foxglove_msgs::FoxgloveColor {
-            r: inner.text_color_r,
-            g: inner.text_color_g,
-            b: inner.text_color_b,
-            a: inner.text_color_a,
-        })
-        .background_color(foxglove_msgs::FoxgloveColor {
-            r: inner.bg_color_r,
-            g: inner.bg_color_g,
-            b: inner.bg_color_b,
-            a: inner.bg_color_a,
-        })
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let r = foxglove_msgs::FoxgloveTextAnnotation::builder()
+            .timestamp(Time::new(inner.timestamp_sec, inner.timestamp_nanosec))
+            .position(foxglove_msgs::FoxglovePoint2 {
+                x: inner.pos_x,
+                y: inner.pos_y,
+            })
+            .text(inner.text.as_str())
+            .font_size(inner.font_size)
+            .text_color(foxglove_msgs::FoxgloveColor {
+                r: inner.text_color_r,
+                g: inner.text_color_g,
+                b: inner.text_color_b,
+                a: inner.text_color_a,
+            })
+            .background_color(foxglove_msgs::FoxgloveColor {
+                r: inner.bg_color_r,
+                g: inner.bg_color_g,
+                b: inner.bg_color_b,
+                a: inner.bg_color_a,
+            })
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -11731,49 +16048,35 @@ pub extern "C" fn ros_foxglove_text_annotation_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = foxglove_msgs::FoxgloveTextAnnotation::builder()
-        .timestamp(Time::new(inner.timestamp_sec, inner.timestamp_nanosec))
-        .position(foxglove_msgs::FoxglovePoint2 {
-            x: inner.pos_x,
-            y: inner.pos_y,
-        })
-        .text(inner.text.as_str())
-        .font_size(inner.font_size)
-        .text_color(foxglove_msgs::FoxgloveColor {
-            r: inner.text_color_r,
-            g: inner.text_color_g,
-            b: inner.text_color_b,
-            a: inner.text_color_a,
-        })
-        .background_color(foxglove_msgs::FoxgloveColor {
-            r: inner.bg_color_r,
-            g: inner.bg_color_g,
-            b: inner.bg_color_b,
-            a: inner.bg_color_a,
-        })
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            foxglove_msgs::FoxgloveTextAnnotation::builder()
+                .timestamp(Time::new(inner.timestamp_sec, inner.timestamp_nanosec))
+                .position(foxglove_msgs::FoxglovePoint2 {
+                    x: inner.pos_x,
+                    y: inner.pos_y,
+                })
+                .text(inner.text.as_str())
+                .font_size(inner.font_size)
+                .text_color(foxglove_msgs::FoxgloveColor {
+                    r: inner.text_color_r,
+                    g: inner.text_color_g,
+                    b: inner.text_color_b,
+                    a: inner.text_color_a,
+                })
+                .background_color(foxglove_msgs::FoxgloveColor {
+                    r: inner.bg_color_r,
+                    g: inner.bg_color_g,
+                    b: inner.bg_color_b,
+                    a: inner.bg_color_a,
+                })
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── foxglove_msgs::FoxglovePointAnnotation ──────────────────────────
@@ -11839,12 +16142,14 @@ struct FoxglovePointAnnotationBuilderOwned {
     type_: u8,
     points: *const ros_foxglove_point2_elem_t,
     points_count: usize,
+    points_store: ElemStore<ros_foxglove_point2_elem_t>,
     outline_color_r: f64,
     outline_color_g: f64,
     outline_color_b: f64,
     outline_color_a: f64,
     outline_colors: *const ros_foxglove_color_elem_t,
     outline_colors_count: usize,
+    outline_colors_store: ElemStore<ros_foxglove_color_elem_t>,
     fill_color_r: f64,
     fill_color_g: f64,
     fill_color_b: f64,
@@ -11857,38 +16162,44 @@ pub struct ros_foxglove_point_annotation_builder_t(FoxglovePointAnnotationBuilde
 #[no_mangle]
 pub extern "C" fn ros_foxglove_point_annotation_builder_new(
 ) -> *mut ros_foxglove_point_annotation_builder_t {
-    Box::into_raw(Box::new(ros_foxglove_point_annotation_builder_t(
-        FoxglovePointAnnotationBuilderOwned {
-            timestamp_sec: 0,
-            timestamp_nanosec: 0,
-            type_: 0,
-            points: ptr::null(),
-            points_count: 0,
-            outline_color_r: 0.0,
-            outline_color_g: 0.0,
-            outline_color_b: 0.0,
-            outline_color_a: 0.0,
-            outline_colors: ptr::null(),
-            outline_colors_count: 0,
-            fill_color_r: 0.0,
-            fill_color_g: 0.0,
-            fill_color_b: 0.0,
-            fill_color_a: 0.0,
-            thickness: 0.0,
-        },
-    )))
+    ffi_guard(|| {
+        new_handle(ros_foxglove_point_annotation_builder_t(
+            FoxglovePointAnnotationBuilderOwned {
+                timestamp_sec: 0,
+                timestamp_nanosec: 0,
+                type_: 0,
+                points: ptr::null(),
+                points_count: 0,
+                points_store: ElemStore::new(),
+                outline_color_r: 0.0,
+                outline_color_g: 0.0,
+                outline_color_b: 0.0,
+                outline_color_a: 0.0,
+                outline_colors: ptr::null(),
+                outline_colors_count: 0,
+                outline_colors_store: ElemStore::new(),
+                fill_color_r: 0.0,
+                fill_color_g: 0.0,
+                fill_color_b: 0.0,
+                fill_color_a: 0.0,
+                thickness: 0.0,
+            },
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_foxglove_point_annotation_builder_free(
     b: *mut ros_foxglove_point_annotation_builder_t,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 #[no_mangle]
@@ -11897,12 +16208,14 @@ pub extern "C" fn ros_foxglove_point_annotation_builder_set_timestamp(
     sec: i32,
     nanosec: u32,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.timestamp_sec = sec;
-    inner.timestamp_nanosec = nanosec;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.timestamp_sec = sec;
+        inner.timestamp_nanosec = nanosec;
+    })
 }
 
 #[no_mangle]
@@ -11910,34 +16223,58 @@ pub extern "C" fn ros_foxglove_point_annotation_builder_set_type(
     b: *mut ros_foxglove_point_annotation_builder_t,
     v: u8,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.type_ = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.type_ = v;
+        }
+    })
 }
 
 /// Set the points descriptor sequence (BORROWED).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_foxglove_point_annotation_builder_set_points(
     b: *mut ros_foxglove_point_annotation_builder_t,
     points: *const ros_foxglove_point2_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if points.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.points = points;
-        (*b).0.points_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if points.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.points = points;
+            (*b).0.points_count = count;
+            (*b).0.points_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a point to the points sequence. Discards an array passed to
+/// `ros_foxglove_point_annotation_builder_set_points`.
+#[no_mangle]
+pub extern "C" fn ros_foxglove_point_annotation_builder_add_point(
+    b: *mut ros_foxglove_point_annotation_builder_t,
+    x: f64,
+    y: f64,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        (inner.points, inner.points_count) =
+            inner.points_store.push(ros_foxglove_point2_elem_t { x, y });
+        0
+    })
 }
 
 #[no_mangle]
@@ -11948,36 +16285,64 @@ pub extern "C" fn ros_foxglove_point_annotation_builder_set_outline_color(
     bc: f64,
     a: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.outline_color_r = r;
-    inner.outline_color_g = g;
-    inner.outline_color_b = bc;
-    inner.outline_color_a = a;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.outline_color_r = r;
+        inner.outline_color_g = g;
+        inner.outline_color_b = bc;
+        inner.outline_color_a = a;
+    })
 }
 
 /// Set the outline_colors descriptor sequence (BORROWED).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_foxglove_point_annotation_builder_set_outline_colors(
     b: *mut ros_foxglove_point_annotation_builder_t,
     colors: *const ros_foxglove_color_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if colors.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.outline_colors = colors;
-        (*b).0.outline_colors_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if colors.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.outline_colors = colors;
+            (*b).0.outline_colors_count = count;
+            (*b).0.outline_colors_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a per-point color to the outline_colors sequence (not to be
+/// confused with `set_outline_color`, the single fallback color). Discards
+/// an array passed to `ros_foxglove_point_annotation_builder_set_outline_colors`.
+#[no_mangle]
+pub extern "C" fn ros_foxglove_point_annotation_builder_add_outline_color(
+    b: *mut ros_foxglove_point_annotation_builder_t,
+    r: f64,
+    g: f64,
+    bc: f64,
+    a: f64,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        (inner.outline_colors, inner.outline_colors_count) = inner
+            .outline_colors_store
+            .push(ros_foxglove_color_elem_t { r, g, b: bc, a });
+        0
+    })
 }
 
 #[no_mangle]
@@ -11988,14 +16353,16 @@ pub extern "C" fn ros_foxglove_point_annotation_builder_set_fill_color(
     bc: f64,
     a: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    let inner = unsafe { &mut (*b).0 };
-    inner.fill_color_r = r;
-    inner.fill_color_g = g;
-    inner.fill_color_b = bc;
-    inner.fill_color_a = a;
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        inner.fill_color_r = r;
+        inner.fill_color_g = g;
+        inner.fill_color_b = bc;
+        inner.fill_color_a = a;
+    })
 }
 
 #[no_mangle]
@@ -12003,12 +16370,14 @@ pub extern "C" fn ros_foxglove_point_annotation_builder_set_thickness(
     b: *mut ros_foxglove_point_annotation_builder_t,
     v: f64,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        (*b).0.thickness = v;
-    }
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return;
+        }
+        unsafe {
+            (*b).0.thickness = v;
+        }
+    })
 }
 
 #[no_mangle]
@@ -12017,40 +16386,42 @@ pub extern "C" fn ros_foxglove_point_annotation_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let pts = unsafe { foxglove_point2_descs_to_vec(inner.points, inner.points_count) };
-    let ocs =
-        unsafe { foxglove_color_descs_to_vec(inner.outline_colors, inner.outline_colors_count) };
-    let r = foxglove_msgs::FoxglovePointAnnotation::builder()
-        .timestamp(Time::new(inner.timestamp_sec, inner.timestamp_nanosec))
-        .type_(inner.type_)
-        .points(&pts)
-        .outline_color(foxglove_msgs::FoxgloveColor {
-            r: inner.outline_color_r,
-            g: inner.outline_color_g,
-            b: inner.outline_color_b,
-            a: inner.outline_color_a,
-        })
-        .outline_colors(&ocs)
-        .fill_color(foxglove_msgs::FoxgloveColor {
-            r: inner.fill_color_r,
-            g: inner.fill_color_g,
-            b: inner.fill_color_b,
-            a: inner.fill_color_a,
-        })
-        .thickness(inner.thickness)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let pts = unsafe { foxglove_point2_descs_to_vec(inner.points, inner.points_count) };
+        let ocs = unsafe {
+            foxglove_color_descs_to_vec(inner.outline_colors, inner.outline_colors_count)
+        };
+        let r = foxglove_msgs::FoxglovePointAnnotation::builder()
+            .timestamp(Time::new(inner.timestamp_sec, inner.timestamp_nanosec))
+            .type_(inner.type_)
+            .points(&pts)
+            .outline_color(foxglove_msgs::FoxgloveColor {
+                r: inner.outline_color_r,
+                g: inner.outline_color_g,
+                b: inner.outline_color_b,
+                a: inner.outline_color_a,
+            })
+            .outline_colors(&ocs)
+            .fill_color(foxglove_msgs::FoxgloveColor {
+                r: inner.fill_color_r,
+                g: inner.fill_color_g,
+                b: inner.fill_color_b,
+                a: inner.fill_color_a,
+            })
+            .thickness(inner.thickness)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -12060,50 +16431,37 @@ pub extern "C" fn ros_foxglove_point_annotation_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let pts = unsafe { foxglove_point2_descs_to_vec(inner.points, inner.points_count) };
-    let ocs =
-        unsafe { foxglove_color_descs_to_vec(inner.outline_colors, inner.outline_colors_count) };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = foxglove_msgs::FoxglovePointAnnotation::builder()
-        .timestamp(Time::new(inner.timestamp_sec, inner.timestamp_nanosec))
-        .type_(inner.type_)
-        .points(&pts)
-        .outline_color(foxglove_msgs::FoxgloveColor {
-            r: inner.outline_color_r,
-            g: inner.outline_color_g,
-            b: inner.outline_color_b,
-            a: inner.outline_color_a,
-        })
-        .outline_colors(&ocs)
-        .fill_color(foxglove_msgs::FoxgloveColor {
-            r: inner.fill_color_r,
-            g: inner.fill_color_g,
-            b: inner.fill_color_b,
-            a: inner.fill_color_a,
-        })
-        .thickness(inner.thickness)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let pts = unsafe { foxglove_point2_descs_to_vec(inner.points, inner.points_count) };
+        let ocs = unsafe {
+            foxglove_color_descs_to_vec(inner.outline_colors, inner.outline_colors_count)
+        };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            foxglove_msgs::FoxglovePointAnnotation::builder()
+                .timestamp(Time::new(inner.timestamp_sec, inner.timestamp_nanosec))
+                .type_(inner.type_)
+                .points(&pts)
+                .outline_color(foxglove_msgs::FoxgloveColor {
+                    r: inner.outline_color_r,
+                    g: inner.outline_color_g,
+                    b: inner.outline_color_b,
+                    a: inner.outline_color_a,
+                })
+                .outline_colors(&ocs)
+                .fill_color(foxglove_msgs::FoxgloveColor {
+                    r: inner.fill_color_r,
+                    g: inner.fill_color_g,
+                    b: inner.fill_color_b,
+                    a: inner.fill_color_a,
+                })
+                .thickness(inner.thickness)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // ── foxglove_msgs::FoxgloveImageAnnotation ──────────────────────────
@@ -12282,10 +16640,13 @@ unsafe fn text_annotation_descs_to_vec(
 struct FoxgloveImageAnnotationBuilderOwned {
     circles: *const ros_foxglove_circle_annotation_elem_t,
     circles_count: usize,
+    circles_store: ElemStore<ros_foxglove_circle_annotation_elem_t>,
     points: *const ros_foxglove_point_annotation_elem_t,
     points_count: usize,
+    points_store: ElemStore<ros_foxglove_point_annotation_elem_t>,
     texts: *const ros_foxglove_text_annotation_elem_t,
     texts_count: usize,
+    texts_store: ElemStore<ros_foxglove_text_annotation_elem_t>,
 }
 
 pub struct ros_foxglove_image_annotation_builder_t(FoxgloveImageAnnotationBuilderOwned);
@@ -12293,96 +16654,250 @@ pub struct ros_foxglove_image_annotation_builder_t(FoxgloveImageAnnotationBuilde
 #[no_mangle]
 pub extern "C" fn ros_foxglove_image_annotation_builder_new(
 ) -> *mut ros_foxglove_image_annotation_builder_t {
-    Box::into_raw(Box::new(ros_foxglove_image_annotation_builder_t(
-        FoxgloveImageAnnotationBuilderOwned {
-            circles: ptr::null(),
-            circles_count: 0,
-            points: ptr::null(),
-            points_count: 0,
-            texts: ptr::null(),
-            texts_count: 0,
-        },
-    )))
+    ffi_guard(|| {
+        new_handle(ros_foxglove_image_annotation_builder_t(
+            FoxgloveImageAnnotationBuilderOwned {
+                circles: ptr::null(),
+                circles_count: 0,
+                circles_store: ElemStore::new(),
+                points: ptr::null(),
+                points_count: 0,
+                points_store: ElemStore::new(),
+                texts: ptr::null(),
+                texts_count: 0,
+                texts_store: ElemStore::new(),
+            },
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn ros_foxglove_image_annotation_builder_free(
     b: *mut ros_foxglove_image_annotation_builder_t,
 ) {
-    if b.is_null() {
-        return;
-    }
-    unsafe {
-        drop(Box::from_raw(b));
-    }
+    ffi_guard(|| {
+        if b.is_null() {
+            return;
+        }
+        unsafe {
+            free_handle(b);
+        }
+    })
 }
 
 /// Set the circles descriptor sequence (BORROWED).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_foxglove_image_annotation_builder_set_circles(
     b: *mut ros_foxglove_image_annotation_builder_t,
     circles: *const ros_foxglove_circle_annotation_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if circles.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.circles = circles;
-        (*b).0.circles_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if circles.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.circles = circles;
+            (*b).0.circles_count = count;
+            (*b).0.circles_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a circle to the circles sequence. Discards an array passed to
+/// `ros_foxglove_image_annotation_builder_set_circles`.
+#[no_mangle]
+pub extern "C" fn ros_foxglove_image_annotation_builder_add_circle_annotation(
+    b: *mut ros_foxglove_image_annotation_builder_t,
+    timestamp_sec: i32,
+    timestamp_nanosec: u32,
+    position_x: f64,
+    position_y: f64,
+    diameter: f64,
+    thickness: f64,
+    fill_color_r: f64,
+    fill_color_g: f64,
+    fill_color_b: f64,
+    fill_color_a: f64,
+    outline_color_r: f64,
+    outline_color_g: f64,
+    outline_color_b: f64,
+    outline_color_a: f64,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        (inner.circles, inner.circles_count) =
+            inner
+                .circles_store
+                .push(ros_foxglove_circle_annotation_elem_t {
+                    timestamp_sec,
+                    timestamp_nanosec,
+                    position_x,
+                    position_y,
+                    diameter,
+                    thickness,
+                    fill_color_r,
+                    fill_color_g,
+                    fill_color_b,
+                    fill_color_a,
+                    outline_color_r,
+                    outline_color_g,
+                    outline_color_b,
+                    outline_color_a,
+                });
+        0
+    })
 }
 
 /// Set the points descriptor sequence (BORROWED — including each
 /// element's inner `points`/`outline_colors` arrays).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_foxglove_image_annotation_builder_set_points(
     b: *mut ros_foxglove_image_annotation_builder_t,
     points: *const ros_foxglove_point_annotation_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if points.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.points = points;
-        (*b).0.points_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if points.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.points = points;
+            (*b).0.points_count = count;
+            (*b).0.points_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a copy of `annotation` to the points sequence, its points and
+/// outline colors included. Unlike
+/// `ros_foxglove_image_annotation_builder_set_points` nothing is borrowed:
+/// `annotation` and its arrays may be freed or reused right away. Discards
+/// an array passed to `set_points`.
+#[no_mangle]
+pub extern "C" fn ros_foxglove_image_annotation_builder_add_point_annotation(
+    b: *mut ros_foxglove_image_annotation_builder_t,
+    annotation: *const ros_foxglove_point_annotation_builder_t,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) || bad_handle(annotation) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        let p = unsafe { &(*annotation).0 };
+        let points: &[ros_foxglove_point2_elem_t] = if p.points.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(p.points, p.points_count) }
+        };
+        let colors: &[ros_foxglove_color_elem_t] = if p.outline_colors.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(p.outline_colors, p.outline_colors_count) }
+        };
+        let points_ptr = inner.points_store.keep_slice(points);
+        let colors_ptr = inner.points_store.keep_slice(colors);
+        (inner.points, inner.points_count) =
+            inner
+                .points_store
+                .push(ros_foxglove_point_annotation_elem_t {
+                    timestamp_sec: p.timestamp_sec,
+                    timestamp_nanosec: p.timestamp_nanosec,
+                    type_: p.type_,
+                    points: points_ptr,
+                    points_count: points.len(),
+                    outline_color_r: p.outline_color_r,
+                    outline_color_g: p.outline_color_g,
+                    outline_color_b: p.outline_color_b,
+                    outline_color_a: p.outline_color_a,
+                    outline_colors: colors_ptr,
+                    outline_colors_count: colors.len(),
+                    fill_color_r: p.fill_color_r,
+                    fill_color_g: p.fill_color_g,
+                    fill_color_b: p.fill_color_b,
+                    fill_color_a: p.fill_color_a,
+                    thickness: p.thickness,
+                });
+        0
+    })
 }
 
 /// Set the texts descriptor sequence (BORROWED — including each
 /// element's `text` C string).
+#[cfg(not(feature = "ffi-opaque"))]
 #[no_mangle]
 pub extern "C" fn ros_foxglove_image_annotation_builder_set_texts(
     b: *mut ros_foxglove_image_annotation_builder_t,
     texts: *const ros_foxglove_text_annotation_elem_t,
     count: usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if texts.is_null() && count > 0 {
-        set_errno(EINVAL);
-        return -1;
-    }
-    unsafe {
-        (*b).0.texts = texts;
-        (*b).0.texts_count = count;
-    }
-    0
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
+        }
+        if texts.is_null() && count > 0 {
+            set_errno(EINVAL);
+            return -1;
+        }
+        unsafe {
+            (*b).0.texts = texts;
+            (*b).0.texts_count = count;
+            (*b).0.texts_store.clear();
+        }
+        0
+    })
+}
+
+/// Append a copy of `annotation` to the texts sequence. Unlike
+/// `ros_foxglove_image_annotation_builder_set_texts` nothing is borrowed:
+/// `annotation` may be freed or reused right away. Discards an array
+/// passed to `set_texts`.
+#[no_mangle]
+pub extern "C" fn ros_foxglove_image_annotation_builder_add_text_annotation(
+    b: *mut ros_foxglove_image_annotation_builder_t,
+    annotation: *const ros_foxglove_text_annotation_builder_t,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(b) || bad_handle(annotation) {
+            return -1;
+        }
+        let inner = unsafe { &mut (*b).0 };
+        let t = unsafe { &(*annotation).0 };
+        let text = inner.texts_store.keep_str(&t.text);
+        (inner.texts, inner.texts_count) =
+            inner.texts_store.push(ros_foxglove_text_annotation_elem_t {
+                timestamp_sec: t.timestamp_sec,
+                timestamp_nanosec: t.timestamp_nanosec,
+                position_x: t.pos_x,
+                position_y: t.pos_y,
+                text,
+                font_size: t.font_size,
+                text_color_r: t.text_color_r,
+                text_color_g: t.text_color_g,
+                text_color_b: t.text_color_b,
+                text_color_a: t.text_color_a,
+                background_color_r: t.bg_color_r,
+                background_color_g: t.bg_color_g,
+                background_color_b: t.bg_color_b,
+                background_color_a: t.bg_color_a,
+            });
+        0
+    })
 }
 
 #[no_mangle]
@@ -12391,29 +16906,30 @@ pub extern "C" fn ros_foxglove_image_annotation_builder_build(
     out_bytes: *mut *mut u8,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let circles = unsafe { circle_descs_to_vec(inner.circles, inner.circles_count) };
-    let points = unsafe { point_annotation_descs_to_vec(inner.points, inner.points_count) };
-    let texts = match unsafe { text_annotation_descs_to_vec(inner.texts, inner.texts_count) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let r = foxglove_msgs::FoxgloveImageAnnotation::builder()
-        .circles(&circles)
-        .points(&points)
-        .texts(&texts)
-        .build();
-    match r {
-        Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let circles = unsafe { circle_descs_to_vec(inner.circles, inner.circles_count) };
+        let points = unsafe { point_annotation_descs_to_vec(inner.points, inner.points_count) };
+        let texts = match unsafe { text_annotation_descs_to_vec(inner.texts, inner.texts_count) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        let r = foxglove_msgs::FoxgloveImageAnnotation::builder()
+            .circles(&circles)
+            .points(&points)
+            .texts(&texts)
+            .build();
+        match r {
+            Ok(v) => return_cdr_bytes(v.into_cdr(), out_bytes, out_len),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 #[no_mangle]
@@ -12423,39 +16939,25 @@ pub extern "C" fn ros_foxglove_image_annotation_builder_encode_into(
     cap: usize,
     out_len: *mut usize,
 ) -> i32 {
-    if b.is_null() || buf.is_null() || out_len.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let inner = unsafe { &(*b).0 };
-    let circles = unsafe { circle_descs_to_vec(inner.circles, inner.circles_count) };
-    let points = unsafe { point_annotation_descs_to_vec(inner.points, inner.points_count) };
-    let texts = match unsafe { text_annotation_descs_to_vec(inner.texts, inner.texts_count) } {
-        Ok(v) => v,
-        Err(_) => return -1,
-    };
-    let dst = unsafe { slice::from_raw_parts_mut(buf, cap) };
-    let r = foxglove_msgs::FoxgloveImageAnnotation::builder()
-        .circles(&circles)
-        .points(&points)
-        .texts(&texts)
-        .encode_into_slice(dst);
-    match r {
-        Ok(n) => {
-            unsafe {
-                *out_len = n;
-            }
-            0
-        }
-        Err(crate::cdr::CdrError::BufferTooShort { .. }) => {
-            set_errno(ENOBUFS);
-            -1
-        }
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if bad_handle(b) {
+            return -1;
         }
-    }
+        let inner = unsafe { &(*b).0 };
+        let circles = unsafe { circle_descs_to_vec(inner.circles, inner.circles_count) };
+        let points = unsafe { point_annotation_descs_to_vec(inner.points, inner.points_count) };
+        let texts = match unsafe { text_annotation_descs_to_vec(inner.texts, inner.texts_count) } {
+            Ok(v) => v,
+            Err(_) => return -1,
+        };
+        encode_into_caller_buf(buf, cap, out_len, |dst| {
+            foxglove_msgs::FoxgloveImageAnnotation::builder()
+                .circles(&circles)
+                .points(&points)
+                .texts(&texts)
+                .encode_into_slice(dst)
+        })
+    })
 }
 
 // =============================================================================
@@ -12482,25 +16984,42 @@ pub extern "C" fn ros_foxglove_image_annotation_builder_encode_into(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_header_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: std_msgs::Header<&mut [u8]> = match std_msgs::Header::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: std_msgs::Header<&mut [u8]> = match std_msgs::Header::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
+}
+
+/// Set the stamp field of a CDR-encoded Header buffer in place to the
+/// current time of `clock` (see [`ros_time_set_now`]).
+///
+/// Returns 0 on success, -1 on error (errno: EINVAL for NULL buf or an
+/// unknown clock, EBADMSG if buf is not a valid encoded Header).
+#[no_mangle]
+pub extern "C" fn ros_header_set_stamp_now(buf: *mut u8, len: usize, clock: i32) -> i32 {
+    ffi_guard(|| {
+        let Some(t) = clock_now(clock) else {
+            return -1;
+        };
+        ros_header_set_stamp(buf, len, t.sec, t.nanosec)
+    })
 }
 
 /// Set the stamp field in place on an existing CDR-encoded Image buffer.
@@ -12509,25 +17028,27 @@ pub extern "C" fn ros_header_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec:
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_image_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the height field in place on an Image buffer.
@@ -12536,25 +17057,27 @@ pub extern "C" fn ros_image_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec:
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_image_set_height(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_height(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_height(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the width field in place on an Image buffer.
@@ -12563,25 +17086,27 @@ pub extern "C" fn ros_image_set_height(buf: *mut u8, len: usize, v: u32) -> i32
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_image_set_width(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_width(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_width(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the is_bigendian field in place on an Image buffer.
@@ -12590,25 +17115,27 @@ pub extern "C" fn ros_image_set_width(buf: *mut u8, len: usize, v: u32) -> i32 {
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_image_set_is_bigendian(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_is_bigendian(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_is_bigendian(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the step (row stride) field in place on an Image buffer.
@@ -12617,25 +17144,27 @@ pub extern "C" fn ros_image_set_is_bigendian(buf: *mut u8, len: usize, v: u8) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_image_set_step(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_step(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Image<&mut [u8]> = match sensor_msgs::Image::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_step(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a CompressedImage buffer.
@@ -12649,26 +17178,28 @@ pub extern "C" fn ros_compressed_image_set_stamp(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CompressedImage<&mut [u8]> =
-        match sensor_msgs::CompressedImage::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CompressedImage<&mut [u8]> =
+            match sensor_msgs::CompressedImage::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on an Imu buffer.
@@ -12677,25 +17208,27 @@ pub extern "C" fn ros_compressed_image_set_stamp(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_imu_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the orientation (Quaternion) field in place on an Imu buffer.
@@ -12711,25 +17244,27 @@ pub extern "C" fn ros_imu_set_orientation(
     z: f64,
     w: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_orientation(Quaternion { x, y, z, w }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_orientation(Quaternion { x, y, z, w }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the 3x3 orientation covariance (row-major, 9 f64 elements) in place.
@@ -12742,33 +17277,35 @@ pub extern "C" fn ros_imu_set_orientation_covariance(
     len: usize,
     c: *const f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if c.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut arr = [0.0f64; 9];
-    unsafe {
-        ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_orientation_covariance(arr) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        if c.is_null() {
+            set_errno(EINVAL);
+            return -1;
         }
-    }
+        let mut arr = [0.0f64; 9];
+        unsafe {
+            ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_orientation_covariance(arr) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 /// Set the angular_velocity (Vector3) field in place on an Imu buffer.
@@ -12783,25 +17320,27 @@ pub extern "C" fn ros_imu_set_angular_velocity(
     y: f64,
     z: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_angular_velocity(Vector3 { x, y, z }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_angular_velocity(Vector3 { x, y, z }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the 3x3 angular velocity covariance (row-major, 9 f64 elements) in place.
@@ -12814,33 +17353,35 @@ pub extern "C" fn ros_imu_set_angular_velocity_covariance(
     len: usize,
     c: *const f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if c.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut arr = [0.0f64; 9];
-    unsafe {
-        ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_angular_velocity_covariance(arr) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        if c.is_null() {
+            set_errno(EINVAL);
+            return -1;
         }
-    }
+        let mut arr = [0.0f64; 9];
+        unsafe {
+            ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_angular_velocity_covariance(arr) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 /// Set the linear_acceleration (Vector3) field in place on an Imu buffer.
@@ -12855,25 +17396,27 @@ pub extern "C" fn ros_imu_set_linear_acceleration(
     y: f64,
     z: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_linear_acceleration(Vector3 { x, y, z }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_linear_acceleration(Vector3 { x, y, z }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the 3x3 linear acceleration covariance (row-major, 9 f64 elements) in place.
@@ -12886,33 +17429,35 @@ pub extern "C" fn ros_imu_set_linear_acceleration_covariance(
     len: usize,
     c: *const f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if c.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut arr = [0.0f64; 9];
-    unsafe {
-        ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_linear_acceleration_covariance(arr) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        if c.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let mut arr = [0.0f64; 9];
+        unsafe {
+            ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
         }
-    }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Imu<&mut [u8]> = match sensor_msgs::Imu::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_linear_acceleration_covariance(arr) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 /// Set the stamp field in place on a NavSatFix buffer.
@@ -12921,25 +17466,28 @@ pub extern "C" fn ros_imu_set_linear_acceleration_covariance(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the NavSatStatus in place on a NavSatFix buffer.
@@ -12953,25 +17501,28 @@ pub extern "C" fn ros_nav_sat_fix_set_status(
     status: i8,
     service: u16,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_status(NavSatStatus { status, service }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_status(NavSatStatus { status, service }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the latitude field in place.
@@ -12980,25 +17531,28 @@ pub extern "C" fn ros_nav_sat_fix_set_status(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_set_latitude(buf: *mut u8, len: usize, v: f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_latitude(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_latitude(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the longitude field in place.
@@ -13007,25 +17561,28 @@ pub extern "C" fn ros_nav_sat_fix_set_latitude(buf: *mut u8, len: usize, v: f64)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_set_longitude(buf: *mut u8, len: usize, v: f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_longitude(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_longitude(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the altitude field in place.
@@ -13034,25 +17591,28 @@ pub extern "C" fn ros_nav_sat_fix_set_longitude(buf: *mut u8, len: usize, v: f64
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_nav_sat_fix_set_altitude(buf: *mut u8, len: usize, v: f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_altitude(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_altitude(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the 3x3 position covariance (row-major, 9 f64 elements) in place.
@@ -13065,33 +17625,36 @@ pub extern "C" fn ros_nav_sat_fix_set_position_covariance(
     len: usize,
     c: *const f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if c.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut arr = [0.0f64; 9];
-    unsafe {
-        ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_position_covariance(arr) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        if c.is_null() {
+            set_errno(EINVAL);
+            return -1;
         }
-    }
+        let mut arr = [0.0f64; 9];
+        unsafe {
+            ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_position_covariance(arr) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 /// Set the position_covariance_type in place on a NavSatFix buffer.
@@ -13104,25 +17667,28 @@ pub extern "C" fn ros_nav_sat_fix_set_position_covariance_type(
     len: usize,
     v: u8,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_position_covariance_type(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::NavSatFix<&mut [u8]> = match sensor_msgs::NavSatFix::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_position_covariance_type(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the offset field in place on a PointField buffer.
@@ -13131,25 +17697,28 @@ pub extern "C" fn ros_nav_sat_fix_set_position_covariance_type(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_field_set_offset(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointField<&mut [u8]> = match sensor_msgs::PointField::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_offset(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointField<&mut [u8]> =
+            match sensor_msgs::PointField::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_offset(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the datatype field in place on a PointField buffer.
@@ -13158,25 +17727,28 @@ pub extern "C" fn ros_point_field_set_offset(buf: *mut u8, len: usize, v: u32) -
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_field_set_datatype(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointField<&mut [u8]> = match sensor_msgs::PointField::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_datatype(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointField<&mut [u8]> =
+            match sensor_msgs::PointField::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_datatype(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the count field in place on a PointField buffer.
@@ -13185,25 +17757,28 @@ pub extern "C" fn ros_point_field_set_datatype(buf: *mut u8, len: usize, v: u8)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_field_set_count(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointField<&mut [u8]> = match sensor_msgs::PointField::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_count(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointField<&mut [u8]> =
+            match sensor_msgs::PointField::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_count(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a PointCloud2 buffer.
@@ -13212,26 +17787,28 @@ pub extern "C" fn ros_point_field_set_count(buf: *mut u8, len: usize, v: u32) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointCloud2<&mut [u8]> = match sensor_msgs::PointCloud2::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointCloud2<&mut [u8]> =
+            match sensor_msgs::PointCloud2::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the height field in place on a PointCloud2 buffer.
@@ -13240,26 +17817,28 @@ pub extern "C" fn ros_point_cloud2_set_stamp(buf: *mut u8, len: usize, sec: i32,
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_set_height(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointCloud2<&mut [u8]> = match sensor_msgs::PointCloud2::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_height(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointCloud2<&mut [u8]> =
+            match sensor_msgs::PointCloud2::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_height(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the width field in place on a PointCloud2 buffer.
@@ -13268,54 +17847,58 @@ pub extern "C" fn ros_point_cloud2_set_height(buf: *mut u8, len: usize, v: u32)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_set_width(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointCloud2<&mut [u8]> = match sensor_msgs::PointCloud2::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_width(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointCloud2<&mut [u8]> =
+            match sensor_msgs::PointCloud2::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_width(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the is_bigendian field in place on a PointCloud2 buffer (0=false, nonzero=true).
-///
-/// Returns 0 on success, -1 on error (errno: EINVAL for NULL buf,
-/// EBADMSG if buf is not a valid encoded message of this type).
-#[no_mangle]
-pub extern "C" fn ros_point_cloud2_set_is_bigendian(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointCloud2<&mut [u8]> = match sensor_msgs::PointCloud2::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+///
+/// Returns 0 on success, -1 on error (errno: EINVAL for NULL buf,
+/// EBADMSG if buf is not a valid encoded message of this type).
+#[no_mangle]
+pub extern "C" fn ros_point_cloud2_set_is_bigendian(buf: *mut u8, len: usize, v: u8) -> i32 {
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_is_bigendian(v != 0) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointCloud2<&mut [u8]> =
+            match sensor_msgs::PointCloud2::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_is_bigendian(v != 0) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the point_step field in place on a PointCloud2 buffer.
@@ -13324,26 +17907,28 @@ pub extern "C" fn ros_point_cloud2_set_is_bigendian(buf: *mut u8, len: usize, v:
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_set_point_step(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointCloud2<&mut [u8]> = match sensor_msgs::PointCloud2::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_point_step(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointCloud2<&mut [u8]> =
+            match sensor_msgs::PointCloud2::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_point_step(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the row_step field in place on a PointCloud2 buffer.
@@ -13352,26 +17937,28 @@ pub extern "C" fn ros_point_cloud2_set_point_step(buf: *mut u8, len: usize, v: u
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_set_row_step(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointCloud2<&mut [u8]> = match sensor_msgs::PointCloud2::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_row_step(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointCloud2<&mut [u8]> =
+            match sensor_msgs::PointCloud2::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_row_step(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the is_dense field in place on a PointCloud2 buffer (0=false, nonzero=true).
@@ -13380,26 +17967,28 @@ pub extern "C" fn ros_point_cloud2_set_row_step(buf: *mut u8, len: usize, v: u32
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_point_cloud2_set_is_dense(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::PointCloud2<&mut [u8]> = match sensor_msgs::PointCloud2::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_is_dense(v != 0) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::PointCloud2<&mut [u8]> =
+            match sensor_msgs::PointCloud2::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_is_dense(v != 0) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a CameraInfo buffer.
@@ -13408,25 +17997,28 @@ pub extern "C" fn ros_point_cloud2_set_is_dense(buf: *mut u8, len: usize, v: u8)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_info_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the height field in place on a CameraInfo buffer.
@@ -13435,25 +18027,28 @@ pub extern "C" fn ros_camera_info_set_stamp(buf: *mut u8, len: usize, sec: i32,
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_info_set_height(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_height(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_height(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the width field in place on a CameraInfo buffer.
@@ -13462,25 +18057,28 @@ pub extern "C" fn ros_camera_info_set_height(buf: *mut u8, len: usize, v: u32) -
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_info_set_width(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_width(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_width(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the 3x3 intrinsic matrix K (row-major, 9 f64 elements) in place.
@@ -13489,33 +18087,36 @@ pub extern "C" fn ros_camera_info_set_width(buf: *mut u8, len: usize, v: u32) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_info_set_k(buf: *mut u8, len: usize, k: *const f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if k.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut arr = [0.0f64; 9];
-    unsafe {
-        ptr::copy_nonoverlapping(k, arr.as_mut_ptr(), 9);
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_k(arr) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        if k.is_null() {
+            set_errno(EINVAL);
+            return -1;
         }
-    }
+        let mut arr = [0.0f64; 9];
+        unsafe {
+            ptr::copy_nonoverlapping(k, arr.as_mut_ptr(), 9);
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_k(arr) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 /// Set the 3x3 rectification matrix R (row-major, 9 f64 elements) in place.
@@ -13524,33 +18125,36 @@ pub extern "C" fn ros_camera_info_set_k(buf: *mut u8, len: usize, k: *const f64)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_info_set_r(buf: *mut u8, len: usize, r: *const f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if r.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut arr = [0.0f64; 9];
-    unsafe {
-        ptr::copy_nonoverlapping(r, arr.as_mut_ptr(), 9);
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_r(arr) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        if r.is_null() {
+            set_errno(EINVAL);
+            return -1;
         }
-    }
+        let mut arr = [0.0f64; 9];
+        unsafe {
+            ptr::copy_nonoverlapping(r, arr.as_mut_ptr(), 9);
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_r(arr) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 /// Set the 3x4 projection matrix P (row-major, 12 f64 elements) in place.
@@ -13559,33 +18163,36 @@ pub extern "C" fn ros_camera_info_set_r(buf: *mut u8, len: usize, r: *const f64)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_info_set_p(buf: *mut u8, len: usize, p: *const f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if p.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut arr = [0.0f64; 12];
-    unsafe {
-        ptr::copy_nonoverlapping(p, arr.as_mut_ptr(), 12);
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_p(arr) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        if p.is_null() {
+            set_errno(EINVAL);
+            return -1;
         }
-    }
+        let mut arr = [0.0f64; 12];
+        unsafe {
+            ptr::copy_nonoverlapping(p, arr.as_mut_ptr(), 12);
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_p(arr) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
+        }
+    })
 }
 
 /// Set the binning_x field in place on a CameraInfo buffer.
@@ -13594,25 +18201,28 @@ pub extern "C" fn ros_camera_info_set_p(buf: *mut u8, len: usize, p: *const f64)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_info_set_binning_x(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_binning_x(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_binning_x(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the binning_y field in place on a CameraInfo buffer.
@@ -13621,25 +18231,28 @@ pub extern "C" fn ros_camera_info_set_binning_x(buf: *mut u8, len: usize, v: u32
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_info_set_binning_y(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_binning_y(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_binning_y(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the RegionOfInterest (roi) field in place on a CameraInfo buffer.
@@ -13656,31 +18269,34 @@ pub extern "C" fn ros_camera_info_set_roi(
     width: u32,
     do_rectify: u8,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::CameraInfo<&mut [u8]> = match sensor_msgs::CameraInfo::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_roi(RegionOfInterest {
-        x_offset,
-        y_offset,
-        height,
-        width,
-        do_rectify: do_rectify != 0,
-    }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::CameraInfo<&mut [u8]> =
+            match sensor_msgs::CameraInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_roi(RegionOfInterest {
+            x_offset,
+            y_offset,
+            height,
+            width,
+            do_rectify: do_rectify != 0,
+        }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a MagneticField buffer.
@@ -13689,31 +18305,33 @@ pub extern "C" fn ros_camera_info_set_roi(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_magnetic_field_set_stamp(
-    buf: *mut u8,
-    len: usize,
-    sec: i32,
-    nsec: u32,
-) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::MagneticField<&mut [u8]> =
-        match sensor_msgs::MagneticField::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    buf: *mut u8,
+    len: usize,
+    sec: i32,
+    nsec: u32,
+) -> i32 {
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::MagneticField<&mut [u8]> =
+            match sensor_msgs::MagneticField::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the magnetic_field (Vector3) in place on a MagneticField buffer.
@@ -13728,26 +18346,28 @@ pub extern "C" fn ros_magnetic_field_set_magnetic_field(
     y: f64,
     z: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::MagneticField<&mut [u8]> =
-        match sensor_msgs::MagneticField::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::MagneticField<&mut [u8]> =
+            match sensor_msgs::MagneticField::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_magnetic_field(Vector3 { x, y, z }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_magnetic_field(Vector3 { x, y, z }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the 3x3 magnetic field covariance (row-major, 9 f64 elements) in place.
@@ -13760,34 +18380,36 @@ pub extern "C" fn ros_magnetic_field_set_magnetic_field_covariance(
     len: usize,
     c: *const f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    if c.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let mut arr = [0.0f64; 9];
-    unsafe {
-        ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::MagneticField<&mut [u8]> =
-        match sensor_msgs::MagneticField::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        if c.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let mut arr = [0.0f64; 9];
+        unsafe {
+            ptr::copy_nonoverlapping(c, arr.as_mut_ptr(), 9);
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::MagneticField<&mut [u8]> =
+            match sensor_msgs::MagneticField::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_magnetic_field_covariance(arr) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_magnetic_field_covariance(arr) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a FluidPressure buffer.
@@ -13801,26 +18423,28 @@ pub extern "C" fn ros_fluid_pressure_set_stamp(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::FluidPressure<&mut [u8]> =
-        match sensor_msgs::FluidPressure::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::FluidPressure<&mut [u8]> =
+            match sensor_msgs::FluidPressure::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the fluid_pressure field in place.
@@ -13829,26 +18453,28 @@ pub extern "C" fn ros_fluid_pressure_set_stamp(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_set_fluid_pressure(buf: *mut u8, len: usize, v: f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::FluidPressure<&mut [u8]> =
-        match sensor_msgs::FluidPressure::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::FluidPressure<&mut [u8]> =
+            match sensor_msgs::FluidPressure::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_fluid_pressure(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_fluid_pressure(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the variance field in place.
@@ -13857,26 +18483,28 @@ pub extern "C" fn ros_fluid_pressure_set_fluid_pressure(buf: *mut u8, len: usize
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_fluid_pressure_set_variance(buf: *mut u8, len: usize, v: f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::FluidPressure<&mut [u8]> =
-        match sensor_msgs::FluidPressure::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::FluidPressure<&mut [u8]> =
+            match sensor_msgs::FluidPressure::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_variance(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_variance(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a Temperature buffer.
@@ -13885,26 +18513,28 @@ pub extern "C" fn ros_fluid_pressure_set_variance(buf: *mut u8, len: usize, v: f
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_temperature_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Temperature<&mut [u8]> = match sensor_msgs::Temperature::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Temperature<&mut [u8]> =
+            match sensor_msgs::Temperature::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the temperature field in place.
@@ -13913,26 +18543,28 @@ pub extern "C" fn ros_temperature_set_stamp(buf: *mut u8, len: usize, sec: i32,
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_temperature_set_temperature(buf: *mut u8, len: usize, v: f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Temperature<&mut [u8]> = match sensor_msgs::Temperature::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_temperature(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Temperature<&mut [u8]> =
+            match sensor_msgs::Temperature::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_temperature(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the variance field in place.
@@ -13941,26 +18573,28 @@ pub extern "C" fn ros_temperature_set_temperature(buf: *mut u8, len: usize, v: f
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_temperature_set_variance(buf: *mut u8, len: usize, v: f64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::Temperature<&mut [u8]> = match sensor_msgs::Temperature::from_cdr(slice)
-    {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_variance(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::Temperature<&mut [u8]> =
+            match sensor_msgs::Temperature::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_variance(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a BatteryState buffer.
@@ -13974,26 +18608,28 @@ pub extern "C" fn ros_battery_state_set_stamp(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the voltage field in place.
@@ -14002,26 +18638,28 @@ pub extern "C" fn ros_battery_state_set_stamp(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_battery_state_set_voltage(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_voltage(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_voltage(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the temperature field in place.
@@ -14030,26 +18668,28 @@ pub extern "C" fn ros_battery_state_set_voltage(buf: *mut u8, len: usize, v: f32
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_battery_state_set_temperature(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_temperature(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_temperature(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the current field in place.
@@ -14058,26 +18698,28 @@ pub extern "C" fn ros_battery_state_set_temperature(buf: *mut u8, len: usize, v:
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_battery_state_set_current(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_current(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_current(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the charge field in place.
@@ -14086,26 +18728,28 @@ pub extern "C" fn ros_battery_state_set_current(buf: *mut u8, len: usize, v: f32
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_battery_state_set_charge(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_charge(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_charge(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the capacity field in place.
@@ -14114,26 +18758,28 @@ pub extern "C" fn ros_battery_state_set_charge(buf: *mut u8, len: usize, v: f32)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_battery_state_set_capacity(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_capacity(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_capacity(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the design_capacity field in place.
@@ -14142,26 +18788,28 @@ pub extern "C" fn ros_battery_state_set_capacity(buf: *mut u8, len: usize, v: f3
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_battery_state_set_design_capacity(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_design_capacity(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_design_capacity(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the percentage field in place.
@@ -14170,26 +18818,28 @@ pub extern "C" fn ros_battery_state_set_design_capacity(buf: *mut u8, len: usize
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_battery_state_set_percentage(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_percentage(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_percentage(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the power_supply_status field in place.
@@ -14202,26 +18852,28 @@ pub extern "C" fn ros_battery_state_set_power_supply_status(
     len: usize,
     v: u8,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_power_supply_status(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_power_supply_status(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the power_supply_health field in place.
@@ -14234,26 +18886,28 @@ pub extern "C" fn ros_battery_state_set_power_supply_health(
     len: usize,
     v: u8,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_power_supply_health(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_power_supply_health(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the power_supply_technology field in place.
@@ -14266,26 +18920,28 @@ pub extern "C" fn ros_battery_state_set_power_supply_technology(
     len: usize,
     v: u8,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_power_supply_technology(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_power_supply_technology(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the present field in place (0=false, nonzero=true).
@@ -14294,26 +18950,28 @@ pub extern "C" fn ros_battery_state_set_power_supply_technology(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_battery_state_set_present(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: sensor_msgs::BatteryState<&mut [u8]> =
-        match sensor_msgs::BatteryState::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: sensor_msgs::BatteryState<&mut [u8]> =
+            match sensor_msgs::BatteryState::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_present(v != 0) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_present(v != 0) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the height field in place on a Mask buffer.
@@ -14322,25 +18980,27 @@ pub extern "C" fn ros_battery_state_set_present(buf: *mut u8, len: usize, v: u8)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_mask_set_height(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Mask<&mut [u8]> = match edgefirst_msgs::Mask::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_height(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Mask<&mut [u8]> = match edgefirst_msgs::Mask::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_height(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the width field in place on a Mask buffer.
@@ -14349,25 +19009,27 @@ pub extern "C" fn ros_mask_set_height(buf: *mut u8, len: usize, v: u32) -> i32 {
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_mask_set_width(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Mask<&mut [u8]> = match edgefirst_msgs::Mask::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_width(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Mask<&mut [u8]> = match edgefirst_msgs::Mask::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_width(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the length field in place on a Mask buffer.
@@ -14376,25 +19038,27 @@ pub extern "C" fn ros_mask_set_width(buf: *mut u8, len: usize, v: u32) -> i32 {
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_mask_set_length(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Mask<&mut [u8]> = match edgefirst_msgs::Mask::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_length(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Mask<&mut [u8]> = match edgefirst_msgs::Mask::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_length(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the boxed field in place (0=false, nonzero=true).
@@ -14403,25 +19067,27 @@ pub extern "C" fn ros_mask_set_length(buf: *mut u8, len: usize, v: u32) -> i32 {
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_mask_set_boxed(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Mask<&mut [u8]> = match edgefirst_msgs::Mask::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_boxed(v != 0) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Mask<&mut [u8]> = match edgefirst_msgs::Mask::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_boxed(v != 0) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a LocalTime buffer.
@@ -14430,26 +19096,28 @@ pub extern "C" fn ros_mask_set_boxed(buf: *mut u8, len: usize, v: u8) -> i32 {
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_local_time_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::LocalTime<&mut [u8]> =
-        match edgefirst_msgs::LocalTime::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::LocalTime<&mut [u8]> =
+            match edgefirst_msgs::LocalTime::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the Date field in place on a LocalTime buffer.
@@ -14464,26 +19132,28 @@ pub extern "C" fn ros_local_time_set_date(
     month: u8,
     day: u8,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::LocalTime<&mut [u8]> =
-        match edgefirst_msgs::LocalTime::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::LocalTime<&mut [u8]> =
+            match edgefirst_msgs::LocalTime::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_date(edgefirst_msgs::Date { year, month, day }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_date(edgefirst_msgs::Date { year, month, day }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the time (Time) field in place on a LocalTime buffer.
@@ -14492,26 +19162,28 @@ pub extern "C" fn ros_local_time_set_date(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_local_time_set_time(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::LocalTime<&mut [u8]> =
-        match edgefirst_msgs::LocalTime::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::LocalTime<&mut [u8]> =
+            match edgefirst_msgs::LocalTime::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_time(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_time(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the timezone field in place on a LocalTime buffer.
@@ -14520,26 +19192,28 @@ pub extern "C" fn ros_local_time_set_time(buf: *mut u8, len: usize, sec: i32, ns
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_local_time_set_timezone(buf: *mut u8, len: usize, v: i16) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::LocalTime<&mut [u8]> =
-        match edgefirst_msgs::LocalTime::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::LocalTime<&mut [u8]> =
+            match edgefirst_msgs::LocalTime::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_timezone(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_timezone(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a RadarCube buffer.
@@ -14548,26 +19222,28 @@ pub extern "C" fn ros_local_time_set_timezone(buf: *mut u8, len: usize, v: i16)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::RadarCube<&mut [u8]> =
-        match edgefirst_msgs::RadarCube::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::RadarCube<&mut [u8]> =
+            match edgefirst_msgs::RadarCube::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the timestamp (u64 ns) field in place on a RadarCube buffer.
@@ -14576,26 +19252,28 @@ pub extern "C" fn ros_radar_cube_set_stamp(buf: *mut u8, len: usize, sec: i32, n
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_set_timestamp(buf: *mut u8, len: usize, v: u64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::RadarCube<&mut [u8]> =
-        match edgefirst_msgs::RadarCube::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::RadarCube<&mut [u8]> =
+            match edgefirst_msgs::RadarCube::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_timestamp(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_timestamp(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the is_complex field in place (0=false, nonzero=true).
@@ -14604,26 +19282,28 @@ pub extern "C" fn ros_radar_cube_set_timestamp(buf: *mut u8, len: usize, v: u64)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_radar_cube_set_is_complex(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::RadarCube<&mut [u8]> =
-        match edgefirst_msgs::RadarCube::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::RadarCube<&mut [u8]> =
+            match edgefirst_msgs::RadarCube::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_is_complex(v != 0) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_is_complex(v != 0) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a RadarInfo buffer.
@@ -14632,26 +19312,28 @@ pub extern "C" fn ros_radar_cube_set_is_complex(buf: *mut u8, len: usize, v: u8)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_radar_info_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::RadarInfo<&mut [u8]> =
-        match edgefirst_msgs::RadarInfo::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::RadarInfo<&mut [u8]> =
+            match edgefirst_msgs::RadarInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the cube field in place on a RadarInfo buffer (0=false, nonzero=true).
@@ -14660,26 +19342,28 @@ pub extern "C" fn ros_radar_info_set_stamp(buf: *mut u8, len: usize, sec: i32, n
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_radar_info_set_cube(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::RadarInfo<&mut [u8]> =
-        match edgefirst_msgs::RadarInfo::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::RadarInfo<&mut [u8]> =
+            match edgefirst_msgs::RadarInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_cube(v != 0) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_cube(v != 0) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the lifetime field in place on a Track buffer.
@@ -14688,25 +19372,27 @@ pub extern "C" fn ros_radar_info_set_cube(buf: *mut u8, len: usize, v: u8) -> i3
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_track_set_lifetime(buf: *mut u8, len: usize, v: i32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Track<&mut [u8]> = match edgefirst_msgs::Track::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_lifetime(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Track<&mut [u8]> = match edgefirst_msgs::Track::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_lifetime(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the created (Time) field in place on a Track buffer.
@@ -14715,25 +19401,27 @@ pub extern "C" fn ros_track_set_lifetime(buf: *mut u8, len: usize, v: i32) -> i3
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_track_set_created(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Track<&mut [u8]> = match edgefirst_msgs::Track::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_created(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Track<&mut [u8]> = match edgefirst_msgs::Track::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_created(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the center_x field in place on a DetectBox buffer.
@@ -14742,26 +19430,28 @@ pub extern "C" fn ros_track_set_created(buf: *mut u8, len: usize, sec: i32, nsec
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_box_set_center_x(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_center_x(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_center_x(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the center_y field in place on a DetectBox buffer.
@@ -14770,26 +19460,28 @@ pub extern "C" fn ros_detect_box_set_center_x(buf: *mut u8, len: usize, v: f32)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_box_set_center_y(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_center_y(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_center_y(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the width field in place on a DetectBox buffer.
@@ -14798,26 +19490,28 @@ pub extern "C" fn ros_detect_box_set_center_y(buf: *mut u8, len: usize, v: f32)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_box_set_width(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_width(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_width(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the height field in place on a DetectBox buffer.
@@ -14826,26 +19520,28 @@ pub extern "C" fn ros_detect_box_set_width(buf: *mut u8, len: usize, v: f32) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_box_set_height(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_height(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_height(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the score field in place on a DetectBox buffer.
@@ -14854,26 +19550,28 @@ pub extern "C" fn ros_detect_box_set_height(buf: *mut u8, len: usize, v: f32) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_box_set_score(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_score(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_score(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the distance field in place on a DetectBox buffer.
@@ -14882,26 +19580,28 @@ pub extern "C" fn ros_detect_box_set_score(buf: *mut u8, len: usize, v: f32) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_box_set_distance(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_distance(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_distance(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the speed field in place on a DetectBox buffer.
@@ -14910,26 +19610,28 @@ pub extern "C" fn ros_detect_box_set_distance(buf: *mut u8, len: usize, v: f32)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_box_set_speed(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_speed(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_speed(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the track_lifetime field in place on a DetectBox buffer.
@@ -14938,26 +19640,28 @@ pub extern "C" fn ros_detect_box_set_speed(buf: *mut u8, len: usize, v: f32) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_box_set_track_lifetime(buf: *mut u8, len: usize, v: i32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_track_lifetime(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_track_lifetime(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the track_created (Time) field in place on a DetectBox buffer.
@@ -14971,26 +19675,28 @@ pub extern "C" fn ros_detect_box_set_track_created(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
-        match edgefirst_msgs::DetectBox::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::DetectBox<&mut [u8]> =
+            match edgefirst_msgs::DetectBox::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_track_created(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_track_created(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp (Time) field in place on a Detect buffer.
@@ -14999,25 +19705,28 @@ pub extern "C" fn ros_detect_box_set_track_created(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Detect<&mut [u8]> = match edgefirst_msgs::Detect::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Detect<&mut [u8]> = match edgefirst_msgs::Detect::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the input_timestamp (Time) field in place on a Detect buffer.
@@ -15031,25 +19740,28 @@ pub extern "C" fn ros_detect_set_input_timestamp(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Detect<&mut [u8]> = match edgefirst_msgs::Detect::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_input_timestamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Detect<&mut [u8]> = match edgefirst_msgs::Detect::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_input_timestamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the model_time (Time) field in place on a Detect buffer.
@@ -15058,25 +19770,28 @@ pub extern "C" fn ros_detect_set_input_timestamp(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_set_model_time(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Detect<&mut [u8]> = match edgefirst_msgs::Detect::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_model_time(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Detect<&mut [u8]> = match edgefirst_msgs::Detect::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_model_time(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the output_time (Time) field in place on a Detect buffer.
@@ -15085,25 +19800,28 @@ pub extern "C" fn ros_detect_set_model_time(buf: *mut u8, len: usize, sec: i32,
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_detect_set_output_time(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Detect<&mut [u8]> = match edgefirst_msgs::Detect::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_output_time(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Detect<&mut [u8]> = match edgefirst_msgs::Detect::from_cdr(slice)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_output_time(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a CameraFrame buffer.
@@ -15112,26 +19830,28 @@ pub extern "C" fn ros_detect_set_output_time(buf: *mut u8, len: usize, sec: i32,
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
-        match edgefirst_msgs::CameraFrame::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
+            match edgefirst_msgs::CameraFrame::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the seq field in place on a CameraFrame buffer.
@@ -15140,26 +19860,28 @@ pub extern "C" fn ros_camera_frame_set_stamp(buf: *mut u8, len: usize, sec: i32,
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_set_seq(buf: *mut u8, len: usize, v: u64) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
-        match edgefirst_msgs::CameraFrame::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
+            match edgefirst_msgs::CameraFrame::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_seq(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_seq(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the pid field in place on a CameraFrame buffer.
@@ -15168,26 +19890,28 @@ pub extern "C" fn ros_camera_frame_set_seq(buf: *mut u8, len: usize, v: u64) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_set_pid(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
-        match edgefirst_msgs::CameraFrame::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
+            match edgefirst_msgs::CameraFrame::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_pid(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_pid(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the width field in place on a CameraFrame buffer.
@@ -15196,26 +19920,28 @@ pub extern "C" fn ros_camera_frame_set_pid(buf: *mut u8, len: usize, v: u32) ->
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_set_width(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
-        match edgefirst_msgs::CameraFrame::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
+            match edgefirst_msgs::CameraFrame::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_width(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_width(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the height field in place on a CameraFrame buffer.
@@ -15224,26 +19950,28 @@ pub extern "C" fn ros_camera_frame_set_width(buf: *mut u8, len: usize, v: u32) -
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_set_height(buf: *mut u8, len: usize, v: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
-        match edgefirst_msgs::CameraFrame::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
+            match edgefirst_msgs::CameraFrame::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_height(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_height(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the fence_fd field in place on a CameraFrame buffer.
@@ -15252,26 +19980,28 @@ pub extern "C" fn ros_camera_frame_set_height(buf: *mut u8, len: usize, v: u32)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_camera_frame_set_fence_fd(buf: *mut u8, len: usize, v: i32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
-        match edgefirst_msgs::CameraFrame::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::CameraFrame<&mut [u8]> =
+            match edgefirst_msgs::CameraFrame::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_fence_fd(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_fence_fd(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp (Time) field in place on a Model buffer.
@@ -15280,25 +20010,27 @@ pub extern "C" fn ros_camera_frame_set_fence_fd(buf: *mut u8, len: usize, v: i32
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_model_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the input_time (Duration) field in place on a Model buffer.
@@ -15307,25 +20039,27 @@ pub extern "C" fn ros_model_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec:
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_model_set_input_time(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_input_time(Duration::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_input_time(Duration::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the model_time (Duration) field in place on a Model buffer.
@@ -15334,25 +20068,27 @@ pub extern "C" fn ros_model_set_input_time(buf: *mut u8, len: usize, sec: i32, n
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_model_set_model_time(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_model_time(Duration::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_model_time(Duration::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the output_time (Duration) field in place on a Model buffer.
@@ -15361,25 +20097,27 @@ pub extern "C" fn ros_model_set_model_time(buf: *mut u8, len: usize, sec: i32, n
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_model_set_output_time(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_output_time(Duration::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_output_time(Duration::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the decode_time (Duration) field in place on a Model buffer.
@@ -15388,25 +20126,27 @@ pub extern "C" fn ros_model_set_output_time(buf: *mut u8, len: usize, sec: i32,
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_model_set_decode_time(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
-        Ok(v) => v,
-        Err(_) => {
-            set_errno(EBADMSG);
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
             return -1;
         }
-    };
-    match m.set_decode_time(Duration::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Model<&mut [u8]> = match edgefirst_msgs::Model::from_cdr(slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        match m.set_decode_time(Duration::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a ModelInfo buffer.
@@ -15415,26 +20155,28 @@ pub extern "C" fn ros_model_set_decode_time(buf: *mut u8, len: usize, sec: i32,
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_model_info_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::ModelInfo<&mut [u8]> =
-        match edgefirst_msgs::ModelInfo::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::ModelInfo<&mut [u8]> =
+            match edgefirst_msgs::ModelInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the input_type field in place on a ModelInfo buffer.
@@ -15443,26 +20185,28 @@ pub extern "C" fn ros_model_info_set_stamp(buf: *mut u8, len: usize, sec: i32, n
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_model_info_set_input_type(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::ModelInfo<&mut [u8]> =
-        match edgefirst_msgs::ModelInfo::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::ModelInfo<&mut [u8]> =
+            match edgefirst_msgs::ModelInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_input_type(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_input_type(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the output_type field in place on a ModelInfo buffer.
@@ -15471,26 +20215,28 @@ pub extern "C" fn ros_model_info_set_input_type(buf: *mut u8, len: usize, v: u8)
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_model_info_set_output_type(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::ModelInfo<&mut [u8]> =
-        match edgefirst_msgs::ModelInfo::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::ModelInfo<&mut [u8]> =
+            match edgefirst_msgs::ModelInfo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_output_type(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_output_type(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a Vibration buffer.
@@ -15499,26 +20245,28 @@ pub extern "C" fn ros_model_info_set_output_type(buf: *mut u8, len: usize, v: u8
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_vibration_set_stamp(buf: *mut u8, len: usize, sec: i32, nsec: u32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
-        match edgefirst_msgs::Vibration::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
+            match edgefirst_msgs::Vibration::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the vibration (Vector3) field in place on a Vibration buffer.
@@ -15533,26 +20281,28 @@ pub extern "C" fn ros_vibration_set_vibration(
     y: f64,
     z: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
-        match edgefirst_msgs::Vibration::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
+            match edgefirst_msgs::Vibration::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_vibration(Vector3 { x, y, z }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_vibration(Vector3 { x, y, z }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the band_lower_hz field in place.
@@ -15561,26 +20311,28 @@ pub extern "C" fn ros_vibration_set_vibration(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_vibration_set_band_lower_hz(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
-        match edgefirst_msgs::Vibration::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
+            match edgefirst_msgs::Vibration::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_band_lower_hz(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_band_lower_hz(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the band_upper_hz field in place.
@@ -15589,26 +20341,28 @@ pub extern "C" fn ros_vibration_set_band_lower_hz(buf: *mut u8, len: usize, v: f
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_vibration_set_band_upper_hz(buf: *mut u8, len: usize, v: f32) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
-        match edgefirst_msgs::Vibration::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
+            match edgefirst_msgs::Vibration::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_band_upper_hz(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_band_upper_hz(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the measurement_type field in place on a Vibration buffer.
@@ -15617,26 +20371,28 @@ pub extern "C" fn ros_vibration_set_band_upper_hz(buf: *mut u8, len: usize, v: f
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_vibration_set_measurement_type(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
-        match edgefirst_msgs::Vibration::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
+            match edgefirst_msgs::Vibration::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_measurement_type(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_measurement_type(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the unit field in place on a Vibration buffer.
@@ -15645,26 +20401,28 @@ pub extern "C" fn ros_vibration_set_measurement_type(buf: *mut u8, len: usize, v
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_vibration_set_unit(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
-        match edgefirst_msgs::Vibration::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: edgefirst_msgs::Vibration<&mut [u8]> =
+            match edgefirst_msgs::Vibration::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_unit(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_unit(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the stamp field in place on a FoxgloveCompressedVideo buffer.
@@ -15678,26 +20436,28 @@ pub extern "C" fn ros_foxglove_compressed_video_set_stamp(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxgloveCompressedVideo<&mut [u8]> =
-        match foxglove_msgs::FoxgloveCompressedVideo::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxgloveCompressedVideo<&mut [u8]> =
+            match foxglove_msgs::FoxgloveCompressedVideo::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_stamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_stamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Alias for `ros_foxglove_compressed_video_set_stamp`; matches the Foxglove schema field name.
@@ -15711,7 +20471,7 @@ pub unsafe extern "C" fn ros_foxglove_compressed_video_set_timestamp(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    ros_foxglove_compressed_video_set_stamp(buf, len, sec, nsec)
+    ffi_guard(|| ros_foxglove_compressed_video_set_stamp(buf, len, sec, nsec))
 }
 
 /// Set the timestamp field in place on a FoxgloveTextAnnotation buffer.
@@ -15725,26 +20485,28 @@ pub extern "C" fn ros_foxglove_text_annotation_set_timestamp(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_timestamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_timestamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the position (FoxglovePoint2) field in place.
@@ -15758,26 +20520,28 @@ pub extern "C" fn ros_foxglove_text_annotation_set_position(
     x: f64,
     y: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_position(foxglove_msgs::FoxglovePoint2 { x, y }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_position(foxglove_msgs::FoxglovePoint2 { x, y }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the font_size field in place.
@@ -15790,26 +20554,28 @@ pub extern "C" fn ros_foxglove_text_annotation_set_font_size(
     len: usize,
     v: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_font_size(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_font_size(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the text_color (FoxgloveColor) field in place.
@@ -15825,26 +20591,28 @@ pub extern "C" fn ros_foxglove_text_annotation_set_text_color(
     b: f64,
     a: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
-            }
-        };
-    match m.set_text_color(foxglove_msgs::FoxgloveColor { r, g, b, a }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_text_color(foxglove_msgs::FoxgloveColor { r, g, b, a }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
+            }
         }
-    }
+    })
 }
 
 /// Set the background_color (FoxgloveColor) field in place.
@@ -15860,26 +20628,28 @@ pub extern "C" fn ros_foxglove_text_annotation_set_background_color(
     b: f64,
     a: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxgloveTextAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxgloveTextAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_background_color(foxglove_msgs::FoxgloveColor { r, g, b, a }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_background_color(foxglove_msgs::FoxgloveColor { r, g, b, a }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the timestamp field in place on a FoxglovePointAnnotation buffer.
@@ -15893,26 +20663,28 @@ pub extern "C" fn ros_foxglove_point_annotation_set_timestamp(
     sec: i32,
     nsec: u32,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_timestamp(Time::new(sec, nsec)) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_timestamp(Time::new(sec, nsec)) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the type_ field in place on a FoxglovePointAnnotation buffer.
@@ -15921,26 +20693,28 @@ pub extern "C" fn ros_foxglove_point_annotation_set_timestamp(
 /// EBADMSG if buf is not a valid encoded message of this type).
 #[no_mangle]
 pub extern "C" fn ros_foxglove_point_annotation_set_type(buf: *mut u8, len: usize, v: u8) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_type_(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_type_(v) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the outline_color (FoxgloveColor) field in place.
@@ -15956,26 +20730,28 @@ pub extern "C" fn ros_foxglove_point_annotation_set_outline_color(
     b: f64,
     a: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_outline_color(foxglove_msgs::FoxgloveColor { r, g, b, a }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_outline_color(foxglove_msgs::FoxgloveColor { r, g, b, a }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the fill_color (FoxgloveColor) field in place.
@@ -15991,26 +20767,28 @@ pub extern "C" fn ros_foxglove_point_annotation_set_fill_color(
     b: f64,
     a: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_fill_color(foxglove_msgs::FoxgloveColor { r, g, b, a }) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
-        };
-    match m.set_fill_color(foxglove_msgs::FoxgloveColor { r, g, b, a }) {
-        Ok(()) => 0,
-        Err(_) => {
-            set_errno(EBADMSG);
-            -1
         }
-    }
+    })
 }
 
 /// Set the thickness field in place on a FoxglovePointAnnotation buffer.
@@ -16023,24 +20801,442 @@ pub extern "C" fn ros_foxglove_point_annotation_set_thickness(
     len: usize,
     v: f64,
 ) -> i32 {
-    if buf.is_null() {
-        set_errno(EINVAL);
-        return -1;
-    }
-    let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
-    let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
-        match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
-            Ok(v) => v,
-            Err(_) => {
-                set_errno(EBADMSG);
-                return -1;
+    ffi_guard(|| {
+        if buf.is_null() {
+            set_errno(EINVAL);
+            return -1;
+        }
+        let slice = unsafe { slice::from_raw_parts_mut(buf, len) };
+        let mut m: foxglove_msgs::FoxglovePointAnnotation<&mut [u8]> =
+            match foxglove_msgs::FoxglovePointAnnotation::from_cdr(slice) {
+                Ok(v) => v,
+                Err(e) => {
+                    set_error(EBADMSG, e);
+                    return -1;
+                }
+            };
+        match m.set_thickness(v) {
+            Ok(()) => 0,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                -1
             }
+        }
+    })
+}
+
+// =============================================================================
+// Schema registry
+// =============================================================================
+
+/// `full_definition` texts handed out so far; entries are never removed, so
+/// the returned pointers stay valid for the life of the process.
+static DEFINITIONS: std::sync::Mutex<std::collections::BTreeMap<String, std::ffi::CString>> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+/// Full `.msg` text of `schema` (e.g. `"sensor_msgs/msg/Image"`) with the
+/// definitions of every type it nests, ready for an MCAP schema record with
+/// encoding `edgefirst_schema_encoding()`.
+///
+/// The string is owned by the library and valid until the process exits.
+/// Returns NULL with `errno = EINVAL` for a NULL or unknown schema name.
+#[no_mangle]
+pub extern "C" fn edgefirst_schema_definition(schema: *const c_char) -> *const c_char {
+    ffi_guard(|| {
+        let Ok(name) = (unsafe { c_to_str_checked(schema) }) else {
+            return ptr::null();
         };
-    match m.set_thickness(v) {
-        Ok(()) => 0,
-        Err(_) => {
+        let mut defs = DEFINITIONS.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(text) = defs.get(name) {
+            return text.as_ptr();
+        }
+        let Some(text) = crate::schema_registry::full_definition(name) else {
+            set_error(EINVAL, format_args!("unknown schema `{}`", name));
+            return ptr::null();
+        };
+        let text = std::ffi::CString::new(text).unwrap_or_default();
+        let p = text.as_ptr();
+        defs.insert(name.to_owned(), text);
+        p
+    })
+}
+
+/// Schema encoding of `edgefirst_schema_definition()` text: `"ros2msg"`.
+#[no_mangle]
+pub extern "C" fn edgefirst_schema_encoding() -> *const c_char {
+    const ENCODING: &std::ffi::CStr = c"ros2msg";
+    debug_assert_eq!(
+        ENCODING.to_bytes(),
+        crate::schema_registry::SCHEMA_ENCODING.as_bytes()
+    );
+    ENCODING.as_ptr()
+}
+
+// =============================================================================
+// Generic message handle
+// =============================================================================
+
+/// Type-erased message of any schema in the registry, keyed by schema name.
+///
+/// The handle owns a copy of the CDR bytes and is walked from the schema's
+/// `.msg` definition, so every registered type is reachable without
+/// per-type functions.
+#[derive(Clone)]
+pub struct edgefirst_message_t {
+    schema: std::ffi::CString,
+    cdr: Vec<u8>,
+}
+
+/// Resolve a schema name from C. Sets `errno = EINVAL` for NULL, invalid
+/// UTF-8 or a schema without a registered definition.
+unsafe fn message_spec(
+    schema: *const c_char,
+) -> Result<(std::ffi::CString, std::rc::Rc<crate::msg_spec::Spec>), ()> {
+    let name = c_to_str_checked(schema)?;
+    match crate::msg_spec::Resolver::default().resolve(name, 0) {
+        Ok(spec) => Ok((std::ffi::CStr::from_ptr(schema).to_owned(), spec)),
+        Err(crate::msg_spec::SpecError::UnknownSchema(name)) => {
+            set_error(EINVAL, format_args!("unknown schema {}", name));
+            Err(())
+        }
+        Err(crate::msg_spec::SpecError::InvalidDefinition { schema, line }) => {
+            set_error(
+                EINVAL,
+                format_args!("invalid definition line in {}: {}", schema, line),
+            );
+            Err(())
+        }
+    }
+}
+
+/// Create a message of `schema` (e.g. `"edgefirst_msgs/msg/Detect"`) with
+/// every field zero, empty or `false`.
+///
+/// Returns NULL with `errno = EINVAL` for a NULL or unknown schema name.
+/// Free with `edgefirst_message_free()`.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_new(schema: *const c_char) -> *mut edgefirst_message_t {
+    ffi_guard(|| {
+        let Ok((schema, spec)) = (unsafe { message_spec(schema) }) else {
+            return ptr::null_mut();
+        };
+        let mut sizer = cdr::CdrSizer::new();
+        crate::msg_spec::write_default(&spec, &mut sizer);
+        let mut buf = vec![0u8; sizer.size()];
+        let written = cdr::CdrWriter::new(&mut buf).and_then(|mut w| {
+            crate::msg_spec::write_default(&spec, &mut w);
+            w.finish()
+        });
+        if written.is_err() {
             set_errno(EBADMSG);
-            -1
+            return ptr::null_mut();
+        }
+        new_handle(edgefirst_message_t { schema, cdr: buf })
+    })
+}
+
+/// Create a message of `schema` from CDR bytes, which are checked against
+/// the schema's definition and copied (the caller may free `data` after
+/// the call).
+///
+/// Returns NULL with `errno = EINVAL` for a NULL argument or unknown schema
+/// name, `EBADMSG` if `data` does not decode as `schema`.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_deserialize(
+    schema: *const c_char,
+    data: *const u8,
+    len: usize,
+) -> *mut edgefirst_message_t {
+    ffi_guard(|| {
+        check_null_ret_null!(data);
+        let Ok((schema, spec)) = (unsafe { message_spec(schema) }) else {
+            return ptr::null_mut();
+        };
+        let slice = unsafe { slice::from_raw_parts(data, len) };
+        let valid = cdr::CdrCursor::detect(slice).and_then(|mut c| {
+            crate::msg_spec::skip_msg(&spec, &mut c)?;
+            c.expect_end()?;
+            crate::schema_registry::validate_registered(schema.to_str().unwrap_or_default(), slice)
+        });
+        if valid.is_err() {
+            set_errno(EBADMSG);
+            return ptr::null_mut();
+        }
+        new_handle(edgefirst_message_t {
+            schema,
+            cdr: slice.to_vec(),
+        })
+    })
+}
+
+/// Deep-copy a message handle. Returns NULL with `errno = EINVAL` for
+/// NULL. Free with `edgefirst_message_free()`.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_clone(
+    msg: *const edgefirst_message_t,
+) -> *mut edgefirst_message_t {
+    ffi_guard(|| {
+        if bad_handle(msg) {
+            return ptr::null_mut();
+        }
+        new_handle(unsafe { (*msg).clone() })
+    })
+}
+
+/// Add a reference to a message handle; see `impl_retain!`. Returns `msg`,
+/// or NULL with `errno = EINVAL` for NULL.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_retain(
+    msg: *mut edgefirst_message_t,
+) -> *mut edgefirst_message_t {
+    ffi_guard(|| {
+        if bad_handle(msg) {
+            return ptr::null_mut();
+        }
+        retain_handle(msg);
+        msg
+    })
+}
+
+/// Drop a reference to a message handle; an alias of
+/// `edgefirst_message_free()`.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_release(msg: *mut edgefirst_message_t) {
+    edgefirst_message_free(msg)
+}
+
+/// 1 if both messages have the same schema and field values, 0 if not.
+/// Returns -1 with `errno = EINVAL` for a NULL handle.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_equals(
+    a: *const edgefirst_message_t,
+    b: *const edgefirst_message_t,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(a) || bad_handle(b) {
+            return -1;
+        }
+        let (a, b) = unsafe { (&*a, &*b) };
+        if a.schema != b.schema {
+            return 0;
+        }
+        cdr_equals(a.schema.to_str().unwrap_or_default(), &a.cdr, &b.cdr)
+    })
+}
+
+/// Copy the message's CDR bytes into `buf`.
+///
+/// Pass `buf = NULL` to query the required size (returned via `written`).
+/// Returns 0 on success, -1 on error (errno: EINVAL for a NULL handle,
+/// ENOBUFS if `cap` is too small).
+#[no_mangle]
+pub extern "C" fn edgefirst_message_serialize(
+    msg: *const edgefirst_message_t,
+    buf: *mut u8,
+    cap: usize,
+    written: *mut usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(msg) {
+            return -1;
+        }
+        let cdr = unsafe { &(*msg).cdr };
+        unsafe {
+            if !written.is_null() {
+                *written = cdr.len();
+            }
+        }
+        if buf.is_null() {
+            return 0; // Size query only
+        }
+        if cap < cdr.len() {
+            set_errno(ENOBUFS);
+            return -1;
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(cdr.as_ptr(), buf, cdr.len());
+        }
+        0
+    })
+}
+
+/// Length of the message's CDR bytes into `out`. Returns 0, or -1 with
+/// `errno = EINVAL` for a NULL argument.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_serialized_size(
+    msg: *const edgefirst_message_t,
+    out: *mut usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(msg) {
+            return -1;
+        }
+        if out.is_null() {
+            set_error(EINVAL, "`out` is NULL");
+            return -1;
+        }
+        unsafe {
+            *out = (*msg).cdr.len();
+        }
+        0
+    })
+}
+
+/// Borrow the message's CDR bytes; valid as long as the handle lives.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_as_cdr(
+    msg: *const edgefirst_message_t,
+    out_len: *mut usize,
+) -> *const u8 {
+    ffi_guard(|| {
+        if bad_handle(msg) {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return ptr::null();
+        }
+        let cdr = unsafe { &(*msg).cdr };
+        if !out_len.is_null() {
+            unsafe {
+                *out_len = cdr.len();
+            }
         }
+        cdr.as_ptr()
+    })
+}
+
+/// Schema name the message was created with; valid as long as the handle
+/// lives. Returns NULL with `errno = EINVAL` for a NULL handle.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_schema(msg: *const edgefirst_message_t) -> *const c_char {
+    ffi_guard(|| {
+        if bad_handle(msg) {
+            return ptr::null();
+        }
+        unsafe { (*msg).schema.as_ptr() }
+    })
+}
+
+impl crate::heap_size::HeapSize for edgefirst_message_t {
+    fn heap_size(&self) -> usize {
+        self.cdr.capacity() + self.schema.as_bytes_with_nul().len()
     }
 }
+
+/// Heap bytes owned by the message: its CDR bytes and schema name.
+/// Returns 0 with `errno = EINVAL` for a NULL handle.
+#[no_mangle]
+pub extern "C" fn edgefirst_message_heap_size(msg: *const edgefirst_message_t) -> usize {
+    ffi_guard(|| {
+        use crate::heap_size::HeapSize;
+        if bad_handle(msg) {
+            return 0;
+        }
+        unsafe { (*msg).heap_size() }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn edgefirst_message_free(msg: *mut edgefirst_message_t) {
+    ffi_guard(|| {
+        if !msg.is_null() {
+            unsafe {
+                free_handle(msg);
+            }
+        }
+    })
+}
+
+/// Render the message as JSON into `buf`, NUL-terminated, in the shape
+/// [`crate::json::transcode_to_json`] produces.
+///
+/// `*written` receives the JSON length excluding the NUL, so `cap` must be
+/// at least `*written + 1`. Pass `buf = NULL` to query the length. Returns
+/// 0 on success, -1 on error (errno: EINVAL for a NULL handle or `written`,
+/// ENOBUFS if `cap` is too small, EBADMSG if the message does not
+/// transcode).
+#[cfg(feature = "json")]
+#[no_mangle]
+pub extern "C" fn edgefirst_message_to_json(
+    msg: *const edgefirst_message_t,
+    buf: *mut c_char,
+    cap: usize,
+    written: *mut usize,
+) -> i32 {
+    ffi_guard(|| {
+        if bad_handle(msg) {
+            return -1;
+        }
+        if written.is_null() {
+            set_error(EINVAL, "`written` is NULL");
+            return -1;
+        }
+        let msg = unsafe { &*msg };
+        let schema = msg.schema.to_str().unwrap_or_default();
+        let json = match crate::json::transcode_to_json(schema, &msg.cdr) {
+            Ok(value) => value.to_string(),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return -1;
+            }
+        };
+        unsafe {
+            *written = json.len();
+        }
+        if buf.is_null() {
+            return 0; // Size query only
+        }
+        if cap <= json.len() {
+            set_error(
+                ENOBUFS,
+                format_args!("JSON needs {} bytes, buffer holds {}", json.len() + 1, cap),
+            );
+            return -1;
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(json.as_ptr(), buf as *mut u8, json.len());
+            *buf.add(json.len()) = 0;
+        }
+        0
+    })
+}
+
+/// Create a message of `schema` from a NUL-terminated JSON object, in the
+/// shape [`crate::json::transcode_from_json`] accepts: missing fields
+/// are written as zero or empty, unknown fields are rejected.
+///
+/// Returns NULL with `errno = EINVAL` for a NULL argument or unknown schema
+/// name, `EBADMSG` if `json` does not parse or does not match `schema`
+/// (`edgefirst_last_error_message()` names the offending field).
+#[cfg(feature = "json")]
+#[no_mangle]
+pub extern "C" fn edgefirst_message_from_json(
+    schema: *const c_char,
+    json: *const c_char,
+) -> *mut edgefirst_message_t {
+    ffi_guard(|| {
+        let Ok((schema, _)) = (unsafe { message_spec(schema) }) else {
+            return ptr::null_mut();
+        };
+        let Ok(text) = (unsafe { c_to_str_checked(json) }) else {
+            return ptr::null_mut();
+        };
+        let value: serde_json::Value = match serde_json::from_str(text) {
+            Ok(v) => v,
+            Err(e) => {
+                set_error(EBADMSG, e);
+                return ptr::null_mut();
+            }
+        };
+        let name = schema.to_str().unwrap_or_default();
+        match crate::json::transcode_from_json(name, &value) {
+            Ok(cdr) => new_handle(edgefirst_message_t { schema, cdr }),
+            Err(e) => {
+                set_error(EBADMSG, e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
@@ -7,11 +7,30 @@
 //!
 //! Buffer-backed: `FoxgloveCompressedVideo`, `FoxgloveTextAnnotation`
 //! (`FoxgloveTextAnnotationView`), `FoxglovePointAnnotation`
-//! (`FoxglovePointAnnotationView`), `FoxgloveImageAnnotation`
+//! (`FoxglovePointAnnotationView`), `FoxgloveImageAnnotation`,
+//! `FoxgloveSceneEntity` (`FoxgloveSceneEntityView`), `FoxgloveSceneUpdate`,
+//! `FoxgloveLaserScan`, `FoxgloveLocationFix`, `FoxgloveLog`,
+//! `FoxgloveCameraCalibration`, `FoxglovePoseInFrame`, `FoxglovePosesInFrame`,
+//! `FoxgloveGeoJson` (with the `GeoJsonFeatures` collection builder),
+//! `FoxgloveRawImage`
+//!
+//! Scene primitives: `FoxgloveArrowPrimitive`, `FoxgloveCubePrimitive`,
+//! `FoxgloveSpherePrimitive`, `FoxgloveCylinderPrimitive` (CdrFixed) and
+//! the `FoxgloveLinePrimitiveView`, `FoxgloveTriangleListPrimitiveView`,
+//! `FoxgloveTextPrimitiveView` and `FoxgloveModelPrimitiveView` views
 
-use crate::builtin_interfaces::Time;
+use crate::builtin_interfaces::{Duration, Time};
 use crate::cdr::*;
+use crate::geometry_msgs::{Point, Pose, Quaternion, Vector3};
+use crate::sensor_msgs::{
+    read_f64_array12, read_f64_array9, size_f64_array12, size_f64_array9, write_f64_array12,
+    write_f64_array9, CameraInfo, Image, NavSatFix,
+};
 use crate::std_msgs::Header;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
+use core::fmt::Write;
 
 // ── CdrFixed types ──────────────────────────────────────────────────
 
@@ -121,6 +140,60 @@ pub mod point_annotation_type {
     pub const LINE_LIST: u8 = 4;
 }
 
+pub mod line_type {
+    pub const LINE_STRIP: u8 = 0;
+    pub const LINE_LOOP: u8 = 1;
+    pub const LINE_LIST: u8 = 2;
+}
+
+pub mod log_level {
+    pub const UNKNOWN: u8 = 0;
+    pub const DEBUG: u8 = 1;
+    pub const INFO: u8 = 2;
+    pub const WARNING: u8 = 3;
+    pub const ERROR: u8 = 4;
+    pub const FATAL: u8 = 5;
+}
+
+crate::cdr_enum! {
+    /// Typed form of the [`log_level`] constants.
+    pub enum FoxgloveLogLevel: u8 {
+        Unknown = 0,
+        Debug = 1,
+        Info = 2,
+        Warning = 3,
+        Error = 4,
+        Fatal = 5,
+    }
+}
+
+impl FoxgloveLogLevel {
+    /// Map an `rcl_interfaces/msg/Log` level (10, 20, … 50) to the
+    /// Foxglove level; anything else is `Unknown`.
+    pub fn from_rcl_level(level: u8) -> Self {
+        match level {
+            10 => FoxgloveLogLevel::Debug,
+            20 => FoxgloveLogLevel::Info,
+            30 => FoxgloveLogLevel::Warning,
+            40 => FoxgloveLogLevel::Error,
+            50 => FoxgloveLogLevel::Fatal,
+            _ => FoxgloveLogLevel::Unknown,
+        }
+    }
+}
+
+pub mod position_covariance_type {
+    pub const UNKNOWN: u8 = 0;
+    pub const APPROXIMATED: u8 = 1;
+    pub const DIAGONAL_KNOWN: u8 = 2;
+    pub const KNOWN: u8 = 3;
+}
+
+pub mod scene_entity_deletion_type {
+    pub const MATCHING_ID: u8 = 0;
+    pub const ALL: u8 = 1;
+}
+
 // ── Buffer-backed types ─────────────────────────────────────────────
 
 // ── FoxgloveCompressedVideo<B> — foxglove_msgs/msg/CompressedVideo ──
@@ -149,13 +222,14 @@ impl<B> FoxgloveCompressedVideo<B> {
 
 impl<B: AsRef<[u8]>> FoxgloveCompressedVideo<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        let _ = c.read_bytes()?; // data
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_bytes().field("data")?;
         let o1 = c.offset();
-        let _ = c.read_string()?; // format
+        let _ = c.read_string().field("format")?;
         let o2 = c.offset();
+        c.expect_end()?;
         Ok(FoxgloveCompressedVideo {
             offsets: [o0, o1, o2],
             buf,
@@ -164,7 +238,7 @@ impl<B: AsRef<[u8]>> FoxgloveCompressedVideo<B> {
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
 
     pub fn stamp(&self) -> Time {
@@ -249,18 +323,18 @@ impl FoxgloveCompressedVideo<Vec<u8>> {
 /// `encode_into_vec()`, or `encode_into_slice()` is called.
 pub struct FoxgloveCompressedVideoBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     data: &'a [u8],
-    format: std::borrow::Cow<'a, str>,
+    format: alloc::borrow::Cow<'a, str>,
 }
 
 impl<'a> Default for FoxgloveCompressedVideoBuilder<'a> {
     fn default() -> Self {
         Self {
-            stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            stamp: Time::new(0, 0),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             data: &[],
-            format: std::borrow::Cow::Borrowed(""),
+            format: alloc::borrow::Cow::Borrowed(""),
         }
     }
 }
@@ -280,7 +354,7 @@ impl<'a> FoxgloveCompressedVideoBuilder<'a> {
         self.stamp(t)
     }
 
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -288,12 +362,13 @@ impl<'a> FoxgloveCompressedVideoBuilder<'a> {
         self.data = d;
         self
     }
-    pub fn format(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn format(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.format = s.into();
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -302,8 +377,8 @@ impl<'a> FoxgloveCompressedVideoBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_bytes(self.data);
@@ -314,30 +389,30 @@ impl<'a> FoxgloveCompressedVideoBuilder<'a> {
     /// Allocate a fresh `Vec<u8>` and return a fully-parsed
     /// `FoxgloveCompressedVideo<Vec<u8>>`.
     pub fn build(&self) -> Result<FoxgloveCompressedVideo<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         FoxgloveCompressedVideo::from_cdr(buf)
     }
 
     /// Serialize into the caller's `Vec<u8>`, resizing to exactly the encoded
     /// size. Reuses existing allocation when capacity suffices.
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     /// Serialize into `buf` and return bytes written. Errors with
     /// `BufferTooShort` when `buf` is smaller than the required size;
     /// nothing is mutated in that case.
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -435,6 +510,7 @@ impl<B: AsRef<[u8]>> FoxgloveTextAnnotation<B> {
         c.read_f64()?;
         FoxgloveColor::read_cdr(&mut c)?;
         FoxgloveColor::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(FoxgloveTextAnnotation { offsets: [o0], buf })
     }
 
@@ -527,7 +603,7 @@ impl FoxgloveTextAnnotation<Vec<u8>> {
 pub struct FoxgloveTextAnnotationBuilder<'a> {
     timestamp: Time,
     position: FoxglovePoint2,
-    text: std::borrow::Cow<'a, str>,
+    text: alloc::borrow::Cow<'a, str>,
     font_size: f64,
     text_color: FoxgloveColor,
     background_color: FoxgloveColor,
@@ -536,9 +612,9 @@ pub struct FoxgloveTextAnnotationBuilder<'a> {
 impl<'a> Default for FoxgloveTextAnnotationBuilder<'a> {
     fn default() -> Self {
         Self {
-            timestamp: Time { sec: 0, nanosec: 0 },
+            timestamp: Time::new(0, 0),
             position: FoxglovePoint2 { x: 0.0, y: 0.0 },
-            text: std::borrow::Cow::Borrowed(""),
+            text: alloc::borrow::Cow::Borrowed(""),
             font_size: 0.0,
             text_color: FoxgloveColor {
                 r: 0.0,
@@ -569,7 +645,7 @@ impl<'a> FoxgloveTextAnnotationBuilder<'a> {
         self.position = p;
         self
     }
-    pub fn text(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn text(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.text = s.into();
         self
     }
@@ -586,7 +662,8 @@ impl<'a> FoxgloveTextAnnotationBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         FoxglovePoint2::size_cdr(&mut s);
@@ -597,8 +674,8 @@ impl<'a> FoxgloveTextAnnotationBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.timestamp.write_cdr(&mut w);
         self.position.write_cdr(&mut w);
         w.write_string(&self.text);
@@ -609,25 +686,25 @@ impl<'a> FoxgloveTextAnnotationBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<FoxgloveTextAnnotation<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         FoxgloveTextAnnotation::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -785,6 +862,7 @@ impl<B: AsRef<[u8]>> FoxglovePointAnnotation<B> {
         let o1 = c.offset();
         FoxgloveColor::read_cdr(&mut c)?;
         c.read_f64()?;
+        c.expect_end()?;
         Ok(FoxglovePointAnnotation {
             offsets: [o0, o1],
             buf,
@@ -933,7 +1011,7 @@ pub struct FoxglovePointAnnotationBuilder<'a> {
 impl<'a> Default for FoxglovePointAnnotationBuilder<'a> {
     fn default() -> Self {
         Self {
-            timestamp: Time { sec: 0, nanosec: 0 },
+            timestamp: Time::new(0, 0),
             type_: 0,
             points: &[],
             outline_color: FoxgloveColor {
@@ -988,7 +1066,8 @@ impl<'a> FoxglovePointAnnotationBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_u8();
@@ -1006,8 +1085,8 @@ impl<'a> FoxglovePointAnnotationBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.timestamp.write_cdr(&mut w);
         w.write_u8(self.type_);
         w.write_u32(self.points.len() as u32);
@@ -1025,25 +1104,25 @@ impl<'a> FoxglovePointAnnotationBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<FoxglovePointAnnotation<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         FoxglovePointAnnotation::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -1129,6 +1208,7 @@ impl<B: AsRef<[u8]>> FoxgloveImageAnnotation<B> {
         for _ in 0..txt_count {
             scan_text_annotation(&mut c)?;
         }
+        c.expect_end()?;
         Ok(FoxgloveImageAnnotation {
             offsets: [o0, o1],
             buf,
@@ -1273,7 +1353,8 @@ impl<'a> FoxgloveImageAnnotationBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         s.size_u32();
         for _ in 0..self.circles.len() {
@@ -1290,8 +1371,8 @@ impl<'a> FoxgloveImageAnnotationBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         w.write_u32(self.circles.len() as u32);
         for c in self.circles {
             c.write_cdr(&mut w);
@@ -1308,226 +1389,3238 @@ impl<'a> FoxgloveImageAnnotationBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<FoxgloveImageAnnotation<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         FoxgloveImageAnnotation::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
 
-// ── Registry ────────────────────────────────────────────────────────
+// ── Scene primitives ────────────────────────────────────────────────
+//
+// Elements of FoxgloveSceneEntity. Arrow, cube, sphere and cylinder are
+// fixed size; the others carry sequences or strings and decode to views
+// that borrow strings and bytes from the CDR buffer and own the rest.
 
-/// Check if a type name is supported by this module.
-pub fn is_type_supported(type_name: &str) -> bool {
-    matches!(type_name, "CompressedVideo")
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct FoxgloveArrowPrimitive {
+    /// Position of the arrow's tail; it points along the pose's +x axis.
+    pub pose: Pose,
+    pub shaft_length: f64,
+    pub shaft_diameter: f64,
+    pub head_length: f64,
+    pub head_diameter: f64,
+    pub color: FoxgloveColor,
 }
 
-/// List all type schema names in this module.
-pub fn list_types() -> &'static [&'static str] {
-    &["foxglove_msgs/msg/CompressedVideo"]
+impl CdrFixed for FoxgloveArrowPrimitive {
+    const CDR_SIZE: usize = 120; // Pose(56) + 4*f64(32) + Color(32)
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(FoxgloveArrowPrimitive {
+            pose: Pose::read_cdr(cursor)?,
+            shaft_length: cursor.read_f64()?,
+            shaft_diameter: cursor.read_f64()?,
+            head_length: cursor.read_f64()?,
+            head_diameter: cursor.read_f64()?,
+            color: FoxgloveColor::read_cdr(cursor)?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        self.pose.write_cdr(writer);
+        writer.write_f64(self.shaft_length);
+        writer.write_f64(self.shaft_diameter);
+        writer.write_f64(self.head_length);
+        writer.write_f64(self.head_diameter);
+        self.color.write_cdr(writer);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        Pose::size_cdr(sizer);
+        for _ in 0..4 {
+            sizer.size_f64();
+        }
+        FoxgloveColor::size_cdr(sizer);
+    }
 }
 
-// SchemaType implementations
-use crate::schema_registry::SchemaType;
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct FoxgloveCubePrimitive {
+    /// Position of the cube's center.
+    pub pose: Pose,
+    pub size: Vector3,
+    pub color: FoxgloveColor,
+}
 
-impl SchemaType for FoxglovePoint2 {
-    const SCHEMA_NAME: &'static str = "foxglove_msgs/msg/FoxglovePoint2";
+impl CdrFixed for FoxgloveCubePrimitive {
+    const CDR_SIZE: usize = 112; // Pose(56) + Vector3(24) + Color(32)
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(FoxgloveCubePrimitive {
+            pose: Pose::read_cdr(cursor)?,
+            size: Vector3::read_cdr(cursor)?,
+            color: FoxgloveColor::read_cdr(cursor)?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        self.pose.write_cdr(writer);
+        self.size.write_cdr(writer);
+        self.color.write_cdr(writer);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        Pose::size_cdr(sizer);
+        Vector3::size_cdr(sizer);
+        FoxgloveColor::size_cdr(sizer);
+    }
 }
 
-impl SchemaType for FoxgloveColor {
-    const SCHEMA_NAME: &'static str = "foxglove_msgs/msg/FoxgloveColor";
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct FoxgloveSpherePrimitive {
+    /// Position of the sphere's center.
+    pub pose: Pose,
+    /// Diameter along each axis.
+    pub size: Vector3,
+    pub color: FoxgloveColor,
 }
 
-impl SchemaType for FoxgloveCircleAnnotations {
-    const SCHEMA_NAME: &'static str = "foxglove_msgs/msg/FoxgloveCircleAnnotations";
+impl CdrFixed for FoxgloveSpherePrimitive {
+    const CDR_SIZE: usize = 112; // Pose(56) + Vector3(24) + Color(32)
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(FoxgloveSpherePrimitive {
+            pose: Pose::read_cdr(cursor)?,
+            size: Vector3::read_cdr(cursor)?,
+            color: FoxgloveColor::read_cdr(cursor)?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        self.pose.write_cdr(writer);
+        self.size.write_cdr(writer);
+        self.color.write_cdr(writer);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        Pose::size_cdr(sizer);
+        Vector3::size_cdr(sizer);
+        FoxgloveColor::size_cdr(sizer);
+    }
 }
 
-#[allow(deprecated)]
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::builtin_interfaces::Time;
-    use crate::cdr::{decode_fixed, encode_fixed};
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct FoxgloveCylinderPrimitive {
+    /// Position of the cylinder's center; its axis is the pose's +z axis.
+    pub pose: Pose,
+    pub size: Vector3,
+    /// Scale of the bottom and top faces relative to `size`, 0 for a cone.
+    pub bottom_scale: f64,
+    pub top_scale: f64,
+    pub color: FoxgloveColor,
+}
 
-    #[test]
-    fn foxglove_color_roundtrip() {
-        let cases = [
-            (0.0, 0.0, 0.0, 0.0, "transparent black"),
-            (1.0, 1.0, 1.0, 1.0, "opaque white"),
-            (1.0, 0.0, 0.0, 1.0, "red"),
-            (0.5, 0.5, 0.5, 0.75, "gray"),
-        ];
-        for (r, g, b, a, name) in cases {
-            let color = FoxgloveColor { r, g, b, a };
-            let bytes = encode_fixed(&color).unwrap();
-            let decoded: FoxgloveColor = decode_fixed(&bytes).unwrap();
-            assert_eq!(color, decoded, "failed for case: {}", name);
-        }
+impl CdrFixed for FoxgloveCylinderPrimitive {
+    const CDR_SIZE: usize = 128; // Pose(56) + Vector3(24) + 2*f64(16) + Color(32)
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(FoxgloveCylinderPrimitive {
+            pose: Pose::read_cdr(cursor)?,
+            size: Vector3::read_cdr(cursor)?,
+            bottom_scale: cursor.read_f64()?,
+            top_scale: cursor.read_f64()?,
+            color: FoxgloveColor::read_cdr(cursor)?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        self.pose.write_cdr(writer);
+        self.size.write_cdr(writer);
+        writer.write_f64(self.bottom_scale);
+        writer.write_f64(self.top_scale);
+        self.color.write_cdr(writer);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        Pose::size_cdr(sizer);
+        Vector3::size_cdr(sizer);
+        sizer.size_f64();
+        sizer.size_f64();
+        FoxgloveColor::size_cdr(sizer);
     }
+}
 
-    #[test]
-    fn foxglove_point2_roundtrip() {
-        let cases = [
-            (0.0, 0.0, "origin"),
-            (100.0, 200.0, "positive"),
-            (-50.0, -75.0, "negative"),
-        ];
-        for (x, y, name) in cases {
-            let point = FoxglovePoint2 { x, y };
-            let bytes = encode_fixed(&point).unwrap();
-            let decoded: FoxglovePoint2 = decode_fixed(&bytes).unwrap();
-            assert_eq!(point, decoded, "failed for case: {}", name);
-        }
+/// Read a sequence (count included) whose elements are at least
+/// `min_element` bytes each.
+fn read_seq<'a, T>(
+    c: &mut CdrCursor<'a>,
+    min_element: usize,
+    mut read: impl FnMut(&mut CdrCursor<'a>) -> Result<T, CdrError>,
+) -> Result<Vec<T>, CdrError> {
+    let raw = c.read_u32()?;
+    let n = c.check_seq_count(raw, min_element)?;
+    (0..n).map(|_| read(c)).collect()
+}
+
+fn read_fixed_seq<'a, T: CdrFixed>(c: &mut CdrCursor<'a>) -> Result<Cow<'a, [T]>, CdrError> {
+    read_seq(c, T::CDR_SIZE, T::read_cdr).map(Cow::Owned)
+}
+
+fn write_fixed_seq<T: CdrFixed>(w: &mut CdrWriter<'_>, items: &[T]) {
+    w.write_u32(items.len() as u32);
+    for item in items {
+        item.write_cdr(w);
     }
+}
 
-    #[test]
-    fn foxglove_circle_annotations_roundtrip() {
-        let circle = FoxgloveCircleAnnotations {
-            timestamp: Time::new(100, 0),
-            position: FoxglovePoint2 { x: 320.0, y: 240.0 },
-            diameter: 50.0,
-            thickness: 2.0,
-            fill_color: FoxgloveColor {
-                r: 1.0,
-                g: 0.0,
-                b: 0.0,
-                a: 0.5,
-            },
-            outline_color: FoxgloveColor {
-                r: 0.0,
-                g: 1.0,
-                b: 0.0,
-                a: 1.0,
-            },
-        };
-        let bytes = encode_fixed(&circle).unwrap();
-        let decoded: FoxgloveCircleAnnotations = decode_fixed(&bytes).unwrap();
-        assert_eq!(circle, decoded);
+fn size_fixed_seq<T: CdrFixed>(s: &mut CdrSizer, items: &[T]) {
+    s.size_u32();
+    for _ in items {
+        T::size_cdr(s);
     }
+}
 
-    #[test]
-    fn foxglove_compressed_video_roundtrip() {
-        let video = FoxgloveCompressedVideo::new(
-            Time::new(100, 500_000_000),
-            "camera",
-            &[0x00, 0x00, 0x00, 0x01, 0x67, 0x42],
-            "h264",
-        )
-        .unwrap();
-        assert_eq!(video.stamp(), Time::new(100, 500_000_000));
-        assert_eq!(video.frame_id(), "camera");
-        assert_eq!(video.data(), &[0x00, 0x00, 0x00, 0x01, 0x67, 0x42]);
-        assert_eq!(video.format(), "h264");
+fn read_u32_seq<'a>(c: &mut CdrCursor<'a>) -> Result<Cow<'a, [u32]>, CdrError> {
+    read_seq(c, 4, |c| c.read_u32()).map(Cow::Owned)
+}
 
-        let bytes = video.to_cdr();
-        let decoded = FoxgloveCompressedVideo::from_cdr(bytes).unwrap();
-        assert_eq!(decoded.format(), "h264");
-        assert_eq!(decoded.data().len(), 6);
+fn write_u32_seq(w: &mut CdrWriter<'_>, items: &[u32]) {
+    w.write_u32(items.len() as u32);
+    for v in items {
+        w.write_u32(*v);
     }
+}
 
-    #[test]
-    fn foxglove_compressed_video_timestamp_alias() {
-        // The `timestamp()` accessors are permanent additive aliases for `stamp()`,
-        // matching the Foxglove schema field name. Both must agree on the view side,
-        // the builder side, and the in-place setter side.
-        let mut video = FoxgloveCompressedVideo::new(
-            Time::new(100, 500_000_000),
-            "camera",
-            &[0xab, 0xcd],
-            "h264",
-        )
-        .unwrap();
+fn size_u32_seq(s: &mut CdrSizer, items: &[u32]) {
+    s.size_u32();
+    for _ in items {
+        s.size_u32();
+    }
+}
 
-        // View side: stamp() and timestamp() return identical values.
-        assert_eq!(video.stamp(), video.timestamp());
-        assert_eq!(video.timestamp(), Time::new(100, 500_000_000));
+/// A polyline or set of segments; `type_` is one of [`line_type`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FoxgloveLinePrimitiveView<'a> {
+    pub type_: u8,
+    pub pose: Pose,
+    pub thickness: f64,
+    /// `thickness` is in pixels rather than metres.
+    pub scale_invariant: bool,
+    pub points: Cow<'a, [Point]>,
+    pub color: FoxgloveColor,
+    /// Per-point colors, overriding `color` when non-empty.
+    pub colors: Cow<'a, [FoxgloveColor]>,
+    /// Indices into `points` to draw, all of them in order when empty.
+    pub indices: Cow<'a, [u32]>,
+}
 
-        // In-place setter side: set_timestamp updates what stamp() observes.
-        video.set_timestamp(Time::new(7, 9)).unwrap();
-        assert_eq!(video.stamp(), Time::new(7, 9));
-        assert_eq!(video.timestamp(), Time::new(7, 9));
+// type, pose, thickness, scale_invariant, points, color, colors, indices
+const MIN_LINE_SIZE: usize = 1 + 56 + 8 + 1 + 4 + 32 + 4 + 4;
+
+fn read_line<'a>(c: &mut CdrCursor<'a>) -> Result<FoxgloveLinePrimitiveView<'a>, CdrError> {
+    Ok(FoxgloveLinePrimitiveView {
+        type_: c.read_u8()?,
+        pose: Pose::read_cdr(c)?,
+        thickness: c.read_f64()?,
+        scale_invariant: c.read_bool()?,
+        points: read_fixed_seq(c)?,
+        color: FoxgloveColor::read_cdr(c)?,
+        colors: read_fixed_seq(c)?,
+        indices: read_u32_seq(c)?,
+    })
+}
 
-        // Builder side: timestamp() and stamp() setters produce equivalent output.
-        let via_stamp = FoxgloveCompressedVideoBuilder::default()
-            .stamp(Time::new(42, 100))
-            .frame_id("c")
-            .data(&[1, 2, 3])
-            .format("h264")
-            .build()
-            .unwrap();
-        let via_timestamp = FoxgloveCompressedVideoBuilder::default()
-            .timestamp(Time::new(42, 100))
-            .frame_id("c")
-            .data(&[1, 2, 3])
-            .format("h264")
-            .build()
-            .unwrap();
-        assert_eq!(via_stamp.to_cdr(), via_timestamp.to_cdr());
-    }
+fn write_line(w: &mut CdrWriter<'_>, l: &FoxgloveLinePrimitiveView<'_>) {
+    w.write_u8(l.type_);
+    l.pose.write_cdr(w);
+    w.write_f64(l.thickness);
+    w.write_bool(l.scale_invariant);
+    write_fixed_seq(w, &l.points);
+    l.color.write_cdr(w);
+    write_fixed_seq(w, &l.colors);
+    write_u32_seq(w, &l.indices);
+}
 
-    #[test]
-    fn foxglove_text_annotation_roundtrip() {
-        let text = FoxgloveTextAnnotation::new(
-            Time::new(100, 0),
-            FoxglovePoint2 { x: 50.0, y: 50.0 },
-            "Detection: car (98%)",
-            14.0,
-            FoxgloveColor {
-                r: 1.0,
-                g: 1.0,
-                b: 1.0,
-                a: 1.0,
-            },
-            FoxgloveColor {
-                r: 0.0,
-                g: 0.0,
-                b: 0.0,
-                a: 0.7,
-            },
-        )
-        .unwrap();
-        assert_eq!(text.text(), "Detection: car (98%)");
-        assert_eq!(text.font_size(), 14.0);
+fn size_line(s: &mut CdrSizer, l: &FoxgloveLinePrimitiveView<'_>) {
+    s.size_u8();
+    Pose::size_cdr(s);
+    s.size_f64();
+    s.size_bool();
+    size_fixed_seq(s, &l.points);
+    FoxgloveColor::size_cdr(s);
+    size_fixed_seq(s, &l.colors);
+    size_u32_seq(s, &l.indices);
+}
 
-        let bytes = text.to_cdr();
-        let decoded = FoxgloveTextAnnotation::from_cdr(bytes).unwrap();
-        assert_eq!(decoded.text(), "Detection: car (98%)");
-    }
+/// A triangle mesh: every three points (or indices) make a triangle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FoxgloveTriangleListPrimitiveView<'a> {
+    pub pose: Pose,
+    pub points: Cow<'a, [Point]>,
+    pub color: FoxgloveColor,
+    /// Per-vertex colors, overriding `color` when non-empty.
+    pub colors: Cow<'a, [FoxgloveColor]>,
+    pub indices: Cow<'a, [u32]>,
+}
 
-    #[test]
-    fn foxglove_point_annotation_roundtrip() {
-        let pa = FoxglovePointAnnotation::new(
-            Time::new(100, 0),
-            point_annotation_type::LINE_LOOP,
-            &[
-                FoxglovePoint2 { x: 0.0, y: 0.0 },
-                FoxglovePoint2 { x: 100.0, y: 0.0 },
-                FoxglovePoint2 { x: 100.0, y: 100.0 },
-            ],
-            FoxgloveColor {
-                r: 0.0,
-                g: 1.0,
-                b: 0.0,
-                a: 1.0,
-            },
-            &[],
-            FoxgloveColor {
+// pose, points, color, colors, indices
+const MIN_TRIANGLES_SIZE: usize = 56 + 4 + 32 + 4 + 4;
+
+fn read_triangles<'a>(
+    c: &mut CdrCursor<'a>,
+) -> Result<FoxgloveTriangleListPrimitiveView<'a>, CdrError> {
+    Ok(FoxgloveTriangleListPrimitiveView {
+        pose: Pose::read_cdr(c)?,
+        points: read_fixed_seq(c)?,
+        color: FoxgloveColor::read_cdr(c)?,
+        colors: read_fixed_seq(c)?,
+        indices: read_u32_seq(c)?,
+    })
+}
+
+fn write_triangles(w: &mut CdrWriter<'_>, t: &FoxgloveTriangleListPrimitiveView<'_>) {
+    t.pose.write_cdr(w);
+    write_fixed_seq(w, &t.points);
+    t.color.write_cdr(w);
+    write_fixed_seq(w, &t.colors);
+    write_u32_seq(w, &t.indices);
+}
+
+fn size_triangles(s: &mut CdrSizer, t: &FoxgloveTriangleListPrimitiveView<'_>) {
+    Pose::size_cdr(s);
+    size_fixed_seq(s, &t.points);
+    FoxgloveColor::size_cdr(s);
+    size_fixed_seq(s, &t.colors);
+    size_u32_seq(s, &t.indices);
+}
+
+/// A text label in the scene.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FoxgloveTextPrimitiveView<'a> {
+    pub pose: Pose,
+    /// Always face the camera.
+    pub billboard: bool,
+    pub font_size: f64,
+    /// `font_size` is in pixels rather than metres.
+    pub scale_invariant: bool,
+    pub color: FoxgloveColor,
+    pub text: &'a str,
+}
+
+// pose, billboard, font_size, scale_invariant, color, text
+const MIN_TEXT_SIZE: usize = 56 + 1 + 8 + 1 + 32 + 5;
+
+fn read_text<'a>(c: &mut CdrCursor<'a>) -> Result<FoxgloveTextPrimitiveView<'a>, CdrError> {
+    Ok(FoxgloveTextPrimitiveView {
+        pose: Pose::read_cdr(c)?,
+        billboard: c.read_bool()?,
+        font_size: c.read_f64()?,
+        scale_invariant: c.read_bool()?,
+        color: FoxgloveColor::read_cdr(c)?,
+        text: c.read_string()?,
+    })
+}
+
+fn write_text(w: &mut CdrWriter<'_>, t: &FoxgloveTextPrimitiveView<'_>) {
+    t.pose.write_cdr(w);
+    w.write_bool(t.billboard);
+    w.write_f64(t.font_size);
+    w.write_bool(t.scale_invariant);
+    t.color.write_cdr(w);
+    w.write_string(t.text);
+}
+
+fn size_text(s: &mut CdrSizer, t: &FoxgloveTextPrimitiveView<'_>) {
+    Pose::size_cdr(s);
+    s.size_bool();
+    s.size_f64();
+    s.size_bool();
+    FoxgloveColor::size_cdr(s);
+    s.size_string(t.text);
+}
+
+/// A 3D model, loaded from `url` or embedded in `data` (glTF, glb, STL,
+/// DAE or OBJ, as given by `media_type`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FoxgloveModelPrimitiveView<'a> {
+    pub pose: Pose,
+    pub scale: Vector3,
+    pub color: FoxgloveColor,
+    /// Draw the model in `color` instead of its own materials.
+    pub override_color: bool,
+    pub url: &'a str,
+    pub media_type: &'a str,
+    pub data: &'a [u8],
+}
+
+// pose, scale, color, override_color, url, media_type, data
+const MIN_MODEL_SIZE: usize = 56 + 24 + 32 + 1 + 5 + 5 + 4;
+
+fn read_model<'a>(c: &mut CdrCursor<'a>) -> Result<FoxgloveModelPrimitiveView<'a>, CdrError> {
+    Ok(FoxgloveModelPrimitiveView {
+        pose: Pose::read_cdr(c)?,
+        scale: Vector3::read_cdr(c)?,
+        color: FoxgloveColor::read_cdr(c)?,
+        override_color: c.read_bool()?,
+        url: c.read_string()?,
+        media_type: c.read_string()?,
+        data: c.read_bytes()?,
+    })
+}
+
+fn write_model(w: &mut CdrWriter<'_>, m: &FoxgloveModelPrimitiveView<'_>) {
+    m.pose.write_cdr(w);
+    m.scale.write_cdr(w);
+    m.color.write_cdr(w);
+    w.write_bool(m.override_color);
+    w.write_string(m.url);
+    w.write_string(m.media_type);
+    w.write_bytes(m.data);
+}
+
+fn size_model(s: &mut CdrSizer, m: &FoxgloveModelPrimitiveView<'_>) {
+    Pose::size_cdr(s);
+    Vector3::size_cdr(s);
+    FoxgloveColor::size_cdr(s);
+    s.size_bool();
+    s.size_string(m.url);
+    s.size_string(m.media_type);
+    s.size_bytes(m.data.len());
+}
+
+/// An entry of a scene entity's `metadata`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FoxgloveKeyValuePairView<'a> {
+    pub key: &'a str,
+    pub value: &'a str,
+}
+
+fn read_key_value<'a>(c: &mut CdrCursor<'a>) -> Result<FoxgloveKeyValuePairView<'a>, CdrError> {
+    Ok(FoxgloveKeyValuePairView {
+        key: c.read_string()?,
+        value: c.read_string()?,
+    })
+}
+
+// ── Scene entities ──────────────────────────────────────────────────
+
+/// Removal of scene entities: the one called `id` for
+/// [`MATCHING_ID`](scene_entity_deletion_type::MATCHING_ID), or every
+/// entity for [`ALL`](scene_entity_deletion_type::ALL).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FoxgloveSceneEntityDeletionView<'a> {
+    pub timestamp: Time,
+    pub type_: u8,
+    pub id: &'a str,
+}
+
+fn read_deletion<'a>(
+    c: &mut CdrCursor<'a>,
+) -> Result<FoxgloveSceneEntityDeletionView<'a>, CdrError> {
+    Ok(FoxgloveSceneEntityDeletionView {
+        timestamp: Time::read_cdr(c)?,
+        type_: c.read_u8()?,
+        id: c.read_string()?,
+    })
+}
+
+fn write_deletion(w: &mut CdrWriter<'_>, d: &FoxgloveSceneEntityDeletionView<'_>) {
+    d.timestamp.write_cdr(w);
+    w.write_u8(d.type_);
+    w.write_string(d.id);
+}
+
+fn size_deletion(s: &mut CdrSizer, d: &FoxgloveSceneEntityDeletionView<'_>) {
+    Time::size_cdr(s);
+    s.size_u8();
+    s.size_string(d.id);
+}
+
+/// An object in a Foxglove 3D scene, drawn as the union of its
+/// primitives. Entities with the same `id` replace each other; `lifetime`
+/// of zero keeps the entity until it is replaced or deleted.
+///
+/// `Default` gives an empty entity, so literals only name what they set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FoxgloveSceneEntityView<'a> {
+    pub timestamp: Time,
+    pub frame_id: &'a str,
+    pub id: &'a str,
+    pub lifetime: Duration,
+    /// Follow `frame_id` as it moves instead of staying where it was at
+    /// `timestamp`.
+    pub frame_locked: bool,
+    pub metadata: Cow<'a, [FoxgloveKeyValuePairView<'a>]>,
+    pub arrows: Cow<'a, [FoxgloveArrowPrimitive]>,
+    pub cubes: Cow<'a, [FoxgloveCubePrimitive]>,
+    pub spheres: Cow<'a, [FoxgloveSpherePrimitive]>,
+    pub cylinders: Cow<'a, [FoxgloveCylinderPrimitive]>,
+    pub lines: Cow<'a, [FoxgloveLinePrimitiveView<'a>]>,
+    pub triangles: Cow<'a, [FoxgloveTriangleListPrimitiveView<'a>]>,
+    pub texts: Cow<'a, [FoxgloveTextPrimitiveView<'a>]>,
+    pub models: Cow<'a, [FoxgloveModelPrimitiveView<'a>]>,
+}
+
+impl Default for FoxgloveSceneEntityView<'_> {
+    fn default() -> Self {
+        FoxgloveSceneEntityView {
+            timestamp: Time::new(0, 0),
+            frame_id: "",
+            id: "",
+            lifetime: Duration::new(0, 0),
+            frame_locked: false,
+            metadata: Cow::Borrowed(&[]),
+            arrows: Cow::Borrowed(&[]),
+            cubes: Cow::Borrowed(&[]),
+            spheres: Cow::Borrowed(&[]),
+            cylinders: Cow::Borrowed(&[]),
+            lines: Cow::Borrowed(&[]),
+            triangles: Cow::Borrowed(&[]),
+            texts: Cow::Borrowed(&[]),
+            models: Cow::Borrowed(&[]),
+        }
+    }
+}
+
+// timestamp, frame_id, id, lifetime, frame_locked and nine counts
+const MIN_ENTITY_SIZE: usize = 8 + 5 + 5 + 8 + 1 + 9 * 4;
+
+fn read_entity<'a>(c: &mut CdrCursor<'a>) -> Result<FoxgloveSceneEntityView<'a>, CdrError> {
+    Ok(FoxgloveSceneEntityView {
+        timestamp: Time::read_cdr(c)?,
+        frame_id: c.read_string()?,
+        id: c.read_string()?,
+        lifetime: Duration::read_cdr(c)?,
+        frame_locked: c.read_bool()?,
+        metadata: Cow::Owned(read_seq(c, 10, read_key_value).field("metadata")?),
+        arrows: read_fixed_seq(c).field("arrows")?,
+        cubes: read_fixed_seq(c).field("cubes")?,
+        spheres: read_fixed_seq(c).field("spheres")?,
+        cylinders: read_fixed_seq(c).field("cylinders")?,
+        lines: Cow::Owned(read_seq(c, MIN_LINE_SIZE, read_line).field("lines")?),
+        triangles: Cow::Owned(read_seq(c, MIN_TRIANGLES_SIZE, read_triangles).field("triangles")?),
+        texts: Cow::Owned(read_seq(c, MIN_TEXT_SIZE, read_text).field("texts")?),
+        models: Cow::Owned(read_seq(c, MIN_MODEL_SIZE, read_model).field("models")?),
+    })
+}
+
+fn write_entity(w: &mut CdrWriter<'_>, e: &FoxgloveSceneEntityView<'_>) {
+    e.timestamp.write_cdr(w);
+    w.write_string(e.frame_id);
+    w.write_string(e.id);
+    e.lifetime.write_cdr(w);
+    w.write_bool(e.frame_locked);
+    w.write_u32(e.metadata.len() as u32);
+    for kv in e.metadata.iter() {
+        w.write_string(kv.key);
+        w.write_string(kv.value);
+    }
+    write_fixed_seq(w, &e.arrows);
+    write_fixed_seq(w, &e.cubes);
+    write_fixed_seq(w, &e.spheres);
+    write_fixed_seq(w, &e.cylinders);
+    w.write_u32(e.lines.len() as u32);
+    for l in e.lines.iter() {
+        write_line(w, l);
+    }
+    w.write_u32(e.triangles.len() as u32);
+    for t in e.triangles.iter() {
+        write_triangles(w, t);
+    }
+    w.write_u32(e.texts.len() as u32);
+    for t in e.texts.iter() {
+        write_text(w, t);
+    }
+    w.write_u32(e.models.len() as u32);
+    for m in e.models.iter() {
+        write_model(w, m);
+    }
+}
+
+fn size_entity(s: &mut CdrSizer, e: &FoxgloveSceneEntityView<'_>) {
+    Time::size_cdr(s);
+    s.size_string(e.frame_id);
+    s.size_string(e.id);
+    Duration::size_cdr(s);
+    s.size_bool();
+    s.size_u32();
+    for kv in e.metadata.iter() {
+        s.size_string(kv.key);
+        s.size_string(kv.value);
+    }
+    size_fixed_seq(s, &e.arrows);
+    size_fixed_seq(s, &e.cubes);
+    size_fixed_seq(s, &e.spheres);
+    size_fixed_seq(s, &e.cylinders);
+    s.size_u32();
+    for l in e.lines.iter() {
+        size_line(s, l);
+    }
+    s.size_u32();
+    for t in e.triangles.iter() {
+        size_triangles(s, t);
+    }
+    s.size_u32();
+    for t in e.texts.iter() {
+        size_text(s, t);
+    }
+    s.size_u32();
+    for m in e.models.iter() {
+        size_model(s, m);
+    }
+}
+
+// ── FoxgloveSceneEntity<B> — foxglove_msgs/msg/SceneEntity ──────────
+//
+// CDR layout: timestamp(Time), frame_id(string) → offsets[0],
+//   id(string) → offsets[1], lifetime(Duration), frame_locked(bool),
+//   metadata, then the eight primitive sequences
+
+pub struct FoxgloveSceneEntity<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
+
+impl<B> FoxgloveSceneEntity<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxgloveSceneEntity<C> {
+        FoxgloveSceneEntity {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxgloveSceneEntity<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        Time::read_cdr(&mut c)?;
+        let _ = c.read_string().field("frame_id")?;
+        let o0 = c.offset();
+        let _ = c.read_string().field("id")?;
+        let o1 = c.offset();
+        c.set_pos(CDR_HEADER_SIZE);
+        read_entity(&mut c)?;
+        c.expect_end()?;
+        Ok(FoxgloveSceneEntity {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
+
+    pub fn timestamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn id(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+    pub fn lifetime(&self) -> Duration {
+        rd_duration(self.buf.as_ref(), cdr_align(self.offsets[1], 4))
+    }
+    pub fn frame_locked(&self) -> bool {
+        rd_bool(self.buf.as_ref(), cdr_align(self.offsets[1], 4) + 8)
+    }
+    /// Every field, primitives included.
+    pub fn view(&self) -> FoxgloveSceneEntityView<'_> {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), CDR_HEADER_SIZE);
+        read_entity(&mut c).expect("entity validated during from_cdr")
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxgloveSceneEntity<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxgloveSceneEntityBuilder` with an empty entity.
+    pub fn builder<'a>() -> FoxgloveSceneEntityBuilder<'a> {
+        FoxgloveSceneEntityBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FoxgloveSceneEntity<B> {
+    pub fn set_timestamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── FoxgloveSceneEntityBuilder<'a> ──────────────────────────────────
+
+/// Builder for `FoxgloveSceneEntity<Vec<u8>>` with buffer-reuse
+/// finalizers.
+///
+/// Metadata and primitives are borrowed for zero-copy input.
+pub struct FoxgloveSceneEntityBuilder<'a> {
+    timestamp: Time,
+    frame_id: Cow<'a, str>,
+    id: Cow<'a, str>,
+    lifetime: Duration,
+    frame_locked: bool,
+    metadata: &'a [FoxgloveKeyValuePairView<'a>],
+    arrows: &'a [FoxgloveArrowPrimitive],
+    cubes: &'a [FoxgloveCubePrimitive],
+    spheres: &'a [FoxgloveSpherePrimitive],
+    cylinders: &'a [FoxgloveCylinderPrimitive],
+    lines: &'a [FoxgloveLinePrimitiveView<'a>],
+    triangles: &'a [FoxgloveTriangleListPrimitiveView<'a>],
+    texts: &'a [FoxgloveTextPrimitiveView<'a>],
+    models: &'a [FoxgloveModelPrimitiveView<'a>],
+}
+
+impl<'a> Default for FoxgloveSceneEntityBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            timestamp: Time::new(0, 0),
+            frame_id: Cow::Borrowed(""),
+            id: Cow::Borrowed(""),
+            lifetime: Duration::new(0, 0),
+            frame_locked: false,
+            metadata: &[],
+            arrows: &[],
+            cubes: &[],
+            spheres: &[],
+            cylinders: &[],
+            lines: &[],
+            triangles: &[],
+            texts: &[],
+            models: &[],
+        }
+    }
+}
+
+impl<'a> FoxgloveSceneEntityBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp(&mut self, t: Time) -> &mut Self {
+        self.timestamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.id = s.into();
+        self
+    }
+    pub fn lifetime(&mut self, d: Duration) -> &mut Self {
+        self.lifetime = d;
+        self
+    }
+    pub fn frame_locked(&mut self, v: bool) -> &mut Self {
+        self.frame_locked = v;
+        self
+    }
+    pub fn metadata(&mut self, v: &'a [FoxgloveKeyValuePairView<'a>]) -> &mut Self {
+        self.metadata = v;
+        self
+    }
+    pub fn arrows(&mut self, v: &'a [FoxgloveArrowPrimitive]) -> &mut Self {
+        self.arrows = v;
+        self
+    }
+    pub fn cubes(&mut self, v: &'a [FoxgloveCubePrimitive]) -> &mut Self {
+        self.cubes = v;
+        self
+    }
+    pub fn spheres(&mut self, v: &'a [FoxgloveSpherePrimitive]) -> &mut Self {
+        self.spheres = v;
+        self
+    }
+    pub fn cylinders(&mut self, v: &'a [FoxgloveCylinderPrimitive]) -> &mut Self {
+        self.cylinders = v;
+        self
+    }
+    pub fn lines(&mut self, v: &'a [FoxgloveLinePrimitiveView<'a>]) -> &mut Self {
+        self.lines = v;
+        self
+    }
+    pub fn triangles(&mut self, v: &'a [FoxgloveTriangleListPrimitiveView<'a>]) -> &mut Self {
+        self.triangles = v;
+        self
+    }
+    pub fn texts(&mut self, v: &'a [FoxgloveTextPrimitiveView<'a>]) -> &mut Self {
+        self.texts = v;
+        self
+    }
+    pub fn models(&mut self, v: &'a [FoxgloveModelPrimitiveView<'a>]) -> &mut Self {
+        self.models = v;
+        self
+    }
+
+    fn view(&self) -> FoxgloveSceneEntityView<'_> {
+        FoxgloveSceneEntityView {
+            timestamp: self.timestamp,
+            frame_id: &self.frame_id,
+            id: &self.id,
+            lifetime: self.lifetime,
+            frame_locked: self.frame_locked,
+            metadata: Cow::Borrowed(self.metadata),
+            arrows: Cow::Borrowed(self.arrows),
+            cubes: Cow::Borrowed(self.cubes),
+            spheres: Cow::Borrowed(self.spheres),
+            cylinders: Cow::Borrowed(self.cylinders),
+            lines: Cow::Borrowed(self.lines),
+            triangles: Cow::Borrowed(self.triangles),
+            texts: Cow::Borrowed(self.texts),
+            models: Cow::Borrowed(self.models),
+        }
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        size_entity(&mut s, &self.view());
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        write_entity(&mut w, &self.view());
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxgloveSceneEntity<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxgloveSceneEntity::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── FoxgloveSceneUpdate<B> — foxglove_msgs/msg/SceneUpdate ──────────
+//
+// CDR layout: uint32 count + SceneEntityDeletion[] deletions,
+//   uint32 count + SceneEntity[] entities → offsets[0]
+//
+// Deletions apply before the entities of the same update are added.
+
+pub struct FoxgloveSceneUpdate<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> FoxgloveSceneUpdate<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxgloveSceneUpdate<C> {
+        FoxgloveSceneUpdate {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxgloveSceneUpdate<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        // Smallest deletion: timestamp (8), type (1) and an empty id (5).
+        read_seq(&mut c, 14, read_deletion).field("deletions")?;
+        let o0 = c.offset();
+        read_seq(&mut c, MIN_ENTITY_SIZE, read_entity).field("entities")?;
+        c.expect_end()?;
+        Ok(FoxgloveSceneUpdate { offsets: [o0], buf })
+    }
+
+    pub fn deletions_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn deletions(&self) -> Vec<FoxgloveSceneEntityDeletionView<'_>> {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), CDR_HEADER_SIZE);
+        read_seq(&mut c, 0, read_deletion).expect("deletions validated during from_cdr")
+    }
+    pub fn entities_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), cdr_align(self.offsets[0], 4))
+    }
+    pub fn entities(&self) -> Vec<FoxgloveSceneEntityView<'_>> {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0]);
+        read_seq(&mut c, 0, read_entity).expect("entities validated during from_cdr")
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxgloveSceneUpdate<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxgloveSceneUpdateBuilder` with no deletions or
+    /// entities.
+    pub fn builder<'a>() -> FoxgloveSceneUpdateBuilder<'a> {
+        FoxgloveSceneUpdateBuilder::new()
+    }
+}
+
+// ── FoxgloveSceneUpdateBuilder<'a> ──────────────────────────────────
+
+/// Builder for `FoxgloveSceneUpdate<Vec<u8>>` with buffer-reuse
+/// finalizers.
+///
+/// `deletions` and `entities` are borrowed for zero-copy input.
+#[derive(Default)]
+pub struct FoxgloveSceneUpdateBuilder<'a> {
+    deletions: &'a [FoxgloveSceneEntityDeletionView<'a>],
+    entities: &'a [FoxgloveSceneEntityView<'a>],
+}
+
+impl<'a> FoxgloveSceneUpdateBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn deletions(&mut self, v: &'a [FoxgloveSceneEntityDeletionView<'a>]) -> &mut Self {
+        self.deletions = v;
+        self
+    }
+    pub fn entities(&mut self, v: &'a [FoxgloveSceneEntityView<'a>]) -> &mut Self {
+        self.entities = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        s.size_u32();
+        for d in self.deletions {
+            size_deletion(&mut s, d);
+        }
+        s.size_u32();
+        for e in self.entities {
+            size_entity(&mut s, e);
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        w.write_u32(self.deletions.len() as u32);
+        for d in self.deletions {
+            write_deletion(&mut w, d);
+        }
+        w.write_u32(self.entities.len() as u32);
+        for e in self.entities {
+            write_entity(&mut w, e);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxgloveSceneUpdate<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxgloveSceneUpdate::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+const IDENTITY_POSE: Pose = Pose {
+    position: Point {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    },
+    orientation: Quaternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    },
+};
+
+// ── FoxgloveLaserScan<B> — foxglove_msgs/msg/LaserScan ──────────────
+//
+// CDR layout: timestamp(Time), frame_id(string),
+//   pose(Pose, 8-aligned) → offsets[0], start_angle(f64), end_angle(f64),
+//   uint32 count + float64[] ranges → offsets[1],
+//   uint32 count + float64[] intensities → offsets[2]
+//
+// Unlike sensor_msgs/LaserScan the beam angles are implied: ranges are
+// spread evenly from start_angle to end_angle, both inclusive.
+
+pub struct FoxgloveLaserScan<B> {
+    buf: B,
+    offsets: [usize; 3],
+}
+
+impl<B> FoxgloveLaserScan<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxgloveLaserScan<C> {
+        FoxgloveLaserScan {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxgloveLaserScan<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), header.end_offset());
+        c.align(8);
+        let o0 = c.offset();
+        Pose::read_cdr(&mut c)?;
+        c.read_f64()?;
+        c.read_f64()?;
+        let o1 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 8).field("ranges")?;
+        for _ in 0..n {
+            c.read_f64()?;
+        }
+        let o2 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 8).field("intensities")?;
+        for _ in 0..n {
+            c.read_f64()?;
+        }
+        c.expect_end()?;
+        Ok(FoxgloveLaserScan {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
+
+    pub fn timestamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    /// Origin of the scan within `frame_id`.
+    pub fn pose(&self) -> Pose {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0]);
+        Pose::read_cdr(&mut c).expect("pose validated during from_cdr")
+    }
+    pub fn start_angle(&self) -> f64 {
+        rd_f64(self.buf.as_ref(), self.offsets[0] + Pose::CDR_SIZE)
+    }
+    pub fn end_angle(&self) -> f64 {
+        rd_f64(self.buf.as_ref(), self.offsets[0] + Pose::CDR_SIZE + 8)
+    }
+    pub fn ranges_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[1])
+    }
+    pub fn ranges(&self) -> Vec<f64> {
+        self.f64_seq(self.offsets[1])
+    }
+    pub fn intensities_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[2])
+    }
+    pub fn intensities(&self) -> Vec<f64> {
+        self.f64_seq(self.offsets[2])
+    }
+    fn f64_seq(&self, at: usize) -> Vec<f64> {
+        let b = self.buf.as_ref();
+        let n = rd_u32(b, at) as usize;
+        let start = cdr_align(at + 4, 8);
+        (0..n).map(|i| rd_f64(b, start + 8 * i)).collect()
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxgloveLaserScan<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxgloveLaserScanBuilder` with zero-valued defaults
+    /// and an identity pose.
+    pub fn builder<'a>() -> FoxgloveLaserScanBuilder<'a> {
+        FoxgloveLaserScanBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FoxgloveLaserScan<B> {
+    pub fn set_timestamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── FoxgloveLaserScanBuilder<'a> ────────────────────────────────────
+
+/// Builder for `FoxgloveLaserScan<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `ranges` and `intensities` are borrowed for zero-copy input.
+pub struct FoxgloveLaserScanBuilder<'a> {
+    timestamp: Time,
+    frame_id: Cow<'a, str>,
+    pose: Pose,
+    start_angle: f64,
+    end_angle: f64,
+    ranges: &'a [f64],
+    intensities: &'a [f64],
+}
+
+impl<'a> Default for FoxgloveLaserScanBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            timestamp: Time::new(0, 0),
+            frame_id: Cow::Borrowed(""),
+            pose: IDENTITY_POSE,
+            start_angle: 0.0,
+            end_angle: 0.0,
+            ranges: &[],
+            intensities: &[],
+        }
+    }
+}
+
+impl<'a> FoxgloveLaserScanBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp(&mut self, t: Time) -> &mut Self {
+        self.timestamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn pose(&mut self, p: Pose) -> &mut Self {
+        self.pose = p;
+        self
+    }
+    pub fn start_angle(&mut self, v: f64) -> &mut Self {
+        self.start_angle = v;
+        self
+    }
+    pub fn end_angle(&mut self, v: f64) -> &mut Self {
+        self.end_angle = v;
+        self
+    }
+    pub fn ranges(&mut self, v: &'a [f64]) -> &mut Self {
+        self.ranges = v;
+        self
+    }
+    pub fn intensities(&mut self, v: &'a [f64]) -> &mut Self {
+        self.intensities = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        Pose::size_cdr(&mut s);
+        s.size_f64();
+        s.size_f64();
+        s.size_u32();
+        for _ in self.ranges {
+            s.size_f64();
+        }
+        s.size_u32();
+        for _ in self.intensities {
+            s.size_f64();
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.timestamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        self.pose.write_cdr(&mut w);
+        w.write_f64(self.start_angle);
+        w.write_f64(self.end_angle);
+        w.write_u32(self.ranges.len() as u32);
+        for v in self.ranges {
+            w.write_f64(*v);
+        }
+        w.write_u32(self.intensities.len() as u32);
+        for v in self.intensities {
+            w.write_f64(*v);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxgloveLaserScan<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxgloveLaserScan::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── FoxgloveLocationFix<B> — foxglove_msgs/msg/LocationFix ──────────
+//
+// CDR layout: timestamp(Time), frame_id(string),
+//   latitude(f64, 8-aligned) → offsets[0], longitude(f64), altitude(f64),
+//   position_covariance([f64; 9]), position_covariance_type(u8)
+
+pub struct FoxgloveLocationFix<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> FoxgloveLocationFix<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxgloveLocationFix<C> {
+        FoxgloveLocationFix {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxgloveLocationFix<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), header.end_offset());
+        c.align(8);
+        let o0 = c.offset();
+        c.read_f64().field("latitude")?;
+        c.read_f64().field("longitude")?;
+        c.read_f64().field("altitude")?;
+        read_f64_array9(&mut c).field("position_covariance")?;
+        c.read_u8().field("position_covariance_type")?;
+        c.expect_end()?;
+        Ok(FoxgloveLocationFix { offsets: [o0], buf })
+    }
+
+    pub fn timestamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    /// Degrees north of the equator.
+    pub fn latitude(&self) -> f64 {
+        rd_f64(self.buf.as_ref(), self.offsets[0])
+    }
+    /// Degrees east of the prime meridian.
+    pub fn longitude(&self) -> f64 {
+        rd_f64(self.buf.as_ref(), self.offsets[0] + 8)
+    }
+    /// Metres above the WGS 84 ellipsoid.
+    pub fn altitude(&self) -> f64 {
+        rd_f64(self.buf.as_ref(), self.offsets[0] + 16)
+    }
+    /// Row-major ENU position covariance in m².
+    pub fn position_covariance(&self) -> [f64; 9] {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0] + 24);
+        read_f64_array9(&mut c).expect("covariance field validated during from_cdr")
+    }
+    /// One of [`position_covariance_type`].
+    pub fn position_covariance_type(&self) -> u8 {
+        rd_u8(self.buf.as_ref(), self.offsets[0] + 96)
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxgloveLocationFix<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxgloveLocationFixBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> FoxgloveLocationFixBuilder<'a> {
+        FoxgloveLocationFixBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FoxgloveLocationFix<B> {
+    pub fn set_timestamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+impl<B: AsRef<[u8]>> NavSatFix<B> {
+    /// The fix as a Foxglove `LocationFix` for the map panel. Position,
+    /// covariance and its type carry over unchanged (both schemas use the
+    /// same covariance type values); the receiver status is dropped.
+    ///
+    /// Fails only if the fix exceeds the current
+    /// [`DecodeLimits`](crate::cdr::DecodeLimits).
+    pub fn to_location_fix(&self) -> Result<FoxgloveLocationFix<Vec<u8>>, CdrError> {
+        FoxgloveLocationFix::builder()
+            .timestamp(self.stamp())
+            .frame_id(self.frame_id())
+            .latitude(self.latitude())
+            .longitude(self.longitude())
+            .altitude(self.altitude())
+            .position_covariance(self.position_covariance())
+            .position_covariance_type(self.position_covariance_type())
+            .build()
+    }
+}
+
+// ── FoxgloveLocationFixBuilder<'a> ──────────────────────────────────
+
+/// Builder for `FoxgloveLocationFix<Vec<u8>>` with buffer-reuse finalizers.
+pub struct FoxgloveLocationFixBuilder<'a> {
+    timestamp: Time,
+    frame_id: Cow<'a, str>,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    position_covariance: [f64; 9],
+    position_covariance_type: u8,
+}
+
+impl<'a> Default for FoxgloveLocationFixBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            timestamp: Time::new(0, 0),
+            frame_id: Cow::Borrowed(""),
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: 0.0,
+            position_covariance: [0.0; 9],
+            position_covariance_type: position_covariance_type::UNKNOWN,
+        }
+    }
+}
+
+impl<'a> FoxgloveLocationFixBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp(&mut self, t: Time) -> &mut Self {
+        self.timestamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn latitude(&mut self, v: f64) -> &mut Self {
+        self.latitude = v;
+        self
+    }
+    pub fn longitude(&mut self, v: f64) -> &mut Self {
+        self.longitude = v;
+        self
+    }
+    pub fn altitude(&mut self, v: f64) -> &mut Self {
+        self.altitude = v;
+        self
+    }
+    pub fn position_covariance(&mut self, v: [f64; 9]) -> &mut Self {
+        self.position_covariance = v;
+        self
+    }
+    pub fn position_covariance_type(&mut self, v: u8) -> &mut Self {
+        self.position_covariance_type = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_f64();
+        s.size_f64();
+        s.size_f64();
+        size_f64_array9(&mut s);
+        s.size_u8();
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.timestamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_f64(self.latitude);
+        w.write_f64(self.longitude);
+        w.write_f64(self.altitude);
+        write_f64_array9(&mut w, &self.position_covariance);
+        w.write_u8(self.position_covariance_type);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxgloveLocationFix<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxgloveLocationFix::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── FoxgloveLog<B> — foxglove_msgs/msg/Log ──────────────────────────
+//
+// CDR layout: timestamp(Time), level(u8), message(string) → offsets[0],
+//   name(string) → offsets[1], file(string) → offsets[2], line(u32)
+
+pub struct FoxgloveLog<B> {
+    buf: B,
+    offsets: [usize; 3],
+}
+
+impl<B> FoxgloveLog<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxgloveLog<C> {
+        FoxgloveLog {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxgloveLog<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        Time::read_cdr(&mut c)?;
+        FoxgloveLogLevel::read_cdr(&mut c).field("level")?;
+        let _ = c.read_string().field("message")?;
+        let o0 = c.offset();
+        let _ = c.read_string().field("name")?;
+        let o1 = c.offset();
+        let _ = c.read_string().field("file")?;
+        let o2 = c.offset();
+        c.read_u32().field("line")?;
+        c.expect_end()?;
+        Ok(FoxgloveLog {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
+
+    pub fn timestamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn level(&self) -> FoxgloveLogLevel {
+        FoxgloveLogLevel::from_repr(rd_u8(self.buf.as_ref(), CDR_HEADER_SIZE + 8))
+            .expect("level validated during from_cdr")
+    }
+    pub fn message(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 12).0
+    }
+    /// Process or node that logged the message.
+    pub fn name(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[0]).0
+    }
+    /// Source file of the log call, empty when unknown.
+    pub fn file(&self) -> &str {
+        rd_string(self.buf.as_ref(), self.offsets[1]).0
+    }
+    /// Source line of the log call, 0 when unknown.
+    pub fn line(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), cdr_align(self.offsets[2], 4))
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxgloveLog<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxgloveLogBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> FoxgloveLogBuilder<'a> {
+        FoxgloveLogBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FoxgloveLog<B> {
+    pub fn set_timestamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── FoxgloveLogBuilder<'a> ──────────────────────────────────────────
+
+/// Builder for `FoxgloveLog<Vec<u8>>` with buffer-reuse finalizers.
+pub struct FoxgloveLogBuilder<'a> {
+    timestamp: Time,
+    level: FoxgloveLogLevel,
+    message: Cow<'a, str>,
+    name: Cow<'a, str>,
+    file: Cow<'a, str>,
+    line: u32,
+}
+
+impl<'a> Default for FoxgloveLogBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            timestamp: Time::new(0, 0),
+            level: FoxgloveLogLevel::Unknown,
+            message: Cow::Borrowed(""),
+            name: Cow::Borrowed(""),
+            file: Cow::Borrowed(""),
+            line: 0,
+        }
+    }
+}
+
+impl<'a> FoxgloveLogBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp(&mut self, t: Time) -> &mut Self {
+        self.timestamp = t;
+        self
+    }
+    pub fn level(&mut self, l: FoxgloveLogLevel) -> &mut Self {
+        self.level = l;
+        self
+    }
+    pub fn message(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.message = s.into();
+        self
+    }
+    pub fn name(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.name = s.into();
+        self
+    }
+    pub fn file(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.file = s.into();
+        self
+    }
+    pub fn line(&mut self, v: u32) -> &mut Self {
+        self.line = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        FoxgloveLogLevel::size_cdr(&mut s);
+        s.size_string(&self.message);
+        s.size_string(&self.name);
+        s.size_string(&self.file);
+        s.size_u32();
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.timestamp.write_cdr(&mut w);
+        self.level.write_cdr(&mut w);
+        w.write_string(&self.message);
+        w.write_string(&self.name);
+        w.write_string(&self.file);
+        w.write_u32(self.line);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxgloveLog<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxgloveLog::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── FoxgloveCameraCalibration<B> — foxglove_msgs/msg/CameraCalibration
+//
+// CDR layout: timestamp(Time), frame_id(string) → offsets[0],
+//   width(u32), height(u32), distortion_model(string) → offsets[1],
+//   d(Vec<f64>) → offsets[2], k[9], r[9], p[12]
+//
+// Same model as sensor_msgs/CameraInfo, with width before height and
+// without binning or region of interest.
+
+pub struct FoxgloveCameraCalibration<B> {
+    buf: B,
+    offsets: [usize; 3],
+}
+
+impl<B> FoxgloveCameraCalibration<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxgloveCameraCalibration<C> {
+        FoxgloveCameraCalibration {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxgloveCameraCalibration<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_u32().field("width")?;
+        c.read_u32().field("height")?;
+        let _ = c.read_string().field("distortion_model")?;
+        let o1 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 8).field("d")?;
+        c.skip_seq_8(n)?;
+        let o2 = c.offset();
+        read_f64_array9(&mut c).field("k")?;
+        read_f64_array9(&mut c).field("r")?;
+        read_f64_array12(&mut c).field("p")?;
+        c.expect_end()?;
+        Ok(FoxgloveCameraCalibration {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
+
+    pub fn timestamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn width(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), cdr_align(self.offsets[0], 4))
+    }
+    pub fn height(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), cdr_align(self.offsets[0], 4) + 4)
+    }
+    pub fn distortion_model(&self) -> &str {
+        rd_string(self.buf.as_ref(), cdr_align(self.offsets[0], 4) + 8).0
+    }
+
+    /// Number of distortion coefficients.
+    pub fn d_len(&self) -> usize {
+        rd_u32(self.buf.as_ref(), cdr_align(self.offsets[1], 4)) as usize
+    }
+    /// Read the i-th distortion coefficient.
+    pub fn d_get(&self, i: usize) -> f64 {
+        let start = cdr_align(cdr_align(self.offsets[1], 4) + 4, 8);
+        rd_f64(self.buf.as_ref(), start + i * 8)
+    }
+
+    fn fixed_base(&self) -> usize {
+        cdr_align(self.offsets[2], 8)
+    }
+    pub fn k(&self) -> [f64; 9] {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.fixed_base());
+        read_f64_array9(&mut c).expect("intrinsic matrix validated during from_cdr")
+    }
+    pub fn r(&self) -> [f64; 9] {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.fixed_base() + 72);
+        read_f64_array9(&mut c).expect("rectification matrix validated during from_cdr")
+    }
+    pub fn p(&self) -> [f64; 12] {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.fixed_base() + 144);
+        read_f64_array12(&mut c).expect("projection matrix validated during from_cdr")
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxgloveCameraCalibration<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxgloveCameraCalibrationBuilder` with zero-valued
+    /// defaults.
+    pub fn builder<'a>() -> FoxgloveCameraCalibrationBuilder<'a> {
+        FoxgloveCameraCalibrationBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FoxgloveCameraCalibration<B> {
+    pub fn set_timestamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+impl<B: AsRef<[u8]>> CameraInfo<B> {
+    /// The calibration as a Foxglove `CameraCalibration`.
+    ///
+    /// Without binning or a region of interest (the usual case) this is
+    /// lossless: D, K, R and P are copied bit for bit. Otherwise, since
+    /// Foxglove has no fields for them, they are folded into K, P and the
+    /// image size the way `image_geometry` does, so the result describes
+    /// the published (cropped, binned) image.
+    ///
+    /// Fails only if the calibration exceeds the current
+    /// [`DecodeLimits`](crate::cdr::DecodeLimits).
+    pub fn to_camera_calibration(&self) -> Result<FoxgloveCameraCalibration<Vec<u8>>, CdrError> {
+        let d: Vec<f64> = (0..self.d_len()).map(|i| self.d_get(i)).collect();
+        let (mut k, mut p) = (self.k(), self.p());
+        let (mut width, mut height) = (self.width(), self.height());
+        let roi = self.roi();
+        if roi.width != 0 && roi.height != 0 {
+            let (x, y) = (roi.x_offset as f64, roi.y_offset as f64);
+            k[2] -= x;
+            k[5] -= y;
+            p[2] -= x;
+            p[6] -= y;
+            width = roi.width;
+            height = roi.height;
+        }
+        let bx = self.binning_x().max(1);
+        let by = self.binning_y().max(1);
+        if bx > 1 {
+            let s = bx as f64;
+            for i in [0, 2] {
+                k[i] /= s;
+            }
+            for i in [0, 2, 3] {
+                p[i] /= s;
+            }
+            width /= bx;
+        }
+        if by > 1 {
+            let s = by as f64;
+            for i in [4, 5] {
+                k[i] /= s;
+            }
+            for i in [5, 6, 7] {
+                p[i] /= s;
+            }
+            height /= by;
+        }
+        FoxgloveCameraCalibration::builder()
+            .timestamp(self.stamp())
+            .frame_id(self.frame_id())
+            .width(width)
+            .height(height)
+            .distortion_model(self.distortion_model())
+            .d(&d)
+            .k(k)
+            .r(self.r())
+            .p(p)
+            .build()
+    }
+}
+
+// ── FoxgloveCameraCalibrationBuilder<'a> ────────────────────────────
+
+/// Builder for `FoxgloveCameraCalibration<Vec<u8>>` with buffer-reuse
+/// finalizers.
+///
+/// `d` is borrowed for zero-copy input.
+pub struct FoxgloveCameraCalibrationBuilder<'a> {
+    timestamp: Time,
+    frame_id: Cow<'a, str>,
+    width: u32,
+    height: u32,
+    distortion_model: Cow<'a, str>,
+    d: &'a [f64],
+    k: [f64; 9],
+    r: [f64; 9],
+    p: [f64; 12],
+}
+
+impl<'a> Default for FoxgloveCameraCalibrationBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            timestamp: Time::new(0, 0),
+            frame_id: Cow::Borrowed(""),
+            width: 0,
+            height: 0,
+            distortion_model: Cow::Borrowed(""),
+            d: &[],
+            k: [0.0; 9],
+            r: [0.0; 9],
+            p: [0.0; 12],
+        }
+    }
+}
+
+impl<'a> FoxgloveCameraCalibrationBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp(&mut self, t: Time) -> &mut Self {
+        self.timestamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn width(&mut self, v: u32) -> &mut Self {
+        self.width = v;
+        self
+    }
+    pub fn height(&mut self, v: u32) -> &mut Self {
+        self.height = v;
+        self
+    }
+    pub fn distortion_model(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.distortion_model = s.into();
+        self
+    }
+    pub fn d(&mut self, d: &'a [f64]) -> &mut Self {
+        self.d = d;
+        self
+    }
+    pub fn k(&mut self, k: [f64; 9]) -> &mut Self {
+        self.k = k;
+        self
+    }
+    pub fn r(&mut self, r: [f64; 9]) -> &mut Self {
+        self.r = r;
+        self
+    }
+    pub fn p(&mut self, p: [f64; 12]) -> &mut Self {
+        self.p = p;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_u32();
+        s.size_u32();
+        s.size_string(&self.distortion_model);
+        s.size_u32();
+        s.size_seq_8(self.d.len());
+        size_f64_array9(&mut s);
+        size_f64_array9(&mut s);
+        size_f64_array12(&mut s);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.timestamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.distortion_model);
+        w.write_u32(self.d.len() as u32);
+        w.write_slice_f64(self.d);
+        write_f64_array9(&mut w, &self.k);
+        write_f64_array9(&mut w, &self.r);
+        write_f64_array12(&mut w, &self.p);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxgloveCameraCalibration<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxgloveCameraCalibration::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── FoxglovePoseInFrame<B> — foxglove_msgs/msg/PoseInFrame ──────────
+//
+// CDR layout: timestamp(Time), frame_id(string),
+//   pose(Pose, 8-aligned) → offsets[0]
+
+pub struct FoxglovePoseInFrame<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> FoxglovePoseInFrame<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxglovePoseInFrame<C> {
+        FoxglovePoseInFrame {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxglovePoseInFrame<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), header.end_offset());
+        c.align(8);
+        let o0 = c.offset();
+        Pose::read_cdr(&mut c).field("pose")?;
+        c.expect_end()?;
+        Ok(FoxglovePoseInFrame { offsets: [o0], buf })
+    }
+
+    pub fn timestamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn pose(&self) -> Pose {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0]);
+        Pose::read_cdr(&mut c).expect("pose validated during from_cdr")
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxglovePoseInFrame<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxglovePoseInFrameBuilder` with an identity pose.
+    pub fn builder<'a>() -> FoxglovePoseInFrameBuilder<'a> {
+        FoxglovePoseInFrameBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FoxglovePoseInFrame<B> {
+    pub fn set_timestamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── FoxglovePoseInFrameBuilder<'a> ──────────────────────────────────
+
+/// Builder for `FoxglovePoseInFrame<Vec<u8>>` with buffer-reuse
+/// finalizers.
+pub struct FoxglovePoseInFrameBuilder<'a> {
+    timestamp: Time,
+    frame_id: Cow<'a, str>,
+    pose: Pose,
+}
+
+impl<'a> Default for FoxglovePoseInFrameBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            timestamp: Time::new(0, 0),
+            frame_id: Cow::Borrowed(""),
+            pose: IDENTITY_POSE,
+        }
+    }
+}
+
+impl<'a> FoxglovePoseInFrameBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp(&mut self, t: Time) -> &mut Self {
+        self.timestamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn pose(&mut self, p: Pose) -> &mut Self {
+        self.pose = p;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        Pose::size_cdr(&mut s);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.timestamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        self.pose.write_cdr(&mut w);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxglovePoseInFrame<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxglovePoseInFrame::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── FoxglovePosesInFrame<B> — foxglove_msgs/msg/PosesInFrame ────────
+//
+// CDR layout: timestamp(Time), frame_id(string),
+//   uint32 count → offsets[0] + Pose[] poses (8-aligned)
+
+pub struct FoxglovePosesInFrame<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> FoxglovePosesInFrame<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxglovePosesInFrame<C> {
+        FoxglovePosesInFrame {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxglovePosesInFrame<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), header.end_offset());
+        c.align(4);
+        let o0 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, Pose::CDR_SIZE).field("poses")?;
+        for _ in 0..n {
+            Pose::read_cdr(&mut c)?;
+        }
+        c.expect_end()?;
+        Ok(FoxglovePosesInFrame { offsets: [o0], buf })
+    }
+
+    pub fn timestamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn poses_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn poses(&self) -> Vec<Pose> {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0] + 4);
+        (0..self.poses_len())
+            .map(|_| Pose::read_cdr(&mut c).expect("poses validated during from_cdr"))
+            .collect()
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxglovePosesInFrame<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxglovePosesInFrameBuilder` with no poses.
+    pub fn builder<'a>() -> FoxglovePosesInFrameBuilder<'a> {
+        FoxglovePosesInFrameBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FoxglovePosesInFrame<B> {
+    pub fn set_timestamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── FoxglovePosesInFrameBuilder<'a> ─────────────────────────────────
+
+/// Builder for `FoxglovePosesInFrame<Vec<u8>>` with buffer-reuse
+/// finalizers.
+///
+/// `poses` is borrowed for zero-copy input.
+pub struct FoxglovePosesInFrameBuilder<'a> {
+    timestamp: Time,
+    frame_id: Cow<'a, str>,
+    poses: &'a [Pose],
+}
+
+impl<'a> Default for FoxglovePosesInFrameBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            timestamp: Time::new(0, 0),
+            frame_id: Cow::Borrowed(""),
+            poses: &[],
+        }
+    }
+}
+
+impl<'a> FoxglovePosesInFrameBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp(&mut self, t: Time) -> &mut Self {
+        self.timestamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn poses(&mut self, v: &'a [Pose]) -> &mut Self {
+        self.poses = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        size_fixed_seq(&mut s, self.poses);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.timestamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        write_fixed_seq(&mut w, self.poses);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxglovePosesInFrame<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxglovePosesInFrame::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── FoxgloveGeoJson<B> — foxglove_msgs/msg/GeoJSON ──────────────────
+//
+// CDR layout: geojson(string)
+
+pub struct FoxgloveGeoJson<B> {
+    buf: B,
+}
+
+impl<B> FoxgloveGeoJson<B> {
+    /// Convert the buffer type.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxgloveGeoJson<C> {
+        FoxgloveGeoJson { buf: f(self.buf) }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxgloveGeoJson<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        let _ = c.read_string().field("geojson")?;
+        c.expect_end()?;
+        Ok(FoxgloveGeoJson { buf })
+    }
+
+    /// The GeoJSON document; its content is not validated.
+    pub fn geojson(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE).0
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxgloveGeoJson<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxgloveGeoJsonBuilder` with an empty document.
+    pub fn builder<'a>() -> FoxgloveGeoJsonBuilder<'a> {
+        FoxgloveGeoJsonBuilder::new()
+    }
+}
+
+// ── FoxgloveGeoJsonBuilder<'a> ──────────────────────────────────────
+
+/// Builder for `FoxgloveGeoJson<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// Pass a [`GeoJsonFeatures`] collection or any GeoJSON text.
+#[derive(Default)]
+pub struct FoxgloveGeoJsonBuilder<'a> {
+    geojson: Cow<'a, str>,
+}
+
+impl<'a> FoxgloveGeoJsonBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn geojson(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.geojson = s.into();
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        s.size_string(&self.geojson);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        w.write_string(&self.geojson);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxgloveGeoJson<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxgloveGeoJson::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── GeoJsonFeatures ─────────────────────────────────────────────────
+
+/// Accumulates GeoJSON features (points, traces, fences) into a
+/// `FeatureCollection` for [`FoxgloveGeoJsonBuilder::geojson`].
+///
+/// Positions are `(longitude, latitude)` in degrees, the GeoJSON order.
+/// Properties become string members of each feature's `properties`.
+/// Non-finite coordinates are written as `null`.
+#[derive(Clone, Debug, Default)]
+pub struct GeoJsonFeatures {
+    features: Vec<String>,
+}
+
+impl GeoJsonFeatures {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A single position, e.g. a waypoint.
+    pub fn point(&mut self, position: (f64, f64), properties: &[(&str, &str)]) -> &mut Self {
+        let mut coords = String::new();
+        push_position(&mut coords, position);
+        self.push("Point", &coords, properties)
+    }
+
+    /// An open path, e.g. a GPS trace.
+    pub fn line_string(
+        &mut self,
+        positions: &[(f64, f64)],
+        properties: &[(&str, &str)],
+    ) -> &mut Self {
+        let mut coords = String::new();
+        push_positions(&mut coords, positions, false);
+        self.push("LineString", &coords, properties)
+    }
+
+    /// A closed area, e.g. a geofence. The ring is closed automatically
+    /// when its last position differs from its first.
+    pub fn polygon(&mut self, ring: &[(f64, f64)], properties: &[(&str, &str)]) -> &mut Self {
+        let mut coords = String::from("[");
+        push_positions(&mut coords, ring, true);
+        coords.push(']');
+        self.push("Polygon", &coords, properties)
+    }
+
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// The `FeatureCollection` document.
+    pub fn to_geojson(&self) -> String {
+        let mut out = String::from(r#"{"type":"FeatureCollection","features":["#);
+        out.push_str(&self.features.join(","));
+        out.push_str("]}");
+        out
+    }
+
+    fn push(&mut self, kind: &str, coords: &str, properties: &[(&str, &str)]) -> &mut Self {
+        let mut f = String::from(r#"{"type":"Feature","geometry":{"type":""#);
+        f.push_str(kind);
+        f.push_str(r#"","coordinates":"#);
+        f.push_str(coords);
+        f.push_str(r#"},"properties":{"#);
+        for (i, (key, value)) in properties.iter().enumerate() {
+            if i > 0 {
+                f.push(',');
+            }
+            push_json_string(&mut f, key);
+            f.push(':');
+            push_json_string(&mut f, value);
+        }
+        f.push_str("}}");
+        self.features.push(f);
+        self
+    }
+}
+
+fn push_number(out: &mut String, v: f64) {
+    if v.is_finite() {
+        let _ = write!(out, "{}", v);
+    } else {
+        out.push_str("null");
+    }
+}
+
+fn push_position(out: &mut String, (lon, lat): (f64, f64)) {
+    out.push('[');
+    push_number(out, lon);
+    out.push(',');
+    push_number(out, lat);
+    out.push(']');
+}
+
+fn push_positions(out: &mut String, positions: &[(f64, f64)], close: bool) {
+    out.push('[');
+    for (i, p) in positions.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        push_position(out, *p);
+    }
+    if let (true, Some(first), Some(last)) = (close, positions.first(), positions.last()) {
+        if first != last {
+            out.push(',');
+            push_position(out, *first);
+        }
+    }
+    out.push(']');
+}
+
+fn push_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// ── FoxgloveRawImage<B> — foxglove_msgs/msg/RawImage ────────────────
+//
+// CDR layout: timestamp(Time), frame_id(string) → offsets[0],
+//   width(u32), height(u32), encoding(string) → offsets[1],
+//   step(u32), data(byte seq)
+//
+// Like sensor_msgs/Image without `is_bigendian`: multi-byte pixels are
+// always little-endian.
+
+pub struct FoxgloveRawImage<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
+
+impl<B> FoxgloveRawImage<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> FoxgloveRawImage<C> {
+        FoxgloveRawImage {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> FoxgloveRawImage<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_u32().field("width")?;
+        c.read_u32().field("height")?;
+        let _ = c.read_string().field("encoding")?;
+        let o1 = c.offset();
+        c.read_u32().field("step")?;
+        let _ = c.read_bytes().field("data")?;
+        c.expect_end()?;
+        Ok(FoxgloveRawImage {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
+
+    pub fn timestamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn width(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), cdr_align(self.offsets[0], 4))
+    }
+    pub fn height(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), cdr_align(self.offsets[0], 4) + 4)
+    }
+    pub fn encoding(&self) -> &str {
+        rd_string(self.buf.as_ref(), cdr_align(self.offsets[0], 4) + 8).0
+    }
+    pub fn step(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), cdr_align(self.offsets[1], 4))
+    }
+    pub fn data(&self) -> &[u8] {
+        rd_bytes(self.buf.as_ref(), cdr_align(self.offsets[1], 4) + 4).0
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl FoxgloveRawImage<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `FoxgloveRawImageBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> FoxgloveRawImageBuilder<'a> {
+        FoxgloveRawImageBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> FoxgloveRawImage<B> {
+    pub fn set_timestamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+/// The Foxglove `RawImage` encoding for a `sensor_msgs/Image` encoding,
+/// or `None` when Foxglove cannot display it.
+///
+/// Names shared by both (`rgb8`, `mono16`, `32FC1`, the 8-bit Bayer
+/// patterns, …) map to themselves; the ROS packed-YUV names map to
+/// Foxglove's `uyvy` (`yuv422`) and `yuyv` (`yuv422_yuy2`).
+pub fn raw_image_encoding(ros_encoding: &str) -> Option<&'static str> {
+    Some(match ros_encoding {
+        "rgb8" => "rgb8",
+        "rgba8" => "rgba8",
+        "bgr8" => "bgr8",
+        "bgra8" => "bgra8",
+        "mono8" => "mono8",
+        "mono16" => "mono16",
+        "8UC1" => "8UC1",
+        "8UC3" => "8UC3",
+        "16UC1" => "16UC1",
+        "32FC1" => "32FC1",
+        "bayer_rggb8" => "bayer_rggb8",
+        "bayer_bggr8" => "bayer_bggr8",
+        "bayer_gbrg8" => "bayer_gbrg8",
+        "bayer_grbg8" => "bayer_grbg8",
+        "yuv422" | "uyvy" => "uyvy",
+        "yuv422_yuy2" | "yuyv" => "yuyv",
+        _ => return None,
+    })
+}
+
+impl<B: AsRef<[u8]>> Image<B> {
+    /// The image as a Foxglove `RawImage`, or `None` when its encoding has
+    /// no Foxglove equivalent (see [`raw_image_encoding`]).
+    ///
+    /// Pixels are copied unchanged, except that big-endian 16- and 32-bit
+    /// images are byte-swapped since `RawImage` is always little-endian.
+    /// Fails only if the image exceeds the current
+    /// [`DecodeLimits`](crate::cdr::DecodeLimits).
+    pub fn to_raw_image(&self) -> Result<Option<FoxgloveRawImage<Vec<u8>>>, CdrError> {
+        let Some(encoding) = raw_image_encoding(self.encoding()) else {
+            return Ok(None);
+        };
+        let sample_size = match encoding {
+            "mono16" | "16UC1" => 2,
+            "32FC1" => 4,
+            _ => 1,
+        };
+        let data: Cow<'_, [u8]> = if self.is_bigendian() != 0 && sample_size > 1 {
+            let mut d = self.data().to_vec();
+            for sample in d.chunks_exact_mut(sample_size) {
+                sample.reverse();
+            }
+            Cow::Owned(d)
+        } else {
+            Cow::Borrowed(self.data())
+        };
+        let image = FoxgloveRawImage::builder()
+            .timestamp(self.stamp())
+            .frame_id(self.frame_id())
+            .width(self.width())
+            .height(self.height())
+            .encoding(encoding)
+            .step(self.step())
+            .data(&data)
+            .build()?;
+        Ok(Some(image))
+    }
+}
+
+// ── FoxgloveRawImageBuilder<'a> ─────────────────────────────────────
+
+/// Builder for `FoxgloveRawImage<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// Pixel `data` is borrowed for zero-copy input.
+pub struct FoxgloveRawImageBuilder<'a> {
+    timestamp: Time,
+    frame_id: Cow<'a, str>,
+    width: u32,
+    height: u32,
+    encoding: Cow<'a, str>,
+    step: u32,
+    data: &'a [u8],
+}
+
+impl<'a> Default for FoxgloveRawImageBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            timestamp: Time::new(0, 0),
+            frame_id: Cow::Borrowed(""),
+            width: 0,
+            height: 0,
+            encoding: Cow::Borrowed(""),
+            step: 0,
+            data: &[],
+        }
+    }
+}
+
+impl<'a> FoxgloveRawImageBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timestamp(&mut self, t: Time) -> &mut Self {
+        self.timestamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn width(&mut self, v: u32) -> &mut Self {
+        self.width = v;
+        self
+    }
+    pub fn height(&mut self, v: u32) -> &mut Self {
+        self.height = v;
+        self
+    }
+    pub fn encoding(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.encoding = s.into();
+        self
+    }
+    pub fn step(&mut self, v: u32) -> &mut Self {
+        self.step = v;
+        self
+    }
+    pub fn data(&mut self, d: &'a [u8]) -> &mut Self {
+        self.data = d;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_u32();
+        s.size_u32();
+        s.size_string(&self.encoding);
+        s.size_u32();
+        s.size_bytes(self.data.len());
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.timestamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.encoding);
+        w.write_u32(self.step);
+        w.write_bytes(self.data);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<FoxgloveRawImage<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        FoxgloveRawImage::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── Registry ────────────────────────────────────────────────────────
+
+/// Check if a type name is supported by this module.
+pub fn is_type_supported(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "CameraCalibration"
+            | "CompressedVideo"
+            | "GeoJSON"
+            | "LaserScan"
+            | "LocationFix"
+            | "Log"
+            | "PoseInFrame"
+            | "PosesInFrame"
+            | "RawImage"
+            | "SceneEntity"
+            | "SceneUpdate"
+    )
+}
+
+/// List all type schema names in this module.
+pub fn list_types() -> &'static [&'static str] {
+    &[
+        "foxglove_msgs/msg/CameraCalibration",
+        "foxglove_msgs/msg/CompressedVideo",
+        "foxglove_msgs/msg/GeoJSON",
+        "foxglove_msgs/msg/LaserScan",
+        "foxglove_msgs/msg/LocationFix",
+        "foxglove_msgs/msg/Log",
+        "foxglove_msgs/msg/PoseInFrame",
+        "foxglove_msgs/msg/PosesInFrame",
+        "foxglove_msgs/msg/RawImage",
+        "foxglove_msgs/msg/SceneEntity",
+        "foxglove_msgs/msg/SceneUpdate",
+    ]
+}
+
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "CameraCalibration" => {
+            "\
+builtin_interfaces/Time timestamp
+string frame_id
+uint32 width
+uint32 height
+string distortion_model
+float64[] d
+float64[9] k
+float64[9] r
+float64[12] p
+"
+        }
+        "CompressedVideo" => {
+            "\
+builtin_interfaces/Time timestamp
+string frame_id
+uint8[] data
+string format
+"
+        }
+        "GeoJSON" => "string geojson\n",
+        "LaserScan" => {
+            "\
+builtin_interfaces/Time timestamp
+string frame_id
+geometry_msgs/Pose pose
+float64 start_angle
+float64 end_angle
+float64[] ranges
+float64[] intensities
+"
+        }
+        "LocationFix" => {
+            "\
+builtin_interfaces/Time timestamp
+string frame_id
+float64 latitude
+float64 longitude
+float64 altitude
+float64[9] position_covariance
+uint8 position_covariance_type
+"
+        }
+        "Log" => {
+            "\
+builtin_interfaces/Time timestamp
+uint8 level
+string message
+string name
+string file
+uint32 line
+"
+        }
+        "PoseInFrame" => {
+            "builtin_interfaces/Time timestamp\nstring frame_id\ngeometry_msgs/Pose pose\n"
+        }
+        "PosesInFrame" => {
+            "builtin_interfaces/Time timestamp\nstring frame_id\ngeometry_msgs/Pose[] poses\n"
+        }
+        "RawImage" => {
+            "\
+builtin_interfaces/Time timestamp
+string frame_id
+uint32 width
+uint32 height
+string encoding
+uint32 step
+uint8[] data
+"
+        }
+        "SceneUpdate" => "SceneEntityDeletion[] deletions\nSceneEntity[] entities\n",
+        "SceneEntity" => {
+            "\
+builtin_interfaces/Time timestamp
+string frame_id
+string id
+builtin_interfaces/Duration lifetime
+bool frame_locked
+KeyValuePair[] metadata
+ArrowPrimitive[] arrows
+CubePrimitive[] cubes
+SpherePrimitive[] spheres
+CylinderPrimitive[] cylinders
+LinePrimitive[] lines
+TriangleListPrimitive[] triangles
+TextPrimitive[] texts
+ModelPrimitive[] models
+"
+        }
+        "SceneEntityDeletion" => "builtin_interfaces/Time timestamp\nuint8 type\nstring id\n",
+        "KeyValuePair" => "string key\nstring value\n",
+        "ArrowPrimitive" => {
+            "\
+geometry_msgs/Pose pose
+float64 shaft_length
+float64 shaft_diameter
+float64 head_length
+float64 head_diameter
+FoxgloveColor color
+"
+        }
+        "CubePrimitive" | "SpherePrimitive" => {
+            "geometry_msgs/Pose pose\ngeometry_msgs/Vector3 size\nFoxgloveColor color\n"
+        }
+        "CylinderPrimitive" => {
+            "\
+geometry_msgs/Pose pose
+geometry_msgs/Vector3 size
+float64 bottom_scale
+float64 top_scale
+FoxgloveColor color
+"
+        }
+        "LinePrimitive" => {
+            "\
+uint8 type
+geometry_msgs/Pose pose
+float64 thickness
+bool scale_invariant
+geometry_msgs/Point[] points
+FoxgloveColor color
+FoxgloveColor[] colors
+uint32[] indices
+"
+        }
+        "TriangleListPrimitive" => {
+            "\
+geometry_msgs/Pose pose
+geometry_msgs/Point[] points
+FoxgloveColor color
+FoxgloveColor[] colors
+uint32[] indices
+"
+        }
+        "TextPrimitive" => {
+            "\
+geometry_msgs/Pose pose
+bool billboard
+float64 font_size
+bool scale_invariant
+FoxgloveColor color
+string text
+"
+        }
+        "ModelPrimitive" => {
+            "\
+geometry_msgs/Pose pose
+geometry_msgs/Vector3 scale
+FoxgloveColor color
+bool override_color
+string url
+string media_type
+uint8[] data
+"
+        }
+        // The annotation types are not registered schemas yet, but their
+        // layouts are known so they can be transcoded and generated.
+        "FoxglovePoint2" => "float64 x\nfloat64 y\n",
+        "FoxgloveColor" => "float64 r\nfloat64 g\nfloat64 b\nfloat64 a\n",
+        "FoxgloveCircleAnnotations" => {
+            "\
+builtin_interfaces/Time timestamp
+FoxglovePoint2 position
+float64 diameter
+float64 thickness
+FoxgloveColor fill_color
+FoxgloveColor outline_color
+"
+        }
+        "TextAnnotation" => {
+            "\
+builtin_interfaces/Time timestamp
+FoxglovePoint2 position
+string text
+float64 font_size
+FoxgloveColor text_color
+FoxgloveColor background_color
+"
+        }
+        "PointsAnnotation" => {
+            "\
+builtin_interfaces/Time timestamp
+uint8 type
+FoxglovePoint2[] points
+FoxgloveColor outline_color
+FoxgloveColor[] outline_colors
+FoxgloveColor fill_color
+float64 thickness
+"
+        }
+        "ImageAnnotations" => {
+            "\
+FoxgloveCircleAnnotations[] circles
+PointsAnnotation[] points
+TextAnnotation[] texts
+"
+        }
+        _ => return None,
+    })
+}
+
+// CdrEncode implementations
+crate::cdr::impl_cdr_encode!(
+    FoxgloveCompressedVideoBuilder,
+    FoxgloveTextAnnotationBuilder,
+    FoxglovePointAnnotationBuilder,
+    FoxgloveImageAnnotationBuilder,
+    FoxgloveSceneEntityBuilder,
+    FoxgloveSceneUpdateBuilder,
+    FoxgloveLaserScanBuilder,
+    FoxgloveLocationFixBuilder,
+    FoxgloveLogBuilder,
+    FoxgloveCameraCalibrationBuilder,
+    FoxglovePoseInFrameBuilder,
+    FoxglovePosesInFrameBuilder,
+    FoxgloveGeoJsonBuilder,
+    FoxgloveRawImageBuilder,
+);
+
+// HeapSize implementations
+crate::heap_size::impl_heap_size!(
+    FoxgloveCompressedVideo,
+    FoxgloveTextAnnotation,
+    FoxglovePointAnnotation,
+    FoxgloveImageAnnotation,
+    FoxgloveSceneEntity,
+    FoxgloveSceneUpdate,
+    FoxgloveLaserScan,
+    FoxgloveLocationFix,
+    FoxgloveLog,
+    FoxgloveCameraCalibration,
+    FoxglovePoseInFrame,
+    FoxglovePosesInFrame,
+    FoxgloveGeoJson,
+    FoxgloveRawImage,
+);
+
+// SchemaType implementations
+use crate::schema_registry::SchemaType;
+
+impl SchemaType for FoxglovePoint2 {
+    const SCHEMA_NAME: &'static str = "foxglove_msgs/msg/FoxglovePoint2";
+}
+
+impl SchemaType for FoxgloveColor {
+    const SCHEMA_NAME: &'static str = "foxglove_msgs/msg/FoxgloveColor";
+}
+
+impl SchemaType for FoxgloveCircleAnnotations {
+    const SCHEMA_NAME: &'static str = "foxglove_msgs/msg/FoxgloveCircleAnnotations";
+}
+
+#[allow(deprecated)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+    use crate::cdr::{decode_fixed, encode_fixed};
+
+    #[test]
+    fn foxglove_color_roundtrip() {
+        let cases = [
+            (0.0, 0.0, 0.0, 0.0, "transparent black"),
+            (1.0, 1.0, 1.0, 1.0, "opaque white"),
+            (1.0, 0.0, 0.0, 1.0, "red"),
+            (0.5, 0.5, 0.5, 0.75, "gray"),
+        ];
+        for (r, g, b, a, name) in cases {
+            let color = FoxgloveColor { r, g, b, a };
+            let bytes = encode_fixed(&color).unwrap();
+            let decoded: FoxgloveColor = decode_fixed(&bytes).unwrap();
+            assert_eq!(color, decoded, "failed for case: {}", name);
+        }
+    }
+
+    #[test]
+    fn foxglove_point2_roundtrip() {
+        let cases = [
+            (0.0, 0.0, "origin"),
+            (100.0, 200.0, "positive"),
+            (-50.0, -75.0, "negative"),
+        ];
+        for (x, y, name) in cases {
+            let point = FoxglovePoint2 { x, y };
+            let bytes = encode_fixed(&point).unwrap();
+            let decoded: FoxglovePoint2 = decode_fixed(&bytes).unwrap();
+            assert_eq!(point, decoded, "failed for case: {}", name);
+        }
+    }
+
+    #[test]
+    fn foxglove_circle_annotations_roundtrip() {
+        let circle = FoxgloveCircleAnnotations {
+            timestamp: Time::new(100, 0),
+            position: FoxglovePoint2 { x: 320.0, y: 240.0 },
+            diameter: 50.0,
+            thickness: 2.0,
+            fill_color: FoxgloveColor {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.5,
+            },
+            outline_color: FoxgloveColor {
+                r: 0.0,
+                g: 1.0,
+                b: 0.0,
+                a: 1.0,
+            },
+        };
+        let bytes = encode_fixed(&circle).unwrap();
+        let decoded: FoxgloveCircleAnnotations = decode_fixed(&bytes).unwrap();
+        assert_eq!(circle, decoded);
+    }
+
+    #[test]
+    fn foxglove_compressed_video_roundtrip() {
+        let video = FoxgloveCompressedVideo::new(
+            Time::new(100, 500_000_000),
+            "camera",
+            &[0x00, 0x00, 0x00, 0x01, 0x67, 0x42],
+            "h264",
+        )
+        .unwrap();
+        assert_eq!(video.stamp(), Time::new(100, 500_000_000));
+        assert_eq!(video.frame_id(), "camera");
+        assert_eq!(video.data(), &[0x00, 0x00, 0x00, 0x01, 0x67, 0x42]);
+        assert_eq!(video.format(), "h264");
+
+        let bytes = video.to_cdr();
+        let decoded = FoxgloveCompressedVideo::from_cdr(bytes).unwrap();
+        assert_eq!(decoded.format(), "h264");
+        assert_eq!(decoded.data().len(), 6);
+    }
+
+    #[test]
+    fn foxglove_compressed_video_timestamp_alias() {
+        // The `timestamp()` accessors are permanent additive aliases for `stamp()`,
+        // matching the Foxglove schema field name. Both must agree on the view side,
+        // the builder side, and the in-place setter side.
+        let mut video = FoxgloveCompressedVideo::new(
+            Time::new(100, 500_000_000),
+            "camera",
+            &[0xab, 0xcd],
+            "h264",
+        )
+        .unwrap();
+
+        // View side: stamp() and timestamp() return identical values.
+        assert_eq!(video.stamp(), video.timestamp());
+        assert_eq!(video.timestamp(), Time::new(100, 500_000_000));
+
+        // In-place setter side: set_timestamp updates what stamp() observes.
+        video.set_timestamp(Time::new(7, 9)).unwrap();
+        assert_eq!(video.stamp(), Time::new(7, 9));
+        assert_eq!(video.timestamp(), Time::new(7, 9));
+
+        // Builder side: timestamp() and stamp() setters produce equivalent output.
+        let via_stamp = FoxgloveCompressedVideoBuilder::default()
+            .stamp(Time::new(42, 100))
+            .frame_id("c")
+            .data(&[1, 2, 3])
+            .format("h264")
+            .build()
+            .unwrap();
+        let via_timestamp = FoxgloveCompressedVideoBuilder::default()
+            .timestamp(Time::new(42, 100))
+            .frame_id("c")
+            .data(&[1, 2, 3])
+            .format("h264")
+            .build()
+            .unwrap();
+        assert_eq!(via_stamp.to_cdr(), via_timestamp.to_cdr());
+    }
+
+    #[test]
+    fn foxglove_text_annotation_roundtrip() {
+        let text = FoxgloveTextAnnotation::new(
+            Time::new(100, 0),
+            FoxglovePoint2 { x: 50.0, y: 50.0 },
+            "Detection: car (98%)",
+            14.0,
+            FoxgloveColor {
+                r: 1.0,
+                g: 1.0,
+                b: 1.0,
+                a: 1.0,
+            },
+            FoxgloveColor {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.7,
+            },
+        )
+        .unwrap();
+        assert_eq!(text.text(), "Detection: car (98%)");
+        assert_eq!(text.font_size(), 14.0);
+
+        let bytes = text.to_cdr();
+        let decoded = FoxgloveTextAnnotation::from_cdr(bytes).unwrap();
+        assert_eq!(decoded.text(), "Detection: car (98%)");
+    }
+
+    #[test]
+    fn foxglove_point_annotation_roundtrip() {
+        let pa = FoxglovePointAnnotation::new(
+            Time::new(100, 0),
+            point_annotation_type::LINE_LOOP,
+            &[
+                FoxglovePoint2 { x: 0.0, y: 0.0 },
+                FoxglovePoint2 { x: 100.0, y: 0.0 },
+                FoxglovePoint2 { x: 100.0, y: 100.0 },
+            ],
+            FoxgloveColor {
+                r: 0.0,
+                g: 1.0,
+                b: 0.0,
+                a: 1.0,
+            },
+            &[],
+            FoxgloveColor {
                 r: 0.0,
                 g: 0.5,
                 b: 0.0,
@@ -1714,4 +4807,381 @@ mod tests {
         assert_eq!(decoded.thickness(), 9.25);
         assert_eq!(decoded.points().len(), 3);
     }
+
+    #[test]
+    fn foxglove_scene_update_roundtrip() {
+        let white = FoxgloveColor {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+            a: 1.0,
+        };
+        let pose = Pose {
+            position: Point {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            orientation: crate::geometry_msgs::Quaternion {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0,
+            },
+        };
+        let cubes = [FoxgloveCubePrimitive {
+            pose,
+            size: Vector3 {
+                x: 1.0,
+                y: 0.5,
+                z: 0.25,
+            },
+            color: white,
+        }];
+        let points = [
+            pose.position,
+            Point {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        ];
+        let lines = [FoxgloveLinePrimitiveView {
+            type_: line_type::LINE_STRIP,
+            pose,
+            thickness: 2.0,
+            scale_invariant: true,
+            points: Cow::Borrowed(&points),
+            color: white,
+            colors: Cow::Borrowed(&[]),
+            indices: Cow::Borrowed(&[1, 0]),
+        }];
+        let texts = [FoxgloveTextPrimitiveView {
+            pose,
+            billboard: true,
+            font_size: 12.0,
+            scale_invariant: false,
+            color: white,
+            text: "car",
+        }];
+        let models = [FoxgloveModelPrimitiveView {
+            pose,
+            scale: Vector3 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+            color: white,
+            override_color: false,
+            url: "",
+            media_type: "model/stl",
+            data: &[1, 2, 3],
+        }];
+        let metadata = [FoxgloveKeyValuePairView {
+            key: "class",
+            value: "car",
+        }];
+
+        let entity = FoxgloveSceneEntity::builder()
+            .timestamp(Time::new(5, 6))
+            .frame_id("map")
+            .id("car-1")
+            .lifetime(Duration::new(1, 0))
+            .frame_locked(true)
+            .metadata(&metadata)
+            .cubes(&cubes)
+            .lines(&lines)
+            .texts(&texts)
+            .models(&models)
+            .build()
+            .unwrap();
+        assert_eq!(entity.timestamp(), Time::new(5, 6));
+        assert_eq!(entity.frame_id(), "map");
+        assert_eq!(entity.id(), "car-1");
+        assert_eq!(entity.lifetime(), Duration::new(1, 0));
+        assert!(entity.frame_locked());
+        let view = entity.view();
+        assert_eq!(&*view.metadata, &metadata);
+        assert_eq!(&*view.cubes, &cubes);
+        assert_eq!(&*view.lines, &lines);
+        assert_eq!(&*view.texts, &texts);
+        assert_eq!(&*view.models, &models);
+        assert!(view.arrows.is_empty() && view.triangles.is_empty());
+
+        let deletions = [FoxgloveSceneEntityDeletionView {
+            timestamp: Time::new(5, 0),
+            type_: scene_entity_deletion_type::MATCHING_ID,
+            id: "car-0",
+        }];
+        let entities = [view.clone()];
+        let update = FoxgloveSceneUpdate::builder()
+            .deletions(&deletions)
+            .entities(&entities)
+            .build()
+            .unwrap();
+        assert_eq!(update.deletions_len(), 1);
+        assert_eq!(update.deletions(), deletions);
+        assert_eq!(update.entities_len(), 1);
+        assert_eq!(update.entities(), entities);
+
+        let decoded = FoxgloveSceneUpdate::from_cdr(update.to_cdr()).unwrap();
+        assert_eq!(decoded.entities()[0].id, "car-1");
+    }
+
+    #[test]
+    fn foxglove_laser_scan_roundtrip() {
+        let ranges = [1.5, 2.0, f64::INFINITY];
+        let intensities = [10.0, 20.0, 0.0];
+        let mut scan = FoxgloveLaserScan::builder()
+            .timestamp(Time::new(3, 4))
+            .frame_id("laser")
+            .start_angle(-1.0)
+            .end_angle(1.0)
+            .ranges(&ranges)
+            .intensities(&intensities)
+            .build()
+            .unwrap();
+        assert_eq!(scan.frame_id(), "laser");
+        assert_eq!(scan.pose().orientation.w, 1.0);
+        assert_eq!(scan.start_angle(), -1.0);
+        assert_eq!(scan.end_angle(), 1.0);
+        assert_eq!(scan.ranges_len(), 3);
+        assert_eq!(scan.ranges(), ranges);
+        assert_eq!(scan.intensities(), intensities);
+
+        scan.set_timestamp(Time::new(9, 0)).unwrap();
+        let decoded = FoxgloveLaserScan::from_cdr(scan.to_cdr()).unwrap();
+        assert_eq!(decoded.timestamp(), Time::new(9, 0));
+        assert_eq!(decoded.ranges(), ranges);
+    }
+
+    #[test]
+    fn nav_sat_fix_to_location_fix() {
+        let cov = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 4.0];
+        let fix = NavSatFix::builder()
+            .stamp(Time::new(12, 34))
+            .frame_id("gps")
+            .latitude(45.5)
+            .longitude(-73.6)
+            .altitude(30.0)
+            .position_covariance(cov)
+            .position_covariance_type(position_covariance_type::DIAGONAL_KNOWN)
+            .build()
+            .unwrap();
+        let loc = fix.to_location_fix().unwrap();
+        assert_eq!(loc.timestamp(), Time::new(12, 34));
+        assert_eq!(loc.frame_id(), "gps");
+        assert_eq!(loc.latitude(), 45.5);
+        assert_eq!(loc.longitude(), -73.6);
+        assert_eq!(loc.altitude(), 30.0);
+        assert_eq!(loc.position_covariance(), cov);
+        assert_eq!(
+            loc.position_covariance_type(),
+            position_covariance_type::DIAGONAL_KNOWN
+        );
+        let decoded = FoxgloveLocationFix::from_cdr(loc.to_cdr()).unwrap();
+        assert_eq!(decoded.latitude(), 45.5);
+    }
+
+    #[test]
+    fn foxglove_log_roundtrip() {
+        let log = FoxgloveLog::builder()
+            .timestamp(Time::new(1, 2))
+            .level(FoxgloveLogLevel::from_rcl_level(30))
+            .message("camera stalled")
+            .name("camera")
+            .file("camera.rs")
+            .line(42)
+            .build()
+            .unwrap();
+        assert_eq!(log.level(), FoxgloveLogLevel::Warning);
+        assert_eq!(log.message(), "camera stalled");
+        assert_eq!(log.name(), "camera");
+        assert_eq!(log.file(), "camera.rs");
+        assert_eq!(log.line(), 42);
+
+        let mut bytes = log.to_cdr();
+        bytes[CDR_HEADER_SIZE + 8] = 9;
+        assert!(matches!(
+            FoxgloveLog::from_cdr(bytes).map(|_| ()).unwrap_err().root(),
+            CdrError::UnknownDiscriminant { value: 9, .. }
+        ));
+    }
+
+    #[test]
+    fn camera_info_to_camera_calibration() {
+        let d = [-0.25, 0.1, 0.001, -0.002, 0.0];
+        let k = [500.0, 0.0, 320.0, 0.0, 505.0, 240.0, 0.0, 0.0, 1.0];
+        let r = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        let p = [
+            500.0, 0.0, 320.0, -25.0, 0.0, 505.0, 240.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+        ];
+        let mut info = CameraInfo::builder();
+        info.stamp(Time::new(8, 9))
+            .frame_id("camera")
+            .width(640)
+            .height(480)
+            .distortion_model("plumb_bob")
+            .d(&d)
+            .k(k)
+            .r(r)
+            .p(p);
+
+        let cal = info.build().unwrap().to_camera_calibration().unwrap();
+        assert_eq!(cal.timestamp(), Time::new(8, 9));
+        assert_eq!(cal.frame_id(), "camera");
+        assert_eq!((cal.width(), cal.height()), (640, 480));
+        assert_eq!(cal.distortion_model(), "plumb_bob");
+        let cal_d: Vec<f64> = (0..cal.d_len()).map(|i| cal.d_get(i)).collect();
+        assert_eq!(cal_d, d);
+        assert_eq!((cal.k(), cal.r(), cal.p()), (k, r, p));
+
+        // A 2x binned crop describes the published 200x100 image.
+        let roi = crate::sensor_msgs::RegionOfInterest {
+            x_offset: 100,
+            y_offset: 40,
+            height: 200,
+            width: 400,
+            do_rectify: false,
+        };
+        let cal = info
+            .binning_x(2)
+            .binning_y(2)
+            .roi(roi)
+            .build()
+            .unwrap()
+            .to_camera_calibration()
+            .unwrap();
+        assert_eq!((cal.width(), cal.height()), (200, 100));
+        let k = cal.k();
+        assert_eq!((k[0], k[2], k[4], k[5]), (250.0, 110.0, 252.5, 100.0));
+        let p = cal.p();
+        assert_eq!((p[0], p[2], p[3], p[6]), (250.0, 110.0, -12.5, 100.0));
+
+        #[cfg(feature = "std")]
+        {
+            let source = info.build().unwrap();
+            let tight = crate::cdr::DecodeLimits {
+                max_sequence_len: 4,
+                ..crate::cdr::DecodeLimits::DEFAULT
+            };
+            let result = tight.scoped(|| source.to_camera_calibration());
+            assert!(matches!(
+                result.map(|_| ()).unwrap_err().root(),
+                CdrError::LimitExceeded { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn foxglove_poses_in_frame_roundtrip() {
+        let mut moved = IDENTITY_POSE;
+        moved.position.x = 4.0;
+        let single = FoxglovePoseInFrame::builder()
+            .timestamp(Time::new(2, 0))
+            .frame_id("odom")
+            .pose(moved)
+            .build()
+            .unwrap();
+        assert_eq!(single.frame_id(), "odom");
+        assert_eq!(single.pose(), moved);
+
+        let poses = [IDENTITY_POSE, moved];
+        let many = FoxglovePosesInFrame::builder()
+            .timestamp(Time::new(2, 0))
+            .frame_id("odom")
+            .poses(&poses)
+            .build()
+            .unwrap();
+        assert_eq!(many.poses_len(), 2);
+        assert_eq!(many.poses(), poses);
+        let decoded = FoxglovePosesInFrame::from_cdr(many.to_cdr()).unwrap();
+        assert_eq!(decoded.poses(), poses);
+    }
+
+    #[test]
+    fn geojson_features_roundtrip() {
+        let mut features = GeoJsonFeatures::new();
+        features
+            .point((-73.5, 45.5), &[("name", "dock \"A\"")])
+            .line_string(&[(0.0, 0.0), (1.0, 0.5)], &[])
+            .polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)], &[("kind", "fence")]);
+        assert_eq!(features.len(), 3);
+        let text = features.to_geojson();
+        assert_eq!(
+            text,
+            concat!(
+                r#"{"type":"FeatureCollection","features":["#,
+                r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[-73.5,45.5]},"#,
+                r#""properties":{"name":"dock \"A\""}},"#,
+                r#"{"type":"Feature","geometry":{"type":"LineString","coordinates":[[0,0],[1,0.5]]},"#,
+                r#""properties":{}},"#,
+                r#"{"type":"Feature","geometry":{"type":"Polygon","#,
+                r#""coordinates":[[[0,0],[1,0],[1,1],[0,0]]]},"properties":{"kind":"fence"}}"#,
+                "]}"
+            )
+        );
+
+        let msg = FoxgloveGeoJson::builder()
+            .geojson(text.as_str())
+            .build()
+            .unwrap();
+        let decoded = FoxgloveGeoJson::from_cdr(msg.as_cdr()).unwrap();
+        assert_eq!(decoded.geojson(), text);
+    }
+
+    #[test]
+    fn image_to_raw_image() {
+        let pixels = [0x01, 0x02, 0x03, 0x04];
+        let mut image = Image::builder();
+        image
+            .stamp(Time::new(6, 7))
+            .frame_id("camera")
+            .width(2)
+            .height(1)
+            .encoding("mono16")
+            .is_bigendian(1)
+            .step(4)
+            .data(&pixels);
+
+        let raw = image.build().unwrap().to_raw_image().unwrap().unwrap();
+        assert_eq!(raw.timestamp(), Time::new(6, 7));
+        assert_eq!(raw.frame_id(), "camera");
+        assert_eq!((raw.width(), raw.height(), raw.step()), (2, 1, 4));
+        assert_eq!(raw.encoding(), "mono16");
+        assert_eq!(raw.data(), &[0x02, 0x01, 0x04, 0x03]);
+
+        let raw = image
+            .encoding("yuv422")
+            .is_bigendian(0)
+            .build()
+            .unwrap()
+            .to_raw_image()
+            .unwrap()
+            .unwrap();
+        assert_eq!(raw.encoding(), "uyvy");
+        assert_eq!(raw.data(), &pixels);
+
+        #[cfg(feature = "std")]
+        {
+            let source = image.build().unwrap();
+            let tight = crate::cdr::DecodeLimits {
+                max_string_len: 4,
+                ..crate::cdr::DecodeLimits::DEFAULT
+            };
+            let result = tight.scoped(|| source.to_raw_image());
+            assert!(matches!(
+                result.map(|_| ()).unwrap_err().root(),
+                CdrError::LimitExceeded { .. }
+            ));
+        }
+
+        assert!(image
+            .encoding("nv12")
+            .build()
+            .unwrap()
+            .to_raw_image()
+            .unwrap()
+            .is_none());
+    }
 }
@@ -8,11 +8,18 @@
 //! `PoseWithCovariance`, `TwistWithCovariance`
 //!
 //! Buffer-backed (stamped wrappers): `AccelStamped`, `TwistStamped`,
-//! `InertiaStamped`, `PointStamped`, `TransformStamped`
+//! `InertiaStamped`, `PointStamped`, `PoseStamped`, `TransformStamped`
+//!
+//! Buffer-backed (variable length): `Polygon`, `PolygonStamped`
+//!
+//! [`validate_frame_graph`] checks a set of `TransformStamped` messages for
+//! cycles, multiple parents, disjoint trees and stale edges.
 
 use crate::builtin_interfaces::Time;
 use crate::cdr::*;
 use crate::std_msgs::Header;
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
 
 // ── CdrFixed types ──────────────────────────────────────────────────
 
@@ -390,10 +397,11 @@ impl<B> AccelStamped<B> {
 
 impl<B: AsRef<[u8]>> AccelStamped<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         Accel::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(AccelStamped { offsets: [o0], buf })
     }
 
@@ -401,7 +409,7 @@ impl<B: AsRef<[u8]>> AccelStamped<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -468,10 +476,11 @@ impl<B> TwistStamped<B> {
 
 impl<B: AsRef<[u8]>> TwistStamped<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         Twist::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(TwistStamped { offsets: [o0], buf })
     }
 
@@ -479,7 +488,7 @@ impl<B: AsRef<[u8]>> TwistStamped<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -546,10 +555,11 @@ impl<B> InertiaStamped<B> {
 
 impl<B: AsRef<[u8]>> InertiaStamped<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         Inertia::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(InertiaStamped { offsets: [o0], buf })
     }
 
@@ -557,7 +567,7 @@ impl<B: AsRef<[u8]>> InertiaStamped<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -624,10 +634,11 @@ impl<B> PointStamped<B> {
 
 impl<B: AsRef<[u8]>> PointStamped<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         Point::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(PointStamped { offsets: [o0], buf })
     }
 
@@ -635,7 +646,7 @@ impl<B: AsRef<[u8]>> PointStamped<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -682,6 +693,275 @@ impl PointStamped<Vec<u8>> {
     }
 }
 
+// ── PoseStamped<B> ──────────────────────────────────────────────────
+
+pub struct PoseStamped<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> PoseStamped<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> PoseStamped<C> {
+        PoseStamped {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> PoseStamped<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let o0 = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        Pose::read_cdr(&mut c)?;
+        c.expect_end()?;
+        Ok(PoseStamped { offsets: [o0], buf })
+    }
+
+    #[inline]
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    #[inline]
+    pub fn pose(&self) -> Pose {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0]);
+        Pose::read_cdr(&mut c).expect("pose field validated during from_cdr")
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl PoseStamped<Vec<u8>> {
+    pub fn new(stamp: Time, frame_id: &str, pose: Pose) -> Result<Self, CdrError> {
+        let mut sizer = CdrSizer::new();
+        Time::size_cdr(&mut sizer);
+        sizer.size_string(frame_id);
+        let o0 = sizer.offset();
+        Pose::size_cdr(&mut sizer);
+
+        let mut buf = vec![0u8; sizer.size()];
+        let mut w = CdrWriter::new(&mut buf)?;
+        stamp.write_cdr(&mut w);
+        w.write_string(frame_id);
+        pose.write_cdr(&mut w);
+        w.finish()?;
+
+        Ok(PoseStamped { offsets: [o0], buf })
+    }
+
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+// ── Polygon<B> ──────────────────────────────────────────────────────
+//
+// CDR layout: offsets[0] (byte index of the points seq count u32),
+//   uint32 count + Point32[] points (12 bytes each)
+
+/// A closed polygon, e.g. a detection zone or geofence; the last point
+/// connects back to the first.
+pub struct Polygon<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> Polygon<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> Polygon<C> {
+        Polygon {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Polygon<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        let o0 = c.offset();
+        scan_points(&mut c)?;
+        c.expect_end()?;
+        Ok(Polygon { offsets: [o0], buf })
+    }
+
+    #[inline]
+    pub fn points_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn points(&self) -> Vec<Point32> {
+        read_points(self.buf.as_ref(), self.offsets[0])
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl Polygon<Vec<u8>> {
+    pub fn new(points: &[Point32]) -> Result<Self, CdrError> {
+        let mut sizer = CdrSizer::new();
+        let o0 = sizer.offset();
+        size_points(&mut sizer, points);
+
+        let mut buf = vec![0u8; sizer.size()];
+        let mut w = CdrWriter::new(&mut buf)?;
+        write_points(&mut w, points);
+        w.finish()?;
+
+        Ok(Polygon { offsets: [o0], buf })
+    }
+
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+// ── PolygonStamped<B> ───────────────────────────────────────────────
+//
+// CDR layout: Header → offsets[0] (byte index of the points seq count
+//   u32, 4-aligned), uint32 count + Point32[] points
+
+pub struct PolygonStamped<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> PolygonStamped<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> PolygonStamped<C> {
+        PolygonStamped {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> PolygonStamped<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), header.end_offset());
+        c.align(4);
+        let o0 = c.offset();
+        scan_points(&mut c).field("polygon")?;
+        c.expect_end()?;
+        Ok(PolygonStamped { offsets: [o0], buf })
+    }
+
+    #[inline]
+    /// Returns a `Header` view by re-parsing the CDR buffer prefix.
+    /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    #[inline]
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    #[inline]
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    #[inline]
+    pub fn points_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    /// The polygon's points, in `frame_id`.
+    pub fn points(&self) -> Vec<Point32> {
+        read_points(self.buf.as_ref(), self.offsets[0])
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl PolygonStamped<Vec<u8>> {
+    pub fn new(stamp: Time, frame_id: &str, points: &[Point32]) -> Result<Self, CdrError> {
+        let mut sizer = CdrSizer::new();
+        Time::size_cdr(&mut sizer);
+        sizer.size_string(frame_id);
+        sizer.align(4);
+        let o0 = sizer.offset();
+        size_points(&mut sizer, points);
+
+        let mut buf = vec![0u8; sizer.size()];
+        let mut w = CdrWriter::new(&mut buf)?;
+        stamp.write_cdr(&mut w);
+        w.write_string(frame_id);
+        write_points(&mut w, points);
+        w.finish()?;
+
+        Ok(PolygonStamped { offsets: [o0], buf })
+    }
+
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+fn scan_points(c: &mut CdrCursor<'_>) -> Result<(), CdrError> {
+    let raw = c.read_u32()?;
+    let n = c.check_seq_count(raw, Point32::CDR_SIZE).field("points")?;
+    for _ in 0..n {
+        Point32::read_cdr(c)?;
+    }
+    Ok(())
+}
+
+fn read_points(b: &[u8], at: usize) -> Vec<Point32> {
+    (0..rd_u32(b, at) as usize)
+        .map(|i| {
+            let p = at + 4 + 12 * i;
+            Point32 {
+                x: rd_f32(b, p),
+                y: rd_f32(b, p + 4),
+                z: rd_f32(b, p + 8),
+            }
+        })
+        .collect()
+}
+
+fn write_points(w: &mut CdrWriter<'_>, points: &[Point32]) {
+    w.write_u32(points.len() as u32);
+    for p in points {
+        p.write_cdr(w);
+    }
+}
+
+fn size_points(s: &mut CdrSizer, points: &[Point32]) {
+    s.size_u32();
+    for _ in points {
+        Point32::size_cdr(s);
+    }
+}
+
 // ── TransformStamped<B> ─────────────────────────────────────────────
 //
 // CDR layout: Header → offsets[0], child_frame_id (string) → offsets[1],
@@ -705,12 +985,13 @@ impl<B> TransformStamped<B> {
 
 impl<B: AsRef<[u8]>> TransformStamped<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        let _ = c.read_string()?; // child_frame_id
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_string().field("child_frame_id")?;
         let o1 = c.offset();
         Transform::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(TransformStamped {
             offsets: [o0, o1],
             buf,
@@ -721,7 +1002,7 @@ impl<B: AsRef<[u8]>> TransformStamped<B> {
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     /// Prefer `stamp()` / `frame_id()` for direct O(1) field access.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -784,6 +1065,154 @@ impl TransformStamped<Vec<u8>> {
     }
 }
 
+// ── Frame graph validation ──────────────────────────────────────────
+
+/// Problem found by [`validate_frame_graph`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameGraphIssue {
+    /// `child` is published under more than one parent frame.
+    MultipleParents { child: String, parents: Vec<String> },
+    /// The frames form a loop; each frame is the parent of the next and the
+    /// last is the parent of the first.
+    Cycle { frames: Vec<String> },
+    /// The graph splits into several trees with these roots, so some frame
+    /// pairs cannot be related.
+    Disconnected { roots: Vec<String> },
+    /// The newest `parent → child` transform is older than the allowed age.
+    Stale {
+        parent: String,
+        child: String,
+        stamp: Time,
+    },
+}
+
+/// Result of [`validate_frame_graph`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameGraphReport {
+    /// Every frame named by a transform, sorted.
+    pub frames: Vec<String>,
+    /// Frames that never appear as a child, sorted.
+    pub roots: Vec<String>,
+    pub issues: Vec<FrameGraphIssue>,
+}
+
+impl FrameGraphReport {
+    /// `true` if the transforms form a single, acyclic, fresh tree.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Check a set of transforms for the misconfigurations that break frame
+/// lookups: children with several parents, cycles, disjoint trees and, when
+/// `max_age` is given, edges whose newest transform is older than `max_age`
+/// at `now`. Repeated transforms for the same edge are expected; only the
+/// newest stamp counts. Pass `None` for static transforms, whose stamps do
+/// not advance.
+pub fn validate_frame_graph<B: AsRef<[u8]>>(
+    transforms: &[TransformStamped<B>],
+    now: Time,
+    max_age: Option<crate::builtin_interfaces::Duration>,
+) -> FrameGraphReport {
+    use alloc::collections::{BTreeMap, BTreeSet};
+    use alloc::string::ToString;
+
+    fn secs(sec: i32, nanosec: u32) -> f64 {
+        sec as f64 + nanosec as f64 * 1e-9
+    }
+
+    // Newest stamp per (parent, child) edge.
+    let mut edges = BTreeMap::<(&str, &str), Time>::new();
+    for tf in transforms {
+        let stamp = tf.stamp();
+        edges
+            .entry((tf.frame_id(), tf.child_frame_id()))
+            .and_modify(|t| {
+                if secs(stamp.sec, stamp.nanosec) > secs(t.sec, t.nanosec) {
+                    *t = stamp;
+                }
+            })
+            .or_insert(stamp);
+    }
+
+    let mut children = BTreeMap::<&str, Vec<&str>>::new();
+    let mut parents = BTreeMap::<&str, Vec<&str>>::new();
+    let mut frames = BTreeSet::<&str>::new();
+    for &(parent, child) in edges.keys() {
+        children.entry(parent).or_default().push(child);
+        parents.entry(child).or_default().push(parent);
+        frames.insert(parent);
+        frames.insert(child);
+    }
+
+    let mut issues = Vec::new();
+    for (child, ps) in &parents {
+        if ps.len() > 1 {
+            issues.push(FrameGraphIssue::MultipleParents {
+                child: child.to_string(),
+                parents: ps.iter().map(|p| p.to_string()).collect(),
+            });
+        }
+    }
+
+    // Depth-first search from every frame; an edge back onto the current
+    // path closes a cycle.
+    let mut done = BTreeSet::<&str>::new();
+    for &start in &frames {
+        if done.contains(start) {
+            continue;
+        }
+        let mut path: Vec<(&str, usize)> = vec![(start, 0)];
+        while let Some((frame, next)) = path.last_mut() {
+            let frame = *frame;
+            let kids = children.get(frame).map(Vec::as_slice).unwrap_or(&[]);
+            let Some(&kid) = kids.get(*next) else {
+                done.insert(frame);
+                path.pop();
+                continue;
+            };
+            *next += 1;
+            if let Some(pos) = path.iter().position(|&(f, _)| f == kid) {
+                issues.push(FrameGraphIssue::Cycle {
+                    frames: path[pos..].iter().map(|(f, _)| f.to_string()).collect(),
+                });
+            } else if !done.contains(kid) {
+                path.push((kid, 0));
+            }
+        }
+    }
+
+    let roots: Vec<String> = frames
+        .iter()
+        .filter(|f| !parents.contains_key(*f))
+        .map(|f| f.to_string())
+        .collect();
+    if roots.len() > 1 {
+        issues.push(FrameGraphIssue::Disconnected {
+            roots: roots.clone(),
+        });
+    }
+
+    if let Some(max_age) = max_age {
+        let limit = secs(now.sec, now.nanosec) - secs(max_age.sec, max_age.nanosec);
+        for (&(parent, child), stamp) in &edges {
+            if secs(stamp.sec, stamp.nanosec) < limit {
+                issues.push(FrameGraphIssue::Stale {
+                    parent: parent.to_string(),
+                    child: child.to_string(),
+                    stamp: *stamp,
+                });
+            }
+        }
+    }
+
+    FrameGraphReport {
+        frames: frames.iter().map(|f| f.to_string()).collect(),
+        roots,
+        issues,
+    }
+}
+
 // ── Registry ────────────────────────────────────────────────────────
 
 /// Check if a type name is supported by this module.
@@ -797,8 +1226,11 @@ pub fn is_type_supported(type_name: &str) -> bool {
             | "Point"
             | "Point32"
             | "PointStamped"
+            | "Polygon"
+            | "PolygonStamped"
             | "Pose"
             | "Pose2D"
+            | "PoseStamped"
             | "Quaternion"
             | "Transform"
             | "TransformStamped"
@@ -818,8 +1250,11 @@ pub fn list_types() -> &'static [&'static str] {
         "geometry_msgs/msg/Point",
         "geometry_msgs/msg/Point32",
         "geometry_msgs/msg/PointStamped",
+        "geometry_msgs/msg/Polygon",
+        "geometry_msgs/msg/PolygonStamped",
         "geometry_msgs/msg/Pose",
         "geometry_msgs/msg/Pose2D",
+        "geometry_msgs/msg/PoseStamped",
         "geometry_msgs/msg/Quaternion",
         "geometry_msgs/msg/Transform",
         "geometry_msgs/msg/TransformStamped",
@@ -829,6 +1264,94 @@ pub fn list_types() -> &'static [&'static str] {
     ]
 }
 
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "Accel" => "Vector3 linear\nVector3 angular\n",
+        "AccelStamped" => "std_msgs/Header header\nAccel accel\n",
+        "Inertia" => {
+            "\
+float64 m
+Vector3 com
+float64 ixx
+float64 ixy
+float64 ixz
+float64 iyy
+float64 iyz
+float64 izz
+"
+        }
+        "InertiaStamped" => "std_msgs/Header header\nInertia inertia\n",
+        "Point" => {
+            "\
+float64 x
+float64 y
+float64 z
+"
+        }
+        "Point32" => {
+            "\
+float32 x
+float32 y
+float32 z
+"
+        }
+        "PointStamped" => "std_msgs/Header header\nPoint point\n",
+        "Polygon" => "Point32[] points\n",
+        "PolygonStamped" => "std_msgs/Header header\nPolygon polygon\n",
+        "Pose" => "Point position\nQuaternion orientation\n",
+        "Pose2D" => {
+            "\
+float64 x
+float64 y
+float64 theta
+"
+        }
+        "PoseStamped" => "std_msgs/Header header\nPose pose\n",
+        "PoseWithCovariance" => "Pose pose\nfloat64[36] covariance\n",
+        "Quaternion" => {
+            "\
+float64 x
+float64 y
+float64 z
+float64 w
+"
+        }
+        "Transform" => "Vector3 translation\nQuaternion rotation\n",
+        "TransformStamped" => {
+            "\
+std_msgs/Header header
+string child_frame_id
+Transform transform
+"
+        }
+        "Twist" => "Vector3 linear\nVector3 angular\n",
+        "TwistStamped" => "std_msgs/Header header\nTwist twist\n",
+        "TwistWithCovariance" => "Twist twist\nfloat64[36] covariance\n",
+        "Vector3" => {
+            "\
+float64 x
+float64 y
+float64 z
+"
+        }
+        _ => return None,
+    })
+}
+
+// HeapSize implementations
+crate::heap_size::impl_heap_size!(
+    AccelStamped,
+    TwistStamped,
+    InertiaStamped,
+    PointStamped,
+    PoseStamped,
+    Polygon,
+    PolygonStamped,
+    TransformStamped,
+);
+
 // SchemaType implementations
 use crate::schema_registry::SchemaType;
 
@@ -1063,6 +1586,63 @@ mod tests {
         assert!((decoded.point().x - 10.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn pose_stamped_roundtrip() {
+        let pose = Pose {
+            position: Point {
+                x: 1.0,
+                y: -2.0,
+                z: 0.5,
+            },
+            orientation: Quaternion {
+                x: 0.0,
+                y: 0.0,
+                z: 0.6,
+                w: 0.8,
+            },
+        };
+        let p = PoseStamped::new(Time::new(5, 6), "map", pose).unwrap();
+        let decoded = PoseStamped::from_cdr(p.to_cdr()).unwrap();
+        assert_eq!(decoded.stamp(), Time::new(5, 6));
+        assert_eq!(decoded.frame_id(), "map");
+        assert_eq!(decoded.pose(), pose);
+        assert!(PoseStamped::from_cdr(&p.as_cdr()[..p.as_cdr().len() - 1]).is_err());
+    }
+
+    #[test]
+    fn polygon_roundtrip() {
+        let zone = [
+            Point32 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            Point32 {
+                x: 4.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            Point32 {
+                x: 4.0,
+                y: 3.0,
+                z: 0.0,
+            },
+        ];
+        let polygon = Polygon::from_cdr(Polygon::new(&zone).unwrap().to_cdr()).unwrap();
+        assert_eq!(polygon.points_len(), 3);
+        assert_eq!(polygon.points(), zone);
+
+        let stamped = PolygonStamped::new(Time::new(9, 1), "map", &zone).unwrap();
+        let decoded = PolygonStamped::from_cdr(stamped.to_cdr()).unwrap();
+        assert_eq!(decoded.stamp(), Time::new(9, 1));
+        assert_eq!(decoded.frame_id(), "map");
+        assert_eq!(decoded.points(), zone);
+        // The stamped body is the Polygon encoding after the header.
+        let body = &polygon.as_cdr()[CDR_HEADER_SIZE..];
+        assert!(decoded.as_cdr().ends_with(body));
+        assert!(PolygonStamped::from_cdr(&stamped.as_cdr()[..stamped.as_cdr().len() - 4]).is_err());
+    }
+
     #[test]
     fn inertia_stamped_roundtrip() {
         let i = InertiaStamped::new(
@@ -1088,4 +1668,77 @@ mod tests {
         let decoded = InertiaStamped::from_cdr(bytes).unwrap();
         assert!((decoded.inertia().m - 10.0).abs() < 1e-10);
     }
+
+    fn tf(sec: i32, parent: &str, child: &str) -> TransformStamped<Vec<u8>> {
+        let identity = Transform {
+            translation: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            rotation: Quaternion {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0,
+            },
+        };
+        TransformStamped::new(Time::new(sec, 0), parent, child, identity).unwrap()
+    }
+
+    #[test]
+    fn frame_graph_valid_tree() {
+        let tfs = [
+            tf(10, "map", "odom"),
+            tf(10, "odom", "base_link"),
+            tf(10, "base_link", "camera"),
+            tf(10, "base_link", "radar"),
+            tf(9, "odom", "base_link"),
+        ];
+        let report = validate_frame_graph(&tfs, Time::new(10, 0), None);
+        assert!(report.is_ok(), "{:?}", report.issues);
+        assert_eq!(report.roots, ["map"]);
+        assert_eq!(report.frames.len(), 5);
+    }
+
+    #[test]
+    fn frame_graph_reports_issues() {
+        let tfs = [
+            tf(10, "map", "base_link"),
+            tf(10, "odom", "base_link"),
+            tf(10, "a", "b"),
+            tf(10, "b", "a"),
+        ];
+        let report = validate_frame_graph(&tfs, Time::new(10, 0), None);
+        assert!(report.issues.contains(&FrameGraphIssue::MultipleParents {
+            child: "base_link".into(),
+            parents: vec!["map".into(), "odom".into()],
+        }));
+        assert!(report.issues.contains(&FrameGraphIssue::Cycle {
+            frames: vec!["a".into(), "b".into()],
+        }));
+        assert!(report.issues.contains(&FrameGraphIssue::Disconnected {
+            roots: vec!["map".into(), "odom".into()],
+        }));
+    }
+
+    #[test]
+    fn frame_graph_stale_uses_newest_stamp() {
+        let tfs = [
+            tf(1, "odom", "base_link"),
+            tf(9, "odom", "base_link"),
+            tf(2, "base_link", "camera"),
+        ];
+        let max_age = Some(crate::builtin_interfaces::Duration::new(5, 0));
+        let report = validate_frame_graph(&tfs, Time::new(10, 0), max_age);
+        assert_eq!(
+            report.issues,
+            [FrameGraphIssue::Stale {
+                parent: "base_link".into(),
+                child: "camera".into(),
+                stamp: Time::new(2, 0),
+            }]
+        );
+        assert!(validate_frame_graph(&tfs, Time::new(10, 0), None).is_ok());
+    }
 }
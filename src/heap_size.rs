@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Heap memory owned by messages, for budgeting and monitoring queues.
+//!
+//! A buffer-backed message owns exactly what its buffer owns: a message
+//! over `Vec<u8>` reports the vector's capacity, one borrowing a `&[u8]`
+//! reports zero, as does every `CdrFixed` type. The offset tables the
+//! views keep are inline and not counted.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::builtin_interfaces::Time;
+//! use edgefirst_schemas::heap_size::HeapSize;
+//! use edgefirst_schemas::std_msgs::Header;
+//!
+//! let owned = Header::builder().frame_id("camera").build().unwrap();
+//! assert!(owned.heap_size() >= owned.as_cdr().len());
+//!
+//! let borrowed = Header::from_cdr(owned.as_cdr()).unwrap();
+//! assert_eq!(borrowed.heap_size(), 0);
+//! assert_eq!(Time::new(1, 2).heap_size(), 0);
+//! ```
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::cdr::CdrFixed;
+
+/// Heap bytes owned by a value, counting allocated capacity rather than
+/// length.
+pub trait HeapSize {
+    fn heap_size(&self) -> usize;
+}
+
+impl<T: CdrFixed> HeapSize for T {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for &[u8] {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for &mut [u8] {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for Vec<u8> {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl HeapSize for Box<[u8]> {
+    fn heap_size(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Implement [`HeapSize`] for buffer-backed messages, which own exactly
+/// what their `buf` owns.
+macro_rules! impl_heap_size {
+    ($($msg:ident),+ $(,)?) => {
+        $(
+            #[allow(deprecated)] // DmaBuffer
+            impl<B: $crate::heap_size::HeapSize> $crate::heap_size::HeapSize for $msg<B> {
+                fn heap_size(&self) -> usize {
+                    self.buf.heap_size()
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_heap_size;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdr::CdrSerializer;
+    use crate::edgefirst_msgs::Detect;
+    use crate::sensor_msgs::Image;
+
+    #[test]
+    fn owned_message_reports_buffer_capacity() {
+        let pixels = [7u8; 1024];
+        let image = Image::builder()
+            .width(32)
+            .height(32)
+            .encoding("mono8")
+            .data(&pixels)
+            .build()
+            .unwrap();
+        let cdr = image.as_cdr().to_vec();
+        assert!(image.heap_size() >= 1024);
+
+        let mut buf = Vec::with_capacity(4096);
+        buf.extend_from_slice(&cdr);
+        assert_eq!(Image::from_cdr(buf).unwrap().heap_size(), 4096);
+        assert_eq!(Image::from_cdr(&cdr[..]).unwrap().heap_size(), 0);
+        let boxed: Box<[u8]> = cdr.clone().into_boxed_slice();
+        assert_eq!(Image::from_cdr(boxed).unwrap().heap_size(), cdr.len());
+    }
+
+    #[test]
+    fn serializer_reports_scratch_buffer() {
+        let mut ser = CdrSerializer::new();
+        assert_eq!(ser.heap_size(), 0);
+        let detect = Detect::builder();
+        let n = ser.serialize(&detect).unwrap().len();
+        assert!(ser.heap_size() >= n);
+        ser.shrink_to(0);
+        assert_eq!(ser.heap_size(), 0);
+    }
+}
@@ -0,0 +1,641 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! CDR ⇄ JSON transcoding for any schema in the registry.
+//!
+//! Messages are walked at runtime from the `.msg` definitions returned by
+//! [`schema_registry::definition`](crate::schema_registry::definition), so
+//! dashboards and debugging tools can render any supported topic without
+//! per-type code. Requires the `json` feature.
+//!
+//! Mapping:
+//!
+//! - messages become objects keyed by field name
+//! - integers, floats and bools map to JSON numbers and booleans; NaN and
+//!   infinite floats become `null` (and `null` encodes as NaN)
+//! - `uint8[]` / `byte[]` / `char[]` fields, fixed or not, become base64
+//!   strings; arrays of numbers are also accepted when encoding
+//! - other arrays become JSON arrays
+//!
+//! When encoding, fields missing from an object are written as zero, empty
+//! or default-valued; unknown keys are rejected.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::builtin_interfaces::Time;
+//! use edgefirst_schemas::cdr::encode_fixed;
+//! use edgefirst_schemas::json::{transcode_from_json, transcode_to_json};
+//!
+//! let cdr = encode_fixed(&Time::new(12, 500)).unwrap();
+//! let json = transcode_to_json("builtin_interfaces/msg/Time", &cdr).unwrap();
+//! assert_eq!(json.to_string(), r#"{"nanosec":500,"sec":12}"#);
+//! assert_eq!(transcode_from_json("builtin_interfaces/msg/Time", &json).unwrap(), cdr);
+//! ```
+
+use std::fmt;
+
+use serde_json::{json, Map, Number, Value};
+
+use crate::cdr::{CdrCursor, CdrError, CdrSizer, CdrWriter, FieldContext};
+use crate::msg_spec::{Arity, Elem, Field, Prim, Resolver, Sink, Spec, SpecError};
+use crate::schema_registry::validate_registered;
+
+/// Schema encoding of [`json_schema`] documents in MCAP schema records and
+/// Foxglove channel advertisements.
+pub const SCHEMA_ENCODING: &str = "jsonschema";
+
+/// Message encoding of channels carrying [`transcode_to_json`] output.
+pub const MESSAGE_ENCODING: &str = "json";
+
+/// Errors from [`transcode_to_json`] and [`transcode_from_json`].
+#[derive(Debug)]
+pub enum TranscodeError {
+    /// No definition is registered for the schema, or for a type it nests.
+    UnknownSchema(String),
+    /// A line of the schema's definition could not be interpreted.
+    InvalidDefinition { schema: String, line: String },
+    /// The CDR bytes did not decode against the definition.
+    Cdr(CdrError),
+    /// The JSON value did not match the definition at `path`, e.g.
+    /// `boxes[2].label`.
+    Json { path: String, reason: String },
+}
+
+impl fmt::Display for TranscodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TranscodeError::UnknownSchema(schema) => write!(f, "unknown schema {}", schema),
+            TranscodeError::InvalidDefinition { schema, line } => {
+                write!(f, "invalid definition line in {}: {}", schema, line)
+            }
+            TranscodeError::Cdr(e) => write!(f, "{}", e),
+            TranscodeError::Json { path, reason } if path.is_empty() => write!(f, "{}", reason),
+            TranscodeError::Json { path, reason } => write!(f, "field `{}`: {}", path, reason),
+        }
+    }
+}
+
+impl std::error::Error for TranscodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TranscodeError::Cdr(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CdrError> for TranscodeError {
+    fn from(e: CdrError) -> Self {
+        TranscodeError::Cdr(e)
+    }
+}
+
+impl From<SpecError> for TranscodeError {
+    fn from(e: SpecError) -> Self {
+        match e {
+            SpecError::UnknownSchema(schema) => TranscodeError::UnknownSchema(schema),
+            SpecError::InvalidDefinition { schema, line } => {
+                TranscodeError::InvalidDefinition { schema, line }
+            }
+        }
+    }
+}
+
+/// Decode a CDR message of type `schema_name` (e.g.
+/// `"sensor_msgs/msg/Image"`) into a JSON value. The byte order follows
+/// the encapsulation header.
+pub fn transcode_to_json(schema_name: &str, cdr: &[u8]) -> Result<Value, TranscodeError> {
+    let spec = Resolver::default().resolve(schema_name, 0)?;
+    let mut c = CdrCursor::detect(cdr)?;
+    let value = read_msg(&spec, &mut c)?;
+    c.expect_end()?;
+    validate_registered(schema_name, cdr)?;
+    Ok(value)
+}
+
+/// Encode a JSON value as a little-endian CDR message of type
+/// `schema_name`, encapsulation header included.
+pub fn transcode_from_json(schema_name: &str, json: &Value) -> Result<Vec<u8>, TranscodeError> {
+    let spec = Resolver::default().resolve(schema_name, 0)?;
+    let mut sizer = CdrSizer::new();
+    write_msg(&spec, Some(json), &mut sizer)?;
+    let mut buf = vec![0u8; sizer.size()];
+    let mut w = CdrWriter::new(&mut buf)?;
+    write_msg(&spec, Some(json), &mut w)?;
+    w.finish()?;
+    Ok(buf)
+}
+
+/// JSON Schema of the values [`transcode_to_json`] produces for
+/// `schema_name`, titled with the schema name, so a Foxglove bridge can
+/// advertise the channel with [`SCHEMA_ENCODING`] to clients without CDR
+/// support.
+///
+/// ```rust
+/// use edgefirst_schemas::json::json_schema;
+///
+/// let schema = json_schema("sensor_msgs/msg/Image").unwrap();
+/// assert_eq!(schema["title"], "sensor_msgs/msg/Image");
+/// assert_eq!(schema["properties"]["height"]["type"], "integer");
+/// assert_eq!(schema["properties"]["data"]["contentEncoding"], "base64");
+/// ```
+pub fn json_schema(schema_name: &str) -> Result<Value, TranscodeError> {
+    let spec = Resolver::default().resolve(schema_name, 0)?;
+    let mut schema = msg_schema(&spec);
+    schema["title"] = schema_name.into();
+    Ok(schema)
+}
+
+fn msg_schema(spec: &Spec) -> Value {
+    let properties: Map<String, Value> = spec
+        .fields
+        .iter()
+        .map(|f| (f.name.to_owned(), field_schema(f)))
+        .collect();
+    json!({ "type": "object", "properties": properties })
+}
+
+fn field_schema(f: &Field) -> Value {
+    if let Arity::Single = f.arity {
+        return elem_schema(&f.elem);
+    }
+    if let Elem::Prim(Prim::U8) = f.elem {
+        return json!({ "type": "string", "contentEncoding": "base64" });
+    }
+    let mut schema = json!({ "type": "array", "items": elem_schema(&f.elem) });
+    match f.arity {
+        Arity::Fixed(n) => {
+            schema["minItems"] = n.into();
+            schema["maxItems"] = n.into();
+        }
+        Arity::Seq(Some(bound)) => schema["maxItems"] = bound.into(),
+        _ => {}
+    }
+    schema
+}
+
+fn elem_schema(elem: &Elem) -> Value {
+    let (min, max): (i128, i128) = match elem {
+        Elem::Msg(spec) => return msg_schema(spec),
+        Elem::String(None) => return json!({ "type": "string" }),
+        Elem::String(Some(bound)) => return json!({ "type": "string", "maxLength": bound }),
+        // NaN and infinities transcode to null.
+        Elem::Prim(Prim::F32 | Prim::F64) => return json!({ "type": ["number", "null"] }),
+        Elem::Prim(Prim::Bool) => return json!({ "type": "boolean" }),
+        Elem::Prim(Prim::I8) => (i8::MIN.into(), i8::MAX.into()),
+        Elem::Prim(Prim::U8) => (0, u8::MAX.into()),
+        Elem::Prim(Prim::I16) => (i16::MIN.into(), i16::MAX.into()),
+        Elem::Prim(Prim::U16) => (0, u16::MAX.into()),
+        Elem::Prim(Prim::I32) => (i32::MIN.into(), i32::MAX.into()),
+        Elem::Prim(Prim::U32) => (0, u32::MAX.into()),
+        Elem::Prim(Prim::I64) => (i64::MIN.into(), i64::MAX.into()),
+        Elem::Prim(Prim::U64) => (0, u64::MAX.into()),
+    };
+    let bound =
+        |v: i128| -> Value { i64::try_from(v).map_or_else(|_| Value::from(v as u64), Value::from) };
+    json!({ "type": "integer", "minimum": bound(min), "maximum": bound(max) })
+}
+
+// ── CDR → JSON ───────────────────────────────────────────────────────
+
+fn read_msg(spec: &Spec, c: &mut CdrCursor<'_>) -> Result<Value, CdrError> {
+    let mut map = Map::with_capacity(spec.fields.len());
+    for f in &spec.fields {
+        let value = read_field(f, c).field(f.name)?;
+        map.insert(f.name.to_owned(), value);
+    }
+    Ok(Value::Object(map))
+}
+
+fn read_field(f: &Field, c: &mut CdrCursor<'_>) -> Result<Value, CdrError> {
+    let count = match f.arity {
+        Arity::Single => return read_elem(&f.elem, c),
+        Arity::Fixed(n) => n,
+        Arity::Seq(Some(bound)) => c.read_seq_len_bounded(bound)?,
+        Arity::Seq(None) => {
+            let raw = c.read_u32()?;
+            c.check_seq_count(raw, 1)?
+        }
+    };
+    if let Elem::Prim(Prim::U8) = f.elem {
+        return Ok(Value::String(base64_encode(c.read_raw(count)?)));
+    }
+    let mut items = Vec::with_capacity(count);
+    for _ in 0..count {
+        items.push(read_elem(&f.elem, c)?);
+    }
+    Ok(Value::Array(items))
+}
+
+fn read_elem(elem: &Elem, c: &mut CdrCursor<'_>) -> Result<Value, CdrError> {
+    Ok(match elem {
+        Elem::Prim(Prim::Bool) => Value::Bool(c.read_bool()?),
+        Elem::Prim(Prim::I8) => c.read_i8()?.into(),
+        Elem::Prim(Prim::U8) => c.read_u8()?.into(),
+        Elem::Prim(Prim::I16) => c.read_i16()?.into(),
+        Elem::Prim(Prim::U16) => c.read_u16()?.into(),
+        Elem::Prim(Prim::I32) => c.read_i32()?.into(),
+        Elem::Prim(Prim::U32) => c.read_u32()?.into(),
+        Elem::Prim(Prim::I64) => c.read_i64()?.into(),
+        Elem::Prim(Prim::U64) => c.read_u64()?.into(),
+        // Via the shortest decimal form, so 0.1f32 renders as 0.1.
+        Elem::Prim(Prim::F32) => float(c.read_f32()?.to_string().parse().unwrap_or(f64::NAN)),
+        Elem::Prim(Prim::F64) => float(c.read_f64()?),
+        Elem::String(Some(bound)) => c.read_string_bounded(*bound)?.into(),
+        Elem::String(None) => c.read_string()?.into(),
+        Elem::Msg(spec) => read_msg(spec, c)?,
+    })
+}
+
+fn float(v: f64) -> Value {
+    Number::from_f64(v).map_or(Value::Null, Value::Number)
+}
+
+// ── JSON → CDR ───────────────────────────────────────────────────────
+
+fn mismatch(reason: impl Into<String>) -> TranscodeError {
+    TranscodeError::Json {
+        path: String::new(),
+        reason: reason.into(),
+    }
+}
+
+/// Prefix the path of a JSON error with a field name or `[index]`.
+fn at(segment: String) -> impl FnOnce(TranscodeError) -> TranscodeError {
+    move |e| match e {
+        TranscodeError::Json { path, reason } => {
+            let path = if path.is_empty() || path.starts_with('[') {
+                segment + &path
+            } else {
+                segment + "." + &path
+            };
+            TranscodeError::Json { path, reason }
+        }
+        e => e,
+    }
+}
+
+fn write_msg(spec: &Spec, value: Option<&Value>, s: &mut dyn Sink) -> Result<(), TranscodeError> {
+    let empty = Map::new();
+    let map = match value {
+        None => &empty,
+        Some(Value::Object(map)) => map,
+        Some(_) => return Err(mismatch("expected an object")),
+    };
+    if let Some(key) = map
+        .keys()
+        .find(|k| !spec.fields.iter().any(|f| f.name == k.as_str()))
+    {
+        return Err(mismatch(format!("unknown field `{}`", key)));
+    }
+    for f in &spec.fields {
+        write_field(f, map.get(f.name), s).map_err(at(f.name.to_owned()))?;
+    }
+    Ok(())
+}
+
+fn write_field(f: &Field, value: Option<&Value>, s: &mut dyn Sink) -> Result<(), TranscodeError> {
+    if let Arity::Single = f.arity {
+        return write_elem(&f.elem, value, s);
+    }
+    let bytes;
+    let items: &[Value] = match value {
+        None => &[],
+        Some(Value::String(b64)) if matches!(f.elem, Elem::Prim(Prim::U8)) => {
+            bytes = base64_decode(b64).ok_or_else(|| mismatch("invalid base64"))?;
+            return write_bytes(f.arity, &bytes, s);
+        }
+        Some(Value::Array(items)) => items,
+        Some(_) => return Err(mismatch("expected an array")),
+    };
+    let count = match f.arity {
+        Arity::Fixed(n) if value.is_none() => n,
+        Arity::Fixed(n) if items.len() != n => {
+            return Err(mismatch(format!(
+                "expected {} elements, got {}",
+                n,
+                items.len()
+            )))
+        }
+        Arity::Seq(Some(bound)) if items.len() > bound => {
+            return Err(mismatch(format!(
+                "{} elements exceed bound {}",
+                items.len(),
+                bound
+            )))
+        }
+        Arity::Fixed(n) => n,
+        _ => {
+            s.u32(items.len() as u32);
+            items.len()
+        }
+    };
+    for i in 0..count {
+        write_elem(&f.elem, items.get(i), s).map_err(at(format!("[{}]", i)))?;
+    }
+    Ok(())
+}
+
+fn write_bytes(arity: Arity, bytes: &[u8], s: &mut dyn Sink) -> Result<(), TranscodeError> {
+    match arity {
+        Arity::Fixed(n) if bytes.len() != n => {
+            return Err(mismatch(format!(
+                "expected {} bytes, got {}",
+                n,
+                bytes.len()
+            )))
+        }
+        Arity::Seq(Some(bound)) if bytes.len() > bound => {
+            return Err(mismatch(format!(
+                "{} bytes exceed bound {}",
+                bytes.len(),
+                bound
+            )))
+        }
+        Arity::Fixed(_) => {}
+        _ => s.u32(bytes.len() as u32),
+    }
+    s.raw(bytes);
+    Ok(())
+}
+
+fn write_elem(elem: &Elem, value: Option<&Value>, s: &mut dyn Sink) -> Result<(), TranscodeError> {
+    let prim = match elem {
+        Elem::Msg(spec) => return write_msg(spec, value, s),
+        Elem::String(bound) => {
+            let v = match value {
+                None => "",
+                Some(Value::String(v)) => v,
+                Some(_) => return Err(mismatch("expected a string")),
+            };
+            if let Some(bound) = bound.filter(|&b| v.len() > b) {
+                return Err(mismatch(format!(
+                    "{} bytes exceed bound {}",
+                    v.len(),
+                    bound
+                )));
+            }
+            s.string(v);
+            return Ok(());
+        }
+        Elem::Prim(prim) => *prim,
+    };
+    let zero = Value::from(0);
+    let value = value.unwrap_or(if prim == Prim::Bool {
+        &Value::Bool(false)
+    } else {
+        &zero
+    });
+    match prim {
+        Prim::Bool => s.u8(value.as_bool().ok_or_else(|| mismatch("expected a bool"))? as u8),
+        Prim::I8 => s.u8(int::<i8>(value)? as u8),
+        Prim::U8 => s.u8(uint(value)?),
+        Prim::I16 => s.u16(int::<i16>(value)? as u16),
+        Prim::U16 => s.u16(uint(value)?),
+        Prim::I32 => s.u32(int::<i32>(value)? as u32),
+        Prim::U32 => s.u32(uint(value)?),
+        Prim::I64 => s.u64(int::<i64>(value)? as u64),
+        Prim::U64 => s.u64(uint(value)?),
+        Prim::F32 => s.u32((real(value)? as f32).to_bits()),
+        Prim::F64 => s.u64(real(value)?.to_bits()),
+    }
+    Ok(())
+}
+
+fn int<T: TryFrom<i64>>(value: &Value) -> Result<T, TranscodeError> {
+    value
+        .as_i64()
+        .and_then(|v| T::try_from(v).ok())
+        .ok_or_else(|| mismatch(format!("expected an integer in range, got {}", value)))
+}
+
+fn uint<T: TryFrom<u64>>(value: &Value) -> Result<T, TranscodeError> {
+    value
+        .as_u64()
+        .and_then(|v| T::try_from(v).ok())
+        .ok_or_else(|| {
+            mismatch(format!(
+                "expected an unsigned integer in range, got {}",
+                value
+            ))
+        })
+}
+
+fn real(value: &Value) -> Result<f64, TranscodeError> {
+    match value {
+        Value::Null => Ok(f64::NAN),
+        v => v
+            .as_f64()
+            .ok_or_else(|| mismatch(format!("expected a number, got {}", v))),
+    }
+}
+
+// ── Base64 (RFC 4648, padded) ────────────────────────────────────────
+
+const B64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(B64[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let text = text.as_bytes();
+    if !text.len().is_multiple_of(4) {
+        return None;
+    }
+    let mut out = Vec::with_capacity(text.len() / 4 * 3);
+    for (q, quad) in text.chunks(4).enumerate() {
+        let last = q == text.len() / 4 - 1;
+        let pad = quad.iter().rev().take_while(|&&b| b == b'=').count();
+        if pad > 2 || (pad > 0 && !last) {
+            return None;
+        }
+        let mut n = 0u32;
+        for &b in &quad[..4 - pad] {
+            let v = B64.iter().position(|&c| c == b)? as u32;
+            n = n << 6 | v;
+        }
+        n <<= 6 * pad as u32;
+        out.extend_from_slice(&n.to_be_bytes()[1..4 - pad]);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+    use crate::edgefirst_msgs::{Detect, DetectBoxView};
+    use crate::schema_registry::list_schemas;
+    use crate::sensor_msgs::{Image, NavSatFix, NavSatStatus};
+
+    #[test]
+    fn base64_reference_vectors() {
+        for (raw, enc) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(base64_encode(raw), enc);
+            assert_eq!(base64_decode(enc).unwrap(), raw);
+        }
+        assert!(base64_decode("Zg=").is_none());
+        assert!(base64_decode("Zg==Zm8=").is_none());
+    }
+
+    #[test]
+    fn every_schema_resolves_and_roundtrips_defaults() {
+        for schema in list_schemas() {
+            let cdr = transcode_from_json(schema, &Value::Object(Map::new()))
+                .unwrap_or_else(|e| panic!("{}: {}", schema, e));
+            let json = transcode_to_json(schema, &cdr).unwrap();
+            assert_eq!(
+                transcode_from_json(schema, &json).unwrap(),
+                cdr,
+                "{}",
+                schema
+            );
+        }
+    }
+
+    #[test]
+    fn image_matches_typed_encoding() {
+        let img = Image::builder()
+            .stamp(Time::new(5, 6))
+            .frame_id("cam")
+            .height(1)
+            .width(3)
+            .encoding("mono8")
+            .step(3)
+            .data(&[1, 2, 3])
+            .build()
+            .unwrap();
+        let json = transcode_to_json("sensor_msgs/msg/Image", img.as_cdr()).unwrap();
+        assert_eq!(json["header"]["frame_id"], "cam");
+        assert_eq!(json["header"]["stamp"]["sec"], 5);
+        assert_eq!(json["data"], "AQID");
+        let back = transcode_from_json("sensor_msgs/msg/Image", &json).unwrap();
+        assert_eq!(back, img.as_cdr());
+    }
+
+    #[test]
+    fn nested_sequences_and_alignment_match_typed_encoding() {
+        let boxes = [DetectBoxView {
+            center_x: 0.5,
+            center_y: 0.25,
+            width: 0.1,
+            height: 0.2,
+            label: "person",
+            score: 0.9,
+            distance: 3.0,
+            speed: 0.0,
+            track_id: "t1",
+            track_lifetime: 4,
+            track_created: Time::new(1, 0),
+        }];
+        let det = Detect::builder()
+            .frame_id("cam")
+            .boxes(&boxes)
+            .build()
+            .unwrap();
+        let json = transcode_to_json("edgefirst_msgs/msg/Detect", det.as_cdr()).unwrap();
+        assert_eq!(json["boxes"][0]["label"], "person");
+        assert_eq!(json["boxes"][0]["width"], 0.1);
+        assert_eq!(json["boxes"][0]["track"]["id"], "t1");
+        let back = transcode_from_json("edgefirst_msgs/msg/Detect", &json).unwrap();
+        assert_eq!(back, det.as_cdr());
+
+        // int8 + uint16 status followed by 8-aligned doubles.
+        let fix = NavSatFix::builder()
+            .status(NavSatStatus {
+                status: -1,
+                service: 3,
+            })
+            .latitude(45.0)
+            .position_covariance([1.0; 9])
+            .build()
+            .unwrap();
+        let json = transcode_to_json("sensor_msgs/msg/NavSatFix", fix.as_cdr()).unwrap();
+        assert_eq!(json["status"]["status"], -1);
+        assert_eq!(json["latitude"], 45.0);
+        let back = transcode_from_json("sensor_msgs/msg/NavSatFix", &json).unwrap();
+        assert_eq!(back, fix.as_cdr());
+    }
+
+    #[test]
+    fn json_schema_describes_transcoded_values() {
+        let schema = json_schema("sensor_msgs/msg/NavSatFix").unwrap();
+        assert_eq!(schema["title"], "sensor_msgs/msg/NavSatFix");
+        assert_eq!(schema["type"], "object");
+        let status = &schema["properties"]["status"]["properties"]["status"];
+        assert_eq!(status["type"], "integer");
+        assert_eq!(status["minimum"], -128);
+        assert_eq!(status["maximum"], 127);
+        let cov = &schema["properties"]["position_covariance"];
+        assert_eq!(cov["type"], "array");
+        assert_eq!(cov["minItems"], 9);
+        assert_eq!(cov["maxItems"], 9);
+        assert_eq!(cov["items"]["type"], serde_json::json!(["number", "null"]));
+
+        // Every field of every transcoded message is described.
+        for schema_name in list_schemas() {
+            let schema = json_schema(schema_name).unwrap();
+            let cdr = transcode_from_json(schema_name, &Value::Object(Map::new())).unwrap();
+            let json = transcode_to_json(schema_name, &cdr).unwrap();
+            for key in json.as_object().unwrap().keys() {
+                assert!(
+                    schema["properties"].get(key).is_some(),
+                    "{}.{}",
+                    schema_name,
+                    key
+                );
+            }
+        }
+        assert!(matches!(
+            json_schema("unknown_msgs/msg/Foo"),
+            Err(TranscodeError::UnknownSchema(_))
+        ));
+    }
+
+    #[test]
+    fn errors_name_the_offending_field() {
+        let schema = "edgefirst_msgs/msg/Detect";
+        let json: Value = serde_json::json!({ "boxes": [{}, { "score": "high" }] });
+        let err = transcode_from_json(schema, &json).unwrap_err();
+        assert!(
+            matches!(&err, TranscodeError::Json { path, .. } if path == "boxes[1].score"),
+            "{}",
+            err
+        );
+
+        let json: Value = serde_json::json!({ "header": { "frame": "x" } });
+        let err = transcode_from_json(schema, &json).unwrap_err();
+        assert_eq!(err.to_string(), "field `header`: unknown field `frame`");
+
+        let cdr = transcode_from_json(schema, &Value::Object(Map::new())).unwrap();
+        let err = transcode_to_json(schema, &cdr[..cdr.len() - 1]).unwrap_err();
+        assert!(matches!(err, TranscodeError::Cdr(_)));
+
+        assert!(matches!(
+            transcode_to_json("unknown_msgs/msg/Foo", &cdr),
+            Err(TranscodeError::UnknownSchema(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright © 2025 Au-Zone Technologies. All Rights Reserved.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(clippy::too_many_arguments)]
 
 //! # EdgeFirst Middleware Schemas
@@ -23,6 +24,16 @@
 //! * [rcl_interfaces](https://github.com/ros2/rcl_interfaces)
 //! * [foxglove schemas](https://github.com/foxglove/schemas/tree/main/ros_foxglove_msgs)
 //! * [edgefirst schemas](https://github.com/EdgeFirstAI/schemas)
+//!
+//! ## `no_std`
+//!
+//! With `default-features = false` the crate is `no_std + alloc`: message
+//! types, the CDR codec, bounded types, DDS keys and the schema registry
+//! stay available. Stream framing, JSON transcoding, the pixel-format and
+//! message converters, shared-memory rings and the C API need the `std`
+//! feature (which `ffi`, `shm`, `json`, `zstd` and `blob-*` enable).
+
+extern crate alloc;
 
 /// EdgeFirst custom perception messages.
 pub mod edgefirst_msgs;
@@ -38,6 +49,8 @@ pub mod nav_msgs;
 pub mod sensor_msgs;
 /// ROS 2 standard message types (Header, ColorRGBA).
 pub mod std_msgs;
+/// ROS 2 vision message types (detections and class hypotheses).
+pub mod vision_msgs;
 
 /// MAVLink/MAVROS message types.
 pub mod mavros_msgs;
@@ -53,8 +66,76 @@ pub mod service;
 /// Zero-copy CDR serialization infrastructure.
 pub mod cdr;
 
+/// IDL bounded strings and sequences.
+pub mod bounded;
+
+/// Resolving and verifying artifacts referenced by `BlobRef`.
+pub mod blob;
+
+/// DDS instance key hashes for keyed topics.
+pub mod dds_key;
+
+/// Heap memory owned by messages.
+pub mod heap_size;
+
 /// Schema registry for runtime schema name lookup.
 pub mod schema_registry;
 
+/// Well-known EdgeFirst topics and the schemas they carry.
+pub mod topics;
+
+/// ROS 1 MD5 sums and connection headers for bridging.
+pub mod ros1;
+
+/// Type-erased messages and decoding by schema name.
+pub mod message;
+
+/// Message types defined outside this crate.
+#[cfg(feature = "std")]
+pub mod custom;
+
+#[cfg(feature = "derive")]
+pub use edgefirst_schemas_derive::EdgeFirstMessage;
+
+/// Message layouts parsed from the schema registry's definitions.
+mod msg_spec;
+
+/// CDR ⇄ JSON transcoding driven by the schema registry.
+#[cfg(feature = "json")]
+pub mod json;
+
+/// Generic value trees decoded and encoded by schema name.
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+
+/// Arbitrary messages for property tests and fuzzing.
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
+/// Golden CDR vectors and seeded sample messages for interop tests.
+#[cfg(feature = "testdata")]
+pub mod testdata;
+
+/// Zstandard compression with per-schema trained dictionaries.
+#[cfg(feature = "zstd")]
+pub mod compression;
+
+/// Conversions between message representations, typed and by schema name.
+#[cfg(feature = "std")]
+pub mod convert;
+
+/// Seeded fault-injection transport for robustness testing.
+#[cfg(feature = "chaos")]
+pub mod chaos;
+
+/// Length-prefixed framing for decoding messages from streams and files.
+#[cfg(feature = "std")]
+pub mod stream;
+
+/// POSIX shared-memory rings for same-host zero-copy transfer.
+#[cfg(all(unix, feature = "shm"))]
+pub mod shm;
+
 /// C FFI bindings.
+#[cfg(feature = "ffi")]
 mod ffi;
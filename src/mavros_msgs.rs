@@ -12,6 +12,7 @@
 use crate::builtin_interfaces::Time;
 use crate::cdr::*;
 use crate::std_msgs::Header;
+use alloc::{vec, vec::Vec};
 
 // ── Altitude<B> ─────────────────────────────────────────────────────
 //
@@ -43,23 +44,24 @@ impl<B> Altitude<B> {
 
 impl<B: AsRef<[u8]>> Altitude<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(4);
         let o0 = c.offset();
-        c.read_f32()?; // monotonic
-        c.read_f32()?; // amsl
-        c.read_f32()?; // local
-        c.read_f32()?; // relative
-        c.read_f32()?; // terrain
-        c.read_f32()?; // bottom_clearance
+        c.read_f32().field("monotonic")?;
+        c.read_f32().field("amsl")?;
+        c.read_f32().field("local")?;
+        c.read_f32().field("relative")?;
+        c.read_f32().field("terrain")?;
+        c.read_f32().field("bottom_clearance")?;
+        c.expect_end()?;
         Ok(Altitude { offsets: [o0], buf })
     }
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -177,23 +179,24 @@ impl<B> VfrHud<B> {
 
 impl<B: AsRef<[u8]>> VfrHud<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(4);
         let o0 = c.offset();
-        c.read_f32()?; // airspeed
-        c.read_f32()?; // groundspeed
-        c.read_i16()?; // heading
+        c.read_f32().field("airspeed")?;
+        c.read_f32().field("groundspeed")?;
+        c.read_i16().field("heading")?;
         c.read_f32()?; // throttle (auto-aligns to 4)
-        c.read_f32()?; // altitude
-        c.read_f32()?; // climb
+        c.read_f32().field("altitude")?;
+        c.read_f32().field("climb")?;
+        c.expect_end()?;
         Ok(VfrHud { offsets: [o0], buf })
     }
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -304,18 +307,19 @@ impl<B> EstimatorStatus<B> {
 
 impl<B: AsRef<[u8]>> EstimatorStatus<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         for _ in 0..12 {
             c.read_bool()?;
         }
+        c.expect_end()?;
         Ok(EstimatorStatus { offsets: [o0], buf })
     }
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -476,17 +480,18 @@ impl<B> ExtendedState<B> {
 
 impl<B: AsRef<[u8]>> ExtendedState<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        c.read_u8()?; // vtol_state
-        c.read_u8()?; // landed_state
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_u8().field("vtol_state")?;
+        c.read_u8().field("landed_state")?;
+        c.expect_end()?;
         Ok(ExtendedState { offsets: [o0], buf })
     }
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -586,30 +591,31 @@ impl<B> SysStatus<B> {
 
 impl<B: AsRef<[u8]>> SysStatus<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(4);
         let o0 = c.offset();
-        c.read_u32()?; // sensors_present
-        c.read_u32()?; // sensors_enabled
-        c.read_u32()?; // sensors_health
-        c.read_u16()?; // load
-        c.read_u16()?; // voltage_battery
-        c.read_i16()?; // current_battery
-        c.read_i8()?; // battery_remaining
+        c.read_u32().field("sensors_present")?;
+        c.read_u32().field("sensors_enabled")?;
+        c.read_u32().field("sensors_health")?;
+        c.read_u16().field("load")?;
+        c.read_u16().field("voltage_battery")?;
+        c.read_i16().field("current_battery")?;
+        c.read_i8().field("battery_remaining")?;
         c.read_u16()?; // drop_rate_comm (auto-aligns to 2)
-        c.read_u16()?; // errors_comm
-        c.read_u16()?; // errors_count1
-        c.read_u16()?; // errors_count2
-        c.read_u16()?; // errors_count3
-        c.read_u16()?; // errors_count4
+        c.read_u16().field("errors_comm")?;
+        c.read_u16().field("errors_count1")?;
+        c.read_u16().field("errors_count2")?;
+        c.read_u16().field("errors_count3")?;
+        c.read_u16().field("errors_count4")?;
+        c.expect_end()?;
         Ok(SysStatus { offsets: [o0], buf })
     }
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -804,18 +810,19 @@ impl<B> State<B> {
 
 impl<B: AsRef<[u8]>> State<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        c.read_bool()?; // connected
-        c.read_bool()?; // armed
-        c.read_bool()?; // guided
-        c.read_bool()?; // manual_input
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_bool().field("connected")?;
+        c.read_bool().field("armed")?;
+        c.read_bool().field("guided")?;
+        c.read_bool().field("manual_input")?;
         c.align(4);
         let o1 = c.offset();
-        let _ = c.read_string()?; // mode
+        let _ = c.read_string().field("mode")?;
         let o2 = c.offset();
-        c.read_u8()?; // system_status
+        c.read_u8().field("system_status")?;
+        c.expect_end()?;
         Ok(State {
             offsets: [o0, o1, o2],
             buf,
@@ -824,7 +831,7 @@ impl<B: AsRef<[u8]>> State<B> {
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -953,13 +960,14 @@ impl<B> StatusText<B> {
 
 impl<B: AsRef<[u8]>> StatusText<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        c.read_u8()?; // severity
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_u8().field("severity")?;
         c.align(4);
         let o1 = c.offset();
-        let _ = c.read_string()?; // text
+        let _ = c.read_string().field("text")?;
+        c.expect_end()?;
         Ok(StatusText {
             offsets: [o0, o1],
             buf,
@@ -968,7 +976,7 @@ impl<B: AsRef<[u8]>> StatusText<B> {
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -1088,37 +1096,38 @@ impl<B> GpsRaw<B> {
 
 impl<B: AsRef<[u8]>> GpsRaw<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         // Align to 4 so that subsequent i32 fields are correctly placed.
         // The u8 fix_type sits at the aligned offset; i32 lat follows with
         // automatic alignment by read_i32.
         c.align(4);
         let o0 = c.offset();
-        c.read_u8()?; // fix_type
+        c.read_u8().field("fix_type")?;
         c.read_i32()?; // lat (auto-aligns to 4)
-        c.read_i32()?; // lon
-        c.read_i32()?; // alt
-        c.read_u16()?; // eph
-        c.read_u16()?; // epv
-        c.read_u16()?; // vel
-        c.read_u16()?; // cog
-        c.read_u8()?; // satellites_visible
+        c.read_i32().field("lon")?;
+        c.read_i32().field("alt")?;
+        c.read_u16().field("eph")?;
+        c.read_u16().field("epv")?;
+        c.read_u16().field("vel")?;
+        c.read_u16().field("cog")?;
+        c.read_u8().field("satellites_visible")?;
         c.read_i32()?; // alt_ellipsoid (auto-aligns to 4)
-        c.read_u32()?; // h_acc
-        c.read_u32()?; // v_acc
-        c.read_u32()?; // vel_acc
-        c.read_i32()?; // hdg_acc
-        c.read_u16()?; // yaw
-        c.read_u8()?; // dgps_numch
+        c.read_u32().field("h_acc")?;
+        c.read_u32().field("v_acc")?;
+        c.read_u32().field("vel_acc")?;
+        c.read_i32().field("hdg_acc")?;
+        c.read_u16().field("yaw")?;
+        c.read_u8().field("dgps_numch")?;
         c.read_u32()?; // dgps_age (auto-aligns to 4)
+        c.expect_end()?;
         Ok(GpsRaw { offsets: [o0], buf })
     }
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -1333,21 +1342,22 @@ impl<B> TimesyncStatus<B> {
 
 impl<B: AsRef<[u8]>> TimesyncStatus<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(8);
         let o0 = c.offset();
-        c.read_u64()?; // remote_timestamp_ns
-        c.read_i64()?; // observed_offset_ns
-        c.read_i64()?; // estimated_offset_ns
-        c.read_f32()?; // round_trip_time_ms
+        c.read_u64().field("remote_timestamp_ns")?;
+        c.read_i64().field("observed_offset_ns")?;
+        c.read_i64().field("estimated_offset_ns")?;
+        c.read_f32().field("round_trip_time_ms")?;
+        c.expect_end()?;
         Ok(TimesyncStatus { offsets: [o0], buf })
     }
 
     #[inline]
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     #[inline]
     pub fn stamp(&self) -> Time {
@@ -1453,6 +1463,140 @@ pub fn list_types() -> &'static [&'static str] {
     ]
 }
 
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "Altitude" => {
+            "\
+std_msgs/Header header
+float32 monotonic
+float32 amsl
+float32 local
+float32 relative
+float32 terrain
+float32 bottom_clearance
+"
+        }
+        "EstimatorStatus" => {
+            "\
+std_msgs/Header header
+bool attitude_status_flag
+bool velocity_horiz_status_flag
+bool velocity_vert_status_flag
+bool pos_horiz_rel_status_flag
+bool pos_horiz_abs_status_flag
+bool pos_vert_abs_status_flag
+bool pos_vert_agl_status_flag
+bool const_pos_mode_status_flag
+bool pred_pos_horiz_rel_status_flag
+bool pred_pos_horiz_abs_status_flag
+bool gps_glitch_status_flag
+bool accel_error_status_flag
+"
+        }
+        "ExtendedState" => {
+            "\
+std_msgs/Header header
+uint8 vtol_state
+uint8 landed_state
+"
+        }
+        "GPSRAW" => {
+            "\
+std_msgs/Header header
+uint8 fix_type
+int32 lat
+int32 lon
+int32 alt
+uint16 eph
+uint16 epv
+uint16 vel
+uint16 cog
+uint8 satellites_visible
+int32 alt_ellipsoid
+uint32 h_acc
+uint32 v_acc
+uint32 vel_acc
+int32 hdg_acc
+uint16 yaw
+uint8 dgps_numch
+uint32 dgps_age
+"
+        }
+        "State" => {
+            "\
+std_msgs/Header header
+bool connected
+bool armed
+bool guided
+bool manual_input
+string mode
+uint8 system_status
+"
+        }
+        "StatusText" => {
+            "\
+std_msgs/Header header
+uint8 severity
+string text
+"
+        }
+        "SysStatus" => {
+            "\
+std_msgs/Header header
+uint32 sensors_present
+uint32 sensors_enabled
+uint32 sensors_health
+uint16 load
+uint16 voltage_battery
+int16 current_battery
+int8 battery_remaining
+uint16 drop_rate_comm
+uint16 errors_comm
+uint16 errors_count1
+uint16 errors_count2
+uint16 errors_count3
+uint16 errors_count4
+"
+        }
+        "TimesyncStatus" => {
+            "\
+std_msgs/Header header
+uint64 remote_timestamp_ns
+int64 observed_offset_ns
+int64 estimated_offset_ns
+float32 round_trip_time_ms
+"
+        }
+        "VfrHud" => {
+            "\
+std_msgs/Header header
+float32 airspeed
+float32 groundspeed
+int16 heading
+float32 throttle
+float32 altitude
+float32 climb
+"
+        }
+        _ => return None,
+    })
+}
+
+// HeapSize implementations
+crate::heap_size::impl_heap_size!(
+    Altitude,
+    VfrHud,
+    EstimatorStatus,
+    ExtendedState,
+    SysStatus,
+    State,
+    StatusText,
+    GpsRaw,
+    TimesyncStatus,
+);
+
 // ── Tests ───────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -0,0 +1,539 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Type-erased messages for layers that handle every schema alike.
+//!
+//! Recorders, routers and bridges that move messages of many types keep
+//! them as `Box<dyn Message>`: each knows its schema name and CDR bytes and
+//! can be cloned, and [`from_cdr`] builds one from a schema name and bytes
+//! off the wire. Code that needs the fields again downcasts to the typed
+//! message.
+//!
+//! Every buffer-backed message implements [`Message`] over any `'static`
+//! buffer, and every `CdrFixed` message by value. Schemas that only occur
+//! nested inside another message (such as `edgefirst_msgs/msg/AggregateZone`)
+//! have no typed struct and are not covered.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::message::{from_cdr, Message};
+//! use edgefirst_schemas::sensor_msgs::Image;
+//! use edgefirst_schemas::std_msgs::{ColorRGBA, Header};
+//!
+//! let header = Header::builder().frame_id("camera").build().unwrap();
+//! let color = ColorRGBA { r: 1.0, g: 0.5, b: 0.0, a: 1.0 };
+//! let queue: Vec<Box<dyn Message>> = vec![Box::new(header), Box::new(color)];
+//!
+//! let names: Vec<_> = queue.iter().map(|m| m.schema_name()).collect();
+//! assert_eq!(names, ["std_msgs/msg/Header", "std_msgs/msg/ColorRGBA"]);
+//!
+//! // Off the wire, by schema name.
+//! let received = from_cdr("std_msgs/msg/Header", &queue[0].to_cdr()).unwrap();
+//! let header = received.downcast_ref::<Header<Vec<u8>>>().unwrap();
+//! assert_eq!(header.frame_id(), "camera");
+//! assert!(received.downcast_ref::<Image<Vec<u8>>>().is_none());
+//! ```
+
+#![allow(deprecated)] // DmaBuffer
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt;
+
+use crate::builtin_interfaces::{Duration, Time};
+use crate::cdr::{decode_fixed, encode_fixed, CdrError, Endianness};
+use crate::edgefirst_msgs::*;
+use crate::foxglove_msgs::*;
+use crate::geometry_msgs::*;
+use crate::mavros_msgs::*;
+use crate::msg_spec::{reorder, Resolver};
+use crate::nav_msgs::{MapMetaData, OccupancyGrid, Odometry};
+use crate::rosgraph_msgs::Clock;
+use crate::sensor_msgs::*;
+use crate::std_msgs::*;
+use crate::vision_msgs::{Detection2D, Detection2DArray, Detection3D, ObjectHypothesisWithPose};
+
+/// A message of any schema, usable as `Box<dyn Message>`.
+pub trait Message: Send + Sync + 'static {
+    /// Schema name, e.g. `sensor_msgs/msg/Image`.
+    fn schema_name(&self) -> &'static str;
+
+    /// CDR bytes, encapsulation header included.
+    fn to_cdr(&self) -> Vec<u8>;
+
+    /// Owned copy. Buffer-backed messages are copied into a `Vec<u8>`
+    /// buffer whatever they were backed by.
+    fn clone_box(&self) -> Box<dyn Message>;
+
+    /// `self` as [`Any`], for [`downcast_ref`](dyn Message::downcast_ref).
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl dyn Message {
+    /// The typed message, if this is one of type `T`.
+    pub fn downcast_ref<T: Message>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+}
+
+impl Clone for Box<dyn Message> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl fmt::Debug for dyn Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Message")
+            .field("schema", &self.schema_name())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Errors from [`from_cdr`].
+#[derive(Debug)]
+pub enum MessageError {
+    /// No typed message has this schema name.
+    UnknownSchema(String),
+    /// The bytes did not decode as the schema.
+    Cdr(CdrError),
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageError::UnknownSchema(schema) => write!(f, "unknown schema {}", schema),
+            MessageError::Cdr(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for MessageError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MessageError::Cdr(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CdrError> for MessageError {
+    fn from(e: CdrError) -> Self {
+        MessageError::Cdr(e)
+    }
+}
+
+/// Decode CDR bytes (encapsulation header included) of the message type
+/// named `schema` into an owned, type-erased message. Big-endian input is
+/// converted with [`to_little_endian`] first.
+pub fn from_cdr(schema: &str, cdr: &[u8]) -> Result<Box<dyn Message>, MessageError> {
+    let converted;
+    let cdr = match Endianness::detect(cdr) {
+        Ok(Endianness::Big) => {
+            converted = to_little_endian(schema, cdr)?;
+            &converted[..]
+        }
+        _ => cdr,
+    };
+    decode(schema, cdr).unwrap_or_else(|| Err(MessageError::UnknownSchema(schema.to_string())))
+}
+
+/// Re-encode a CDR message of the registered schema `schema` as
+/// little-endian, the byte order buffer-backed views read in place (their
+/// `from_cdr` rejects big-endian input with
+/// [`CdrError::UnsupportedEndianness`]). Little-endian input is returned
+/// unchanged once it has been checked to decode.
+///
+/// ```rust
+/// use edgefirst_schemas::cdr::{CdrWriter, Endianness};
+/// use edgefirst_schemas::message::to_little_endian;
+/// use edgefirst_schemas::std_msgs::Header;
+///
+/// // A Header from a big-endian DDS participant.
+/// let mut be = vec![0u8; 20];
+/// let mut w = CdrWriter::with_endianness(&mut be, Endianness::Big).unwrap();
+/// w.write_i32(5);
+/// w.write_u32(6);
+/// w.write_string("cam");
+/// w.finish().unwrap();
+///
+/// assert!(Header::from_cdr(&be[..]).is_err());
+/// let header = Header::from_cdr(to_little_endian("std_msgs/msg/Header", &be).unwrap()).unwrap();
+/// assert_eq!(header.frame_id(), "cam");
+/// ```
+pub fn to_little_endian(schema: &str, cdr: &[u8]) -> Result<Vec<u8>, MessageError> {
+    let spec = Resolver::default()
+        .resolve(schema, 0)
+        .map_err(|_| MessageError::UnknownSchema(schema.to_string()))?;
+    Ok(reorder(&spec, cdr, Endianness::Little)?)
+}
+
+macro_rules! impl_message {
+    (
+        views { $($view:ident => $view_name:literal),+ $(,)? }
+        fixed { $($fixed:ident => $fixed_name:literal),+ $(,)? }
+    ) => {
+        $(
+            impl<B: AsRef<[u8]> + Send + Sync + 'static> Message for $view<B> {
+                fn schema_name(&self) -> &'static str {
+                    $view_name
+                }
+                fn to_cdr(&self) -> Vec<u8> {
+                    self.as_cdr().to_vec()
+                }
+                fn clone_box(&self) -> Box<dyn Message> {
+                    Box::new(
+                        $view::from_cdr(self.as_cdr().to_vec())
+                            .expect(concat!("a valid ", $view_name, " decodes again")),
+                    )
+                }
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+            }
+        )+
+        $(
+            impl Message for $fixed {
+                fn schema_name(&self) -> &'static str {
+                    $fixed_name
+                }
+                fn to_cdr(&self) -> Vec<u8> {
+                    encode_fixed(self).expect("fixed-size messages always encode")
+                }
+                fn clone_box(&self) -> Box<dyn Message> {
+                    Box::new(*self)
+                }
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+            }
+        )+
+
+        fn decode(schema: &str, cdr: &[u8]) -> Option<Result<Box<dyn Message>, MessageError>> {
+            let message: Result<Box<dyn Message>, CdrError> = match schema {
+                $($view_name => $view::from_cdr(cdr.to_vec()).map(|m| Box::new(m) as _),)+
+                $($fixed_name => decode_fixed::<$fixed>(cdr).map(|m| Box::new(m) as _),)+
+                _ => return None,
+            };
+            Some(message.map_err(MessageError::from))
+        }
+
+        #[cfg(test)]
+        const SCHEMAS: &[&str] = &[$($view_name,)+ $($fixed_name,)+];
+    };
+}
+
+impl_message! {
+    views {
+        Header => "std_msgs/msg/Header",
+        MultiArrayDimension => "std_msgs/msg/MultiArrayDimension",
+        MultiArrayLayout => "std_msgs/msg/MultiArrayLayout",
+        ByteMultiArray => "std_msgs/msg/ByteMultiArray",
+        Float32MultiArray => "std_msgs/msg/Float32MultiArray",
+        Float64MultiArray => "std_msgs/msg/Float64MultiArray",
+        Int16MultiArray => "std_msgs/msg/Int16MultiArray",
+        Int32MultiArray => "std_msgs/msg/Int32MultiArray",
+        Int64MultiArray => "std_msgs/msg/Int64MultiArray",
+        Int8MultiArray => "std_msgs/msg/Int8MultiArray",
+        UInt16MultiArray => "std_msgs/msg/UInt16MultiArray",
+        UInt32MultiArray => "std_msgs/msg/UInt32MultiArray",
+        UInt64MultiArray => "std_msgs/msg/UInt64MultiArray",
+        UInt8MultiArray => "std_msgs/msg/UInt8MultiArray",
+        AccelStamped => "geometry_msgs/msg/AccelStamped",
+        TwistStamped => "geometry_msgs/msg/TwistStamped",
+        InertiaStamped => "geometry_msgs/msg/InertiaStamped",
+        PointStamped => "geometry_msgs/msg/PointStamped",
+        PoseStamped => "geometry_msgs/msg/PoseStamped",
+        Polygon => "geometry_msgs/msg/Polygon",
+        PolygonStamped => "geometry_msgs/msg/PolygonStamped",
+        TransformStamped => "geometry_msgs/msg/TransformStamped",
+        Odometry => "nav_msgs/msg/Odometry",
+        OccupancyGrid => "nav_msgs/msg/OccupancyGrid",
+        CompressedImage => "sensor_msgs/msg/CompressedImage",
+        Image => "sensor_msgs/msg/Image",
+        Imu => "sensor_msgs/msg/Imu",
+        NavSatFix => "sensor_msgs/msg/NavSatFix",
+        PointField => "sensor_msgs/msg/PointField",
+        PointCloud2 => "sensor_msgs/msg/PointCloud2",
+        CameraInfo => "sensor_msgs/msg/CameraInfo",
+        MagneticField => "sensor_msgs/msg/MagneticField",
+        FluidPressure => "sensor_msgs/msg/FluidPressure",
+        Temperature => "sensor_msgs/msg/Temperature",
+        BatteryState => "sensor_msgs/msg/BatteryState",
+        LaserScan => "sensor_msgs/msg/LaserScan",
+        LaserEcho => "sensor_msgs/msg/LaserEcho",
+        MultiEchoLaserScan => "sensor_msgs/msg/MultiEchoLaserScan",
+        Joy => "sensor_msgs/msg/Joy",
+        ChannelFloat32 => "sensor_msgs/msg/ChannelFloat32",
+        PointCloud => "sensor_msgs/msg/PointCloud",
+        FoxgloveCompressedVideo => "foxglove_msgs/msg/CompressedVideo",
+        FoxgloveTextAnnotation => "foxglove_msgs/msg/TextAnnotation",
+        FoxglovePointAnnotation => "foxglove_msgs/msg/PointsAnnotation",
+        FoxgloveImageAnnotation => "foxglove_msgs/msg/ImageAnnotations",
+        FoxgloveSceneEntity => "foxglove_msgs/msg/SceneEntity",
+        FoxgloveSceneUpdate => "foxglove_msgs/msg/SceneUpdate",
+        FoxgloveLaserScan => "foxglove_msgs/msg/LaserScan",
+        FoxgloveLocationFix => "foxglove_msgs/msg/LocationFix",
+        FoxgloveCameraCalibration => "foxglove_msgs/msg/CameraCalibration",
+        FoxglovePoseInFrame => "foxglove_msgs/msg/PoseInFrame",
+        FoxglovePosesInFrame => "foxglove_msgs/msg/PosesInFrame",
+        FoxgloveGeoJson => "foxglove_msgs/msg/GeoJSON",
+        FoxgloveRawImage => "foxglove_msgs/msg/RawImage",
+        FoxgloveLog => "foxglove_msgs/msg/Log",
+        Altitude => "mavros_msgs/msg/Altitude",
+        VfrHud => "mavros_msgs/msg/VfrHud",
+        EstimatorStatus => "mavros_msgs/msg/EstimatorStatus",
+        ExtendedState => "mavros_msgs/msg/ExtendedState",
+        SysStatus => "mavros_msgs/msg/SysStatus",
+        State => "mavros_msgs/msg/State",
+        StatusText => "mavros_msgs/msg/StatusText",
+        GpsRaw => "mavros_msgs/msg/GPSRAW",
+        TimesyncStatus => "mavros_msgs/msg/TimesyncStatus",
+        Mask => "edgefirst_msgs/msg/Mask",
+        DmaBuffer => "edgefirst_msgs/msg/DmaBuffer",
+        LocalTime => "edgefirst_msgs/msg/LocalTime",
+        RadarCube => "edgefirst_msgs/msg/RadarCube",
+        RadarInfo => "edgefirst_msgs/msg/RadarInfo",
+        Track => "edgefirst_msgs/msg/Track",
+        DetectBox => "edgefirst_msgs/msg/Box",
+        Detect => "edgefirst_msgs/msg/Detect",
+        CameraFrame => "edgefirst_msgs/msg/CameraFrame",
+        Model => "edgefirst_msgs/msg/Model",
+        ModelInfo => "edgefirst_msgs/msg/ModelInfo",
+        Vibration => "edgefirst_msgs/msg/Vibration",
+        Aggregate => "edgefirst_msgs/msg/Aggregate",
+        Attribute => "edgefirst_msgs/msg/Attribute",
+        VideoStreamRequest => "edgefirst_msgs/msg/VideoStreamRequest",
+        VideoStreamInfo => "edgefirst_msgs/msg/VideoStreamInfo",
+        CameraControl => "edgefirst_msgs/msg/CameraControl",
+        CameraStatus => "edgefirst_msgs/msg/CameraStatus",
+        Provenance => "edgefirst_msgs/msg/Provenance",
+        ShmRing => "edgefirst_msgs/msg/ShmRing",
+        Heartbeat => "edgefirst_msgs/msg/Heartbeat",
+        BlobRef => "edgefirst_msgs/msg/BlobRef",
+        ImageWithInfo => "edgefirst_msgs/msg/ImageWithInfo",
+        FusionDebug => "edgefirst_msgs/msg/FusionDebug",
+        ServiceResult => "edgefirst_msgs/msg/ServiceResult",
+        ObjectHypothesisWithPose => "vision_msgs/msg/ObjectHypothesisWithPose",
+        Detection2D => "vision_msgs/msg/Detection2D",
+        Detection2DArray => "vision_msgs/msg/Detection2DArray",
+        Detection3D => "vision_msgs/msg/Detection3D",
+    }
+    fixed {
+        Time => "builtin_interfaces/msg/Time",
+        Duration => "builtin_interfaces/msg/Duration",
+        ColorRGBA => "std_msgs/msg/ColorRGBA",
+        Vector3 => "geometry_msgs/msg/Vector3",
+        Point => "geometry_msgs/msg/Point",
+        Point32 => "geometry_msgs/msg/Point32",
+        Quaternion => "geometry_msgs/msg/Quaternion",
+        Pose => "geometry_msgs/msg/Pose",
+        Pose2D => "geometry_msgs/msg/Pose2D",
+        Transform => "geometry_msgs/msg/Transform",
+        Accel => "geometry_msgs/msg/Accel",
+        Twist => "geometry_msgs/msg/Twist",
+        PoseWithCovariance => "geometry_msgs/msg/PoseWithCovariance",
+        TwistWithCovariance => "geometry_msgs/msg/TwistWithCovariance",
+        Inertia => "geometry_msgs/msg/Inertia",
+        MapMetaData => "nav_msgs/msg/MapMetaData",
+        NavSatStatus => "sensor_msgs/msg/NavSatStatus",
+        RegionOfInterest => "sensor_msgs/msg/RegionOfInterest",
+        Clock => "rosgraph_msgs/msg/Clock",
+        Date => "edgefirst_msgs/msg/Date",
+        FoxglovePoint2 => "foxglove_msgs/msg/FoxglovePoint2",
+        FoxgloveColor => "foxglove_msgs/msg/FoxgloveColor",
+        FoxgloveCircleAnnotations => "foxglove_msgs/msg/FoxgloveCircleAnnotations",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema_registry::{definition, list_schemas};
+
+    #[test]
+    fn typed_messages_erase_and_decode_by_name() {
+        for schema in SCHEMAS {
+            if *schema == "rosgraph_msgs/msg/Clock" {
+                continue;
+            }
+            assert!(definition(schema).is_some(), "{}", schema);
+        }
+        // Every listed schema has a typed message but those only nested.
+        let untyped: Vec<_> = list_schemas()
+            .into_iter()
+            .filter(|s| !SCHEMAS.contains(s))
+            .collect();
+        assert_eq!(
+            untyped,
+            [
+                "edgefirst_msgs/msg/AggregateCount",
+                "edgefirst_msgs/msg/AggregateZone",
+                "edgefirst_msgs/msg/AttributeEntry",
+                "edgefirst_msgs/msg/CameraPlane",
+                "edgefirst_msgs/msg/FusionAssociation",
+                "edgefirst_msgs/msg/ProvenanceInput",
+                "edgefirst_msgs/msg/VideoStreamVariant",
+            ]
+        );
+
+        let image = Image::builder()
+            .frame_id("cam")
+            .width(2)
+            .height(1)
+            .encoding("mono8")
+            .step(2)
+            .data(&[1, 2])
+            .build()
+            .unwrap();
+        let boxed: Box<dyn Message> = Box::new(image);
+        let copy = boxed.clone();
+        assert_eq!(copy.schema_name(), "sensor_msgs/msg/Image");
+        assert_eq!(copy.to_cdr(), boxed.to_cdr());
+        let decoded = from_cdr("sensor_msgs/msg/Image", &boxed.to_cdr()).unwrap();
+        assert_eq!(
+            decoded.downcast_ref::<Image<Vec<u8>>>().unwrap().data(),
+            [1, 2]
+        );
+        assert!(format!("{:?}", decoded).contains("sensor_msgs/msg/Image"));
+
+        let time: Box<dyn Message> = Box::new(Time::new(3, 4));
+        let back = from_cdr("builtin_interfaces/msg/Time", &time.to_cdr()).unwrap();
+        assert_eq!(back.downcast_ref::<Time>(), Some(&Time::new(3, 4)));
+
+        assert!(matches!(
+            from_cdr("sensor_msgs/msg/Image", &[0, 1, 0, 0]),
+            Err(MessageError::Cdr(_))
+        ));
+        assert!(matches!(
+            from_cdr("unknown_msgs/msg/Foo", &[]),
+            Err(MessageError::UnknownSchema(_))
+        ));
+    }
+
+    #[test]
+    fn big_endian_views_convert_by_schema() {
+        use crate::cdr::{CdrError, CdrWriter, Endianness};
+
+        let image = Image::builder()
+            .stamp(Time::new(7, 8))
+            .frame_id("cam")
+            .width(3)
+            .height(1)
+            .encoding("rgb8")
+            .step(9)
+            .data(&[1, 2, 3, 4, 5, 6, 7, 8, 9])
+            .build()
+            .unwrap();
+
+        // The same Image as a big-endian participant would publish it.
+        let mut be = vec![0u8; image.as_cdr().len()];
+        let mut w = CdrWriter::with_endianness(&mut be, Endianness::Big).unwrap();
+        w.write_i32(7);
+        w.write_u32(8);
+        w.write_string("cam");
+        w.write_u32(1);
+        w.write_u32(3);
+        w.write_string("rgb8");
+        w.write_u8(0);
+        w.write_u32(9);
+        w.write_bytes(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        w.finish().unwrap();
+
+        assert!(matches!(
+            Image::from_cdr(&be[..]),
+            Err(CdrError::UnsupportedEndianness)
+        ));
+        let le = to_little_endian("sensor_msgs/msg/Image", &be).unwrap();
+        assert_eq!(le, image.as_cdr());
+        let view = Image::from_cdr(le).unwrap();
+        assert_eq!((view.width(), view.encoding()), (3, "rgb8"));
+
+        let decoded = from_cdr("sensor_msgs/msg/Image", &be).unwrap();
+        assert_eq!(decoded.to_cdr(), image.as_cdr());
+
+        // Little-endian input passes through; truncated input is rejected.
+        assert_eq!(
+            to_little_endian("sensor_msgs/msg/Image", image.as_cdr()).unwrap(),
+            image.as_cdr()
+        );
+        assert!(matches!(
+            to_little_endian("sensor_msgs/msg/Image", &be[..be.len() - 1]),
+            Err(MessageError::Cdr(_))
+        ));
+        assert!(matches!(
+            to_little_endian("unknown_msgs/msg/Foo", &be),
+            Err(MessageError::UnknownSchema(_))
+        ));
+    }
+
+    #[test]
+    fn builders_encode_big_endian() {
+        use crate::cdr::{CdrEncode, CdrSerializer};
+
+        fn check<M: CdrEncode>(schema: &str, msg: &M) {
+            let mut le = vec![0u8; msg.cdr_size()];
+            msg.encode_into_slice(&mut le).unwrap();
+            let mut ser = CdrSerializer::new();
+            let be = ser.serialize_with(msg, Endianness::Big).unwrap();
+            assert!(
+                matches!(Endianness::detect(be), Ok(Endianness::Big)),
+                "{}",
+                schema
+            );
+            assert_eq!(to_little_endian(schema, be).unwrap(), le, "{}", schema);
+            assert_eq!(from_cdr(schema, be).unwrap().to_cdr(), le, "{}", schema);
+        }
+
+        let boxes = [DetectBoxView {
+            center_x: 0.5,
+            center_y: 0.25,
+            width: 0.125,
+            height: 0.5,
+            label: "car",
+            score: 0.75,
+            distance: 12.0,
+            speed: -1.5,
+            track_id: "t1",
+            track_lifetime: 3,
+            track_created: Time::new(1, 2),
+        }];
+        let mut detect = Detect::builder();
+        detect.stamp(Time::new(5, 6)).frame_id("cam").boxes(&boxes);
+        check("edgefirst_msgs/msg/Detect", &detect);
+
+        let mut cube = RadarCube::builder();
+        cube.timestamp(1 << 40)
+            .layout(&[1, 2])
+            .shape(&[2, 3])
+            .scales(&[0.5, 2.0])
+            .cube(&[-1, 2, -3, 4, -5, 6])
+            .is_complex(true);
+        check("edgefirst_msgs/msg/RadarCube", &cube);
+
+        let dim = [MultiArrayDimensionView {
+            label: "x",
+            size: 3,
+            stride: 3,
+        }];
+        let mut array = Float64MultiArray::builder();
+        array.dim(&dim).data(&[1.0, -2.0, 3.5]);
+        check("std_msgs/msg/Float64MultiArray", &array);
+
+        let mut grid = OccupancyGrid::builder();
+        grid.frame_id("map").data(&[0, -1, 100]);
+        check("nav_msgs/msg/OccupancyGrid", &grid);
+
+        check(
+            "geometry_msgs/msg/Vector3",
+            &Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+        );
+    }
+}
@@ -0,0 +1,432 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Message layouts parsed from the registry's `.msg` definitions.
+//!
+//! Shared by the modules that walk messages of any schema at runtime
+//! (JSON transcoding, arbitrary message generation, byte order conversion,
+//! the C API's generic message handle) instead of going through the typed
+//! views.
+
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::cdr::{CdrCursor, CdrError, CdrSizer, CdrWriter, Endianness};
+use crate::schema_registry::{definition, nested_schema, parse_schema};
+
+/// Nesting depth beyond which a definition is assumed to be recursive.
+const MAX_DEPTH: usize = 32;
+
+// The details are only reported by the JSON transcoder and `dynamic`.
+#[cfg_attr(not(any(feature = "json", feature = "dynamic")), allow(dead_code))]
+pub(crate) enum SpecError {
+    /// No definition is registered for the schema, or for a type it nests.
+    UnknownSchema(String),
+    /// A line of the schema's definition could not be interpreted.
+    InvalidDefinition { schema: String, line: String },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Prim {
+    Bool,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+}
+
+pub(crate) enum Elem {
+    Prim(Prim),
+    /// Upper bound in bytes for `string<=N`.
+    String(Option<usize>),
+    Msg(Rc<Spec>),
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Arity {
+    Single,
+    Fixed(usize),
+    /// Upper bound in elements for `T[<=N]`.
+    Seq(Option<usize>),
+}
+
+pub(crate) struct Field {
+    #[cfg_attr(not(any(feature = "json", feature = "dynamic")), allow(dead_code))]
+    pub name: &'static str,
+    pub elem: Elem,
+    pub arity: Arity,
+}
+
+pub(crate) struct Spec {
+    pub fields: Vec<Field>,
+}
+
+/// Parses definitions into [`Spec`]s, sharing nested types between fields.
+#[derive(Default)]
+pub(crate) struct Resolver {
+    cache: BTreeMap<String, Rc<Spec>>,
+}
+
+impl Resolver {
+    pub fn resolve(&mut self, schema: &str, depth: usize) -> Result<Rc<Spec>, SpecError> {
+        if let Some(spec) = self.cache.get(schema) {
+            return Ok(spec.clone());
+        }
+        let text = definition(schema).ok_or_else(|| SpecError::UnknownSchema(schema.into()))?;
+        let invalid = |line: &str| SpecError::InvalidDefinition {
+            schema: schema.into(),
+            line: line.into(),
+        };
+        if depth > MAX_DEPTH {
+            return Err(invalid("nesting too deep"));
+        }
+        let package = parse_schema(schema).map_or("", |(p, _)| p);
+
+        let mut fields = Vec::new();
+        for raw in text.lines() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            let mut tokens = line.split_whitespace();
+            let (Some(ty), Some(name)) = (tokens.next(), tokens.next()) else {
+                if line.is_empty() {
+                    continue;
+                }
+                return Err(invalid(raw));
+            };
+            // Constants: `TYPE NAME=VALUE` or `TYPE NAME = VALUE`.
+            if name.contains('=') || tokens.next().is_some_and(|t| t.starts_with('=')) {
+                continue;
+            }
+            let (base, arity) = split_array(ty).ok_or_else(|| invalid(raw))?;
+            let elem = match base {
+                "bool" => Elem::Prim(Prim::Bool),
+                "int8" => Elem::Prim(Prim::I8),
+                "uint8" | "byte" | "char" => Elem::Prim(Prim::U8),
+                "int16" => Elem::Prim(Prim::I16),
+                "uint16" => Elem::Prim(Prim::U16),
+                "int32" => Elem::Prim(Prim::I32),
+                "uint32" => Elem::Prim(Prim::U32),
+                "int64" => Elem::Prim(Prim::I64),
+                "uint64" => Elem::Prim(Prim::U64),
+                "float32" => Elem::Prim(Prim::F32),
+                "float64" => Elem::Prim(Prim::F64),
+                "string" => Elem::String(None),
+                _ if base.starts_with("string<=") => Elem::String(Some(
+                    base["string<=".len()..].parse().map_err(|_| invalid(raw))?,
+                )),
+                _ if base.starts_with("wstring") => return Err(invalid(raw)),
+                _ => Elem::Msg(self.resolve(&nested_schema(package, base), depth + 1)?),
+            };
+            fields.push(Field { name, elem, arity });
+        }
+
+        let spec = Rc::new(Spec { fields });
+        self.cache.insert(schema.to_owned(), spec.clone());
+        Ok(spec)
+    }
+}
+
+/// Split `float64[9]`, `int32[]` or `uint8[<=4]` into base type and arity.
+fn split_array(ty: &str) -> Option<(&str, Arity)> {
+    let Some(open) = ty.find('[') else {
+        return Some((ty, Arity::Single));
+    };
+    let inner = ty[open + 1..].strip_suffix(']')?;
+    let arity = if inner.is_empty() {
+        Arity::Seq(None)
+    } else if let Some(bound) = inner.strip_prefix("<=") {
+        Arity::Seq(Some(bound.parse().ok()?))
+    } else {
+        Arity::Fixed(inner.parse().ok()?)
+    };
+    Some((&ty[..open], arity))
+}
+
+/// Writes a message in two passes, sizing then encoding, from one walk.
+pub(crate) trait Sink {
+    fn u8(&mut self, v: u8);
+    fn u16(&mut self, v: u16);
+    fn u32(&mut self, v: u32);
+    fn u64(&mut self, v: u64);
+    fn string(&mut self, s: &str);
+    #[cfg_attr(
+        not(any(feature = "json", feature = "arbitrary", feature = "dynamic")),
+        allow(dead_code)
+    )]
+    fn raw(&mut self, b: &[u8]);
+}
+
+impl Sink for CdrSizer {
+    fn u8(&mut self, _: u8) {
+        self.size_u8();
+    }
+    fn u16(&mut self, _: u16) {
+        self.size_u16();
+    }
+    fn u32(&mut self, _: u32) {
+        self.size_u32();
+    }
+    fn u64(&mut self, _: u64) {
+        self.size_u64();
+    }
+    fn string(&mut self, s: &str) {
+        self.size_string(s);
+    }
+    fn raw(&mut self, b: &[u8]) {
+        self.size_raw(b.len());
+    }
+}
+
+impl Sink for CdrWriter<'_> {
+    fn u8(&mut self, v: u8) {
+        self.write_u8(v);
+    }
+    fn u16(&mut self, v: u16) {
+        self.write_u16(v);
+    }
+    fn u32(&mut self, v: u32) {
+        self.write_u32(v);
+    }
+    fn u64(&mut self, v: u64) {
+        self.write_u64(v);
+    }
+    fn string(&mut self, s: &str) {
+        self.write_string(s);
+    }
+    fn raw(&mut self, b: &[u8]) {
+        self.write_raw(b);
+    }
+}
+
+/// Write a message with every field zero, empty or `false`; fixed arrays
+/// keep their length.
+#[cfg_attr(not(any(feature = "ffi", feature = "dynamic")), allow(dead_code))]
+pub(crate) fn write_default(spec: &Spec, s: &mut dyn Sink) {
+    for f in &spec.fields {
+        write_default_field(f, s);
+    }
+}
+
+/// [`write_default`] for a single field.
+#[cfg_attr(not(any(feature = "ffi", feature = "dynamic")), allow(dead_code))]
+pub(crate) fn write_default_field(f: &Field, s: &mut dyn Sink) {
+    let count = match f.arity {
+        Arity::Single => 1,
+        Arity::Fixed(n) => n,
+        Arity::Seq(_) => {
+            s.u32(0);
+            0
+        }
+    };
+    for _ in 0..count {
+        match &f.elem {
+            Elem::Prim(p) => match p {
+                Prim::Bool | Prim::I8 | Prim::U8 => s.u8(0),
+                Prim::I16 | Prim::U16 => s.u16(0),
+                Prim::I32 | Prim::U32 | Prim::F32 => s.u32(0),
+                Prim::I64 | Prim::U64 | Prim::F64 => s.u64(0),
+            },
+            Elem::String(_) => s.string(""),
+            Elem::Msg(nested) => write_default(nested, s),
+        }
+    }
+}
+
+/// Walk a message without keeping its values, checking that it decodes
+/// against `spec`: sequence and string bounds, UTF-8 strings and `bool`
+/// values.
+#[cfg_attr(not(feature = "ffi"), allow(dead_code))]
+pub(crate) fn skip_msg(spec: &Spec, c: &mut CdrCursor<'_>) -> Result<(), CdrError> {
+    for f in &spec.fields {
+        let count = match f.arity {
+            Arity::Single => 1,
+            Arity::Fixed(n) => n,
+            Arity::Seq(Some(bound)) => c.read_seq_len_bounded(bound)?,
+            Arity::Seq(None) => {
+                let raw = c.read_u32()?;
+                c.check_seq_count(raw, 1)?
+            }
+        };
+        if let (Elem::Prim(Prim::U8), Arity::Fixed(_) | Arity::Seq(_)) = (&f.elem, f.arity) {
+            c.read_raw(count)?;
+            continue;
+        }
+        for _ in 0..count {
+            match &f.elem {
+                Elem::Prim(p) => match p {
+                    Prim::Bool => {
+                        c.read_bool()?;
+                    }
+                    Prim::I8 | Prim::U8 => {
+                        c.read_u8()?;
+                    }
+                    Prim::I16 | Prim::U16 => {
+                        c.read_u16()?;
+                    }
+                    Prim::I32 | Prim::U32 | Prim::F32 => {
+                        c.read_u32()?;
+                    }
+                    Prim::I64 | Prim::U64 | Prim::F64 => {
+                        c.read_u64()?;
+                    }
+                },
+                Elem::String(Some(bound)) => {
+                    c.read_string_bounded(*bound)?;
+                }
+                Elem::String(None) => {
+                    c.read_string()?;
+                }
+                Elem::Msg(nested) => skip_msg(nested, c)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Copy a CDR message of `spec` into byte order `order`, checking that it
+/// decodes. Alignment does not depend on the byte order, so converting only
+/// reverses each multi-byte value, length prefixes included, in place.
+pub(crate) fn reorder(spec: &Spec, cdr: &[u8], order: Endianness) -> Result<Vec<u8>, CdrError> {
+    let mut c = CdrCursor::detect(cdr)?;
+    let mut out = cdr.to_vec();
+    let swap = c.endianness() != order;
+    reorder_msg(spec, &mut c, swap.then_some(&mut out[..]))?;
+    c.expect_end()?;
+    out[..4].copy_from_slice(&order.header());
+    Ok(out)
+}
+
+fn reorder_msg(
+    spec: &Spec,
+    c: &mut CdrCursor<'_>,
+    mut out: Option<&mut [u8]>,
+) -> Result<(), CdrError> {
+    // Read a value of `size` bytes, reversing it in `out`.
+    fn scalar(
+        c: &mut CdrCursor<'_>,
+        out: &mut Option<&mut [u8]>,
+        size: usize,
+    ) -> Result<(), CdrError> {
+        c.align(size);
+        let at = c.offset();
+        c.skip(size)?;
+        if let Some(out) = out {
+            out[at..at + size].reverse();
+        }
+        Ok(())
+    }
+    for f in &spec.fields {
+        let count = match f.arity {
+            Arity::Single => 1,
+            Arity::Fixed(n) => n,
+            Arity::Seq(bound) => {
+                c.align(4);
+                let at = c.offset();
+                let count = match bound {
+                    Some(bound) => c.read_seq_len_bounded(bound)?,
+                    None => {
+                        let raw = c.read_u32()?;
+                        c.check_seq_count(raw, 1)?
+                    }
+                };
+                if let Some(out) = &mut out {
+                    out[at..at + 4].reverse();
+                }
+                count
+            }
+        };
+        if let (Elem::Prim(Prim::U8), Arity::Fixed(_) | Arity::Seq(_)) = (&f.elem, f.arity) {
+            c.read_raw(count)?;
+            continue;
+        }
+        for _ in 0..count {
+            match &f.elem {
+                Elem::Prim(p) => match p {
+                    Prim::Bool => {
+                        c.read_bool()?;
+                    }
+                    Prim::I8 | Prim::U8 => {
+                        c.read_u8()?;
+                    }
+                    Prim::I16 | Prim::U16 => scalar(c, &mut out, 2)?,
+                    Prim::I32 | Prim::U32 | Prim::F32 => scalar(c, &mut out, 4)?,
+                    Prim::I64 | Prim::U64 | Prim::F64 => scalar(c, &mut out, 8)?,
+                },
+                Elem::String(bound) => {
+                    c.align(4);
+                    let at = c.offset();
+                    match bound {
+                        Some(bound) => c.read_string_bounded(*bound)?,
+                        None => c.read_string()?,
+                    };
+                    if let Some(out) = &mut out {
+                        out[at..at + 4].reverse();
+                    }
+                }
+                Elem::Msg(nested) => reorder_msg(nested, c, out.as_deref_mut())?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Walk two messages of `spec` in lockstep and compare their field values,
+/// the way `PartialEq` on the decoded structs would: floats by value (so
+/// `NaN` never equals itself), strings by content, sequences by length and
+/// elements. Byte order, alignment padding and trailing bytes are ignored.
+#[cfg_attr(not(feature = "ffi"), allow(dead_code))]
+pub(crate) fn equal_msg(
+    spec: &Spec,
+    a: &mut CdrCursor<'_>,
+    b: &mut CdrCursor<'_>,
+) -> Result<bool, CdrError> {
+    let mut equal = true;
+    for f in &spec.fields {
+        let (na, nb) = match f.arity {
+            Arity::Single => (1, 1),
+            Arity::Fixed(n) => (n, n),
+            Arity::Seq(Some(bound)) => (
+                a.read_seq_len_bounded(bound)?,
+                b.read_seq_len_bounded(bound)?,
+            ),
+            Arity::Seq(None) => {
+                let (ra, rb) = (a.read_u32()?, b.read_u32()?);
+                (a.check_seq_count(ra, 1)?, b.check_seq_count(rb, 1)?)
+            }
+        };
+        if na != nb {
+            return Ok(false);
+        }
+        if let (Elem::Prim(Prim::U8), Arity::Fixed(_) | Arity::Seq(_)) = (&f.elem, f.arity) {
+            equal &= a.read_raw(na)? == b.read_raw(nb)?;
+            continue;
+        }
+        for _ in 0..na {
+            equal &= match &f.elem {
+                Elem::Prim(p) => match p {
+                    Prim::Bool => a.read_bool()? == b.read_bool()?,
+                    Prim::I8 | Prim::U8 => a.read_u8()? == b.read_u8()?,
+                    Prim::I16 | Prim::U16 => a.read_u16()? == b.read_u16()?,
+                    Prim::I32 | Prim::U32 => a.read_u32()? == b.read_u32()?,
+                    Prim::I64 | Prim::U64 => a.read_u64()? == b.read_u64()?,
+                    Prim::F32 => a.read_f32()? == b.read_f32()?,
+                    Prim::F64 => a.read_f64()? == b.read_f64()?,
+                },
+                Elem::String(Some(bound)) => {
+                    a.read_string_bounded(*bound)? == b.read_string_bounded(*bound)?
+                }
+                Elem::String(None) => a.read_string()? == b.read_string()?,
+                Elem::Msg(nested) => equal_msg(nested, a, b)?,
+            };
+        }
+    }
+    Ok(equal)
+}
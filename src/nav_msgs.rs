@@ -3,12 +3,21 @@
 
 //! ROS 2 `nav_msgs` message types.
 //!
-//! Buffer-backed: `Odometry`
+//! CdrFixed: `MapMetaData`
+//!
+//! Buffer-backed: `Odometry`, `OccupancyGrid`
+//!
+//! `OccupancyGrid` renders to and from `mono8` / `rgb8`
+//! [`Image`](crate::sensor_msgs::Image) messages (with the `std` feature),
+//! so grids can be shown by image panels and recorded as video.
 
 use crate::builtin_interfaces::Time;
 use crate::cdr::*;
-use crate::geometry_msgs::{PoseWithCovariance, TwistWithCovariance};
+use crate::geometry_msgs::{Pose, PoseWithCovariance, TwistWithCovariance};
+#[cfg(feature = "std")]
+use crate::sensor_msgs::{pixel_convert::ConvertError, Image};
 use crate::std_msgs::Header;
+use alloc::{vec, vec::Vec};
 
 // ── Odometry<B> ─────────────────────────────────────────────────────
 //
@@ -43,16 +52,17 @@ impl<B> Odometry<B> {
 
 impl<B: AsRef<[u8]>> Odometry<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         let o0 = c.offset();
-        let _ = c.read_string()?; // child_frame_id
+        let _ = c.read_string().field("child_frame_id")?;
         c.align(8);
         let o1 = c.offset();
         PoseWithCovariance::read_cdr(&mut c)?;
         let o2 = c.offset();
         TwistWithCovariance::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(Odometry {
             offsets: [o0, o1, o2],
             buf,
@@ -61,7 +71,7 @@ impl<B: AsRef<[u8]>> Odometry<B> {
 
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -127,14 +137,495 @@ impl Odometry<Vec<u8>> {
     }
 }
 
+// ── MapMetaData ─────────────────────────────────────────────────────
+
+/// Geometry of an occupancy grid: cell size in metres, dimensions in cells
+/// and the pose of cell (0, 0) in the map frame.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct MapMetaData {
+    pub map_load_time: Time,
+    pub resolution: f32,
+    pub width: u32,
+    pub height: u32,
+    pub origin: Pose,
+}
+
+impl CdrFixed for MapMetaData {
+    // Time (8) + resolution, width, height (12) + pad to 8 (4) + Pose (56)
+    const CDR_SIZE: usize = 80;
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(MapMetaData {
+            map_load_time: Time::read_cdr(cursor)?,
+            resolution: cursor.read_f32()?,
+            width: cursor.read_u32()?,
+            height: cursor.read_u32()?,
+            origin: Pose::read_cdr(cursor)?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        self.map_load_time.write_cdr(writer);
+        writer.write_f32(self.resolution);
+        writer.write_u32(self.width);
+        writer.write_u32(self.height);
+        self.origin.write_cdr(writer);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        Time::size_cdr(sizer);
+        sizer.size_f32();
+        sizer.size_u32();
+        sizer.size_u32();
+        Pose::size_cdr(sizer);
+    }
+}
+
+// ── OccupancyGrid<B> ────────────────────────────────────────────────
+//
+// CDR layout: Header → offsets[0] (start of MapMetaData, 4-aligned; its
+//   Pose realigns to 8), uint32 count + int8[] data → offsets[1].
+//
+// Cells are row-major starting at the origin, values 0..=100 give the
+// occupancy probability in percent and -1 marks unknown cells.
+
+pub struct OccupancyGrid<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
+
+impl<B> OccupancyGrid<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> OccupancyGrid<C> {
+        OccupancyGrid {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> OccupancyGrid<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let pre = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
+        c.align(4);
+        let o0 = c.offset();
+        MapMetaData::read_cdr(&mut c)?;
+        let o1 = c.offset();
+        c.read_bytes().field("data")?;
+        c.expect_end()?;
+        Ok(OccupancyGrid {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
+
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn info(&self) -> MapMetaData {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0]);
+        MapMetaData::read_cdr(&mut c).expect("info validated during from_cdr")
+    }
+    pub fn data_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), align(self.offsets[1], 4))
+    }
+    /// Cell values, borrowed from the CDR buffer.
+    pub fn data(&self) -> &[i8] {
+        let at = align(self.offsets[1], 4);
+        rd_slice_i8(self.buf.as_ref(), at + 4, self.data_len() as usize)
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl OccupancyGrid<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `OccupancyGridBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> OccupancyGridBuilder<'a> {
+        OccupancyGridBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> OccupancyGrid<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── OccupancyGridBuilder<'a> ────────────────────────────────────────
+
+/// Builder for `OccupancyGrid<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `data` is borrowed for zero-copy input.
+pub struct OccupancyGridBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    info: MapMetaData,
+    data: &'a [i8],
+}
+
+impl<'a> Default for OccupancyGridBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            info: MapMetaData {
+                map_load_time: Time { sec: 0, nanosec: 0 },
+                resolution: 0.0,
+                width: 0,
+                height: 0,
+                origin: Pose {
+                    position: crate::geometry_msgs::Point {
+                        x: 0.0,
+                        y: 0.0,
+                        z: 0.0,
+                    },
+                    orientation: crate::geometry_msgs::Quaternion {
+                        x: 0.0,
+                        y: 0.0,
+                        z: 0.0,
+                        w: 1.0,
+                    },
+                },
+            },
+            data: &[],
+        }
+    }
+}
+
+impl<'a> OccupancyGridBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn info(&mut self, v: MapMetaData) -> &mut Self {
+        self.info = v;
+        self
+    }
+    pub fn data(&mut self, d: &'a [i8]) -> &mut Self {
+        self.data = d;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.align(4);
+        MapMetaData::size_cdr(&mut s);
+        s.size_bytes(self.data.len());
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        self.info.write_cdr(&mut w);
+        let bytes = unsafe {
+            core::slice::from_raw_parts(self.data.as_ptr() as *const u8, self.data.len())
+        };
+        w.write_bytes(bytes);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<OccupancyGrid<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        OccupancyGrid::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+crate::cdr::impl_cdr_encode!(OccupancyGridBuilder);
+
+// ── Image rendering ─────────────────────────────────────────────────
+//
+// Same conventions as the ROS map_server: free cells are white (254),
+// occupied cells black (0), unknown cells grey (205), and probabilities in
+// between shade linearly. Grid row 0 is at the origin, i.e. the bottom of
+// the map, so rows are flipped to put it at the bottom of the image.
+
+/// Thresholds for reading an occupancy grid back from an image.
+///
+/// A pixel's occupancy is `(255 - value) / 255` (or `value / 255` when
+/// `negate` is set); above `occupied` the cell is 100, below `free` it is
+/// 0, and anything in between is unknown (-1). The defaults are the
+/// map_server ones.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridThresholds {
+    pub occupied: f32,
+    pub free: f32,
+    pub negate: bool,
+}
+
+#[cfg(feature = "std")]
+impl Default for GridThresholds {
+    fn default() -> Self {
+        GridThresholds {
+            occupied: 0.65,
+            free: 0.196,
+            negate: false,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn cell_to_grey(cell: i8) -> u8 {
+    match cell {
+        0..=100 => (255 - (cell as u32 * 255) / 100).min(254) as u8,
+        _ => 205,
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: AsRef<[u8]>> OccupancyGrid<B> {
+    /// Render the grid as a `mono8` or `rgb8` image with the grid's stamp
+    /// and frame, one pixel per cell.
+    pub fn to_image(&self, encoding: &str) -> Result<Image<Vec<u8>>, ConvertError> {
+        use crate::sensor_msgs::pixel_convert::encodings::{MONO8, RGB8};
+
+        let channels = match encoding {
+            MONO8 => 1,
+            RGB8 => 3,
+            _ => {
+                return Err(ConvertError::Unsupported {
+                    from: "occupancy_grid".into(),
+                    to: encoding.into(),
+                })
+            }
+        };
+        let info = self.info();
+        let (width, height) = (info.width as usize, info.height as usize);
+        let cells = self.data();
+        if cells.len() != width * height {
+            return Err(ConvertError::InvalidFrame);
+        }
+        let mut pixels = Vec::with_capacity(cells.len() * channels);
+        for row in cells.chunks_exact(width.max(1)).rev() {
+            for &cell in row {
+                let grey = cell_to_grey(cell);
+                pixels.extend(core::iter::repeat_n(grey, channels));
+            }
+        }
+        Ok(Image::builder()
+            .stamp(self.stamp())
+            .frame_id(self.frame_id())
+            .width(info.width)
+            .height(info.height)
+            .encoding(encoding)
+            .step(info.width * channels as u32)
+            .data(&pixels)
+            .build()?)
+    }
+}
+
+#[cfg(feature = "std")]
+impl OccupancyGrid<Vec<u8>> {
+    /// Threshold a `mono8`, `rgb8` or `bgr8` image back into a grid, one
+    /// cell per pixel. `info.width` and `info.height` are taken from the
+    /// image; stamp and frame come from the image header.
+    pub fn from_image<B: AsRef<[u8]>>(
+        image: &Image<B>,
+        mut info: MapMetaData,
+        thresholds: &GridThresholds,
+    ) -> Result<Self, ConvertError> {
+        use crate::sensor_msgs::pixel_convert::encodings::{BGR8, MONO8, RGB8};
+
+        let channels = match image.encoding() {
+            MONO8 => 1,
+            RGB8 | BGR8 => 3,
+            other => {
+                return Err(ConvertError::Unsupported {
+                    from: other.into(),
+                    to: "occupancy_grid".into(),
+                })
+            }
+        };
+        let (width, height) = (image.width() as usize, image.height() as usize);
+        let step = image.step() as usize;
+        let data = image.data();
+        if step < width * channels || data.len() < step * height {
+            return Err(ConvertError::InvalidFrame);
+        }
+        let mut cells = Vec::with_capacity(width * height);
+        for row in data.chunks_exact(step.max(1)).take(height).rev() {
+            for px in row[..width * channels].chunks_exact(channels) {
+                let value = px.iter().map(|&v| v as u32).sum::<u32>() / channels as u32;
+                let mut p = value as f32 / 255.0;
+                if !thresholds.negate {
+                    p = 1.0 - p;
+                }
+                cells.push(if p > thresholds.occupied {
+                    100
+                } else if p < thresholds.free {
+                    0
+                } else {
+                    -1
+                });
+            }
+        }
+        info.width = image.width();
+        info.height = image.height();
+        Ok(OccupancyGrid::builder()
+            .stamp(image.stamp())
+            .frame_id(image.frame_id())
+            .info(info)
+            .data(&cells)
+            .build()?)
+    }
+}
+
 // ── Registry ────────────────────────────────────────────────────────
 
 /// Check if a type name is supported by this module.
 pub fn is_type_supported(type_name: &str) -> bool {
-    matches!(type_name, "Odometry")
+    matches!(type_name, "MapMetaData" | "OccupancyGrid" | "Odometry")
 }
 
 /// List all type schema names in this module.
 pub fn list_types() -> &'static [&'static str] {
-    &["nav_msgs/msg/Odometry"]
+    &[
+        "nav_msgs/msg/MapMetaData",
+        "nav_msgs/msg/OccupancyGrid",
+        "nav_msgs/msg/Odometry",
+    ]
+}
+
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "MapMetaData" => {
+            "\
+builtin_interfaces/Time map_load_time
+float32 resolution
+uint32 width
+uint32 height
+geometry_msgs/Pose origin
+"
+        }
+        "OccupancyGrid" => {
+            "\
+std_msgs/Header header
+MapMetaData info
+int8[] data
+"
+        }
+        "Odometry" => {
+            "\
+std_msgs/Header header
+string child_frame_id
+geometry_msgs/PoseWithCovariance pose
+geometry_msgs/TwistWithCovariance twist
+"
+        }
+        _ => return None,
+    })
+}
+
+// HeapSize implementations
+crate::heap_size::impl_heap_size!(Odometry, OccupancyGrid);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(width: u32, height: u32) -> MapMetaData {
+        let mut info = OccupancyGridBuilder::new().info;
+        info.resolution = 0.05;
+        info.width = width;
+        info.height = height;
+        info.origin.position.x = -1.0;
+        info
+    }
+
+    #[test]
+    fn occupancy_grid_roundtrip() {
+        let cells = [0i8, 100, -1, 50, 0, 0];
+        let grid = OccupancyGrid::builder()
+            .stamp(Time::new(3, 4))
+            .frame_id("map")
+            .info(info(3, 2))
+            .data(&cells)
+            .build()
+            .unwrap();
+        let decoded = OccupancyGrid::from_cdr(grid.to_cdr()).unwrap();
+        assert_eq!(decoded.stamp(), Time::new(3, 4));
+        assert_eq!(decoded.frame_id(), "map");
+        assert_eq!(decoded.info(), info(3, 2));
+        assert_eq!(decoded.data(), cells);
+
+        let cdr = grid.to_cdr();
+        assert!(OccupancyGrid::from_cdr(&cdr[..cdr.len() - 1]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn occupancy_grid_image_roundtrip() {
+        // Bottom row (grid row 0): free, occupied; top row: unknown, free.
+        let cells = [0i8, 100, -1, 0];
+        let grid = OccupancyGrid::builder()
+            .frame_id("map")
+            .info(info(2, 2))
+            .data(&cells)
+            .build()
+            .unwrap();
+
+        let mono = grid.to_image("mono8").unwrap();
+        assert_eq!(mono.data(), [205, 254, 254, 0]);
+        let rgb = grid.to_image("rgb8").unwrap();
+        assert_eq!(rgb.step(), 6);
+        assert_eq!(&rgb.data()[9..], [0, 0, 0]);
+        assert!(grid.to_image("yuv422_yuy2").is_err());
+
+        for image in [&mono, &rgb] {
+            let back =
+                OccupancyGrid::from_image(image, info(0, 0), &GridThresholds::default()).unwrap();
+            assert_eq!(back.frame_id(), "map");
+            assert_eq!(back.info(), info(2, 2));
+            assert_eq!(back.data(), cells);
+        }
+    }
 }
@@ -0,0 +1,498 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! ROS 1 message fingerprints and connection headers for bridging.
+//!
+//! ROS 1 peers (`roscpp`, `rospy`, `ros1_bridge`, rosbridge, rosbag 1)
+//! agree on a type by its MD5 sum, computed by `genmsg` from the `.msg`
+//! text with constants first and every nested type replaced by its own
+//! sum. The sums here are those of the ROS 1 counterpart of each schema:
+//! the registry definition with `builtin_interfaces/Time` and `Duration`
+//! as the `time` and `duration` primitives and bounds dropped, or the
+//! ROS 1 definition itself for the standard types that changed between
+//! ROS 1 and ROS 2 (`std_msgs/Header` regained `seq`, `CameraInfo` its
+//! upper-case matrix names, and the constants the registry omits).
+//!
+//! [`connection_header`] builds the TCPROS handshake that carries the sum,
+//! type and definition; [`parse_connection_header`] reads one back.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::ros1::{connection_header, md5sum, parse_connection_header};
+//!
+//! assert_eq!(
+//!     md5sum("std_msgs/msg/Header").unwrap(),
+//!     "2176decaecbce78abc3b96ef049fabed"
+//! );
+//!
+//! let header = connection_header(
+//!     "sensor_msgs/msg/Imu",
+//!     &[("callerid", "/edgefirst_bridge"), ("topic", "/imu")],
+//! )
+//! .unwrap();
+//! let fields = parse_connection_header(&header).unwrap();
+//! assert!(fields.contains(&("type".into(), "sensor_msgs/Imu".into())));
+//! assert!(fields.contains(&("md5sum".into(), "6a62c6daae103f4ff57a132d6f95cec2".into())));
+//! ```
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::dds_key::md5;
+use crate::schema_registry::{definition as ros2_definition, nested_schema, parse_schema};
+
+/// ROS 1 definitions of the standard types whose registry definition does
+/// not translate to them field for field.
+const ROS1_DEFINITIONS: &[(&str, &str)] = &[
+    (
+        "std_msgs/msg/Header",
+        "uint32 seq\n\
+         time stamp\n\
+         string frame_id\n",
+    ),
+    (
+        "sensor_msgs/msg/CameraInfo",
+        "Header header\n\
+         uint32 height\n\
+         uint32 width\n\
+         string distortion_model\n\
+         float64[] D\n\
+         float64[9] K\n\
+         float64[9] R\n\
+         float64[12] P\n\
+         uint32 binning_x\n\
+         uint32 binning_y\n\
+         RegionOfInterest roi\n",
+    ),
+    (
+        "sensor_msgs/msg/NavSatStatus",
+        "int8 STATUS_NO_FIX=-1\n\
+         int8 STATUS_FIX=0\n\
+         int8 STATUS_SBAS_FIX=1\n\
+         int8 STATUS_GBAS_FIX=2\n\
+         int8 status\n\
+         uint16 SERVICE_GPS=1\n\
+         uint16 SERVICE_GLONASS=2\n\
+         uint16 SERVICE_COMPASS=4\n\
+         uint16 SERVICE_GALILEO=8\n\
+         uint16 service\n",
+    ),
+    (
+        "sensor_msgs/msg/NavSatFix",
+        "Header header\n\
+         NavSatStatus status\n\
+         float64 latitude\n\
+         float64 longitude\n\
+         float64 altitude\n\
+         float64[9] position_covariance\n\
+         uint8 COVARIANCE_TYPE_UNKNOWN=0\n\
+         uint8 COVARIANCE_TYPE_APPROXIMATED=1\n\
+         uint8 COVARIANCE_TYPE_DIAGONAL_KNOWN=2\n\
+         uint8 COVARIANCE_TYPE_KNOWN=3\n\
+         uint8 position_covariance_type\n",
+    ),
+    (
+        "sensor_msgs/msg/PointField",
+        "uint8 INT8=1\n\
+         uint8 UINT8=2\n\
+         uint8 INT16=3\n\
+         uint8 UINT16=4\n\
+         uint8 INT32=5\n\
+         uint8 UINT32=6\n\
+         uint8 FLOAT32=7\n\
+         uint8 FLOAT64=8\n\
+         string name\n\
+         uint32 offset\n\
+         uint8 datatype\n\
+         uint32 count\n",
+    ),
+];
+
+/// Nesting depth beyond which a definition is assumed to be recursive.
+const MAX_DEPTH: usize = 32;
+
+/// ROS 1 type name of a schema: `sensor_msgs/msg/Image` is
+/// `sensor_msgs/Image`. `None` for unknown schemas and for
+/// `builtin_interfaces` types, which are primitives in ROS 1.
+pub fn ros1_type(schema: &str) -> Option<String> {
+    let (package, type_name) = parse_schema(schema)?;
+    if package == "builtin_interfaces" || ros2_definition(schema).is_none() {
+        return None;
+    }
+    Some(format!("{}/{}", package, type_name))
+}
+
+/// ROS 1 `.msg` text of a schema, without comments. Nested types are
+/// written `package/Type`, except `Header`.
+pub fn definition(schema: &str) -> Option<String> {
+    ros1_type(schema)?;
+    if let Some((_, text)) = ROS1_DEFINITIONS.iter().find(|(s, _)| *s == schema) {
+        return Some((*text).to_owned());
+    }
+    let (package, _) = parse_schema(schema)?;
+    let mut text = String::new();
+    for line in ros2_definition(schema)?.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut tokens = line.split_whitespace();
+        let Some(ty) = tokens.next() else {
+            continue;
+        };
+        let rest = tokens.collect::<Vec<_>>().join(" ");
+        let rest = match rest.split_once('=') {
+            Some((name, value)) => format!("{}={}", name.trim(), value.trim()),
+            None => rest,
+        };
+        text.push_str(&format!("{} {}\n", ros1_field_type(package, ty), rest));
+    }
+    Some(text)
+}
+
+/// [`definition`] followed by those of every message type it nests, the
+/// `message_definition` of a connection header or rosbag 1 connection
+/// record.
+pub fn full_definition(schema: &str) -> Option<String> {
+    let mut text = definition(schema)?;
+    let mut seen = Vec::new();
+    append_nested(schema, &mut seen, &mut text, 0)?;
+    Some(text)
+}
+
+fn append_nested(
+    schema: &str,
+    seen: &mut Vec<String>,
+    text: &mut String,
+    depth: usize,
+) -> Option<()> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    for nested in nested_types(schema)? {
+        if seen.contains(&nested) {
+            continue;
+        }
+        text.push_str(&format!(
+            "{}\nMSG: {}\n{}",
+            "=".repeat(80),
+            ros1_type(&nested)?,
+            definition(&nested)?
+        ));
+        seen.push(nested.clone());
+        append_nested(&nested, seen, text, depth + 1)?;
+    }
+    Some(())
+}
+
+/// Message types a schema's ROS 1 definition nests, in field order.
+fn nested_types(schema: &str) -> Option<Vec<String>> {
+    let (package, _) = parse_schema(schema)?;
+    let mut nested = Vec::new();
+    for (ty, _) in fields(&definition(schema)?) {
+        let base = ty.split('[').next().unwrap_or(ty);
+        if !is_builtin(base) {
+            nested.push(nested_schema(package, base));
+        }
+    }
+    Some(nested)
+}
+
+/// ROS 1 MD5 sum of a schema, as 32 lowercase hex digits.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::ros1::md5sum;
+///
+/// assert_eq!(
+///     md5sum("sensor_msgs/msg/Image").unwrap(),
+///     "060021388200f6f0f447d0fcd9c64743"
+/// );
+/// assert!(md5sum("builtin_interfaces/msg/Time").is_none());
+/// ```
+pub fn md5sum(schema: &str) -> Option<String> {
+    md5sum_at(schema, 0)
+}
+
+fn md5sum_at(schema: &str, depth: usize) -> Option<String> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    let (package, _) = parse_schema(schema)?;
+    let text = definition(schema)?;
+    let mut lines = Vec::new();
+    // genmsg lists every constant before the fields.
+    for line in text.lines().filter(|l| l.contains('=')) {
+        lines.push(line.to_owned());
+    }
+    for (ty, name) in fields(&text) {
+        let base = ty.split('[').next().unwrap_or(ty);
+        if is_builtin(base) {
+            lines.push(format!("{} {}", ty, name));
+        } else {
+            let nested = md5sum_at(&nested_schema(package, base), depth + 1)?;
+            lines.push(format!("{} {}", nested, name));
+        }
+    }
+    let mut sum = String::with_capacity(32);
+    for byte in md5(lines.join("\n").as_bytes()) {
+        sum.push_str(&format!("{:02x}", byte));
+    }
+    Some(sum)
+}
+
+/// `(type, name)` of each field line of a ROS 1 definition.
+fn fields(text: &str) -> impl Iterator<Item = (&str, &str)> {
+    text.lines()
+        .filter(|l| !l.contains('='))
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+            Some((tokens.next()?, tokens.next()?))
+        })
+}
+
+fn is_builtin(base: &str) -> bool {
+    matches!(
+        base,
+        "bool"
+            | "byte"
+            | "char"
+            | "int8"
+            | "uint8"
+            | "int16"
+            | "uint16"
+            | "int32"
+            | "uint32"
+            | "int64"
+            | "uint64"
+            | "float32"
+            | "float64"
+            | "string"
+            | "time"
+            | "duration"
+    )
+}
+
+/// ROS 1 spelling of a registry field type: `time`/`duration` for the
+/// `builtin_interfaces` types, bounds dropped, nested types qualified.
+fn ros1_field_type(package: &str, ty: &str) -> String {
+    let (base, suffix) = ty.find('[').map_or((ty, ""), |i| ty.split_at(i));
+    let suffix = if suffix.starts_with("[<=") {
+        "[]"
+    } else {
+        suffix
+    };
+    let base = match base {
+        "builtin_interfaces/Time" => "time".to_owned(),
+        "builtin_interfaces/Duration" => "duration".to_owned(),
+        _ if base.starts_with("string<=") || base.starts_with("wstring") => "string".to_owned(),
+        _ if is_builtin(base) => base.to_owned(),
+        _ => match nested_schema(package, base).as_str() {
+            "std_msgs/msg/Header" => "Header".to_owned(),
+            nested => ros1_type(nested).unwrap_or_else(|| base.to_owned()),
+        },
+    };
+    format!("{}{}", base, suffix)
+}
+
+/// TCPROS connection header for a publisher or subscriber of `schema`:
+/// `type`, `md5sum` and `message_definition`, then `fields` such as
+/// `callerid`, `topic`, `latching` or `tcp_nodelay`. Each field is a
+/// little-endian `u32` length and `key=value`, and the whole header is
+/// prefixed with its own length, as sent on the socket.
+pub fn connection_header(schema: &str, fields: &[(&str, &str)]) -> Option<Vec<u8>> {
+    let ty = ros1_type(schema)?;
+    let md5 = md5sum(schema)?;
+    let text = full_definition(schema)?;
+    let mut body = Vec::new();
+    let own = [
+        ("type", ty.as_str()),
+        ("md5sum", md5.as_str()),
+        ("message_definition", text.as_str()),
+    ];
+    for (key, value) in own.iter().chain(fields) {
+        let len = (key.len() + 1 + value.len()) as u32;
+        body.extend_from_slice(&len.to_le_bytes());
+        body.extend_from_slice(key.as_bytes());
+        body.push(b'=');
+        body.extend_from_slice(value.as_bytes());
+    }
+    let mut header = Vec::with_capacity(4 + body.len());
+    header.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    header.extend_from_slice(&body);
+    Some(header)
+}
+
+/// `(key, value)` fields of a TCPROS connection header in the layout
+/// [`connection_header`] writes, length prefix included. `None` if the
+/// header is truncated, a field has no `=`, or it is not UTF-8.
+pub fn parse_connection_header(header: &[u8]) -> Option<Vec<(String, String)>> {
+    let read_len = |b: &[u8]| -> Option<usize> {
+        Some(u32::from_le_bytes(b.get(..4)?.try_into().ok()?) as usize)
+    };
+    let total = read_len(header)?;
+    let mut body = header.get(4..4 + total)?;
+    let mut fields = Vec::new();
+    while !body.is_empty() {
+        let len = read_len(body)?;
+        let field = core::str::from_utf8(body.get(4..4 + len)?).ok()?;
+        let (key, value) = field.split_once('=')?;
+        fields.push((key.to_owned(), value.to_owned()));
+        body = &body[4 + len..];
+    }
+    Some(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema_registry::list_schemas;
+
+    #[test]
+    fn md5sums_match_ros1() {
+        // From `rosmsg md5` on ROS 1 Noetic.
+        for (schema, sum) in [
+            ("std_msgs/msg/Header", "2176decaecbce78abc3b96ef049fabed"),
+            ("std_msgs/msg/ColorRGBA", "a29a96539573343b1310c73607334b00"),
+            (
+                "std_msgs/msg/MultiArrayDimension",
+                "4cd0c83a8683deae40ecdac60e53bfa8",
+            ),
+            (
+                "std_msgs/msg/MultiArrayLayout",
+                "0fed2a11c13e11c5571b4e2a995a91a3",
+            ),
+            (
+                "std_msgs/msg/Float32MultiArray",
+                "6a40e0ffa6a17a503ac3f8616991b1f6",
+            ),
+            (
+                "geometry_msgs/msg/Point",
+                "4a842b65f413084dc2b10fb484ea7f17",
+            ),
+            (
+                "geometry_msgs/msg/Vector3",
+                "4a842b65f413084dc2b10fb484ea7f17",
+            ),
+            (
+                "geometry_msgs/msg/Quaternion",
+                "a779879fadf0160734f906b8c19c7004",
+            ),
+            (
+                "geometry_msgs/msg/Polygon",
+                "cd60a26494a087f577976f0329fa120e",
+            ),
+            (
+                "geometry_msgs/msg/PolygonStamped",
+                "c6be8f7dc3bee7fe9e8d296070f53340",
+            ),
+            ("geometry_msgs/msg/Pose", "e45d45a5a1ce597b249e23fb30fc871f"),
+            (
+                "geometry_msgs/msg/PoseStamped",
+                "d3812c3cbc69362b77dc0b19b345f8f5",
+            ),
+            (
+                "geometry_msgs/msg/Twist",
+                "9f195f881246fdfa2798d1d3eebca84a",
+            ),
+            (
+                "geometry_msgs/msg/TransformStamped",
+                "b5764a33bfeb3588febc2682852579b0",
+            ),
+            ("nav_msgs/msg/Odometry", "cd5e73d190d741a2f92e81eda573aca7"),
+            (
+                "nav_msgs/msg/OccupancyGrid",
+                "3381f2d731d4076ec5c71b0759edbe4e",
+            ),
+            ("sensor_msgs/msg/Image", "060021388200f6f0f447d0fcd9c64743"),
+            (
+                "sensor_msgs/msg/CompressedImage",
+                "8f7a12909da2c9d3332d540a0977563f",
+            ),
+            (
+                "sensor_msgs/msg/CameraInfo",
+                "c9a58c1b0b154e0e6da7578cb991d214",
+            ),
+            ("sensor_msgs/msg/Imu", "6a62c6daae103f4ff57a132d6f95cec2"),
+            (
+                "sensor_msgs/msg/ChannelFloat32",
+                "3d40139cdd33dfedcb71ffeeeb42ae7f",
+            ),
+            ("sensor_msgs/msg/Joy", "5a9ea5f83505693b71e785041e67a8bb"),
+            (
+                "sensor_msgs/msg/PointCloud",
+                "d8e9c3f5afbdd8a130fd1d2763945fca",
+            ),
+            (
+                "sensor_msgs/msg/LaserEcho",
+                "8bc5ae449b200fba4d552b4225586696",
+            ),
+            (
+                "sensor_msgs/msg/LaserScan",
+                "90c7ef2dc6895d81024acba2ac42f369",
+            ),
+            (
+                "sensor_msgs/msg/MultiEchoLaserScan",
+                "6fefb0c6da89d7c8abe4b339f5c2f8fb",
+            ),
+            (
+                "sensor_msgs/msg/NavSatStatus",
+                "331cdbddfa4bc96ffc3b9ad98900a54c",
+            ),
+            (
+                "sensor_msgs/msg/NavSatFix",
+                "2d3a8cd499b9b4a0249fb98fd05cfa48",
+            ),
+            (
+                "sensor_msgs/msg/PointField",
+                "268eacb2962780ceac86cbd17e328150",
+            ),
+            (
+                "sensor_msgs/msg/PointCloud2",
+                "1158d486dd51d683ce2f1be655c3c181",
+            ),
+        ] {
+            assert_eq!(md5sum(schema).as_deref(), Some(sum), "{}", schema);
+        }
+    }
+
+    #[test]
+    fn every_message_schema_has_a_ros1_form() {
+        for schema in list_schemas() {
+            if schema.starts_with("builtin_interfaces/") {
+                assert!(md5sum(schema).is_none());
+                continue;
+            }
+            let text = full_definition(schema).unwrap();
+            assert!(!text.contains("builtin_interfaces"), "{}", schema);
+            assert!(!text.contains("<="), "{}", schema);
+            assert_eq!(md5sum(schema).unwrap().len(), 32, "{}", schema);
+        }
+        assert!(md5sum("unknown_msgs/msg/Foo").is_none());
+    }
+
+    #[test]
+    fn full_definition_nests_ros1_types() {
+        let text = full_definition("sensor_msgs/msg/CameraInfo").unwrap();
+        assert!(text.starts_with("Header header\n"));
+        assert!(text.contains("\nMSG: std_msgs/Header\nuint32 seq\ntime stamp\n"));
+        assert!(text.contains("\nMSG: sensor_msgs/RegionOfInterest\n"));
+    }
+
+    #[test]
+    fn connection_header_roundtrip() {
+        let header = connection_header("std_msgs/msg/ColorRGBA", &[("topic", "/color")]).unwrap();
+        assert_eq!(
+            u32::from_le_bytes(header[..4].try_into().unwrap()) as usize,
+            header.len() - 4
+        );
+        let fields = parse_connection_header(&header).unwrap();
+        let keys: Vec<&str> = fields.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["type", "md5sum", "message_definition", "topic"]);
+        assert_eq!(fields[2].1, "float32 r\nfloat32 g\nfloat32 b\nfloat32 a\n");
+
+        assert!(parse_connection_header(&header[..header.len() - 1]).is_none());
+        assert!(connection_header("builtin_interfaces/msg/Time", &[]).is_none());
+    }
+}
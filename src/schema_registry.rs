@@ -21,11 +21,38 @@
 //! assert!(is_supported("sensor_msgs/msg/Image"));
 //! assert!(!is_supported("unknown_msgs/msg/Foo"));
 //! ```
+//!
+//! # Message definitions
+//!
+//! [`definition`] returns a schema's own `.msg` text and [`full_definition`]
+//! that text with every nested type appended, which is what an MCAP schema
+//! record or a foxglove-websocket channel advertisement carries alongside
+//! the name (encoding [`SCHEMA_ENCODING`]):
+//!
+//! ```rust
+//! use edgefirst_schemas::schema_registry::{full_definition, SCHEMA_ENCODING};
+//!
+//! let data = full_definition("sensor_msgs/msg/Image").unwrap();
+//! assert_eq!(SCHEMA_ENCODING, "ros2msg");
+//! assert!(data.contains("MSG: std_msgs/Header"));
+//! ```
 
+use crate::cdr::{CdrError, CDR_HEADER_SIZE};
 use crate::{
     builtin_interfaces, edgefirst_msgs, foxglove_msgs, geometry_msgs, mavros_msgs, nav_msgs,
-    sensor_msgs, std_msgs,
+    sensor_msgs, std_msgs, vision_msgs,
 };
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Encoding of [`full_definition`] text, as MCAP schema records name it.
+pub const SCHEMA_ENCODING: &str = "ros2msg";
+
+/// Message encoding of MCAP channels that carry these CDR messages.
+pub const MESSAGE_ENCODING: &str = "cdr";
 
 /// Trait for types that have a schema name.
 ///
@@ -82,6 +109,10 @@ pub fn is_supported(schema: &str) -> bool {
     let Some((package, type_name)) = parse_schema(schema) else {
         return false;
     };
+    #[cfg(feature = "std")]
+    if registered(schema).is_some() {
+        return true;
+    }
 
     match package {
         "builtin_interfaces" => builtin_interfaces::is_type_supported(type_name),
@@ -92,13 +123,180 @@ pub fn is_supported(schema: &str) -> bool {
         "foxglove_msgs" => foxglove_msgs::is_type_supported(type_name),
         "edgefirst_msgs" => edgefirst_msgs::is_type_supported(type_name),
         "mavros_msgs" => mavros_msgs::is_type_supported(type_name),
+        "vision_msgs" => vision_msgs::is_type_supported(type_name),
         _ => false,
     }
 }
 
+/// ROS 2 message definition (`.msg` field list, constants and comments
+/// included) for a schema name.
+///
+/// Nested field types are written `package/Type`, or just `Type` within the
+/// same package, and resolve through this function as well. Besides every
+/// supported schema this covers nested types that are not published on
+/// their own, such as `geometry_msgs/msg/PoseWithCovariance`.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::definition;
+///
+/// let def = definition("builtin_interfaces/msg/Time").unwrap();
+/// assert_eq!(def, "int32 sec\nuint32 nanosec\n");
+/// assert!(definition("unknown_msgs/msg/Foo").is_none());
+/// ```
+pub fn definition(schema: &str) -> Option<&'static str> {
+    let builtin = builtin_definition(schema);
+    #[cfg(feature = "std")]
+    let builtin = builtin.or_else(|| registered(schema).map(|s| s.definition));
+    builtin
+}
+
+fn builtin_definition(schema: &str) -> Option<&'static str> {
+    let (package, type_name) = parse_schema(schema)?;
+
+    match package {
+        "builtin_interfaces" => builtin_interfaces::definition(type_name),
+        "std_msgs" => std_msgs::definition(type_name),
+        "geometry_msgs" => geometry_msgs::definition(type_name),
+        "nav_msgs" => nav_msgs::definition(type_name),
+        "sensor_msgs" => sensor_msgs::definition(type_name),
+        "foxglove_msgs" => foxglove_msgs::definition(type_name),
+        "edgefirst_msgs" => edgefirst_msgs::definition(type_name),
+        "mavros_msgs" => mavros_msgs::definition(type_name),
+        "vision_msgs" => vision_msgs::definition(type_name),
+        _ => None,
+    }
+}
+
+/// [`definition`] of a schema followed by those of every type it nests,
+/// in the `ros2msg` layout MCAP schema records and ROS 2 bags expect: each
+/// nested definition after a line of 80 `=` and a `MSG: package/Type` line.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::full_definition;
+///
+/// let text = full_definition("std_msgs/msg/Header").unwrap();
+/// assert!(text.starts_with("builtin_interfaces/Time stamp\n"));
+/// assert!(text.contains("\nMSG: builtin_interfaces/Time\nint32 sec\n"));
+/// ```
+pub fn full_definition(schema: &str) -> Option<String> {
+    let mut text = String::from(definition(schema)?);
+    let mut seen = Vec::new();
+    append_nested(schema, &mut seen, &mut text)?;
+    Some(text)
+}
+
+fn append_nested(schema: &str, seen: &mut Vec<String>, text: &mut String) -> Option<()> {
+    let (package, _) = parse_schema(schema)?;
+    for line in definition(schema)?.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut tokens = line.split_whitespace();
+        let (Some(ty), Some(_)) = (tokens.next(), tokens.next()) else {
+            continue;
+        };
+        let base = ty.split('[').next().unwrap_or(ty);
+        if is_primitive(base) {
+            continue;
+        }
+        let nested = nested_schema(package, base);
+        if seen.contains(&nested) {
+            continue;
+        }
+        let (nested_package, nested_type) = parse_schema(&nested)?;
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text.push_str(&format!(
+            "{}\nMSG: {}/{}\n{}",
+            "=".repeat(80),
+            nested_package,
+            nested_type,
+            definition(&nested)?
+        ));
+        seen.push(nested.clone());
+        append_nested(&nested, seen, text)?;
+    }
+    Some(())
+}
+
+fn is_primitive(base: &str) -> bool {
+    matches!(
+        base,
+        "bool"
+            | "byte"
+            | "char"
+            | "int8"
+            | "uint8"
+            | "int16"
+            | "uint16"
+            | "int32"
+            | "uint32"
+            | "int64"
+            | "uint64"
+            | "float32"
+            | "float64"
+            | "string"
+            | "wstring"
+    ) || base.starts_with("string<=")
+        || base.starts_with("wstring<=")
+}
+
+/// Fields of an MCAP `Schema` record for a schema, and the message
+/// encoding of channels that use it; see [`mcap_schema`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct McapSchema {
+    /// Schema name, e.g. `sensor_msgs/msg/Image`.
+    pub name: String,
+    /// [`SCHEMA_ENCODING`].
+    pub encoding: &'static str,
+    /// [`full_definition`] of the schema; the record stores its UTF-8 bytes.
+    pub data: String,
+    /// [`MESSAGE_ENCODING`], for the `Channel` records of this schema.
+    pub message_encoding: &'static str,
+}
+
+/// MCAP `Schema` record fields for a registered schema, as ROS 2 bags and
+/// Foxglove expect them.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::mcap_schema;
+///
+/// let schema = mcap_schema("sensor_msgs/msg/Imu").unwrap();
+/// assert_eq!(schema.name, "sensor_msgs/msg/Imu");
+/// assert_eq!(schema.encoding, "ros2msg");
+/// assert_eq!(schema.message_encoding, "cdr");
+/// assert!(schema.data.contains("\nMSG: geometry_msgs/Quaternion\n"));
+/// assert!(mcap_schema("unknown_msgs/msg/Foo").is_none());
+/// ```
+pub fn mcap_schema(schema: &str) -> Option<McapSchema> {
+    Some(McapSchema {
+        name: schema.to_owned(),
+        encoding: SCHEMA_ENCODING,
+        data: full_definition(schema)?,
+        message_encoding: MESSAGE_ENCODING,
+    })
+}
+
+/// Schema name of a field type in a definition from `package`: `pkg/Type`
+/// and a bare `Type` (same package) become `pkg/msg/Type`, and a bare
+/// `Header` is `std_msgs/msg/Header`.
+pub(crate) fn nested_schema(package: &str, base: &str) -> String {
+    match base.split_once('/') {
+        Some((pkg, ty)) => format!("{}/msg/{}", pkg, ty),
+        None if base == "Header" => "std_msgs/msg/Header".to_owned(),
+        None => format!("{}/msg/{}", package, base),
+    }
+}
+
 /// List all supported schema names.
 ///
-/// Returns a vector of all schema names that this library supports.
+/// Returns a vector of all schema names that this library supports,
+/// followed by those added with [`register_schema`].
 pub fn list_schemas() -> Vec<&'static str> {
     let mut schemas = Vec::new();
 
@@ -110,10 +308,1319 @@ pub fn list_schemas() -> Vec<&'static str> {
     schemas.extend(foxglove_msgs::list_types().iter().copied());
     schemas.extend(edgefirst_msgs::list_types().iter().copied());
     schemas.extend(mavros_msgs::list_types().iter().copied());
+    schemas.extend(vision_msgs::list_types().iter().copied());
+    #[cfg(feature = "std")]
+    schemas.extend(
+        REGISTERED
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|s| s.name),
+    );
 
     schemas
 }
 
+// ── User-defined schemas ────────────────────────────────────────────
+
+/// Checks CDR bytes (encapsulation header included) of a registered
+/// schema beyond what its definition describes, typically by calling the
+/// type's own `from_cdr`.
+#[cfg(feature = "std")]
+pub type ValidateFn = fn(&[u8]) -> Result<(), CdrError>;
+
+/// A message type defined outside this crate; see [`register_schema`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct UserSchema {
+    /// `package/msg/Type`.
+    pub name: &'static str,
+    /// `.msg` text in the form [`definition`] returns. Nested types must
+    /// be built in or registered first.
+    pub definition: &'static str,
+    /// Run by the decoders that walk the definition (the C API's generic
+    /// message handle, [`dynamic`](crate::dynamic) and JSON transcoding)
+    /// after the bytes have matched it.
+    pub validate: Option<ValidateFn>,
+}
+
+/// Errors from [`register_schema`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegisterError {
+    /// The name is not of the form `package/msg/Type`.
+    InvalidName(String),
+    /// A built-in schema already has this name.
+    Builtin(String),
+    /// The name is registered with a different definition.
+    Conflict(String),
+    /// A line of the definition could not be interpreted, or names a
+    /// nested type with no definition.
+    InvalidDefinition { schema: String, line: String },
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for RegisterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RegisterError::InvalidName(name) => write!(f, "invalid schema name {}", name),
+            RegisterError::Builtin(name) => write!(f, "{} is a built-in schema", name),
+            RegisterError::Conflict(name) => {
+                write!(f, "{} is registered with another definition", name)
+            }
+            RegisterError::InvalidDefinition { schema, line } => {
+                write!(f, "invalid definition line in {}: {}", schema, line)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegisterError {}
+
+#[cfg(feature = "std")]
+static REGISTERED: std::sync::RwLock<Vec<UserSchema>> = std::sync::RwLock::new(Vec::new());
+
+/// Add a message type defined outside this crate to the registry, so
+/// that [`definition`], [`mcap_schema`], [`dynamic`](crate::dynamic) and the
+/// C API's generic `edgefirst_message_*` handle work with it like with a
+/// built-in type. Registrations last for the life of the process;
+/// registering the same name and definition again is a no-op.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::{definition, register_schema, UserSchema};
+///
+/// register_schema(UserSchema {
+///     name: "acme_msgs/msg/Gripper",
+///     definition: "std_msgs/Header header\nfloat32 opening\nbool holding\n",
+///     validate: None,
+/// })
+/// .unwrap();
+/// assert!(definition("acme_msgs/msg/Gripper").is_some());
+/// ```
+#[cfg(feature = "std")]
+pub fn register_schema(schema: UserSchema) -> Result<(), RegisterError> {
+    let Some((package, _)) = parse_schema(schema.name) else {
+        return Err(RegisterError::InvalidName(schema.name.to_owned()));
+    };
+    if builtin_definition(schema.name).is_some() {
+        return Err(RegisterError::Builtin(schema.name.to_owned()));
+    }
+    for raw in schema.definition.lines() {
+        let line = raw.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = || RegisterError::InvalidDefinition {
+            schema: schema.name.to_owned(),
+            line: raw.to_owned(),
+        };
+        let mut tokens = line.split_whitespace();
+        let (Some(ty), Some(_)) = (tokens.next(), tokens.next()) else {
+            return Err(invalid());
+        };
+        let (base, _) = split_field_type(ty).ok_or_else(invalid)?;
+        if !is_primitive(base) && definition(&nested_schema(package, base)).is_none() {
+            return Err(invalid());
+        }
+    }
+
+    let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+    match registered.iter().find(|s| s.name == schema.name) {
+        Some(s) if s.definition == schema.definition => Ok(()),
+        Some(_) => Err(RegisterError::Conflict(schema.name.to_owned())),
+        None => {
+            registered.push(schema);
+            Ok(())
+        }
+    }
+}
+
+/// The [`UserSchema`] registered under `schema`, if any.
+#[cfg(feature = "std")]
+pub fn registered(schema: &str) -> Option<UserSchema> {
+    let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+    registered.iter().find(|s| s.name == schema).copied()
+}
+
+/// Run the [`UserSchema::validate`] hook of a registered schema on bytes
+/// that already matched its definition.
+#[cfg_attr(
+    not(any(feature = "json", feature = "dynamic", feature = "ffi")),
+    allow(dead_code)
+)]
+pub(crate) fn validate_registered(schema: &str, cdr: &[u8]) -> Result<(), CdrError> {
+    #[cfg(feature = "std")]
+    if let Some(validate) = registered(schema).and_then(|s| s.validate) {
+        return validate(cdr);
+    }
+    let _ = (schema, cdr);
+    Ok(())
+}
+
+/// Metadata of a registry entry; see [`schema_info`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchemaInfo {
+    /// Full schema name, e.g. `sensor_msgs/msg/Image`.
+    pub name: &'static str,
+    /// Package, e.g. `sensor_msgs`.
+    pub package: &'static str,
+    /// Type name within the package, e.g. `Image`.
+    pub type_name: &'static str,
+    /// Wire encoding of the messages: [`MESSAGE_ENCODING`].
+    pub encoding: &'static str,
+    /// Prefix of the schema's typed C API functions (`ros_image` for
+    /// `ros_image_from_cdr` and so on), or `None` if it has none or the
+    /// crate is built without `ffi`. Every schema is still reachable
+    /// through the generic `edgefirst_message_*` handle.
+    pub ffi_prefix: Option<&'static str>,
+    /// Serialized size in bytes, encapsulation header included, with every
+    /// string and sequence empty.
+    pub min_size: usize,
+    /// Upper bound on the serialized size, or `None` when the message holds
+    /// an unbounded string or sequence. Equal to `min_size` for fixed-size
+    /// messages.
+    pub max_size: Option<usize>,
+}
+
+/// [`SchemaInfo`] of a supported schema, for pre-sizing buffers and
+/// capability manifests.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::schema_info;
+///
+/// let time = schema_info("builtin_interfaces/msg/Time").unwrap();
+/// assert_eq!((time.package, time.type_name), ("builtin_interfaces", "Time"));
+/// assert_eq!((time.min_size, time.max_size), (12, Some(12)));
+///
+/// let image = schema_info("sensor_msgs/msg/Image").unwrap();
+/// assert_eq!(image.max_size, None);
+/// assert!(schema_info("unknown_msgs/msg/Foo").is_none());
+/// ```
+pub fn schema_info(schema: &str) -> Option<SchemaInfo> {
+    let name = list_schemas().into_iter().find(|s| *s == schema)?;
+    let (package, type_name) = parse_schema(name)?;
+    let min = min_size(name, 0)?;
+    let max = max_size(name, Pos::Exact(0))?;
+    Some(SchemaInfo {
+        name,
+        package,
+        type_name,
+        encoding: MESSAGE_ENCODING,
+        ffi_prefix: ffi_prefix(name),
+        min_size: CDR_HEADER_SIZE + min,
+        max_size: max.map(|pos| CDR_HEADER_SIZE + pos.bound()),
+    })
+}
+
+/// [`schema_info`] of every entry of [`list_schemas`].
+pub fn list_schema_info() -> Vec<SchemaInfo> {
+    list_schemas().into_iter().filter_map(schema_info).collect()
+}
+
+/// Schemas with typed C API functions, and their function prefix.
+const FFI_PREFIXES: &[(&str, &str)] = &[
+    ("builtin_interfaces/msg/Duration", "ros_duration"),
+    ("builtin_interfaces/msg/Time", "ros_time"),
+    ("std_msgs/msg/ColorRGBA", "ros_color_rgba"),
+    ("std_msgs/msg/Header", "ros_header"),
+    ("geometry_msgs/msg/Accel", "ros_accel"),
+    ("geometry_msgs/msg/AccelStamped", "ros_accel_stamped"),
+    ("geometry_msgs/msg/Inertia", "ros_inertia"),
+    ("geometry_msgs/msg/InertiaStamped", "ros_inertia_stamped"),
+    ("geometry_msgs/msg/Point", "ros_point"),
+    ("geometry_msgs/msg/Point32", "ros_point32"),
+    ("geometry_msgs/msg/PointStamped", "ros_point_stamped"),
+    ("geometry_msgs/msg/Pose", "ros_pose"),
+    ("geometry_msgs/msg/Pose2D", "ros_pose2d"),
+    ("geometry_msgs/msg/PoseStamped", "ros_pose_stamped"),
+    ("geometry_msgs/msg/Quaternion", "ros_quaternion"),
+    ("geometry_msgs/msg/Transform", "ros_transform"),
+    (
+        "geometry_msgs/msg/TransformStamped",
+        "ros_transform_stamped",
+    ),
+    ("geometry_msgs/msg/Twist", "ros_twist"),
+    ("geometry_msgs/msg/TwistStamped", "ros_twist_stamped"),
+    ("geometry_msgs/msg/Vector3", "ros_vector3"),
+    ("nav_msgs/msg/MapMetaData", "ros_map_meta_data"),
+    ("nav_msgs/msg/OccupancyGrid", "ros_occupancy_grid"),
+    ("nav_msgs/msg/Odometry", "ros_odometry"),
+    ("sensor_msgs/msg/BatteryState", "ros_battery_state"),
+    ("sensor_msgs/msg/CameraInfo", "ros_camera_info"),
+    ("sensor_msgs/msg/CompressedImage", "ros_compressed_image"),
+    ("sensor_msgs/msg/FluidPressure", "ros_fluid_pressure"),
+    ("sensor_msgs/msg/Image", "ros_image"),
+    ("sensor_msgs/msg/Imu", "ros_imu"),
+    ("sensor_msgs/msg/LaserScan", "ros_laser_scan"),
+    ("sensor_msgs/msg/MagneticField", "ros_magnetic_field"),
+    ("sensor_msgs/msg/NavSatFix", "ros_nav_sat_fix"),
+    ("sensor_msgs/msg/NavSatStatus", "ros_nav_sat_status"),
+    ("sensor_msgs/msg/PointCloud2", "ros_point_cloud2"),
+    ("sensor_msgs/msg/PointField", "ros_point_field"),
+    ("sensor_msgs/msg/RegionOfInterest", "ros_region_of_interest"),
+    ("sensor_msgs/msg/Temperature", "ros_temperature"),
+    ("foxglove_msgs/msg/CompressedVideo", "ros_compressed_video"),
+    ("edgefirst_msgs/msg/Box", "ros_box"),
+    ("edgefirst_msgs/msg/CameraFrame", "ros_camera_frame"),
+    ("edgefirst_msgs/msg/Detect", "ros_detect"),
+    ("edgefirst_msgs/msg/DmaBuffer", "ros_dmabuffer"),
+    ("edgefirst_msgs/msg/LocalTime", "ros_local_time"),
+    ("edgefirst_msgs/msg/Mask", "ros_mask"),
+    ("edgefirst_msgs/msg/Model", "ros_model"),
+    ("edgefirst_msgs/msg/ModelInfo", "ros_model_info"),
+    ("edgefirst_msgs/msg/RadarCube", "ros_radar_cube"),
+    ("edgefirst_msgs/msg/RadarInfo", "ros_radar_info"),
+    ("edgefirst_msgs/msg/Track", "ros_track"),
+    ("edgefirst_msgs/msg/Vibration", "ros_vibration"),
+    ("mavros_msgs/msg/Altitude", "ros_mavros_altitude"),
+    (
+        "mavros_msgs/msg/EstimatorStatus",
+        "ros_mavros_estimator_status",
+    ),
+    ("mavros_msgs/msg/ExtendedState", "ros_mavros_extended_state"),
+    ("mavros_msgs/msg/GPSRAW", "ros_mavros_gps_raw"),
+    ("mavros_msgs/msg/State", "ros_mavros_state"),
+    ("mavros_msgs/msg/StatusText", "ros_mavros_status_text"),
+    ("mavros_msgs/msg/SysStatus", "ros_mavros_sys_status"),
+    (
+        "mavros_msgs/msg/TimesyncStatus",
+        "ros_mavros_timesync_status",
+    ),
+    ("mavros_msgs/msg/VfrHud", "ros_mavros_vfrhud"),
+];
+
+fn ffi_prefix(schema: &str) -> Option<&'static str> {
+    if !cfg!(feature = "ffi") {
+        return None;
+    }
+    FFI_PREFIXES
+        .iter()
+        .find(|(name, _)| *name == schema)
+        .map(|(_, prefix)| *prefix)
+}
+
+/// `(type, name)` of each field line of a definition; constants and
+/// comments are skipped.
+fn field_lines(definition: &str) -> impl Iterator<Item = (&str, &str)> {
+    definition.lines().filter_map(|line| {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut tokens = line.split_whitespace();
+        let (ty, name) = (tokens.next()?, tokens.next()?);
+        if name.contains('=') || tokens.next().is_some_and(|t| t.starts_with('=')) {
+            return None;
+        }
+        Some((ty, name))
+    })
+}
+
+/// Split `float64[9]`, `int32[]` or `uint8[<=4]` into base type and shape.
+fn split_field_type(ty: &str) -> Option<(&str, FieldShape)> {
+    let Some((base, bound)) = ty.split_once('[') else {
+        return Some((ty, FieldShape::Single));
+    };
+    let shape = match bound.strip_suffix(']')? {
+        "" => FieldShape::Seq(None),
+        bound => match bound.strip_prefix("<=") {
+            Some(max) => FieldShape::Seq(Some(max.parse().ok()?)),
+            None => FieldShape::Fixed(bound.parse().ok()?),
+        },
+    };
+    Some((base, shape))
+}
+
+/// Whether a field holds one value, a fixed array or a sequence; see
+/// [`FieldInfo::shape`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldShape {
+    /// A single value, `float64`.
+    Single,
+    /// A fixed-size array, `float64[9]`.
+    Fixed(usize),
+    /// A sequence, `float64[]`, with its bound for `float64[<=4]`.
+    Seq(Option<usize>),
+}
+
+/// Width of a primitive field type, `None` for strings and messages.
+fn primitive_size(base: &str) -> Option<usize> {
+    Some(match base {
+        "bool" | "byte" | "char" | "int8" | "uint8" => 1,
+        "int16" | "uint16" => 2,
+        "int32" | "uint32" | "float32" => 4,
+        "int64" | "uint64" | "float64" => 8,
+        _ => return None,
+    })
+}
+
+/// Position after the smallest encoding of `schema` starting at `pos`
+/// (relative to the end of the encapsulation header).
+fn min_size(schema: &str, mut pos: usize) -> Option<usize> {
+    let (package, _) = parse_schema(schema)?;
+    for (ty, _) in field_lines(definition(schema)?) {
+        let (base, shape) = split_field_type(ty)?;
+        let count = match shape {
+            FieldShape::Single => 1,
+            FieldShape::Fixed(n) => n,
+            FieldShape::Seq(_) => {
+                pos = align(pos, 4) + 4;
+                0
+            }
+        };
+        for _ in 0..count {
+            pos = match primitive_size(base) {
+                Some(size) => align(pos, size) + size,
+                None if base == "string" || base.starts_with("string<=") => align(pos, 4) + 5,
+                None => min_size(&nested_schema(package, base), pos)?,
+            };
+        }
+    }
+    Some(pos)
+}
+
+/// Position while walking the largest encoding: known exactly until a
+/// string or sequence of variable length, an upper bound after it.
+#[derive(Clone, Copy)]
+enum Pos {
+    Exact(usize),
+    Bound(usize),
+}
+
+impl Pos {
+    fn bound(self) -> usize {
+        match self {
+            Pos::Exact(p) | Pos::Bound(p) => p,
+        }
+    }
+
+    fn align(self, n: usize) -> Pos {
+        match self {
+            Pos::Exact(p) => Pos::Exact(align(p, n)),
+            Pos::Bound(p) => Pos::Bound(p + n - 1),
+        }
+    }
+
+    fn add(self, n: usize) -> Pos {
+        match self {
+            Pos::Exact(p) => Pos::Exact(p + n),
+            Pos::Bound(p) => Pos::Bound(p + n),
+        }
+    }
+
+    fn loosen(self) -> Pos {
+        Pos::Bound(self.bound())
+    }
+}
+
+/// Position after the largest encoding of `schema` starting at `pos`:
+/// `Some(None)` if it is unbounded, `None` for an unknown schema.
+fn max_size(schema: &str, mut pos: Pos) -> Option<Option<Pos>> {
+    let (package, _) = parse_schema(schema)?;
+    for (ty, _) in field_lines(definition(schema)?) {
+        let (base, shape) = split_field_type(ty)?;
+        let count = match shape {
+            FieldShape::Single => 1,
+            FieldShape::Fixed(n) => n,
+            FieldShape::Seq(None) => return Some(None),
+            FieldShape::Seq(Some(bound)) => {
+                pos = pos.align(4).add(4);
+                bound
+            }
+        };
+        for _ in 0..count {
+            pos = match primitive_size(base) {
+                Some(size) => pos.align(size).add(size),
+                None if base == "string" => return Some(None),
+                None if base.starts_with("string<=") => {
+                    let bound: usize = base["string<=".len()..].parse().ok()?;
+                    pos.align(4).add(4 + bound + 1).loosen()
+                }
+                None => match max_size(&nested_schema(package, base), pos)? {
+                    Some(pos) => pos,
+                    None => return Some(None),
+                },
+            };
+        }
+        if let FieldShape::Seq(Some(_)) = shape {
+            pos = pos.loosen();
+        }
+    }
+    Some(Some(pos))
+}
+
+fn align(pos: usize, n: usize) -> usize {
+    pos.div_ceil(n) * n
+}
+
+/// ROS 2 type hash (`RIHS01_` and 64 hex digits) of a schema, as rmw
+/// implementations compare on discovery and rmw_zenoh puts in liveliness
+/// tokens.
+///
+/// This is the SHA-256 of the type description that
+/// `rosidl_generator_type_description` derives from the same definitions:
+/// the type's fields followed by those of every type it references, sorted
+/// by name, without constants or default values.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::type_hash;
+///
+/// assert_eq!(
+///     type_hash("builtin_interfaces/msg/Time").unwrap(),
+///     "RIHS01_b106235e25a4c5ed35098aa0a61a3ee9c9b18d197f398b0e4206cea9acf9c197"
+/// );
+/// assert!(type_hash("unknown_msgs/msg/Foo").is_none());
+/// ```
+pub fn type_hash(schema: &str) -> Option<String> {
+    let (description, mut pending) = type_description(schema)?;
+    let mut referenced = BTreeMap::new();
+    while let Some(nested) = pending.pop() {
+        if referenced.contains_key(&nested) {
+            continue;
+        }
+        let (nested_description, more) = type_description(&nested)?;
+        referenced.insert(nested, nested_description);
+        pending.extend(more);
+    }
+    let json = format!(
+        "{{\"type_description\": {}, \"referenced_type_descriptions\": [{}]}}",
+        description,
+        referenced.into_values().collect::<Vec<_>>().join(", ")
+    );
+    let mut hash = String::from("RIHS01_");
+    for byte in sha256(json.as_bytes()) {
+        hash.push_str(&format!("{:02x}", byte));
+    }
+    Some(hash)
+}
+
+/// `TypeDescription` JSON of one schema, in the key order and separators
+/// the Python generator hashes, and the schemas its fields nest.
+fn type_description(schema: &str) -> Option<(String, Vec<String>)> {
+    let (package, _) = parse_schema(schema)?;
+    let mut fields = Vec::new();
+    let mut nested = Vec::new();
+    for (ty, name) in field_lines(definition(schema)?) {
+        // FieldType ids: arrays add 48, bounded sequences 96, sequences 144.
+        let (base, offset, capacity) = match ty.split_once('[') {
+            None => (ty, 0, 0),
+            Some((base, bound)) => match bound.strip_suffix(']')? {
+                "" => (base, 144, 0),
+                bound => match bound.strip_prefix("<=") {
+                    Some(max) => (base, 96, max.parse().ok()?),
+                    None => (base, 48, bound.parse().ok()?),
+                },
+            },
+        };
+        let (type_id, string_capacity, nested_type_name) = match base {
+            "int8" => (2, 0, String::new()),
+            "uint8" | "char" => (3, 0, String::new()),
+            "int16" => (4, 0, String::new()),
+            "uint16" => (5, 0, String::new()),
+            "int32" => (6, 0, String::new()),
+            "uint32" => (7, 0, String::new()),
+            "int64" => (8, 0, String::new()),
+            "uint64" => (9, 0, String::new()),
+            "float32" => (10, 0, String::new()),
+            "float64" => (11, 0, String::new()),
+            "bool" => (15, 0, String::new()),
+            "byte" => (16, 0, String::new()),
+            "string" => (17, 0, String::new()),
+            "wstring" => (18, 0, String::new()),
+            _ if base.starts_with("string<=") => (21, base[8..].parse().ok()?, String::new()),
+            _ if base.starts_with("wstring<=") => (22, base[9..].parse().ok()?, String::new()),
+            _ => {
+                let schema = nested_schema(package, base);
+                nested.push(schema.clone());
+                (1, 0, schema)
+            }
+        };
+        fields.push(field_description(
+            name,
+            type_id + offset,
+            capacity,
+            string_capacity,
+            &nested_type_name,
+        ));
+    }
+    // rosidl gives messages without fields a placeholder member.
+    if fields.is_empty() {
+        fields.push(field_description(
+            "structure_needs_at_least_one_member",
+            3,
+            0,
+            0,
+            "",
+        ));
+    }
+    let description = format!(
+        "{{\"type_name\": \"{}\", \"fields\": [{}]}}",
+        schema,
+        fields.join(", ")
+    );
+    Some((description, nested))
+}
+
+fn field_description(
+    name: &str,
+    type_id: u32,
+    capacity: u64,
+    string_capacity: u64,
+    nested_type_name: &str,
+) -> String {
+    format!(
+        "{{\"name\": \"{}\", \"type\": {{\"type_id\": {}, \"capacity\": {}, \
+         \"string_capacity\": {}, \"nested_type_name\": \"{}\"}}}}",
+        name, type_id, capacity, string_capacity, nested_type_name
+    )
+}
+
+/// OMG IDL for a schema, in the layout `rosidl_adapter` generates from the
+/// same `.msg` text, for DDS tooling that takes IDL input (Cyclone DDS or
+/// Fast DDS `idlc`, RTI Code Generator).
+///
+/// Nested types are pulled in with `#include "package/msg/Type.idl"`, so
+/// each type the schema references needs its own file at that path; see
+/// [`idl_path`]. Constants go in a `Type_Constants` module.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::{idl, idl_path};
+///
+/// let text = idl("std_msgs/msg/Header").unwrap();
+/// assert!(text.contains("#include \"builtin_interfaces/msg/Time.idl\""));
+/// assert!(text.contains("      builtin_interfaces::msg::Time stamp;\n"));
+/// assert_eq!(idl_path("std_msgs/msg/Header").unwrap(), "std_msgs/msg/Header.idl");
+/// ```
+pub fn idl(schema: &str) -> Option<String> {
+    let (package, type_name) = parse_schema(schema)?;
+    let mut includes = Vec::new();
+    let mut constants = String::new();
+    let mut members = String::new();
+    for line in definition(schema)?.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let Some((ty, rest)) = line.split_once(char::is_whitespace) else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let (base, shape) = split_field_type(ty)?;
+        if let Some((name, value)) = rest.split_once('=') {
+            constants.push_str(&format!(
+                "      const {} {} = {};\n",
+                idl_base_type(package, base, &mut includes),
+                name.trim(),
+                idl_literal(base, value.trim())
+            ));
+            continue;
+        }
+        let name = rest.split_whitespace().next().unwrap_or(rest);
+        let element = idl_base_type(package, base, &mut includes);
+        let name = idl_identifier(name);
+        members.push_str(&match shape {
+            FieldShape::Single => format!("      {} {};\n", element, name),
+            FieldShape::Fixed(n) => format!("      {} {}[{}];\n", element, name, n),
+            FieldShape::Seq(None) => format!("      sequence<{}> {};\n", element, name),
+            FieldShape::Seq(Some(n)) => {
+                format!("      sequence<{}, {}> {};\n", element, n, name)
+            }
+        });
+    }
+    if members.is_empty() {
+        members.push_str("      uint8 structure_needs_at_least_one_member;\n");
+    }
+
+    let mut text = format!(
+        "// generated from edgefirst_schemas::schema_registry::idl\n\
+         // with input from {}/msg/{}.msg\n",
+        package, type_name
+    );
+    if !includes.is_empty() {
+        text.push('\n');
+        for include in &includes {
+            text.push_str(&format!("#include \"{}\"\n", include));
+        }
+    }
+    text.push_str(&format!("\nmodule {} {{\n  module msg {{\n", package));
+    if !constants.is_empty() {
+        text.push_str(&format!(
+            "    module {}_Constants {{\n{}    }};\n",
+            type_name, constants
+        ));
+    }
+    text.push_str(&format!(
+        "    struct {} {{\n{}    }};\n  }};\n}};\n",
+        type_name, members
+    ));
+    Some(text)
+}
+
+/// Path of a schema's [`idl`] file relative to the include root, the one
+/// the files of types nesting it `#include`.
+pub fn idl_path(schema: &str) -> Option<String> {
+    let (package, type_name) = parse_schema(schema)?;
+    Some(format!("{}/msg/{}.idl", package, type_name))
+}
+
+/// IDL spelling of a `.msg` base type, recording the include a nested
+/// message type needs.
+fn idl_base_type(package: &str, base: &str, includes: &mut Vec<String>) -> String {
+    let idl = match base {
+        "bool" => "boolean",
+        "byte" => "octet",
+        "char" => "uint8",
+        "float32" => "float",
+        "float64" => "double",
+        "string" | "wstring" | "int8" | "uint8" | "int16" | "uint16" | "int32" | "uint32"
+        | "int64" | "uint64" => base,
+        _ => {
+            if let Some(bound) = base.strip_prefix("string<=") {
+                return format!("string<{}>", bound);
+            }
+            if let Some(bound) = base.strip_prefix("wstring<=") {
+                return format!("wstring<{}>", bound);
+            }
+            let nested = nested_schema(package, base);
+            if let Some(path) = idl_path(&nested) {
+                if !includes.contains(&path) {
+                    includes.push(path);
+                }
+            }
+            return nested.replace('/', "::");
+        }
+    };
+    idl.to_owned()
+}
+
+/// Field name escaped with a leading underscore where it collides
+/// (case-insensitively) with an IDL keyword, as IDL 4.2 §7.2.3.1 specifies.
+fn idl_identifier(name: &str) -> String {
+    const KEYWORDS: &str = "\
+        abstract any alias attribute bitfield bitmask bitset boolean case char component \
+        connector const consumes context custom default double exception emits enum \
+        eventtype factory false finder fixed float getraises getter home import in inout \
+        int8 int16 int32 int64 interface local long manages map mirrorport module multiple \
+        native object octet oneway out primarykey private port porttype provides public \
+        publishes raises readonly setraises setter sequence short string struct supports \
+        switch true truncatable typedef typeid typename typeprefix uint8 uint16 uint32 \
+        uint64 unsigned union uses valuebase valuetype void wchar wstring";
+    if KEYWORDS
+        .split_whitespace()
+        .any(|k| k.eq_ignore_ascii_case(name))
+    {
+        format!("_{}", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+/// IDL literal for a constant of `base` type.
+fn idl_literal(base: &str, value: &str) -> String {
+    match base {
+        "bool" => match value {
+            "true" | "True" | "1" => "TRUE".to_owned(),
+            _ => "FALSE".to_owned(),
+        },
+        _ if base.starts_with("string") || base.starts_with("wstring") => {
+            let inner = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+                .unwrap_or(value);
+            format!("\"{}\"", inner.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+        _ => value.to_owned(),
+    }
+}
+
+/// One field of a schema, as [`introspect`] describes it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Field name, e.g. `frame_id`.
+    pub name: &'static str,
+    /// Element type: a primitive such as `float32`, `string` or
+    /// `string<=8`, or a fully qualified schema name such as
+    /// `std_msgs/msg/Header`.
+    pub ty: String,
+    /// Single value, fixed array or sequence.
+    pub shape: FieldShape,
+    /// Fields of the nested message, empty for primitives and strings.
+    pub fields: Vec<FieldInfo>,
+}
+
+impl FieldInfo {
+    /// Whether the field is a fixed array or a sequence.
+    pub fn is_array(&self) -> bool {
+        self.shape != FieldShape::Single
+    }
+
+    /// Whether the element type is a message rather than a primitive or
+    /// string.
+    pub fn is_message(&self) -> bool {
+        !is_primitive(&self.ty)
+    }
+
+    /// The nested field at a dotted `path`, e.g. `stamp.sec` of a
+    /// `header` field.
+    pub fn field(&self, path: &str) -> Option<&FieldInfo> {
+        find_field(&self.fields, path)
+    }
+}
+
+/// The fields of a schema in declaration order, nested messages expanded,
+/// for tools that let users pick fields at runtime (topic plotters,
+/// filtering rules). `None` if the schema, or a type it nests, is not
+/// registered.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::{find_field, introspect, FieldShape};
+///
+/// let fields = introspect("edgefirst_msgs/msg/Detect").unwrap();
+/// assert_eq!(fields[0].name, "header");
+/// assert_eq!(fields[0].ty, "std_msgs/msg/Header");
+///
+/// let sec = find_field(&fields, "header.stamp.sec").unwrap();
+/// assert_eq!((sec.ty.as_str(), sec.is_message()), ("int32", false));
+///
+/// let boxes = find_field(&fields, "boxes").unwrap();
+/// assert_eq!(boxes.shape, FieldShape::Seq(None));
+/// assert!(boxes.field("score").is_some());
+/// ```
+pub fn introspect(schema: &str) -> Option<Vec<FieldInfo>> {
+    introspect_fields(schema, 0)
+}
+
+/// The field at a dotted `path` among [`introspect`]'s fields.
+pub fn find_field<'a>(fields: &'a [FieldInfo], path: &str) -> Option<&'a FieldInfo> {
+    let (name, rest) = match path.split_once('.') {
+        Some((name, rest)) => (name, Some(rest)),
+        None => (path, None),
+    };
+    let field = fields.iter().find(|f| f.name == name)?;
+    match rest {
+        Some(rest) => field.field(rest),
+        None => Some(field),
+    }
+}
+
+fn introspect_fields(schema: &str, depth: usize) -> Option<Vec<FieldInfo>> {
+    // Recursive definitions cannot be encoded; stop rather than overflow.
+    if depth > 32 {
+        return None;
+    }
+    let (package, _) = parse_schema(schema)?;
+    field_lines(definition(schema)?)
+        .map(|(ty, name)| {
+            let (base, shape) = split_field_type(ty)?;
+            let (ty, fields) = if is_primitive(base) {
+                (base.to_owned(), Vec::new())
+            } else {
+                let nested = nested_schema(package, base);
+                let fields = introspect_fields(&nested, depth + 1)?;
+                (nested, fields)
+            };
+            Some(FieldInfo {
+                name,
+                ty,
+                shape,
+                fields,
+            })
+        })
+        .collect()
+}
+
+/// How a remote definition of a schema relates to the compiled-in one; see
+/// [`is_compatible`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Compatibility {
+    /// Same fields with the same names and types, nested types included.
+    /// Comments, constants and default values are not compared.
+    Identical,
+    /// Only field names differ, so the CDR encodings are interchangeable.
+    Compatible(Vec<FieldChange>),
+    /// Fields were added, removed, retyped or reordered: messages written
+    /// against one definition do not decode against the other. Empty when
+    /// only a type hash was given and it differs.
+    Breaking(Vec<FieldChange>),
+}
+
+/// One difference between a remote and the compiled-in definition. Field
+/// paths are dotted from the top-level message (`header.stamp.sec`);
+/// types are written with nested types fully qualified
+/// (`std_msgs/msg/Header`, `float64[9]`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldChange {
+    /// A field only the remote definition has.
+    Added { field: String, ty: String },
+    /// A field the remote definition lacks.
+    Removed { field: String, ty: String },
+    /// A field whose type differs.
+    Retyped {
+        field: String,
+        local: String,
+        remote: String,
+    },
+    /// A field at the same position with the same type but another name.
+    Renamed { local: String, remote: String },
+    /// The fields of `message` (empty for the top level) appear in another
+    /// order.
+    Reordered { message: String },
+}
+
+/// Compare a remote definition of `schema` with the compiled-in one, e.g.
+/// from a device running older middleware. `remote` is either `.msg` text
+/// (a single definition, or [`full_definition`] layout with nested types
+/// after `MSG:` lines) or a [`type_hash`]. Nested types the text does not
+/// include are assumed to match. `None` if `schema` is not registered.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::{is_compatible, Compatibility, FieldChange};
+///
+/// let schema = "builtin_interfaces/msg/Time";
+/// assert_eq!(is_compatible(schema, "int32 sec\nuint32 nanosec\n"), Some(Compatibility::Identical));
+/// assert_eq!(
+///     is_compatible(schema, "int32 sec\nuint32 nsec\n"),
+///     Some(Compatibility::Compatible(vec![FieldChange::Renamed {
+///         local: "nanosec".into(),
+///         remote: "nsec".into(),
+///     }]))
+/// );
+/// assert!(matches!(is_compatible(schema, "int64 sec\nuint32 nanosec\n"), Some(Compatibility::Breaking(_))));
+/// ```
+pub fn is_compatible(schema: &str, remote: &str) -> Option<Compatibility> {
+    let remote = remote.trim();
+    if remote.starts_with("RIHS01_") {
+        return Some(if type_hash(schema)? == remote {
+            Compatibility::Identical
+        } else {
+            Compatibility::Breaking(Vec::new())
+        });
+    }
+    definition(schema)?;
+    let sections = remote_sections(schema, remote);
+    let mut changes = Vec::new();
+    compare_fields(schema, None, &sections, "", &mut changes, 0)?;
+    Some(classify(changes))
+}
+
+/// Compare two definitions of `schema` given as `.msg` text, e.g. from two
+/// versions of this crate, in the same way as [`is_compatible`]: changes
+/// go from `old` to `new`, so [`FieldChange::Added`] is a field only `new`
+/// has and `local`/`remote` in the other variants are `old`/`new`. Nested
+/// types either text does not include are assumed to match. `None` if
+/// `schema` is not a valid schema name.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::{diff_definitions, Compatibility, FieldChange};
+///
+/// let old = "int32 x\nint32 y\n";
+/// let new = "int32 x\nint32 y\nfloat32 score\n";
+/// assert_eq!(
+///     diff_definitions("acme_msgs/msg/Hit", old, new),
+///     Some(Compatibility::Breaking(vec![FieldChange::Added {
+///         field: "score".into(),
+///         ty: "float32".into(),
+///     }]))
+/// );
+/// ```
+pub fn diff_definitions(schema: &str, old: &str, new: &str) -> Option<Compatibility> {
+    parse_schema(schema)?;
+    let old = remote_sections(schema, old.trim());
+    let new = remote_sections(schema, new.trim());
+    let mut changes = Vec::new();
+    compare_fields(schema, Some(&old), &new, "", &mut changes, 0)?;
+    Some(classify(changes))
+}
+
+fn classify(changes: Vec<FieldChange>) -> Compatibility {
+    if changes.is_empty() {
+        Compatibility::Identical
+    } else if changes
+        .iter()
+        .all(|c| matches!(c, FieldChange::Renamed { .. }))
+    {
+        Compatibility::Compatible(changes)
+    } else {
+        Compatibility::Breaking(changes)
+    }
+}
+
+/// Split `ros2msg` text into definitions keyed by schema name; the first
+/// section is `schema`'s own.
+fn remote_sections(schema: &str, text: &str) -> BTreeMap<String, String> {
+    let mut sections = BTreeMap::new();
+    let mut name = schema.to_owned();
+    let mut body = String::new();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if line.len() >= 3 && line.chars().all(|c| c == '=') {
+            sections.insert(core::mem::take(&mut name), core::mem::take(&mut body));
+            name = match lines.next().and_then(|l| l.trim().strip_prefix("MSG:")) {
+                Some(n) if n.contains("/msg/") => n.trim().to_owned(),
+                Some(n) => nested_schema("", n.trim()),
+                None => String::new(),
+            };
+        } else {
+            body.push_str(line);
+            body.push('\n');
+        }
+    }
+    sections.insert(name, body);
+    sections
+}
+
+/// Field type with a nested base type fully qualified.
+fn resolve_type(package: &str, ty: &str) -> String {
+    let (base, suffix) = ty.find('[').map_or((ty, ""), |i| ty.split_at(i));
+    if is_primitive(base) {
+        ty.to_owned()
+    } else {
+        format!("{}{}", nested_schema(package, base), suffix)
+    }
+}
+
+/// Compare the fields of `schema` in `remote` against `local_sections`, or
+/// against the registry's definitions when that is `None`.
+fn compare_fields(
+    schema: &str,
+    local_sections: Option<&BTreeMap<String, String>>,
+    remote: &BTreeMap<String, String>,
+    path: &str,
+    changes: &mut Vec<FieldChange>,
+    depth: usize,
+) -> Option<()> {
+    // Recursive definitions cannot be encoded; stop rather than overflow.
+    if depth > 32 {
+        return None;
+    }
+    let Some(remote_text) = remote.get(schema) else {
+        return Some(());
+    };
+    let local_text = match local_sections {
+        None => definition(schema)?,
+        Some(sections) => match sections.get(schema) {
+            Some(text) => text,
+            None => return Some(()),
+        },
+    };
+    let (package, _) = parse_schema(schema)?;
+    let fields = |text| -> Vec<(String, &str)> {
+        field_lines(text)
+            .map(|(ty, name)| (resolve_type(package, ty), name))
+            .collect()
+    };
+    let local = fields(local_text);
+    let theirs = fields(remote_text);
+    let join = |name: &str| {
+        if path.is_empty() {
+            name.to_owned()
+        } else {
+            format!("{}.{}", path, name)
+        }
+    };
+    let nested = |ty: &str, field: String, changes: &mut Vec<FieldChange>| {
+        let base = ty.split('[').next().unwrap_or(ty);
+        if is_primitive(base) {
+            return Some(());
+        }
+        compare_fields(base, local_sections, remote, &field, changes, depth + 1)
+    };
+
+    // Pair local fields with remote ones by name, then unpaired ones at the
+    // same position and with the same type as renames.
+    let mut pairs: Vec<Option<usize>> = local
+        .iter()
+        .map(|(_, name)| theirs.iter().position(|(_, n)| n == name))
+        .collect();
+    for i in 0..local.len().min(theirs.len()) {
+        if pairs[i].is_none() && theirs[i].0 == local[i].0 && !pairs.contains(&Some(i)) {
+            pairs[i] = Some(i);
+        }
+    }
+
+    for (i, (ty, name)) in local.iter().enumerate() {
+        let Some(j) = pairs[i] else {
+            changes.push(FieldChange::Removed {
+                field: join(name),
+                ty: ty.clone(),
+            });
+            continue;
+        };
+        let (their_ty, their_name) = &theirs[j];
+        if their_name != name {
+            changes.push(FieldChange::Renamed {
+                local: join(name),
+                remote: join(their_name),
+            });
+        }
+        if ty != their_ty {
+            changes.push(FieldChange::Retyped {
+                field: join(name),
+                local: ty.clone(),
+                remote: their_ty.clone(),
+            });
+        } else {
+            nested(ty, join(name), changes)?;
+        }
+    }
+    for (j, (ty, name)) in theirs.iter().enumerate() {
+        if !pairs.contains(&Some(j)) {
+            changes.push(FieldChange::Added {
+                field: join(name),
+                ty: ty.clone(),
+            });
+        }
+    }
+    let order: Vec<usize> = pairs.iter().flatten().copied().collect();
+    if !order.windows(2).all(|w| w[0] < w[1]) {
+        changes.push(FieldChange::Reordered {
+            message: path.to_owned(),
+        });
+    }
+    Some(())
+}
+
+/// The [`full_definition`] of every schema at one point in time, kept
+/// alongside recorded data or a release so a later registry can be checked
+/// against it with [`diff_snapshots`].
+///
+/// [`to_text`](Self::to_text) and [`from_text`](Self::from_text) store it
+/// as `SCHEMA: package/msg/Type` lines, each followed by that schema's
+/// definition text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegistrySnapshot {
+    definitions: BTreeMap<String, String>,
+}
+
+impl RegistrySnapshot {
+    /// Every schema of [`list_schemas`], registered ones included.
+    pub fn current() -> Self {
+        let mut snapshot = Self::default();
+        for schema in list_schemas() {
+            if let Some(text) = full_definition(schema) {
+                snapshot.insert(schema, &text);
+            }
+        }
+        snapshot
+    }
+
+    /// Add or replace the definition of `schema`, in [`full_definition`]
+    /// layout. Trailing blank lines are dropped.
+    pub fn insert(&mut self, schema: impl Into<String>, definition: &str) {
+        let mut definition = definition.trim_end().to_owned();
+        definition.push('\n');
+        self.definitions.insert(schema.into(), definition);
+    }
+
+    /// The definition recorded for `schema`.
+    pub fn get(&self, schema: &str) -> Option<&str> {
+        self.definitions.get(schema).map(String::as_str)
+    }
+
+    /// Schema names, sorted.
+    pub fn schemas(&self) -> impl Iterator<Item = &str> {
+        self.definitions.keys().map(String::as_str)
+    }
+
+    /// The snapshot as text, for [`from_text`](Self::from_text).
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for (schema, definition) in &self.definitions {
+            text.push_str(&format!("SCHEMA: {}\n", schema));
+            text.push_str(definition);
+        }
+        text
+    }
+
+    /// Parse [`to_text`](Self::to_text) output. `None` if text comes before
+    /// the first `SCHEMA:` line or a schema name is invalid.
+    pub fn from_text(text: &str) -> Option<Self> {
+        let mut snapshot = Self::default();
+        let mut current: Option<(&str, String)> = None;
+        // Not `lines()`: definitions may keep their CRLF line endings.
+        for line in text.split('\n') {
+            if let Some(schema) = line.strip_prefix("SCHEMA:") {
+                let schema = schema.trim();
+                parse_schema(schema)?;
+                if let Some((schema, definition)) = current.take() {
+                    snapshot.insert(schema, &definition);
+                }
+                current = Some((schema, String::new()));
+            } else if let Some((_, definition)) = &mut current {
+                definition.push_str(line);
+                definition.push('\n');
+            } else if !line.trim().is_empty() {
+                return None;
+            }
+        }
+        if let Some((schema, definition)) = current {
+            snapshot.insert(schema, &definition);
+        }
+        Some(snapshot)
+    }
+}
+
+/// How one schema differs between two [`RegistrySnapshot`]s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaChange {
+    /// Only the new snapshot has the schema.
+    Added,
+    /// Only the old snapshot has the schema.
+    Removed,
+    /// Both have it with different fields; never
+    /// [`Compatibility::Identical`].
+    Changed(Compatibility),
+}
+
+/// A schema that differs between two snapshots; see [`diff_snapshots`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaDiff {
+    /// Schema name, e.g. `sensor_msgs/msg/Image`.
+    pub schema: String,
+    /// What changed.
+    pub change: SchemaChange,
+}
+
+impl SchemaDiff {
+    /// Whether data recorded with the old snapshot's schemas can no longer
+    /// be decoded: the schema was removed or its encoding changed.
+    pub fn is_breaking(&self) -> bool {
+        matches!(
+            self.change,
+            SchemaChange::Removed | SchemaChange::Changed(Compatibility::Breaking(_))
+        )
+    }
+}
+
+/// The schemas that were added, removed or changed from `old` to `new`,
+/// sorted by name, e.g. to block an update whose schemas break consumers of
+/// data recorded before it.
+///
+/// # Example
+///
+/// ```rust
+/// use edgefirst_schemas::schema_registry::{diff_snapshots, RegistrySnapshot, SchemaChange};
+///
+/// let old = RegistrySnapshot::current();
+/// assert!(diff_snapshots(&old, &RegistrySnapshot::current()).is_empty());
+///
+/// let mut new = RegistrySnapshot::from_text(&old.to_text()).unwrap();
+/// new.insert("std_msgs/msg/Header", "builtin_interfaces/Time stamp\n");
+/// let diffs = diff_snapshots(&old, &new);
+/// assert_eq!(diffs[0].schema, "std_msgs/msg/Header");
+/// assert!(matches!(diffs[0].change, SchemaChange::Changed(_)));
+/// assert!(diffs[0].is_breaking());
+/// ```
+pub fn diff_snapshots(old: &RegistrySnapshot, new: &RegistrySnapshot) -> Vec<SchemaDiff> {
+    let mut diffs = Vec::new();
+    for (schema, old_text) in &old.definitions {
+        let change = match new.get(schema) {
+            None => SchemaChange::Removed,
+            Some(new_text) => match diff_definitions(schema, old_text, new_text) {
+                Some(Compatibility::Identical) | None => continue,
+                Some(compatibility) => SchemaChange::Changed(compatibility),
+            },
+        };
+        diffs.push(SchemaDiff {
+            schema: schema.clone(),
+            change,
+        });
+    }
+    for schema in new.schemas().filter(|s| old.get(s).is_none()) {
+        diffs.push(SchemaDiff {
+            schema: schema.to_owned(),
+            change: SchemaChange::Added,
+        });
+    }
+    diffs.sort_by(|a, b| a.schema.cmp(&b.schema));
+    diffs
+}
+
+// ── SHA-256 (FIPS 180-4) ─────────────────────────────────────────────
+
+/// First 32 bits of the fractional parts of the cube roots of the first
+/// 64 primes.
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    let mut msg = data.to_vec();
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&((data.len() as u64).wrapping_mul(8)).to_be_bytes());
+
+    for block in msg.chunks_exact(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (word, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = word.wrapping_add(v);
+        }
+    }
+
+    let mut out = [0u8; 32];
+    for (chunk, word) in out.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,4 +1675,309 @@ mod tests {
         assert!(schemas.contains(&"edgefirst_msgs/msg/Box"));
         assert!(!schemas.contains(&"unknown_msgs/msg/Foo"));
     }
+
+    #[test]
+    fn test_every_schema_has_definition() {
+        for schema in list_schemas() {
+            assert!(definition(schema).is_some(), "no definition for {}", schema);
+        }
+        assert!(definition("geometry_msgs/msg/PoseWithCovariance").is_some());
+        assert!(definition("unknown_msgs/msg/Foo").is_none());
+    }
+
+    #[test]
+    fn test_full_definition_appends_nested_types_once() {
+        let text = full_definition("nav_msgs/msg/Odometry").unwrap();
+        assert!(text.starts_with(definition("nav_msgs/msg/Odometry").unwrap()));
+        let sections: Vec<&str> = text
+            .lines()
+            .filter_map(|l| l.strip_prefix("MSG: "))
+            .collect();
+        for nested in [
+            "std_msgs/Header",
+            "builtin_interfaces/Time",
+            "geometry_msgs/PoseWithCovariance",
+            "geometry_msgs/Pose",
+            "geometry_msgs/Point",
+        ] {
+            assert_eq!(sections.iter().filter(|s| **s == nested).count(), 1);
+        }
+        assert_eq!(
+            full_definition("builtin_interfaces/msg/Time").unwrap(),
+            definition("builtin_interfaces/msg/Time").unwrap()
+        );
+        for schema in list_schemas() {
+            assert!(full_definition(schema).is_some(), "{}", schema);
+        }
+        assert!(full_definition("unknown_msgs/msg/Foo").is_none());
+    }
+
+    #[test]
+    fn test_sha256_reference_vectors() {
+        let hex = |b: [u8; 32]| -> String { b.iter().map(|x| format!("{:02x}", x)).collect() };
+        assert_eq!(
+            hex(sha256(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(sha256(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex(sha256(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_type_hash_matches_ros2() {
+        assert_eq!(
+            type_hash("builtin_interfaces/msg/Time").unwrap(),
+            "RIHS01_b106235e25a4c5ed35098aa0a61a3ee9c9b18d197f398b0e4206cea9acf9c197"
+        );
+        // Nested: the referenced Time description is hashed along with it.
+        assert_eq!(
+            type_hash("std_msgs/msg/Header").unwrap(),
+            "RIHS01_f49fb3ae2cf070f793645ff749683ac6b06203e41c891e17701b1cb597ce6a01"
+        );
+        for schema in list_schemas() {
+            let hash = type_hash(schema).unwrap();
+            assert_eq!(hash.len(), "RIHS01_".len() + 64, "{}", schema);
+        }
+        assert!(type_hash("unknown_msgs/msg/Foo").is_none());
+    }
+
+    #[test]
+    fn test_schema_info_sizes_match_encodings() {
+        use crate::cdr::encode_fixed;
+        use crate::sensor_msgs::{Image, NavSatStatus};
+
+        let infos = list_schema_info();
+        assert_eq!(infos.len(), list_schemas().len());
+
+        let time = schema_info("builtin_interfaces/msg/Time").unwrap();
+        let len = encode_fixed(&builtin_interfaces::Time::new(1, 2))
+            .unwrap()
+            .len();
+        assert_eq!((time.min_size, time.max_size), (len, Some(len)));
+
+        // int8 then uint16: one byte of padding.
+        let status = schema_info("sensor_msgs/msg/NavSatStatus").unwrap();
+        let len = encode_fixed(&NavSatStatus {
+            status: 0,
+            service: 0,
+        })
+        .unwrap()
+        .len();
+        assert_eq!((status.min_size, status.max_size), (len, Some(len)));
+
+        let image = schema_info("sensor_msgs/msg/Image").unwrap();
+        let empty = Image::builder().build().unwrap();
+        assert_eq!(image.min_size, empty.as_cdr().len());
+        assert_eq!(image.max_size, None);
+        assert_eq!(image.encoding, "cdr");
+        assert_eq!(
+            image.ffi_prefix,
+            cfg!(feature = "ffi").then_some("ros_image")
+        );
+
+        for (name, _) in FFI_PREFIXES {
+            assert!(list_schemas().contains(name), "{}", name);
+        }
+        for info in infos {
+            assert!(info.max_size.is_none_or(|max| max >= info.min_size));
+        }
+    }
+
+    #[test]
+    fn test_is_compatible_classifies_changes() {
+        use Compatibility::*;
+
+        for schema in list_schemas() {
+            let full = full_definition(schema).unwrap();
+            assert_eq!(is_compatible(schema, &full), Some(Identical), "{}", schema);
+            let hash = type_hash(schema).unwrap();
+            assert_eq!(is_compatible(schema, &hash), Some(Identical), "{}", schema);
+        }
+
+        // A nested type changed inside full_definition text.
+        let schema = "std_msgs/msg/Header";
+        let remote = full_definition(schema)
+            .unwrap()
+            .replace("int32 sec", "int64 sec");
+        assert_eq!(
+            is_compatible(schema, &remote),
+            Some(Breaking(vec![FieldChange::Retyped {
+                field: "stamp.sec".into(),
+                local: "int32".into(),
+                remote: "int64".into(),
+            }]))
+        );
+
+        let remote = "builtin_interfaces/Time stamp\nstring frame_id\nuint32 seq\n";
+        assert_eq!(
+            is_compatible(schema, remote),
+            Some(Breaking(vec![FieldChange::Added {
+                field: "seq".into(),
+                ty: "uint32".into(),
+            }]))
+        );
+        assert_eq!(
+            is_compatible(schema, "string frame_id\nbuiltin_interfaces/Time stamp\n"),
+            Some(Breaking(vec![FieldChange::Reordered {
+                message: String::new()
+            }]))
+        );
+        assert_eq!(
+            is_compatible(schema, "builtin_interfaces/Time stamp\n"),
+            Some(Breaking(vec![FieldChange::Removed {
+                field: "frame_id".into(),
+                ty: "string".into(),
+            }]))
+        );
+
+        let other = type_hash("builtin_interfaces/msg/Duration").unwrap();
+        assert_eq!(
+            is_compatible("builtin_interfaces/msg/Time", &other),
+            Some(Breaking(Vec::new()))
+        );
+        assert!(is_compatible("unknown_msgs/msg/Foo", "int32 x").is_none());
+    }
+
+    #[test]
+    fn test_introspect_expands_nested_types() {
+        let fields = introspect("sensor_msgs/msg/CameraInfo").unwrap();
+        let names: Vec<_> = fields.iter().map(|f| f.name).collect();
+        assert_eq!(
+            &names[..4],
+            ["header", "height", "width", "distortion_model"]
+        );
+        let d = find_field(&fields, "d").unwrap();
+        assert_eq!((d.ty.as_str(), d.shape), ("float64", FieldShape::Seq(None)));
+        let k = find_field(&fields, "k").unwrap();
+        assert_eq!(k.shape, FieldShape::Fixed(9));
+        assert!(k.is_array() && !k.is_message());
+        let roi = find_field(&fields, "roi").unwrap();
+        assert_eq!(roi.ty, "sensor_msgs/msg/RegionOfInterest");
+        assert!(roi.is_message() && !roi.is_array());
+        assert_eq!(roi.field("do_rectify").unwrap().ty, "bool");
+        assert_eq!(find_field(&fields, "header.frame_id").unwrap().ty, "string");
+        assert!(find_field(&fields, "header.seq").is_none());
+        assert!(find_field(&fields, "height.value").is_none());
+
+        // Constants are not fields.
+        let heartbeat = introspect("edgefirst_msgs/msg/Heartbeat").unwrap();
+        assert!(heartbeat.iter().all(|f| !f.name.starts_with("STATUS_")));
+
+        for schema in list_schemas() {
+            assert!(introspect(schema).is_some(), "{}", schema);
+        }
+        assert!(introspect("unknown_msgs/msg/Foo").is_none());
+    }
+
+    #[test]
+    fn test_diff_definitions_and_snapshots() {
+        use Compatibility::*;
+
+        let schema = "std_msgs/msg/Header";
+        let full = full_definition(schema).unwrap();
+        assert_eq!(diff_definitions(schema, &full, &full), Some(Identical));
+        assert_eq!(
+            diff_definitions(
+                schema,
+                &full,
+                &full.replace("uint32 nanosec", "uint32 nsec")
+            ),
+            Some(Compatible(vec![FieldChange::Renamed {
+                local: "stamp.nanosec".into(),
+                remote: "stamp.nsec".into(),
+            }]))
+        );
+        assert_eq!(
+            diff_definitions(schema, &full, "builtin_interfaces/Time stamp\n"),
+            Some(Breaking(vec![FieldChange::Removed {
+                field: "frame_id".into(),
+                ty: "string".into(),
+            }]))
+        );
+        assert!(diff_definitions("Header", "", "").is_none());
+
+        let old = RegistrySnapshot::current();
+        let text = old.to_text();
+        assert_eq!(RegistrySnapshot::from_text(&text), Some(old.clone()));
+        assert_eq!(old.schemas().count(), list_schemas().len());
+        assert!(RegistrySnapshot::from_text("int32 x\n").is_none());
+        assert!(RegistrySnapshot::from_text("SCHEMA: Foo\n").is_none());
+
+        let mut new = old.clone();
+        new.insert("acme_msgs/msg/Hit", "int32 x\n");
+        new.insert(
+            "builtin_interfaces/msg/Time",
+            "int32 sec\nuint32 nanosec\nuint32 extra\n",
+        );
+        new.insert(
+            "builtin_interfaces/msg/Duration",
+            "int32 seconds\nuint32 nanosec\n",
+        );
+        new.definitions.remove("std_msgs/msg/ColorRGBA");
+        let diffs = diff_snapshots(&old, &new);
+        let summary: Vec<_> = diffs
+            .iter()
+            .map(|d| (d.schema.as_str(), d.is_breaking()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("acme_msgs/msg/Hit", false),
+                ("builtin_interfaces/msg/Duration", false),
+                ("builtin_interfaces/msg/Time", true),
+                ("std_msgs/msg/ColorRGBA", true),
+            ]
+        );
+        assert_eq!(diffs[0].change, SchemaChange::Added);
+        assert_eq!(diffs[3].change, SchemaChange::Removed);
+    }
+
+    #[test]
+    fn test_idl_generation() {
+        assert_eq!(
+            idl("std_msgs/msg/Header").unwrap(),
+            "// generated from edgefirst_schemas::schema_registry::idl\n\
+             // with input from std_msgs/msg/Header.msg\n\
+             \n\
+             #include \"builtin_interfaces/msg/Time.idl\"\n\
+             \n\
+             module std_msgs {\n\
+             \x20 module msg {\n\
+             \x20   struct Header {\n\
+             \x20     builtin_interfaces::msg::Time stamp;\n\
+             \x20     string frame_id;\n\
+             \x20   };\n\
+             \x20 };\n\
+             };\n"
+        );
+
+        let heartbeat = idl("edgefirst_msgs/msg/Heartbeat").unwrap();
+        assert!(heartbeat.contains("    module Heartbeat_Constants {\n"));
+        assert!(heartbeat.contains("      const uint8 STATUS_OK = 0;\n"));
+        let info = idl("sensor_msgs/msg/CameraInfo").unwrap();
+        assert!(info.contains("      sequence<double> d;\n"));
+        assert!(info.contains("      double k[9];\n"));
+        assert!(idl("mavros_msgs/msg/Altitude")
+            .unwrap()
+            .contains("      float _local;\n"));
+
+        // Every include names a type that has an IDL file of its own.
+        for schema in list_schemas() {
+            for line in idl(schema).unwrap().lines() {
+                if let Some(include) = line.strip_prefix("#include ") {
+                    let nested = include.trim_matches('"').trim_end_matches(".idl");
+                    assert!(idl(nested).is_some(), "{}: {}", schema, include);
+                }
+            }
+        }
+        assert!(idl("unknown_msgs/msg/Foo").is_none());
+    }
 }
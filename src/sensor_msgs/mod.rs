@@ -6,18 +6,29 @@
 //! CdrFixed: `NavSatStatus`, `RegionOfInterest`
 //!
 //! Buffer-backed: `Image`, `CompressedImage`, `Imu`, `NavSatFix`,
-//! `PointCloud2`, `PointField` (with `PointFieldView`), `CameraInfo`
+//! `PointCloud2`, `PointField` (with `PointFieldView`), `CameraInfo`,
+//! `LaserScan`, `MultiEchoLaserScan` / `LaserEcho` (flattened to a
+//! `LaserScan` by first or strongest return), `Joy`, and the legacy
+//! `PointCloud` / `ChannelFloat32` (with `ChannelFloat32View`),
+//! convertible to and from `PointCloud2`
 //!
 //! Pointcloud access: [`pointcloud`] module provides zero-copy
 //! [`DynPointCloud`](pointcloud::DynPointCloud) and
-//! [`PointCloud<P>`](pointcloud::PointCloud) views over PointCloud2 data.
+//! [`PointCloud<P>`](pointcloud::PointCloud) views over PointCloud2 data,
+//! and `ScanProjection` to flatten a cloud into a `LaserScan`.
+//!
+//! Pixel conversion: [`pixel_convert`] module provides the pluggable
+//! [`PixelConvert`](pixel_convert::PixelConvert) trait for `Image` encodings.
 
+#[cfg(feature = "std")]
+pub mod pixel_convert;
 pub mod pointcloud;
 
 use crate::builtin_interfaces::Time;
 use crate::cdr::*;
-use crate::geometry_msgs::{Quaternion, Vector3};
+use crate::geometry_msgs::{Point32, Quaternion, Vector3};
 use crate::std_msgs::Header;
+use alloc::{vec, vec::Vec};
 
 // ── CdrFixed types ──────────────────────────────────────────────────
 
@@ -87,7 +98,7 @@ impl CdrFixed for RegionOfInterest {
 
 // ── Helper arrays ───────────────────────────────────────────────────
 
-fn read_f64_array9(c: &mut CdrCursor<'_>) -> Result<[f64; 9], CdrError> {
+pub(crate) fn read_f64_array9(c: &mut CdrCursor<'_>) -> Result<[f64; 9], CdrError> {
     Ok([
         c.read_f64()?,
         c.read_f64()?,
@@ -101,19 +112,19 @@ fn read_f64_array9(c: &mut CdrCursor<'_>) -> Result<[f64; 9], CdrError> {
     ])
 }
 
-fn write_f64_array9(w: &mut CdrWriter<'_>, a: &[f64; 9]) {
+pub(crate) fn write_f64_array9(w: &mut CdrWriter<'_>, a: &[f64; 9]) {
     for v in a {
         w.write_f64(*v);
     }
 }
 
-fn size_f64_array9(s: &mut CdrSizer) {
+pub(crate) fn size_f64_array9(s: &mut CdrSizer) {
     for _ in 0..9 {
         s.size_f64();
     }
 }
 
-fn read_f64_array12(c: &mut CdrCursor<'_>) -> Result<[f64; 12], CdrError> {
+pub(crate) fn read_f64_array12(c: &mut CdrCursor<'_>) -> Result<[f64; 12], CdrError> {
     Ok([
         c.read_f64()?,
         c.read_f64()?,
@@ -130,13 +141,13 @@ fn read_f64_array12(c: &mut CdrCursor<'_>) -> Result<[f64; 12], CdrError> {
     ])
 }
 
-fn write_f64_array12(w: &mut CdrWriter<'_>, a: &[f64; 12]) {
+pub(crate) fn write_f64_array12(w: &mut CdrWriter<'_>, a: &[f64; 12]) {
     for v in a {
         w.write_f64(*v);
     }
 }
 
-fn size_f64_array12(s: &mut CdrSizer) {
+pub(crate) fn size_f64_array12(s: &mut CdrSizer) {
     for _ in 0..12 {
         s.size_f64();
     }
@@ -213,6 +224,55 @@ impl<'a> Iterator for PointFieldIter<'a> {
 
 impl ExactSizeIterator for PointFieldIter<'_> {}
 
+// ── ChannelFloat32 helpers ──────────────────────────────────────────
+
+/// A ChannelFloat32 element of a legacy `PointCloud`. Decoded views borrow
+/// `name` from the CDR buffer and own `values`; builders borrow both.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelFloat32View<'a> {
+    pub name: &'a str,
+    pub values: alloc::borrow::Cow<'a, [f32]>,
+}
+
+fn scan_channel_element(c: &mut CdrCursor<'_>) -> Result<(), CdrError> {
+    c.read_string()?;
+    let raw = c.read_u32()?;
+    let n = c.check_seq_count(raw, 4).field("values")?;
+    for _ in 0..n {
+        c.read_f32()?;
+    }
+    Ok(())
+}
+
+fn read_channel_element<'a>(c: &mut CdrCursor<'a>) -> Result<ChannelFloat32View<'a>, CdrError> {
+    let name = c.read_string()?;
+    let raw = c.read_u32()?;
+    let n = c.check_seq_count(raw, 4)?;
+    let values = (0..n)
+        .map(|_| c.read_f32())
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(ChannelFloat32View {
+        name,
+        values: alloc::borrow::Cow::Owned(values),
+    })
+}
+
+fn write_channel_element(w: &mut CdrWriter<'_>, ch: &ChannelFloat32View<'_>) {
+    w.write_string(ch.name);
+    w.write_u32(ch.values.len() as u32);
+    for v in ch.values.iter() {
+        w.write_f32(*v);
+    }
+}
+
+fn size_channel_element(s: &mut CdrSizer, ch: &ChannelFloat32View<'_>) {
+    s.size_string(ch.name);
+    s.size_u32();
+    for _ in ch.values.iter() {
+        s.size_f32();
+    }
+}
+
 // ── Buffer-backed types ─────────────────────────────────────────────
 
 // ── CompressedImage<B> ──────────────────────────────────────────────
@@ -241,13 +301,14 @@ impl<B> CompressedImage<B> {
 
 impl<B: AsRef<[u8]>> CompressedImage<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        let _ = c.read_string()?; // format
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_string().field("format")?;
         let o1 = c.offset();
-        let _ = c.read_bytes()?; // data
+        let _ = c.read_bytes().field("data")?;
         let o2 = c.offset();
+        c.expect_end()?;
         Ok(CompressedImage {
             offsets: [o0, o1, o2],
             buf,
@@ -256,7 +317,7 @@ impl<B: AsRef<[u8]>> CompressedImage<B> {
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -281,6 +342,18 @@ impl<B: AsRef<[u8]>> CompressedImage<B> {
     }
 }
 
+/// Zero-copy `CompressedImage` borrowing its CDR buffer, e.g. straight from a
+/// Zenoh sample.
+pub type CompressedImageRef<'a> = CompressedImage<&'a [u8]>;
+
+impl<'a> CompressedImage<&'a [u8]> {
+    /// Consume the view and return the payload borrowed for the full
+    /// lifetime of the input buffer, so it can outlive the view.
+    pub fn into_data(self) -> &'a [u8] {
+        rd_bytes(self.buf, self.offsets[1]).0
+    }
+}
+
 impl CompressedImage<Vec<u8>> {
     #[deprecated(
         since = "3.2.0",
@@ -328,8 +401,8 @@ impl CompressedImage<Vec<u8>> {
 /// Builder for `CompressedImage<Vec<u8>>` with buffer-reuse finalizers.
 pub struct CompressedImageBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
-    format: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    format: alloc::borrow::Cow<'a, str>,
     data: &'a [u8],
 }
 
@@ -337,8 +410,8 @@ impl<'a> Default for CompressedImageBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
-            format: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            format: alloc::borrow::Cow::Borrowed(""),
             data: &[],
         }
     }
@@ -353,11 +426,11 @@ impl<'a> CompressedImageBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
-    pub fn format(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn format(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.format = s.into();
         self
     }
@@ -366,7 +439,8 @@ impl<'a> CompressedImageBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -375,8 +449,8 @@ impl<'a> CompressedImageBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_string(&self.format);
@@ -386,28 +460,28 @@ impl<'a> CompressedImageBuilder<'a> {
 
     /// Allocate a fresh `Vec<u8>` and return a fully-parsed `CompressedImage<Vec<u8>>`.
     pub fn build(&self) -> Result<CompressedImage<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         CompressedImage::from_cdr(buf)
     }
 
     /// Serialize into the caller's `Vec<u8>`, resizing to exactly the encoded size.
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     /// Serialize into `buf` and return bytes written. Errors with `BufferTooShort`
     /// when `buf` is smaller than the required size; nothing is mutated in that case.
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -449,17 +523,18 @@ impl<B> Image<B> {
 
 impl<B: AsRef<[u8]>> Image<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        let _ = c.read_u32()?; // height
-        let _ = c.read_u32()?; // width
-        let _ = c.read_string()?; // encoding
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        let _ = c.read_u32().field("height")?;
+        let _ = c.read_u32().field("width")?;
+        let _ = c.read_string().field("encoding")?;
         let o1 = c.offset();
-        let _ = c.read_u8()?; // is_bigendian
-        let _ = c.read_u32()?; // step
-        let _ = c.read_bytes()?; // data
+        let _ = c.read_u8().field("is_bigendian")?;
+        let _ = c.read_u32().field("step")?;
+        let _ = c.read_bytes().field("data")?;
         let o2 = c.offset();
+        c.expect_end()?;
         Ok(Image {
             offsets: [o0, o1, o2],
             buf,
@@ -468,7 +543,7 @@ impl<B: AsRef<[u8]>> Image<B> {
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -517,6 +592,19 @@ impl<B: AsRef<[u8]>> Image<B> {
     }
 }
 
+/// Zero-copy `Image` borrowing its CDR buffer, e.g. straight from a
+/// Zenoh sample.
+pub type ImageRef<'a> = Image<&'a [u8]>;
+
+impl<'a> Image<&'a [u8]> {
+    /// Consume the view and return the pixel data borrowed for the full
+    /// lifetime of the input buffer, so it can outlive the view.
+    pub fn into_data(self) -> &'a [u8] {
+        let p = align(self.offsets[1] + 1, 4) + 4;
+        rd_bytes(self.buf, p).0
+    }
+}
+
 impl Image<Vec<u8>> {
     #[deprecated(
         since = "3.2.0",
@@ -614,10 +702,10 @@ impl<B: AsRef<[u8]> + AsMut<[u8]>> Image<B> {
 /// `encode_into_vec()`, or `encode_into_slice()` is called.
 pub struct ImageBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     height: u32,
     width: u32,
-    encoding: std::borrow::Cow<'a, str>,
+    encoding: alloc::borrow::Cow<'a, str>,
     is_bigendian: u8,
     step: u32,
     data: &'a [u8],
@@ -627,10 +715,10 @@ impl<'a> Default for ImageBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             height: 0,
             width: 0,
-            encoding: std::borrow::Cow::Borrowed(""),
+            encoding: alloc::borrow::Cow::Borrowed(""),
             is_bigendian: 0,
             step: 0,
             data: &[],
@@ -647,7 +735,7 @@ impl<'a> ImageBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -659,7 +747,7 @@ impl<'a> ImageBuilder<'a> {
         self.width = w;
         self
     }
-    pub fn encoding(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn encoding(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.encoding = s.into();
         self
     }
@@ -676,7 +764,8 @@ impl<'a> ImageBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -689,8 +778,8 @@ impl<'a> ImageBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_u32(self.height);
@@ -704,8 +793,8 @@ impl<'a> ImageBuilder<'a> {
 
     /// Allocate a fresh `Vec<u8>` and return a fully-parsed `Image<Vec<u8>>`.
     pub fn build(&self) -> Result<Image<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Image::from_cdr(buf)
     }
 
@@ -713,22 +802,22 @@ impl<'a> ImageBuilder<'a> {
     /// size. After return, `buf.len()` is the CDR size and `&buf[..]` is a
     /// complete CDR message. Reuses existing allocation when capacity suffices.
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     /// Serialize into `buf` and return bytes written. Errors with
     /// `BufferTooShort` when `buf` is smaller than the required size; nothing
     /// is mutated in that case.
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -757,21 +846,22 @@ impl<B> Imu<B> {
 
 impl<B: AsRef<[u8]>> Imu<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         Quaternion::read_cdr(&mut c)?;
         read_f64_array9(&mut c)?;
         Vector3::read_cdr(&mut c)?;
         read_f64_array9(&mut c)?;
         Vector3::read_cdr(&mut c)?;
         read_f64_array9(&mut c)?;
+        c.expect_end()?;
         Ok(Imu { offsets: [o0], buf })
     }
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -881,7 +971,7 @@ impl Imu<Vec<u8>> {
 /// Builder for `Imu<Vec<u8>>` with buffer-reuse finalizers.
 pub struct ImuBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     orientation: Quaternion,
     orientation_covariance: [f64; 9],
     angular_velocity: Vector3,
@@ -894,7 +984,7 @@ impl<'a> Default for ImuBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             orientation: Quaternion {
                 x: 0.0,
                 y: 0.0,
@@ -927,7 +1017,7 @@ impl<'a> ImuBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -956,7 +1046,8 @@ impl<'a> ImuBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -969,8 +1060,8 @@ impl<'a> ImuBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         self.orientation.write_cdr(&mut w);
@@ -983,25 +1074,25 @@ impl<'a> ImuBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<Imu<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Imu::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -1098,17 +1189,18 @@ impl<B> NavSatFix<B> {
 
 impl<B: AsRef<[u8]>> NavSatFix<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
         NavSatStatus::read_cdr(&mut c)?;
         c.align(8);
         let o1 = c.offset();
-        c.read_f64()?; // latitude
-        c.read_f64()?; // longitude
-        c.read_f64()?; // altitude
-        read_f64_array9(&mut c)?; // position_covariance
-        c.read_u8()?; // position_covariance_type
+        c.read_f64().field("latitude")?;
+        c.read_f64().field("longitude")?;
+        c.read_f64().field("altitude")?;
+        read_f64_array9(&mut c).field("position_covariance")?;
+        c.read_u8().field("position_covariance_type")?;
+        c.expect_end()?;
         Ok(NavSatFix {
             offsets: [o0, o1],
             buf,
@@ -1117,7 +1209,7 @@ impl<B: AsRef<[u8]>> NavSatFix<B> {
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -1226,7 +1318,7 @@ impl NavSatFix<Vec<u8>> {
 /// Builder for `NavSatFix<Vec<u8>>` with buffer-reuse finalizers.
 pub struct NavSatFixBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     status: NavSatStatus,
     latitude: f64,
     longitude: f64,
@@ -1239,7 +1331,7 @@ impl<'a> Default for NavSatFixBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             status: NavSatStatus {
                 status: 0,
                 service: 0,
@@ -1262,7 +1354,7 @@ impl<'a> NavSatFixBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -1291,7 +1383,8 @@ impl<'a> NavSatFixBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -1305,8 +1398,8 @@ impl<'a> NavSatFixBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         self.status.write_cdr(&mut w);
@@ -1319,25 +1412,25 @@ impl<'a> NavSatFixBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<NavSatFix<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         NavSatFix::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -1421,6 +1514,7 @@ impl<B: AsRef<[u8]>> PointField<B> {
         c.read_u32()?;
         c.read_u8()?;
         c.read_u32()?;
+        c.expect_end()?;
         Ok(PointField { offsets: [o0], buf })
     }
 
@@ -1500,7 +1594,7 @@ impl PointField<Vec<u8>> {
 
 /// Builder for `PointField<Vec<u8>>` with buffer-reuse finalizers.
 pub struct PointFieldBuilder<'a> {
-    name: std::borrow::Cow<'a, str>,
+    name: alloc::borrow::Cow<'a, str>,
     offset: u32,
     datatype: u8,
     count: u32,
@@ -1509,7 +1603,7 @@ pub struct PointFieldBuilder<'a> {
 impl<'a> Default for PointFieldBuilder<'a> {
     fn default() -> Self {
         Self {
-            name: std::borrow::Cow::Borrowed(""),
+            name: alloc::borrow::Cow::Borrowed(""),
             offset: 0,
             datatype: 0,
             count: 0,
@@ -1522,7 +1616,7 @@ impl<'a> PointFieldBuilder<'a> {
         Self::default()
     }
 
-    pub fn name(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn name(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.name = s.into();
         self
     }
@@ -1539,7 +1633,8 @@ impl<'a> PointFieldBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         s.size_string(&self.name);
         s.size_u32();
@@ -1548,8 +1643,8 @@ impl<'a> PointFieldBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         w.write_string(&self.name);
         w.write_u32(self.offset);
         w.write_u8(self.datatype);
@@ -1558,25 +1653,25 @@ impl<'a> PointFieldBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<PointField<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         PointField::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -1631,23 +1726,24 @@ impl<B> PointCloud2<B> {
 
 impl<B: AsRef<[u8]>> PointCloud2<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        c.read_u32()?; // height
-        c.read_u32()?; // width
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_u32().field("height")?;
+        c.read_u32().field("width")?;
         let raw_fields = c.read_u32()?;
         let fields_count = c.check_seq_count(raw_fields, 9)?;
         for _ in 0..fields_count {
             scan_point_field_element(&mut c)?;
         }
         let o1 = c.offset();
-        c.read_bool()?; // is_bigendian
-        c.read_u32()?; // point_step
-        c.read_u32()?; // row_step
-        let _ = c.read_bytes()?; // data
+        c.read_bool().field("is_bigendian")?;
+        c.read_u32().field("point_step")?;
+        c.read_u32().field("row_step")?;
+        let _ = c.read_bytes().field("data")?;
         let o2 = c.offset();
-        c.read_bool()?; // is_dense
+        c.read_bool().field("is_dense")?;
+        c.expect_end()?;
         Ok(PointCloud2 {
             offsets: [o0, o1, o2],
             buf,
@@ -1656,7 +1752,7 @@ impl<B: AsRef<[u8]>> PointCloud2<B> {
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -1745,6 +1841,18 @@ impl<B: AsRef<[u8]>> PointCloud2<B> {
     }
 }
 
+/// Zero-copy `PointCloud2` borrowing its CDR buffer, e.g. straight from a
+/// Zenoh sample.
+pub type PointCloud2Ref<'a> = PointCloud2<&'a [u8]>;
+
+impl<'a> PointCloud2<&'a [u8]> {
+    /// Consume the view and return the point data borrowed for the full
+    /// lifetime of the input buffer, so it can outlive the view.
+    pub fn into_data(self) -> &'a [u8] {
+        rd_bytes(self.buf, align(self.offsets[1] + 1, 4) + 8).0
+    }
+}
+
 impl PointCloud2<Vec<u8>> {
     #[deprecated(
         since = "3.2.0",
@@ -1825,7 +1933,7 @@ impl PointCloud2<Vec<u8>> {
 /// All borrows must remain valid until a finalizer is called.
 pub struct PointCloud2Builder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     height: u32,
     width: u32,
     fields: &'a [PointFieldView<'a>],
@@ -1840,7 +1948,7 @@ impl<'a> Default for PointCloud2Builder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             height: 0,
             width: 0,
             fields: &[],
@@ -1862,7 +1970,7 @@ impl<'a> PointCloud2Builder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -1899,7 +2007,8 @@ impl<'a> PointCloud2Builder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -1917,8 +2026,8 @@ impl<'a> PointCloud2Builder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_u32(self.height);
@@ -1936,25 +2045,25 @@ impl<'a> PointCloud2Builder<'a> {
     }
 
     pub fn build(&self) -> Result<PointCloud2<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         PointCloud2::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -2020,22 +2129,23 @@ impl<B> CameraInfo<B> {
 
 impl<B: AsRef<[u8]>> CameraInfo<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let o0 = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), o0);
-        c.read_u32()?; // height
-        c.read_u32()?; // width
-        let _ = c.read_string()?; // distortion_model
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), o0);
+        c.read_u32().field("height")?;
+        c.read_u32().field("width")?;
+        let _ = c.read_string().field("distortion_model")?;
         let o1 = c.offset();
         let d_count = c.read_u32()? as usize;
         c.skip_seq_8(d_count)?;
         let o2 = c.offset();
-        read_f64_array9(&mut c)?; // k
-        read_f64_array9(&mut c)?; // r
+        read_f64_array9(&mut c).field("k")?;
+        read_f64_array9(&mut c).field("r")?;
         read_f64_array12(&mut c)?; // p
-        c.read_u32()?; // binning_x
-        c.read_u32()?; // binning_y
+        c.read_u32().field("binning_x")?;
+        c.read_u32().field("binning_y")?;
         RegionOfInterest::read_cdr(&mut c)?;
+        c.expect_end()?;
         Ok(CameraInfo {
             offsets: [o0, o1, o2],
             buf,
@@ -2044,7 +2154,7 @@ impl<B: AsRef<[u8]>> CameraInfo<B> {
 
     /// Returns a `Header` view (re-parses CDR prefix; prefer `stamp()`/`frame_id()`).
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -2194,10 +2304,10 @@ impl CameraInfo<Vec<u8>> {
 /// arrays stored by value. All other fields are owned/copied.
 pub struct CameraInfoBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     height: u32,
     width: u32,
-    distortion_model: std::borrow::Cow<'a, str>,
+    distortion_model: alloc::borrow::Cow<'a, str>,
     d: &'a [f64],
     k: [f64; 9],
     r: [f64; 9],
@@ -2211,10 +2321,10 @@ impl<'a> Default for CameraInfoBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             height: 0,
             width: 0,
-            distortion_model: std::borrow::Cow::Borrowed(""),
+            distortion_model: alloc::borrow::Cow::Borrowed(""),
             d: &[],
             k: [0.0; 9],
             r: [0.0; 9],
@@ -2241,7 +2351,7 @@ impl<'a> CameraInfoBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -2253,7 +2363,7 @@ impl<'a> CameraInfoBuilder<'a> {
         self.width = v;
         self
     }
-    pub fn distortion_model(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn distortion_model(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.distortion_model = s.into();
         self
     }
@@ -2286,7 +2396,8 @@ impl<'a> CameraInfoBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -2304,8 +2415,8 @@ impl<'a> CameraInfoBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_u32(self.height);
@@ -2323,25 +2434,25 @@ impl<'a> CameraInfoBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<CameraInfo<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         CameraInfo::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -2467,19 +2578,20 @@ impl<B> MagneticField<B> {
 
 impl<B: AsRef<[u8]>> MagneticField<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(8);
         let o0 = c.offset();
         Vector3::read_cdr(&mut c)?;
         read_f64_array9(&mut c)?;
+        c.expect_end()?;
         Ok(MagneticField { offsets: [o0], buf })
     }
 
     /// Returns a `Header` view by re-parsing the CDR buffer prefix.
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -2548,7 +2660,7 @@ impl MagneticField<Vec<u8>> {
 /// Builder for `MagneticField<Vec<u8>>` with buffer-reuse finalizers.
 pub struct MagneticFieldBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     magnetic_field: Vector3,
     magnetic_field_covariance: [f64; 9],
 }
@@ -2557,7 +2669,7 @@ impl<'a> Default for MagneticFieldBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             magnetic_field: Vector3 {
                 x: 0.0,
                 y: 0.0,
@@ -2577,7 +2689,7 @@ impl<'a> MagneticFieldBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -2590,7 +2702,8 @@ impl<'a> MagneticFieldBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -2600,8 +2713,8 @@ impl<'a> MagneticFieldBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         self.magnetic_field.write_cdr(&mut w);
@@ -2610,25 +2723,25 @@ impl<'a> MagneticFieldBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<MagneticField<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         MagneticField::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -2681,18 +2794,19 @@ impl<B> FluidPressure<B> {
 
 impl<B: AsRef<[u8]>> FluidPressure<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(8);
         let o0 = c.offset();
-        c.read_f64()?; // fluid_pressure
-        c.read_f64()?; // variance
+        c.read_f64().field("fluid_pressure")?;
+        c.read_f64().field("variance")?;
+        c.expect_end()?;
         Ok(FluidPressure { offsets: [o0], buf })
     }
 
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -2759,7 +2873,7 @@ impl FluidPressure<Vec<u8>> {
 /// Builder for `FluidPressure<Vec<u8>>` with buffer-reuse finalizers.
 pub struct FluidPressureBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     fluid_pressure: f64,
     variance: f64,
 }
@@ -2768,7 +2882,7 @@ impl<'a> Default for FluidPressureBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             fluid_pressure: 0.0,
             variance: 0.0,
         }
@@ -2784,7 +2898,7 @@ impl<'a> FluidPressureBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -2797,7 +2911,8 @@ impl<'a> FluidPressureBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -2807,8 +2922,8 @@ impl<'a> FluidPressureBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_f64(self.fluid_pressure);
@@ -2817,25 +2932,25 @@ impl<'a> FluidPressureBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<FluidPressure<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         FluidPressure::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -2879,18 +2994,19 @@ impl<B> Temperature<B> {
 
 impl<B: AsRef<[u8]>> Temperature<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(8);
         let o0 = c.offset();
-        c.read_f64()?; // temperature
-        c.read_f64()?; // variance
+        c.read_f64().field("temperature")?;
+        c.read_f64().field("variance")?;
+        c.expect_end()?;
         Ok(Temperature { offsets: [o0], buf })
     }
 
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -2957,7 +3073,7 @@ impl Temperature<Vec<u8>> {
 /// Builder for `Temperature<Vec<u8>>` with buffer-reuse finalizers.
 pub struct TemperatureBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     temperature: f64,
     variance: f64,
 }
@@ -2966,7 +3082,7 @@ impl<'a> Default for TemperatureBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             temperature: 0.0,
             variance: 0.0,
         }
@@ -2982,7 +3098,7 @@ impl<'a> TemperatureBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -2995,7 +3111,8 @@ impl<'a> TemperatureBuilder<'a> {
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -3005,8 +3122,8 @@ impl<'a> TemperatureBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_f64(self.temperature);
@@ -3015,25 +3132,25 @@ impl<'a> TemperatureBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<Temperature<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Temperature::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -3124,19 +3241,19 @@ impl<B> BatteryState<B> {
 
 impl<B: AsRef<[u8]>> BatteryState<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
-        let header = Header::<&[u8]>::from_cdr(buf.as_ref())?;
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
         let pre = header.end_offset();
-        let mut c = CdrCursor::resume(buf.as_ref(), pre);
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
         c.align(4);
         let o0 = c.offset();
         // Seven f32 scalars
         for _ in 0..7 {
             c.read_f32()?;
         }
-        c.read_u8()?; // power_supply_status
-        c.read_u8()?; // power_supply_health
-        c.read_u8()?; // power_supply_technology
-        c.read_bool()?; // present
+        c.read_u8().field("power_supply_status")?;
+        c.read_u8().field("power_supply_health")?;
+        c.read_u8().field("power_supply_technology")?;
+        c.read_bool().field("present")?;
         let o1 = c.offset();
         // f32 = 4 bytes each; hardening check against pathological counts.
         let raw = c.read_u32()?;
@@ -3151,9 +3268,10 @@ impl<B: AsRef<[u8]>> BatteryState<B> {
             c.read_f32()?;
         }
         let o3 = c.offset();
-        c.read_string()?; // location
+        c.read_string().field("location")?;
         let o4 = c.offset();
-        c.read_string()?; // serial_number
+        c.read_string().field("serial_number")?;
+        c.expect_end()?;
         Ok(BatteryState {
             offsets: [o0, o1, o2, o3, o4],
             buf,
@@ -3161,7 +3279,7 @@ impl<B: AsRef<[u8]>> BatteryState<B> {
     }
 
     pub fn header(&self) -> Header<&[u8]> {
-        Header::from_cdr(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
     }
     pub fn stamp(&self) -> Time {
         rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
@@ -3363,7 +3481,7 @@ impl BatteryState<Vec<u8>> {
 /// borrowed for zero-copy input; both strings use `Cow<'a, str>`.
 pub struct BatteryStateBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
     voltage: f32,
     temperature: f32,
     current: f32,
@@ -3377,15 +3495,15 @@ pub struct BatteryStateBuilder<'a> {
     present: bool,
     cell_voltage: &'a [f32],
     cell_temperature: &'a [f32],
-    location: std::borrow::Cow<'a, str>,
-    serial_number: std::borrow::Cow<'a, str>,
+    location: alloc::borrow::Cow<'a, str>,
+    serial_number: alloc::borrow::Cow<'a, str>,
 }
 
 impl<'a> Default for BatteryStateBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
             voltage: 0.0,
             temperature: 0.0,
             current: 0.0,
@@ -3399,8 +3517,8 @@ impl<'a> Default for BatteryStateBuilder<'a> {
             present: false,
             cell_voltage: &[],
             cell_temperature: &[],
-            location: std::borrow::Cow::Borrowed(""),
-            serial_number: std::borrow::Cow::Borrowed(""),
+            location: alloc::borrow::Cow::Borrowed(""),
+            serial_number: alloc::borrow::Cow::Borrowed(""),
         }
     }
 }
@@ -3414,7 +3532,7 @@ impl<'a> BatteryStateBuilder<'a> {
         self.stamp = t;
         self
     }
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
@@ -3470,16 +3588,17 @@ impl<'a> BatteryStateBuilder<'a> {
         self.cell_temperature = v;
         self
     }
-    pub fn location(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn location(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.location = s.into();
         self
     }
-    pub fn serial_number(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn serial_number(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.serial_number = s.into();
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
@@ -3504,8 +3623,8 @@ impl<'a> BatteryStateBuilder<'a> {
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.write_f32(self.voltage);
@@ -3533,25 +3652,25 @@ impl<'a> BatteryStateBuilder<'a> {
     }
 
     pub fn build(&self) -> Result<BatteryState<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         BatteryState::from_cdr(buf)
     }
 
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -3608,111 +3727,1986 @@ impl<B: AsRef<[u8]> + AsMut<[u8]>> BatteryState<B> {
     }
 }
 
-// ── Registry ────────────────────────────────────────────────────────
+// ── LaserScan<B> ────────────────────────────────────────────────────
+//
+// CDR layout: Header → offsets[0] (start of the seven f32 scalars,
+//   4-aligned), uint32 count + float32[] ranges → offsets[1],
+//   uint32 count + float32[] intensities → offsets[2].
 
-/// Check if a type name is supported by this module.
-pub fn is_type_supported(type_name: &str) -> bool {
-    matches!(
-        type_name,
-        "BatteryState"
-            | "CameraInfo"
-            | "CompressedImage"
-            | "FluidPressure"
-            | "Image"
-            | "Imu"
-            | "MagneticField"
-            | "NavSatFix"
-            | "NavSatStatus"
-            | "PointCloud2"
-            | "PointField"
-            | "RegionOfInterest"
-            | "Temperature"
-    )
+pub struct LaserScan<B> {
+    buf: B,
+    offsets: [usize; 3],
 }
 
-/// List all type schema names in this module.
-pub fn list_types() -> &'static [&'static str] {
-    &[
-        "sensor_msgs/msg/BatteryState",
-        "sensor_msgs/msg/CameraInfo",
-        "sensor_msgs/msg/CompressedImage",
-        "sensor_msgs/msg/FluidPressure",
-        "sensor_msgs/msg/Image",
-        "sensor_msgs/msg/Imu",
-        "sensor_msgs/msg/MagneticField",
-        "sensor_msgs/msg/NavSatFix",
-        "sensor_msgs/msg/NavSatStatus",
-        "sensor_msgs/msg/PointCloud2",
-        "sensor_msgs/msg/PointField",
-        "sensor_msgs/msg/RegionOfInterest",
-        "sensor_msgs/msg/Temperature",
-    ]
+impl<B> LaserScan<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> LaserScan<C> {
+        LaserScan {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
 }
 
-// SchemaType implementations
-use crate::schema_registry::SchemaType;
+impl<B: AsRef<[u8]>> LaserScan<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let pre = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
+        c.align(4);
+        let o0 = c.offset();
+        for _ in 0..7 {
+            c.read_f32()?;
+        }
+        let o1 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 4).field("ranges")?;
+        for _ in 0..n {
+            c.read_f32()?;
+        }
+        let o2 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 4).field("intensities")?;
+        for _ in 0..n {
+            c.read_f32()?;
+        }
+        c.expect_end()?;
+        Ok(LaserScan {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
 
-impl SchemaType for NavSatStatus {
-    const SCHEMA_NAME: &'static str = "sensor_msgs/msg/NavSatStatus";
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn angle_min(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn angle_max(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 4)
+    }
+    pub fn angle_increment(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 8)
+    }
+    pub fn time_increment(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 12)
+    }
+    pub fn scan_time(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 16)
+    }
+    pub fn range_min(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 20)
+    }
+    pub fn range_max(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 24)
+    }
+    pub fn ranges_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[1])
+    }
+    /// Byte offset of the `ranges` sequence (u32 count, then elements).
+    /// Exposed for allocation-free decoders (e.g. FFI).
+    pub fn ranges_seq_offset(&self) -> usize {
+        self.offsets[1]
+    }
+    pub fn ranges(&self) -> Vec<f32> {
+        self.f32_seq(self.offsets[1])
+    }
+    pub fn intensities_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[2])
+    }
+    /// Byte offset of the `intensities` sequence (u32 count, then elements).
+    pub fn intensities_seq_offset(&self) -> usize {
+        self.offsets[2]
+    }
+    pub fn intensities(&self) -> Vec<f32> {
+        self.f32_seq(self.offsets[2])
+    }
+    fn f32_seq(&self, at: usize) -> Vec<f32> {
+        let b = self.buf.as_ref();
+        let n = rd_u32(b, at) as usize;
+        (0..n).map(|i| rd_f32(b, at + 4 + 4 * i)).collect()
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
 }
 
-impl SchemaType for RegionOfInterest {
-    const SCHEMA_NAME: &'static str = "sensor_msgs/msg/RegionOfInterest";
+impl LaserScan<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `LaserScanBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> LaserScanBuilder<'a> {
+        LaserScanBuilder::new()
+    }
 }
 
-#[cfg(test)]
-#[allow(deprecated)] // Tests exercise Image::new / PointCloud2::new, which are deprecated in 3.2.0 but still supported until 4.0.
-mod tests {
-    use super::*;
-    use crate::builtin_interfaces::Time;
-    use crate::cdr::{decode_fixed, encode_fixed};
-    use crate::geometry_msgs::{Quaternion, Vector3};
+// ── LaserScanBuilder<'a> ────────────────────────────────────────────
 
-    #[test]
-    fn compressed_image_roundtrip() {
-        let img = CompressedImage::new(
-            Time::new(100, 500_000_000),
-            "camera",
-            "jpeg",
-            &[0xFF, 0xD8, 0xFF],
-        )
-        .unwrap();
-        assert_eq!(img.stamp(), Time::new(100, 500_000_000));
-        assert_eq!(img.frame_id(), "camera");
-        assert_eq!(img.format(), "jpeg");
-        assert_eq!(img.data(), &[0xFF, 0xD8, 0xFF]);
+/// Builder for `LaserScan<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `ranges` and `intensities` are borrowed for zero-copy input.
+pub struct LaserScanBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    angle_min: f32,
+    angle_max: f32,
+    angle_increment: f32,
+    time_increment: f32,
+    scan_time: f32,
+    range_min: f32,
+    range_max: f32,
+    ranges: &'a [f32],
+    intensities: &'a [f32],
+}
 
-        let bytes = img.to_cdr();
-        let decoded = CompressedImage::from_cdr(bytes).unwrap();
-        assert_eq!(decoded.format(), "jpeg");
-        assert_eq!(decoded.data(), &[0xFF, 0xD8, 0xFF]);
+impl<'a> Default for LaserScanBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            angle_min: 0.0,
+            angle_max: 0.0,
+            angle_increment: 0.0,
+            time_increment: 0.0,
+            scan_time: 0.0,
+            range_min: 0.0,
+            range_max: 0.0,
+            ranges: &[],
+            intensities: &[],
+        }
     }
+}
 
-    #[test]
-    fn image_roundtrip() {
-        let data = vec![128u8; 1920 * 480];
-        let img = Image::new(
-            Time::new(100, 500_000_000),
-            "camera_optical",
-            480,
-            640,
-            "rgb8",
-            0,
-            1920,
-            &data,
-        )
-        .unwrap();
-        assert_eq!(img.height(), 480);
-        assert_eq!(img.width(), 640);
-        assert_eq!(img.encoding(), "rgb8");
-        assert_eq!(img.is_bigendian(), 0);
-        assert_eq!(img.step(), 1920);
-        assert_eq!(img.data().len(), 1920 * 480);
+impl<'a> LaserScanBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        let bytes = img.to_cdr();
-        let decoded = Image::from_cdr(bytes).unwrap();
-        assert_eq!(decoded.height(), 480);
-        assert_eq!(decoded.width(), 640);
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn angle_min(&mut self, v: f32) -> &mut Self {
+        self.angle_min = v;
+        self
+    }
+    pub fn angle_max(&mut self, v: f32) -> &mut Self {
+        self.angle_max = v;
+        self
+    }
+    pub fn angle_increment(&mut self, v: f32) -> &mut Self {
+        self.angle_increment = v;
+        self
+    }
+    pub fn time_increment(&mut self, v: f32) -> &mut Self {
+        self.time_increment = v;
+        self
+    }
+    pub fn scan_time(&mut self, v: f32) -> &mut Self {
+        self.scan_time = v;
+        self
+    }
+    pub fn range_min(&mut self, v: f32) -> &mut Self {
+        self.range_min = v;
+        self
+    }
+    pub fn range_max(&mut self, v: f32) -> &mut Self {
+        self.range_max = v;
+        self
+    }
+    pub fn ranges(&mut self, v: &'a [f32]) -> &mut Self {
+        self.ranges = v;
+        self
+    }
+    pub fn intensities(&mut self, v: &'a [f32]) -> &mut Self {
+        self.intensities = v;
+        self
+    }
+    /// Copy the angular and range limits of a [`ScanProjection`] so the
+    /// scan describes the bins it produced.
+    ///
+    /// [`ScanProjection`]: pointcloud::ScanProjection
+    #[cfg(feature = "std")]
+    pub fn projection(&mut self, p: &pointcloud::ScanProjection) -> &mut Self {
+        self.angle_min = p.angle_min;
+        self.angle_max = p.angle_max;
+        self.angle_increment = p.angle_increment;
+        self.range_min = p.range_min;
+        self.range_max = p.range_max;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.align(4);
+        for _ in 0..7 {
+            s.size_f32();
+        }
+        s.size_u32();
+        for _ in self.ranges {
+            s.size_f32();
+        }
+        s.size_u32();
+        for _ in self.intensities {
+            s.size_f32();
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_f32(self.angle_min);
+        w.write_f32(self.angle_max);
+        w.write_f32(self.angle_increment);
+        w.write_f32(self.time_increment);
+        w.write_f32(self.scan_time);
+        w.write_f32(self.range_min);
+        w.write_f32(self.range_max);
+        w.write_u32(self.ranges.len() as u32);
+        for v in self.ranges {
+            w.write_f32(*v);
+        }
+        w.write_u32(self.intensities.len() as u32);
+        for v in self.intensities {
+            w.write_f32(*v);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<LaserScan<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        LaserScan::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> LaserScan<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── LaserEcho helpers ───────────────────────────────────────────────
+
+fn scan_echo_element(c: &mut CdrCursor<'_>) -> Result<(), CdrError> {
+    let raw = c.read_u32()?;
+    let n = c.check_seq_count(raw, 4).field("echoes")?;
+    for _ in 0..n {
+        c.read_f32()?;
+    }
+    Ok(())
+}
+
+/// Read a `LaserEcho[]` sequence (count included) starting at `at`.
+fn read_echoes(b: &[u8], at: usize) -> Vec<Vec<f32>> {
+    let mut p = at + 4;
+    (0..rd_u32(b, at))
+        .map(|_| {
+            let n = rd_u32(b, p) as usize;
+            let echoes = (0..n).map(|i| rd_f32(b, p + 4 + 4 * i)).collect();
+            p += 4 + 4 * n;
+            echoes
+        })
+        .collect()
+}
+
+fn write_echoes(w: &mut CdrWriter<'_>, beams: &[&[f32]]) {
+    w.write_u32(beams.len() as u32);
+    for echoes in beams {
+        w.write_u32(echoes.len() as u32);
+        for v in *echoes {
+            w.write_f32(*v);
+        }
+    }
+}
+
+fn size_echoes(s: &mut CdrSizer, beams: &[&[f32]]) {
+    s.size_u32();
+    for echoes in beams {
+        s.size_u32();
+        for _ in *echoes {
+            s.size_f32();
+        }
+    }
+}
+
+// ── LaserEcho<B> ────────────────────────────────────────────────────
+//
+// CDR layout: uint32 count + float32[] echoes
+
+/// The returns of one beam of a multi-echo scan, in the order the sensor
+/// reports them.
+pub struct LaserEcho<B> {
+    buf: B,
+}
+
+impl<B> LaserEcho<B> {
+    /// Convert the buffer type.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> LaserEcho<C> {
+        LaserEcho { buf: f(self.buf) }
+    }
+}
+
+impl<B: AsRef<[u8]>> LaserEcho<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        scan_echo_element(&mut c)?;
+        c.expect_end()?;
+        Ok(LaserEcho { buf })
+    }
+
+    pub fn echoes_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn echoes(&self) -> Vec<f32> {
+        let b = self.buf.as_ref();
+        (0..self.echoes_len() as usize)
+            .map(|i| rd_f32(b, CDR_HEADER_SIZE + 4 + 4 * i))
+            .collect()
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl LaserEcho<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `LaserEchoBuilder` with no echoes.
+    pub fn builder<'a>() -> LaserEchoBuilder<'a> {
+        LaserEchoBuilder::new()
+    }
+}
+
+// ── LaserEchoBuilder<'a> ────────────────────────────────────────────
+
+/// Builder for `LaserEcho<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `echoes` is borrowed for zero-copy input.
+#[derive(Default)]
+pub struct LaserEchoBuilder<'a> {
+    echoes: &'a [f32],
+}
+
+impl<'a> LaserEchoBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn echoes(&mut self, v: &'a [f32]) -> &mut Self {
+        self.echoes = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        s.size_u32();
+        for _ in self.echoes {
+            s.size_f32();
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        w.write_u32(self.echoes.len() as u32);
+        for v in self.echoes {
+            w.write_f32(*v);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<LaserEcho<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        LaserEcho::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── MultiEchoLaserScan<B> ───────────────────────────────────────────
+//
+// CDR layout: Header → offsets[0] (start of the seven f32 scalars,
+//   4-aligned), uint32 count + LaserEcho[] ranges → offsets[1],
+//   uint32 count + LaserEcho[] intensities → offsets[2].
+
+/// Which return of each beam [`MultiEchoLaserScan::to_laser_scan`] keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EchoSelection {
+    /// The first return reported for the beam.
+    First,
+    /// The return with the highest intensity; the first one when the
+    /// scan has no intensities for the beam.
+    Strongest,
+}
+
+/// A `LaserScan` whose beams each carry every return the sensor saw, as
+/// published by multi-echo 2D lidars.
+pub struct MultiEchoLaserScan<B> {
+    buf: B,
+    offsets: [usize; 3],
+}
+
+impl<B> MultiEchoLaserScan<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> MultiEchoLaserScan<C> {
+        MultiEchoLaserScan {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> MultiEchoLaserScan<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let pre = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
+        c.align(4);
+        let o0 = c.offset();
+        for _ in 0..7 {
+            c.read_f32()?;
+        }
+        let o1 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 4).field("ranges")?;
+        for _ in 0..n {
+            scan_echo_element(&mut c).field("ranges")?;
+        }
+        let o2 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 4).field("intensities")?;
+        for _ in 0..n {
+            scan_echo_element(&mut c).field("intensities")?;
+        }
+        c.expect_end()?;
+        Ok(MultiEchoLaserScan {
+            offsets: [o0, o1, o2],
+            buf,
+        })
+    }
+
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn angle_min(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn angle_max(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 4)
+    }
+    pub fn angle_increment(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 8)
+    }
+    pub fn time_increment(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 12)
+    }
+    pub fn scan_time(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 16)
+    }
+    pub fn range_min(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 20)
+    }
+    pub fn range_max(&self) -> f32 {
+        rd_f32(self.buf.as_ref(), self.offsets[0] + 24)
+    }
+    pub fn ranges_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[1])
+    }
+    /// The returns of each beam, one `Vec` per beam.
+    pub fn ranges(&self) -> Vec<Vec<f32>> {
+        read_echoes(self.buf.as_ref(), self.offsets[1])
+    }
+    pub fn intensities_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[2])
+    }
+    /// The intensity of each return, shaped like [`ranges`](Self::ranges).
+    pub fn intensities(&self) -> Vec<Vec<f32>> {
+        read_echoes(self.buf.as_ref(), self.offsets[2])
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+
+    /// Flatten to a `LaserScan` with the same header and limits, keeping
+    /// one return per beam as chosen by `echo`. Beams without returns get
+    /// a NaN range; intensities are kept when the scan has them.
+    ///
+    /// Fails only if the scan exceeds the current
+    /// [`DecodeLimits`](crate::cdr::DecodeLimits).
+    pub fn to_laser_scan(&self, echo: EchoSelection) -> Result<LaserScan<Vec<u8>>, CdrError> {
+        let ranges = self.ranges();
+        let intensities = self.intensities();
+        let mut out_ranges = Vec::with_capacity(ranges.len());
+        let mut out_intensities = Vec::new();
+        for (beam, echoes) in ranges.iter().enumerate() {
+            let strengths = intensities.get(beam).map(Vec::as_slice).unwrap_or(&[]);
+            let pick = match echo {
+                EchoSelection::First => 0,
+                EchoSelection::Strongest => strengths
+                    .iter()
+                    .take(echoes.len())
+                    .enumerate()
+                    .fold(None, |best: Option<(usize, f32)>, (i, &v)| match best {
+                        Some((_, b)) if b >= v => best,
+                        _ => Some((i, v)),
+                    })
+                    .map_or(0, |(i, _)| i),
+            };
+            out_ranges.push(echoes.get(pick).copied().unwrap_or(f32::NAN));
+            if !intensities.is_empty() {
+                out_intensities.push(strengths.get(pick).copied().unwrap_or(0.0));
+            }
+        }
+        LaserScan::builder()
+            .stamp(self.stamp())
+            .frame_id(self.frame_id())
+            .angle_min(self.angle_min())
+            .angle_max(self.angle_max())
+            .angle_increment(self.angle_increment())
+            .time_increment(self.time_increment())
+            .scan_time(self.scan_time())
+            .range_min(self.range_min())
+            .range_max(self.range_max())
+            .ranges(&out_ranges)
+            .intensities(&out_intensities)
+            .build()
+    }
+}
+
+impl MultiEchoLaserScan<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `MultiEchoLaserScanBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> MultiEchoLaserScanBuilder<'a> {
+        MultiEchoLaserScanBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> MultiEchoLaserScan<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── MultiEchoLaserScanBuilder<'a> ───────────────────────────────────
+
+/// Builder for `MultiEchoLaserScan<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `ranges` and `intensities` are borrowed for zero-copy input, one slice
+/// of returns per beam.
+pub struct MultiEchoLaserScanBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    angle_min: f32,
+    angle_max: f32,
+    angle_increment: f32,
+    time_increment: f32,
+    scan_time: f32,
+    range_min: f32,
+    range_max: f32,
+    ranges: &'a [&'a [f32]],
+    intensities: &'a [&'a [f32]],
+}
+
+impl<'a> Default for MultiEchoLaserScanBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            angle_min: 0.0,
+            angle_max: 0.0,
+            angle_increment: 0.0,
+            time_increment: 0.0,
+            scan_time: 0.0,
+            range_min: 0.0,
+            range_max: 0.0,
+            ranges: &[],
+            intensities: &[],
+        }
+    }
+}
+
+impl<'a> MultiEchoLaserScanBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn angle_min(&mut self, v: f32) -> &mut Self {
+        self.angle_min = v;
+        self
+    }
+    pub fn angle_max(&mut self, v: f32) -> &mut Self {
+        self.angle_max = v;
+        self
+    }
+    pub fn angle_increment(&mut self, v: f32) -> &mut Self {
+        self.angle_increment = v;
+        self
+    }
+    pub fn time_increment(&mut self, v: f32) -> &mut Self {
+        self.time_increment = v;
+        self
+    }
+    pub fn scan_time(&mut self, v: f32) -> &mut Self {
+        self.scan_time = v;
+        self
+    }
+    pub fn range_min(&mut self, v: f32) -> &mut Self {
+        self.range_min = v;
+        self
+    }
+    pub fn range_max(&mut self, v: f32) -> &mut Self {
+        self.range_max = v;
+        self
+    }
+    pub fn ranges(&mut self, v: &'a [&'a [f32]]) -> &mut Self {
+        self.ranges = v;
+        self
+    }
+    pub fn intensities(&mut self, v: &'a [&'a [f32]]) -> &mut Self {
+        self.intensities = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.align(4);
+        for _ in 0..7 {
+            s.size_f32();
+        }
+        size_echoes(&mut s, self.ranges);
+        size_echoes(&mut s, self.intensities);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_f32(self.angle_min);
+        w.write_f32(self.angle_max);
+        w.write_f32(self.angle_increment);
+        w.write_f32(self.time_increment);
+        w.write_f32(self.scan_time);
+        w.write_f32(self.range_min);
+        w.write_f32(self.range_max);
+        write_echoes(&mut w, self.ranges);
+        write_echoes(&mut w, self.intensities);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<MultiEchoLaserScan<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        MultiEchoLaserScan::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── Joy<B> ──────────────────────────────────────────────────────────
+//
+// CDR layout: Header → offsets[0] (byte index of the axes seq count u32),
+//   uint32 count + float32[] axes, uint32 count + int32[] buttons →
+//   offsets[1].
+
+/// Gamepad or joystick state for teleoperation: one value in `[-1, 1]` per
+/// axis and `0`/`1` per button.
+pub struct Joy<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
+
+impl<B> Joy<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> Joy<C> {
+        Joy {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Joy<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let pre = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
+        c.align(4);
+        let o0 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 4).field("axes")?;
+        for _ in 0..n {
+            c.read_f32()?;
+        }
+        let o1 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 4).field("buttons")?;
+        for _ in 0..n {
+            c.read_i32()?;
+        }
+        c.expect_end()?;
+        Ok(Joy {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
+
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn axes_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn axes(&self) -> Vec<f32> {
+        let b = self.buf.as_ref();
+        let at = self.offsets[0];
+        (0..self.axes_len() as usize)
+            .map(|i| rd_f32(b, at + 4 + 4 * i))
+            .collect()
+    }
+    pub fn buttons_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[1])
+    }
+    pub fn buttons(&self) -> Vec<i32> {
+        let b = self.buf.as_ref();
+        let at = self.offsets[1];
+        (0..self.buttons_len() as usize)
+            .map(|i| rd_i32(b, at + 4 + 4 * i))
+            .collect()
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl Joy<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `JoyBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> JoyBuilder<'a> {
+        JoyBuilder::new()
+    }
+}
+
+// ── JoyBuilder<'a> ──────────────────────────────────────────────────
+
+/// Builder for `Joy<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `axes` and `buttons` are borrowed for zero-copy input.
+pub struct JoyBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    axes: &'a [f32],
+    buttons: &'a [i32],
+}
+
+impl<'a> Default for JoyBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            axes: &[],
+            buttons: &[],
+        }
+    }
+}
+
+impl<'a> JoyBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn axes(&mut self, v: &'a [f32]) -> &mut Self {
+        self.axes = v;
+        self
+    }
+    pub fn buttons(&mut self, v: &'a [i32]) -> &mut Self {
+        self.buttons = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_u32();
+        for _ in self.axes {
+            s.size_f32();
+        }
+        s.size_u32();
+        for _ in self.buttons {
+            s.size_i32();
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_u32(self.axes.len() as u32);
+        for v in self.axes {
+            w.write_f32(*v);
+        }
+        w.write_u32(self.buttons.len() as u32);
+        for v in self.buttons {
+            w.write_i32(*v);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<Joy<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        Joy::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Joy<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── ChannelFloat32<B> ───────────────────────────────────────────────
+//
+// CDR layout: name (string) → offsets[0] (byte index of the values seq
+//   count u32, 4-aligned), uint32 count + float32[] values.
+
+pub struct ChannelFloat32<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> ChannelFloat32<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> ChannelFloat32<C> {
+        ChannelFloat32 {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> ChannelFloat32<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        let _ = c.read_string().field("name")?;
+        c.align(4);
+        let o0 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, 4).field("values")?;
+        for _ in 0..n {
+            c.read_f32()?;
+        }
+        c.expect_end()?;
+        Ok(ChannelFloat32 { offsets: [o0], buf })
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE).0
+    }
+    pub fn values_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn values(&self) -> Vec<f32> {
+        let b = self.buf.as_ref();
+        let at = self.offsets[0];
+        (0..self.values_len() as usize)
+            .map(|i| rd_f32(b, at + 4 + 4 * i))
+            .collect()
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl ChannelFloat32<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `ChannelFloat32Builder` with zero-valued defaults.
+    pub fn builder<'a>() -> ChannelFloat32Builder<'a> {
+        ChannelFloat32Builder::new()
+    }
+}
+
+// ── ChannelFloat32Builder<'a> ───────────────────────────────────────
+
+/// Builder for `ChannelFloat32<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `values` is borrowed for zero-copy input.
+pub struct ChannelFloat32Builder<'a> {
+    name: alloc::borrow::Cow<'a, str>,
+    values: &'a [f32],
+}
+
+impl<'a> Default for ChannelFloat32Builder<'a> {
+    fn default() -> Self {
+        Self {
+            name: alloc::borrow::Cow::Borrowed(""),
+            values: &[],
+        }
+    }
+}
+
+impl<'a> ChannelFloat32Builder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.name = s.into();
+        self
+    }
+    pub fn values(&mut self, v: &'a [f32]) -> &mut Self {
+        self.values = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        s.size_string(&self.name);
+        s.size_u32();
+        for _ in self.values {
+            s.size_f32();
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        w.write_string(&self.name);
+        w.write_u32(self.values.len() as u32);
+        for v in self.values {
+            w.write_f32(*v);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<ChannelFloat32<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        ChannelFloat32::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── PointCloud<B> ───────────────────────────────────────────────────
+//
+// CDR layout: Header → offsets[0] (byte index of the points seq count
+//   u32, 4-aligned), uint32 count + Point32[] points (12 bytes each),
+//   uint32 count + ChannelFloat32[] channels → offsets[1].
+
+/// The ROS 1 era point cloud that some radar and sonar drivers still
+/// publish: one `Point32` per point plus named `float32` channels holding
+/// one value per point. Prefer `PointCloud2`; see
+/// [`to_pointcloud2`](Self::to_pointcloud2).
+pub struct PointCloud<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
+
+impl<B> PointCloud<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> PointCloud<C> {
+        PointCloud {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> PointCloud<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let pre = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
+        c.align(4);
+        let o0 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c.check_seq_count(raw, Point32::CDR_SIZE).field("points")?;
+        for _ in 0..n {
+            Point32::read_cdr(&mut c).field("points")?;
+        }
+        let o1 = c.offset();
+        let raw = c.read_u32()?;
+        // Smallest element: empty name (4 + 1) and an empty values count (4).
+        let n = c.check_seq_count(raw, 9).field("channels")?;
+        for _ in 0..n {
+            scan_channel_element(&mut c).field("channels")?;
+        }
+        c.expect_end()?;
+        Ok(PointCloud {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
+
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn points_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn points(&self) -> Vec<Point32> {
+        let b = self.buf.as_ref();
+        let at = self.offsets[0] + 4;
+        (0..self.points_len() as usize)
+            .map(|i| {
+                let p = at + 12 * i;
+                Point32 {
+                    x: rd_f32(b, p),
+                    y: rd_f32(b, p + 4),
+                    z: rd_f32(b, p + 8),
+                }
+            })
+            .collect()
+    }
+    pub fn channels_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[1])
+    }
+    pub fn channels(&self) -> Vec<ChannelFloat32View<'_>> {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[1] + 4);
+        (0..self.channels_len())
+            .map(|_| {
+                read_channel_element(&mut c).expect("channel elements validated during from_cdr")
+            })
+            .collect()
+    }
+    /// The channel called `name`, e.g. `intensity`.
+    pub fn channel(&self, name: &str) -> Option<ChannelFloat32View<'_>> {
+        self.channels().into_iter().find(|ch| ch.name == name)
+    }
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+
+    /// The cloud as an unorganized (`height` 1) little-endian
+    /// `PointCloud2` with the same stamp and frame_id: `FLOAT32` fields
+    /// `x`, `y`, `z`, then one per channel in order. `is_dense` is set
+    /// when every coordinate is finite.
+    ///
+    /// # Errors
+    ///
+    /// [`PointCloudError::InvalidLayout`](pointcloud::PointCloudError::InvalidLayout)
+    /// if a channel does not hold one value per point, and
+    /// [`PointCloudError::Cdr`](pointcloud::PointCloudError::Cdr) if the
+    /// result exceeds the current [`DecodeLimits`](crate::cdr::DecodeLimits).
+    pub fn to_pointcloud2(&self) -> Result<PointCloud2<Vec<u8>>, pointcloud::PointCloudError> {
+        let points = self.points();
+        let channels = self.channels();
+        if channels.iter().any(|ch| ch.values.len() != points.len()) {
+            return Err(pointcloud::PointCloudError::InvalidLayout {
+                reason: "channel length differs from the point count",
+            });
+        }
+        let fields: Vec<PointFieldView<'_>> = ["x", "y", "z"]
+            .into_iter()
+            .chain(channels.iter().map(|ch| ch.name))
+            .enumerate()
+            .map(|(i, name)| PointFieldView {
+                name,
+                offset: 4 * i as u32,
+                datatype: point_field::FLOAT32,
+                count: 1,
+            })
+            .collect();
+        let point_step = 4 * fields.len();
+        let mut data = Vec::with_capacity(point_step * points.len());
+        for (i, p) in points.iter().enumerate() {
+            for v in [p.x, p.y, p.z] {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+            for ch in &channels {
+                data.extend_from_slice(&ch.values[i].to_le_bytes());
+            }
+        }
+        let is_dense = points
+            .iter()
+            .all(|p| p.x.is_finite() && p.y.is_finite() && p.z.is_finite());
+        Ok(PointCloud2::builder()
+            .stamp(self.stamp())
+            .frame_id(self.frame_id())
+            .height(1)
+            .width(points.len() as u32)
+            .fields(&fields)
+            .point_step(point_step as u32)
+            .row_step(data.len() as u32)
+            .data(&data)
+            .is_dense(is_dense)
+            .build()?)
+    }
+}
+
+impl PointCloud<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `PointCloudBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> PointCloudBuilder<'a> {
+        PointCloudBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]>> PointCloud2<B> {
+    /// The cloud as a legacy `PointCloud` with the same stamp and
+    /// frame_id, for consumers that only read the old type: `x`, `y`, `z`
+    /// become the points, in row-major order, and every other field a
+    /// channel holding its first element as `f32`.
+    ///
+    /// # Errors
+    ///
+    /// [`PointCloudError::FieldNotFound`](pointcloud::PointCloudError::FieldNotFound)
+    /// if the cloud lacks `x`, `y` or `z`,
+    /// [`PointCloudError::Cdr`](pointcloud::PointCloudError::Cdr) if the
+    /// result exceeds the current [`DecodeLimits`](crate::cdr::DecodeLimits),
+    /// and any error of [`as_dyn_cloud`](Self::as_dyn_cloud).
+    pub fn to_point_cloud(&self) -> Result<PointCloud<Vec<u8>>, pointcloud::PointCloudError> {
+        let cloud = self.as_dyn_cloud()?;
+        let gather = |name: &'static str| {
+            cloud
+                .gather_as_f32(name)
+                .ok_or(pointcloud::PointCloudError::FieldNotFound { name })
+        };
+        let (xs, ys, zs) = (gather("x")?, gather("y")?, gather("z")?);
+        let points: Vec<Point32> = xs
+            .iter()
+            .zip(&ys)
+            .zip(&zs)
+            .map(|((&x, &y), &z)| Point32 { x, y, z })
+            .collect();
+        let values: Vec<(&str, Vec<f32>)> = cloud
+            .fields()
+            .filter(|f| !matches!(f.name, "x" | "y" | "z"))
+            .filter_map(|f| Some((f.name, cloud.gather_as_f32(f.name)?)))
+            .collect();
+        let channels: Vec<ChannelFloat32View<'_>> = values
+            .iter()
+            .map(|(name, values)| ChannelFloat32View {
+                name,
+                values: alloc::borrow::Cow::Borrowed(values),
+            })
+            .collect();
+        Ok(PointCloud::builder()
+            .stamp(self.stamp())
+            .frame_id(self.frame_id())
+            .points(&points)
+            .channels(&channels)
+            .build()?)
+    }
+}
+
+// ── PointCloudBuilder<'a> ───────────────────────────────────────────
+
+/// Builder for `PointCloud<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `points` and `channels` are borrowed for zero-copy input; each channel
+/// should hold one value per point.
+pub struct PointCloudBuilder<'a> {
+    stamp: Time,
+    frame_id: alloc::borrow::Cow<'a, str>,
+    points: &'a [Point32],
+    channels: &'a [ChannelFloat32View<'a>],
+}
+
+impl<'a> Default for PointCloudBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: alloc::borrow::Cow::Borrowed(""),
+            points: &[],
+            channels: &[],
+        }
+    }
+}
+
+impl<'a> PointCloudBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn points(&mut self, v: &'a [Point32]) -> &mut Self {
+        self.points = v;
+        self
+    }
+    pub fn channels(&mut self, v: &'a [ChannelFloat32View<'a>]) -> &mut Self {
+        self.channels = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_u32();
+        for _ in self.points {
+            Point32::size_cdr(&mut s);
+        }
+        s.size_u32();
+        for ch in self.channels {
+            size_channel_element(&mut s, ch);
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_u32(self.points.len() as u32);
+        for p in self.points {
+            p.write_cdr(&mut w);
+        }
+        w.write_u32(self.channels.len() as u32);
+        for ch in self.channels {
+            write_channel_element(&mut w, ch);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<PointCloud<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        PointCloud::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> PointCloud<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── Registry ────────────────────────────────────────────────────────
+
+/// Check if a type name is supported by this module.
+pub fn is_type_supported(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "BatteryState"
+            | "CameraInfo"
+            | "ChannelFloat32"
+            | "CompressedImage"
+            | "FluidPressure"
+            | "Image"
+            | "Imu"
+            | "Joy"
+            | "LaserEcho"
+            | "LaserScan"
+            | "MagneticField"
+            | "MultiEchoLaserScan"
+            | "NavSatFix"
+            | "NavSatStatus"
+            | "PointCloud"
+            | "PointCloud2"
+            | "PointField"
+            | "RegionOfInterest"
+            | "Temperature"
+    )
+}
+
+/// List all type schema names in this module.
+pub fn list_types() -> &'static [&'static str] {
+    &[
+        "sensor_msgs/msg/BatteryState",
+        "sensor_msgs/msg/CameraInfo",
+        "sensor_msgs/msg/ChannelFloat32",
+        "sensor_msgs/msg/CompressedImage",
+        "sensor_msgs/msg/FluidPressure",
+        "sensor_msgs/msg/Image",
+        "sensor_msgs/msg/Imu",
+        "sensor_msgs/msg/Joy",
+        "sensor_msgs/msg/LaserEcho",
+        "sensor_msgs/msg/LaserScan",
+        "sensor_msgs/msg/MagneticField",
+        "sensor_msgs/msg/MultiEchoLaserScan",
+        "sensor_msgs/msg/NavSatFix",
+        "sensor_msgs/msg/NavSatStatus",
+        "sensor_msgs/msg/PointCloud",
+        "sensor_msgs/msg/PointCloud2",
+        "sensor_msgs/msg/PointField",
+        "sensor_msgs/msg/RegionOfInterest",
+        "sensor_msgs/msg/Temperature",
+    ]
+}
+
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "BatteryState" => {
+            "\
+std_msgs/Header header
+float32 voltage
+float32 temperature
+float32 current
+float32 charge
+float32 capacity
+float32 design_capacity
+float32 percentage
+uint8 power_supply_status
+uint8 power_supply_health
+uint8 power_supply_technology
+bool present
+float32[] cell_voltage
+float32[] cell_temperature
+string location
+string serial_number
+"
+        }
+        "CameraInfo" => {
+            "\
+std_msgs/Header header
+uint32 height
+uint32 width
+string distortion_model
+float64[] d
+float64[9] k
+float64[9] r
+float64[12] p
+uint32 binning_x
+uint32 binning_y
+RegionOfInterest roi
+"
+        }
+        "ChannelFloat32" => "string name\nfloat32[] values\n",
+        "CompressedImage" => {
+            "\
+std_msgs/Header header
+string format
+uint8[] data
+"
+        }
+        "FluidPressure" => {
+            "\
+std_msgs/Header header
+float64 fluid_pressure
+float64 variance
+"
+        }
+        "Image" => {
+            "\
+std_msgs/Header header
+uint32 height
+uint32 width
+string encoding
+uint8 is_bigendian
+uint32 step
+uint8[] data
+"
+        }
+        "Imu" => {
+            "\
+std_msgs/Header header
+geometry_msgs/Quaternion orientation
+float64[9] orientation_covariance
+geometry_msgs/Vector3 angular_velocity
+float64[9] angular_velocity_covariance
+geometry_msgs/Vector3 linear_acceleration
+float64[9] linear_acceleration_covariance
+"
+        }
+        "Joy" => {
+            "\
+std_msgs/Header header
+float32[] axes
+int32[] buttons
+"
+        }
+        "LaserEcho" => "float32[] echoes\n",
+        "LaserScan" => {
+            "\
+std_msgs/Header header
+float32 angle_min
+float32 angle_max
+float32 angle_increment
+float32 time_increment
+float32 scan_time
+float32 range_min
+float32 range_max
+float32[] ranges
+float32[] intensities
+"
+        }
+        "MagneticField" => {
+            "\
+std_msgs/Header header
+geometry_msgs/Vector3 magnetic_field
+float64[9] magnetic_field_covariance
+"
+        }
+        "MultiEchoLaserScan" => {
+            "\
+std_msgs/Header header
+float32 angle_min
+float32 angle_max
+float32 angle_increment
+float32 time_increment
+float32 scan_time
+float32 range_min
+float32 range_max
+LaserEcho[] ranges
+LaserEcho[] intensities
+"
+        }
+        "NavSatFix" => {
+            "\
+std_msgs/Header header
+NavSatStatus status
+float64 latitude
+float64 longitude
+float64 altitude
+float64[9] position_covariance
+uint8 position_covariance_type
+"
+        }
+        "NavSatStatus" => "int8 status\nuint16 service\n",
+        "PointCloud" => {
+            "\
+std_msgs/Header header
+geometry_msgs/Point32[] points
+ChannelFloat32[] channels
+"
+        }
+        "PointCloud2" => {
+            "\
+std_msgs/Header header
+uint32 height
+uint32 width
+PointField[] fields
+bool is_bigendian
+uint32 point_step
+uint32 row_step
+uint8[] data
+bool is_dense
+"
+        }
+        "PointField" => {
+            "\
+string name
+uint32 offset
+uint8 datatype
+uint32 count
+"
+        }
+        "RegionOfInterest" => {
+            "\
+uint32 x_offset
+uint32 y_offset
+uint32 height
+uint32 width
+bool do_rectify
+"
+        }
+        "Temperature" => {
+            "\
+std_msgs/Header header
+float64 temperature
+float64 variance
+"
+        }
+        _ => return None,
+    })
+}
+
+// CdrEncode implementations
+crate::cdr::impl_cdr_encode!(
+    CompressedImageBuilder,
+    ImageBuilder,
+    ImuBuilder,
+    NavSatFixBuilder,
+    PointFieldBuilder,
+    PointCloud2Builder,
+    CameraInfoBuilder,
+    MagneticFieldBuilder,
+    FluidPressureBuilder,
+    TemperatureBuilder,
+    BatteryStateBuilder,
+    JoyBuilder,
+    ChannelFloat32Builder,
+    PointCloudBuilder,
+    LaserEchoBuilder,
+    MultiEchoLaserScanBuilder,
+);
+
+// HeapSize implementations
+crate::heap_size::impl_heap_size!(
+    CompressedImage,
+    Image,
+    Imu,
+    NavSatFix,
+    PointField,
+    PointCloud2,
+    CameraInfo,
+    MagneticField,
+    FluidPressure,
+    Temperature,
+    BatteryState,
+    LaserScan,
+    Joy,
+    ChannelFloat32,
+    PointCloud,
+    LaserEcho,
+    MultiEchoLaserScan,
+);
+
+// SchemaType implementations
+use crate::schema_registry::SchemaType;
+
+impl SchemaType for NavSatStatus {
+    const SCHEMA_NAME: &'static str = "sensor_msgs/msg/NavSatStatus";
+}
+
+impl SchemaType for RegionOfInterest {
+    const SCHEMA_NAME: &'static str = "sensor_msgs/msg/RegionOfInterest";
+}
+
+#[cfg(test)]
+#[allow(deprecated)] // Tests exercise Image::new / PointCloud2::new, which are deprecated in 3.2.0 but still supported until 4.0.
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+    use crate::cdr::{decode_fixed, encode_fixed};
+    use crate::geometry_msgs::{Point32, Quaternion, Vector3};
+
+    #[test]
+    fn compressed_image_roundtrip() {
+        let img = CompressedImage::new(
+            Time::new(100, 500_000_000),
+            "camera",
+            "jpeg",
+            &[0xFF, 0xD8, 0xFF],
+        )
+        .unwrap();
+        assert_eq!(img.stamp(), Time::new(100, 500_000_000));
+        assert_eq!(img.frame_id(), "camera");
+        assert_eq!(img.format(), "jpeg");
+        assert_eq!(img.data(), &[0xFF, 0xD8, 0xFF]);
+
+        let bytes = img.to_cdr();
+        let decoded = CompressedImage::from_cdr(bytes).unwrap();
+        assert_eq!(decoded.format(), "jpeg");
+        assert_eq!(decoded.data(), &[0xFF, 0xD8, 0xFF]);
+    }
+
+    #[test]
+    fn image_roundtrip() {
+        let data = vec![128u8; 1920 * 480];
+        let img = Image::new(
+            Time::new(100, 500_000_000),
+            "camera_optical",
+            480,
+            640,
+            "rgb8",
+            0,
+            1920,
+            &data,
+        )
+        .unwrap();
+        assert_eq!(img.height(), 480);
+        assert_eq!(img.width(), 640);
+        assert_eq!(img.encoding(), "rgb8");
+        assert_eq!(img.is_bigendian(), 0);
+        assert_eq!(img.step(), 1920);
+        assert_eq!(img.data().len(), 1920 * 480);
+
+        let bytes = img.to_cdr();
+        let decoded = Image::from_cdr(bytes).unwrap();
+        assert_eq!(decoded.height(), 480);
+        assert_eq!(decoded.width(), 640);
+    }
+
+    #[test]
+    fn laser_scan_roundtrip() {
+        let ranges = [1.0f32, f32::INFINITY, 2.5];
+        let intensities = [10.0f32, 0.0, 30.0];
+        let scan = LaserScan::builder()
+            .stamp(Time::new(5, 6))
+            .frame_id("laser")
+            .angle_min(-0.5)
+            .angle_max(0.5)
+            .angle_increment(0.5)
+            .range_max(30.0)
+            .ranges(&ranges)
+            .intensities(&intensities)
+            .build()
+            .unwrap();
+        let decoded = LaserScan::from_cdr(scan.to_cdr()).unwrap();
+        assert_eq!(decoded.stamp(), Time::new(5, 6));
+        assert_eq!(decoded.frame_id(), "laser");
+        assert_eq!(decoded.angle_min(), -0.5);
+        assert_eq!(decoded.range_max(), 30.0);
+        assert_eq!(decoded.ranges_len(), 3);
+        assert_eq!(decoded.ranges(), ranges);
+        assert_eq!(decoded.intensities(), intensities);
+
+        let cdr = scan.to_cdr();
+        assert!(LaserScan::from_cdr(&cdr[..cdr.len() - 2]).is_err());
+    }
+
+    #[test]
+    fn multi_echo_laser_scan_flattens() {
+        let ranges: [&[f32]; 3] = [&[1.0, 4.0], &[], &[2.5]];
+        let intensities: [&[f32]; 3] = [&[10.0, 40.0], &[], &[30.0]];
+        let scan = MultiEchoLaserScan::builder()
+            .stamp(Time::new(5, 6))
+            .frame_id("laser")
+            .angle_min(-0.5)
+            .angle_max(0.5)
+            .angle_increment(0.5)
+            .range_max(30.0)
+            .ranges(&ranges)
+            .intensities(&intensities)
+            .build()
+            .unwrap();
+        let decoded = MultiEchoLaserScan::from_cdr(scan.to_cdr()).unwrap();
+        assert_eq!(decoded.frame_id(), "laser");
+        assert_eq!(decoded.angle_increment(), 0.5);
+        assert_eq!(decoded.ranges_len(), 3);
+        assert_eq!(decoded.ranges(), ranges.map(<[f32]>::to_vec));
+        assert_eq!(decoded.intensities(), intensities.map(<[f32]>::to_vec));
+
+        let first = decoded.to_laser_scan(EchoSelection::First).unwrap();
+        assert_eq!(first.stamp(), Time::new(5, 6));
+        assert_eq!(first.range_max(), 30.0);
+        let r = first.ranges();
+        assert_eq!((r[0], r[2]), (1.0, 2.5));
+        assert!(r[1].is_nan());
+        assert_eq!(first.intensities(), [10.0, 0.0, 30.0]);
+        let strongest = decoded.to_laser_scan(EchoSelection::Strongest).unwrap();
+        assert_eq!(strongest.ranges()[0], 4.0);
+        assert_eq!(strongest.intensities()[0], 40.0);
+        #[cfg(feature = "std")]
+        {
+            let tight = crate::cdr::DecodeLimits {
+                max_string_len: 4,
+                ..crate::cdr::DecodeLimits::DEFAULT
+            };
+            let result = tight.scoped(|| decoded.to_laser_scan(EchoSelection::First));
+            assert!(matches!(
+                result.map(|_| ()).unwrap_err().root(),
+                CdrError::LimitExceeded { .. }
+            ));
+        }
+
+        let echo = LaserEcho::builder().echoes(&[1.5, 3.0]).build().unwrap();
+        let echo = LaserEcho::from_cdr(echo.to_cdr()).unwrap();
+        assert_eq!(echo.echoes(), [1.5, 3.0]);
+
+        let cdr = scan.to_cdr();
+        assert!(MultiEchoLaserScan::from_cdr(&cdr[..cdr.len() - 2]).is_err());
+    }
+
+    #[test]
+    fn joy_roundtrip() {
+        let axes = [0.0f32, -1.0, 0.25];
+        let buttons = [1i32, 0, 0, 1];
+        let joy = Joy::builder()
+            .stamp(Time::new(7, 8))
+            .frame_id("gamepad")
+            .axes(&axes)
+            .buttons(&buttons)
+            .build()
+            .unwrap();
+        let decoded = Joy::from_cdr(joy.to_cdr()).unwrap();
+        assert_eq!(decoded.stamp(), Time::new(7, 8));
+        assert_eq!(decoded.frame_id(), "gamepad");
+        assert_eq!(decoded.axes_len(), 3);
+        assert_eq!(decoded.axes(), axes);
+        assert_eq!(decoded.buttons_len(), 4);
+        assert_eq!(decoded.buttons(), buttons);
+
+        let cdr = joy.to_cdr();
+        assert!(Joy::from_cdr(&cdr[..cdr.len() - 2]).is_err());
+    }
+
+    #[test]
+    fn point_cloud_roundtrip_and_pointcloud2_conversion() {
+        let points = [
+            Point32 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            Point32 {
+                x: -4.0,
+                y: 5.5,
+                z: 0.0,
+            },
+        ];
+        let intensity = [10.0f32, 20.0];
+        let channels = [ChannelFloat32View {
+            name: "intensity",
+            values: alloc::borrow::Cow::Borrowed(&intensity),
+        }];
+        let cloud = PointCloud::builder()
+            .stamp(Time::new(3, 4))
+            .frame_id("radar")
+            .points(&points)
+            .channels(&channels)
+            .build()
+            .unwrap();
+        let decoded = PointCloud::from_cdr(cloud.to_cdr()).unwrap();
+        assert_eq!(decoded.frame_id(), "radar");
+        assert_eq!(decoded.points(), points);
+        assert_eq!(decoded.channels(), channels);
+        assert_eq!(decoded.channel("intensity").unwrap().values[1], 20.0);
+        assert!(decoded.channel("range").is_none());
+
+        let pc2 = decoded.to_pointcloud2().unwrap();
+        assert_eq!(pc2.stamp(), Time::new(3, 4));
+        assert_eq!((pc2.height(), pc2.width(), pc2.point_step()), (1, 2, 16));
+        assert!(pc2.is_dense());
+        let back = pc2.to_point_cloud().unwrap();
+        assert_eq!(back.frame_id(), "radar");
+        assert_eq!(back.points(), points);
+        assert_eq!(back.channels(), channels);
+
+        // An output over the current limits is an error, not a panic.
+        #[cfg(feature = "std")]
+        {
+            let tight = crate::cdr::DecodeLimits {
+                max_sequence_len: 8,
+                ..crate::cdr::DecodeLimits::DEFAULT
+            };
+            match tight.scoped(|| decoded.to_pointcloud2()) {
+                Err(pointcloud::PointCloudError::Cdr(e)) => {
+                    assert!(matches!(e.root(), CdrError::LimitExceeded { .. }))
+                }
+                other => panic!("expected a limit error, got {:?}", other.map(|_| ())),
+            }
+        }
+
+        let short = [ChannelFloat32View {
+            name: "intensity",
+            values: alloc::borrow::Cow::Borrowed(&intensity[..1]),
+        }];
+        let ragged = PointCloud::builder()
+            .points(&points)
+            .channels(&short)
+            .build()
+            .unwrap();
+        assert!(ragged.to_pointcloud2().is_err());
+
+        let channel = ChannelFloat32::builder()
+            .name("intensity")
+            .values(&intensity)
+            .build()
+            .unwrap();
+        let channel = ChannelFloat32::from_cdr(channel.to_cdr()).unwrap();
+        assert_eq!(channel.name(), "intensity");
+        assert_eq!(channel.values(), intensity);
     }
 
     #[test]
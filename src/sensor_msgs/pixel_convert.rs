@@ -0,0 +1,365 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Pluggable pixel-format conversion for [`Image`] messages.
+//!
+//! The [`PixelConvert`] trait is the extension point: this crate ships
+//! [`SoftwareConvert`], a portable CPU implementation, and platform crates
+//! (i.MX G2D, VPU, …) register hardware-accelerated converters with
+//! [`register_converter`]. [`converter_for`] picks the most recently
+//! registered converter that supports a given conversion and falls back to
+//! the software path, so services only ever call [`convert_image`].
+//!
+//! Encoding names follow `sensor_msgs/image_encodings` (`rgb8`, `bgr8`,
+//! `rgba8`, `bgra8`, `mono8`, `yuv422_yuy2`).
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use super::Image;
+use crate::cdr::CdrError;
+
+/// `sensor_msgs/image_encodings` names understood by [`SoftwareConvert`].
+pub mod encodings {
+    pub const RGB8: &str = "rgb8";
+    pub const BGR8: &str = "bgr8";
+    pub const RGBA8: &str = "rgba8";
+    pub const BGRA8: &str = "bgra8";
+    pub const MONO8: &str = "mono8";
+    pub const YUV422_YUY2: &str = "yuv422_yuy2";
+}
+
+// ── Error ───────────────────────────────────────────────────────────
+
+/// Errors that can occur during pixel conversion.
+#[derive(Debug)]
+pub enum ConvertError {
+    /// No registered converter handles this encoding pair.
+    Unsupported { from: String, to: String },
+    /// Source dimensions, step or data length are inconsistent.
+    InvalidFrame,
+    /// Re-encoding the converted image failed.
+    Cdr(CdrError),
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::Unsupported { from, to } => {
+                write!(f, "unsupported pixel conversion: {} -> {}", from, to)
+            }
+            ConvertError::InvalidFrame => write!(f, "image dimensions do not match its data"),
+            ConvertError::Cdr(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ConvertError {}
+
+impl From<CdrError> for ConvertError {
+    fn from(e: CdrError) -> Self {
+        ConvertError::Cdr(e)
+    }
+}
+
+// ── PixelConvert ────────────────────────────────────────────────────
+
+/// Borrowed view of raw pixel data, independent of any message buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct PixelFrame<'a> {
+    pub encoding: &'a str,
+    pub width: u32,
+    pub height: u32,
+    /// Row stride in bytes.
+    pub step: u32,
+    pub data: &'a [u8],
+}
+
+/// A pixel-format converter.
+///
+/// Implementations must be thread-safe: one instance is shared by every
+/// caller of [`converter_for`].
+pub trait PixelConvert: Send + Sync {
+    /// Short identifier for logging (e.g. `"software"`, `"g2d"`).
+    fn name(&self) -> &str;
+
+    /// Whether this converter handles `from` → `to`.
+    fn supports(&self, from: &str, to: &str) -> bool;
+
+    /// Convert `src` to the `to` encoding, writing tightly packed rows into
+    /// `out` (resized as needed, allocation reused). Returns the output
+    /// row stride in bytes.
+    fn convert(
+        &self,
+        src: &PixelFrame<'_>,
+        to: &str,
+        out: &mut Vec<u8>,
+    ) -> Result<u32, ConvertError>;
+}
+
+// ── SoftwareConvert ─────────────────────────────────────────────────
+
+/// Portable CPU converter between the [`encodings`] formats.
+///
+/// `yuv422_yuy2` is supported as a source only (BT.601 limited range).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SoftwareConvert;
+
+fn bytes_per_pixel(encoding: &str) -> Option<usize> {
+    match encoding {
+        encodings::MONO8 => Some(1),
+        encodings::YUV422_YUY2 => Some(2),
+        encodings::RGB8 | encodings::BGR8 => Some(3),
+        encodings::RGBA8 | encodings::BGRA8 => Some(4),
+        _ => None,
+    }
+}
+
+fn store(encoding: &str, [r, g, b, a]: [u8; 4], out: &mut [u8]) {
+    match encoding {
+        encodings::RGB8 => out.copy_from_slice(&[r, g, b]),
+        encodings::BGR8 => out.copy_from_slice(&[b, g, r]),
+        encodings::RGBA8 => out.copy_from_slice(&[r, g, b, a]),
+        encodings::BGRA8 => out.copy_from_slice(&[b, g, r, a]),
+        // ITU-R BT.601 luma, integer approximation.
+        _ => out[0] = ((77 * r as u32 + 150 * g as u32 + 29 * b as u32) >> 8) as u8,
+    }
+}
+
+fn load(encoding: &str, px: &[u8]) -> [u8; 4] {
+    match encoding {
+        encodings::RGB8 => [px[0], px[1], px[2], 255],
+        encodings::BGR8 => [px[2], px[1], px[0], 255],
+        encodings::RGBA8 => [px[0], px[1], px[2], px[3]],
+        encodings::BGRA8 => [px[2], px[1], px[0], px[3]],
+        _ => [px[0], px[0], px[0], 255],
+    }
+}
+
+fn yuv_to_rgba(y: u8, u: u8, v: u8) -> [u8; 4] {
+    let c = (y as i32 - 16) * 298;
+    let d = u as i32 - 128;
+    let e = v as i32 - 128;
+    let clamp = |x: i32| ((x + 128) >> 8).clamp(0, 255) as u8;
+    [
+        clamp(c + 409 * e),
+        clamp(c - 100 * d - 208 * e),
+        clamp(c + 516 * d),
+        255,
+    ]
+}
+
+impl PixelConvert for SoftwareConvert {
+    fn name(&self) -> &str {
+        "software"
+    }
+
+    fn supports(&self, from: &str, to: &str) -> bool {
+        bytes_per_pixel(from).is_some()
+            && bytes_per_pixel(to).is_some()
+            && to != encodings::YUV422_YUY2
+    }
+
+    fn convert(
+        &self,
+        src: &PixelFrame<'_>,
+        to: &str,
+        out: &mut Vec<u8>,
+    ) -> Result<u32, ConvertError> {
+        if !self.supports(src.encoding, to) {
+            return Err(ConvertError::Unsupported {
+                from: src.encoding.to_owned(),
+                to: to.to_owned(),
+            });
+        }
+        let (w, h) = (src.width as usize, src.height as usize);
+        let in_bpp = bytes_per_pixel(src.encoding).unwrap_or(0);
+        let out_bpp = bytes_per_pixel(to).unwrap_or(0);
+        let step = src.step as usize;
+        let yuyv = src.encoding == encodings::YUV422_YUY2;
+        if step < w * in_bpp || (yuyv && w % 2 != 0) || src.data.len() < step * h {
+            return Err(ConvertError::InvalidFrame);
+        }
+
+        let out_step = w * out_bpp;
+        out.clear();
+        if w == 0 || h == 0 {
+            return Ok(out_step as u32);
+        }
+        out.resize(out_step * h, 0);
+        for (row_in, row_out) in src
+            .data
+            .chunks(step)
+            .zip(out.chunks_exact_mut(out_step))
+            .take(h)
+        {
+            if yuyv {
+                for (pair, dst) in row_in[..w * 2]
+                    .chunks_exact(4)
+                    .zip(row_out.chunks_exact_mut(out_bpp * 2))
+                {
+                    let (y0, u, y1, v) = (pair[0], pair[1], pair[2], pair[3]);
+                    store(to, yuv_to_rgba(y0, u, v), &mut dst[..out_bpp]);
+                    store(to, yuv_to_rgba(y1, u, v), &mut dst[out_bpp..]);
+                }
+            } else {
+                for (px, dst) in row_in[..w * in_bpp]
+                    .chunks_exact(in_bpp)
+                    .zip(row_out.chunks_exact_mut(out_bpp))
+                {
+                    store(to, load(src.encoding, px), dst);
+                }
+            }
+        }
+        Ok(out_step as u32)
+    }
+}
+
+// ── Registry ────────────────────────────────────────────────────────
+
+static CONVERTERS: RwLock<Vec<Arc<dyn PixelConvert>>> = RwLock::new(Vec::new());
+
+/// Register a converter. Later registrations take precedence over earlier
+/// ones and over [`SoftwareConvert`].
+pub fn register_converter(converter: Arc<dyn PixelConvert>) {
+    CONVERTERS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(converter);
+}
+
+/// Pick the converter for `from` → `to`: the most recently registered one
+/// that supports it, else [`SoftwareConvert`] if it does, else `None`.
+pub fn converter_for(from: &str, to: &str) -> Option<Arc<dyn PixelConvert>> {
+    let registered = CONVERTERS.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(c) = registered.iter().rev().find(|c| c.supports(from, to)) {
+        return Some(Arc::clone(c));
+    }
+    SoftwareConvert
+        .supports(from, to)
+        .then(|| Arc::new(SoftwareConvert) as Arc<dyn PixelConvert>)
+}
+
+/// Convert `img` to the `to` encoding using [`converter_for`], producing a
+/// new `Image` with the same header and dimensions.
+///
+/// `scratch` holds the converted pixels and is reused across calls.
+pub fn convert_image<B: AsRef<[u8]>>(
+    img: &Image<B>,
+    to: &str,
+    scratch: &mut Vec<u8>,
+) -> Result<Image<Vec<u8>>, ConvertError> {
+    let from = img.encoding();
+    let converter = converter_for(from, to).ok_or_else(|| ConvertError::Unsupported {
+        from: from.to_owned(),
+        to: to.to_owned(),
+    })?;
+    let frame = PixelFrame {
+        encoding: from,
+        width: img.width(),
+        height: img.height(),
+        step: img.step(),
+        data: img.data(),
+    };
+    let step = converter.convert(&frame, to, scratch)?;
+    Ok(Image::builder()
+        .stamp(img.stamp())
+        .frame_id(img.frame_id())
+        .height(img.height())
+        .width(img.width())
+        .encoding(to)
+        .step(step)
+        .data(scratch)
+        .build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_interfaces::Time;
+
+    fn image(encoding: &str, w: u32, h: u32, bpp: u32, data: &[u8]) -> Image<Vec<u8>> {
+        Image::builder()
+            .stamp(Time::new(5, 6))
+            .frame_id("cam")
+            .width(w)
+            .height(h)
+            .encoding(encoding)
+            .step(w * bpp)
+            .data(data)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn rgb_to_bgr_and_back() {
+        let src = image("rgb8", 2, 1, 3, &[1, 2, 3, 4, 5, 6]);
+        let mut scratch = Vec::new();
+        let bgr = convert_image(&src, "bgr8", &mut scratch).unwrap();
+        assert_eq!(bgr.data(), &[3, 2, 1, 6, 5, 4]);
+        assert_eq!(bgr.step(), 6);
+        assert_eq!(bgr.frame_id(), "cam");
+        let rgb = convert_image(&bgr, "rgb8", &mut scratch).unwrap();
+        assert_eq!(rgb.data(), src.data());
+    }
+
+    #[test]
+    fn padded_rows_are_packed() {
+        // 1x2 mono8 with a 4-byte stride
+        let src = image("mono8", 1, 2, 4, &[10, 0, 0, 0, 20, 0, 0, 0]);
+        let mut scratch = Vec::new();
+        let rgba = convert_image(&src, "rgba8", &mut scratch).unwrap();
+        assert_eq!(rgba.data(), &[10, 10, 10, 255, 20, 20, 20, 255]);
+    }
+
+    #[test]
+    fn yuyv_grey_decodes_to_grey() {
+        let src = image("yuv422_yuy2", 2, 1, 2, &[126, 128, 126, 128]);
+        let mut scratch = Vec::new();
+        let rgb = convert_image(&src, "rgb8", &mut scratch).unwrap();
+        assert!(rgb.data().iter().all(|&c| c == 128));
+    }
+
+    #[test]
+    fn unsupported_and_invalid() {
+        let mut scratch = Vec::new();
+        let src = image("rgb8", 2, 1, 3, &[0; 6]);
+        assert!(matches!(
+            convert_image(&src, "yuv422_yuy2", &mut scratch),
+            Err(ConvertError::Unsupported { .. })
+        ));
+        let short = image("rgb8", 2, 2, 3, &[0; 6]);
+        assert!(matches!(
+            convert_image(&short, "bgr8", &mut scratch),
+            Err(ConvertError::InvalidFrame)
+        ));
+    }
+
+    struct Fake;
+    impl PixelConvert for Fake {
+        fn name(&self) -> &str {
+            "fake"
+        }
+        fn supports(&self, from: &str, to: &str) -> bool {
+            from == "fake_in" && to == "rgb8"
+        }
+        fn convert(
+            &self,
+            src: &PixelFrame<'_>,
+            _to: &str,
+            out: &mut Vec<u8>,
+        ) -> Result<u32, ConvertError> {
+            out.clear();
+            out.resize((src.width * src.height * 3) as usize, 7);
+            Ok(src.width * 3)
+        }
+    }
+
+    #[test]
+    fn registered_converter_is_discovered() {
+        assert!(converter_for("fake_in", "rgb8").is_none());
+        register_converter(Arc::new(Fake));
+        assert_eq!(converter_for("fake_in", "rgb8").unwrap().name(), "fake");
+        assert_eq!(converter_for("rgb8", "bgr8").unwrap().name(), "software");
+    }
+}
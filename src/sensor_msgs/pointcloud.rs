@@ -17,6 +17,8 @@
 //! This is useful when the field's storage type varies across services.
 
 use super::PointFieldView;
+use crate::cdr::CdrError;
+use alloc::{string::String, string::ToString, vec::Vec};
 
 /// Maximum number of fields supported by [`DynPointCloud`].
 ///
@@ -173,6 +175,8 @@ pub enum PointCloudError {
     InvalidLayout { reason: &'static str },
     /// A field descriptor's byte offset is out of range for the point data.
     FieldAccessOutOfBounds { byte_offset: u32 },
+    /// Encoding a converted cloud failed.
+    Cdr(CdrError),
 }
 
 impl core::fmt::Display for PointCloudError {
@@ -194,11 +198,18 @@ impl core::fmt::Display for PointCloudError {
             Self::FieldAccessOutOfBounds { byte_offset } => {
                 write!(f, "field access out of bounds at byte offset {byte_offset}")
             }
+            Self::Cdr(e) => write!(f, "{e}"),
         }
     }
 }
 
-impl std::error::Error for PointCloudError {}
+impl core::error::Error for PointCloudError {}
+
+impl From<CdrError> for PointCloudError {
+    fn from(e: CdrError) -> Self {
+        PointCloudError::Cdr(e)
+    }
+}
 
 // ── PointScalar ─────────────────────────────────────────────────────
 
@@ -1402,6 +1413,90 @@ impl<P: Point> Iterator for PointIter<'_, P> {
 
 impl<P: Point> ExactSizeIterator for PointIter<'_, P> {}
 
+// ── ScanProjection ─────────────────────────────────────────────────
+
+/// Settings for flattening a cloud into a planar
+/// [`LaserScan`](super::LaserScan).
+///
+/// Points whose `z` lies within `[min_height, max_height]` are projected
+/// onto the XY plane and binned by bearing; each of the
+/// [`bins`](Self::bins) keeps its nearest range. Angles are in radians,
+/// counter-clockwise from +X, as in `sensor_msgs/LaserScan`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScanProjection {
+    pub min_height: f32,
+    pub max_height: f32,
+    pub angle_min: f32,
+    pub angle_max: f32,
+    pub angle_increment: f32,
+    pub range_min: f32,
+    pub range_max: f32,
+}
+
+#[cfg(feature = "std")]
+impl Default for ScanProjection {
+    /// A full turn at one-degree resolution with no height or range limit.
+    fn default() -> Self {
+        ScanProjection {
+            min_height: f32::MIN,
+            max_height: f32::MAX,
+            angle_min: -core::f32::consts::PI,
+            angle_max: core::f32::consts::PI,
+            angle_increment: core::f32::consts::PI / 180.0,
+            range_min: 0.0,
+            range_max: f32::MAX,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ScanProjection {
+    /// Number of range bins spanning `angle_min..=angle_max`.
+    pub fn bins(&self) -> usize {
+        if self.angle_increment <= 0.0 || self.angle_max < self.angle_min {
+            return 0;
+        }
+        ((self.angle_max - self.angle_min) / self.angle_increment) as usize + 1
+    }
+
+    /// Project the `x`, `y`, `z` fields of `cloud` into per-bin ranges.
+    ///
+    /// Bins no point fell into hold `f32::INFINITY` (no return), following
+    /// REP 117. Points with a non-finite coordinate are skipped.
+    ///
+    /// # Errors
+    ///
+    /// [`PointCloudError::FieldNotFound`] if the cloud lacks `x`, `y` or `z`.
+    pub fn project(&self, cloud: &DynPointCloud<'_>) -> Result<Vec<f32>, PointCloudError> {
+        let gather = |name: &'static str| {
+            cloud
+                .gather_as_f32(name)
+                .ok_or(PointCloudError::FieldNotFound { name })
+        };
+        let (xs, ys, zs) = (gather("x")?, gather("y")?, gather("z")?);
+        let mut ranges = alloc::vec![f32::INFINITY; self.bins()];
+        for ((&x, &y), &z) in xs.iter().zip(&ys).zip(&zs) {
+            if !(self.min_height..=self.max_height).contains(&z) {
+                continue;
+            }
+            let range = x.hypot(y);
+            if !(self.range_min..=self.range_max).contains(&range) {
+                continue;
+            }
+            let angle = y.atan2(x);
+            if !(self.angle_min..=self.angle_max).contains(&angle) {
+                continue;
+            }
+            let bin = ((angle - self.angle_min) / self.angle_increment) as usize;
+            if let Some(r) = ranges.get_mut(bin) {
+                *r = r.min(range);
+            }
+        }
+        Ok(ranges)
+    }
+}
+
 // ── Tests ───────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -1471,6 +1566,67 @@ mod tests {
         .unwrap()
     }
 
+    // ── ScanProjection tests ────────────────────────────────────────
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn scan_projection_bins_nearest_in_band() {
+        let pcd = make_test_cloud();
+        let cloud = DynPointCloud::from_pointcloud2(&pcd).unwrap();
+        let proj = ScanProjection {
+            min_height: 0.0,
+            max_height: 7.0,
+            ..ScanProjection::default()
+        };
+        assert_eq!(proj.bins(), 361);
+        let ranges = proj.project(&cloud).unwrap();
+        let bin = |x: f32, y: f32| ((y.atan2(x) - proj.angle_min) / proj.angle_increment) as usize;
+        assert!((ranges[bin(1.0, 2.0)] - 5f32.sqrt()).abs() < 1e-6);
+        assert!((ranges[bin(4.0, 5.0)] - 41f32.sqrt()).abs() < 1e-6);
+        // (7,8,9) and (10,11,12) are above the band.
+        assert_eq!(ranges.iter().filter(|r| r.is_finite()).count(), 2);
+
+        let scan = crate::sensor_msgs::LaserScan::builder()
+            .stamp(pcd.stamp())
+            .frame_id(pcd.frame_id())
+            .projection(&proj)
+            .ranges(&ranges)
+            .build()
+            .unwrap();
+        assert_eq!(scan.frame_id(), "lidar");
+        assert_eq!(scan.angle_increment(), proj.angle_increment);
+        assert_eq!(scan.ranges(), ranges);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn scan_projection_requires_xyz() {
+        let fields = [PointFieldView {
+            name: "x",
+            offset: 0,
+            datatype: 7,
+            count: 1,
+        }];
+        let pcd = PointCloud2::new(
+            Time::new(0, 0),
+            "f",
+            1,
+            1,
+            &fields,
+            false,
+            4,
+            4,
+            &[0; 4],
+            true,
+        )
+        .unwrap();
+        let cloud = DynPointCloud::from_pointcloud2(&pcd).unwrap();
+        assert!(matches!(
+            ScanProjection::default().project(&cloud),
+            Err(PointCloudError::FieldNotFound { name: "y" })
+        ));
+    }
+
     // ── DynPointCloud tests ─────────────────────────────────────────
 
     #[test]
@@ -2212,8 +2368,8 @@ mod tests {
         data[4..6].copy_from_slice(&1000u16.to_le_bytes()); // u16
         data[6..10].copy_from_slice(&(-100_000i32).to_le_bytes()); // i32
         data[10..14].copy_from_slice(&3_000_000u32.to_le_bytes()); // u32
-        data[14..18].copy_from_slice(&std::f32::consts::PI.to_le_bytes()); // f32
-        data[18..26].copy_from_slice(&std::f64::consts::E.to_le_bytes()); // f64
+        data[14..18].copy_from_slice(&core::f32::consts::PI.to_le_bytes()); // f32
+        data[18..26].copy_from_slice(&core::f64::consts::E.to_le_bytes()); // f64
 
         let pc = PointCloud2::new(
             Time::new(0, 0),
@@ -2250,7 +2406,7 @@ mod tests {
         assert_eq!(p.read_u8("u8_field"), Some(42));
         assert_eq!(p.read_u16("u16_field"), Some(1000));
         assert_eq!(p.read_u32("u32_field"), Some(3_000_000));
-        assert_eq!(p.read_f32("f32_field"), Some(std::f32::consts::PI));
+        assert_eq!(p.read_f32("f32_field"), Some(core::f32::consts::PI));
 
         // Verify descriptor-based access
         let u16_desc = cloud.field("u16_field").unwrap();
@@ -2757,8 +2913,8 @@ mod tests {
         data[4..6].copy_from_slice(&60000u16.to_le_bytes());
         data[6..10].copy_from_slice(&(-1_000_000i32).to_le_bytes());
         data[10..14].copy_from_slice(&4_000_000u32.to_le_bytes());
-        data[14..18].copy_from_slice(&std::f32::consts::E.to_le_bytes());
-        data[18..26].copy_from_slice(&std::f64::consts::PI.to_le_bytes());
+        data[14..18].copy_from_slice(&core::f32::consts::E.to_le_bytes());
+        data[18..26].copy_from_slice(&core::f64::consts::PI.to_le_bytes());
 
         let p = TestAllTypesPoint::read_from(&data, 0);
         assert_eq!(p.a, -2);
@@ -2767,8 +2923,8 @@ mod tests {
         assert_eq!(p.d, 60000);
         assert_eq!(p.e, -1_000_000);
         assert_eq!(p.f, 4_000_000);
-        assert_eq!(p.g, std::f32::consts::E);
-        assert_eq!(p.h, std::f64::consts::PI);
+        assert_eq!(p.g, core::f32::consts::E);
+        assert_eq!(p.h, core::f64::consts::PI);
     }
 
     // ── Coverage tests for signed/f64 accessors (PR #12 Comment 4) ──
@@ -2833,8 +2989,8 @@ mod tests {
         data[4..6].copy_from_slice(&1000u16.to_le_bytes());
         data[6..10].copy_from_slice(&(-100_000i32).to_le_bytes());
         data[10..14].copy_from_slice(&3_000_000u32.to_le_bytes());
-        data[14..18].copy_from_slice(&std::f32::consts::PI.to_le_bytes());
-        data[18..26].copy_from_slice(&std::f64::consts::E.to_le_bytes());
+        data[14..18].copy_from_slice(&core::f32::consts::PI.to_le_bytes());
+        data[18..26].copy_from_slice(&core::f64::consts::E.to_le_bytes());
 
         PointCloud2::new(
             Time::new(0, 0),
@@ -2862,7 +3018,7 @@ mod tests {
         assert_eq!(p.read_i8("i8_field"), Some(-2));
         assert_eq!(p.read_i16("i16_field"), Some(-300));
         assert_eq!(p.read_i32("i32_field"), Some(-100_000));
-        assert_eq!(p.read_f64("f64_field"), Some(std::f64::consts::E));
+        assert_eq!(p.read_f64("f64_field"), Some(core::f64::consts::E));
     }
 
     #[test]
@@ -2880,7 +3036,7 @@ mod tests {
         assert_eq!(p.read_i8_at(i8_desc).unwrap(), -2);
         assert_eq!(p.read_i16_at(i16_desc).unwrap(), -300);
         assert_eq!(p.read_i32_at(i32_desc).unwrap(), -100_000);
-        assert_eq!(p.read_f64_at(f64_desc).unwrap(), std::f64::consts::E);
+        assert_eq!(p.read_f64_at(f64_desc).unwrap(), core::f64::consts::E);
     }
 
     #[test]
@@ -2894,7 +3050,7 @@ mod tests {
         assert_eq!(cloud.gather_i32("i32_field"), Some(vec![-100_000]));
         assert_eq!(
             cloud.gather_f64("f64_field"),
-            Some(vec![std::f64::consts::E])
+            Some(vec![core::f64::consts::E])
         );
     }
 
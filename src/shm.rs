@@ -0,0 +1,446 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! POSIX shared-memory rings announced by [`ShmRing`] messages.
+//!
+//! [`ShmRingWriter`] creates a named shared-memory object holding
+//! `slot_count` fixed-size slots and copies each payload into the next slot;
+//! the returned sequence number goes into the `ShmRing` message published
+//! alongside. [`ShmRingReader`] maps the same object read-only and copies
+//! the announced slot out, reporting when the writer has already lapped it.
+//!
+//! The segment layout is described in `edgefirst_msgs/msg/ShmRing.msg`:
+//! a 64-byte control block, one `u64` seqlock stamp per slot, then the
+//! slots at the next 64-byte boundary.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use edgefirst_schemas::edgefirst_msgs::ShmRing;
+//! use edgefirst_schemas::shm::{ShmRingReader, ShmRingWriter};
+//!
+//! let mut writer = ShmRingWriter::create("/edgefirst.example", 4096, 4).unwrap();
+//! let seq = writer.write(b"frame bytes").unwrap();
+//! let msg = writer.announce(seq, 11).schema("").build().unwrap();
+//!
+//! let reader = ShmRingReader::open(msg.name()).unwrap();
+//! let mut out = Vec::new();
+//! assert!(reader.read(&msg, &mut out).unwrap());
+//! assert_eq!(out, b"frame bytes");
+//! ```
+
+use std::ffi::CString;
+use std::io;
+use std::sync::atomic::{fence, AtomicU32, AtomicU64, Ordering};
+
+use crate::edgefirst_msgs::{ShmRing, ShmRingBuilder};
+
+/// `"EFSR"` little-endian.
+pub const MAGIC: u32 = u32::from_le_bytes(*b"EFSR");
+/// Segment layout version written into the control block.
+pub const VERSION: u32 = 1;
+
+const CONTROL_SIZE: usize = 64;
+const OFF_MAGIC: usize = 0;
+const OFF_VERSION: usize = 4;
+const OFF_SLOT_SIZE: usize = 8;
+const OFF_SLOT_COUNT: usize = 12;
+const OFF_HEAD: usize = 16;
+
+/// Byte offset of the first slot for a ring of `slot_count` slots.
+pub const fn slots_offset(slot_count: u32) -> usize {
+    (CONTROL_SIZE + 8 * slot_count as usize + 63) & !63
+}
+
+/// Total segment size in bytes for the given geometry.
+pub const fn segment_size(slot_size: u32, slot_count: u32) -> usize {
+    slots_offset(slot_count) + slot_size as usize * slot_count as usize
+}
+
+/// A mapped shared-memory segment.
+struct Mapping {
+    ptr: *mut u8,
+    len: usize,
+}
+
+// The mapping is only accessed through atomics and seqlock-guarded copies.
+unsafe impl Send for Mapping {}
+unsafe impl Sync for Mapping {}
+
+impl Mapping {
+    fn map(fd: libc::c_int, len: usize, writable: bool) -> io::Result<Self> {
+        let prot = if writable {
+            libc::PROT_READ | libc::PROT_WRITE
+        } else {
+            libc::PROT_READ
+        };
+        // SAFETY: fd is a valid shared-memory descriptor of at least `len`
+        // bytes; the mapping is released in Drop.
+        let ptr = unsafe { libc::mmap(std::ptr::null_mut(), len, prot, libc::MAP_SHARED, fd, 0) };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Mapping {
+            ptr: ptr.cast(),
+            len,
+        })
+    }
+
+    fn u32_at(&self, off: usize) -> &AtomicU32 {
+        debug_assert!(off + 4 <= self.len && off.is_multiple_of(4));
+        // SAFETY: in bounds, aligned (mmap is page-aligned), and lives as
+        // long as `self`.
+        unsafe { &*(self.ptr.add(off) as *const AtomicU32) }
+    }
+
+    fn u64_at(&self, off: usize) -> &AtomicU64 {
+        debug_assert!(off + 8 <= self.len && off.is_multiple_of(8));
+        // SAFETY: as for `u32_at`.
+        unsafe { &*(self.ptr.add(off) as *const AtomicU64) }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // SAFETY: ptr/len come from a successful mmap.
+        unsafe {
+            libc::munmap(self.ptr.cast(), self.len);
+        }
+    }
+}
+
+/// Geometry shared by reader and writer.
+struct Ring {
+    map: Mapping,
+    name: String,
+    slot_size: u32,
+    slot_count: u32,
+}
+
+impl Ring {
+    fn stamp(&self, slot: u32) -> &AtomicU64 {
+        self.map.u64_at(CONTROL_SIZE + 8 * slot as usize)
+    }
+
+    fn head(&self) -> &AtomicU64 {
+        self.map.u64_at(OFF_HEAD)
+    }
+
+    fn slot_ptr(&self, slot: u32) -> *mut u8 {
+        let off = slots_offset(self.slot_count) + slot as usize * self.slot_size as usize;
+        // SAFETY: slot < slot_count, so the slot lies inside the mapping.
+        unsafe { self.map.ptr.add(off) }
+    }
+
+    fn slot_of(&self, seq: u64) -> u32 {
+        (seq % u64::from(self.slot_count)) as u32
+    }
+}
+
+fn c_name(name: &str) -> io::Result<CString> {
+    CString::new(name).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "NUL in shm name"))
+}
+
+fn open_fd(name: &CString, flags: libc::c_int) -> io::Result<libc::c_int> {
+    // SAFETY: name is a valid NUL-terminated string.
+    let fd = unsafe { libc::shm_open(name.as_ptr(), flags, 0o600 as libc::mode_t) };
+    if fd < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(fd)
+    }
+}
+
+fn close_fd(fd: libc::c_int) {
+    // SAFETY: fd was returned by shm_open and is closed exactly once.
+    unsafe {
+        libc::close(fd);
+    }
+}
+
+/// Remove the named shared-memory object. Existing mappings stay valid.
+pub fn unlink(name: &str) -> io::Result<()> {
+    let cname = c_name(name)?;
+    // SAFETY: cname is a valid NUL-terminated string.
+    if unsafe { libc::shm_unlink(cname.as_ptr()) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+// ── ShmRingWriter ───────────────────────────────────────────────────
+
+/// Owner of a shared-memory ring. The object is unlinked on drop.
+pub struct ShmRingWriter {
+    ring: Ring,
+    head: u64,
+}
+
+impl ShmRingWriter {
+    /// Create the named ring, failing if the object already exists.
+    ///
+    /// `name` follows `shm_open` rules: a leading `/` and no further
+    /// slashes.
+    pub fn create(name: &str, slot_size: u32, slot_count: u32) -> io::Result<Self> {
+        if slot_size == 0 || slot_count == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "slot_size and slot_count must be non-zero",
+            ));
+        }
+        let cname = c_name(name)?;
+        let len = segment_size(slot_size, slot_count);
+        let fd = open_fd(&cname, libc::O_CREAT | libc::O_EXCL | libc::O_RDWR)?;
+        let mapped = (|| {
+            // SAFETY: fd is a freshly created shared-memory object.
+            if unsafe { libc::ftruncate(fd, len as libc::off_t) } < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Mapping::map(fd, len, true)
+        })();
+        close_fd(fd);
+        let map = match mapped {
+            Ok(map) => map,
+            Err(e) => {
+                let _ = unlink(name);
+                return Err(e);
+            }
+        };
+        map.u32_at(OFF_VERSION).store(VERSION, Ordering::Relaxed);
+        map.u32_at(OFF_SLOT_SIZE)
+            .store(slot_size, Ordering::Relaxed);
+        map.u32_at(OFF_SLOT_COUNT)
+            .store(slot_count, Ordering::Relaxed);
+        map.u32_at(OFF_MAGIC).store(MAGIC, Ordering::Release);
+        Ok(ShmRingWriter {
+            ring: Ring {
+                map,
+                name: name.to_owned(),
+                slot_size,
+                slot_count,
+            },
+            head: 0,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.ring.name
+    }
+    pub fn slot_size(&self) -> u32 {
+        self.ring.slot_size
+    }
+    pub fn slot_count(&self) -> u32 {
+        self.ring.slot_count
+    }
+    /// Number of writes completed so far.
+    pub fn head(&self) -> u64 {
+        self.head
+    }
+
+    /// Copy `data` into the next slot and return its sequence number.
+    pub fn write(&mut self, data: &[u8]) -> io::Result<u64> {
+        if data.len() > self.ring.slot_size as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "payload of {} bytes exceeds slot size {}",
+                    data.len(),
+                    self.ring.slot_size
+                ),
+            ));
+        }
+        let seq = self.head;
+        let slot = self.ring.slot_of(seq);
+        let stamp = self.ring.stamp(slot);
+        stamp.store(2 * seq + 1, Ordering::Relaxed);
+        fence(Ordering::Release);
+        // SAFETY: the slot holds slot_size >= data.len() bytes; readers
+        // detect the concurrent overwrite through the stamp.
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), self.ring.slot_ptr(slot), data.len());
+        }
+        stamp.store(2 * seq + 2, Ordering::Release);
+        self.head = seq + 1;
+        self.ring.head().store(self.head, Ordering::Release);
+        Ok(seq)
+    }
+
+    /// Builder for the `ShmRing` message announcing write `seq` of
+    /// `length` bytes, with name, geometry and pid filled in.
+    pub fn announce(&self, seq: u64, length: u32) -> ShmRingBuilder<'_> {
+        let mut b = ShmRing::builder();
+        b.name(self.ring.name.as_str())
+            .pid(std::process::id())
+            .slot_size(self.ring.slot_size)
+            .slot_count(self.ring.slot_count)
+            .seq(seq)
+            .length(length);
+        b
+    }
+}
+
+impl Drop for ShmRingWriter {
+    fn drop(&mut self) {
+        let _ = unlink(&self.ring.name);
+    }
+}
+
+// ── ShmRingReader ───────────────────────────────────────────────────
+
+/// Read-only view of a ring created by another process.
+pub struct ShmRingReader {
+    ring: Ring,
+}
+
+impl ShmRingReader {
+    /// Open the named ring and validate its control block.
+    pub fn open(name: &str) -> io::Result<Self> {
+        let cname = c_name(name)?;
+        let fd = open_fd(&cname, libc::O_RDONLY)?;
+        let control = Mapping::map(fd, CONTROL_SIZE, false).and_then(|control| {
+            let invalid = |msg| io::Error::new(io::ErrorKind::InvalidData, msg);
+            if control.u32_at(OFF_MAGIC).load(Ordering::Acquire) != MAGIC {
+                return Err(invalid("not an EdgeFirst shm ring"));
+            }
+            if control.u32_at(OFF_VERSION).load(Ordering::Relaxed) != VERSION {
+                return Err(invalid("unsupported shm ring version"));
+            }
+            let slot_size = control.u32_at(OFF_SLOT_SIZE).load(Ordering::Relaxed);
+            let slot_count = control.u32_at(OFF_SLOT_COUNT).load(Ordering::Relaxed);
+            if slot_size == 0 || slot_count == 0 {
+                return Err(invalid("empty shm ring geometry"));
+            }
+            Ok((slot_size, slot_count))
+        });
+        let mapped = control.and_then(|(slot_size, slot_count)| {
+            Mapping::map(fd, segment_size(slot_size, slot_count), false)
+                .map(|map| (map, slot_size, slot_count))
+        });
+        close_fd(fd);
+        let (map, slot_size, slot_count) = mapped?;
+        Ok(ShmRingReader {
+            ring: Ring {
+                map,
+                name: name.to_owned(),
+                slot_size,
+                slot_count,
+            },
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.ring.name
+    }
+    pub fn slot_size(&self) -> u32 {
+        self.ring.slot_size
+    }
+    pub fn slot_count(&self) -> u32 {
+        self.ring.slot_count
+    }
+    /// Number of writes the writer has completed.
+    pub fn head(&self) -> u64 {
+        self.ring.head().load(Ordering::Acquire)
+    }
+
+    /// Copy the write announced by `msg` into `out`.
+    ///
+    /// Returns `Ok(false)` when the slot has since been reused, i.e. the
+    /// reader fell more than `slot_count` writes behind.
+    pub fn read<B: AsRef<[u8]>>(&self, msg: &ShmRing<B>, out: &mut Vec<u8>) -> io::Result<bool> {
+        if msg.slot_size() != self.ring.slot_size || msg.slot_count() != self.ring.slot_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "ShmRing message geometry does not match the mapped ring",
+            ));
+        }
+        self.read_seq(msg.seq(), msg.length(), out)
+    }
+
+    /// Copy `length` bytes of write `seq` into `out`; see [`Self::read`].
+    pub fn read_seq(&self, seq: u64, length: u32, out: &mut Vec<u8>) -> io::Result<bool> {
+        if length > self.ring.slot_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "announced length exceeds slot size",
+            ));
+        }
+        let slot = self.ring.slot_of(seq);
+        let stamp = self.ring.stamp(slot);
+        let done = 2 * seq + 2;
+        if stamp.load(Ordering::Acquire) != done {
+            return Ok(false);
+        }
+        out.clear();
+        out.reserve(length as usize);
+        // SAFETY: the slot holds slot_size >= length bytes and `out` has
+        // capacity for them; a racing write is caught by the stamp re-check.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self.ring.slot_ptr(slot),
+                out.as_mut_ptr(),
+                length as usize,
+            );
+            out.set_len(length as usize);
+        }
+        fence(Ordering::Acquire);
+        if stamp.load(Ordering::Relaxed) != done {
+            out.clear();
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique(tag: &str) -> String {
+        use std::sync::atomic::AtomicUsize;
+        static N: AtomicUsize = AtomicUsize::new(0);
+        format!(
+            "/efsr.{}.{}.{}",
+            tag,
+            std::process::id(),
+            N.fetch_add(1, Ordering::Relaxed)
+        )
+    }
+
+    #[test]
+    fn write_announce_read() {
+        let mut w = ShmRingWriter::create(&unique("rw"), 64, 4).unwrap();
+        let seq = w.write(b"hello ring").unwrap();
+        let msg = w.announce(seq, 10).schema("raw").build().unwrap();
+        let msg = ShmRing::from_cdr(msg.into_cdr()).unwrap();
+        assert_eq!(msg.slot(), 0);
+
+        let r = ShmRingReader::open(msg.name()).unwrap();
+        assert_eq!((r.slot_size(), r.slot_count(), r.head()), (64, 4, 1));
+        let mut out = Vec::new();
+        assert!(r.read(&msg, &mut out).unwrap());
+        assert_eq!(out, b"hello ring");
+
+        assert!(w.write(&[0u8; 65]).is_err());
+    }
+
+    #[test]
+    fn lapped_reader_sees_overwrite() {
+        let mut w = ShmRingWriter::create(&unique("lap"), 8, 2).unwrap();
+        let first = w.write(&[1; 8]).unwrap();
+        let r = ShmRingReader::open(w.name()).unwrap();
+        w.write(&[2; 8]).unwrap();
+        w.write(&[3; 8]).unwrap();
+
+        let mut out = Vec::new();
+        assert!(!r.read_seq(first, 8, &mut out).unwrap());
+        assert!(r.read_seq(first + 2, 8, &mut out).unwrap());
+        assert_eq!(out, [3; 8]);
+    }
+
+    #[test]
+    fn writer_drop_unlinks() {
+        let name = unique("drop");
+        drop(ShmRingWriter::create(&name, 8, 1).unwrap());
+        assert!(ShmRingReader::open(&name).is_err());
+    }
+}
@@ -5,9 +5,12 @@
 //!
 //! - `ColorRGBA` — CdrFixed, 16 bytes (4 × f32)
 //! - `Header` — buffer-backed, contains stamp + frame_id
+//! - `MultiArrayDimension`, `MultiArrayLayout` and the `*MultiArray`
+//!   messages (`Float32MultiArray`, `UInt8MultiArray`, …) — buffer-backed
 
 use crate::builtin_interfaces::Time;
 use crate::cdr::*;
+use alloc::{vec, vec::Vec};
 
 // ── CdrFixed types ──────────────────────────────────────────────────
 
@@ -70,10 +73,18 @@ impl<B> Header<B> {
 
 impl<B: AsRef<[u8]>> Header<B> {
     pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Self::from_cdr_prefix(buf)?;
+        CdrCursor::resume_checked(header.buf.as_ref(), header.offsets[0]).expect_end()?;
+        Ok(header)
+    }
+
+    /// Scan a Header at the start of a larger message, ignoring whatever
+    /// follows it. Used by the composite types' `from_cdr`.
+    pub(crate) fn from_cdr_prefix(buf: B) -> Result<Self, CdrError> {
         let mut c = CdrCursor::new(buf.as_ref())?;
         // skip stamp (8 bytes)
-        c.skip(8)?;
-        let _ = c.read_string()?;
+        c.skip(8).field("stamp")?;
+        let _ = c.read_string().field("frame_id")?;
         Ok(Header {
             offsets: [c.offset()],
             buf,
@@ -149,14 +160,14 @@ impl Header<Vec<u8>> {
 /// `encode_into_slice()` is called.
 pub struct HeaderBuilder<'a> {
     stamp: Time,
-    frame_id: std::borrow::Cow<'a, str>,
+    frame_id: alloc::borrow::Cow<'a, str>,
 }
 
 impl<'a> Default for HeaderBuilder<'a> {
     fn default() -> Self {
         Self {
             stamp: Time { sec: 0, nanosec: 0 },
-            frame_id: std::borrow::Cow::Borrowed(""),
+            frame_id: alloc::borrow::Cow::Borrowed(""),
         }
     }
 }
@@ -171,20 +182,21 @@ impl<'a> HeaderBuilder<'a> {
         self
     }
 
-    pub fn frame_id(&mut self, s: impl Into<std::borrow::Cow<'a, str>>) -> &mut Self {
+    pub fn frame_id(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
         self.frame_id = s.into();
         self
     }
 
-    fn size(&self) -> usize {
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
         let mut s = CdrSizer::new();
         Time::size_cdr(&mut s);
         s.size_string(&self.frame_id);
         s.size()
     }
 
-    fn write_into(&self, buf: &mut [u8]) -> Result<(), CdrError> {
-        let mut w = CdrWriter::new(buf)?;
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
         self.stamp.write_cdr(&mut w);
         w.write_string(&self.frame_id);
         w.finish()
@@ -192,8 +204,8 @@ impl<'a> HeaderBuilder<'a> {
 
     /// Allocate a fresh `Vec<u8>` and return a fully-parsed `Header<Vec<u8>>`.
     pub fn build(&self) -> Result<Header<Vec<u8>>, CdrError> {
-        let mut buf = vec![0u8; self.size()];
-        self.write_into(&mut buf)?;
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
         Header::from_cdr(buf)
     }
 
@@ -201,22 +213,22 @@ impl<'a> HeaderBuilder<'a> {
     /// size. After return, `buf.len()` is the CDR size and `&buf[..]` is a
     /// complete CDR message. Reuses existing allocation when capacity suffices.
     pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
-        buf.resize(self.size(), 0);
-        self.write_into(buf)
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
     }
 
     /// Serialize into `buf` and return bytes written. Errors with
     /// `BufferTooShort` when `buf` is smaller than the required size; nothing
     /// is mutated in that case.
     pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
-        let need = self.size();
+        let need = self.cdr_size();
         if buf.len() < need {
             return Err(CdrError::BufferTooShort {
                 need,
                 have: buf.len(),
             });
         }
-        self.write_into(&mut buf[..need])?;
+        self.write_into(&mut buf[..need], Endianness::Little)?;
         Ok(need)
     }
 }
@@ -229,18 +241,676 @@ impl<B: AsRef<[u8]> + AsMut<[u8]>> Header<B> {
     }
 }
 
+// ── MultiArray helpers ──────────────────────────────────────────────
+
+/// Zero-copy view of a MultiArrayDimension element within a CDR sequence.
+///
+/// Per the ROS definition, `size` is the number of elements along the
+/// dimension and `stride` the number of elements spanned by one step
+/// along it (the product of `size` over this and all later dimensions).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MultiArrayDimensionView<'a> {
+    pub label: &'a str,
+    pub size: u32,
+    pub stride: u32,
+}
+
+fn scan_dimension_element<'a>(
+    c: &mut CdrCursor<'a>,
+) -> Result<MultiArrayDimensionView<'a>, CdrError> {
+    Ok(MultiArrayDimensionView {
+        label: c.read_string()?,
+        size: c.read_u32()?,
+        stride: c.read_u32()?,
+    })
+}
+
+fn write_dimension_element(w: &mut CdrWriter<'_>, d: &MultiArrayDimensionView<'_>) {
+    w.write_string(d.label);
+    w.write_u32(d.size);
+    w.write_u32(d.stride);
+}
+
+fn size_dimension_element(s: &mut CdrSizer, d: &MultiArrayDimensionView<'_>) {
+    s.size_string(d.label);
+    s.size_u32();
+    s.size_u32();
+}
+
+/// Scan a MultiArrayLayout starting at the (4-aligned) dim count and return
+/// the byte index of its `data_offset` field.
+fn scan_layout(c: &mut CdrCursor<'_>) -> Result<usize, CdrError> {
+    let raw = c.read_u32()?;
+    // Smallest element: empty label (4 + 1), size and stride (8).
+    let n = c.check_seq_count(raw, 13).field("dim")?;
+    for _ in 0..n {
+        scan_dimension_element(c).field("dim")?;
+    }
+    c.align(4);
+    let at = c.offset();
+    c.read_u32().field("data_offset")?;
+    Ok(at)
+}
+
+fn read_dims(b: &[u8], at: usize) -> Vec<MultiArrayDimensionView<'_>> {
+    let mut c = CdrCursor::resume(b, at + 4);
+    (0..rd_u32(b, at))
+        .map(|_| scan_dimension_element(&mut c).expect("dim elements validated during from_cdr"))
+        .collect()
+}
+
+fn write_layout(w: &mut CdrWriter<'_>, dim: &[MultiArrayDimensionView<'_>], data_offset: u32) {
+    w.write_u32(dim.len() as u32);
+    for d in dim {
+        write_dimension_element(w, d);
+    }
+    w.write_u32(data_offset);
+}
+
+fn size_layout(s: &mut CdrSizer, dim: &[MultiArrayDimensionView<'_>]) {
+    s.size_u32();
+    for d in dim {
+        size_dimension_element(s, d);
+    }
+    s.size_u32();
+}
+
+// ── MultiArrayDimension<B> ──────────────────────────────────────────
+//
+// CDR layout: label (string) → offsets[0], then size(u32), stride(u32)
+
+pub struct MultiArrayDimension<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> MultiArrayDimension<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> MultiArrayDimension<C> {
+        MultiArrayDimension {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> MultiArrayDimension<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        let _ = c.read_string().field("label")?;
+        c.align(4);
+        let o0 = c.offset();
+        c.read_u32()?;
+        c.read_u32()?;
+        c.expect_end()?;
+        Ok(MultiArrayDimension { offsets: [o0], buf })
+    }
+
+    #[inline]
+    pub fn label(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE).0
+    }
+    #[inline]
+    pub fn size(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    #[inline]
+    pub fn stride(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0] + 4)
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl MultiArrayDimension<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `MultiArrayDimensionBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> MultiArrayDimensionBuilder<'a> {
+        MultiArrayDimensionBuilder::new()
+    }
+}
+
+// ── MultiArrayDimensionBuilder<'a> ──────────────────────────────────
+
+/// Builder for `MultiArrayDimension<Vec<u8>>` with buffer-reuse finalizers.
+pub struct MultiArrayDimensionBuilder<'a> {
+    label: alloc::borrow::Cow<'a, str>,
+    size: u32,
+    stride: u32,
+}
+
+impl<'a> Default for MultiArrayDimensionBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            label: alloc::borrow::Cow::Borrowed(""),
+            size: 0,
+            stride: 0,
+        }
+    }
+}
+
+impl<'a> MultiArrayDimensionBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn label(&mut self, s: impl Into<alloc::borrow::Cow<'a, str>>) -> &mut Self {
+        self.label = s.into();
+        self
+    }
+    pub fn size(&mut self, v: u32) -> &mut Self {
+        self.size = v;
+        self
+    }
+    pub fn stride(&mut self, v: u32) -> &mut Self {
+        self.stride = v;
+        self
+    }
+
+    fn view(&self) -> MultiArrayDimensionView<'_> {
+        MultiArrayDimensionView {
+            label: &self.label,
+            size: self.size,
+            stride: self.stride,
+        }
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        size_dimension_element(&mut s, &self.view());
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        write_dimension_element(&mut w, &self.view());
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<MultiArrayDimension<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        MultiArrayDimension::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── MultiArrayLayout<B> ─────────────────────────────────────────────
+//
+// CDR layout: offsets[0] (byte index of the dim seq count u32),
+//   uint32 count + MultiArrayDimension[] dim → offsets[1], data_offset(u32)
+
+pub struct MultiArrayLayout<B> {
+    buf: B,
+    offsets: [usize; 2],
+}
+
+impl<B> MultiArrayLayout<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> MultiArrayLayout<C> {
+        MultiArrayLayout {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> MultiArrayLayout<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        let o0 = c.offset();
+        let o1 = scan_layout(&mut c)?;
+        c.expect_end()?;
+        Ok(MultiArrayLayout {
+            offsets: [o0, o1],
+            buf,
+        })
+    }
+
+    pub fn dim_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn dim(&self) -> Vec<MultiArrayDimensionView<'_>> {
+        read_dims(self.buf.as_ref(), self.offsets[0])
+    }
+    #[inline]
+    pub fn data_offset(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[1])
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl MultiArrayLayout<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `MultiArrayLayoutBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> MultiArrayLayoutBuilder<'a> {
+        MultiArrayLayoutBuilder::new()
+    }
+}
+
+// ── MultiArrayLayoutBuilder<'a> ─────────────────────────────────────
+
+/// Builder for `MultiArrayLayout<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `dim` is borrowed for zero-copy input; each `MultiArrayDimensionView`
+/// borrows its `label` as well.
+#[derive(Default)]
+pub struct MultiArrayLayoutBuilder<'a> {
+    dim: &'a [MultiArrayDimensionView<'a>],
+    data_offset: u32,
+}
+
+impl<'a> MultiArrayLayoutBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn dim(&mut self, v: &'a [MultiArrayDimensionView<'a>]) -> &mut Self {
+        self.dim = v;
+        self
+    }
+    pub fn data_offset(&mut self, v: u32) -> &mut Self {
+        self.data_offset = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        size_layout(&mut s, self.dim);
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        write_layout(&mut w, self.dim, self.data_offset);
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<MultiArrayLayout<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        MultiArrayLayout::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── *MultiArray<B> ──────────────────────────────────────────────────
+//
+// CDR layout: offsets[0] (byte index of the dim seq count u32),
+//   uint32 count + MultiArrayDimension[] dim → offsets[1], data_offset(u32)
+//   → offsets[2], uint32 count + T[] data (aligned to size_of::<T>() when
+//   non-empty)
+//
+// The eleven element types differ only in T, so one macro generates each
+// view and builder pair.
+
+macro_rules! multi_array {
+    ($(#[$doc:meta])* $name:ident, $builder:ident, $ty:ty, $read:ident, $rd:ident, $write:ident, $size:ident) => {
+        $(#[$doc])*
+        pub struct $name<B> {
+            buf: B,
+            offsets: [usize; 3],
+        }
+
+        impl<B> $name<B> {
+            /// Convert the buffer type without re-parsing the offset table.
+            #[inline]
+            pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> $name<C> {
+                $name {
+                    buf: f(self.buf),
+                    offsets: self.offsets,
+                }
+            }
+        }
+
+        impl<B: AsRef<[u8]>> $name<B> {
+            pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+                let mut c = CdrCursor::new(buf.as_ref())?;
+                let o0 = c.offset();
+                let o1 = scan_layout(&mut c).field("layout")?;
+                let o2 = c.offset();
+                let raw = c.read_u32()?;
+                let n = c
+                    .check_seq_count(raw, core::mem::size_of::<$ty>())
+                    .field("data")?;
+                for _ in 0..n {
+                    c.$read()?;
+                }
+                c.expect_end()?;
+                Ok($name {
+                    offsets: [o0, o1, o2],
+                    buf,
+                })
+            }
+
+            pub fn dim_len(&self) -> u32 {
+                rd_u32(self.buf.as_ref(), self.offsets[0])
+            }
+            /// `layout.dim`: the array's dimensions, outermost first.
+            pub fn dim(&self) -> Vec<MultiArrayDimensionView<'_>> {
+                read_dims(self.buf.as_ref(), self.offsets[0])
+            }
+            /// `layout.data_offset`: elements of `data` before the array.
+            #[inline]
+            pub fn data_offset(&self) -> u32 {
+                rd_u32(self.buf.as_ref(), self.offsets[1])
+            }
+            #[inline]
+            pub fn data_len(&self) -> u32 {
+                rd_u32(self.buf.as_ref(), self.offsets[2])
+            }
+            pub fn data(&self) -> Vec<$ty> {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+                let b = self.buf.as_ref();
+                let at = cdr_align(self.offsets[2] + 4, SIZE);
+                (0..self.data_len() as usize)
+                    .map(|i| $rd(b, at + SIZE * i))
+                    .collect()
+            }
+            #[inline]
+            pub fn as_cdr(&self) -> &[u8] {
+                self.buf.as_ref()
+            }
+            pub fn to_cdr(&self) -> Vec<u8> {
+                self.buf.as_ref().to_vec()
+            }
+        }
+
+        impl $name<Vec<u8>> {
+            pub fn into_cdr(self) -> Vec<u8> {
+                self.buf
+            }
+
+            #[doc = concat!("Start a new `", stringify!($builder), "` with zero-valued defaults.")]
+            pub fn builder<'a>() -> $builder<'a> {
+                $builder::new()
+            }
+        }
+
+        #[doc = concat!("Builder for `", stringify!($name), "<Vec<u8>>` with buffer-reuse finalizers.")]
+        ///
+        /// `dim` and `data` are borrowed for zero-copy input.
+        #[derive(Default)]
+        pub struct $builder<'a> {
+            dim: &'a [MultiArrayDimensionView<'a>],
+            data_offset: u32,
+            data: &'a [$ty],
+        }
+
+        impl<'a> $builder<'a> {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            pub fn dim(&mut self, v: &'a [MultiArrayDimensionView<'a>]) -> &mut Self {
+                self.dim = v;
+                self
+            }
+            pub fn data_offset(&mut self, v: u32) -> &mut Self {
+                self.data_offset = v;
+                self
+            }
+            pub fn data(&mut self, v: &'a [$ty]) -> &mut Self {
+                self.data = v;
+                self
+            }
+
+            /// Exact CDR size in bytes (header included) that `build()` would produce.
+            pub fn cdr_size(&self) -> usize {
+                let mut s = CdrSizer::new();
+                size_layout(&mut s, self.dim);
+                s.size_u32();
+                for _ in self.data {
+                    s.$size();
+                }
+                s.size()
+            }
+
+            fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+                let mut w = CdrWriter::with_endianness(buf, endianness)?;
+                write_layout(&mut w, self.dim, self.data_offset);
+                w.write_u32(self.data.len() as u32);
+                for v in self.data {
+                    w.$write(*v);
+                }
+                w.finish()
+            }
+
+            pub fn build(&self) -> Result<$name<Vec<u8>>, CdrError> {
+                let mut buf = vec![0u8; self.cdr_size()];
+                self.write_into(&mut buf, Endianness::Little)?;
+                $name::from_cdr(buf)
+            }
+
+            pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+                buf.resize(self.cdr_size(), 0);
+                self.write_into(buf, Endianness::Little)
+            }
+
+            pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+                let need = self.cdr_size();
+                if buf.len() < need {
+                    return Err(CdrError::BufferTooShort {
+                        need,
+                        have: buf.len(),
+                    });
+                }
+                self.write_into(&mut buf[..need], Endianness::Little)?;
+                Ok(need)
+            }
+        }
+    };
+}
+
+multi_array!(
+    /// `byte[]` (octet) multi-dimensional array.
+    ByteMultiArray, ByteMultiArrayBuilder, u8, read_u8, rd_u8, write_u8, size_u8
+);
+multi_array!(
+    /// `float32[]` multi-dimensional array, e.g. a model output tensor.
+    Float32MultiArray, Float32MultiArrayBuilder, f32, read_f32, rd_f32, write_f32, size_f32
+);
+multi_array!(
+    /// `float64[]` multi-dimensional array.
+    Float64MultiArray, Float64MultiArrayBuilder, f64, read_f64, rd_f64, write_f64, size_f64
+);
+multi_array!(
+    /// `int8[]` multi-dimensional array.
+    Int8MultiArray, Int8MultiArrayBuilder, i8, read_i8, rd_i8, write_i8, size_i8
+);
+multi_array!(
+    /// `int16[]` multi-dimensional array.
+    Int16MultiArray, Int16MultiArrayBuilder, i16, read_i16, rd_i16, write_i16, size_i16
+);
+multi_array!(
+    /// `int32[]` multi-dimensional array.
+    Int32MultiArray, Int32MultiArrayBuilder, i32, read_i32, rd_i32, write_i32, size_i32
+);
+multi_array!(
+    /// `int64[]` multi-dimensional array.
+    Int64MultiArray, Int64MultiArrayBuilder, i64, read_i64, rd_i64, write_i64, size_i64
+);
+multi_array!(
+    /// `uint8[]` multi-dimensional array.
+    UInt8MultiArray, UInt8MultiArrayBuilder, u8, read_u8, rd_u8, write_u8, size_u8
+);
+multi_array!(
+    /// `uint16[]` multi-dimensional array.
+    UInt16MultiArray, UInt16MultiArrayBuilder, u16, read_u16, rd_u16, write_u16, size_u16
+);
+multi_array!(
+    /// `uint32[]` multi-dimensional array.
+    UInt32MultiArray, UInt32MultiArrayBuilder, u32, read_u32, rd_u32, write_u32, size_u32
+);
+multi_array!(
+    /// `uint64[]` multi-dimensional array.
+    UInt64MultiArray, UInt64MultiArrayBuilder, u64, read_u64, rd_u64, write_u64, size_u64
+);
+
 // ── Registry ────────────────────────────────────────────────────────
 
 /// Check if a type name is supported by this module.
 pub fn is_type_supported(type_name: &str) -> bool {
-    matches!(type_name, "Header" | "ColorRGBA")
+    matches!(
+        type_name,
+        "Header"
+            | "ColorRGBA"
+            | "MultiArrayDimension"
+            | "MultiArrayLayout"
+            | "ByteMultiArray"
+            | "Float32MultiArray"
+            | "Float64MultiArray"
+            | "Int16MultiArray"
+            | "Int32MultiArray"
+            | "Int64MultiArray"
+            | "Int8MultiArray"
+            | "UInt16MultiArray"
+            | "UInt32MultiArray"
+            | "UInt64MultiArray"
+            | "UInt8MultiArray"
+    )
 }
 
 /// List all type schema names in this module.
 pub fn list_types() -> &'static [&'static str] {
-    &["std_msgs/msg/Header", "std_msgs/msg/ColorRGBA"]
+    &[
+        "std_msgs/msg/Header",
+        "std_msgs/msg/ColorRGBA",
+        "std_msgs/msg/MultiArrayDimension",
+        "std_msgs/msg/MultiArrayLayout",
+        "std_msgs/msg/ByteMultiArray",
+        "std_msgs/msg/Float32MultiArray",
+        "std_msgs/msg/Float64MultiArray",
+        "std_msgs/msg/Int16MultiArray",
+        "std_msgs/msg/Int32MultiArray",
+        "std_msgs/msg/Int64MultiArray",
+        "std_msgs/msg/Int8MultiArray",
+        "std_msgs/msg/UInt16MultiArray",
+        "std_msgs/msg/UInt32MultiArray",
+        "std_msgs/msg/UInt64MultiArray",
+        "std_msgs/msg/UInt8MultiArray",
+    ]
 }
 
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "Header" => "builtin_interfaces/Time stamp\nstring frame_id\n",
+        "ColorRGBA" => {
+            "\
+float32 r
+float32 g
+float32 b
+float32 a
+"
+        }
+        "MultiArrayDimension" => "string label\nuint32 size\nuint32 stride\n",
+        "MultiArrayLayout" => "MultiArrayDimension[] dim\nuint32 data_offset\n",
+        "ByteMultiArray" => "MultiArrayLayout layout\nbyte[] data\n",
+        "Float32MultiArray" => "MultiArrayLayout layout\nfloat32[] data\n",
+        "Float64MultiArray" => "MultiArrayLayout layout\nfloat64[] data\n",
+        "Int16MultiArray" => "MultiArrayLayout layout\nint16[] data\n",
+        "Int32MultiArray" => "MultiArrayLayout layout\nint32[] data\n",
+        "Int64MultiArray" => "MultiArrayLayout layout\nint64[] data\n",
+        "Int8MultiArray" => "MultiArrayLayout layout\nint8[] data\n",
+        "UInt16MultiArray" => "MultiArrayLayout layout\nuint16[] data\n",
+        "UInt32MultiArray" => "MultiArrayLayout layout\nuint32[] data\n",
+        "UInt64MultiArray" => "MultiArrayLayout layout\nuint64[] data\n",
+        "UInt8MultiArray" => "MultiArrayLayout layout\nuint8[] data\n",
+        _ => return None,
+    })
+}
+
+// CdrEncode implementations
+crate::cdr::impl_cdr_encode!(
+    HeaderBuilder,
+    MultiArrayDimensionBuilder,
+    MultiArrayLayoutBuilder,
+    ByteMultiArrayBuilder,
+    Float32MultiArrayBuilder,
+    Float64MultiArrayBuilder,
+    Int16MultiArrayBuilder,
+    Int32MultiArrayBuilder,
+    Int64MultiArrayBuilder,
+    Int8MultiArrayBuilder,
+    UInt16MultiArrayBuilder,
+    UInt32MultiArrayBuilder,
+    UInt64MultiArrayBuilder,
+    UInt8MultiArrayBuilder,
+);
+
+// HeapSize implementations
+crate::heap_size::impl_heap_size!(
+    Header,
+    MultiArrayDimension,
+    MultiArrayLayout,
+    ByteMultiArray,
+    Float32MultiArray,
+    Float64MultiArray,
+    Int16MultiArray,
+    Int32MultiArray,
+    Int64MultiArray,
+    Int8MultiArray,
+    UInt16MultiArray,
+    UInt32MultiArray,
+    UInt64MultiArray,
+    UInt8MultiArray,
+);
+
 // SchemaType implementations
 use crate::schema_registry::SchemaType;
 
@@ -293,6 +963,70 @@ mod tests {
         assert_eq!(header.stamp(), Time::new(42, 123));
     }
 
+    #[test]
+    fn multi_array_roundtrip() {
+        let dim = [
+            MultiArrayDimensionView {
+                label: "rows",
+                size: 2,
+                stride: 6,
+            },
+            MultiArrayDimensionView {
+                label: "cols",
+                size: 3,
+                stride: 3,
+            },
+        ];
+        let data = [1.5f64, -2.0, 3.25, 0.0, 5.0, 6.5];
+        let array = Float64MultiArray::builder()
+            .dim(&dim)
+            .data(&data)
+            .build()
+            .unwrap();
+        let decoded = Float64MultiArray::from_cdr(array.to_cdr()).unwrap();
+        assert_eq!(decoded.dim(), dim);
+        assert_eq!(decoded.data_offset(), 0);
+        assert_eq!(decoded.data(), data);
+
+        let bytes = UInt8MultiArray::builder()
+            .dim(&dim[1..])
+            .data_offset(1)
+            .data(&[9, 8, 7, 6])
+            .build()
+            .unwrap();
+        let decoded = UInt8MultiArray::from_cdr(bytes.to_cdr()).unwrap();
+        assert_eq!(decoded.dim_len(), 1);
+        assert_eq!(decoded.data_offset(), 1);
+        assert_eq!(decoded.data(), [9, 8, 7, 6]);
+
+        let empty = Int64MultiArray::builder().build().unwrap();
+        assert!(Int64MultiArray::from_cdr(empty.to_cdr())
+            .unwrap()
+            .data()
+            .is_empty());
+
+        let layout = MultiArrayLayout::builder()
+            .dim(&dim)
+            .data_offset(4)
+            .build()
+            .unwrap();
+        let layout = MultiArrayLayout::from_cdr(layout.to_cdr()).unwrap();
+        assert_eq!(layout.dim(), dim);
+        assert_eq!(layout.data_offset(), 4);
+
+        let d = MultiArrayDimension::builder()
+            .label("channels")
+            .size(3)
+            .stride(3)
+            .build()
+            .unwrap();
+        let d = MultiArrayDimension::from_cdr(d.to_cdr()).unwrap();
+        assert_eq!((d.label(), d.size(), d.stride()), ("channels", 3, 3));
+
+        let cdr = array.to_cdr();
+        assert!(Float64MultiArray::from_cdr(&cdr[..cdr.len() - 8]).is_err());
+    }
+
     #[test]
     fn color_rgba_roundtrip() {
         use crate::cdr::{decode_fixed, encode_fixed};
@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Decoding CDR messages straight from byte streams.
+//!
+//! Buffer-backed messages are zero-copy views and therefore need their
+//! bytes in memory, but nothing requires a whole recording or socket
+//! backlog to be buffered first. [`FrameReader`] pulls one length-prefixed
+//! message at a time from any [`Read`] into a reused buffer, and
+//! [`cdr::read_fixed`](crate::cdr::read_fixed) decodes `CdrFixed` values
+//! reading exactly their wire size.
+//!
+//! # Frame format
+//!
+//! Each frame is a little-endian `u32` byte count followed by that many
+//! bytes of CDR, encapsulation header included — the layout used for
+//! recordings and raw TCP streams. [`FrameWriter`] produces it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::builtin_interfaces::Time;
+//! use edgefirst_schemas::std_msgs::Header;
+//! use edgefirst_schemas::stream::{FrameReader, FrameWriter};
+//!
+//! let mut w = FrameWriter::new(Vec::new());
+//! for i in 0..3 {
+//!     let hdr = Header::builder().stamp(Time::new(i, 0)).frame_id("cam").build().unwrap();
+//!     w.write_frame(hdr.as_cdr()).unwrap();
+//! }
+//! let recording = w.into_inner();
+//!
+//! let mut r = FrameReader::new(&recording[..]);
+//! let mut n = 0;
+//! while let Some(frame) = r.next_frame().unwrap() {
+//!     let hdr = Header::from_cdr(frame).unwrap();
+//!     assert_eq!(hdr.stamp().sec, n);
+//!     n += 1;
+//! }
+//! assert_eq!(n, 3);
+//! ```
+
+use std::io::{self, Read, Write};
+
+use crate::cdr::{CdrError, DecodeLimits};
+
+/// Reads length-prefixed CDR frames from a byte stream.
+///
+/// The frame buffer is reused between calls and grows only as frame bytes
+/// actually arrive, so a corrupt length prefix cannot trigger a large
+/// allocation; prefixes above the `max_message_size` limit are rejected
+/// before anything is read.
+pub struct FrameReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    limits: DecodeLimits,
+}
+
+impl<R: Read> FrameReader<R> {
+    /// Wrap `inner`, enforcing the current [`DecodeLimits`].
+    pub fn new(inner: R) -> Self {
+        Self::with_limits(inner, DecodeLimits::current())
+    }
+
+    /// Wrap `inner`, enforcing `limits` on frame sizes.
+    pub fn with_limits(inner: R, limits: DecodeLimits) -> Self {
+        FrameReader {
+            inner,
+            buf: Vec::new(),
+            limits,
+        }
+    }
+
+    /// Read the next frame and return its CDR bytes.
+    ///
+    /// Returns `Ok(None)` on a clean end of stream (no bytes of a new
+    /// frame), and `ErrorKind::UnexpectedEof` if the stream ends inside a
+    /// frame. Oversized prefixes fail with `ErrorKind::InvalidData`
+    /// wrapping [`CdrError::LimitExceeded`].
+    pub fn next_frame(&mut self) -> io::Result<Option<&[u8]>> {
+        let mut prefix = [0u8; 4];
+        let mut got = 0;
+        while got < prefix.len() {
+            match self.inner.read(&mut prefix[got..]) {
+                Ok(0) if got == 0 => return Ok(None),
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => got += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let len = u32::from_le_bytes(prefix) as usize;
+        if len > self.limits.max_message_size {
+            return Err(CdrError::LimitExceeded {
+                limit: "max_message_size",
+                value: len,
+                max: self.limits.max_message_size,
+                offset: 0,
+            }
+            .into());
+        }
+
+        self.buf.clear();
+        let read = (&mut self.inner)
+            .take(len as u64)
+            .read_to_end(&mut self.buf)?;
+        if read < len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(Some(&self.buf))
+    }
+
+    /// The wrapped stream.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwrap the stream. Bytes of a partially read frame are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Writes length-prefixed CDR frames readable by [`FrameReader`].
+pub struct FrameWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> FrameWriter<W> {
+    pub fn new(inner: W) -> Self {
+        FrameWriter { inner }
+    }
+
+    /// Write one CDR message (encapsulation header included) as a frame.
+    pub fn write_frame(&mut self, cdr: &[u8]) -> io::Result<()> {
+        let len = u32::try_from(cdr.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "frame exceeds 4 GiB"))?;
+        self.inner.write_all(&len.to_le_bytes())?;
+        self.inner.write_all(cdr)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// The wrapped sink.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor_msgs::Image;
+
+    fn image(w: u32) -> Vec<u8> {
+        let data = vec![7u8; w as usize];
+        Image::builder()
+            .frame_id("cam")
+            .width(w)
+            .height(1)
+            .encoding("mono8")
+            .step(w)
+            .data(&data)
+            .build()
+            .unwrap()
+            .into_cdr()
+    }
+
+    #[test]
+    fn frames_roundtrip() {
+        let mut w = FrameWriter::new(Vec::new());
+        for width in [1, 16, 1000] {
+            w.write_frame(&image(width)).unwrap();
+        }
+        let bytes = w.into_inner();
+
+        let mut r = FrameReader::new(&bytes[..]);
+        for width in [1, 16, 1000] {
+            let frame = r.next_frame().unwrap().unwrap();
+            let img = Image::from_cdr(frame).unwrap();
+            assert_eq!(img.width(), width);
+            assert_eq!(img.data().len(), width as usize);
+        }
+        assert!(r.next_frame().unwrap().is_none());
+    }
+
+    #[test]
+    fn truncated_stream_is_unexpected_eof() {
+        let mut w = FrameWriter::new(Vec::new());
+        w.write_frame(&image(8)).unwrap();
+        let bytes = w.into_inner();
+        for cut in [2, 4, bytes.len() - 1] {
+            let mut r = FrameReader::new(&bytes[..cut]);
+            let err = r.next_frame().unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        }
+    }
+
+    #[test]
+    fn oversized_prefix_is_rejected_without_reading() {
+        let limits = DecodeLimits {
+            max_message_size: 1024,
+            ..DecodeLimits::DEFAULT
+        };
+        let bytes = [0xFF, 0xFF, 0xFF, 0x7F, 0, 1, 0, 0];
+        let mut r = FrameReader::with_limits(&bytes[..], limits);
+        let err = r.next_frame().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(r.get_ref().len(), 4);
+    }
+}
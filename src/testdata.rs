@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Shared test vectors for interop tests in downstream crates.
+//!
+//! [`GOLDEN`] embeds the CDR fixtures under `testdata/cdr`, the same bytes
+//! this crate's own golden tests and the C/C++/Python suites decode, so a
+//! consumer can check its decoder against them without vendoring the
+//! files. [`sample`] generates a well-formed message of any registered
+//! schema from a seed; a seed always yields the same bytes, so a failing
+//! case is reproduced by its seed alone.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::sensor_msgs::Imu;
+//! use edgefirst_schemas::testdata;
+//!
+//! for g in testdata::GOLDEN.iter().filter(|g| g.schema == "sensor_msgs/msg/Imu") {
+//!     let _ = Imu::from_cdr(g.cdr);
+//! }
+//! for seed in 0..8 {
+//!     let cdr = testdata::sample("sensor_msgs/msg/Imu", seed).unwrap();
+//!     assert!(Imu::from_cdr(&cdr[..]).is_ok());
+//! }
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::arbitrary::{arbitrary_cdr, Unstructured};
+use crate::chaos::SplitMix64;
+
+/// A golden CDR vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Golden {
+    /// Schema name, e.g. `"sensor_msgs/msg/Image"`.
+    pub schema: &'static str,
+    /// Distinguishes several vectors of one schema (`"i420"` for
+    /// `CameraFrame_i420.cdr`); empty for the default vector.
+    pub variant: &'static str,
+    /// Encoded message, encapsulation header included.
+    pub cdr: &'static [u8],
+}
+
+macro_rules! golden {
+    ($pkg:literal, $name:literal) => {
+        Golden {
+            schema: concat!($pkg, "/msg/", $name),
+            variant: "",
+            cdr: include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/testdata/cdr/",
+                $pkg,
+                "/",
+                $name,
+                ".cdr"
+            )),
+        }
+    };
+    ($pkg:literal, $name:literal, $variant:literal) => {
+        Golden {
+            schema: concat!($pkg, "/msg/", $name),
+            variant: $variant,
+            cdr: include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/testdata/cdr/",
+                $pkg,
+                "/",
+                $name,
+                "_",
+                $variant,
+                ".cdr"
+            )),
+        }
+    };
+}
+
+/// Every golden vector, sorted by file path.
+pub const GOLDEN: &[Golden] = &[
+    golden!("builtin_interfaces", "Duration"),
+    golden!("builtin_interfaces", "Time"),
+    golden!("edgefirst_msgs", "Box"),
+    golden!("edgefirst_msgs", "CameraFrame"),
+    golden!("edgefirst_msgs", "CameraFrame", "empty"),
+    golden!("edgefirst_msgs", "CameraFrame", "h264"),
+    golden!("edgefirst_msgs", "CameraFrame", "i420"),
+    golden!("edgefirst_msgs", "CameraFrame", "inlined"),
+    golden!("edgefirst_msgs", "CameraFrame", "nv12"),
+    golden!("edgefirst_msgs", "CameraFrame", "planar_nchw"),
+    golden!("edgefirst_msgs", "CameraFrame", "split_fd"),
+    golden!("edgefirst_msgs", "Date"),
+    golden!("edgefirst_msgs", "Detect"),
+    golden!("edgefirst_msgs", "Detect", "multi"),
+    golden!("edgefirst_msgs", "DmaBuffer"),
+    golden!("edgefirst_msgs", "LocalTime"),
+    golden!("edgefirst_msgs", "Mask"),
+    golden!("edgefirst_msgs", "Model"),
+    golden!("edgefirst_msgs", "ModelInfo"),
+    golden!("edgefirst_msgs", "ModelInfo", "empty"),
+    golden!("edgefirst_msgs", "ModelInfo", "labels"),
+    golden!("edgefirst_msgs", "RadarCube"),
+    golden!("edgefirst_msgs", "RadarInfo"),
+    golden!("edgefirst_msgs", "Track"),
+    golden!("edgefirst_msgs", "Vibration"),
+    golden!("foxglove_msgs", "CircleAnnotation"),
+    golden!("foxglove_msgs", "Color"),
+    golden!("foxglove_msgs", "CompressedVideo"),
+    golden!("foxglove_msgs", "ImageAnnotations"),
+    golden!("foxglove_msgs", "Point2"),
+    golden!("foxglove_msgs", "PointsAnnotation"),
+    golden!("foxglove_msgs", "TextAnnotation"),
+    golden!("geometry_msgs", "Accel"),
+    golden!("geometry_msgs", "AccelStamped"),
+    golden!("geometry_msgs", "Inertia"),
+    golden!("geometry_msgs", "InertiaStamped"),
+    golden!("geometry_msgs", "Point"),
+    golden!("geometry_msgs", "Point32"),
+    golden!("geometry_msgs", "PointStamped"),
+    golden!("geometry_msgs", "Pose"),
+    golden!("geometry_msgs", "Pose2D"),
+    golden!("geometry_msgs", "PoseWithCovariance"),
+    golden!("geometry_msgs", "Quaternion"),
+    golden!("geometry_msgs", "Transform"),
+    golden!("geometry_msgs", "TransformStamped"),
+    golden!("geometry_msgs", "Twist"),
+    golden!("geometry_msgs", "TwistStamped"),
+    golden!("geometry_msgs", "TwistWithCovariance"),
+    golden!("geometry_msgs", "Vector3"),
+    golden!("nav_msgs", "Odometry"),
+    golden!("rosgraph_msgs", "Clock"),
+    golden!("sensor_msgs", "BatteryState"),
+    golden!("sensor_msgs", "CameraInfo"),
+    golden!("sensor_msgs", "CompressedImage"),
+    golden!("sensor_msgs", "FluidPressure"),
+    golden!("sensor_msgs", "Image"),
+    golden!("sensor_msgs", "Imu"),
+    golden!("sensor_msgs", "MagneticField"),
+    golden!("sensor_msgs", "NavSatFix"),
+    golden!("sensor_msgs", "NavSatStatus"),
+    golden!("sensor_msgs", "PointCloud2"),
+    golden!("sensor_msgs", "RegionOfInterest"),
+    golden!("sensor_msgs", "Temperature"),
+    golden!("std_msgs", "ColorRGBA"),
+    golden!("std_msgs", "Header"),
+];
+
+/// The default golden vector of `schema`.
+pub fn golden(schema: &str) -> Option<&'static [u8]> {
+    golden_variant(schema, "")
+}
+
+/// The golden vector of `schema` named `variant`.
+pub fn golden_variant(schema: &str, variant: &str) -> Option<&'static [u8]> {
+    GOLDEN
+        .iter()
+        .find(|g| g.schema == schema && g.variant == variant)
+        .map(|g| g.cdr)
+}
+
+/// Bytes of generator input behind each sample; enough to populate
+/// sequences and strings in every registered schema.
+const SAMPLE_INPUT: usize = 512;
+
+/// Generate a well-formed CDR message of `schema` from `seed`.
+///
+/// The same seed always yields the same bytes. Returns `None` when the
+/// schema has no registered definition.
+pub fn sample(schema: &str, seed: u64) -> Option<Vec<u8>> {
+    let mut rng = SplitMix64(seed);
+    let input: Vec<u8> = (0..SAMPLE_INPUT / 8)
+        .flat_map(|_| rng.next().to_le_bytes())
+        .collect();
+    arbitrary_cdr(schema, &mut Unstructured::new(&input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn golden_lookup() {
+        assert!(GOLDEN.len() > 60);
+        assert!(golden("std_msgs/msg/Header").is_some());
+        assert!(golden_variant("edgefirst_msgs/msg/CameraFrame", "i420").is_some());
+        assert!(golden("edgefirst_msgs/msg/Nope").is_none());
+    }
+
+    #[test]
+    fn sample_is_deterministic() {
+        let a = sample("sensor_msgs/msg/Image", 7).unwrap();
+        assert_eq!(a, sample("sensor_msgs/msg/Image", 7).unwrap());
+        assert_ne!(a, sample("sensor_msgs/msg/Image", 8).unwrap());
+        assert!(crate::sensor_msgs::Image::from_cdr(&a[..]).is_ok());
+        assert!(sample("nope/msg/Nope", 0).is_none());
+    }
+}
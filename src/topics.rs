@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Well-known EdgeFirst Perception topics and their schemas.
+//!
+//! EdgeFirst services publish on Zenoh key expressions under the `rt/`
+//! prefix (see ARCHITECTURE.md). [`TOPICS`] is the shared table of those
+//! topics and the schema each carries, so subscribers, bridges and
+//! recorders resolve them the same way. Lookups also accept the ROS 2
+//! topic name, without `rt` (`/camera/info`).
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgefirst_schemas::topics::{schema_for_topic, topics_for_schema};
+//!
+//! assert_eq!(schema_for_topic("rt/camera/info"), Some("sensor_msgs/msg/CameraInfo"));
+//! assert_eq!(schema_for_topic("/radar/cube"), Some("edgefirst_msgs/msg/RadarCube"));
+//! assert_eq!(topics_for_schema("sensor_msgs/msg/Imu"), ["rt/imu"]);
+//! ```
+
+use alloc::vec::Vec;
+
+/// `(topic, schema)` for every well-known EdgeFirst topic.
+pub const TOPICS: &[(&str, &str)] = &[
+    ("rt/camera/dma", "edgefirst_msgs/msg/DmaBuffer"),
+    ("rt/camera/h264", "foxglove_msgs/msg/CompressedVideo"),
+    ("rt/camera/info", "sensor_msgs/msg/CameraInfo"),
+    ("rt/camera/jpeg", "sensor_msgs/msg/CompressedImage"),
+    ("rt/gps", "sensor_msgs/msg/NavSatFix"),
+    ("rt/imu", "sensor_msgs/msg/Imu"),
+    ("rt/model/detect", "edgefirst_msgs/msg/Detect"),
+    ("rt/radar/clusters", "sensor_msgs/msg/PointCloud2"),
+    ("rt/radar/cube", "edgefirst_msgs/msg/RadarCube"),
+    ("rt/radar/info", "edgefirst_msgs/msg/RadarInfo"),
+    ("rt/radar/targets", "sensor_msgs/msg/PointCloud2"),
+    ("rt/tf_static", "geometry_msgs/msg/TransformStamped"),
+];
+
+/// Schema carried by a well-known topic, given as a Zenoh key
+/// (`rt/camera/info`) or a ROS 2 topic name (`/camera/info`).
+pub fn schema_for_topic(topic: &str) -> Option<&'static str> {
+    let key = topic
+        .strip_prefix("rt/")
+        .or_else(|| topic.strip_prefix('/'))?;
+    TOPICS
+        .iter()
+        .find(|(t, _)| t.strip_prefix("rt/") == Some(key))
+        .map(|(_, schema)| *schema)
+}
+
+/// Well-known topics (Zenoh keys) that carry `schema`, in table order.
+pub fn topics_for_schema(schema: &str) -> Vec<&'static str> {
+    TOPICS
+        .iter()
+        .filter(|(_, s)| *s == schema)
+        .map(|(topic, _)| *topic)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema_registry::is_supported;
+
+    #[test]
+    fn every_topic_maps_to_a_supported_schema() {
+        for (topic, schema) in TOPICS {
+            assert!(topic.starts_with("rt/"), "{}", topic);
+            assert!(is_supported(schema), "{} -> {}", topic, schema);
+            assert_eq!(schema_for_topic(topic), Some(*schema));
+            assert!(topics_for_schema(schema).contains(topic));
+        }
+    }
+
+    #[test]
+    fn lookups_in_both_directions() {
+        assert_eq!(
+            schema_for_topic("/camera/h264"),
+            Some("foxglove_msgs/msg/CompressedVideo")
+        );
+        assert_eq!(schema_for_topic("camera/h264"), None);
+        assert_eq!(schema_for_topic("rt/unknown"), None);
+        assert_eq!(
+            topics_for_schema("sensor_msgs/msg/PointCloud2"),
+            ["rt/radar/clusters", "rt/radar/targets"]
+        );
+        assert!(topics_for_schema("unknown_msgs/msg/Foo").is_empty());
+    }
+}
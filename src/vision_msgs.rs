@@ -0,0 +1,1096 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! ROS 2 `vision_msgs` message types, in the layout shipped since Humble
+//! (vision_msgs 4.x).
+//!
+//! CdrFixed (nested only): `Point2D`, `Pose2D`, `BoundingBox2D`,
+//! `BoundingBox3D`
+//!
+//! Buffer-backed: `ObjectHypothesisWithPose`, `Detection2D`,
+//! `Detection2DArray`, `Detection3D`
+//!
+//! Sequence elements decode to `ObjectHypothesisWithPoseView` and
+//! `Detection2DView`. [`Detect::to_detection2d_array`] bridges EdgeFirst
+//! detections to `Detection2DArray`, one `Detection2D` per box.
+
+use crate::builtin_interfaces::Time;
+use crate::cdr::*;
+use crate::edgefirst_msgs::Detect;
+use crate::geometry_msgs::{Point, Pose, PoseWithCovariance, Quaternion, Vector3};
+use crate::std_msgs::Header;
+use alloc::borrow::Cow;
+use alloc::{vec, vec::Vec};
+
+// ── CdrFixed types ──────────────────────────────────────────────────
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Point2D {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Position and rotation in an image plane. Unlike
+/// [`geometry_msgs::Pose2D`](crate::geometry_msgs::Pose2D) the position is
+/// a nested `Point2D`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Pose2D {
+    pub position: Point2D,
+    /// Rotation in radians, counter-clockwise.
+    pub theta: f64,
+}
+
+/// Image-space box: center pose and full extent, usually in pixels.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct BoundingBox2D {
+    pub center: Pose2D,
+    pub size_x: f64,
+    pub size_y: f64,
+}
+
+/// Oriented 3D box: center pose and full extent along each box axis.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct BoundingBox3D {
+    pub center: Pose,
+    pub size: Vector3,
+}
+
+impl CdrFixed for Point2D {
+    const CDR_SIZE: usize = 16;
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(Point2D {
+            x: cursor.read_f64()?,
+            y: cursor.read_f64()?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        writer.write_f64(self.x);
+        writer.write_f64(self.y);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        sizer.size_f64();
+        sizer.size_f64();
+    }
+}
+
+impl CdrFixed for Pose2D {
+    const CDR_SIZE: usize = 24; // Point2D(16) + theta(8)
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(Pose2D {
+            position: Point2D::read_cdr(cursor)?,
+            theta: cursor.read_f64()?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        self.position.write_cdr(writer);
+        writer.write_f64(self.theta);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        Point2D::size_cdr(sizer);
+        sizer.size_f64();
+    }
+}
+
+impl CdrFixed for BoundingBox2D {
+    const CDR_SIZE: usize = 40; // Pose2D(24) + size_x, size_y(16)
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(BoundingBox2D {
+            center: Pose2D::read_cdr(cursor)?,
+            size_x: cursor.read_f64()?,
+            size_y: cursor.read_f64()?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        self.center.write_cdr(writer);
+        writer.write_f64(self.size_x);
+        writer.write_f64(self.size_y);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        Pose2D::size_cdr(sizer);
+        sizer.size_f64();
+        sizer.size_f64();
+    }
+}
+
+impl CdrFixed for BoundingBox3D {
+    const CDR_SIZE: usize = 80; // Pose(56) + Vector3(24)
+    fn read_cdr(cursor: &mut CdrCursor<'_>) -> Result<Self, CdrError> {
+        Ok(BoundingBox3D {
+            center: Pose::read_cdr(cursor)?,
+            size: Vector3::read_cdr(cursor)?,
+        })
+    }
+    fn write_cdr(&self, writer: &mut CdrWriter<'_>) {
+        self.center.write_cdr(writer);
+        self.size.write_cdr(writer);
+    }
+    fn size_cdr(sizer: &mut CdrSizer) {
+        Pose::size_cdr(sizer);
+        Vector3::size_cdr(sizer);
+    }
+}
+
+const ORIGIN: Pose = Pose {
+    position: Point {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    },
+    orientation: Quaternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    },
+};
+
+const NO_POSE: PoseWithCovariance = PoseWithCovariance {
+    pose: ORIGIN,
+    covariance: [0.0; 36],
+};
+
+const NO_BOX_2D: BoundingBox2D = BoundingBox2D {
+    center: Pose2D {
+        position: Point2D { x: 0.0, y: 0.0 },
+        theta: 0.0,
+    },
+    size_x: 0.0,
+    size_y: 0.0,
+};
+
+const NO_BOX_3D: BoundingBox3D = BoundingBox3D {
+    center: ORIGIN,
+    size: Vector3 {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    },
+};
+
+// ── ObjectHypothesisWithPose helpers ────────────────────────────────
+
+/// An ObjectHypothesisWithPose element of a detection's `results`, with
+/// `hypothesis.class_id` and `hypothesis.score` flattened. Decoded views
+/// borrow `class_id` from the CDR buffer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ObjectHypothesisWithPoseView<'a> {
+    pub class_id: &'a str,
+    pub score: f64,
+    pub pose: PoseWithCovariance,
+}
+
+// Smallest element: empty class_id (4 + 1), score (8) and the pose.
+const MIN_HYPOTHESIS_SIZE: usize = 5 + 8 + PoseWithCovariance::CDR_SIZE;
+
+fn read_hypothesis_element<'a>(
+    c: &mut CdrCursor<'a>,
+) -> Result<ObjectHypothesisWithPoseView<'a>, CdrError> {
+    Ok(ObjectHypothesisWithPoseView {
+        class_id: c.read_string()?,
+        score: c.read_f64()?,
+        pose: PoseWithCovariance::read_cdr(c)?,
+    })
+}
+
+fn write_hypothesis_element(w: &mut CdrWriter<'_>, h: &ObjectHypothesisWithPoseView<'_>) {
+    w.write_string(h.class_id);
+    w.write_f64(h.score);
+    h.pose.write_cdr(w);
+}
+
+fn size_hypothesis_element(s: &mut CdrSizer, h: &ObjectHypothesisWithPoseView<'_>) {
+    s.size_string(h.class_id);
+    s.size_f64();
+    PoseWithCovariance::size_cdr(s);
+}
+
+/// Read a `results` sequence (count included) at the cursor.
+fn read_results<'a>(
+    c: &mut CdrCursor<'a>,
+) -> Result<Vec<ObjectHypothesisWithPoseView<'a>>, CdrError> {
+    let raw = c.read_u32()?;
+    let n = c.check_seq_count(raw, MIN_HYPOTHESIS_SIZE)?;
+    (0..n).map(|_| read_hypothesis_element(c)).collect()
+}
+
+fn write_results(w: &mut CdrWriter<'_>, results: &[ObjectHypothesisWithPoseView<'_>]) {
+    w.write_u32(results.len() as u32);
+    for h in results {
+        write_hypothesis_element(w, h);
+    }
+}
+
+fn size_results(s: &mut CdrSizer, results: &[ObjectHypothesisWithPoseView<'_>]) {
+    s.size_u32();
+    for h in results {
+        size_hypothesis_element(s, h);
+    }
+}
+
+// ── ObjectHypothesisWithPose<B> ─────────────────────────────────────
+//
+// CDR layout: hypothesis.class_id(string) → offsets[0],
+//   hypothesis.score(f64, 8-aligned), pose(PoseWithCovariance)
+
+pub struct ObjectHypothesisWithPose<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> ObjectHypothesisWithPose<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> ObjectHypothesisWithPose<C> {
+        ObjectHypothesisWithPose {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> ObjectHypothesisWithPose<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let mut c = CdrCursor::new(buf.as_ref())?;
+        let _ = c.read_string().field("hypothesis.class_id")?;
+        let o0 = c.offset();
+        c.read_f64().field("hypothesis.score")?;
+        PoseWithCovariance::read_cdr(&mut c).field("pose")?;
+        c.expect_end()?;
+        Ok(ObjectHypothesisWithPose { offsets: [o0], buf })
+    }
+
+    #[inline]
+    pub fn class_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE).0
+    }
+    #[inline]
+    pub fn score(&self) -> f64 {
+        rd_f64(self.buf.as_ref(), cdr_align(self.offsets[0], 8))
+    }
+    pub fn pose(&self) -> PoseWithCovariance {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), cdr_align(self.offsets[0], 8) + 8);
+        PoseWithCovariance::read_cdr(&mut c).expect("pose validated during from_cdr")
+    }
+    /// The message as a `results` element, e.g. for a detection builder.
+    pub fn view(&self) -> ObjectHypothesisWithPoseView<'_> {
+        ObjectHypothesisWithPoseView {
+            class_id: self.class_id(),
+            score: self.score(),
+            pose: self.pose(),
+        }
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl ObjectHypothesisWithPose<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `ObjectHypothesisWithPoseBuilder` with zero-valued
+    /// defaults and an identity pose.
+    pub fn builder<'a>() -> ObjectHypothesisWithPoseBuilder<'a> {
+        ObjectHypothesisWithPoseBuilder::new()
+    }
+}
+
+// ── ObjectHypothesisWithPoseBuilder<'a> ─────────────────────────────
+
+/// Builder for `ObjectHypothesisWithPose<Vec<u8>>` with buffer-reuse
+/// finalizers.
+pub struct ObjectHypothesisWithPoseBuilder<'a> {
+    class_id: Cow<'a, str>,
+    score: f64,
+    pose: PoseWithCovariance,
+}
+
+impl<'a> Default for ObjectHypothesisWithPoseBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            class_id: Cow::Borrowed(""),
+            score: 0.0,
+            pose: NO_POSE,
+        }
+    }
+}
+
+impl<'a> ObjectHypothesisWithPoseBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn class_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.class_id = s.into();
+        self
+    }
+    pub fn score(&mut self, v: f64) -> &mut Self {
+        self.score = v;
+        self
+    }
+    pub fn pose(&mut self, v: PoseWithCovariance) -> &mut Self {
+        self.pose = v;
+        self
+    }
+
+    fn view(&self) -> ObjectHypothesisWithPoseView<'_> {
+        ObjectHypothesisWithPoseView {
+            class_id: &self.class_id,
+            score: self.score,
+            pose: self.pose,
+        }
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        size_hypothesis_element(&mut s, &self.view());
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        write_hypothesis_element(&mut w, &self.view());
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<ObjectHypothesisWithPose<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        ObjectHypothesisWithPose::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── Detection2D<B> / Detection3D<B> ─────────────────────────────────
+//
+// CDR layout: Header, then offsets[0] (byte index of the results seq
+//   count u32, 4-aligned), uint32 count + ObjectHypothesisWithPose[]
+//   results, bbox (8-aligned) → offsets[1], id(string) → offsets[2]
+//
+// The two differ only in the bounding box type, so one macro generates
+// each view, element helpers, message and builder.
+
+macro_rules! detection {
+    (
+        $(#[$doc:meta])*
+        $name:ident, $view:ident, $builder:ident, $bbox:ty, $no_box:expr,
+        $read:ident, $write:ident, $size:ident
+    ) => {
+        #[doc = concat!("A `", stringify!($name), "` as a value, e.g. an element of an")]
+        /// array message. Decoded views borrow the strings from the CDR
+        /// buffer and own `results`; builders borrow both.
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $view<'a> {
+            pub stamp: Time,
+            pub frame_id: &'a str,
+            pub results: Cow<'a, [ObjectHypothesisWithPoseView<'a>]>,
+            pub bbox: $bbox,
+            pub id: &'a str,
+        }
+
+        fn $read<'a>(c: &mut CdrCursor<'a>) -> Result<$view<'a>, CdrError> {
+            let stamp = Time::read_cdr(c)?;
+            let frame_id = c.read_string()?;
+            let results = read_results(c).field("results")?;
+            let bbox = <$bbox>::read_cdr(c).field("bbox")?;
+            let id = c.read_string().field("id")?;
+            Ok($view {
+                stamp,
+                frame_id,
+                results: Cow::Owned(results),
+                bbox,
+                id,
+            })
+        }
+
+        fn $write(w: &mut CdrWriter<'_>, d: &$view<'_>) {
+            d.stamp.write_cdr(w);
+            w.write_string(d.frame_id);
+            write_results(w, &d.results);
+            d.bbox.write_cdr(w);
+            w.write_string(d.id);
+        }
+
+        fn $size(s: &mut CdrSizer, d: &$view<'_>) {
+            Time::size_cdr(s);
+            s.size_string(d.frame_id);
+            size_results(s, &d.results);
+            <$bbox>::size_cdr(s);
+            s.size_string(d.id);
+        }
+
+        $(#[$doc])*
+        pub struct $name<B> {
+            buf: B,
+            offsets: [usize; 3],
+        }
+
+        impl<B> $name<B> {
+            /// Convert the buffer type without re-parsing the offset table.
+            #[inline]
+            pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> $name<C> {
+                $name {
+                    buf: f(self.buf),
+                    offsets: self.offsets,
+                }
+            }
+        }
+
+        impl<B: AsRef<[u8]>> $name<B> {
+            pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+                let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+                let pre = header.end_offset();
+                let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
+                c.align(4);
+                let o0 = c.offset();
+                read_results(&mut c).field("results")?;
+                c.align(8);
+                let o1 = c.offset();
+                <$bbox>::read_cdr(&mut c).field("bbox")?;
+                let o2 = c.offset();
+                let _ = c.read_string().field("id")?;
+                c.expect_end()?;
+                Ok($name {
+                    offsets: [o0, o1, o2],
+                    buf,
+                })
+            }
+
+            pub fn header(&self) -> Header<&[u8]> {
+                Header::from_cdr_prefix(self.buf.as_ref())
+                    .expect("header bytes validated during from_cdr")
+            }
+            pub fn stamp(&self) -> Time {
+                rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+            }
+            pub fn frame_id(&self) -> &str {
+                rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+            }
+            pub fn results_len(&self) -> u32 {
+                rd_u32(self.buf.as_ref(), self.offsets[0])
+            }
+            /// Class hypotheses, most likely first by convention.
+            pub fn results(&self) -> Vec<ObjectHypothesisWithPoseView<'_>> {
+                let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0]);
+                read_results(&mut c).expect("results validated during from_cdr")
+            }
+            pub fn bbox(&self) -> $bbox {
+                let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[1]);
+                <$bbox>::read_cdr(&mut c).expect("bbox validated during from_cdr")
+            }
+            /// Tracking ID, empty when the detection is not tracked.
+            pub fn id(&self) -> &str {
+                rd_string(self.buf.as_ref(), self.offsets[2]).0
+            }
+            /// The message as a value, e.g. an element for an array builder.
+            pub fn view(&self) -> $view<'_> {
+                let mut c = CdrCursor::resume(self.buf.as_ref(), CDR_HEADER_SIZE);
+                $read(&mut c).expect("detection validated during from_cdr")
+            }
+            #[inline]
+            pub fn as_cdr(&self) -> &[u8] {
+                self.buf.as_ref()
+            }
+            pub fn to_cdr(&self) -> Vec<u8> {
+                self.buf.as_ref().to_vec()
+            }
+        }
+
+        impl $name<Vec<u8>> {
+            pub fn into_cdr(self) -> Vec<u8> {
+                self.buf
+            }
+
+            #[doc = concat!("Start a new `", stringify!($builder), "` with zero-valued defaults.")]
+            pub fn builder<'a>() -> $builder<'a> {
+                $builder::new()
+            }
+        }
+
+        impl<B: AsRef<[u8]> + AsMut<[u8]>> $name<B> {
+            pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+                let b = self.buf.as_mut();
+                wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+                wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+            }
+        }
+
+        #[doc = concat!("Builder for `", stringify!($name), "<Vec<u8>>` with buffer-reuse finalizers.")]
+        ///
+        /// `results` is borrowed for zero-copy input.
+        pub struct $builder<'a> {
+            stamp: Time,
+            frame_id: Cow<'a, str>,
+            results: &'a [ObjectHypothesisWithPoseView<'a>],
+            bbox: $bbox,
+            id: Cow<'a, str>,
+        }
+
+        impl<'a> Default for $builder<'a> {
+            fn default() -> Self {
+                Self {
+                    stamp: Time { sec: 0, nanosec: 0 },
+                    frame_id: Cow::Borrowed(""),
+                    results: &[],
+                    bbox: $no_box,
+                    id: Cow::Borrowed(""),
+                }
+            }
+        }
+
+        impl<'a> $builder<'a> {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            pub fn stamp(&mut self, t: Time) -> &mut Self {
+                self.stamp = t;
+                self
+            }
+            pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+                self.frame_id = s.into();
+                self
+            }
+            pub fn results(&mut self, v: &'a [ObjectHypothesisWithPoseView<'a>]) -> &mut Self {
+                self.results = v;
+                self
+            }
+            pub fn bbox(&mut self, v: $bbox) -> &mut Self {
+                self.bbox = v;
+                self
+            }
+            pub fn id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+                self.id = s.into();
+                self
+            }
+
+            fn view(&self) -> $view<'_> {
+                $view {
+                    stamp: self.stamp,
+                    frame_id: &self.frame_id,
+                    results: Cow::Borrowed(self.results),
+                    bbox: self.bbox,
+                    id: &self.id,
+                }
+            }
+
+            /// Exact CDR size in bytes (header included) that `build()` would produce.
+            pub fn cdr_size(&self) -> usize {
+                let mut s = CdrSizer::new();
+                $size(&mut s, &self.view());
+                s.size()
+            }
+
+            fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+                let mut w = CdrWriter::with_endianness(buf, endianness)?;
+                $write(&mut w, &self.view());
+                w.finish()
+            }
+
+            pub fn build(&self) -> Result<$name<Vec<u8>>, CdrError> {
+                let mut buf = vec![0u8; self.cdr_size()];
+                self.write_into(&mut buf, Endianness::Little)?;
+                $name::from_cdr(buf)
+            }
+
+            pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+                buf.resize(self.cdr_size(), 0);
+                self.write_into(buf, Endianness::Little)
+            }
+
+            pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+                let need = self.cdr_size();
+                if buf.len() < need {
+                    return Err(CdrError::BufferTooShort {
+                        need,
+                        have: buf.len(),
+                    });
+                }
+                self.write_into(&mut buf[..need], Endianness::Little)?;
+                Ok(need)
+            }
+        }
+    };
+}
+
+detection!(
+    /// A detection in an image: class hypotheses and a pixel-space box.
+    Detection2D, Detection2DView, Detection2DBuilder, BoundingBox2D, NO_BOX_2D,
+    read_detection2d_element, write_detection2d_element, size_detection2d_element
+);
+
+detection!(
+    /// A detection in 3D: class hypotheses and an oriented box.
+    Detection3D, Detection3DView, Detection3DBuilder, BoundingBox3D, NO_BOX_3D,
+    read_detection3d_element, write_detection3d_element, size_detection3d_element
+);
+
+// ── Detection2DArray<B> ─────────────────────────────────────────────
+//
+// CDR layout: Header, then offsets[0] (byte index of the detections seq
+//   count u32, 4-aligned), uint32 count + Detection2D[] detections
+
+// Smallest element: Time (8), empty frame_id (5), results count (4), the
+// box (40) and an empty id (5).
+const MIN_DETECTION2D_SIZE: usize = 8 + 5 + 4 + BoundingBox2D::CDR_SIZE + 5;
+
+pub struct Detection2DArray<B> {
+    buf: B,
+    offsets: [usize; 1],
+}
+
+impl<B> Detection2DArray<B> {
+    /// Convert the buffer type without re-parsing the offset table.
+    #[inline]
+    pub fn map_buffer<C>(self, f: impl FnOnce(B) -> C) -> Detection2DArray<C> {
+        Detection2DArray {
+            buf: f(self.buf),
+            offsets: self.offsets,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Detection2DArray<B> {
+    pub fn from_cdr(buf: B) -> Result<Self, CdrError> {
+        let header = Header::<&[u8]>::from_cdr_prefix(buf.as_ref())?;
+        let pre = header.end_offset();
+        let mut c = CdrCursor::resume_checked(buf.as_ref(), pre);
+        c.align(4);
+        let o0 = c.offset();
+        let raw = c.read_u32()?;
+        let n = c
+            .check_seq_count(raw, MIN_DETECTION2D_SIZE)
+            .field("detections")?;
+        for _ in 0..n {
+            read_detection2d_element(&mut c).field("detections")?;
+        }
+        c.expect_end()?;
+        Ok(Detection2DArray { offsets: [o0], buf })
+    }
+
+    pub fn header(&self) -> Header<&[u8]> {
+        Header::from_cdr_prefix(self.buf.as_ref()).expect("header bytes validated during from_cdr")
+    }
+    pub fn stamp(&self) -> Time {
+        rd_time(self.buf.as_ref(), CDR_HEADER_SIZE)
+    }
+    pub fn frame_id(&self) -> &str {
+        rd_string(self.buf.as_ref(), CDR_HEADER_SIZE + 8).0
+    }
+    pub fn detections_len(&self) -> u32 {
+        rd_u32(self.buf.as_ref(), self.offsets[0])
+    }
+    pub fn detections(&self) -> Vec<Detection2DView<'_>> {
+        let mut c = CdrCursor::resume(self.buf.as_ref(), self.offsets[0] + 4);
+        (0..self.detections_len())
+            .map(|_| {
+                read_detection2d_element(&mut c)
+                    .expect("detection elements validated during from_cdr")
+            })
+            .collect()
+    }
+    #[inline]
+    pub fn as_cdr(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+    pub fn to_cdr(&self) -> Vec<u8> {
+        self.buf.as_ref().to_vec()
+    }
+}
+
+impl Detection2DArray<Vec<u8>> {
+    pub fn into_cdr(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Start a new `Detection2DArrayBuilder` with zero-valued defaults.
+    pub fn builder<'a>() -> Detection2DArrayBuilder<'a> {
+        Detection2DArrayBuilder::new()
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Detection2DArray<B> {
+    pub fn set_stamp(&mut self, t: Time) -> Result<(), CdrError> {
+        let b = self.buf.as_mut();
+        wr_i32(b, CDR_HEADER_SIZE, t.sec)?;
+        wr_u32(b, CDR_HEADER_SIZE + 4, t.nanosec)
+    }
+}
+
+// ── Detection2DArrayBuilder<'a> ─────────────────────────────────────
+
+/// Builder for `Detection2DArray<Vec<u8>>` with buffer-reuse finalizers.
+///
+/// `detections` is borrowed for zero-copy input.
+pub struct Detection2DArrayBuilder<'a> {
+    stamp: Time,
+    frame_id: Cow<'a, str>,
+    detections: &'a [Detection2DView<'a>],
+}
+
+impl<'a> Default for Detection2DArrayBuilder<'a> {
+    fn default() -> Self {
+        Self {
+            stamp: Time { sec: 0, nanosec: 0 },
+            frame_id: Cow::Borrowed(""),
+            detections: &[],
+        }
+    }
+}
+
+impl<'a> Detection2DArrayBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stamp(&mut self, t: Time) -> &mut Self {
+        self.stamp = t;
+        self
+    }
+    pub fn frame_id(&mut self, s: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.frame_id = s.into();
+        self
+    }
+    pub fn detections(&mut self, v: &'a [Detection2DView<'a>]) -> &mut Self {
+        self.detections = v;
+        self
+    }
+
+    /// Exact CDR size in bytes (header included) that `build()` would produce.
+    pub fn cdr_size(&self) -> usize {
+        let mut s = CdrSizer::new();
+        Time::size_cdr(&mut s);
+        s.size_string(&self.frame_id);
+        s.size_u32();
+        for d in self.detections {
+            size_detection2d_element(&mut s, d);
+        }
+        s.size()
+    }
+
+    fn write_into(&self, buf: &mut [u8], endianness: Endianness) -> Result<(), CdrError> {
+        let mut w = CdrWriter::with_endianness(buf, endianness)?;
+        self.stamp.write_cdr(&mut w);
+        w.write_string(&self.frame_id);
+        w.write_u32(self.detections.len() as u32);
+        for d in self.detections {
+            write_detection2d_element(&mut w, d);
+        }
+        w.finish()
+    }
+
+    pub fn build(&self) -> Result<Detection2DArray<Vec<u8>>, CdrError> {
+        let mut buf = vec![0u8; self.cdr_size()];
+        self.write_into(&mut buf, Endianness::Little)?;
+        Detection2DArray::from_cdr(buf)
+    }
+
+    pub fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Result<(), CdrError> {
+        buf.resize(self.cdr_size(), 0);
+        self.write_into(buf, Endianness::Little)
+    }
+
+    pub fn encode_into_slice(&self, buf: &mut [u8]) -> Result<usize, CdrError> {
+        let need = self.cdr_size();
+        if buf.len() < need {
+            return Err(CdrError::BufferTooShort {
+                need,
+                have: buf.len(),
+            });
+        }
+        self.write_into(&mut buf[..need], Endianness::Little)?;
+        Ok(need)
+    }
+}
+
+// ── Detect → Detection2DArray ───────────────────────────────────────
+
+impl<B: AsRef<[u8]>> Detect<B> {
+    /// The boxes as a `Detection2DArray` with the same stamp and frame_id,
+    /// one `Detection2D` per box: `label` and `score` become its single
+    /// hypothesis (with an identity pose), the track ID its `id`, and the
+    /// normalized box is scaled by `width` × `height`. Pass the input
+    /// image size for pixel coordinates, or 1 × 1 to keep them normalized.
+    ///
+    /// Fails only if the encoded array exceeds the current [`DecodeLimits`].
+    ///
+    /// [`DecodeLimits`]: crate::cdr::DecodeLimits
+    pub fn to_detection2d_array(
+        &self,
+        width: u32,
+        height: u32,
+    ) -> Result<Detection2DArray<Vec<u8>>, CdrError> {
+        let (sx, sy) = (width as f64, height as f64);
+        let (stamp, frame_id) = (self.stamp(), self.frame_id());
+        let boxes = self.boxes();
+        let results: Vec<[ObjectHypothesisWithPoseView<'_>; 1]> = boxes
+            .iter()
+            .map(|b| {
+                [ObjectHypothesisWithPoseView {
+                    class_id: b.label,
+                    score: b.score as f64,
+                    pose: NO_POSE,
+                }]
+            })
+            .collect();
+        let detections: Vec<Detection2DView<'_>> = boxes
+            .iter()
+            .zip(&results)
+            .map(|(b, result)| Detection2DView {
+                stamp,
+                frame_id,
+                results: Cow::Borrowed(result),
+                bbox: BoundingBox2D {
+                    center: Pose2D {
+                        position: Point2D {
+                            x: b.center_x as f64 * sx,
+                            y: b.center_y as f64 * sy,
+                        },
+                        theta: 0.0,
+                    },
+                    size_x: b.width as f64 * sx,
+                    size_y: b.height as f64 * sy,
+                },
+                id: b.track_id,
+            })
+            .collect();
+        Detection2DArray::builder()
+            .stamp(stamp)
+            .frame_id(frame_id)
+            .detections(&detections)
+            .build()
+    }
+}
+
+// ── Registry ────────────────────────────────────────────────────────
+
+/// Check if a type name is supported by this module.
+pub fn is_type_supported(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "Detection2D" | "Detection2DArray" | "Detection3D" | "ObjectHypothesisWithPose"
+    )
+}
+
+/// List all type schema names in this module.
+pub fn list_types() -> &'static [&'static str] {
+    &[
+        "vision_msgs/msg/Detection2D",
+        "vision_msgs/msg/Detection2DArray",
+        "vision_msgs/msg/Detection3D",
+        "vision_msgs/msg/ObjectHypothesisWithPose",
+    ]
+}
+
+/// ROS 2 message definition (`.msg` field list) of a type in this module,
+/// covering the supported types and the nested types they embed.
+pub fn definition(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "BoundingBox2D" => "Pose2D center\nfloat64 size_x\nfloat64 size_y\n",
+        "BoundingBox3D" => "geometry_msgs/Pose center\ngeometry_msgs/Vector3 size\n",
+        "Detection2D" => {
+            "\
+std_msgs/Header header
+ObjectHypothesisWithPose[] results
+BoundingBox2D bbox
+string id
+"
+        }
+        "Detection2DArray" => "std_msgs/Header header\nDetection2D[] detections\n",
+        "Detection3D" => {
+            "\
+std_msgs/Header header
+ObjectHypothesisWithPose[] results
+BoundingBox3D bbox
+string id
+"
+        }
+        "ObjectHypothesis" => "string class_id\nfloat64 score\n",
+        "ObjectHypothesisWithPose" => {
+            "ObjectHypothesis hypothesis\ngeometry_msgs/PoseWithCovariance pose\n"
+        }
+        "Point2D" => "float64 x\nfloat64 y\n",
+        "Pose2D" => "Point2D position\nfloat64 theta\n",
+        _ => return None,
+    })
+}
+
+// CdrEncode implementations
+crate::cdr::impl_cdr_encode!(
+    ObjectHypothesisWithPoseBuilder,
+    Detection2DBuilder,
+    Detection2DArrayBuilder,
+    Detection3DBuilder,
+);
+
+// HeapSize implementations
+crate::heap_size::impl_heap_size!(
+    ObjectHypothesisWithPose,
+    Detection2D,
+    Detection2DArray,
+    Detection3D,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypothesis(class_id: &str, score: f64) -> ObjectHypothesisWithPoseView<'_> {
+        let mut pose = NO_POSE;
+        pose.pose.position.z = 2.5;
+        pose.covariance[0] = 0.1;
+        ObjectHypothesisWithPoseView {
+            class_id,
+            score,
+            pose,
+        }
+    }
+
+    #[test]
+    fn detection_roundtrip() {
+        let h = ObjectHypothesisWithPose::builder()
+            .class_id("person")
+            .score(0.75)
+            .pose(hypothesis("", 0.0).pose)
+            .build()
+            .unwrap();
+        let h = ObjectHypothesisWithPose::from_cdr(h.to_cdr()).unwrap();
+        assert_eq!(h.view(), hypothesis("person", 0.75));
+
+        let results = [hypothesis("car", 0.9), hypothesis("truck", 0.25)];
+        let bbox = BoundingBox2D {
+            center: Pose2D {
+                position: Point2D { x: 320.0, y: 240.0 },
+                theta: 0.5,
+            },
+            size_x: 64.0,
+            size_y: 48.0,
+        };
+        let d = Detection2D::builder()
+            .stamp(Time::new(5, 6))
+            .frame_id("camera")
+            .results(&results)
+            .bbox(bbox)
+            .id("track-7")
+            .build()
+            .unwrap();
+        let d = Detection2D::from_cdr(d.to_cdr()).unwrap();
+        assert_eq!(d.stamp(), Time::new(5, 6));
+        assert_eq!(d.frame_id(), "camera");
+        assert_eq!(d.results_len(), 2);
+        assert_eq!(d.results(), results);
+        assert_eq!(d.bbox(), bbox);
+        assert_eq!(d.id(), "track-7");
+
+        let array = Detection2DArray::builder()
+            .frame_id("camera")
+            .detections(&[d.view(), Detection2D::builder().build().unwrap().view()])
+            .build()
+            .unwrap();
+        let array = Detection2DArray::from_cdr(array.to_cdr()).unwrap();
+        assert_eq!(array.detections_len(), 2);
+        assert_eq!(array.detections()[0], d.view());
+        assert!(array.detections()[1].results.is_empty());
+
+        let bbox = BoundingBox3D {
+            center: ORIGIN,
+            size: Vector3 {
+                x: 4.0,
+                y: 2.0,
+                z: 1.5,
+            },
+        };
+        let d3 = Detection3D::builder()
+            .frame_id("lidar")
+            .results(&results[..1])
+            .bbox(bbox)
+            .build()
+            .unwrap();
+        let d3 = Detection3D::from_cdr(d3.to_cdr()).unwrap();
+        assert_eq!(d3.results(), results[..1]);
+        assert_eq!(d3.bbox(), bbox);
+        assert_eq!(d3.id(), "");
+
+        let cdr = d.to_cdr();
+        assert!(Detection2D::from_cdr(&cdr[..cdr.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn detect_to_detection2d_array() {
+        use crate::edgefirst_msgs::DetectBoxView;
+
+        let boxes = [DetectBoxView {
+            center_x: 0.5,
+            center_y: 0.25,
+            width: 0.125,
+            height: 0.5,
+            label: "person",
+            score: 0.5,
+            distance: 0.0,
+            speed: 0.0,
+            track_id: "t1",
+            track_lifetime: 3,
+            track_created: Time::new(0, 0),
+        }];
+        let detect = Detect::builder()
+            .stamp(Time::new(1, 2))
+            .frame_id("camera")
+            .boxes(&boxes)
+            .build()
+            .unwrap();
+
+        let array = detect.to_detection2d_array(640, 480).unwrap();
+        assert_eq!(array.stamp(), Time::new(1, 2));
+        assert_eq!(array.frame_id(), "camera");
+        let detections = array.detections();
+        assert_eq!(detections.len(), 1);
+        let d = &detections[0];
+        assert_eq!(
+            (d.stamp, d.frame_id, d.id),
+            (Time::new(1, 2), "camera", "t1")
+        );
+        assert_eq!(d.results[0].class_id, "person");
+        assert_eq!(d.results[0].score, 0.5);
+        assert_eq!(d.bbox.center.position, Point2D { x: 320.0, y: 120.0 });
+        assert_eq!((d.bbox.size_x, d.bbox.size_y), (80.0, 240.0));
+
+        let normalized = detect.to_detection2d_array(1, 1).unwrap();
+        assert_eq!(normalized.detections()[0].bbox.size_y, 0.5);
+
+        // Limits tighter than the output report an error rather than panic.
+        #[cfg(feature = "std")]
+        {
+            let tight = crate::cdr::DecodeLimits {
+                max_string_len: 4,
+                ..crate::cdr::DecodeLimits::DEFAULT
+            };
+            let result = tight.scoped(|| detect.to_detection2d_array(1, 1));
+            assert!(matches!(
+                result.map(|_| ()).unwrap_err().root(),
+                CdrError::LimitExceeded { .. }
+            ));
+        }
+    }
+}
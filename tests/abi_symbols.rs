@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! ABI symbol diff for the C API.
+//!
+//! `include/edgefirst/abi-symbols.txt` is the committed list of symbols the
+//! C header declares. Any difference between it and `schemas.h` fails here,
+//! which forces every ABI change through review together with the matching
+//! `EDGEFIRST_ABI_VERSION_*` bump (see "ABI Versioning" in CAPI.md):
+//!
+//! - a symbol missing from the header is a removal → bump the major;
+//! - a symbol new in the header is an addition → bump the minor.
+//!
+//! The test also pins `edgefirst_abi_version()` and the embedded ABI tag to
+//! the header macros, `edgefirst_schemas_version()` to the crate version,
+//! and checks that every `#[no_mangle]` function written
+//! out in `src/ffi.rs` is declared in the header.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+// Nothing else references the crate; link it for the FFI symbols.
+extern crate edgefirst_schemas;
+
+extern "C" {
+    fn edgefirst_abi_version() -> u32;
+    fn edgefirst_schemas_version() -> *const std::os::raw::c_char;
+    fn edgefirst_schemas_has_feature(name: *const std::os::raw::c_char) -> i32;
+    static EDGEFIRST_ABI_TAG: [u8; 18];
+}
+
+fn read(rel: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(rel);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+/// Strip comments, attributes, preprocessor lines and the `extern "C"` wrapper.
+fn preprocess(src: &str) -> String {
+    let mut out = String::with_capacity(src.len());
+    let mut rest = src;
+    while let Some(i) = rest.find("/*") {
+        out.push_str(&rest[..i]);
+        let end = rest[i..].find("*/").expect("unterminated comment");
+        rest = &rest[i + end + 2..];
+    }
+    out.push_str(rest);
+
+    // `__attribute__((deprecated("...; ...")))` may hide a `;` in a string.
+    while let Some(i) = out.find("__attribute__") {
+        let mut depth = 0;
+        let mut end = i;
+        for (j, c) in out[i..].char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = i + j + 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        out.replace_range(i..end, "");
+    }
+
+    let mut lines = Vec::new();
+    let mut in_cplusplus = false;
+    let mut continued = false;
+    for line in out.lines() {
+        let line = line.split("//").next().unwrap();
+        let trimmed = line.trim();
+        if trimmed.starts_with("#ifdef __cplusplus") {
+            in_cplusplus = true;
+        } else if in_cplusplus {
+            in_cplusplus = !trimmed.starts_with("#endif");
+        } else if continued || trimmed.starts_with('#') {
+            continued = trimmed.ends_with('\\');
+        } else {
+            lines.push(line);
+        }
+    }
+    lines.join("\n")
+}
+
+fn identifier_before(s: &str, delim: char) -> Option<String> {
+    let head = s[..s.find(delim)?].trim_end();
+    let start = head
+        .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .map_or(0, |i| i + 1);
+    Some(head[start..].to_string()).filter(|name| !name.is_empty())
+}
+
+/// Names of the functions and variables the header exports from the library.
+fn header_symbols(header: &str) -> BTreeSet<String> {
+    let src = preprocess(header);
+    let mut symbols = BTreeSet::new();
+    let mut stmt = String::new();
+    let mut depth = 0usize;
+    for c in src.chars() {
+        stmt.push(c);
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                // `static inline` helpers are compiled into the caller.
+                if depth == 0 && stmt.trim_start().starts_with("static ") {
+                    stmt.clear();
+                }
+            }
+            ';' if depth == 0 => {
+                let s = stmt.trim();
+                if s.starts_with("extern ") {
+                    symbols.extend(identifier_before(s, '['));
+                } else if !s.starts_with("typedef") && !s.starts_with("struct") {
+                    symbols.extend(identifier_before(s, '('));
+                }
+                stmt.clear();
+            }
+            _ => {}
+        }
+    }
+    symbols
+}
+
+fn header_define(header: &str, name: &str) -> u32 {
+    let prefix = format!("#define {name} ");
+    header
+        .lines()
+        .find_map(|l| l.strip_prefix(&prefix))
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or_else(|| panic!("schemas.h: missing {name}"))
+}
+
+#[test]
+fn header_matches_committed_symbol_list() {
+    let declared = header_symbols(&read("include/edgefirst/schemas.h"));
+    let committed: BTreeSet<String> = read("include/edgefirst/abi-symbols.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(String::from)
+        .collect();
+
+    let removed: Vec<_> = committed.difference(&declared).collect();
+    let added: Vec<_> = declared.difference(&committed).collect();
+    assert!(
+        removed.is_empty(),
+        "symbols removed from schemas.h: {removed:?}\n\
+         removing a symbol breaks the ABI: bump EDGEFIRST_ABI_VERSION_MAJOR \
+         and drop them from abi-symbols.txt"
+    );
+    assert!(
+        added.is_empty(),
+        "symbols added to schemas.h: {added:?}\n\
+         list them in abi-symbols.txt and bump EDGEFIRST_ABI_VERSION_MINOR"
+    );
+}
+
+#[test]
+fn ffi_exports_are_declared() {
+    let declared = header_symbols(&read("include/edgefirst/schemas.h"));
+    let ffi = read("src/ffi.rs");
+    let mut undeclared = Vec::new();
+    let mut lines = ffi.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line != "#[no_mangle]" {
+            continue;
+        }
+        let Some(item) = lines.find(|l| !l.starts_with("#[")) else {
+            break;
+        };
+        let name = if let Some(rest) = item.split(" fn ").nth(1) {
+            identifier_before(rest, '(')
+        } else if let Some(rest) = item.strip_prefix("pub static ") {
+            identifier_before(rest, ':')
+        } else {
+            None
+        };
+        // Macro-generated exports (`fn $name(`) are covered by the C tests.
+        let generated = item.contains(" fn $");
+        if let Some(name) = name.filter(|n| !generated && !declared.contains(n)) {
+            undeclared.push(name);
+        }
+    }
+    assert!(
+        undeclared.is_empty(),
+        "exported from src/ffi.rs but not declared in schemas.h: {undeclared:?}"
+    );
+}
+
+#[test]
+fn abi_version_matches_header() {
+    let header = read("include/edgefirst/schemas.h");
+    let major = header_define(&header, "EDGEFIRST_ABI_VERSION_MAJOR");
+    let minor = header_define(&header, "EDGEFIRST_ABI_VERSION_MINOR");
+
+    let version = unsafe { edgefirst_abi_version() };
+    assert_eq!((version >> 16, version & 0xffff), (major, minor));
+    assert_eq!(
+        major.to_string(),
+        env!("CARGO_PKG_VERSION_MAJOR"),
+        "the ABI major tracks the SONAME major"
+    );
+
+    let tag = unsafe { &EDGEFIRST_ABI_TAG };
+    let tag = std::ffi::CStr::from_bytes_until_nul(tag).unwrap();
+    assert_eq!(
+        tag.to_str().unwrap(),
+        format!("EDGEFIRST_ABI {major}.{minor}")
+    );
+}
+
+#[test]
+fn version_and_features_match_the_build() {
+    let version = unsafe { std::ffi::CStr::from_ptr(edgefirst_schemas_version()) };
+    assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+
+    let has = |name: &std::ffi::CStr| unsafe { edgefirst_schemas_has_feature(name.as_ptr()) };
+    assert_eq!(has(c"json"), cfg!(feature = "json") as i32);
+    assert_eq!(has(c"checked"), cfg!(feature = "ffi-checked") as i32);
+    assert_eq!(has(c"xcdr2"), 0);
+    assert_eq!(
+        unsafe { edgefirst_schemas_has_feature(std::ptr::null()) },
+        -1
+    );
+}
+
+#[test]
+fn schema_info_ffi_prefixes_name_exported_functions() {
+    let committed = read("include/edgefirst/abi-symbols.txt");
+    for info in edgefirst_schemas::schema_registry::list_schema_info() {
+        let Some(prefix) = info.ffi_prefix else {
+            continue;
+        };
+        let prefix = format!("{prefix}_");
+        assert!(
+            committed.lines().any(|l| l.starts_with(&prefix)),
+            "{}: no {}* symbol",
+            info.name,
+            prefix
+        );
+    }
+}
@@ -9,6 +9,8 @@
 //! contract: any divergence from the Rust builder is a regression.
 
 #![allow(non_camel_case_types)]
+// The C-POD descriptors and their setters are compiled out in opaque mode.
+#![cfg_attr(feature = "ffi-opaque", allow(dead_code, unused_imports))]
 
 use edgefirst_schemas::builtin_interfaces::{Duration, Time};
 use edgefirst_schemas::edgefirst_msgs::{self, Date, DetectBoxView, MaskView};
@@ -201,12 +203,28 @@ extern "C" {
     fn ros_image_builder_set_is_bigendian(b: *mut ros_image_builder_t, v: u8);
     fn ros_image_builder_set_step(b: *mut ros_image_builder_t, v: u32);
     fn ros_image_builder_set_data(b: *mut ros_image_builder_t, data: *const u8, len: usize) -> i32;
+    fn ros_image_builder_set_data_owned(
+        b: *mut ros_image_builder_t,
+        data: *const u8,
+        len: usize,
+        free_fn: Option<unsafe extern "C" fn(*mut std::ffi::c_void)>,
+        ctx: *mut std::ffi::c_void,
+    ) -> i32;
     fn ros_image_builder_encode_into(
         b: *mut ros_image_builder_t,
         buf: *mut u8,
         cap: usize,
         out_len: *mut usize,
     ) -> i32;
+    fn ros_image_builder_encode_parts(
+        b: *mut ros_image_builder_t,
+        head: *mut u8,
+        head_cap: usize,
+        head_len: *mut usize,
+        tail: *mut u8,
+        tail_cap: usize,
+        tail_len: *mut usize,
+    ) -> i32;
 
     // FluidPressure
     fn ros_fluid_pressure_builder_new() -> *mut ros_fluid_pressure_builder_t;
@@ -377,6 +395,15 @@ extern "C" {
         cap: usize,
         out_len: *mut usize,
     ) -> i32;
+    fn ros_point_cloud2_builder_encode_parts(
+        b: *mut ros_point_cloud2_builder_t,
+        head: *mut u8,
+        head_cap: usize,
+        head_len: *mut usize,
+        tail: *mut u8,
+        tail_cap: usize,
+        tail_len: *mut usize,
+    ) -> i32;
 
     // CameraInfo
     fn ros_camera_info_builder_new() -> *mut ros_camera_info_builder_t;
@@ -839,6 +866,15 @@ extern "C" {
         cap: usize,
         out_len: *mut usize,
     ) -> i32;
+    fn ros_foxglove_compressed_video_builder_encode_parts(
+        b: *mut ros_foxglove_compressed_video_builder_t,
+        head: *mut u8,
+        head_cap: usize,
+        head_len: *mut usize,
+        tail: *mut u8,
+        tail_cap: usize,
+        tail_len: *mut usize,
+    ) -> i32;
 
     // FoxgloveTextAnnotation
     fn ros_foxglove_text_annotation_builder_new() -> *mut ros_foxglove_text_annotation_builder_t;
@@ -1265,6 +1301,7 @@ fn ros_point_field_builder_encode_into_matches_rust_builder() {
 }
 
 #[test]
+#[cfg(not(feature = "ffi-opaque"))]
 fn ros_point_cloud2_builder_encode_into_matches_rust_builder() {
     unsafe {
         let b = ros_point_cloud2_builder_new();
@@ -1747,6 +1784,7 @@ fn ros_detect_box_builder_encode_into_matches_rust_builder() {
 }
 
 #[test]
+#[cfg(not(feature = "ffi-opaque"))]
 fn ros_detect_builder_encode_into_matches_rust_builder() {
     unsafe {
         let b = ros_detect_builder_new();
@@ -1843,6 +1881,7 @@ fn ros_detect_builder_encode_into_matches_rust_builder() {
 }
 
 #[test]
+#[cfg(not(feature = "ffi-opaque"))]
 fn ros_camera_frame_builder_encode_into_matches_rust_builder() {
     unsafe {
         let b = ros_camera_frame_builder_new();
@@ -1942,6 +1981,7 @@ fn ros_camera_frame_builder_encode_into_matches_rust_builder() {
 }
 
 #[test]
+#[cfg(not(feature = "ffi-opaque"))]
 fn ros_model_builder_encode_into_matches_rust_builder() {
     unsafe {
         let b = ros_model_builder_new();
@@ -2231,6 +2271,7 @@ fn ros_foxglove_text_annotation_builder_encode_into_matches_rust_builder() {
 }
 
 #[test]
+#[cfg(not(feature = "ffi-opaque"))]
 fn ros_foxglove_point_annotation_builder_encode_into_matches_rust_builder() {
     unsafe {
         let b = ros_foxglove_point_annotation_builder_new();
@@ -2302,6 +2343,7 @@ fn ros_foxglove_point_annotation_builder_encode_into_matches_rust_builder() {
 }
 
 #[test]
+#[cfg(not(feature = "ffi-opaque"))]
 fn ros_foxglove_image_annotation_builder_encode_into_matches_rust_builder() {
     unsafe {
         let b = ros_foxglove_image_annotation_builder_new();
@@ -2453,6 +2495,283 @@ fn ros_foxglove_image_annotation_builder_encode_into_matches_rust_builder() {
     }
 }
 
+// ============================================================================
+// `add_*` element functions: the layout-independent alternative to the C-POD
+// descriptor arrays, and the only one in opaque-handle builds.
+// ============================================================================
+
+extern "C" {
+    fn ros_detect_builder_add_box(
+        b: *mut ros_detect_builder_t,
+        detect_box: *const ros_detect_box_builder_t,
+    ) -> i32;
+    fn ros_detect_builder_get_boxes_len(b: *const ros_detect_builder_t) -> usize;
+    fn ros_detect_builder_remove_box(b: *mut ros_detect_builder_t, index: usize) -> i32;
+    fn ros_camera_frame_builder_add_plane(
+        b: *mut ros_camera_frame_builder_t,
+        fd: i32,
+        offset: u32,
+        stride: u32,
+        size: u32,
+        used: u32,
+        data: *const u8,
+        data_len: usize,
+    ) -> i32;
+    fn ros_foxglove_point_annotation_builder_add_point(
+        b: *mut ros_foxglove_point_annotation_builder_t,
+        x: f64,
+        y: f64,
+    ) -> i32;
+    fn ros_foxglove_point_annotation_builder_add_outline_color(
+        b: *mut ros_foxglove_point_annotation_builder_t,
+        r: f64,
+        g: f64,
+        bc: f64,
+        a: f64,
+    ) -> i32;
+    fn ros_foxglove_image_annotation_builder_add_point_annotation(
+        b: *mut ros_foxglove_image_annotation_builder_t,
+        annotation: *const ros_foxglove_point_annotation_builder_t,
+    ) -> i32;
+    fn ros_foxglove_image_annotation_builder_add_text_annotation(
+        b: *mut ros_foxglove_image_annotation_builder_t,
+        annotation: *const ros_foxglove_text_annotation_builder_t,
+    ) -> i32;
+}
+
+#[test]
+fn ros_detect_builder_add_box_matches_rust_builder() {
+    unsafe {
+        let b = ros_detect_builder_new();
+        ros_detect_builder_set_stamp(b, 1, 2);
+        for (label, score) in [("person", 0.95f32), ("bike", 0.4)] {
+            let bx = ros_detect_box_builder_new();
+            ros_detect_box_builder_set_center_x(bx, 0.5);
+            let label = CString::new(label).unwrap();
+            assert_eq!(ros_detect_box_builder_set_label(bx, label.as_ptr()), 0);
+            ros_detect_box_builder_set_score(bx, score);
+            ros_detect_box_builder_set_track_created(bx, 3, 4);
+            assert_eq!(ros_detect_builder_add_box(b, bx), 0);
+            // The detect builder holds its own copy.
+            ros_detect_box_builder_free(bx);
+        }
+        assert_eq!(ros_detect_builder_add_box(b, std::ptr::null()), -1);
+
+        let mut buf = [0u8; 1024];
+        let mut out_len: usize = 0;
+        let rc = ros_detect_builder_encode_into(b, buf.as_mut_ptr(), buf.len(), &mut out_len);
+        assert_eq!(rc, 0);
+
+        let view = |label, score| DetectBoxView {
+            center_x: 0.5,
+            center_y: 0.0,
+            width: 0.0,
+            height: 0.0,
+            label,
+            score,
+            distance: 0.0,
+            speed: 0.0,
+            track_id: "",
+            track_lifetime: 0,
+            track_created: Time::new(3, 4),
+        };
+        let via_rust = edgefirst_msgs::Detect::builder()
+            .stamp(Time::new(1, 2))
+            .boxes(&[view("person", 0.95), view("bike", 0.4)])
+            .build()
+            .expect("rust builder.build()");
+        assert_eq!(&buf[..out_len], via_rust.as_cdr());
+        ros_detect_builder_free(b);
+    }
+}
+
+#[test]
+fn ros_detect_builder_remove_box_matches_rust_builder() {
+    unsafe {
+        let b = ros_detect_builder_new();
+        for label in ["a", "b", "c"] {
+            let bx = ros_detect_box_builder_new();
+            let label = CString::new(label).unwrap();
+            assert_eq!(ros_detect_box_builder_set_label(bx, label.as_ptr()), 0);
+            assert_eq!(ros_detect_builder_add_box(b, bx), 0);
+            ros_detect_box_builder_free(bx);
+        }
+        assert_eq!(ros_detect_builder_get_boxes_len(b), 3);
+        assert_eq!(ros_detect_builder_remove_box(b, 1), 0);
+        assert_eq!(ros_detect_builder_get_boxes_len(b), 2);
+        errno::set_errno(errno::Errno(0));
+        assert_eq!(ros_detect_builder_remove_box(b, 2), -1);
+        assert_eq!(test_errno(), libc::EINVAL);
+
+        let mut buf = [0u8; 1024];
+        let mut out_len: usize = 0;
+        let rc = ros_detect_builder_encode_into(b, buf.as_mut_ptr(), buf.len(), &mut out_len);
+        assert_eq!(rc, 0);
+        let decoded = edgefirst_msgs::Detect::from_cdr(&buf[..out_len]).unwrap();
+        let labels: Vec<_> = decoded.boxes().iter().map(|b| b.label).collect();
+        assert_eq!(labels, ["a", "c"]);
+        ros_detect_builder_free(b);
+        assert_eq!(ros_detect_builder_get_boxes_len(std::ptr::null()), 0);
+    }
+}
+
+#[cfg(not(feature = "ffi-opaque"))]
+#[test]
+fn ros_detect_builder_remove_box_leaves_borrowed_array() {
+    unsafe {
+        let labels = ["a", "b"].map(|l| CString::new(l).unwrap());
+        let track = CString::new("").unwrap();
+        let elems: Vec<ros_detect_box_elem_t> = labels
+            .iter()
+            .map(|l| ros_detect_box_elem_t {
+                center_x: 0.0,
+                center_y: 0.0,
+                width: 0.0,
+                height: 0.0,
+                label: l.as_ptr(),
+                score: 0.0,
+                distance: 0.0,
+                speed: 0.0,
+                track_id: track.as_ptr(),
+                track_lifetime: 0,
+                track_created_sec: 0,
+                track_created_nanosec: 0,
+            })
+            .collect();
+        let b = ros_detect_builder_new();
+        assert_eq!(
+            ros_detect_builder_set_boxes(b, elems.as_ptr(), elems.len()),
+            0
+        );
+        assert_eq!(ros_detect_builder_remove_box(b, 0), 0);
+        assert_eq!(ros_detect_builder_get_boxes_len(b), 1);
+        // The caller's array is untouched.
+        assert_eq!(elems[0].label, labels[0].as_ptr());
+
+        let mut buf = [0u8; 1024];
+        let mut out_len: usize = 0;
+        let rc = ros_detect_builder_encode_into(b, buf.as_mut_ptr(), buf.len(), &mut out_len);
+        assert_eq!(rc, 0);
+        let decoded = edgefirst_msgs::Detect::from_cdr(&buf[..out_len]).unwrap();
+        assert_eq!(decoded.boxes()[0].label, "b");
+        ros_detect_builder_free(b);
+    }
+}
+
+#[test]
+fn ros_camera_frame_builder_add_plane_copies_data() {
+    unsafe {
+        let b = ros_camera_frame_builder_new();
+        ros_camera_frame_builder_set_width(b, 2);
+        ros_camera_frame_builder_set_height(b, 2);
+        let data = vec![1u8, 2, 3, 4];
+        let rc = ros_camera_frame_builder_add_plane(b, -1, 0, 2, 4, 4, data.as_ptr(), data.len());
+        assert_eq!(rc, 0);
+        drop(data);
+        let rc = ros_camera_frame_builder_add_plane(b, 7, 0, 2, 2, 2, std::ptr::null(), 0);
+        assert_eq!(rc, 0);
+
+        let mut buf = [0u8; 1024];
+        let mut out_len: usize = 0;
+        let rc = ros_camera_frame_builder_encode_into(b, buf.as_mut_ptr(), buf.len(), &mut out_len);
+        assert_eq!(rc, 0);
+        let frame = edgefirst_msgs::CameraFrame::from_cdr(&buf[..out_len]).unwrap();
+        let planes = frame.planes();
+        assert_eq!(planes.len(), 2);
+        assert_eq!(planes[0].data, &[1, 2, 3, 4]);
+        assert_eq!((planes[1].fd, planes[1].size), (7, 2));
+        ros_camera_frame_builder_free(b);
+    }
+}
+
+#[test]
+fn ros_foxglove_image_annotation_builder_add_matches_rust_builder() {
+    unsafe {
+        let p = ros_foxglove_point_annotation_builder_new();
+        ros_foxglove_point_annotation_builder_set_type(p, 2);
+        assert_eq!(
+            ros_foxglove_point_annotation_builder_add_point(p, 1.0, 2.0),
+            0
+        );
+        assert_eq!(
+            ros_foxglove_point_annotation_builder_add_point(p, 3.0, 4.0),
+            0
+        );
+        assert_eq!(
+            ros_foxglove_point_annotation_builder_add_outline_color(p, 1.0, 0.0, 0.0, 1.0),
+            0
+        );
+        let t = ros_foxglove_text_annotation_builder_new();
+        let text = CString::new("label").unwrap();
+        assert_eq!(
+            ros_foxglove_text_annotation_builder_set_text(t, text.as_ptr()),
+            0
+        );
+        ros_foxglove_text_annotation_builder_set_font_size(t, 12.0);
+
+        let b = ros_foxglove_image_annotation_builder_new();
+        assert_eq!(
+            ros_foxglove_image_annotation_builder_add_point_annotation(b, p),
+            0
+        );
+        assert_eq!(
+            ros_foxglove_image_annotation_builder_add_text_annotation(b, t),
+            0
+        );
+        ros_foxglove_point_annotation_builder_free(p);
+        ros_foxglove_text_annotation_builder_free(t);
+
+        let mut buf = [0u8; 1024];
+        let mut out_len: usize = 0;
+        let rc = ros_foxglove_image_annotation_builder_encode_into(
+            b,
+            buf.as_mut_ptr(),
+            buf.len(),
+            &mut out_len,
+        );
+        assert_eq!(rc, 0);
+
+        let black = FoxgloveColor {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.0,
+        };
+        let rust_points = [FoxglovePointAnnotationView {
+            timestamp: Time::new(0, 0),
+            type_: 2,
+            points: vec![
+                FoxglovePoint2 { x: 1.0, y: 2.0 },
+                FoxglovePoint2 { x: 3.0, y: 4.0 },
+            ],
+            outline_color: black,
+            outline_colors: vec![FoxgloveColor {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            }],
+            fill_color: black,
+            thickness: 0.0,
+        }];
+        let rust_texts = [FoxgloveTextAnnotationView {
+            timestamp: Time::new(0, 0),
+            position: FoxglovePoint2 { x: 0.0, y: 0.0 },
+            text: "label",
+            font_size: 12.0,
+            text_color: black,
+            background_color: black,
+        }];
+        let via_rust = foxglove_msgs::FoxgloveImageAnnotation::builder()
+            .points(&rust_points)
+            .texts(&rust_texts)
+            .build()
+            .expect("rust builder.build()");
+        assert_eq!(&buf[..out_len], via_rust.as_cdr());
+        ros_foxglove_image_annotation_builder_free(b);
+    }
+}
+
 // ============================================================================
 // Error-path tests for NULL / invalid-UTF-8 inputs on builder setters.
 // ============================================================================
@@ -2505,6 +2824,171 @@ fn ros_image_builder_set_data_null_with_nonzero_len_returns_einval() {
     }
 }
 
+#[test]
+fn ros_image_builder_set_data_owned_releases_buffer() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static RELEASED: AtomicUsize = AtomicUsize::new(0);
+    unsafe extern "C" fn release(ctx: *mut std::ffi::c_void) {
+        drop(Box::from_raw(ctx as *mut Vec<u8>));
+        RELEASED.fetch_add(1, Ordering::SeqCst);
+    }
+    unsafe {
+        let b = ros_image_builder_new();
+        assert!(!b.is_null());
+        ros_image_builder_set_height(b, 1);
+        ros_image_builder_set_width(b, 4);
+        ros_image_builder_set_step(b, 4);
+        let pixels = Box::into_raw(Box::new(vec![1u8, 2, 3, 4]));
+        let (ptr, len) = ((*pixels).as_ptr(), (*pixels).len());
+        let rc = ros_image_builder_set_data_owned(b, ptr, len, Some(release), pixels.cast());
+        assert_eq!(rc, 0);
+
+        let mut buf = [0u8; 256];
+        let mut out_len: usize = 0;
+        assert_eq!(
+            ros_image_builder_encode_into(b, buf.as_mut_ptr(), buf.len(), &mut out_len),
+            0
+        );
+        let via_rust = sensor_msgs::Image::builder()
+            .height(1)
+            .width(4)
+            .step(4)
+            .data(&[1, 2, 3, 4])
+            .build()
+            .unwrap();
+        assert_eq!(&buf[..out_len], via_rust.as_cdr());
+
+        // Replacing the data hands the first buffer back; freeing the
+        // builder hands back the second.
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 0);
+        let second = Box::into_raw(Box::new(vec![9u8; 4]));
+        let rc = ros_image_builder_set_data_owned(
+            b,
+            (*second).as_ptr(),
+            4,
+            Some(release),
+            second.cast(),
+        );
+        assert_eq!(rc, 0);
+
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+
+        errno::set_errno(errno::Errno(0));
+        let rc = ros_image_builder_set_data_owned(b, ptr, len, None, std::ptr::null_mut());
+        assert_eq!(rc, -1);
+        assert_eq!(test_errno(), libc::EINVAL);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+        ros_image_builder_free(b);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 2);
+    }
+}
+
+/// `encode_parts` around `data` must reproduce `encode_into` byte for byte,
+/// whatever padding the data length leaves before the tail.
+unsafe fn assert_parts_match(
+    data: &[u8],
+    encode_into: impl Fn(*mut u8, usize, *mut usize) -> i32,
+    encode_parts: impl Fn(*mut u8, usize, *mut usize, *mut u8, usize, *mut usize) -> i32,
+) {
+    let mut whole = vec![0u8; 512];
+    let mut whole_len = 0;
+    assert_eq!(
+        encode_into(whole.as_mut_ptr(), whole.len(), &mut whole_len),
+        0
+    );
+
+    let (mut head_len, mut tail_len) = (0, 0);
+    let rc = encode_parts(
+        std::ptr::null_mut(),
+        0,
+        &mut head_len,
+        std::ptr::null_mut(),
+        0,
+        &mut tail_len,
+    );
+    assert_eq!(rc, 0);
+    assert_eq!(head_len + data.len() + tail_len, whole_len);
+
+    let (mut head, mut tail) = (vec![0u8; head_len], vec![0u8; tail_len]);
+    let rc = encode_parts(
+        head.as_mut_ptr(),
+        head.len(),
+        &mut head_len,
+        tail.as_mut_ptr(),
+        tail.len(),
+        &mut tail_len,
+    );
+    assert_eq!(rc, 0);
+    assert_eq!([&head[..], data, &tail[..]].concat(), &whole[..whole_len]);
+
+    if head_len > 0 {
+        errno::set_errno(errno::Errno(0));
+        let rc = encode_parts(
+            head.as_mut_ptr(),
+            head_len - 1,
+            &mut head_len,
+            tail.as_mut_ptr(),
+            tail.len(),
+            &mut tail_len,
+        );
+        assert_eq!(rc, -1);
+        assert_eq!(test_errno(), libc::ENOBUFS);
+    }
+}
+
+#[test]
+fn builder_encode_parts_match_encode_into() {
+    let frame = CString::new("camera").unwrap();
+    let format = CString::new("h264").unwrap();
+    for len in 0..=17 {
+        let data: Vec<u8> = (0..len as u8).map(|i| i.wrapping_mul(37)).collect();
+        unsafe {
+            let b = ros_image_builder_new();
+            ros_image_builder_set_frame_id(b, frame.as_ptr());
+            ros_image_builder_set_width(b, len as u32);
+            ros_image_builder_set_height(b, 1);
+            ros_image_builder_set_step(b, len as u32);
+            ros_image_builder_set_data(b, data.as_ptr(), data.len());
+            assert_parts_match(
+                &data,
+                |buf, cap, out| ros_image_builder_encode_into(b, buf, cap, out),
+                |h, hc, hl, t, tc, tl| ros_image_builder_encode_parts(b, h, hc, hl, t, tc, tl),
+            );
+            ros_image_builder_free(b);
+
+            let b = ros_point_cloud2_builder_new();
+            ros_point_cloud2_builder_set_frame_id(b, frame.as_ptr());
+            ros_point_cloud2_builder_set_width(b, len as u32);
+            ros_point_cloud2_builder_set_height(b, 1);
+            ros_point_cloud2_builder_set_point_step(b, 1);
+            ros_point_cloud2_builder_set_row_step(b, len as u32);
+            ros_point_cloud2_builder_set_data(b, data.as_ptr(), data.len());
+            ros_point_cloud2_builder_set_is_dense(b, true);
+            assert_parts_match(
+                &data,
+                |buf, cap, out| ros_point_cloud2_builder_encode_into(b, buf, cap, out),
+                |h, hc, hl, t, tc, tl| {
+                    ros_point_cloud2_builder_encode_parts(b, h, hc, hl, t, tc, tl)
+                },
+            );
+            ros_point_cloud2_builder_free(b);
+
+            let b = ros_foxglove_compressed_video_builder_new();
+            ros_foxglove_compressed_video_builder_set_frame_id(b, frame.as_ptr());
+            ros_foxglove_compressed_video_builder_set_data(b, data.as_ptr(), data.len());
+            ros_foxglove_compressed_video_builder_set_format(b, format.as_ptr());
+            assert_parts_match(
+                &data,
+                |buf, cap, out| ros_foxglove_compressed_video_builder_encode_into(b, buf, cap, out),
+                |h, hc, hl, t, tc, tl| {
+                    ros_foxglove_compressed_video_builder_encode_parts(b, h, hc, hl, t, tc, tl)
+                },
+            );
+            ros_foxglove_compressed_video_builder_free(b);
+        }
+    }
+}
+
 #[test]
 fn ros_imu_builder_set_orientation_covariance_null_returns_einval() {
     unsafe {
@@ -2519,6 +3003,7 @@ fn ros_imu_builder_set_orientation_covariance_null_returns_einval() {
 }
 
 #[test]
+#[cfg(not(feature = "ffi-opaque"))]
 fn ros_point_cloud2_builder_fields_null_name_fails_at_build() {
     unsafe {
         let b = ros_point_cloud2_builder_new();
@@ -2545,6 +3030,7 @@ fn ros_point_cloud2_builder_fields_null_name_fails_at_build() {
 }
 
 #[test]
+#[cfg(not(feature = "ffi-opaque"))]
 fn ros_detect_builder_boxes_null_label_fails_at_build() {
     unsafe {
         let b = ros_detect_builder_new();
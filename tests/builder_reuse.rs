@@ -271,3 +271,41 @@ fn pointcloud2_encode_into_vec_reuses_allocation() {
         "capacity must not grow across identical encodes",
     );
 }
+
+#[test]
+fn builder_cdr_size_matches_encoded_len() {
+    use edgefirst_schemas::edgefirst_msgs::{Detect, DetectBoxView};
+
+    let pixels = vec![0u8; 12];
+    let mut b = Image::builder();
+    b.stamp(Time::new(1, 2))
+        .frame_id("camera")
+        .height(2)
+        .width(2)
+        .encoding("rgb8")
+        .step(6)
+        .data(&pixels);
+    let need = b.cdr_size();
+    let mut exact = vec![0u8; need];
+    assert_eq!(b.encode_into_slice(&mut exact).unwrap(), need);
+    assert_eq!(b.build().unwrap().cdr_size(), need);
+
+    let boxes = [DetectBoxView {
+        center_x: 0.5,
+        center_y: 0.5,
+        width: 0.1,
+        height: 0.1,
+        label: "person",
+        score: 0.9,
+        distance: 0.0,
+        speed: 0.0,
+        track_id: "t1",
+        track_lifetime: 0,
+        track_created: Time::new(0, 0),
+    }];
+    let mut d = Detect::builder();
+    d.frame_id("camera").boxes(&boxes);
+    let mut buf = Vec::new();
+    d.encode_into_vec(&mut buf).unwrap();
+    assert_eq!(d.cdr_size(), buf.len());
+}
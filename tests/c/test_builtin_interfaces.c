@@ -5,6 +5,7 @@
  * Tests the CdrFixed encode/decode API:
  *   ros_time_encode(buf, cap, &written, sec, nanosec) -> int
  *   ros_time_decode(data, len, &sec, &nanosec) -> int
+ *   ros_time_set_now(&sec, &nanosec, clock) -> int
  */
 
 #include <criterion/criterion.h>
@@ -12,6 +13,7 @@
 #include <string.h>
 #include <stdint.h>
 #include <stdlib.h>
+#include <time.h>
 #include "edgefirst/schemas.h"
 
 // ============================================================================
@@ -130,6 +132,29 @@ Test(builtin_interfaces, time_decode_null_out_pointers) {
     cr_assert_eq(ret, 0, "Decode with NULL out pointers should succeed");
 }
 
+Test(builtin_interfaces, time_set_now_reads_clocks) {
+    int32_t sec = 0;
+    uint32_t nanosec = 0;
+    time_t before = time(NULL);
+    cr_assert_eq(ros_time_set_now(&sec, &nanosec, ROS_CLOCK_REALTIME), 0);
+    cr_assert_geq(sec, (int32_t)before);
+    // time() may read a coarse clock a tick behind.
+    cr_assert_leq(sec, (int32_t)time(NULL) + 1);
+    cr_assert_lt(nanosec, 1000000000u);
+
+    int32_t sec2 = 0;
+    uint32_t nanosec2 = 0;
+    cr_assert_eq(ros_time_set_now(&sec, &nanosec, ROS_CLOCK_MONOTONIC), 0);
+    cr_assert_eq(ros_time_set_now(&sec2, &nanosec2, ROS_CLOCK_MONOTONIC), 0);
+    cr_assert(sec2 > sec || (sec2 == sec && nanosec2 >= nanosec));
+
+    cr_assert_eq(ros_time_set_now(NULL, NULL, ROS_CLOCK_REALTIME), 0);
+
+    errno = 0;
+    cr_assert_eq(ros_time_set_now(&sec, &nanosec, 42), -1);
+    cr_assert_eq(errno, EINVAL);
+}
+
 // ============================================================================
 // Duration Tests
 // ============================================================================
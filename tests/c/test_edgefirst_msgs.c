@@ -12,6 +12,7 @@
 
 #include <criterion/criterion.h>
 #include <errno.h>
+#include <pthread.h>
 #include <stdio.h>
 #include <stdlib.h>
 #include <string.h>
@@ -441,6 +442,34 @@ Test(edgefirst_msgs, model_info_getters_null) {
     cr_assert_null(ros_model_info_get_label(NULL, 0));
 }
 
+Test(edgefirst_msgs, model_info_labels_in_bulk) {
+    const char* const names[] = {"person", "bicycle", "car"};
+    ros_model_info_builder_t* b = ros_model_info_builder_new();
+    cr_assert_not_null(b);
+    cr_assert_eq(ros_model_info_builder_set_labels(b, names, 3), 0);
+    uint8_t* bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_model_info_builder_build(b, &bytes, &len), 0);
+
+    ros_model_info_t* v = ros_model_info_from_cdr(bytes, len);
+    cr_assert_not_null(v);
+    cr_assert_eq(ros_model_info_get_labels(v, NULL, 0), 3);
+    const char* out[2] = {NULL, NULL};
+    cr_assert_eq(ros_model_info_get_labels(v, out, 2), 3);
+    cr_assert_str_eq(out[0], "person");
+    cr_assert_str_eq(out[1], "bicycle");
+    ros_model_info_free(v);
+    ros_bytes_free(bytes, len);
+
+    const char* const with_null[] = {"person", NULL};
+    errno = 0;
+    cr_assert_eq(ros_model_info_builder_set_labels(b, with_null, 2), -1);
+    cr_assert_eq(errno, EINVAL);
+    ros_model_info_builder_free(b);
+
+    cr_assert_eq(ros_model_info_get_labels(NULL, out, 2), 0);
+}
+
 // ============================================================================
 // LocalTime Tests (buffer-backed — no encode)
 // ============================================================================
@@ -528,6 +557,67 @@ Test(edgefirst_msgs, detect_get_box_roundtrip) {
     ros_detect_free(handle);
 }
 
+Test(edgefirst_msgs, detect_clone_owns_boxes) {
+    uint8_t buf[sizeof(detect_multi_cdr)];
+    memcpy(buf, detect_multi_cdr, sizeof(buf));
+    ros_detect_t *handle = ros_detect_from_cdr(buf, sizeof(buf));
+    cr_assert_not_null(handle);
+    ros_detect_t *copy = ros_detect_clone(handle);
+    cr_assert_not_null(copy);
+    ros_detect_free(handle);
+    memset(buf, 0, sizeof(buf));
+
+    cr_assert_eq(ros_detect_get_boxes_len(copy), 3u);
+    const ros_box_t *box1 = ros_detect_get_box(copy, 1);
+    cr_assert_str_eq(ros_box_get_label(box1), "person");
+    cr_assert_str_eq(ros_box_get_track_id(box1), "track_long_id");
+
+    size_t len = 0;
+    const uint8_t *cdr = ros_detect_as_cdr(copy, &len);
+    cr_assert_eq(len, sizeof(detect_multi_cdr));
+    cr_assert_eq(memcmp(cdr, detect_multi_cdr, len), 0);
+    ros_detect_free(copy);
+}
+
+Test(edgefirst_msgs, detect_equals_compares_boxes) {
+    uint8_t buf[sizeof(detect_multi_cdr)];
+    memcpy(buf, detect_multi_cdr, sizeof(buf));
+    ros_detect_t *a = ros_detect_from_cdr(detect_multi_cdr, sizeof(detect_multi_cdr));
+    ros_detect_t *b = ros_detect_from_cdr(buf, sizeof(buf));
+    cr_assert_not_null(a);
+    cr_assert_not_null(b);
+    cr_assert_eq(ros_detect_equals(a, b), 1);
+
+    // Box 0's score is the float at offset 80.
+    buf[80] ^= 0x01;
+    cr_assert_eq(ros_detect_equals(a, b), 0);
+
+    ros_detect_free(b);
+    ros_detect_free(a);
+}
+
+static void *detect_recorder(void *arg) {
+    ros_detect_t *handle = arg;
+    const ros_box_t *box = ros_detect_get_box(handle, 1);
+    int ok = box != NULL && strcmp(ros_box_get_label(box), "person") == 0;
+    ros_detect_release(handle);
+    return ok ? handle : NULL;
+}
+
+Test(edgefirst_msgs, detect_retain_shares_across_threads) {
+    ros_detect_t *handle = ros_detect_from_cdr(detect_multi_cdr, sizeof(detect_multi_cdr));
+    cr_assert_not_null(handle);
+
+    pthread_t recorder;
+    cr_assert_eq(pthread_create(&recorder, NULL, detect_recorder, ros_detect_retain(handle)), 0);
+    cr_assert_eq(ros_detect_get_boxes_len(handle), 3u);
+    ros_detect_release(handle);
+
+    void *ok = NULL;
+    cr_assert_eq(pthread_join(recorder, &ok), 0);
+    cr_assert_not_null(ok);
+}
+
 Test(edgefirst_msgs, detect_get_box_null_view) {
     errno = 0;
     const ros_box_t *box = ros_detect_get_box(NULL, 0);
@@ -10,6 +10,7 @@
 
 #include <criterion/criterion.h>
 #include <errno.h>
+#include <stdlib.h>
 #include <string.h>
 #include <stdint.h>
 #include "edgefirst/schemas.h"
@@ -256,6 +257,41 @@ Test(errno_handling, getter_null_mask) {
     cr_assert_eq(ros_mask_get_boxed(NULL), false);
 }
 
+Test(errno_handling, getter_null_sets_einval) {
+    errno = 0;
+    cr_assert_eq(ros_image_get_height(NULL), 0);
+    cr_assert_eq(errno, EINVAL);
+
+    errno = 0;
+    cr_assert_null(ros_header_get_frame_id(NULL));
+    cr_assert_eq(errno, EINVAL);
+
+    errno = 0;
+    cr_assert_eq(ros_mask_get_boxed(NULL), false);
+    cr_assert_eq(errno, EINVAL);
+
+    errno = 0;
+    cr_assert_eq(ros_camera_frame_get_fence_fd(NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+
+    size_t len = 1;
+    errno = 0;
+    cr_assert_null(ros_image_as_cdr(NULL, &len));
+    cr_assert_eq(len, 0);
+    cr_assert_eq(errno, EINVAL);
+
+    double x = 1.0;
+    errno = 0;
+    ros_imu_get_orientation(NULL, &x, NULL, NULL, NULL);
+    cr_assert_eq(errno, EINVAL);
+}
+
+Test(errno_handling, setter_null_builder_sets_einval) {
+    errno = 0;
+    ros_image_builder_set_height(NULL, 480);
+    cr_assert_eq(errno, EINVAL);
+}
+
 // ============================================================================
 // Sequential error recovery
 // ============================================================================
@@ -325,3 +361,212 @@ Test(errno_handling, bytes_free_null) {
     ros_bytes_free(NULL, 0);
     ros_bytes_free(NULL, 100);
 }
+
+// ============================================================================
+// Last error message
+// ============================================================================
+
+Test(errno_handling, last_error_names_failing_field) {
+    uint8_t* bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &len, 1, 2, "camera"), 0);
+
+    edgefirst_clear_last_error();
+    cr_assert_null(edgefirst_last_error_message());
+
+    // Truncated inside frame_id.
+    errno = 0;
+    cr_assert_null(ros_header_from_cdr(bytes, len - 3));
+    cr_assert_eq(errno, EBADMSG);
+    const char* msg = edgefirst_last_error_message();
+    cr_assert_not_null(msg);
+    cr_assert(strstr(msg, "frame_id") != NULL, "message was: %s", msg);
+
+    // Successful calls leave it in place.
+    ros_header_t* ok = ros_header_from_cdr(bytes, len);
+    cr_assert_not_null(ok);
+    cr_assert_eq(edgefirst_last_error_message(), msg);
+    ros_header_free(ok);
+
+    edgefirst_clear_last_error();
+    cr_assert_null(edgefirst_last_error_message());
+    ros_bytes_free(bytes, len);
+}
+
+Test(errno_handling, last_error_null_argument) {
+    edgefirst_clear_last_error();
+    cr_assert_null(ros_image_from_cdr(NULL, 16));
+    cr_assert_eq(errno, EINVAL);
+    cr_assert_str_eq(edgefirst_last_error_message(), "`data` is NULL");
+
+    uint8_t buf[2];
+    size_t written = 0;
+    cr_assert_eq(ros_time_encode(buf, sizeof(buf), &written, 1, 2), -1);
+    cr_assert_str_eq(edgefirst_last_error_message(), "buffer too small");
+    edgefirst_clear_last_error();
+}
+
+Test(errno_handling, panic_reported_as_eio) {
+    uint8_t* bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &len, 1, 2, "camera"), 0);
+    ros_header_t* h = ros_header_from_cdr(bytes, len);
+    cr_assert_not_null(h);
+
+    // The handle borrows `bytes`; corrupting the frame_id length after
+    // parsing makes the getter read past the buffer, which must fail
+    // cleanly rather than abort the process.
+    memset(bytes + 12, 0xff, 4);
+    errno = 0;
+    cr_assert_null(ros_header_get_frame_id(h));
+    cr_assert_eq(errno, EIO);
+    const char* msg = edgefirst_last_error_message();
+    cr_assert_not_null(msg);
+    cr_assert(strstr(msg, "internal error") != NULL, "message was: %s", msg);
+
+    ros_header_free(h);
+    ros_bytes_free(bytes, len);
+}
+
+Test(errno_handling, builder_encode_into_size_query) {
+    ros_header_builder_t* b = ros_header_builder_new();
+    cr_assert_not_null(b);
+    ros_header_builder_set_stamp(b, 1, 2);
+    cr_assert_eq(ros_header_builder_set_frame_id(b, "camera"), 0);
+
+    size_t need = 0;
+    cr_assert_eq(ros_header_builder_encode_into(b, NULL, 0, &need), 0);
+    cr_assert_gt(need, 0);
+
+    // Too small: fails but still reports the size.
+    uint8_t small[4];
+    size_t got = 0;
+    errno = 0;
+    cr_assert_eq(ros_header_builder_encode_into(b, small, sizeof(small), &got), -1);
+    cr_assert_eq(errno, ENOBUFS);
+    cr_assert_eq(got, need);
+
+    uint8_t buf[64];
+    cr_assert_eq(ros_header_builder_encode_into(b, buf, sizeof(buf), &got), 0);
+    cr_assert_eq(got, need);
+
+    errno = 0;
+    cr_assert_eq(ros_header_builder_encode_into(b, buf, sizeof(buf), NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+    ros_header_builder_free(b);
+}
+
+Test(errno_handling, serialized_size_matches_encoding) {
+    ros_detect_builder_t* b = ros_detect_builder_new();
+    cr_assert_not_null(b);
+    cr_assert_eq(ros_detect_builder_set_frame_id(b, "camera"), 0);
+    size_t size = 0;
+    cr_assert_eq(ros_detect_builder_serialized_size(b, &size), 0);
+
+    uint8_t* bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_detect_builder_build(b, &bytes, &len), 0);
+    cr_assert_eq(size, len);
+    ros_detect_builder_free(b);
+
+    ros_detect_t* d = ros_detect_from_cdr(bytes, len);
+    cr_assert_not_null(d);
+    size = 0;
+    cr_assert_eq(ros_detect_serialized_size(d, &size), 0);
+    cr_assert_eq(size, len);
+
+    errno = 0;
+    cr_assert_eq(ros_detect_serialized_size(d, NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+    errno = 0;
+    cr_assert_eq(ros_detect_serialized_size(NULL, &size), -1);
+    cr_assert_eq(errno, EINVAL);
+    errno = 0;
+    cr_assert_eq(ros_detect_builder_serialized_size(NULL, &size), -1);
+    cr_assert_eq(errno, EINVAL);
+
+    edgefirst_message_t* msg = edgefirst_message_deserialize("edgefirst_msgs/msg/Detect", bytes, len);
+    cr_assert_not_null(msg);
+    size = 0;
+    cr_assert_eq(edgefirst_message_serialized_size(msg, &size), 0);
+    cr_assert_eq(size, len);
+    edgefirst_message_free(msg);
+
+    ros_detect_free(d);
+    ros_bytes_free(bytes, len);
+}
+
+// ============================================================================
+// Host allocator (Criterion runs each test in its own process, so the
+// process-wide registration does not leak into other tests)
+// ============================================================================
+
+static size_t host_mallocs;
+static size_t host_frees;
+
+static void* counting_malloc(size_t n) {
+    host_mallocs++;
+    return malloc(n);
+}
+
+static void counting_free(void* p) {
+    host_frees++;
+    free(p);
+}
+
+Test(errno_handling, host_allocator_owns_buffers) {
+    errno = 0;
+    cr_assert_eq(edgefirst_set_allocator(NULL, counting_free), -1);
+    cr_assert_eq(errno, EINVAL);
+
+    cr_assert_eq(edgefirst_set_allocator(counting_malloc, counting_free), 0);
+
+    ros_header_builder_t* b = ros_header_builder_new();
+    cr_assert_not_null(b);
+    cr_assert_eq(ros_header_builder_set_frame_id(b, "camera"), 0);
+
+    uint8_t* bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_header_builder_build(b, &bytes, &len), 0);
+    cr_assert_eq(host_mallocs, 1);
+    edgefirst_bytes_free(bytes, len);
+    cr_assert_eq(host_frees, 1);
+
+    // Buffers are plain host allocations, so free_fn may release them too.
+    cr_assert_eq(ros_header_builder_build(b, &bytes, &len), 0);
+    counting_free(bytes);
+    cr_assert_eq(host_mallocs, host_frees);
+
+    errno = 0;
+    cr_assert_eq(edgefirst_set_allocator(malloc, free), -1);
+    cr_assert_eq(errno, EBUSY);
+    ros_header_builder_free(b);
+}
+
+// Conforming allocator that returns NULL for zero-size requests.
+static void* null_for_zero_malloc(size_t n) {
+    host_mallocs++;
+    return n == 0 ? NULL : malloc(n);
+}
+
+Test(errno_handling, host_allocator_zero_length_payload) {
+    cr_assert_eq(edgefirst_set_allocator(null_for_zero_malloc, counting_free), 0);
+
+    // A zero-length buffer is NULL with length 0 and releasing it never
+    // reaches free_fn.
+    edgefirst_bytes_free(NULL, 0);
+    ros_bytes_free(NULL, 0);
+    cr_assert_eq(host_frees, 0);
+
+    ros_header_builder_t* b = ros_header_builder_new();
+    cr_assert_not_null(b);
+    uint8_t* bytes = NULL;
+    size_t len = 0;
+    errno = 0;
+    cr_assert_eq(ros_header_builder_build(b, &bytes, &len), 0);
+    cr_assert_not_null(bytes);
+    cr_assert_gt(len, 0);
+    edgefirst_bytes_free(bytes, len);
+    cr_assert_eq(host_mallocs, host_frees);
+    ros_header_builder_free(b);
+}
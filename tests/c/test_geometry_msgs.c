@@ -527,6 +527,41 @@ Test(geometry_msgs, point_stamped_roundtrip) {
     ros_point_stamped_free(h);
 }
 
+// ============================================================================
+// PoseStamped Tests (buffer-backed)
+// ============================================================================
+
+Test(geometry_msgs, pose_stamped_getters_null) {
+    double px = 1.0;
+    cr_assert_eq(ros_pose_stamped_get_stamp_sec(NULL), 0);
+    cr_assert_null(ros_pose_stamped_get_frame_id(NULL));
+    ros_pose_stamped_get_pose(NULL, &px, NULL, NULL, NULL, NULL, NULL, NULL);
+    cr_assert_float_eq(px, 1.0, 1e-12);
+    ros_pose_stamped_free(NULL);
+}
+
+Test(geometry_msgs, pose_stamped_roundtrip) {
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    int ret = ros_pose_stamped_encode(&bytes, &len, 42, 7, "map",
+                                      1.0, -2.0, 0.5, 0.0, 0.0, 0.0, 1.0);
+    cr_assert_eq(ret, 0);
+
+    ros_pose_stamped_t *h = ros_pose_stamped_from_cdr(bytes, len);
+    cr_assert_not_null(h);
+    cr_assert_eq(ros_pose_stamped_get_stamp_sec(h), 42);
+    cr_assert_eq(ros_pose_stamped_get_stamp_nanosec(h), 7);
+    cr_assert_str_eq(ros_pose_stamped_get_frame_id(h), "map");
+    double px, py, pz, ox, oy, oz, ow;
+    ros_pose_stamped_get_pose(h, &px, &py, &pz, &ox, &oy, &oz, &ow);
+    cr_assert_float_eq(px, 1.0, 1e-12);
+    cr_assert_float_eq(py, -2.0, 1e-12);
+    cr_assert_float_eq(pz, 0.5, 1e-12);
+    cr_assert_float_eq(ow, 1.0, 1e-12);
+    ros_pose_stamped_free(h);
+    ros_bytes_free(bytes, len);
+}
+
 // ============================================================================
 // InertiaStamped Tests (buffer-backed, from_cdr only)
 // ============================================================================
@@ -573,3 +608,32 @@ Test(geometry_msgs, inertia_stamped_roundtrip) {
     cr_assert_str_eq(ros_inertia_stamped_get_frame_id(h), "test_frame");
     ros_inertia_stamped_free(h);
 }
+
+Test(geometry_msgs, point32_pose2d_inertia_roundtrip) {
+    uint8_t buf[128];
+    size_t written = 0;
+
+    cr_assert_eq(ros_point32_encode(buf, sizeof(buf), &written, 1.5f, -2.0f, 3.25f), 0);
+    float fx = 0, fy = 0, fz = 0;
+    cr_assert_eq(ros_point32_decode(buf, written, &fx, &fy, &fz), 0);
+    cr_assert_float_eq(fx, 1.5f, 1e-6);
+    cr_assert_float_eq(fy, -2.0f, 1e-6);
+    cr_assert_float_eq(fz, 3.25f, 1e-6);
+
+    cr_assert_eq(ros_pose2d_encode(buf, sizeof(buf), &written, 1.0, 2.0, 1.25), 0);
+    double theta = 0;
+    cr_assert_eq(ros_pose2d_decode(buf, written, NULL, NULL, &theta), 0);
+    cr_assert_float_eq(theta, 1.25, 1e-12);
+
+    const double tensor[6] = {1.0, 0.1, 0.2, 2.0, 0.3, 3.0};
+    cr_assert_eq(ros_inertia_encode(buf, sizeof(buf), &written, 5.0, 0.1, 0.2, 0.3, tensor), 0);
+    double m = 0, cz = 0, out[6] = {0};
+    cr_assert_eq(ros_inertia_decode(buf, written, &m, NULL, NULL, &cz, out), 0);
+    cr_assert_float_eq(m, 5.0, 1e-12);
+    cr_assert_float_eq(cz, 0.3, 1e-12);
+    cr_assert_eq(memcmp(out, tensor, sizeof(tensor)), 0);
+
+    errno = 0;
+    cr_assert_eq(ros_inertia_encode(buf, sizeof(buf), &written, 5.0, 0, 0, 0, NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+}
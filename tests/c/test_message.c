@@ -0,0 +1,307 @@
+/**
+ * @file test_message.c
+ * @brief Criterion tests for the generic, schema-keyed message handle
+ *
+ * Tests edgefirst_message_* against the typed API:
+ *   - new: zero-valued messages for any registered schema
+ *   - deserialize/serialize: byte-exact round trips, errno on bad input
+ *   - NULL safety
+ */
+
+#include <criterion/criterion.h>
+#include <errno.h>
+#include <string.h>
+#include <stdint.h>
+#include "edgefirst/schemas.h"
+
+// ============================================================================
+// new
+// ============================================================================
+
+Test(message, new_time_is_zero) {
+    edgefirst_message_t* msg = edgefirst_message_new("builtin_interfaces/msg/Time");
+    cr_assert_not_null(msg);
+    cr_assert_str_eq(edgefirst_message_schema(msg), "builtin_interfaces/msg/Time");
+
+    size_t len = 0;
+    const uint8_t* cdr = edgefirst_message_as_cdr(msg, &len);
+    cr_assert_not_null(cdr);
+
+    int32_t sec = -1;
+    uint32_t nanosec = 1;
+    cr_assert_eq(ros_time_decode(cdr, len, &sec, &nanosec), 0);
+    cr_assert_eq(sec, 0);
+    cr_assert_eq(nanosec, 0);
+    edgefirst_message_free(msg);
+}
+
+Test(message, new_detect_decodes_with_typed_api) {
+    edgefirst_message_t* msg = edgefirst_message_new("edgefirst_msgs/msg/Detect");
+    cr_assert_not_null(msg);
+
+    size_t len = 0;
+    const uint8_t* cdr = edgefirst_message_as_cdr(msg, &len);
+    ros_detect_t* detect = ros_detect_from_cdr(cdr, len);
+    cr_assert_not_null(detect);
+    cr_assert_str_eq(ros_detect_get_frame_id(detect), "");
+    cr_assert_eq(ros_detect_get_boxes_len(detect), 0);
+    ros_detect_free(detect);
+    edgefirst_message_free(msg);
+}
+
+Test(message, new_round_trips_for_composite_schemas) {
+    // Nested messages, sequences and fixed-size covariance arrays.
+    const char* schemas[] = {
+        "nav_msgs/msg/Odometry",
+        "geometry_msgs/msg/PoseWithCovariance",
+        "sensor_msgs/msg/CameraInfo",
+        "edgefirst_msgs/msg/Model",
+    };
+    for (size_t i = 0; i < sizeof(schemas) / sizeof(schemas[0]); i++) {
+        edgefirst_message_t* msg = edgefirst_message_new(schemas[i]);
+        cr_assert_not_null(msg, "%s", schemas[i]);
+        size_t len = 0;
+        const uint8_t* cdr = edgefirst_message_as_cdr(msg, &len);
+        edgefirst_message_t* copy = edgefirst_message_deserialize(schemas[i], cdr, len);
+        cr_assert_not_null(copy, "%s", schemas[i]);
+        edgefirst_message_free(copy);
+        edgefirst_message_free(msg);
+    }
+}
+
+Test(message, new_unknown_schema) {
+    errno = 0;
+    cr_assert_null(edgefirst_message_new("unknown_msgs/msg/Foo"));
+    cr_assert_eq(errno, EINVAL);
+
+    errno = 0;
+    cr_assert_null(edgefirst_message_new("not a schema"));
+    cr_assert_eq(errno, EINVAL);
+
+    errno = 0;
+    cr_assert_null(edgefirst_message_new(NULL));
+    cr_assert_eq(errno, EINVAL);
+}
+
+// ============================================================================
+// deserialize / serialize
+// ============================================================================
+
+Test(message, deserialize_serialize_round_trip) {
+    uint8_t* bytes = NULL;
+    size_t bytes_len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &bytes_len, 5, 6, "camera"), 0);
+
+    edgefirst_message_t* msg =
+        edgefirst_message_deserialize("std_msgs/msg/Header", bytes, bytes_len);
+    cr_assert_not_null(msg);
+
+    size_t need = 0;
+    cr_assert_eq(edgefirst_message_serialize(msg, NULL, 0, &need), 0);
+    cr_assert_eq(need, bytes_len);
+
+    uint8_t out[64];
+    size_t written = 0;
+    cr_assert_eq(edgefirst_message_serialize(msg, out, sizeof(out), &written), 0);
+    cr_assert_eq(written, bytes_len);
+    cr_assert_eq(memcmp(out, bytes, bytes_len), 0);
+
+    errno = 0;
+    cr_assert_eq(edgefirst_message_serialize(msg, out, 4, &written), -1);
+    cr_assert_eq(errno, ENOBUFS);
+
+    edgefirst_message_free(msg);
+    ros_bytes_free(bytes, bytes_len);
+}
+
+Test(message, clone_is_independent) {
+    uint8_t* bytes = NULL;
+    size_t bytes_len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &bytes_len, 5, 6, "camera"), 0);
+    edgefirst_message_t* msg =
+        edgefirst_message_deserialize("std_msgs/msg/Header", bytes, bytes_len);
+    cr_assert_not_null(msg);
+
+    edgefirst_message_t* copy = edgefirst_message_clone(msg);
+    cr_assert_not_null(copy);
+    edgefirst_message_free(msg);
+
+    cr_assert_str_eq(edgefirst_message_schema(copy), "std_msgs/msg/Header");
+    size_t len = 0;
+    const uint8_t* cdr = edgefirst_message_as_cdr(copy, &len);
+    cr_assert_eq(len, bytes_len);
+    cr_assert_eq(memcmp(cdr, bytes, len), 0);
+
+    edgefirst_message_free(copy);
+    ros_bytes_free(bytes, bytes_len);
+}
+
+Test(message, equals_compares_fields) {
+    edgefirst_message_t* a = edgefirst_message_new("std_msgs/msg/Header");
+    edgefirst_message_t* b = edgefirst_message_new("std_msgs/msg/Header");
+    edgefirst_message_t* time = edgefirst_message_new("builtin_interfaces/msg/Time");
+    cr_assert_not_null(a);
+    cr_assert_not_null(b);
+    cr_assert_not_null(time);
+    cr_assert_eq(edgefirst_message_equals(a, b), 1);
+    cr_assert_eq(edgefirst_message_equals(a, time), 0);
+
+    uint8_t* bytes = NULL;
+    size_t bytes_len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &bytes_len, 5, 6, "camera"), 0);
+    edgefirst_message_t* c =
+        edgefirst_message_deserialize("std_msgs/msg/Header", bytes, bytes_len);
+    cr_assert_not_null(c);
+    cr_assert_eq(edgefirst_message_equals(a, c), 0);
+
+    // The same header big-endian.
+    static const uint8_t big[] = {
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 5,   0x00, 0x00, 0x00, 6,
+        0x00, 0x00, 0x00, 7,    'c',  'a',  'm',  'e', 'r',  'a',  0x00,
+    };
+    edgefirst_message_t* d = edgefirst_message_deserialize("std_msgs/msg/Header", big, sizeof(big));
+    cr_assert_not_null(d);
+    cr_assert_eq(edgefirst_message_equals(c, d), 1);
+
+    edgefirst_message_free(d);
+    edgefirst_message_free(c);
+    edgefirst_message_free(time);
+    edgefirst_message_free(b);
+    edgefirst_message_free(a);
+    ros_bytes_free(bytes, bytes_len);
+}
+
+Test(message, deserialize_rejects_bad_cdr) {
+    uint8_t* bytes = NULL;
+    size_t bytes_len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &bytes_len, 5, 6, "camera"), 0);
+
+    errno = 0;
+    cr_assert_null(edgefirst_message_deserialize("sensor_msgs/msg/Imu", bytes, bytes_len));
+    cr_assert_eq(errno, EBADMSG);
+
+    // Truncated inside frame_id.
+    errno = 0;
+    cr_assert_null(edgefirst_message_deserialize("std_msgs/msg/Header", bytes, bytes_len - 3));
+    cr_assert_eq(errno, EBADMSG);
+
+    ros_bytes_free(bytes, bytes_len);
+}
+
+Test(message, deserialize_null_data) {
+    errno = 0;
+    cr_assert_null(edgefirst_message_deserialize("std_msgs/msg/Header", NULL, 8));
+    cr_assert_eq(errno, EINVAL);
+}
+
+Test(message, heap_size_covers_cdr_bytes) {
+    edgefirst_message_t* msg = edgefirst_message_new("sensor_msgs/msg/Imu");
+    size_t len = 0;
+    edgefirst_message_as_cdr(msg, &len);
+    cr_assert_geq(edgefirst_message_heap_size(msg), len + strlen("sensor_msgs/msg/Imu"));
+    edgefirst_message_free(msg);
+}
+
+Test(message, json_roundtrip) {
+    edgefirst_message_t* msg = edgefirst_message_from_json(
+        "std_msgs/msg/Header", "{\"stamp\": {\"sec\": 5, \"nanosec\": 6}, \"frame_id\": \"camera\"}");
+    cr_assert_not_null(msg);
+
+    uint8_t* bytes = NULL;
+    size_t bytes_len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &bytes_len, 5, 6, "camera"), 0);
+    size_t len = 0;
+    const uint8_t* cdr = edgefirst_message_as_cdr(msg, &len);
+    cr_assert_eq(len, bytes_len);
+    cr_assert_eq(memcmp(cdr, bytes, len), 0);
+    ros_bytes_free(bytes, bytes_len);
+
+    size_t need = 0;
+    cr_assert_eq(edgefirst_message_to_json(msg, NULL, 0, &need), 0);
+    char json[128];
+    cr_assert_lt(need, sizeof(json));
+    cr_assert_eq(edgefirst_message_to_json(msg, json, sizeof(json), &need), 0);
+    cr_assert_eq(strlen(json), need);
+    cr_assert_str_eq(json, "{\"frame_id\":\"camera\",\"stamp\":{\"nanosec\":6,\"sec\":5}}");
+
+    errno = 0;
+    cr_assert_eq(edgefirst_message_to_json(msg, json, need, &need), -1);
+    cr_assert_eq(errno, ENOBUFS);
+
+    errno = 0;
+    cr_assert_eq(edgefirst_message_to_json(msg, NULL, 0, NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+    cr_assert_str_eq(edgefirst_last_error_message(), "`written` is NULL");
+
+    edgefirst_message_free(msg);
+}
+
+Test(message, from_json_rejects_bad_input) {
+    errno = 0;
+    cr_assert_null(edgefirst_message_from_json("std_msgs/msg/Header", "{\"stamp\":"));
+    cr_assert_eq(errno, EBADMSG);
+
+    errno = 0;
+    cr_assert_null(edgefirst_message_from_json("std_msgs/msg/Header", "{\"frame\": \"x\"}"));
+    cr_assert_eq(errno, EBADMSG);
+
+    errno = 0;
+    cr_assert_null(edgefirst_message_from_json("not_a/msg/Type", "{}"));
+    cr_assert_eq(errno, EINVAL);
+    cr_assert_str_eq(edgefirst_last_error_message(), "unknown schema not_a/msg/Type");
+
+    errno = 0;
+    cr_assert_null(edgefirst_message_from_json("std_msgs/msg/Header", NULL));
+    cr_assert_eq(errno, EINVAL);
+    cr_assert_str_eq(edgefirst_last_error_message(), "string argument is NULL");
+}
+
+// ============================================================================
+// Schema definitions
+// ============================================================================
+
+Test(message, schema_definition_includes_nested_types) {
+    cr_assert_str_eq(edgefirst_schema_encoding(), "ros2msg");
+
+    const char* text = edgefirst_schema_definition("std_msgs/msg/Header");
+    cr_assert_not_null(text);
+    cr_assert_eq(strncmp(text, "builtin_interfaces/Time stamp\n", 30), 0);
+    cr_assert_not_null(strstr(text, "\nMSG: builtin_interfaces/Time\nint32 sec\n"));
+    // Repeated lookups return the same string.
+    cr_assert_eq(edgefirst_schema_definition("std_msgs/msg/Header"), text);
+
+    errno = 0;
+    cr_assert_null(edgefirst_schema_definition("not_a/msg/Type"));
+    cr_assert_eq(errno, EINVAL);
+    errno = 0;
+    cr_assert_null(edgefirst_schema_definition(NULL));
+    cr_assert_eq(errno, EINVAL);
+}
+
+// ============================================================================
+// NULL safety
+// ============================================================================
+
+Test(message, null_handle) {
+    size_t len = 1;
+    cr_assert_null(edgefirst_message_as_cdr(NULL, &len));
+    cr_assert_eq(len, 0);
+    cr_assert_null(edgefirst_message_schema(NULL));
+    cr_assert_eq(edgefirst_message_heap_size(NULL), 0);
+    cr_assert_null(edgefirst_message_clone(NULL));
+
+    errno = 0;
+    cr_assert_eq(edgefirst_message_equals(NULL, NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+
+    errno = 0;
+    cr_assert_eq(edgefirst_message_serialize(NULL, NULL, 0, &len), -1);
+    cr_assert_eq(errno, EINVAL);
+
+    errno = 0;
+    cr_assert_eq(edgefirst_message_to_json(NULL, NULL, 0, &len), -1);
+    cr_assert_eq(errno, EINVAL);
+    cr_assert_str_eq(edgefirst_last_error_message(), "edgefirst_message_t handle is NULL");
+
+    edgefirst_message_free(NULL);
+}
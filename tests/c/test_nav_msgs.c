@@ -91,3 +91,70 @@ Test(nav_msgs, odometry_getters_null) {
     ros_odometry_get_twist(NULL, NULL, NULL, NULL, NULL, NULL, NULL);
     ros_odometry_get_twist_covariance(NULL, NULL);
 }
+
+Test(nav_msgs, map_meta_data_roundtrip) {
+    uint8_t buf[128];
+    size_t written = 0;
+    cr_assert_eq(ros_map_meta_data_encode(buf, sizeof(buf), &written, 3, 4, 0.05f, 200, 100,
+                                          -5.0, -2.5, 0.0, 0.0, 0.0, 0.0, 1.0),
+                 0);
+
+    int32_t sec = 0;
+    float resolution = 0;
+    uint32_t width = 0, height = 0;
+    double origin[7] = {0};
+    cr_assert_eq(ros_map_meta_data_decode(buf, written, &sec, NULL, &resolution, &width,
+                                          &height, origin),
+                 0);
+    cr_assert_eq(sec, 3);
+    cr_assert_eq(resolution, 0.05f);
+    cr_assert_eq(width, 200);
+    cr_assert_eq(height, 100);
+    cr_assert_eq(origin[0], -5.0);
+    cr_assert_eq(origin[1], -2.5);
+    cr_assert_eq(origin[6], 1.0);
+}
+
+Test(nav_msgs, occupancy_grid_view) {
+    edgefirst_message_t* msg = edgefirst_message_from_json(
+        "nav_msgs/msg/OccupancyGrid",
+        "{\"header\": {\"stamp\": {\"sec\": 1, \"nanosec\": 2}, \"frame_id\": \"map\"},"
+        " \"info\": {\"map_load_time\": {\"sec\": 9, \"nanosec\": 0}, \"resolution\": 0.5,"
+        " \"width\": 2, \"height\": 2, \"origin\": {\"position\": {\"x\": 1.0, \"y\": 2.0,"
+        " \"z\": 0.0}, \"orientation\": {\"x\": 0.0, \"y\": 0.0, \"z\": 0.0, \"w\": 1.0}}},"
+        " \"data\": [-1, 0, 50, 100]}");
+    cr_assert_not_null(msg);
+    size_t len = 0;
+    const uint8_t* cdr = edgefirst_message_as_cdr(msg, &len);
+
+    ros_occupancy_grid_t* grid = ros_occupancy_grid_from_cdr(cdr, len);
+    cr_assert_not_null(grid);
+    cr_assert_str_eq(ros_occupancy_grid_get_frame_id(grid), "map");
+
+    int32_t load_sec = 0;
+    float resolution = 0;
+    uint32_t width = 0, height = 0;
+    double origin[7] = {0};
+    cr_assert_eq(ros_occupancy_grid_get_info(grid, &load_sec, NULL, &resolution, &width,
+                                             &height, origin),
+                 0);
+    cr_assert_eq(load_sec, 9);
+    cr_assert_eq(resolution, 0.5f);
+    cr_assert_eq(width, 2);
+    cr_assert_eq(height, 2);
+    cr_assert_eq(origin[1], 2.0);
+
+    size_t n = 0;
+    const int8_t* cells = ros_occupancy_grid_get_data(grid, &n);
+    cr_assert_eq(ros_occupancy_grid_get_data_len(grid), 4);
+    cr_assert_eq(n, 4);
+    cr_assert_eq(cells[0], -1);
+    cr_assert_eq(cells[3], 100);
+
+    ros_occupancy_grid_free(grid);
+    edgefirst_message_free(msg);
+
+    errno = 0;
+    cr_assert_eq(ros_occupancy_grid_get_info(NULL, NULL, NULL, NULL, NULL, NULL, NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+}
@@ -1,10 +1,6 @@
 /**
  * @file test_rosgraph_msgs.c
  * @brief Criterion tests for rosgraph_msgs types
- *
- * Note: Clock type has no FFI bindings in the current v2 buffer-view API.
- * The Clock type uses builtin_interfaces::Time which is available via
- * ros_time_encode/ros_time_decode. This file is a placeholder.
  */
 
 #include <criterion/criterion.h>
@@ -32,3 +28,24 @@ Test(rosgraph_msgs, clock_via_time_encode_decode) {
     cr_assert_eq(sec, 1000000);
     cr_assert_eq(nanosec, 123456789);
 }
+
+// ============================================================================
+// Clock (CdrFixed)
+// ============================================================================
+
+Test(rosgraph_msgs, clock_encode_decode) {
+    uint8_t buf[16];
+    size_t written = 0;
+    cr_assert_eq(ros_clock_encode(buf, sizeof(buf), &written, 42, 500), 0);
+    cr_assert_eq(written, 12);
+
+    int32_t sec = 0;
+    uint32_t nanosec = 0;
+    cr_assert_eq(ros_clock_decode(buf, written, &sec, &nanosec), 0);
+    cr_assert_eq(sec, 42);
+    cr_assert_eq(nanosec, 500);
+
+    errno = 0;
+    cr_assert_eq(ros_clock_decode(buf, 2, &sec, &nanosec), -1);
+    cr_assert_eq(errno, EBADMSG);
+}
@@ -127,6 +127,113 @@ Test(sensor_msgs, image_free_null) {
     ros_image_free(NULL);
 }
 
+Test(sensor_msgs, image_data_lock_outlives_free) {
+    uint8_t pixel_data[] = {1, 2, 3, 4};
+    ros_image_builder_t *b = ros_image_builder_new();
+    ros_image_builder_set_height(b, 1);
+    ros_image_builder_set_width(b, 4);
+    ros_image_builder_set_step(b, 4);
+    cr_assert_eq(ros_image_builder_set_data(b, pixel_data, sizeof(pixel_data)), 0);
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_image_builder_build(b, &bytes, &len), 0);
+    ros_image_builder_free(b);
+
+    ros_image_t *view = ros_image_from_cdr(bytes, len);
+    cr_assert_not_null(view);
+    ros_image_t *handle = ros_image_clone(view);
+    ros_image_free(view);
+    ros_bytes_free(bytes, len);
+    cr_assert_not_null(handle);
+    size_t data_len = 0;
+    const uint8_t *data = ros_image_data_lock(handle, &data_len);
+    cr_assert_eq(data_len, sizeof(pixel_data));
+    cr_assert_not_null(ros_image_data_lock(handle, NULL));
+
+    // Freed while locked: the handle and its bytes live until the last
+    // unlock.
+    ros_image_free(handle);
+    cr_assert_eq(memcmp(data, pixel_data, sizeof(pixel_data)), 0);
+    cr_assert_eq(ros_image_data_unlock(handle), 0);
+    cr_assert_eq(ros_image_data_unlock(handle), 0);
+}
+
+Test(sensor_msgs, image_data_lock_rejects_borrowed_handle) {
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    ros_image_builder_t *b = ros_image_builder_new();
+    cr_assert_eq(ros_image_builder_build(b, &bytes, &len), 0);
+    ros_image_builder_free(b);
+    ros_image_t *handle = ros_image_from_cdr(bytes, len);
+
+    errno = 0;
+    size_t data_len = 1;
+    cr_assert_null(ros_image_data_lock(handle, &data_len));
+    cr_assert_eq(errno, EINVAL);
+    cr_assert_eq(data_len, 0);
+    cr_assert_str_eq(edgefirst_last_error_message(),
+                     "ros_image_t borrows its CDR bytes; lock a ros_image_clone() copy");
+
+    ros_image_free(handle);
+    ros_bytes_free(bytes, len);
+}
+
+Test(sensor_msgs, image_retain_with_data_lock) {
+    uint8_t pixel_data[] = {1, 2, 3, 4};
+    ros_image_builder_t *b = ros_image_builder_new();
+    ros_image_builder_set_height(b, 1);
+    ros_image_builder_set_width(b, 4);
+    ros_image_builder_set_step(b, 4);
+    cr_assert_eq(ros_image_builder_set_data(b, pixel_data, sizeof(pixel_data)), 0);
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_image_builder_build(b, &bytes, &len), 0);
+    ros_image_builder_free(b);
+
+    ros_image_t *view = ros_image_from_cdr(bytes, len);
+    ros_image_t *handle = ros_image_clone(view);
+    ros_image_free(view);
+    cr_assert_not_null(handle);
+    cr_assert_eq(ros_image_retain(handle), handle);
+    size_t data_len = 0;
+    const uint8_t *data = ros_image_data_lock(handle, &data_len);
+
+    // Neither dropping one reference nor the unlock destroys the handle
+    // while the other reference lives.
+    ros_image_release(handle);
+    cr_assert_eq(ros_image_data_unlock(handle), 0);
+    cr_assert_eq(ros_image_get_width(handle), 4u);
+
+    data = ros_image_data_lock(handle, &data_len);
+    ros_image_free(handle);
+    cr_assert_eq(memcmp(data, pixel_data, sizeof(pixel_data)), 0);
+    cr_assert_eq(ros_image_data_unlock(handle), 0);
+
+    ros_bytes_free(bytes, len);
+}
+
+Test(sensor_msgs, image_data_unlock_without_lock) {
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    ros_image_builder_t *b = ros_image_builder_new();
+    cr_assert_eq(ros_image_builder_build(b, &bytes, &len), 0);
+    ros_image_builder_free(b);
+    ros_image_t *handle = ros_image_from_cdr(bytes, len);
+
+    errno = 0;
+    cr_assert_eq(ros_image_data_unlock(handle), -1);
+    cr_assert_eq(errno, EINVAL);
+    errno = 0;
+    cr_assert_eq(ros_image_data_unlock(NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+    size_t data_len = 1;
+    cr_assert_null(ros_image_data_lock(NULL, &data_len));
+    cr_assert_eq(data_len, 0);
+
+    ros_image_free(handle);
+    ros_bytes_free(bytes, len);
+}
+
 Test(sensor_msgs, image_getters_null) {
     cr_assert_eq(ros_image_get_stamp_sec(NULL), 0);
     cr_assert_eq(ros_image_get_stamp_nanosec(NULL), 0);
@@ -301,6 +408,74 @@ Test(sensor_msgs, point_cloud2_getters_null) {
     cr_assert_eq(ros_point_cloud2_get_fields_len(NULL), 0);
 }
 
+static void _add_pc2_field(ros_point_cloud2_builder_t *b, const char *name,
+                           uint32_t offset, uint8_t datatype) {
+    ros_point_field_builder_t *f = ros_point_field_builder_new();
+    cr_assert_eq(ros_point_field_builder_set_name(f, name), 0);
+    ros_point_field_builder_set_offset(f, offset);
+    ros_point_field_builder_set_datatype(f, datatype);
+    ros_point_field_builder_set_count(f, 1);
+    cr_assert_eq(ros_point_cloud2_builder_add_field(b, f), 0);
+    ros_point_field_builder_free(f);
+}
+
+Test(sensor_msgs, point_cloud2_decode_columns) {
+    struct { float x, y, z; uint32_t cluster_id; } pts[2] = {
+        {1.0f, 2.0f, 3.0f, 7}, {4.0f, 5.0f, 6.0f, 16777217u},
+    };
+    ros_point_cloud2_builder_t *b = ros_point_cloud2_builder_new();
+    ros_point_cloud2_builder_set_height(b, 1);
+    ros_point_cloud2_builder_set_width(b, 2);
+    _add_pc2_field(b, "x", 0, 7);          // FLOAT32
+    _add_pc2_field(b, "y", 4, 7);
+    _add_pc2_field(b, "z", 8, 7);
+    _add_pc2_field(b, "cluster_id", 12, 6); // UINT32
+    ros_point_cloud2_builder_set_point_step(b, sizeof(pts[0]));
+    ros_point_cloud2_builder_set_row_step(b, sizeof(pts));
+    cr_assert_eq(ros_point_cloud2_builder_set_data(b, (const uint8_t *) pts, sizeof(pts)), 0);
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_point_cloud2_builder_build(b, &bytes, &len), 0);
+    ros_point_cloud2_builder_free(b);
+
+    ros_point_cloud2_t *cloud = ros_point_cloud2_from_cdr(bytes, len);
+    cr_assert_not_null(cloud);
+    edgefirst_pcd_t *pcd = edgefirst_pcd_decode(cloud);
+    cr_assert_not_null(pcd);
+    ros_point_cloud2_free(cloud);
+    ros_bytes_free(bytes, len);
+
+    cr_assert_eq(edgefirst_pcd_count(pcd), 2);
+    cr_assert_float_eq(edgefirst_pcd_x(pcd)[1], 4.0f, 1e-6);
+    cr_assert_float_eq(edgefirst_pcd_y(pcd)[0], 2.0f, 1e-6);
+    cr_assert_float_eq(edgefirst_pcd_z(pcd)[1], 6.0f, 1e-6);
+    const uint32_t *ids = edgefirst_pcd_cluster_id(pcd);
+    cr_assert_eq(ids[0], 7);
+    cr_assert_eq(ids[1], 16777217u);
+    cr_assert_float_eq(edgefirst_pcd_field(pcd, "cluster_id")[0], 7.0f, 1e-6);
+
+    cr_assert_eq(edgefirst_pcd_field_count(pcd), 4);
+    cr_assert_str_eq(edgefirst_pcd_field_name(pcd, 3), "cluster_id");
+    errno = 0;
+    cr_assert_null(edgefirst_pcd_field_name(pcd, 4));
+    cr_assert_eq(errno, EINVAL);
+    errno = 0;
+    cr_assert_null(edgefirst_pcd_field(pcd, "intensity"));
+    cr_assert_eq(errno, ENOENT);
+
+    edgefirst_pcd_free(pcd);
+}
+
+Test(sensor_msgs, point_cloud2_decode_null) {
+    errno = 0;
+    cr_assert_null(edgefirst_pcd_decode(NULL));
+    cr_assert_eq(errno, EINVAL);
+    cr_assert_eq(edgefirst_pcd_count(NULL), 0);
+    cr_assert_null(edgefirst_pcd_x(NULL));
+    cr_assert_null(edgefirst_pcd_cluster_id(NULL));
+    edgefirst_pcd_free(NULL);
+}
+
 // ============================================================================
 // CameraInfo Tests (buffer-backed — no encode, test from_cdr + getters)
 // ============================================================================
@@ -440,3 +615,59 @@ Test(sensor_msgs, new_types_free_null_ok) {
     ros_temperature_free(NULL);
     ros_battery_state_free(NULL);
 }
+
+Test(sensor_msgs, region_of_interest_roundtrip) {
+    uint8_t buf[32];
+    size_t written = 0;
+    cr_assert_eq(ros_region_of_interest_encode(buf, sizeof(buf), &written, 10, 20, 480, 640, true), 0);
+    uint32_t x = 0, y = 0, h = 0, w = 0;
+    bool rectify = false;
+    cr_assert_eq(ros_region_of_interest_decode(buf, written, &x, &y, &h, &w, &rectify), 0);
+    cr_assert_eq(x, 10);
+    cr_assert_eq(y, 20);
+    cr_assert_eq(h, 480);
+    cr_assert_eq(w, 640);
+    cr_assert(rectify);
+}
+
+Test(sensor_msgs, laser_scan_view) {
+    edgefirst_message_t* msg = edgefirst_message_from_json(
+        "sensor_msgs/msg/LaserScan",
+        "{\"header\": {\"stamp\": {\"sec\": 7, \"nanosec\": 8}, \"frame_id\": \"laser\"},"
+        " \"angle_min\": -1.5, \"angle_max\": 1.5, \"angle_increment\": 0.5,"
+        " \"time_increment\": 0.001, \"scan_time\": 0.1,"
+        " \"range_min\": 0.2, \"range_max\": 30.0,"
+        " \"ranges\": [1.0, 2.0, 3.0], \"intensities\": [100.0]}");
+    cr_assert_not_null(msg);
+    size_t len = 0;
+    const uint8_t* cdr = edgefirst_message_as_cdr(msg, &len);
+
+    ros_laser_scan_t* scan = ros_laser_scan_from_cdr(cdr, len);
+    cr_assert_not_null(scan);
+    cr_assert_eq(ros_laser_scan_get_stamp_sec(scan), 7);
+    cr_assert_str_eq(ros_laser_scan_get_frame_id(scan), "laser");
+    cr_assert_eq(ros_laser_scan_get_angle_min(scan), -1.5f);
+    cr_assert_eq(ros_laser_scan_get_range_max(scan), 30.0f);
+
+    float ranges[2] = {0};
+    cr_assert_eq(ros_laser_scan_get_ranges_len(scan), 3);
+    cr_assert_eq(ros_laser_scan_get_ranges(scan, ranges, 2), 3);
+    cr_assert_eq(ranges[0], 1.0f);
+    cr_assert_eq(ranges[1], 2.0f);
+    float intensity = 0;
+    cr_assert_eq(ros_laser_scan_get_intensities(scan, &intensity, 1), 1);
+    cr_assert_eq(intensity, 100.0f);
+
+    ros_laser_scan_t* copy = ros_laser_scan_clone(scan);
+    cr_assert_eq(ros_laser_scan_equals(scan, copy), 1);
+    size_t size = 0;
+    cr_assert_eq(ros_laser_scan_serialized_size(copy, &size), 0);
+    cr_assert_eq(size, len);
+    ros_laser_scan_free(copy);
+    ros_laser_scan_free(scan);
+    edgefirst_message_free(msg);
+
+    errno = 0;
+    cr_assert_eq(ros_laser_scan_get_angle_max(NULL), 0.0f);
+    cr_assert_eq(errno, EINVAL);
+}
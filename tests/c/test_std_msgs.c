@@ -124,6 +124,118 @@ Test(std_msgs, header_encode_special_chars) {
     ros_bytes_free(bytes, len);
 }
 
+Test(std_msgs, header_clone_outlives_buffer) {
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &len, 42, 999, "test_frame"), 0);
+
+    ros_header_t *handle = ros_header_from_cdr(bytes, len);
+    cr_assert_not_null(handle);
+    ros_header_t *copy = ros_header_clone(handle);
+    cr_assert_not_null(copy);
+
+    // The clone owns its bytes: the source handle and buffer can go.
+    ros_header_free(handle);
+    memset(bytes, 0, len);
+    ros_bytes_free(bytes, len);
+
+    cr_assert_eq(ros_header_get_stamp_sec(copy), 42);
+    cr_assert_eq(ros_header_get_stamp_nanosec(copy), 999);
+    cr_assert_str_eq(ros_header_get_frame_id(copy), "test_frame");
+    ros_header_free(copy);
+
+    errno = 0;
+    cr_assert_null(ros_header_clone(NULL));
+    cr_assert_eq(errno, EINVAL);
+}
+
+Test(std_msgs, header_equals_ignores_padding) {
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &len, 42, 999, "test_frame"), 0);
+
+    // The same header padded to a 4-byte boundary: the bytes differ, the
+    // fields do not.
+    uint8_t padded[32] = {0};
+    size_t padded_len = (len + 3) & ~(size_t)3;
+    cr_assert_lt(len, padded_len);
+    memcpy(padded, bytes, len);
+
+    ros_header_t *a = ros_header_from_cdr(bytes, len);
+    ros_header_t *b = ros_header_from_cdr(padded, padded_len);
+    cr_assert_not_null(a);
+    cr_assert_not_null(b);
+    cr_assert_eq(ros_header_equals(a, b), 1);
+    cr_assert_eq(ros_header_equals(a, a), 1);
+
+    uint8_t *other = NULL;
+    size_t other_len = 0;
+    cr_assert_eq(ros_header_encode(&other, &other_len, 42, 999, "test_fram"), 0);
+    ros_header_t *c = ros_header_from_cdr(other, other_len);
+    cr_assert_not_null(c);
+    cr_assert_eq(ros_header_equals(a, c), 0);
+
+    errno = 0;
+    cr_assert_eq(ros_header_equals(a, NULL), -1);
+    cr_assert_eq(errno, EINVAL);
+
+    ros_header_free(c);
+    ros_header_free(b);
+    ros_header_free(a);
+    ros_bytes_free(other, other_len);
+    ros_bytes_free(bytes, len);
+}
+
+Test(std_msgs, header_set_stamp_now) {
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &len, 0, 0, "test_frame"), 0);
+
+    int32_t before = 0;
+    cr_assert_eq(ros_time_set_now(&before, NULL, ROS_CLOCK_REALTIME), 0);
+    cr_assert_eq(ros_header_set_stamp_now(bytes, len, ROS_CLOCK_REALTIME), 0);
+
+    ros_header_t *handle = ros_header_from_cdr(bytes, len);
+    cr_assert_not_null(handle);
+    cr_assert_geq(ros_header_get_stamp_sec(handle), before);
+    cr_assert_str_eq(ros_header_get_frame_id(handle), "test_frame");
+    ros_header_free(handle);
+
+    errno = 0;
+    cr_assert_eq(ros_header_set_stamp_now(bytes, len, -1), -1);
+    cr_assert_eq(errno, EINVAL);
+    errno = 0;
+    cr_assert_eq(ros_header_set_stamp_now(NULL, len, ROS_CLOCK_REALTIME), -1);
+    cr_assert_eq(errno, EINVAL);
+
+    ros_bytes_free(bytes, len);
+}
+
+Test(std_msgs, header_retain_outlives_free) {
+    uint8_t *bytes = NULL;
+    size_t len = 0;
+    cr_assert_eq(ros_header_encode(&bytes, &len, 42, 999, "test_frame"), 0);
+
+    ros_header_t *handle = ros_header_from_cdr(bytes, len);
+    cr_assert_not_null(handle);
+    cr_assert_eq(ros_header_retain(handle), handle);
+    cr_assert_eq(ros_header_retain(handle), handle);
+
+    // Three references: each free or release drops one.
+    ros_header_free(handle);
+    ros_header_release(handle);
+    cr_assert_eq(ros_header_get_stamp_sec(handle), 42);
+    cr_assert_str_eq(ros_header_get_frame_id(handle), "test_frame");
+    ros_header_release(handle);
+
+    errno = 0;
+    cr_assert_null(ros_header_retain(NULL));
+    cr_assert_eq(errno, EINVAL);
+    ros_header_release(NULL);
+
+    ros_bytes_free(bytes, len);
+}
+
 Test(std_msgs, header_from_cdr_null) {
     errno = 0;
     ros_header_t *handle = ros_header_from_cdr(NULL, 100);
@@ -149,3 +261,18 @@ Test(std_msgs, header_getters_null_handle) {
     cr_assert_eq(ros_header_get_stamp_nanosec(NULL), 0);
     cr_assert_null(ros_header_get_frame_id(NULL));
 }
+
+Test(std_msgs, color_rgba_roundtrip) {
+    uint8_t buf[32];
+    size_t written = 0;
+    cr_assert_eq(ros_color_rgba_encode(NULL, 0, &written, 0, 0, 0, 0), 0);
+    cr_assert_eq(written, 20);
+
+    cr_assert_eq(ros_color_rgba_encode(buf, sizeof(buf), &written, 1.0f, 0.5f, 0.25f, 0.75f), 0);
+    float r = 0, g = 0, b = 0, a = 0;
+    cr_assert_eq(ros_color_rgba_decode(buf, written, &r, &g, &b, &a), 0);
+    cr_assert_eq(r, 1.0f);
+    cr_assert_eq(g, 0.5f);
+    cr_assert_eq(b, 0.25f);
+    cr_assert_eq(a, 0.75f);
+}
@@ -95,6 +95,21 @@ TEST_CASE("HeaderBuilder encode_into too-small buffer", "[builder][header][error
     CHECK(!len.has_value());
 }
 
+TEST_CASE("HeaderBuilder encoded_size sizes encode_into", "[builder][header][encode_into]") {
+    auto b = ef::HeaderBuilder::create();
+    REQUIRE(b.has_value());
+    b->stamp({7, 8});
+    auto fid = b->frame_id("sized");
+    REQUIRE(fid.has_value());
+
+    auto need = b->encoded_size();
+    REQUIRE(need.has_value());
+    std::vector<std::uint8_t> buf(*need);
+    auto len = b->encode_into({buf.data(), buf.size()});
+    REQUIRE(len.has_value());
+    CHECK(*len == *need);
+}
+
 // ============================================================================
 // sensor_msgs — ImuBuilder
 // ============================================================================
@@ -287,3 +287,24 @@ TEST_CASE("NavSatStatus decode error", "[cdr_fixed][nav_sat_status]") {
     auto result = ef::NavSatStatus::decode(empty);
     REQUIRE_FALSE(result.has_value());
 }
+
+// ---------------------------------------------------------------------------
+// value_or_throw
+// ---------------------------------------------------------------------------
+
+TEST_CASE("value_or_throw unwraps or throws Exception", "[cdr_fixed][errors]") {
+    std::uint8_t buf[64]{};
+    auto written =
+        ef::value_or_throw(ef::Time{7, 8}.encode(ef::span<std::uint8_t>{buf, sizeof(buf)}));
+    auto t = ef::value_or_throw(ef::Time::decode(ef::span<const std::uint8_t>{buf, written}));
+    CHECK(t.sec == 7);
+
+    try {
+        (void)ef::value_or_throw(ef::Time::decode(ef::span<const std::uint8_t>{buf, 2}));
+        FAIL("expected an exception");
+    } catch (const ef::Exception& e) {
+        CHECK(e.error().code == EBADMSG);
+        CHECK(e.code() == std::errc::bad_message);
+        CHECK(std::string(e.what()).find("ros_time_decode") != std::string::npos);
+    }
+}
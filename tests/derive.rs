@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Messages declared with `#[derive(EdgeFirstMessage)]`: encoding, the
+//! generated definition and registration, and the exported C accessors.
+
+use edgefirst_schemas::builtin_interfaces::Time;
+use edgefirst_schemas::custom::{CdrValue, CustomMessage};
+use edgefirst_schemas::geometry_msgs::Vector3;
+use edgefirst_schemas::message::Message;
+use edgefirst_schemas::schema_registry::{definition, full_definition, registered, SchemaType};
+use edgefirst_schemas::std_msgs::Header;
+use edgefirst_schemas_derive::EdgeFirstMessage;
+
+#[derive(Clone, Debug, PartialEq, EdgeFirstMessage)]
+#[edgefirst(schema = "derive_msgs/msg/Joint")]
+struct Joint {
+    name: String,
+    position: f64,
+    limits: [f32; 2],
+}
+
+#[derive(EdgeFirstMessage)]
+#[edgefirst(schema = "derive_msgs/msg/Gripper", ffi = "derive_gripper")]
+struct Gripper {
+    header: Header<Vec<u8>>,
+    joints: Vec<Joint>,
+    force: Vector3,
+    opening: f32,
+    holding: bool,
+    mode: u8,
+}
+
+fn gripper() -> Gripper {
+    Gripper {
+        header: Header::builder()
+            .stamp(Time::new(5, 10))
+            .frame_id("hand")
+            .build()
+            .unwrap(),
+        joints: vec![
+            Joint {
+                name: "left".into(),
+                position: 0.25,
+                limits: [0.0, 0.5],
+            },
+            Joint {
+                name: "right".into(),
+                position: -0.25,
+                limits: [-0.5, 0.0],
+            },
+        ],
+        force: Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: -9.8,
+        },
+        opening: 0.04,
+        holding: true,
+        mode: 1,
+    }
+}
+
+extern "C" {
+    fn edgefirst_last_error_message() -> *const std::ffi::c_char;
+}
+
+fn errno() -> i32 {
+    errno::errno().0
+}
+
+#[test]
+fn definition_follows_fields() {
+    assert_eq!(Gripper::SCHEMA_NAME, "derive_msgs/msg/Gripper");
+    assert_eq!(Joint::msg_type(), "derive_msgs/Joint");
+    assert_eq!(
+        Joint::definition(),
+        "string name\nfloat64 position\nfloat32[2] limits\n"
+    );
+    assert_eq!(
+        Gripper::definition(),
+        "std_msgs/Header header\n\
+         derive_msgs/Joint[] joints\n\
+         geometry_msgs/Vector3 force\n\
+         float32 opening\n\
+         bool holding\n\
+         uint8 mode\n"
+    );
+}
+
+#[test]
+fn round_trip() {
+    let msg = gripper();
+    let cdr = msg.to_cdr();
+    let back = Gripper::from_cdr(&cdr).unwrap();
+    assert_eq!(back.header.stamp(), Time::new(5, 10));
+    assert_eq!(back.header.frame_id(), "hand");
+    assert_eq!(back.joints, msg.joints);
+    assert_eq!(back.force.z, -9.8);
+    assert_eq!((back.opening, back.holding, back.mode), (0.04, true, 1));
+    assert_eq!(back.to_cdr(), cdr);
+
+    let err = Gripper::from_cdr(&cdr[..cdr.len() - 2]).err().unwrap();
+    assert_eq!(err.field(), Some("holding"));
+}
+
+#[test]
+fn register_includes_nested_messages() {
+    Gripper::register().unwrap();
+    Gripper::register().unwrap();
+
+    assert_eq!(definition(Joint::SCHEMA_NAME), Some(Joint::definition()));
+    assert!(registered(Joint::SCHEMA_NAME).is_some());
+    let full = full_definition(Gripper::SCHEMA_NAME).unwrap();
+    assert!(full.contains("\nMSG: derive_msgs/Joint\nstring name\n"));
+
+    let validate = registered(Gripper::SCHEMA_NAME).unwrap().validate.unwrap();
+    let cdr = gripper().to_cdr();
+    assert!(validate(&cdr).is_ok());
+    assert!(validate(&cdr[..cdr.len() - 1]).is_err());
+}
+
+#[test]
+fn type_erased() {
+    let boxed: Box<dyn Message> = Box::new(gripper());
+    assert_eq!(boxed.schema_name(), "derive_msgs/msg/Gripper");
+    assert_eq!(boxed.to_cdr(), gripper().to_cdr());
+    let copy = boxed.clone();
+    assert_eq!(copy.downcast_ref::<Gripper>().unwrap().mode, 1);
+}
+
+#[test]
+fn c_accessors() {
+    let cdr = gripper().to_cdr();
+    unsafe {
+        assert_eq!(derive_gripper_register(), 0);
+
+        let mut opening = 0.0f32;
+        assert_eq!(
+            derive_gripper_get_opening(cdr.as_ptr(), cdr.len(), &mut opening),
+            0
+        );
+        assert_eq!(opening, 0.04);
+        let mut holding = false;
+        assert_eq!(
+            derive_gripper_get_holding(cdr.as_ptr(), cdr.len(), &mut holding),
+            0
+        );
+        assert!(holding);
+
+        let mut mode = 0u8;
+        assert_eq!(derive_gripper_get_mode(cdr.as_ptr(), 3, &mut mode), -1);
+        assert_eq!(errno(), libc::EBADMSG);
+        let msg = std::ffi::CStr::from_ptr(edgefirst_last_error_message());
+        assert!(msg
+            .to_str()
+            .unwrap()
+            .starts_with("derive_msgs/msg/Gripper: "));
+        assert_eq!(derive_gripper_get_mode(std::ptr::null(), 0, &mut mode), -1);
+        assert_eq!(errno(), libc::EINVAL);
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! `edgefirst_set_allocator` racing the first returned buffers.
+//!
+//! The allocator is process-wide and can only be registered once, so this
+//! binary holds a single test. Whichever side wins, every buffer must come
+//! from one allocator: a registered `free_fn` must never see a buffer the
+//! Rust allocator handed out.
+
+use edgefirst_schemas::builtin_interfaces::Time;
+use edgefirst_schemas::std_msgs;
+use std::collections::HashSet;
+use std::ffi::{c_void, CString};
+use std::os::raw::c_char;
+use std::sync::{Barrier, Mutex};
+
+extern "C" {
+    fn edgefirst_set_allocator(
+        malloc_fn: Option<unsafe extern "C" fn(usize) -> *mut c_void>,
+        free_fn: Option<unsafe extern "C" fn(*mut c_void)>,
+    ) -> i32;
+    fn ros_header_encode(
+        out_bytes: *mut *mut u8,
+        out_len: *mut usize,
+        stamp_sec: i32,
+        stamp_nanosec: u32,
+        frame_id: *const c_char,
+    ) -> i32;
+    fn ros_bytes_free(bytes: *mut u8, len: usize);
+}
+
+static HOST_BUFFERS: Mutex<Option<HashSet<usize>>> = Mutex::new(None);
+
+unsafe extern "C" fn tracking_malloc(size: usize) -> *mut c_void {
+    let p = libc::malloc(size.max(1));
+    HOST_BUFFERS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashSet::new)
+        .insert(p as usize);
+    p
+}
+
+unsafe extern "C" fn tracking_free(p: *mut c_void) {
+    let known = HOST_BUFFERS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashSet::new)
+        .remove(&(p as usize));
+    assert!(known, "free_fn received a buffer malloc_fn did not return");
+    libc::free(p);
+}
+
+#[test]
+fn registration_races_returned_buffers() {
+    const THREADS: usize = 8;
+    const PER_THREAD: usize = 64;
+    let start = Barrier::new(THREADS + 1);
+    let frame_id = CString::new("camera").unwrap();
+
+    let (registered, buffers) = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..THREADS)
+            .map(|_| {
+                s.spawn(|| {
+                    start.wait();
+                    (0..PER_THREAD)
+                        .map(|_| {
+                            let (mut p, mut len) = (std::ptr::null_mut(), 0);
+                            let rc = unsafe {
+                                ros_header_encode(&mut p, &mut len, 1, 2, frame_id.as_ptr())
+                            };
+                            assert_eq!(rc, 0);
+                            (p as usize, len)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        start.wait();
+        let rc = unsafe { edgefirst_set_allocator(Some(tracking_malloc), Some(tracking_free)) };
+        let buffers: Vec<_> = workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap())
+            .collect();
+        (rc == 0, buffers)
+    });
+
+    let host = HOST_BUFFERS.lock().unwrap().clone().unwrap_or_default();
+    let from_host = buffers.iter().filter(|(p, _)| host.contains(p)).count();
+    if registered {
+        assert_eq!(
+            from_host,
+            buffers.len(),
+            "Rust buffers returned after registration"
+        );
+    } else {
+        assert_eq!(from_host, 0, "host buffers returned without registration");
+    }
+    let expected = std_msgs::Header::builder()
+        .stamp(Time::new(1, 2))
+        .frame_id("camera")
+        .build()
+        .unwrap();
+    for (p, len) in buffers {
+        let bytes = unsafe { std::slice::from_raw_parts(p as *const u8, len) };
+        assert_eq!(bytes, expected.as_cdr());
+        unsafe { ros_bytes_free(p as *mut u8, len) };
+    }
+    if registered {
+        assert!(HOST_BUFFERS.lock().unwrap().as_ref().unwrap().is_empty());
+    }
+}
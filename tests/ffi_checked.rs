@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Handle type checks of the `ffi-checked` C API build.
+//!
+//! Each test misuses a handle the way a C caller could — wrong type, used
+//! after free, a borrowed child freed or outliving its parent, used after
+//! its last reference is released — and checks the call fails with
+//! `EINVAL` and a message naming the handle types.
+
+#![allow(non_camel_case_types)]
+
+use edgefirst_schemas::builtin_interfaces::Time;
+use edgefirst_schemas::edgefirst_msgs::{self, DetectBoxView};
+use edgefirst_schemas::std_msgs;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::Mutex;
+
+enum ros_header_t {}
+enum ros_image_t {}
+enum ros_detect_t {}
+enum ros_box_t {}
+
+extern "C" {
+    fn edgefirst_last_error_message() -> *const c_char;
+
+    fn ros_header_from_cdr(data: *const u8, len: usize) -> *mut ros_header_t;
+    fn ros_header_free(view: *mut ros_header_t);
+    fn ros_header_get_stamp_sec(view: *const ros_header_t) -> i32;
+    fn ros_header_retain(view: *mut ros_header_t) -> *mut ros_header_t;
+    fn ros_header_release(view: *mut ros_header_t);
+
+    fn ros_image_get_width(view: *const ros_image_t) -> u32;
+    fn ros_image_free(view: *mut ros_image_t);
+
+    fn ros_detect_from_cdr(data: *const u8, len: usize) -> *mut ros_detect_t;
+    fn ros_detect_free(view: *mut ros_detect_t);
+    fn ros_detect_get_box(view: *const ros_detect_t, index: u32) -> *const ros_box_t;
+    fn ros_box_get_score(view: *const ros_box_t) -> f32;
+    fn ros_box_free(view: *mut ros_box_t);
+}
+
+/// Held by every test: a handle allocated on another thread could reuse a
+/// freed address and make a use-after-free look valid.
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Clear errno, run `f`, and return errno with the last error message.
+fn failure(f: impl FnOnce()) -> (i32, String) {
+    errno::set_errno(errno::Errno(0));
+    f();
+    let code = errno::errno().0;
+    let msg = unsafe { CStr::from_ptr(edgefirst_last_error_message()) };
+    (code, msg.to_string_lossy().into_owned())
+}
+
+fn header() -> std_msgs::Header<Vec<u8>> {
+    std_msgs::Header::builder()
+        .stamp(Time::new(42, 0))
+        .frame_id("base_link")
+        .build()
+        .unwrap()
+}
+
+/// A Detect with boxes scored 0.9 and 0.4.
+fn detect() -> edgefirst_msgs::Detect<Vec<u8>> {
+    let bx = |score| DetectBoxView {
+        center_x: 0.5,
+        center_y: 0.5,
+        width: 0.1,
+        height: 0.1,
+        label: "person",
+        score,
+        distance: 0.0,
+        speed: 0.0,
+        track_id: "",
+        track_lifetime: 0,
+        track_created: Time::new(0, 0),
+    };
+    edgefirst_msgs::Detect::builder()
+        .boxes(&[bx(0.9), bx(0.4)])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn wrong_handle_type_is_rejected() {
+    let _serial = SERIAL.lock().unwrap();
+    let msg = detect();
+    unsafe {
+        let d = ros_detect_from_cdr(msg.as_cdr().as_ptr(), msg.as_cdr().len());
+        assert!(!d.is_null());
+        let img = d as *mut ros_image_t;
+
+        let (code, text) = failure(|| assert_eq!(ros_image_get_width(img), 0));
+        assert_eq!(code, libc::EINVAL);
+        assert_eq!(text, "expected a ros_image_t handle, got a ros_detect_t");
+
+        // Freeing it as the wrong type leaves it alive.
+        let (code, _) = failure(|| ros_image_free(img));
+        assert_eq!(code, libc::EINVAL);
+        assert!(!ros_detect_get_box(d, 0).is_null());
+        ros_detect_free(d);
+    }
+}
+
+#[test]
+fn freed_handle_is_rejected() {
+    let _serial = SERIAL.lock().unwrap();
+    let msg = header();
+    unsafe {
+        let h = ros_header_from_cdr(msg.as_cdr().as_ptr(), msg.as_cdr().len());
+        ros_header_free(h);
+
+        let (code, text) = failure(|| assert_eq!(ros_header_get_stamp_sec(h), 0));
+        assert_eq!(code, libc::EINVAL);
+        assert!(
+            text.ends_with("is not a live ros_header_t handle"),
+            "{text}"
+        );
+
+        let (code, _) = failure(|| ros_header_free(h));
+        assert_eq!(code, libc::EINVAL);
+    }
+}
+
+#[test]
+fn child_handles_live_with_their_parent() {
+    let _serial = SERIAL.lock().unwrap();
+    let msg = detect();
+    unsafe {
+        let d = ros_detect_from_cdr(msg.as_cdr().as_ptr(), msg.as_cdr().len());
+        assert!(!d.is_null());
+        let child = ros_detect_get_box(d, 1);
+        assert_eq!(ros_box_get_score(child), 0.4);
+
+        let (code, _) = failure(|| ros_box_free(child as *mut ros_box_t));
+        assert_eq!(code, libc::EINVAL);
+        assert_eq!(ros_box_get_score(child), 0.4);
+
+        ros_detect_free(d);
+        let (code, text) = failure(|| assert_eq!(ros_box_get_score(child), 0.0));
+        assert_eq!(code, libc::EINVAL);
+        assert!(text.ends_with("is not a live ros_box_t handle"), "{text}");
+    }
+}
+
+#[test]
+fn concurrent_retains_and_releases_balance() {
+    let _serial = SERIAL.lock().unwrap();
+    let msg = header();
+    let h = unsafe { ros_header_from_cdr(msg.as_cdr().as_ptr(), msg.as_cdr().len()) };
+    let addr = h as usize;
+    std::thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(move || {
+                let h = addr as *mut ros_header_t;
+                for _ in 0..1000 {
+                    unsafe {
+                        assert_eq!(ros_header_retain(h), h);
+                        assert_eq!(ros_header_get_stamp_sec(h), 42);
+                        ros_header_release(h);
+                    }
+                }
+            });
+        }
+    });
+    unsafe {
+        assert_eq!(ros_header_get_stamp_sec(h), 42);
+        ros_header_free(h);
+        let (code, _) = failure(|| assert_eq!(ros_header_get_stamp_sec(h), 0));
+        assert_eq!(code, libc::EINVAL);
+    }
+}
+
+#[test]
+fn retained_handle_lives_until_last_release() {
+    let _serial = SERIAL.lock().unwrap();
+    let msg = header();
+    unsafe {
+        let h = ros_header_from_cdr(msg.as_cdr().as_ptr(), msg.as_cdr().len());
+        assert_eq!(ros_header_retain(h), h);
+        ros_header_free(h);
+        assert_eq!(ros_header_get_stamp_sec(h), 42);
+
+        ros_header_release(h);
+        let (code, text) = failure(|| assert_eq!(ros_header_get_stamp_sec(h), 0));
+        assert_eq!(code, libc::EINVAL);
+        assert!(
+            text.ends_with("is not a live ros_header_t handle"),
+            "{text}"
+        );
+    }
+}
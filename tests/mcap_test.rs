@@ -547,3 +547,58 @@ fn test_mcap_cdr_timing() {
         println!("Validation: {} mismatches", grand_mismatches);
     }
 }
+
+/// Schema and channel records written from `mcap_schema` read back intact,
+/// and the message decodes against the schema they name.
+#[test]
+fn test_mcap_schema_records_roundtrip() {
+    use edgefirst_schemas::builtin_interfaces::Time;
+    use edgefirst_schemas::schema_registry::mcap_schema;
+    use std::collections::BTreeMap;
+
+    let schema = mcap_schema("sensor_msgs/msg/Image").unwrap();
+    let image = sensor_msgs::Image::builder()
+        .stamp(Time::new(1, 2))
+        .frame_id("cam")
+        .encoding("mono8")
+        .build()
+        .unwrap();
+
+    let mut writer = mcap::Writer::new(std::io::Cursor::new(Vec::new())).unwrap();
+    let schema_id = writer
+        .add_schema(&schema.name, schema.encoding, schema.data.as_bytes())
+        .unwrap();
+    let channel_id = writer
+        .add_channel(
+            schema_id,
+            "/camera/image",
+            schema.message_encoding,
+            &BTreeMap::new(),
+        )
+        .unwrap();
+    writer
+        .write_to_known_channel(
+            &mcap::records::MessageHeader {
+                channel_id,
+                sequence: 0,
+                log_time: 1,
+                publish_time: 1,
+            },
+            image.as_cdr(),
+        )
+        .unwrap();
+    writer.finish().unwrap();
+    let bytes = writer.into_inner().into_inner();
+
+    let message = mcap::MessageStream::new(&bytes)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    let record = message.channel.schema.as_ref().unwrap();
+    assert_eq!(record.name, "sensor_msgs/msg/Image");
+    assert_eq!(record.encoding, "ros2msg");
+    assert_eq!(&record.data[..], schema.data.as_bytes());
+    assert_eq!(message.channel.message_encoding, "cdr");
+    assert!(validate_message(&record.name, &message.data).is_ok());
+}
@@ -39,6 +39,7 @@ from edgefirst.schemas.sensor_msgs import (
     PointField,
     RegionOfInterest,
     Temperature,
+    decode_pcd,
 )
 from edgefirst.schemas.std_msgs import Header
 
@@ -208,6 +209,49 @@ class TestPointCloud2:
         )
         assert pc.data.tobytes() == payload
 
+    def test_decode_pcd(self, sample_header):
+        points = np.zeros(3, dtype=[("x", "<f4"), ("y", "<f4"), ("z", "<f4"), ("ring", "u1")])
+        points["x"] = [1.0, 2.0, 3.0]
+        points["ring"] = [0, 5, 9]
+        fields = [
+            PointField(name="x", offset=0, datatype=7, count=1),
+            PointField(name="y", offset=4, datatype=7, count=1),
+            PointField(name="z", offset=8, datatype=7, count=1),
+            PointField(name="ring", offset=12, datatype=2, count=1),
+        ]
+        pc = PointCloud2(
+            header=sample_header, height=1, width=3,
+            fields=fields, is_bigendian=False,
+            point_step=13, row_step=13 * 3, data=points.tobytes(), is_dense=True,
+        )
+
+        decoded = decode_pcd(pc)
+        assert sorted(decoded) == ["ring", "x", "y", "z"]
+        assert decoded["x"].dtype == np.float32
+        np.testing.assert_array_equal(decoded["x"], [1.0, 2.0, 3.0])
+        assert decoded["ring"].dtype == np.uint8
+        np.testing.assert_array_equal(decoded["ring"], [0, 5, 9])
+
+    def test_decode_pcd_multi_element_field(self, sample_header):
+        points = np.zeros(2, dtype=[("x", "<f4"), ("rgb", "u1", (3,))])
+        points["x"] = [1.0, 2.0]
+        points["rgb"] = [[1, 2, 3], [4, 5, 6]]
+        fields = [
+            PointField(name="x", offset=0, datatype=7, count=1),
+            PointField(name="rgb", offset=4, datatype=2, count=3),
+        ]
+        pc = PointCloud2(
+            header=sample_header, height=1, width=2,
+            fields=fields, is_bigendian=False,
+            point_step=7, row_step=7 * 2, data=points.tobytes(), is_dense=True,
+        )
+
+        decoded = decode_pcd(pc)
+        assert decoded["x"].shape == (2,)
+        assert decoded["rgb"].shape == (2, 3)
+        assert decoded["rgb"].dtype == np.uint8
+        np.testing.assert_array_equal(decoded["rgb"], [[1, 2, 3], [4, 5, 6]])
+
 
 # ── PointField ─────────────────────────────────────────────────────
 
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Message types registered at runtime with `register_schema`, seen
+//! through the registry, MCAP helpers and the C API's generic handle.
+//!
+//! Registrations are process-wide, so they live in this test binary rather
+//! than beside the built-in registry tests.
+
+use std::ffi::{c_char, c_void, CString};
+
+use edgefirst_schemas::cdr::CdrError;
+use edgefirst_schemas::schema_registry::{
+    definition, full_definition, is_supported, list_schemas, mcap_schema, register_schema,
+    registered, schema_info, RegisterError, UserSchema,
+};
+
+extern "C" {
+    fn edgefirst_message_new(schema: *const c_char) -> *mut c_void;
+    fn edgefirst_message_deserialize(
+        schema: *const c_char,
+        data: *const u8,
+        len: usize,
+    ) -> *mut c_void;
+    fn edgefirst_message_serialize(
+        msg: *const c_void,
+        buf: *mut u8,
+        cap: usize,
+        written: *mut usize,
+    ) -> i32;
+    fn edgefirst_message_free(msg: *mut c_void);
+}
+
+const JOINT: UserSchema = UserSchema {
+    name: "acme_msgs/msg/Joint",
+    definition: "string name\nfloat64 position\n",
+    validate: None,
+};
+
+/// Rejects a gripper whose trailing `mode` is not one of its constants.
+fn validate_gripper(cdr: &[u8]) -> Result<(), CdrError> {
+    match cdr.last() {
+        Some(0 | 1) => Ok(()),
+        value => Err(CdrError::UnknownDiscriminant {
+            type_name: "acme_msgs/Gripper.mode",
+            value: value.copied().unwrap_or_default().into(),
+            offset: Some(cdr.len().saturating_sub(1)),
+        }),
+    }
+}
+
+const GRIPPER: UserSchema = UserSchema {
+    name: "acme_msgs/msg/Gripper",
+    definition: "# Parallel gripper state\n\
+                 uint8 MODE_OPEN=0\n\
+                 uint8 MODE_CLOSED=1\n\
+                 std_msgs/Header header\n\
+                 Joint[] joints\n\
+                 float32 opening\n\
+                 bool holding\n\
+                 uint8 mode\n",
+    validate: Some(validate_gripper),
+};
+
+fn register_all() {
+    register_schema(JOINT).unwrap();
+    register_schema(GRIPPER).unwrap();
+}
+
+#[test]
+fn registered_schemas_join_the_registry() {
+    register_all();
+
+    assert!(is_supported(GRIPPER.name));
+    assert!(list_schemas().contains(&GRIPPER.name));
+    assert_eq!(definition(JOINT.name), Some(JOINT.definition));
+    assert!(registered(GRIPPER.name).unwrap().validate.is_some());
+
+    let full = full_definition(GRIPPER.name).unwrap();
+    assert!(full.contains("\nMSG: acme_msgs/Joint\nstring name\n"));
+    assert!(full.contains("\nMSG: builtin_interfaces/Time\n"));
+    let mcap = mcap_schema(GRIPPER.name).unwrap();
+    assert_eq!(mcap.data, full);
+
+    // Header (stamp 8, frame_id 4 + 1) + padding + joints count + opening
+    // + holding + mode, after the encapsulation header.
+    let info = schema_info(GRIPPER.name).unwrap();
+    assert_eq!(
+        (info.min_size, info.max_size),
+        (4 + 13 + 3 + 4 + 4 + 1 + 1, None)
+    );
+    assert_eq!(info.ffi_prefix, None);
+}
+
+#[test]
+fn registration_is_checked() {
+    register_all();
+
+    // Same definition again is fine, a different one is not.
+    assert_eq!(register_schema(JOINT), Ok(()));
+    let changed = UserSchema {
+        definition: "string name\n",
+        ..JOINT
+    };
+    assert_eq!(
+        register_schema(changed),
+        Err(RegisterError::Conflict(JOINT.name.into()))
+    );
+
+    let builtin = UserSchema {
+        name: "std_msgs/msg/Header",
+        ..JOINT
+    };
+    assert_eq!(
+        register_schema(builtin),
+        Err(RegisterError::Builtin("std_msgs/msg/Header".into()))
+    );
+    let unnamed = UserSchema {
+        name: "acme_msgs/Joint",
+        ..JOINT
+    };
+    assert!(matches!(
+        register_schema(unnamed),
+        Err(RegisterError::InvalidName(_))
+    ));
+    let dangling = UserSchema {
+        name: "acme_msgs/msg/Arm",
+        definition: "Link[3] links\n",
+        validate: None,
+    };
+    assert_eq!(
+        register_schema(dangling),
+        Err(RegisterError::InvalidDefinition {
+            schema: "acme_msgs/msg/Arm".into(),
+            line: "Link[3] links".into(),
+        })
+    );
+    assert!(definition("acme_msgs/msg/Arm").is_none());
+}
+
+#[test]
+fn generic_handle_uses_registered_definition_and_validator() {
+    register_all();
+    let schema = CString::new(GRIPPER.name).unwrap();
+
+    unsafe {
+        let msg = edgefirst_message_new(schema.as_ptr());
+        assert!(!msg.is_null());
+        let mut len = 0usize;
+        assert_eq!(
+            edgefirst_message_serialize(msg, std::ptr::null_mut(), 0, &mut len),
+            0
+        );
+        let mut cdr = vec![0u8; len];
+        assert_eq!(
+            edgefirst_message_serialize(msg, cdr.as_mut_ptr(), len, &mut len),
+            0
+        );
+        edgefirst_message_free(msg);
+        assert_eq!(len, schema_info(GRIPPER.name).unwrap().min_size);
+
+        let ok = edgefirst_message_deserialize(schema.as_ptr(), cdr.as_ptr(), cdr.len());
+        assert!(!ok.is_null());
+        edgefirst_message_free(ok);
+
+        // Matches the definition, but the validator rejects it.
+        *cdr.last_mut().unwrap() = 7;
+        let bad = edgefirst_message_deserialize(schema.as_ptr(), cdr.as_ptr(), cdr.len());
+        assert!(bad.is_null());
+    }
+}
+
+#[cfg(feature = "dynamic")]
+#[test]
+fn dynamic_values_of_registered_schemas() {
+    use edgefirst_schemas::dynamic::{decode, encode, DynamicError, Value};
+
+    register_all();
+    let value = Value::Struct(vec![
+        (
+            "joints".into(),
+            Value::Array(vec![Value::Struct(vec![
+                ("name".into(), Value::String("finger".into())),
+                ("position".into(), Value::F64(0.25)),
+            ])]),
+        ),
+        ("mode".into(), Value::U8(1)),
+    ]);
+    let mut cdr = encode(GRIPPER.name, &value).unwrap();
+    let decoded = decode(GRIPPER.name, &cdr).unwrap();
+    assert_eq!(decoded.get("joints"), value.get("joints"));
+
+    *cdr.last_mut().unwrap() = 7;
+    assert!(matches!(
+        decode(GRIPPER.name, &cdr),
+        Err(DynamicError::Cdr(CdrError::UnknownDiscriminant {
+            value: 7,
+            ..
+        }))
+    ));
+}
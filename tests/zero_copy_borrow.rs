@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright © 2026 Au-Zone Technologies. All Rights Reserved.
+
+//! Borrowed (`FooRef<'a>`) decoding must reference bulk payloads in place.
+//!
+//! Each test decodes from a slice, checks that the payload pointer lies
+//! inside that slice, and that `into_*` payloads outlive the view.
+
+use edgefirst_schemas::builtin_interfaces::Time;
+use edgefirst_schemas::edgefirst_msgs::{RadarCube, RadarCubeRef};
+use edgefirst_schemas::sensor_msgs::{
+    CompressedImage, CompressedImageRef, Image, ImageRef, PointCloud2, PointCloud2Ref,
+};
+
+fn assert_within(inner: &[u8], outer: &[u8]) {
+    let outer = outer.as_ptr_range();
+    let inner = inner.as_ptr_range();
+    assert!(outer.start <= inner.start && inner.end <= outer.end);
+}
+
+#[test]
+fn image_ref_borrows_pixels() {
+    let pixels: Vec<u8> = (0..=255).cycle().take(64 * 48 * 3).collect();
+    let owned = Image::builder()
+        .stamp(Time::new(1, 2))
+        .frame_id("cam")
+        .height(48)
+        .width(64)
+        .encoding("rgb8")
+        .step(64 * 3)
+        .data(&pixels)
+        .build()
+        .unwrap();
+    let wire = owned.into_cdr();
+
+    let view: ImageRef<'_> = Image::from_cdr(wire.as_slice()).unwrap();
+    assert_eq!(view.width(), 64);
+    assert_within(view.data(), &wire);
+    let data = view.into_data();
+    assert_within(data, &wire);
+    assert_eq!(data, pixels.as_slice());
+}
+
+#[test]
+fn compressed_image_ref_borrows_payload() {
+    let jpeg = [0xFFu8, 0xD8, 0xFF, 0xE0, 1, 2, 3, 0xFF, 0xD9];
+    let wire = CompressedImage::builder()
+        .frame_id("cam")
+        .format("jpeg")
+        .data(&jpeg)
+        .build()
+        .unwrap()
+        .into_cdr();
+
+    let view: CompressedImageRef<'_> = CompressedImage::from_cdr(wire.as_slice()).unwrap();
+    assert_eq!(view.format(), "jpeg");
+    let data = view.into_data();
+    assert_within(data, &wire);
+    assert_eq!(data, &jpeg);
+}
+
+#[test]
+fn pointcloud_ref_borrows_points() {
+    let points = vec![7u8; 16 * 10];
+    let wire = PointCloud2::builder()
+        .frame_id("lidar")
+        .height(1)
+        .width(10)
+        .point_step(16)
+        .row_step(160)
+        .data(&points)
+        .build()
+        .unwrap()
+        .into_cdr();
+
+    let view: PointCloud2Ref<'_> = PointCloud2::from_cdr(wire.as_slice()).unwrap();
+    let data = view.into_data();
+    assert_within(data, &wire);
+    assert_eq!(data, points.as_slice());
+}
+
+#[test]
+fn radar_cube_ref_borrows_cube() {
+    let cube: Vec<i16> = (0..128).collect();
+    let wire = RadarCube::builder()
+        .frame_id("radar")
+        .layout(&[1, 2])
+        .shape(&[8, 16])
+        .cube(&cube)
+        .build()
+        .unwrap()
+        .into_cdr();
+
+    let view: RadarCubeRef<'_> = RadarCube::from_cdr(wire.as_slice()).unwrap();
+    let raw = RadarCube::from_cdr(wire.as_slice())
+        .unwrap()
+        .into_cube_raw();
+    assert_within(raw, &wire);
+    assert_eq!(raw.len(), cube.len() * 2);
+    assert_eq!(view.into_cube(), cube.as_slice());
+}